@@ -101,6 +101,33 @@ pub fn affine3a_from_srt(c: &mut Criterion) {
     });
 }
 
+bench_slice_op!(
+    affine3a_transform_points3,
+    "affine3a transform points3",
+    op => transform_points3,
+    loop_op => transform_point3,
+    from1 => random_srt_affine3a,
+    from2 => random_vec3
+);
+
+bench_slice_op!(
+    affine3a_transform_points3a,
+    "affine3a transform points3a",
+    op => transform_points3a,
+    loop_op => transform_point3a,
+    from1 => random_srt_affine3a,
+    from2 => random_vec3a
+);
+
+bench_slice_op!(
+    affine3a_transform_vectors3a,
+    "affine3a transform vectors3a",
+    op => transform_vectors3a,
+    loop_op => transform_vector3a,
+    from1 => random_srt_affine3a,
+    from2 => random_vec3a
+);
+
 criterion_group!(
     benches,
     affine3a_from_srt,
@@ -109,8 +136,11 @@ criterion_group!(
     affine3a_mul_mat4,
     affine3a_transform_point3,
     affine3a_transform_point3a,
+    affine3a_transform_points3,
+    affine3a_transform_points3a,
     affine3a_transform_vector3,
     affine3a_transform_vector3a,
+    affine3a_transform_vectors3a,
     mat4_mul_affine3a,
 );
 
@@ -0,0 +1,95 @@
+#[path = "support/macros.rs"]
+#[macro_use]
+mod macros;
+mod support;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use glam::IVec4;
+use std::ops::{Add, Mul};
+use support::random_ivec4;
+
+bench_binop!(
+    ivec4_add_ivec4,
+    "ivec4 add ivec4",
+    op => add,
+    from1 => random_ivec4,
+    from2 => random_ivec4
+);
+
+bench_binop!(
+    ivec4_mul_ivec4,
+    "ivec4 mul ivec4",
+    op => mul,
+    from1 => random_ivec4,
+    from2 => random_ivec4
+);
+
+bench_binop!(
+    ivec4_wrapping_add,
+    "ivec4 wrapping add",
+    op => wrapping_add,
+    from1 => random_ivec4,
+    from2 => random_ivec4
+);
+
+bench_binop!(
+    ivec4_wrapping_mul,
+    "ivec4 wrapping mul",
+    op => wrapping_mul,
+    from1 => random_ivec4,
+    from2 => random_ivec4
+);
+
+bench_binop!(
+    ivec4_min,
+    "ivec4 min",
+    op => min,
+    from1 => random_ivec4,
+    from2 => random_ivec4
+);
+
+bench_select!(
+    ivec4_select,
+    "ivec4 select",
+    ty => IVec4,
+    op => cmple,
+    from => random_ivec4
+);
+
+#[inline]
+fn ivec4_shl_op(v: IVec4) -> IVec4 {
+    v << 3
+}
+
+#[inline]
+fn ivec4_shr_op(v: IVec4) -> IVec4 {
+    v >> 3
+}
+
+bench_func!(
+    ivec4_shl,
+    "ivec4 shl",
+    op => ivec4_shl_op,
+    from => random_ivec4
+);
+
+bench_func!(
+    ivec4_shr,
+    "ivec4 shr",
+    op => ivec4_shr_op,
+    from => random_ivec4
+);
+
+criterion_group!(
+    benches,
+    ivec4_add_ivec4,
+    ivec4_mul_ivec4,
+    ivec4_wrapping_add,
+    ivec4_wrapping_mul,
+    ivec4_min,
+    ivec4_select,
+    ivec4_shl,
+    ivec4_shr,
+);
+
+criterion_main!(benches);
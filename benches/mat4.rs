@@ -1,10 +1,16 @@
+// On x86 the SSE2 backend uses AVX and FMA code paths when they are enabled at compile time. Fused
+// multiply-adds round differently to a separate multiply and add, so they are only used when the
+// `fast-math` feature is enabled. The `mat4 mul add` group runs the unfused and fused column
+// combination side by side, the fused variant is included when the `fma` target feature is on:
+//
+//     RUSTFLAGS="-C target-feature=+fma" cargo bench --bench mat4 -- "mat4 mul add"
 #[path = "support/macros.rs"]
 #[macro_use]
 mod macros;
 mod support;
 
 use criterion::{criterion_group, criterion_main, Criterion};
-use glam::Mat4;
+use glam::{DMat4, DVec4, Mat4, Vec4};
 use std::ops::Mul;
 use support::*;
 
@@ -84,6 +90,43 @@ bench_binop!(
     from => random_srt_mat4
 );
 
+pub fn random_srt_dmat4(rng: &mut PCG32) -> DMat4 {
+    random_srt_mat4(rng).as_dmat4()
+}
+
+pub fn random_dvec4(rng: &mut PCG32) -> DVec4 {
+    random_vec4(rng).as_dvec4()
+}
+
+bench_unop!(
+    dmat4_inverse,
+    "dmat4 inverse",
+    op => inverse,
+    from => random_srt_dmat4
+);
+
+bench_binop!(
+    dmat4_mul_dvec4,
+    "dmat4 mul dvec4",
+    op => mul,
+    from1 => random_srt_dmat4,
+    from2 => random_dvec4
+);
+
+bench_binop!(
+    dmat4_mul_dmat4,
+    "dmat4 mul dmat4",
+    op => mul,
+    from => random_srt_dmat4
+);
+
+bench_binop!(
+    dvec4_mul_dvec4,
+    "dvec4 mul dvec4",
+    op => mul,
+    from => random_dvec4
+);
+
 bench_from_ypr!(
     mat4_from_ypr,
     "mat4 from ypr",
@@ -119,19 +162,124 @@ pub fn mat4_from_srt(c: &mut Criterion) {
     });
 }
 
+bench_slice_op!(
+    mat4_transform_points3,
+    "mat4 transform points3",
+    op => transform_points3,
+    loop_op => transform_point3,
+    from1 => random_srt_mat4,
+    from2 => random_vec3
+);
+
+bench_slice_op!(
+    mat4_transform_vectors3,
+    "mat4 transform vectors3",
+    op => transform_vectors3,
+    loop_op => transform_vector3,
+    from1 => random_srt_mat4,
+    from2 => random_vec3
+);
+
+bench_slice_op!(
+    mat4_project_points3,
+    "mat4 project points3",
+    op => project_points3,
+    loop_op => project_point3,
+    from1 => random_srt_mat4,
+    from2 => random_vec3
+);
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "sse2",
+    not(feature = "scalar-math")
+))]
+pub fn mat4_mul_add(c: &mut Criterion) {
+    // These mirror the two `m128_mul_add` code paths in the SSE2 backend, applied to the columns
+    // of a `Mat4` the same way as `Mat4 * Vec4`.
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::*;
+
+    #[inline(always)]
+    unsafe fn mul_add(a: __m128, b: __m128, c: __m128) -> __m128 {
+        _mm_add_ps(_mm_mul_ps(a, b), c)
+    }
+
+    #[cfg(target_feature = "fma")]
+    #[inline(always)]
+    unsafe fn fused_mul_add(a: __m128, b: __m128, c: __m128) -> __m128 {
+        _mm_fmadd_ps(a, b, c)
+    }
+
+    macro_rules! bench_mul_add {
+        ($group:ident, $name:expr, $mul_add:ident) => {{
+            const SIZE: usize = 1 << 13;
+            let mut rng = support::PCG32::default();
+            let inputs = core::hint::black_box(
+                (0..SIZE)
+                    .map(|_| (random_srt_mat4(&mut rng), random_vec4(&mut rng)))
+                    .collect::<Vec<(Mat4, Vec4)>>(),
+            );
+            let mut outputs = vec![Vec4::default(); SIZE];
+            let mut i = 0;
+            $group.bench_function($name, |b| {
+                b.iter(|| {
+                    i = (i + 1) & (SIZE - 1);
+                    unsafe {
+                        let (m, v) = inputs.get_unchecked(i);
+                        let v: __m128 = (*v).into();
+                        let x = _mm_shuffle_ps(v, v, 0b00_00_00_00);
+                        let y = _mm_shuffle_ps(v, v, 0b01_01_01_01);
+                        let z = _mm_shuffle_ps(v, v, 0b10_10_10_10);
+                        let w = _mm_shuffle_ps(v, v, 0b11_11_11_11);
+                        let r = _mm_mul_ps(m.w_axis.into(), w);
+                        let r = $mul_add(m.z_axis.into(), z, r);
+                        let r = $mul_add(m.y_axis.into(), y, r);
+                        let r = $mul_add(m.x_axis.into(), x, r);
+                        *outputs.get_unchecked_mut(i) = r.into();
+                    }
+                });
+            });
+        }};
+    }
+
+    let mut group = c.benchmark_group("mat4 mul add");
+    bench_mul_add!(group, "unfused", mul_add);
+    #[cfg(target_feature = "fma")]
+    bench_mul_add!(group, "fused", fused_mul_add);
+    group.finish();
+}
+
+#[cfg(not(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "sse2",
+    not(feature = "scalar-math")
+)))]
+pub fn mat4_mul_add(_: &mut Criterion) {}
+
 criterion_group!(
     benches,
+    dmat4_inverse,
+    dmat4_mul_dmat4,
+    dmat4_mul_dvec4,
+    dvec4_mul_dvec4,
     mat4_determinant,
     mat4_from_srt,
     mat4_from_ypr,
     mat4_inverse,
+    mat4_mul_add,
     mat4_mul_mat4,
     mat4_mul_vec4,
     mat4_mul_transpose_vec4,
+    mat4_project_points3,
     mat4_transform_point3,
     mat4_transform_point3a,
+    mat4_transform_points3,
     mat4_transform_vector3,
     mat4_transform_vector3a,
+    mat4_transform_vectors3,
     mat4_transpose,
 );
 
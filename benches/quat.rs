@@ -65,6 +65,27 @@ bench_trinop!(
 
 bench_from_ypr!(quat_from_ypr, "quat from ypr", ty => Quat);
 
+pub fn quat_rotate_slice(c: &mut Criterion) {
+    const SIZE: usize = 1 << 10;
+    let mut rng = support::PCG32::default();
+    let q = core::hint::black_box(random_quat(&mut rng));
+    let mut vectors =
+        core::hint::black_box((0..SIZE).map(|_| random_vec3(&mut rng)).collect::<Vec<_>>());
+    let mut group = c.benchmark_group("quat rotate slice");
+    group.throughput(criterion::Throughput::Elements(SIZE as u64));
+    // compare against calling `mul_vec3` in a loop
+    group.bench_function("loop", |b| {
+        b.iter(|| {
+            for v in vectors.iter_mut() {
+                *v = q.mul_vec3(*v);
+            }
+        })
+    });
+    group.bench_function("batch", |b| b.iter(|| q.rotate_slice(&mut vectors)));
+    group.finish();
+    core::hint::black_box(vectors);
+}
+
 criterion_group!(
     benches,
     quat_conjugate,
@@ -74,7 +95,8 @@ criterion_group!(
     quat_mul_quat,
     quat_mul_vec3,
     quat_mul_vec3a,
-    quat_from_ypr
+    quat_from_ypr,
+    quat_rotate_slice
 );
 
 criterion_main!(benches);
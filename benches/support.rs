@@ -1,6 +1,6 @@
 #![allow(dead_code)]
 use core::f32;
-use glam::{Mat2, Mat3, Mat3A, Mat4, Quat, Vec2, Vec3, Vec3A, Vec4};
+use glam::{IVec4, Mat2, Mat3, Mat3A, Mat4, Quat, Vec2, Vec3, Vec3A, Vec4};
 
 pub struct PCG32 {
     state: u64,
@@ -61,6 +61,20 @@ pub fn random_vec4(rng: &mut PCG32) -> Vec4 {
     )
 }
 
+pub fn random_i32(rng: &mut PCG32) -> i32 {
+    // small enough that products of two values stay on the SIMD path
+    (rng.next_u32() >> 16) as i32 - 0x8000
+}
+
+pub fn random_ivec4(rng: &mut PCG32) -> IVec4 {
+    IVec4::new(
+        random_i32(rng),
+        random_i32(rng),
+        random_i32(rng),
+        random_i32(rng),
+    )
+}
+
 pub fn random_nonzero_vec2(rng: &mut PCG32) -> Vec2 {
     loop {
         let v = random_vec2(rng);
@@ -75,6 +75,33 @@ macro_rules! bench_binop {
     };
 }
 
+#[macro_export]
+macro_rules! bench_slice_op {
+    ($name: ident, $desc: expr, op => $op: ident, loop_op => $loop_op: ident, from1 => $from1:expr, from2 => $from2:expr) => {
+        pub(crate) fn $name(c: &mut Criterion) {
+            const SIZE: usize = 1 << 10;
+            let mut rng = support::PCG32::default();
+            let input1 = core::hint::black_box($from1(&mut rng));
+            let inputs2 =
+                core::hint::black_box((0..SIZE).map(|_| $from2(&mut rng)).collect::<Vec<_>>());
+            let mut outputs = inputs2.clone();
+            let mut group = c.benchmark_group($desc);
+            group.throughput(criterion::Throughput::Elements(SIZE as u64));
+            // compare the batch method against calling the single element method in a loop
+            group.bench_function("loop", |b| {
+                b.iter(|| {
+                    for (input2, output) in inputs2.iter().zip(outputs.iter_mut()) {
+                        *output = input1.$loop_op(*input2);
+                    }
+                })
+            });
+            group.bench_function("batch", |b| b.iter(|| input1.$op(&inputs2, &mut outputs)));
+            group.finish();
+            core::hint::black_box(outputs);
+        }
+    };
+}
+
 #[macro_export]
 macro_rules! bench_trinop {
     ($name: ident, $desc: expr, op => $trinop: ident, from1 => $from1:expr, from2 => $from2:expr, from3 => $from3:expr) => {
@@ -24,6 +24,100 @@ bench_select!(
     from => random_vec4
 );
 
-criterion_group!(benches, vec4_mul_vec4, vec4_select);
+bench_unop!(
+    vec4_sin,
+    "vec4 sin",
+    op => sin,
+    from => random_vec4
+);
+
+bench_unop!(
+    vec4_fast_sin,
+    "vec4 fast sin",
+    op => fast_sin,
+    from => random_vec4
+);
+
+bench_unop!(
+    vec4_sin_cos,
+    "vec4 sin cos",
+    op => sin_cos,
+    from => random_vec4
+);
+
+bench_unop!(
+    vec4_fast_sin_cos,
+    "vec4 fast sin cos",
+    op => fast_sin_cos,
+    from => random_vec4
+);
+
+bench_unop!(
+    vec4_exp,
+    "vec4 exp",
+    op => exp,
+    from => random_vec4
+);
+
+bench_unop!(
+    vec4_fast_exp,
+    "vec4 fast exp",
+    op => fast_exp,
+    from => random_vec4
+);
+
+bench_unop!(
+    vec4_ln,
+    "vec4 ln",
+    op => ln,
+    from => random_vec4
+);
+
+bench_unop!(
+    vec4_fast_ln,
+    "vec4 fast ln",
+    op => fast_ln,
+    from => random_vec4
+);
+
+#[inline]
+fn vec4_powf_op(v: Vec4) -> Vec4 {
+    v.powf(2.2)
+}
+
+#[inline]
+fn vec4_fast_powf_op(v: Vec4) -> Vec4 {
+    v.fast_powf(2.2)
+}
+
+bench_func!(
+    vec4_powf,
+    "vec4 powf",
+    op => vec4_powf_op,
+    from => random_vec4
+);
+
+bench_func!(
+    vec4_fast_powf,
+    "vec4 fast powf",
+    op => vec4_fast_powf_op,
+    from => random_vec4
+);
+
+criterion_group!(
+    benches,
+    vec4_mul_vec4,
+    vec4_select,
+    vec4_sin,
+    vec4_fast_sin,
+    vec4_sin_cos,
+    vec4_fast_sin_cos,
+    vec4_exp,
+    vec4_fast_exp,
+    vec4_ln,
+    vec4_fast_ln,
+    vec4_powf,
+    vec4_fast_powf,
+);
 
 criterion_main!(benches);
@@ -13,9 +13,16 @@ FEATURE_SETS=(
   "std $DEPENDENCIES bytecheck"
   "std scalar-math $DEPENDENCIES"
   "std cuda"
+  "std nanoserde"
   "std scalar-math cuda"
+  "std int-simd"
+  "std scalar-math int-simd"
   "std libm"
   "std scalar-math libm"
+  "std deterministic"
+  "std scalar-math deterministic"
+  "std const-float-arithmetic"
+  "std scalar-math const-float-arithmetic"
   # no_std
   "libm"
   "libm scalar-math $DEPENDENCIES"
@@ -14,10 +14,21 @@ pub(crate) fn dot3_in_x(lhs: f32x4, rhs: f32x4) -> f32x4 {
 #[inline(always)]
 pub(crate) fn dot4_in_x(lhs: f32x4, rhs: f32x4) -> f32x4 {
     let x2_y2_z2_w2 = lhs * rhs;
-    let z2_w2_0_0 = simd_swizzle!(x2_y2_z2_w2, [2, 3, 0, 0]);
-    let x2z2_y2w2_0_0 = x2_y2_z2_w2 + z2_w2_0_0;
-    let y2w2_0_0_0 = simd_swizzle!(x2z2_y2w2_0_0, [1, 0, 0, 0]);
-    x2z2_y2w2_0_0 + y2w2_0_0_0
+    #[cfg(not(feature = "deterministic"))]
+    {
+        let z2_w2_0_0 = simd_swizzle!(x2_y2_z2_w2, [2, 3, 0, 0]);
+        let x2z2_y2w2_0_0 = x2_y2_z2_w2 + z2_w2_0_0;
+        let y2w2_0_0_0 = simd_swizzle!(x2z2_y2w2_0_0, [1, 0, 0, 0]);
+        x2z2_y2w2_0_0 + y2w2_0_0_0
+    }
+    // Adds the products in the same order as the scalar implementation.
+    #[cfg(feature = "deterministic")]
+    {
+        let y2_0_0_0 = simd_swizzle!(x2_y2_z2_w2, [1, 0, 0, 0]);
+        let z2_0_0_0 = simd_swizzle!(x2_y2_z2_w2, [2, 0, 0, 0]);
+        let w2_0_0_0 = simd_swizzle!(x2_y2_z2_w2, [3, 0, 0, 0]);
+        x2_y2_z2_w2 + y2_0_0_0 + z2_0_0_0 + w2_0_0_0
+    }
 }
 
 #[inline]
@@ -311,6 +311,8 @@ macro_rules! impl_quat_from_euler {
                     angles.y = -angles.y;
                 }
 
+                // Only scalar operations are used here on every backend, the `deterministic`
+                // feature relies on this order and on `math::sin_cos` using `libm`.
                 let ti = angles.x * 0.5;
                 let tj = angles.y * 0.5;
                 let th = angles.z * 0.5;
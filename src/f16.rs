@@ -0,0 +1,34 @@
+mod half;
+mod hvec2;
+mod hvec3;
+mod hvec4;
+
+pub use half::F16;
+pub use hvec2::{hvec2, HVec2};
+pub use hvec3::{hvec3, HVec3};
+pub use hvec4::{hvec4, HVec4};
+
+#[cfg(not(target_arch = "spirv"))]
+mod test {
+    use super::*;
+
+    mod const_test_f16 {
+        const_assert_eq!(2, core::mem::size_of::<super::F16>());
+        const_assert_eq!(2, core::mem::align_of::<super::F16>());
+    }
+
+    mod const_test_hvec2 {
+        const_assert_eq!(4, core::mem::size_of::<super::HVec2>());
+        const_assert_eq!(2, core::mem::align_of::<super::HVec2>());
+    }
+
+    mod const_test_hvec3 {
+        const_assert_eq!(6, core::mem::size_of::<super::HVec3>());
+        const_assert_eq!(2, core::mem::align_of::<super::HVec3>());
+    }
+
+    mod const_test_hvec4 {
+        const_assert_eq!(8, core::mem::size_of::<super::HVec4>());
+        const_assert_eq!(2, core::mem::align_of::<super::HVec4>());
+    }
+}
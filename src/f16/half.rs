@@ -0,0 +1,375 @@
+use core::cmp::Ordering;
+use core::fmt;
+use core::ops::*;
+
+#[cfg(all(
+    target_arch = "x86",
+    target_feature = "f16c",
+    not(feature = "scalar-math")
+))]
+use core::arch::x86::*;
+#[cfg(all(
+    target_arch = "x86_64",
+    target_feature = "f16c",
+    not(feature = "scalar-math")
+))]
+use core::arch::x86_64::*;
+
+#[cfg(feature = "zerocopy")]
+use zerocopy_derive::*;
+
+/// A 16-bit IEEE 754-2008 `binary16` floating point number.
+///
+/// This is a storage type. Conversions to and from `f32` round to the nearest representable
+/// value, with ties to even, and arithmetic is evaluated in `f32` and rounded back to `F16`.
+/// Because `f32` has more than twice the precision of `F16`, this gives the same result as
+/// evaluating the arithmetic in half precision directly.
+///
+/// Conversions use the F16C instructions when the `f16c` target feature is enabled and a
+/// software implementation otherwise, both of which produce identical results.
+#[derive(Clone, Copy, Default)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(FromBytes, Immutable, IntoBytes, KnownLayout)
+)]
+#[repr(transparent)]
+pub struct F16(u16);
+
+impl F16 {
+    /// Positive zero.
+    pub const ZERO: Self = Self(0x0000);
+
+    /// Negative zero.
+    pub const NEG_ZERO: Self = Self(0x8000);
+
+    /// One.
+    pub const ONE: Self = Self(0x3c00);
+
+    /// Negative one.
+    pub const NEG_ONE: Self = Self(0xbc00);
+
+    /// Not a Number (NaN).
+    pub const NAN: Self = Self(0x7e00);
+
+    /// Positive infinity.
+    pub const INFINITY: Self = Self(0x7c00);
+
+    /// Negative infinity.
+    pub const NEG_INFINITY: Self = Self(0xfc00);
+
+    /// The smallest finite value, `-65504.0`.
+    pub const MIN: Self = Self(0xfbff);
+
+    /// The largest finite value, `65504.0`.
+    pub const MAX: Self = Self(0x7bff);
+
+    /// The smallest positive normal value, `2^-14`.
+    pub const MIN_POSITIVE: Self = Self(0x0400);
+
+    /// The difference between `1.0` and the next larger representable value, `2^-10`.
+    pub const EPSILON: Self = Self(0x1400);
+
+    /// Creates a value from its raw IEEE 754 `binary16` bits.
+    #[inline]
+    #[must_use]
+    pub const fn from_bits(bits: u16) -> Self {
+        Self(bits)
+    }
+
+    /// Returns the raw IEEE 754 `binary16` bits of `self`.
+    #[inline]
+    #[must_use]
+    pub const fn to_bits(self) -> u16 {
+        self.0
+    }
+
+    /// Converts `value` to the nearest representable `F16`, rounding ties to even.
+    ///
+    /// Values too large to be represented become infinity and NaNs stay NaN.
+    #[inline]
+    #[must_use]
+    pub fn from_f32(value: f32) -> Self {
+        #[cfg(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            target_feature = "f16c",
+            not(feature = "scalar-math")
+        ))]
+        unsafe {
+            let v = _mm_cvtps_ph::<_MM_FROUND_TO_NEAREST_INT>(_mm_set_ss(value));
+            Self(_mm_extract_epi16::<0>(v) as u16)
+        }
+
+        #[cfg(not(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            target_feature = "f16c",
+            not(feature = "scalar-math")
+        )))]
+        {
+            Self(f32_to_f16_bits(value))
+        }
+    }
+
+    /// Converts `self` to `f32`. This is exact.
+    #[inline]
+    #[must_use]
+    pub fn to_f32(self) -> f32 {
+        #[cfg(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            target_feature = "f16c",
+            not(feature = "scalar-math")
+        ))]
+        unsafe {
+            _mm_cvtss_f32(_mm_cvtph_ps(_mm_cvtsi32_si128(self.0 as i32)))
+        }
+
+        #[cfg(not(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            target_feature = "f16c",
+            not(feature = "scalar-math")
+        )))]
+        {
+            f16_bits_to_f32(self.0)
+        }
+    }
+
+    /// Converts `self` to `f64`. This is exact.
+    #[inline]
+    #[must_use]
+    pub fn to_f64(self) -> f64 {
+        self.to_f32() as f64
+    }
+
+    /// Returns `true` if `self` is NaN.
+    #[inline]
+    #[must_use]
+    pub const fn is_nan(self) -> bool {
+        (self.0 & 0x7fff) > 0x7c00
+    }
+
+    /// Returns `true` if `self` is positive or negative infinity.
+    #[inline]
+    #[must_use]
+    pub const fn is_infinite(self) -> bool {
+        (self.0 & 0x7fff) == 0x7c00
+    }
+
+    /// Returns `true` if `self` is neither infinite nor NaN.
+    #[inline]
+    #[must_use]
+    pub const fn is_finite(self) -> bool {
+        (self.0 & 0x7c00) != 0x7c00
+    }
+
+    /// Returns `true` if `self` has a negative sign, including `-0.0` and NaNs with a negative
+    /// sign bit.
+    #[inline]
+    #[must_use]
+    pub const fn is_sign_negative(self) -> bool {
+        (self.0 & 0x8000) != 0
+    }
+
+    /// Returns the absolute value of `self`.
+    #[inline]
+    #[must_use]
+    pub const fn abs(self) -> Self {
+        Self(self.0 & 0x7fff)
+    }
+}
+
+/// Converts `f32` to `binary16` bits, rounding to nearest with ties to even.
+#[cfg(not(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "f16c",
+    not(feature = "scalar-math")
+)))]
+#[inline]
+fn f32_to_f16_bits(value: f32) -> u16 {
+    let x = value.to_bits();
+    let sign = ((x >> 16) & 0x8000) as u16;
+    let exp = (x >> 23) & 0xff;
+    let man = x & 0x007f_ffff;
+
+    // Infinity or NaN, keeping the top of the NaN payload and making it quiet.
+    if exp == 0xff {
+        let nan_bit = if man == 0 { 0 } else { 0x0200 };
+        return sign | 0x7c00 | nan_bit | (man >> 13) as u16;
+    }
+
+    let half_exp = exp as i32 - 127 + 15;
+
+    // Overflow to infinity.
+    if half_exp >= 0x1f {
+        return sign | 0x7c00;
+    }
+
+    // Subnormal result, or zero if the value is too small to round up to the smallest subnormal.
+    if half_exp <= 0 {
+        if half_exp < -10 {
+            return sign;
+        }
+        let man = man | 0x0080_0000;
+        let shift = (14 - half_exp) as u32;
+        let half_man = man >> shift;
+        let round_bit = 1 << (shift - 1);
+        let round_up = (man & round_bit) != 0 && (man & (3 * round_bit - 1)) != 0;
+        return sign | (half_man + round_up as u32) as u16;
+    }
+
+    // Normal result. A carry out of the mantissa correctly rounds up into the exponent, and into
+    // infinity for values just below the overflow threshold.
+    let half = ((half_exp as u32) << 10) | (man >> 13);
+    let round_bit = 0x1000;
+    let round_up = (man & round_bit) != 0 && (man & (3 * round_bit - 1)) != 0;
+    sign | (half + round_up as u32) as u16
+}
+
+/// Converts `binary16` bits to `f32`. This is exact.
+#[cfg(not(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "f16c",
+    not(feature = "scalar-math")
+)))]
+#[inline]
+fn f16_bits_to_f32(bits: u16) -> f32 {
+    let bits = bits as u32;
+    let sign = (bits & 0x8000) << 16;
+    let exp = bits & 0x7c00;
+    let man = bits & 0x03ff;
+
+    if exp == 0x7c00 {
+        // Infinity or NaN, quietening signaling NaNs.
+        let nan_bit = if man == 0 { 0 } else { 0x0040_0000 };
+        return f32::from_bits(sign | 0x7f80_0000 | nan_bit | (man << 13));
+    }
+
+    if exp == 0 {
+        if man == 0 {
+            return f32::from_bits(sign);
+        }
+        // Subnormal, normalize the mantissa.
+        let shift = man.leading_zeros() - 21;
+        let exp = (127 - 14 - shift) << 23;
+        let man = (man << (shift + 13)) & 0x007f_ffff;
+        return f32::from_bits(sign | exp | man);
+    }
+
+    f32::from_bits(sign | ((exp >> 10) + 127 - 15) << 23 | (man << 13))
+}
+
+impl PartialEq for F16 {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        if self.is_nan() || other.is_nan() {
+            false
+        } else {
+            // Positive and negative zero compare equal.
+            self.0 == other.0 || ((self.0 | other.0) & 0x7fff) == 0
+        }
+    }
+}
+
+impl PartialOrd for F16 {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.to_f32().partial_cmp(&other.to_f32())
+    }
+}
+
+impl Neg for F16 {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self(self.0 ^ 0x8000)
+    }
+}
+
+impl Neg for &F16 {
+    type Output = F16;
+    #[inline]
+    fn neg(self) -> F16 {
+        (*self).neg()
+    }
+}
+
+macro_rules! impl_f16_binop {
+    ($imp:ident, $op:ident, $imp_assign:ident, $op_assign:ident) => {
+        impl $imp for F16 {
+            type Output = Self;
+            #[inline]
+            fn $op(self, rhs: Self) -> Self {
+                Self::from_f32(self.to_f32().$op(rhs.to_f32()))
+            }
+        }
+
+        impl $imp<&F16> for F16 {
+            type Output = Self;
+            #[inline]
+            fn $op(self, rhs: &F16) -> Self {
+                self.$op(*rhs)
+            }
+        }
+
+        impl $imp<&F16> for &F16 {
+            type Output = F16;
+            #[inline]
+            fn $op(self, rhs: &F16) -> F16 {
+                (*self).$op(*rhs)
+            }
+        }
+
+        impl $imp<F16> for &F16 {
+            type Output = F16;
+            #[inline]
+            fn $op(self, rhs: F16) -> F16 {
+                (*self).$op(rhs)
+            }
+        }
+
+        impl $imp_assign for F16 {
+            #[inline]
+            fn $op_assign(&mut self, rhs: Self) {
+                *self = self.$op(rhs);
+            }
+        }
+
+        impl $imp_assign<&F16> for F16 {
+            #[inline]
+            fn $op_assign(&mut self, rhs: &F16) {
+                self.$op_assign(*rhs);
+            }
+        }
+    };
+}
+
+impl_f16_binop!(Add, add, AddAssign, add_assign);
+impl_f16_binop!(Sub, sub, SubAssign, sub_assign);
+impl_f16_binop!(Mul, mul, MulAssign, mul_assign);
+impl_f16_binop!(Div, div, DivAssign, div_assign);
+impl_f16_binop!(Rem, rem, RemAssign, rem_assign);
+
+impl From<F16> for f32 {
+    #[inline]
+    fn from(v: F16) -> Self {
+        v.to_f32()
+    }
+}
+
+impl From<F16> for f64 {
+    #[inline]
+    fn from(v: F16) -> Self {
+        v.to_f64()
+    }
+}
+
+impl fmt::Display for F16 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.to_f32(), f)
+    }
+}
+
+impl fmt::Debug for F16 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.to_f32(), f)
+    }
+}
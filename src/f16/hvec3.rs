@@ -0,0 +1,880 @@
+// Generated from hvec.rs.tera template. Edit the template, not the generated file.
+
+use crate::{U16Vec3, Vec3, Vec3A, F16};
+
+use core::fmt;
+use core::ops::*;
+
+#[cfg(feature = "zerocopy")]
+use zerocopy_derive::*;
+
+/// Creates a 3-dimensional half-precision vector.
+#[inline(always)]
+#[must_use]
+pub const fn hvec3(x: F16, y: F16, z: F16) -> HVec3 {
+    HVec3::new(x, y, z)
+}
+
+/// A 3-dimensional vector of half-precision [`F16`] floats.
+///
+/// This is a storage type for vertex data and other buffers where memory or bandwidth matters
+/// more than precision. Use [`Self::as_vec3()`] and [`Vec3::as_hvec3()`]
+/// to convert to and from `f32`, rounding to the nearest representable value with ties to even.
+/// Arithmetic converts each element to `f32`, evaluates the operation and rounds the result
+/// back to [`F16`].
+#[derive(Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(FromBytes, Immutable, IntoBytes, KnownLayout)
+)]
+#[repr(C)]
+pub struct HVec3 {
+    pub x: F16,
+    pub y: F16,
+    pub z: F16,
+}
+
+impl HVec3 {
+    /// All zeroes.
+    pub const ZERO: Self = Self::splat(F16::ZERO);
+
+    /// All ones.
+    pub const ONE: Self = Self::splat(F16::ONE);
+
+    /// All negative ones.
+    pub const NEG_ONE: Self = Self::splat(F16::NEG_ONE);
+
+    /// All NaN.
+    pub const NAN: Self = Self::splat(F16::NAN);
+
+    /// A unit vector pointing along the positive X axis.
+    pub const X: Self = Self::new(F16::ONE, F16::ZERO, F16::ZERO);
+
+    /// A unit vector pointing along the positive Y axis.
+    pub const Y: Self = Self::new(F16::ZERO, F16::ONE, F16::ZERO);
+
+    /// A unit vector pointing along the positive Z axis.
+    pub const Z: Self = Self::new(F16::ZERO, F16::ZERO, F16::ONE);
+
+    /// A unit vector pointing along the negative X axis.
+    pub const NEG_X: Self = Self::new(F16::NEG_ONE, F16::ZERO, F16::ZERO);
+
+    /// A unit vector pointing along the negative Y axis.
+    pub const NEG_Y: Self = Self::new(F16::ZERO, F16::NEG_ONE, F16::ZERO);
+
+    /// A unit vector pointing along the negative Z axis.
+    pub const NEG_Z: Self = Self::new(F16::ZERO, F16::ZERO, F16::NEG_ONE);
+
+    /// The unit axes.
+    pub const AXES: [Self; 3] = [Self::X, Self::Y, Self::Z];
+
+    /// Creates a new vector.
+    #[inline(always)]
+    #[must_use]
+    pub const fn new(x: F16, y: F16, z: F16) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Creates a vector with all elements set to `v`.
+    #[inline]
+    #[must_use]
+    pub const fn splat(v: F16) -> Self {
+        Self { x: v, y: v, z: v }
+    }
+
+    /// Creates a new vector from an array.
+    #[inline]
+    #[must_use]
+    pub const fn from_array(a: [F16; 3]) -> Self {
+        Self::new(a[0], a[1], a[2])
+    }
+
+    /// `[x, y, z]`
+    #[inline]
+    #[must_use]
+    pub const fn to_array(&self) -> [F16; 3] {
+        [self.x, self.y, self.z]
+    }
+
+    /// Creates a vector from the first 3 values in `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than 3 elements long.
+    #[inline]
+    #[must_use]
+    pub const fn from_slice(slice: &[F16]) -> Self {
+        assert!(slice.len() >= 3);
+        Self::new(slice[0], slice[1], slice[2])
+    }
+
+    /// Writes the elements of `self` to the first 3 elements in `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than 3 elements long.
+    #[inline]
+    pub fn write_to_slice(self, slice: &mut [F16]) {
+        slice[..3].copy_from_slice(&self.to_array());
+    }
+
+    /// Creates a vector from the raw IEEE 754 `binary16` bits of each element.
+    #[inline]
+    #[must_use]
+    pub const fn from_bits(bits: U16Vec3) -> Self {
+        Self::new(
+            F16::from_bits(bits.x),
+            F16::from_bits(bits.y),
+            F16::from_bits(bits.z),
+        )
+    }
+
+    /// Returns the raw IEEE 754 `binary16` bits of each element.
+    #[inline]
+    #[must_use]
+    pub const fn to_bits(self) -> U16Vec3 {
+        U16Vec3::new(self.x.to_bits(), self.y.to_bits(), self.z.to_bits())
+    }
+
+    /// Returns `true` if, and only if, all elements are finite.  If any element is either
+    /// `NaN`, positive or negative infinity, this will return `false`.
+    #[inline]
+    #[must_use]
+    pub const fn is_finite(self) -> bool {
+        self.x.is_finite() && self.y.is_finite() && self.z.is_finite()
+    }
+
+    /// Returns `true` if any elements are `NaN`.
+    #[inline]
+    #[must_use]
+    pub const fn is_nan(self) -> bool {
+        self.x.is_nan() || self.y.is_nan() || self.z.is_nan()
+    }
+
+    /// Converts all elements of `self` to `f32`. This is exact.
+    #[inline]
+    #[must_use]
+    pub fn as_vec3(self) -> Vec3 {
+        Vec3::new(self.x.to_f32(), self.y.to_f32(), self.z.to_f32())
+    }
+
+    /// Converts all elements of `self` to `f32`. This is exact.
+    #[inline]
+    #[must_use]
+    pub fn as_vec3a(self) -> Vec3A {
+        Vec3A::new(self.x.to_f32(), self.y.to_f32(), self.z.to_f32())
+    }
+}
+
+impl Add for HVec3 {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        self.as_vec3().add(rhs.as_vec3()).as_hvec3()
+    }
+}
+
+impl Add<&Self> for HVec3 {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: &Self) -> Self {
+        self.add(*rhs)
+    }
+}
+
+impl Add<&HVec3> for &HVec3 {
+    type Output = HVec3;
+    #[inline]
+    fn add(self, rhs: &HVec3) -> HVec3 {
+        (*self).add(*rhs)
+    }
+}
+
+impl Add<HVec3> for &HVec3 {
+    type Output = HVec3;
+    #[inline]
+    fn add(self, rhs: HVec3) -> HVec3 {
+        (*self).add(rhs)
+    }
+}
+
+impl AddAssign for HVec3 {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = self.add(rhs);
+    }
+}
+
+impl AddAssign<&Self> for HVec3 {
+    #[inline]
+    fn add_assign(&mut self, rhs: &Self) {
+        self.add_assign(*rhs);
+    }
+}
+
+impl Add<F16> for HVec3 {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: F16) -> Self {
+        self.as_vec3().add(rhs.to_f32()).as_hvec3()
+    }
+}
+
+impl Add<&F16> for HVec3 {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: &F16) -> Self {
+        self.add(*rhs)
+    }
+}
+
+impl Add<&F16> for &HVec3 {
+    type Output = HVec3;
+    #[inline]
+    fn add(self, rhs: &F16) -> HVec3 {
+        (*self).add(*rhs)
+    }
+}
+
+impl Add<F16> for &HVec3 {
+    type Output = HVec3;
+    #[inline]
+    fn add(self, rhs: F16) -> HVec3 {
+        (*self).add(rhs)
+    }
+}
+
+impl AddAssign<F16> for HVec3 {
+    #[inline]
+    fn add_assign(&mut self, rhs: F16) {
+        *self = self.add(rhs);
+    }
+}
+
+impl AddAssign<&F16> for HVec3 {
+    #[inline]
+    fn add_assign(&mut self, rhs: &F16) {
+        self.add_assign(*rhs);
+    }
+}
+
+impl Add<HVec3> for F16 {
+    type Output = HVec3;
+    #[inline]
+    fn add(self, rhs: HVec3) -> HVec3 {
+        self.to_f32().add(rhs.as_vec3()).as_hvec3()
+    }
+}
+
+impl Add<&HVec3> for F16 {
+    type Output = HVec3;
+    #[inline]
+    fn add(self, rhs: &HVec3) -> HVec3 {
+        self.add(*rhs)
+    }
+}
+
+impl Add<&HVec3> for &F16 {
+    type Output = HVec3;
+    #[inline]
+    fn add(self, rhs: &HVec3) -> HVec3 {
+        (*self).add(*rhs)
+    }
+}
+
+impl Add<HVec3> for &F16 {
+    type Output = HVec3;
+    #[inline]
+    fn add(self, rhs: HVec3) -> HVec3 {
+        (*self).add(rhs)
+    }
+}
+
+impl Sub for HVec3 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        self.as_vec3().sub(rhs.as_vec3()).as_hvec3()
+    }
+}
+
+impl Sub<&Self> for HVec3 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: &Self) -> Self {
+        self.sub(*rhs)
+    }
+}
+
+impl Sub<&HVec3> for &HVec3 {
+    type Output = HVec3;
+    #[inline]
+    fn sub(self, rhs: &HVec3) -> HVec3 {
+        (*self).sub(*rhs)
+    }
+}
+
+impl Sub<HVec3> for &HVec3 {
+    type Output = HVec3;
+    #[inline]
+    fn sub(self, rhs: HVec3) -> HVec3 {
+        (*self).sub(rhs)
+    }
+}
+
+impl SubAssign for HVec3 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = self.sub(rhs);
+    }
+}
+
+impl SubAssign<&Self> for HVec3 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: &Self) {
+        self.sub_assign(*rhs);
+    }
+}
+
+impl Sub<F16> for HVec3 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: F16) -> Self {
+        self.as_vec3().sub(rhs.to_f32()).as_hvec3()
+    }
+}
+
+impl Sub<&F16> for HVec3 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: &F16) -> Self {
+        self.sub(*rhs)
+    }
+}
+
+impl Sub<&F16> for &HVec3 {
+    type Output = HVec3;
+    #[inline]
+    fn sub(self, rhs: &F16) -> HVec3 {
+        (*self).sub(*rhs)
+    }
+}
+
+impl Sub<F16> for &HVec3 {
+    type Output = HVec3;
+    #[inline]
+    fn sub(self, rhs: F16) -> HVec3 {
+        (*self).sub(rhs)
+    }
+}
+
+impl SubAssign<F16> for HVec3 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: F16) {
+        *self = self.sub(rhs);
+    }
+}
+
+impl SubAssign<&F16> for HVec3 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: &F16) {
+        self.sub_assign(*rhs);
+    }
+}
+
+impl Sub<HVec3> for F16 {
+    type Output = HVec3;
+    #[inline]
+    fn sub(self, rhs: HVec3) -> HVec3 {
+        self.to_f32().sub(rhs.as_vec3()).as_hvec3()
+    }
+}
+
+impl Sub<&HVec3> for F16 {
+    type Output = HVec3;
+    #[inline]
+    fn sub(self, rhs: &HVec3) -> HVec3 {
+        self.sub(*rhs)
+    }
+}
+
+impl Sub<&HVec3> for &F16 {
+    type Output = HVec3;
+    #[inline]
+    fn sub(self, rhs: &HVec3) -> HVec3 {
+        (*self).sub(*rhs)
+    }
+}
+
+impl Sub<HVec3> for &F16 {
+    type Output = HVec3;
+    #[inline]
+    fn sub(self, rhs: HVec3) -> HVec3 {
+        (*self).sub(rhs)
+    }
+}
+
+impl Mul for HVec3 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        self.as_vec3().mul(rhs.as_vec3()).as_hvec3()
+    }
+}
+
+impl Mul<&Self> for HVec3 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: &Self) -> Self {
+        self.mul(*rhs)
+    }
+}
+
+impl Mul<&HVec3> for &HVec3 {
+    type Output = HVec3;
+    #[inline]
+    fn mul(self, rhs: &HVec3) -> HVec3 {
+        (*self).mul(*rhs)
+    }
+}
+
+impl Mul<HVec3> for &HVec3 {
+    type Output = HVec3;
+    #[inline]
+    fn mul(self, rhs: HVec3) -> HVec3 {
+        (*self).mul(rhs)
+    }
+}
+
+impl MulAssign for HVec3 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.mul(rhs);
+    }
+}
+
+impl MulAssign<&Self> for HVec3 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: &Self) {
+        self.mul_assign(*rhs);
+    }
+}
+
+impl Mul<F16> for HVec3 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: F16) -> Self {
+        self.as_vec3().mul(rhs.to_f32()).as_hvec3()
+    }
+}
+
+impl Mul<&F16> for HVec3 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: &F16) -> Self {
+        self.mul(*rhs)
+    }
+}
+
+impl Mul<&F16> for &HVec3 {
+    type Output = HVec3;
+    #[inline]
+    fn mul(self, rhs: &F16) -> HVec3 {
+        (*self).mul(*rhs)
+    }
+}
+
+impl Mul<F16> for &HVec3 {
+    type Output = HVec3;
+    #[inline]
+    fn mul(self, rhs: F16) -> HVec3 {
+        (*self).mul(rhs)
+    }
+}
+
+impl MulAssign<F16> for HVec3 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: F16) {
+        *self = self.mul(rhs);
+    }
+}
+
+impl MulAssign<&F16> for HVec3 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: &F16) {
+        self.mul_assign(*rhs);
+    }
+}
+
+impl Mul<HVec3> for F16 {
+    type Output = HVec3;
+    #[inline]
+    fn mul(self, rhs: HVec3) -> HVec3 {
+        self.to_f32().mul(rhs.as_vec3()).as_hvec3()
+    }
+}
+
+impl Mul<&HVec3> for F16 {
+    type Output = HVec3;
+    #[inline]
+    fn mul(self, rhs: &HVec3) -> HVec3 {
+        self.mul(*rhs)
+    }
+}
+
+impl Mul<&HVec3> for &F16 {
+    type Output = HVec3;
+    #[inline]
+    fn mul(self, rhs: &HVec3) -> HVec3 {
+        (*self).mul(*rhs)
+    }
+}
+
+impl Mul<HVec3> for &F16 {
+    type Output = HVec3;
+    #[inline]
+    fn mul(self, rhs: HVec3) -> HVec3 {
+        (*self).mul(rhs)
+    }
+}
+
+impl Div for HVec3 {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: Self) -> Self {
+        self.as_vec3().div(rhs.as_vec3()).as_hvec3()
+    }
+}
+
+impl Div<&Self> for HVec3 {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: &Self) -> Self {
+        self.div(*rhs)
+    }
+}
+
+impl Div<&HVec3> for &HVec3 {
+    type Output = HVec3;
+    #[inline]
+    fn div(self, rhs: &HVec3) -> HVec3 {
+        (*self).div(*rhs)
+    }
+}
+
+impl Div<HVec3> for &HVec3 {
+    type Output = HVec3;
+    #[inline]
+    fn div(self, rhs: HVec3) -> HVec3 {
+        (*self).div(rhs)
+    }
+}
+
+impl DivAssign for HVec3 {
+    #[inline]
+    fn div_assign(&mut self, rhs: Self) {
+        *self = self.div(rhs);
+    }
+}
+
+impl DivAssign<&Self> for HVec3 {
+    #[inline]
+    fn div_assign(&mut self, rhs: &Self) {
+        self.div_assign(*rhs);
+    }
+}
+
+impl Div<F16> for HVec3 {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: F16) -> Self {
+        self.as_vec3().div(rhs.to_f32()).as_hvec3()
+    }
+}
+
+impl Div<&F16> for HVec3 {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: &F16) -> Self {
+        self.div(*rhs)
+    }
+}
+
+impl Div<&F16> for &HVec3 {
+    type Output = HVec3;
+    #[inline]
+    fn div(self, rhs: &F16) -> HVec3 {
+        (*self).div(*rhs)
+    }
+}
+
+impl Div<F16> for &HVec3 {
+    type Output = HVec3;
+    #[inline]
+    fn div(self, rhs: F16) -> HVec3 {
+        (*self).div(rhs)
+    }
+}
+
+impl DivAssign<F16> for HVec3 {
+    #[inline]
+    fn div_assign(&mut self, rhs: F16) {
+        *self = self.div(rhs);
+    }
+}
+
+impl DivAssign<&F16> for HVec3 {
+    #[inline]
+    fn div_assign(&mut self, rhs: &F16) {
+        self.div_assign(*rhs);
+    }
+}
+
+impl Div<HVec3> for F16 {
+    type Output = HVec3;
+    #[inline]
+    fn div(self, rhs: HVec3) -> HVec3 {
+        self.to_f32().div(rhs.as_vec3()).as_hvec3()
+    }
+}
+
+impl Div<&HVec3> for F16 {
+    type Output = HVec3;
+    #[inline]
+    fn div(self, rhs: &HVec3) -> HVec3 {
+        self.div(*rhs)
+    }
+}
+
+impl Div<&HVec3> for &F16 {
+    type Output = HVec3;
+    #[inline]
+    fn div(self, rhs: &HVec3) -> HVec3 {
+        (*self).div(*rhs)
+    }
+}
+
+impl Div<HVec3> for &F16 {
+    type Output = HVec3;
+    #[inline]
+    fn div(self, rhs: HVec3) -> HVec3 {
+        (*self).div(rhs)
+    }
+}
+
+impl Rem for HVec3 {
+    type Output = Self;
+    #[inline]
+    fn rem(self, rhs: Self) -> Self {
+        self.as_vec3().rem(rhs.as_vec3()).as_hvec3()
+    }
+}
+
+impl Rem<&Self> for HVec3 {
+    type Output = Self;
+    #[inline]
+    fn rem(self, rhs: &Self) -> Self {
+        self.rem(*rhs)
+    }
+}
+
+impl Rem<&HVec3> for &HVec3 {
+    type Output = HVec3;
+    #[inline]
+    fn rem(self, rhs: &HVec3) -> HVec3 {
+        (*self).rem(*rhs)
+    }
+}
+
+impl Rem<HVec3> for &HVec3 {
+    type Output = HVec3;
+    #[inline]
+    fn rem(self, rhs: HVec3) -> HVec3 {
+        (*self).rem(rhs)
+    }
+}
+
+impl RemAssign for HVec3 {
+    #[inline]
+    fn rem_assign(&mut self, rhs: Self) {
+        *self = self.rem(rhs);
+    }
+}
+
+impl RemAssign<&Self> for HVec3 {
+    #[inline]
+    fn rem_assign(&mut self, rhs: &Self) {
+        self.rem_assign(*rhs);
+    }
+}
+
+impl Rem<F16> for HVec3 {
+    type Output = Self;
+    #[inline]
+    fn rem(self, rhs: F16) -> Self {
+        self.as_vec3().rem(rhs.to_f32()).as_hvec3()
+    }
+}
+
+impl Rem<&F16> for HVec3 {
+    type Output = Self;
+    #[inline]
+    fn rem(self, rhs: &F16) -> Self {
+        self.rem(*rhs)
+    }
+}
+
+impl Rem<&F16> for &HVec3 {
+    type Output = HVec3;
+    #[inline]
+    fn rem(self, rhs: &F16) -> HVec3 {
+        (*self).rem(*rhs)
+    }
+}
+
+impl Rem<F16> for &HVec3 {
+    type Output = HVec3;
+    #[inline]
+    fn rem(self, rhs: F16) -> HVec3 {
+        (*self).rem(rhs)
+    }
+}
+
+impl RemAssign<F16> for HVec3 {
+    #[inline]
+    fn rem_assign(&mut self, rhs: F16) {
+        *self = self.rem(rhs);
+    }
+}
+
+impl RemAssign<&F16> for HVec3 {
+    #[inline]
+    fn rem_assign(&mut self, rhs: &F16) {
+        self.rem_assign(*rhs);
+    }
+}
+
+impl Rem<HVec3> for F16 {
+    type Output = HVec3;
+    #[inline]
+    fn rem(self, rhs: HVec3) -> HVec3 {
+        self.to_f32().rem(rhs.as_vec3()).as_hvec3()
+    }
+}
+
+impl Rem<&HVec3> for F16 {
+    type Output = HVec3;
+    #[inline]
+    fn rem(self, rhs: &HVec3) -> HVec3 {
+        self.rem(*rhs)
+    }
+}
+
+impl Rem<&HVec3> for &F16 {
+    type Output = HVec3;
+    #[inline]
+    fn rem(self, rhs: &HVec3) -> HVec3 {
+        (*self).rem(*rhs)
+    }
+}
+
+impl Rem<HVec3> for &F16 {
+    type Output = HVec3;
+    #[inline]
+    fn rem(self, rhs: HVec3) -> HVec3 {
+        (*self).rem(rhs)
+    }
+}
+
+impl Neg for HVec3 {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self::new(self.x.neg(), self.y.neg(), self.z.neg())
+    }
+}
+
+impl Neg for &HVec3 {
+    type Output = HVec3;
+    #[inline]
+    fn neg(self) -> HVec3 {
+        (*self).neg()
+    }
+}
+
+impl Index<usize> for HVec3 {
+    type Output = F16;
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            _ => panic!("index out of bounds"),
+        }
+    }
+}
+
+impl IndexMut<usize> for HVec3 {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            _ => panic!("index out of bounds"),
+        }
+    }
+}
+
+impl AsRef<[F16; 3]> for HVec3 {
+    #[inline]
+    fn as_ref(&self) -> &[F16; 3] {
+        unsafe { &*(self as *const Self as *const [F16; 3]) }
+    }
+}
+
+impl AsMut<[F16; 3]> for HVec3 {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [F16; 3] {
+        unsafe { &mut *(self as *mut Self as *mut [F16; 3]) }
+    }
+}
+
+impl fmt::Display for HVec3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.as_vec3(), f)
+    }
+}
+
+impl fmt::Debug for HVec3 {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_tuple(stringify!(HVec3))
+            .field(&self.x)
+            .field(&self.y)
+            .field(&self.z)
+            .finish()
+    }
+}
+
+impl From<[F16; 3]> for HVec3 {
+    #[inline]
+    fn from(a: [F16; 3]) -> Self {
+        Self::from_array(a)
+    }
+}
+
+impl From<HVec3> for [F16; 3] {
+    #[inline]
+    fn from(v: HVec3) -> Self {
+        v.to_array()
+    }
+}
+
+impl From<HVec3> for Vec3 {
+    #[inline]
+    fn from(v: HVec3) -> Self {
+        v.as_vec3()
+    }
+}
@@ -1,11 +1,22 @@
 mod affine2;
 mod affine3;
 mod affine3a;
+mod dualquat;
 mod float;
+mod isometry2;
+mod isometry3a;
+mod mat2x3;
 mod mat3;
+mod mat3x2;
+mod mat3x4;
+mod mat4x3;
 pub(crate) mod math;
+mod rot2;
+mod transform2;
+mod transform3;
 mod vec2;
 mod vec3;
+mod vec3x8;
 
 #[cfg(all(feature = "core-simd", not(feature = "scalar-math")))]
 mod coresimd;
@@ -74,14 +85,27 @@ use coresimd::*;
 pub use affine2::Affine2;
 pub use affine3::Affine3;
 pub use affine3a::Affine3A;
+pub use dualquat::{dualquat, DualQuat};
+pub use isometry2::Isometry2;
+pub use isometry3a::Isometry3A;
 pub use mat2::{mat2, Mat2};
+pub use mat2x3::{mat2x3, Mat2x3};
 pub use mat3::{mat3, Mat3};
 pub use mat3a::{mat3a, Mat3A};
+pub use mat3x2::{mat3x2, Mat3x2};
+pub use mat3x4::{mat3x4, Mat3x4};
 pub use mat4::{mat4, Mat4};
+pub use mat4x3::{mat4x3, Mat4x3};
 pub use quat::{quat, Quat};
+pub use quatx4::Quatx4;
+pub use rot2::Rot2;
+pub use transform2::Transform2;
+pub use transform3::Transform3;
 pub use vec2::{vec2, Vec2};
 pub use vec3::{vec3, Vec3};
 pub use vec3a::{vec3a, Vec3A};
+pub use vec3x4::Vec3x4;
+pub use vec3x8::Vec3x8;
 pub use vec4::{vec4, Vec4};
 
 #[cfg(not(target_arch = "spirv"))]
@@ -103,6 +127,34 @@ mod test {
         const_assert_eq!(32, core::mem::size_of::<super::Affine2>());
     }
 
+    mod const_test_dualquat {
+        const_assert_eq!(
+            core::mem::align_of::<super::Quat>(),
+            core::mem::align_of::<super::DualQuat>()
+        );
+        const_assert_eq!(32, core::mem::size_of::<super::DualQuat>());
+    }
+
+    #[cfg(all(not(feature = "cuda"), feature = "scalar-math"))]
+    mod const_test_isometry2 {
+        const_assert_eq!(
+            core::mem::align_of::<super::Vec2>(),
+            core::mem::align_of::<super::Isometry2>()
+        );
+        const_assert_eq!(24, core::mem::size_of::<super::Isometry2>());
+    }
+
+    #[cfg(not(feature = "scalar-math"))]
+    mod const_test_isometry2 {
+        const_assert_eq!(16, core::mem::align_of::<super::Isometry2>());
+        const_assert_eq!(32, core::mem::size_of::<super::Isometry2>());
+    }
+
+    mod const_test_isometry3a {
+        const_assert_eq!(16, core::mem::align_of::<super::Isometry3A>());
+        const_assert_eq!(32, core::mem::size_of::<super::Isometry3A>());
+    }
+
     mod const_test_mat2 {
         #[cfg(feature = "scalar-math")]
         const_assert_eq!(
@@ -114,6 +166,14 @@ mod test {
         const_assert_eq!(16, core::mem::size_of::<super::Mat2>());
     }
 
+    mod const_test_mat2x3 {
+        const_assert_eq!(
+            core::mem::align_of::<f32>(),
+            core::mem::align_of::<super::Mat2x3>()
+        );
+        const_assert_eq!(24, core::mem::size_of::<super::Mat2x3>());
+    }
+
     mod const_test_mat3 {
         const_assert_eq!(
             core::mem::align_of::<f32>(),
@@ -127,6 +187,22 @@ mod test {
         const_assert_eq!(48, core::mem::size_of::<super::Mat3A>());
     }
 
+    mod const_test_mat3x2 {
+        const_assert_eq!(
+            core::mem::align_of::<super::Vec2>(),
+            core::mem::align_of::<super::Mat3x2>()
+        );
+        const_assert_eq!(24, core::mem::size_of::<super::Mat3x2>());
+    }
+
+    mod const_test_mat3x4 {
+        const_assert_eq!(
+            core::mem::align_of::<super::Vec4>(),
+            core::mem::align_of::<super::Mat3x4>()
+        );
+        const_assert_eq!(48, core::mem::size_of::<super::Mat3x4>());
+    }
+
     mod const_test_mat4 {
         const_assert_eq!(
             core::mem::align_of::<super::Vec4>(),
@@ -135,6 +211,14 @@ mod test {
         const_assert_eq!(64, core::mem::size_of::<super::Mat4>());
     }
 
+    mod const_test_mat4x3 {
+        const_assert_eq!(
+            core::mem::align_of::<f32>(),
+            core::mem::align_of::<super::Mat4x3>()
+        );
+        const_assert_eq!(48, core::mem::size_of::<super::Mat4x3>());
+    }
+
     mod const_test_quat {
         #[cfg(feature = "scalar-math")]
         const_assert_eq!(
@@ -146,6 +230,44 @@ mod test {
         const_assert_eq!(16, core::mem::size_of::<super::Quat>());
     }
 
+    mod const_test_quatx4 {
+        const_assert_eq!(
+            core::mem::align_of::<super::Vec4>(),
+            core::mem::align_of::<super::Quatx4>()
+        );
+        const_assert_eq!(64, core::mem::size_of::<super::Quatx4>());
+    }
+
+    mod const_test_rot2 {
+        #[cfg(feature = "scalar-math")]
+        const_assert_eq!(
+            core::mem::align_of::<f32>(),
+            core::mem::align_of::<super::Rot2>()
+        );
+        #[cfg(not(any(feature = "scalar-math", target_arch = "spirv")))]
+        const_assert_eq!(8, core::mem::align_of::<super::Rot2>());
+        const_assert_eq!(8, core::mem::size_of::<super::Rot2>());
+    }
+
+    mod const_test_transform2 {
+        const_assert_eq!(
+            core::mem::align_of::<f32>(),
+            core::mem::align_of::<super::Transform2>()
+        );
+        const_assert_eq!(20, core::mem::size_of::<super::Transform2>());
+    }
+
+    mod const_test_transform3 {
+        const_assert_eq!(
+            core::mem::align_of::<super::Quat>(),
+            core::mem::align_of::<super::Transform3>()
+        );
+        #[cfg(feature = "scalar-math")]
+        const_assert_eq!(40, core::mem::size_of::<super::Transform3>());
+        #[cfg(not(any(feature = "scalar-math", target_arch = "spirv")))]
+        const_assert_eq!(48, core::mem::size_of::<super::Transform3>());
+    }
+
     mod const_test_vec2 {
         #[cfg(not(feature = "cuda"))]
         const_assert_eq!(
@@ -170,6 +292,22 @@ mod test {
         const_assert_eq!(16, core::mem::size_of::<super::Vec3A>());
     }
 
+    mod const_test_vec3x4 {
+        const_assert_eq!(
+            core::mem::align_of::<super::Vec4>(),
+            core::mem::align_of::<super::Vec3x4>()
+        );
+        const_assert_eq!(48, core::mem::size_of::<super::Vec3x4>());
+    }
+
+    mod const_test_vec3x8 {
+        const_assert_eq!(
+            core::mem::align_of::<super::Vec4>(),
+            core::mem::align_of::<super::Vec3x8>()
+        );
+        const_assert_eq!(96, core::mem::size_of::<super::Vec3x8>());
+    }
+
     mod const_test_vec4 {
         #[cfg(all(feature = "scalar-math", not(feature = "cuda")))]
         const_assert_eq!(
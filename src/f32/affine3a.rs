@@ -1,6 +1,6 @@
 // Generated from affine.rs.tera template. Edit the template, not the generated file.
 
-use crate::{Affine3, Mat3, Mat3A, Mat4, Quat, Vec3, Vec3A};
+use crate::{Affine3, Mat3, Mat3A, Mat4, Quat, Vec3, Vec3A, Vec3x4};
 use core::ops::{Deref, DerefMut, Mul, MulAssign};
 
 #[cfg(all(feature = "zerocopy", not(feature = "core-simd")))]
@@ -294,7 +294,8 @@ impl Affine3A {
     /// Extracts `scale`, `rotation` and `translation` from `self`.
     ///
     /// The transform is expected to be non-degenerate and without shearing, or the output
-    /// will be invalid.
+    /// will be invalid. Use [`Self::to_scale_shear_rotation_translation()`] for transforms
+    /// containing shear.
     ///
     /// # Panics
     ///
@@ -328,6 +329,44 @@ impl Affine3A {
         (scale, rotation, self.translation.into())
     }
 
+    /// Creates an affine transform from the given 3D `scale`, `shear`, `rotation` and
+    /// `translation`.
+    ///
+    /// This is the inverse of [`Self::to_scale_shear_rotation_translation()`]. See
+    /// [`Mat3::from_scale_shear_rotation()`] for the meaning of `shear`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `rotation` is not normalized when `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn from_scale_shear_rotation_translation(
+        scale: Vec3,
+        shear: Vec3,
+        rotation: Quat,
+        translation: Vec3,
+    ) -> Self {
+        #[allow(clippy::useless_conversion)]
+        Self {
+            matrix3: Mat3A::from_scale_shear_rotation(scale, shear, rotation),
+            translation: translation.into(),
+        }
+    }
+
+    /// Extracts `scale`, `shear`, `rotation` and `translation` from `self`.
+    ///
+    /// Unlike [`Self::to_scale_rotation_translation()`] this handles transforms containing
+    /// shear. See [`Mat3::to_scale_shear_rotation()`] for details.
+    ///
+    /// Returns `None` if `self.matrix3` is not invertible.
+    #[inline]
+    #[must_use]
+    pub fn to_scale_shear_rotation_translation(&self) -> Option<(Vec3, Vec3, Quat, Vec3)> {
+        let (scale, shear, rotation) = self.matrix3.to_scale_shear_rotation()?;
+        #[allow(clippy::useless_conversion)]
+        Some((scale, shear, rotation, self.translation.into()))
+    }
+
     /// Creates a left-handed view transform using a camera position, an up direction, and a facing
     /// direction.
     ///
@@ -429,6 +468,113 @@ impl Affine3A {
         self.matrix3 * rhs
     }
 
+    /// Transforms each point of `points` as if by [`Self::transform_point3()`], writing the
+    /// results to `out`.
+    ///
+    /// The points are transformed four at a time in [`Vec3x4`] layout, giving the same results as
+    /// calling [`Self::transform_point3()`] on each point without the per call overhead.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `points` and `out` are not the same length.
+    #[inline]
+    pub fn transform_points3(&self, points: &[Vec3], out: &mut [Vec3]) {
+        let [x_axis, y_axis, z_axis, translation] = self.wide_axes3();
+        Vec3x4::map_slice(points, out, |v| {
+            x_axis * v.x + y_axis * v.y + z_axis * v.z + translation
+        });
+    }
+
+    /// Transforms each point of `points` in place as if by [`Self::transform_point3()`].
+    ///
+    /// See [`Self::transform_points3()`].
+    #[inline]
+    pub fn transform_points3_in_place(&self, points: &mut [Vec3]) {
+        let [x_axis, y_axis, z_axis, translation] = self.wide_axes3();
+        Vec3x4::map_slice_in_place(points, |v| {
+            x_axis * v.x + y_axis * v.y + z_axis * v.z + translation
+        });
+    }
+
+    /// Transforms each vector of `vectors` as if by [`Self::transform_vector3()`], writing the
+    /// results to `out`.
+    ///
+    /// The vectors are transformed four at a time in [`Vec3x4`] layout, giving the same results
+    /// as calling [`Self::transform_vector3()`] on each vector without the per call overhead.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `vectors` and `out` are not the same length.
+    #[inline]
+    pub fn transform_vectors3(&self, vectors: &[Vec3], out: &mut [Vec3]) {
+        let [x_axis, y_axis, z_axis, _] = self.wide_axes3();
+        Vec3x4::map_slice(vectors, out, |v| x_axis * v.x + y_axis * v.y + z_axis * v.z);
+    }
+
+    /// Transforms each vector of `vectors` in place as if by [`Self::transform_vector3()`].
+    ///
+    /// See [`Self::transform_vectors3()`].
+    #[inline]
+    pub fn transform_vectors3_in_place(&self, vectors: &mut [Vec3]) {
+        let [x_axis, y_axis, z_axis, _] = self.wide_axes3();
+        Vec3x4::map_slice_in_place(vectors, |v| x_axis * v.x + y_axis * v.y + z_axis * v.z);
+    }
+
+    /// Transforms each point of `points` as if by [`Self::transform_point3a()`], writing the
+    /// results to `out`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `points` and `out` are not the same length.
+    #[inline]
+    pub fn transform_points3a(&self, points: &[Vec3A], out: &mut [Vec3A]) {
+        assert_eq!(points.len(), out.len());
+        for (p, o) in points.iter().zip(out) {
+            *o = self.transform_point3a(*p);
+        }
+    }
+
+    /// Transforms each point of `points` in place as if by [`Self::transform_point3a()`].
+    #[inline]
+    pub fn transform_points3a_in_place(&self, points: &mut [Vec3A]) {
+        for p in points {
+            *p = self.transform_point3a(*p);
+        }
+    }
+
+    /// Transforms each vector of `vectors` as if by [`Self::transform_vector3a()`], writing the
+    /// results to `out`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `vectors` and `out` are not the same length.
+    #[inline]
+    pub fn transform_vectors3a(&self, vectors: &[Vec3A], out: &mut [Vec3A]) {
+        assert_eq!(vectors.len(), out.len());
+        for (v, o) in vectors.iter().zip(out) {
+            *o = self.transform_vector3a(*v);
+        }
+    }
+
+    /// Transforms each vector of `vectors` in place as if by [`Self::transform_vector3a()`].
+    #[inline]
+    pub fn transform_vectors3a_in_place(&self, vectors: &mut [Vec3A]) {
+        for v in vectors {
+            *v = self.transform_vector3a(*v);
+        }
+    }
+
+    /// Returns the columns of `self` splatted for transforming vectors in [`Vec3x4`] layout.
+    #[inline(always)]
+    fn wide_axes3(&self) -> [Vec3x4; 4] {
+        [
+            Vec3x4::splat(self.matrix3.x_axis.into()),
+            Vec3x4::splat(self.matrix3.y_axis.into()),
+            Vec3x4::splat(self.matrix3.z_axis.into()),
+            Vec3x4::splat(self.translation.into()),
+        ]
+    }
+
     /// Returns `true` if, and only if, all elements are finite.
     ///
     /// If any element is either `NaN`, positive or negative infinity, this will return
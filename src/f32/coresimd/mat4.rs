@@ -5,7 +5,7 @@ use crate::{
     euler::{FromEuler, ToEuler},
     f32::math,
     swizzles::*,
-    DMat4, EulerRot, Mat3, Mat3A, Quat, Vec3, Vec3A, Vec4,
+    DMat4, EulerRot, Mat3, Mat3A, Quat, Vec3, Vec3A, Vec3x4, Vec4,
 };
 use core::fmt;
 use core::iter::{Product, Sum};
@@ -241,6 +241,9 @@ impl Mat4 {
     /// Extracts `scale`, `rotation` and `translation` from `self`. The input matrix is
     /// expected to be a 3D affine transformation matrix otherwise the output will be invalid.
     ///
+    /// The output will also be invalid if `self` contains shear, use [`Self::decompose()`] for
+    /// such matrices.
+    ///
     /// # Panics
     ///
     /// Will panic if the determinant of `self` is zero or if the resulting scale vector
@@ -272,6 +275,64 @@ impl Mat4 {
         (scale, rotation, translation)
     }
 
+    /// Extracts `scale`, `shear`, `rotation`, `translation` and `perspective` from `self`.
+    ///
+    /// This is a full decomposition in the style of the classic unmatrix algorithm which,
+    /// unlike [`Self::to_scale_rotation_translation()`], handles matrices containing shear and a
+    /// perspective part. `self` is split into a perspective matrix and an affine transform,
+    /// and the upper 3x3 of the affine transform is decomposed using
+    /// [`Mat3::to_scale_shear_rotation()`].
+    ///
+    /// The `perspective` vector is the bottom row of the perspective matrix, which is
+    /// `Vec4::W` if `self` is an affine transform.
+    ///
+    /// Returns `None` if the upper 3x3 of `self` is not invertible.
+    #[must_use]
+    pub fn decompose(&self) -> Option<(Vec3, Vec3, Quat, Vec3, Vec4)> {
+        let (scale, shear, rotation) = Mat3::from_mat4(*self).to_scale_shear_rotation()?;
+
+        let affine = Self::from_cols(
+            self.x_axis.with_w(0.0),
+            self.y_axis.with_w(0.0),
+            self.z_axis.with_w(0.0),
+            self.w_axis.with_w(1.0),
+        );
+        let perspective = affine.inverse().transpose().mul_vec4(self.row(3));
+
+        Some((scale, shear, rotation, self.w_axis.xyz(), perspective))
+    }
+
+    /// Creates a 4x4 matrix from the given `scale`, `shear`, `rotation`, `translation` and
+    /// `perspective`.
+    ///
+    /// This is the inverse of [`Self::decompose()`]. See
+    /// [`Mat3::from_scale_shear_rotation()`] for the meaning of `shear`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `rotation` is not normalized when `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn recompose(
+        scale: Vec3,
+        shear: Vec3,
+        rotation: Quat,
+        translation: Vec3,
+        perspective: Vec4,
+    ) -> Self {
+        let affine = Self::from_mat3_translation(
+            Mat3::from_scale_shear_rotation(scale, shear, rotation),
+            translation,
+        );
+        let perspective = Self::from_cols(
+            Vec4::new(1.0, 0.0, 0.0, perspective.x),
+            Vec4::new(0.0, 1.0, 0.0, perspective.y),
+            Vec4::new(0.0, 0.0, 1.0, perspective.z),
+            Vec4::new(0.0, 0.0, 0.0, perspective.w),
+        );
+        perspective.mul_mat4(&affine)
+    }
+
     /// Creates an affine transformation matrix from the given `rotation` quaternion.
     ///
     /// The resulting matrix can be used to transform 3D points and vectors. See
@@ -681,153 +742,237 @@ impl Mat4 {
     #[inline(always)]
     #[must_use]
     fn inverse_checked<const CHECKED: bool>(&self) -> (Self, bool) {
-        // Based on https://github.com/g-truc/glm `glm_mat4_inverse`
-        let fac0 = {
-            let swp0a = simd_swizzle!(self.w_axis.0, self.z_axis.0, [3, 3, 7, 7]);
-            let swp0b = simd_swizzle!(self.w_axis.0, self.z_axis.0, [2, 2, 6, 6]);
-
-            let swp00 = simd_swizzle!(self.z_axis.0, self.y_axis.0, [2, 2, 6, 6]);
-            let swp01 = simd_swizzle!(swp0a, [0, 0, 0, 2]);
-            let swp02 = simd_swizzle!(swp0b, [0, 0, 0, 2]);
-            let swp03 = simd_swizzle!(self.z_axis.0, self.y_axis.0, [3, 3, 7, 7]);
-
-            let mul00 = swp00 * swp01;
-            let mul01 = swp02 * swp03;
-            mul00 - mul01
-        };
-        let fac1 = {
-            let swp0a = simd_swizzle!(self.w_axis.0, self.z_axis.0, [3, 3, 7, 7]);
-            let swp0b = simd_swizzle!(self.w_axis.0, self.z_axis.0, [1, 1, 5, 5]);
-
-            let swp00 = simd_swizzle!(self.z_axis.0, self.y_axis.0, [1, 1, 5, 5]);
-            let swp01 = simd_swizzle!(swp0a, [0, 0, 0, 2]);
-            let swp02 = simd_swizzle!(swp0b, [0, 0, 0, 2]);
-            let swp03 = simd_swizzle!(self.z_axis.0, self.y_axis.0, [3, 3, 7, 7]);
-
-            let mul00 = swp00 * swp01;
-            let mul01 = swp02 * swp03;
-            mul00 - mul01
-        };
-        let fac2 = {
-            let swp0a = simd_swizzle!(self.w_axis.0, self.z_axis.0, [2, 2, 6, 6]);
-            let swp0b = simd_swizzle!(self.w_axis.0, self.z_axis.0, [1, 1, 5, 5]);
-
-            let swp00 = simd_swizzle!(self.z_axis.0, self.y_axis.0, [1, 1, 5, 5]);
-            let swp01 = simd_swizzle!(swp0a, [0, 0, 0, 2]);
-            let swp02 = simd_swizzle!(swp0b, [0, 0, 0, 2]);
-            let swp03 = simd_swizzle!(self.z_axis.0, self.y_axis.0, [2, 2, 6, 6]);
-
-            let mul00 = swp00 * swp01;
-            let mul01 = swp02 * swp03;
-            mul00 - mul01
-        };
-        let fac3 = {
-            let swp0a = simd_swizzle!(self.w_axis.0, self.z_axis.0, [3, 3, 7, 7]);
-            let swp0b = simd_swizzle!(self.w_axis.0, self.z_axis.0, [0, 0, 4, 4]);
-
-            let swp00 = simd_swizzle!(self.z_axis.0, self.y_axis.0, [0, 0, 4, 4]);
-            let swp01 = simd_swizzle!(swp0a, [0, 0, 0, 2]);
-            let swp02 = simd_swizzle!(swp0b, [0, 0, 0, 2]);
-            let swp03 = simd_swizzle!(self.z_axis.0, self.y_axis.0, [3, 3, 7, 7]);
-
-            let mul00 = swp00 * swp01;
-            let mul01 = swp02 * swp03;
-            mul00 - mul01
-        };
-        let fac4 = {
-            let swp0a = simd_swizzle!(self.w_axis.0, self.z_axis.0, [2, 2, 6, 6]);
-            let swp0b = simd_swizzle!(self.w_axis.0, self.z_axis.0, [0, 0, 4, 4]);
-
-            let swp00 = simd_swizzle!(self.z_axis.0, self.y_axis.0, [0, 0, 4, 4]);
-            let swp01 = simd_swizzle!(swp0a, [0, 0, 0, 2]);
-            let swp02 = simd_swizzle!(swp0b, [0, 0, 0, 2]);
-            let swp03 = simd_swizzle!(self.z_axis.0, self.y_axis.0, [2, 2, 6, 6]);
-
-            let mul00 = swp00 * swp01;
-            let mul01 = swp02 * swp03;
-            mul00 - mul01
-        };
-        let fac5 = {
-            let swp0a = simd_swizzle!(self.w_axis.0, self.z_axis.0, [1, 1, 5, 5]);
-            let swp0b = simd_swizzle!(self.w_axis.0, self.z_axis.0, [0, 0, 4, 4]);
-
-            let swp00 = simd_swizzle!(self.z_axis.0, self.y_axis.0, [0, 0, 4, 4]);
-            let swp01 = simd_swizzle!(swp0a, [0, 0, 0, 2]);
-            let swp02 = simd_swizzle!(swp0b, [0, 0, 0, 2]);
-            let swp03 = simd_swizzle!(self.z_axis.0, self.y_axis.0, [1, 1, 5, 5]);
-
-            let mul00 = swp00 * swp01;
-            let mul01 = swp02 * swp03;
-            mul00 - mul01
-        };
-        let sign_a = f32x4::from_array([-1.0, 1.0, -1.0, 1.0]);
-        let sign_b = f32x4::from_array([1.0, -1.0, 1.0, -1.0]);
-
-        let temp0 = simd_swizzle!(self.y_axis.0, self.x_axis.0, [0, 0, 4, 4]);
-        let vec0 = simd_swizzle!(temp0, [0, 2, 2, 2]);
-
-        let temp1 = simd_swizzle!(self.y_axis.0, self.x_axis.0, [1, 1, 5, 5]);
-        let vec1 = simd_swizzle!(temp1, [0, 2, 2, 2]);
-
-        let temp2 = simd_swizzle!(self.y_axis.0, self.x_axis.0, [2, 2, 6, 6]);
-        let vec2 = simd_swizzle!(temp2, [0, 2, 2, 2]);
-
-        let temp3 = simd_swizzle!(self.y_axis.0, self.x_axis.0, [3, 3, 7, 7]);
-        let vec3 = simd_swizzle!(temp3, [0, 2, 2, 2]);
-
-        let mul00 = vec1 * fac0;
-        let mul01 = vec2 * fac1;
-        let mul02 = vec3 * fac2;
-        let sub00 = mul00 - mul01;
-        let add00 = sub00 + mul02;
-        let inv0 = sign_b * add00;
-
-        let mul03 = vec0 * fac0;
-        let mul04 = vec2 * fac3;
-        let mul05 = vec3 * fac4;
-        let sub01 = mul03 - mul04;
-        let add01 = sub01 + mul05;
-        let inv1 = sign_a * add01;
-
-        let mul06 = vec0 * fac1;
-        let mul07 = vec1 * fac3;
-        let mul08 = vec3 * fac5;
-        let sub02 = mul06 - mul07;
-        let add02 = sub02 + mul08;
-        let inv2 = sign_b * add02;
-
-        let mul09 = vec0 * fac2;
-        let mul10 = vec1 * fac4;
-        let mul11 = vec2 * fac5;
-        let sub03 = mul09 - mul10;
-        let add03 = sub03 + mul11;
-        let inv3 = sign_a * add03;
-
-        let row0 = simd_swizzle!(inv0, inv1, [0, 0, 4, 4]);
-        let row1 = simd_swizzle!(inv2, inv3, [0, 0, 4, 4]);
-        let row2 = simd_swizzle!(row0, row1, [0, 2, 4, 6]);
-
-        let dot0 = dot4(self.x_axis.0, row2);
-
-        if CHECKED {
-            if dot0 == 0.0 {
-                return (Self::ZERO, false);
+        // Uses the operation order of the scalar implementation so the result is bit-for-bit
+        // identical on all backends.
+        #[cfg(feature = "deterministic")]
+        {
+            let (m00, m01, m02, m03) = self.x_axis.into();
+            let (m10, m11, m12, m13) = self.y_axis.into();
+            let (m20, m21, m22, m23) = self.z_axis.into();
+            let (m30, m31, m32, m33) = self.w_axis.into();
+
+            let coef00 = m22 * m33 - m32 * m23;
+            let coef02 = m12 * m33 - m32 * m13;
+            let coef03 = m12 * m23 - m22 * m13;
+
+            let coef04 = m21 * m33 - m31 * m23;
+            let coef06 = m11 * m33 - m31 * m13;
+            let coef07 = m11 * m23 - m21 * m13;
+
+            let coef08 = m21 * m32 - m31 * m22;
+            let coef10 = m11 * m32 - m31 * m12;
+            let coef11 = m11 * m22 - m21 * m12;
+
+            let coef12 = m20 * m33 - m30 * m23;
+            let coef14 = m10 * m33 - m30 * m13;
+            let coef15 = m10 * m23 - m20 * m13;
+
+            let coef16 = m20 * m32 - m30 * m22;
+            let coef18 = m10 * m32 - m30 * m12;
+            let coef19 = m10 * m22 - m20 * m12;
+
+            let coef20 = m20 * m31 - m30 * m21;
+            let coef22 = m10 * m31 - m30 * m11;
+            let coef23 = m10 * m21 - m20 * m11;
+
+            let fac0 = Vec4::new(coef00, coef00, coef02, coef03);
+            let fac1 = Vec4::new(coef04, coef04, coef06, coef07);
+            let fac2 = Vec4::new(coef08, coef08, coef10, coef11);
+            let fac3 = Vec4::new(coef12, coef12, coef14, coef15);
+            let fac4 = Vec4::new(coef16, coef16, coef18, coef19);
+            let fac5 = Vec4::new(coef20, coef20, coef22, coef23);
+
+            let vec0 = Vec4::new(m10, m00, m00, m00);
+            let vec1 = Vec4::new(m11, m01, m01, m01);
+            let vec2 = Vec4::new(m12, m02, m02, m02);
+            let vec3 = Vec4::new(m13, m03, m03, m03);
+
+            let inv0 = vec1.mul(fac0).sub(vec2.mul(fac1)).add(vec3.mul(fac2));
+            let inv1 = vec0.mul(fac0).sub(vec2.mul(fac3)).add(vec3.mul(fac4));
+            let inv2 = vec0.mul(fac1).sub(vec1.mul(fac3)).add(vec3.mul(fac5));
+            let inv3 = vec0.mul(fac2).sub(vec1.mul(fac4)).add(vec2.mul(fac5));
+
+            let sign_a = Vec4::new(1.0, -1.0, 1.0, -1.0);
+            let sign_b = Vec4::new(-1.0, 1.0, -1.0, 1.0);
+
+            let inverse = Self::from_cols(
+                inv0.mul(sign_a),
+                inv1.mul(sign_b),
+                inv2.mul(sign_a),
+                inv3.mul(sign_b),
+            );
+
+            let col0 = Vec4::new(
+                inverse.x_axis.x,
+                inverse.y_axis.x,
+                inverse.z_axis.x,
+                inverse.w_axis.x,
+            );
+
+            let dot0 = self.x_axis.mul(col0);
+            let dot1 = dot0.x + dot0.y + dot0.z + dot0.w;
+
+            if CHECKED {
+                if dot1 == 0.0 {
+                    return (Self::ZERO, false);
+                }
+            } else {
+                glam_assert!(dot1 != 0.0);
             }
-        } else {
-            glam_assert!(dot0 != 0.0);
+
+            let rcp_det = dot1.recip();
+            (inverse.mul(rcp_det), true)
         }
+        #[cfg(not(feature = "deterministic"))]
+        {
+            // Based on https://github.com/g-truc/glm `glm_mat4_inverse`
+            let fac0 = {
+                let swp0a = simd_swizzle!(self.w_axis.0, self.z_axis.0, [3, 3, 7, 7]);
+                let swp0b = simd_swizzle!(self.w_axis.0, self.z_axis.0, [2, 2, 6, 6]);
+
+                let swp00 = simd_swizzle!(self.z_axis.0, self.y_axis.0, [2, 2, 6, 6]);
+                let swp01 = simd_swizzle!(swp0a, [0, 0, 0, 2]);
+                let swp02 = simd_swizzle!(swp0b, [0, 0, 0, 2]);
+                let swp03 = simd_swizzle!(self.z_axis.0, self.y_axis.0, [3, 3, 7, 7]);
+
+                let mul00 = swp00 * swp01;
+                let mul01 = swp02 * swp03;
+                mul00 - mul01
+            };
+            let fac1 = {
+                let swp0a = simd_swizzle!(self.w_axis.0, self.z_axis.0, [3, 3, 7, 7]);
+                let swp0b = simd_swizzle!(self.w_axis.0, self.z_axis.0, [1, 1, 5, 5]);
+
+                let swp00 = simd_swizzle!(self.z_axis.0, self.y_axis.0, [1, 1, 5, 5]);
+                let swp01 = simd_swizzle!(swp0a, [0, 0, 0, 2]);
+                let swp02 = simd_swizzle!(swp0b, [0, 0, 0, 2]);
+                let swp03 = simd_swizzle!(self.z_axis.0, self.y_axis.0, [3, 3, 7, 7]);
+
+                let mul00 = swp00 * swp01;
+                let mul01 = swp02 * swp03;
+                mul00 - mul01
+            };
+            let fac2 = {
+                let swp0a = simd_swizzle!(self.w_axis.0, self.z_axis.0, [2, 2, 6, 6]);
+                let swp0b = simd_swizzle!(self.w_axis.0, self.z_axis.0, [1, 1, 5, 5]);
+
+                let swp00 = simd_swizzle!(self.z_axis.0, self.y_axis.0, [1, 1, 5, 5]);
+                let swp01 = simd_swizzle!(swp0a, [0, 0, 0, 2]);
+                let swp02 = simd_swizzle!(swp0b, [0, 0, 0, 2]);
+                let swp03 = simd_swizzle!(self.z_axis.0, self.y_axis.0, [2, 2, 6, 6]);
+
+                let mul00 = swp00 * swp01;
+                let mul01 = swp02 * swp03;
+                mul00 - mul01
+            };
+            let fac3 = {
+                let swp0a = simd_swizzle!(self.w_axis.0, self.z_axis.0, [3, 3, 7, 7]);
+                let swp0b = simd_swizzle!(self.w_axis.0, self.z_axis.0, [0, 0, 4, 4]);
+
+                let swp00 = simd_swizzle!(self.z_axis.0, self.y_axis.0, [0, 0, 4, 4]);
+                let swp01 = simd_swizzle!(swp0a, [0, 0, 0, 2]);
+                let swp02 = simd_swizzle!(swp0b, [0, 0, 0, 2]);
+                let swp03 = simd_swizzle!(self.z_axis.0, self.y_axis.0, [3, 3, 7, 7]);
+
+                let mul00 = swp00 * swp01;
+                let mul01 = swp02 * swp03;
+                mul00 - mul01
+            };
+            let fac4 = {
+                let swp0a = simd_swizzle!(self.w_axis.0, self.z_axis.0, [2, 2, 6, 6]);
+                let swp0b = simd_swizzle!(self.w_axis.0, self.z_axis.0, [0, 0, 4, 4]);
+
+                let swp00 = simd_swizzle!(self.z_axis.0, self.y_axis.0, [0, 0, 4, 4]);
+                let swp01 = simd_swizzle!(swp0a, [0, 0, 0, 2]);
+                let swp02 = simd_swizzle!(swp0b, [0, 0, 0, 2]);
+                let swp03 = simd_swizzle!(self.z_axis.0, self.y_axis.0, [2, 2, 6, 6]);
+
+                let mul00 = swp00 * swp01;
+                let mul01 = swp02 * swp03;
+                mul00 - mul01
+            };
+            let fac5 = {
+                let swp0a = simd_swizzle!(self.w_axis.0, self.z_axis.0, [1, 1, 5, 5]);
+                let swp0b = simd_swizzle!(self.w_axis.0, self.z_axis.0, [0, 0, 4, 4]);
+
+                let swp00 = simd_swizzle!(self.z_axis.0, self.y_axis.0, [0, 0, 4, 4]);
+                let swp01 = simd_swizzle!(swp0a, [0, 0, 0, 2]);
+                let swp02 = simd_swizzle!(swp0b, [0, 0, 0, 2]);
+                let swp03 = simd_swizzle!(self.z_axis.0, self.y_axis.0, [1, 1, 5, 5]);
+
+                let mul00 = swp00 * swp01;
+                let mul01 = swp02 * swp03;
+                mul00 - mul01
+            };
+            let sign_a = f32x4::from_array([-1.0, 1.0, -1.0, 1.0]);
+            let sign_b = f32x4::from_array([1.0, -1.0, 1.0, -1.0]);
+
+            let temp0 = simd_swizzle!(self.y_axis.0, self.x_axis.0, [0, 0, 4, 4]);
+            let vec0 = simd_swizzle!(temp0, [0, 2, 2, 2]);
+
+            let temp1 = simd_swizzle!(self.y_axis.0, self.x_axis.0, [1, 1, 5, 5]);
+            let vec1 = simd_swizzle!(temp1, [0, 2, 2, 2]);
+
+            let temp2 = simd_swizzle!(self.y_axis.0, self.x_axis.0, [2, 2, 6, 6]);
+            let vec2 = simd_swizzle!(temp2, [0, 2, 2, 2]);
+
+            let temp3 = simd_swizzle!(self.y_axis.0, self.x_axis.0, [3, 3, 7, 7]);
+            let vec3 = simd_swizzle!(temp3, [0, 2, 2, 2]);
+
+            let mul00 = vec1 * fac0;
+            let mul01 = vec2 * fac1;
+            let mul02 = vec3 * fac2;
+            let sub00 = mul00 - mul01;
+            let add00 = sub00 + mul02;
+            let inv0 = sign_b * add00;
+
+            let mul03 = vec0 * fac0;
+            let mul04 = vec2 * fac3;
+            let mul05 = vec3 * fac4;
+            let sub01 = mul03 - mul04;
+            let add01 = sub01 + mul05;
+            let inv1 = sign_a * add01;
+
+            let mul06 = vec0 * fac1;
+            let mul07 = vec1 * fac3;
+            let mul08 = vec3 * fac5;
+            let sub02 = mul06 - mul07;
+            let add02 = sub02 + mul08;
+            let inv2 = sign_b * add02;
+
+            let mul09 = vec0 * fac2;
+            let mul10 = vec1 * fac4;
+            let mul11 = vec2 * fac5;
+            let sub03 = mul09 - mul10;
+            let add03 = sub03 + mul11;
+            let inv3 = sign_a * add03;
+
+            let row0 = simd_swizzle!(inv0, inv1, [0, 0, 4, 4]);
+            let row1 = simd_swizzle!(inv2, inv3, [0, 0, 4, 4]);
+            let row2 = simd_swizzle!(row0, row1, [0, 2, 4, 6]);
+
+            let dot0 = dot4(self.x_axis.0, row2);
+
+            if CHECKED {
+                if dot0 == 0.0 {
+                    return (Self::ZERO, false);
+                }
+            } else {
+                glam_assert!(dot0 != 0.0);
+            }
 
-        let rcp0 = f32x4::splat(dot0.recip());
+            let rcp0 = f32x4::splat(dot0.recip());
 
-        (
-            Self {
-                x_axis: Vec4(inv0 * rcp0),
-                y_axis: Vec4(inv1 * rcp0),
-                z_axis: Vec4(inv2 * rcp0),
-                w_axis: Vec4(inv3 * rcp0),
-            },
-            true,
-        )
+            (
+                Self {
+                    x_axis: Vec4(inv0 * rcp0),
+                    y_axis: Vec4(inv1 * rcp0),
+                    z_axis: Vec4(inv2 * rcp0),
+                    w_axis: Vec4(inv3 * rcp0),
+                },
+                true,
+            )
+        }
     }
 
     /// Returns the inverse of `self`.
@@ -859,6 +1004,173 @@ impl Mat4 {
         self.inverse_checked::<true>().0
     }
 
+    /// Solves the linear system `self * x == rhs` for `x`, returning `None` if `self` is not
+    /// invertible.
+    ///
+    /// This uses the [LU decomposition](Self::lu) of `self` which is faster and more accurate
+    /// than multiplying `rhs` by the inverse of `self`.
+    #[must_use]
+    pub fn solve(&self, rhs: Vec4) -> Option<Vec4> {
+        let (lu, perm) = self.lu_packed();
+        let b = rhs.to_array();
+
+        // Forward substitution with the unit lower triangular factor.
+        let mut x = [0.0; 4];
+        for i in 0..4 {
+            let mut sum = b[perm[i]];
+            for j in 0..i {
+                sum -= lu[i][j] * x[j];
+            }
+            x[i] = sum;
+        }
+
+        // Back substitution with the upper triangular factor.
+        for i in (0..4).rev() {
+            let pivot = lu[i][i];
+            if pivot == 0.0 {
+                return None;
+            }
+            let mut sum = x[i];
+            for j in i + 1..4 {
+                sum -= lu[i][j] * x[j];
+            }
+            x[i] = sum / pivot;
+        }
+
+        Some(Vec4::from_array(x))
+    }
+
+    /// Computes the LU decomposition of `self` with partial pivoting.
+    ///
+    /// Returns a tuple `(p, l, u)` where `p` is a permutation matrix, `l` is a lower triangular
+    /// matrix with a unit diagonal and `u` is an upper triangular matrix, such that
+    /// `p * self == l * u`.
+    ///
+    /// The decomposition always exists. If `self` is not invertible `u` has a zero on its
+    /// diagonal.
+    #[must_use]
+    pub fn lu(&self) -> (Self, Self, Self) {
+        let (lu, perm) = self.lu_packed();
+        let mut p = [[0.0; 4]; 4];
+        let mut l = [[0.0; 4]; 4];
+        let mut u = [[0.0; 4]; 4];
+        for (i, row) in lu.iter().enumerate() {
+            p[i][perm[i]] = 1.0;
+            l[i][i] = 1.0;
+            l[i][..i].copy_from_slice(&row[..i]);
+            u[i][i..].copy_from_slice(&row[i..]);
+        }
+        // The factors were built row by row.
+        (
+            Self::from_cols_array_2d(&p).transpose(),
+            Self::from_cols_array_2d(&l).transpose(),
+            Self::from_cols_array_2d(&u).transpose(),
+        )
+    }
+
+    /// Computes the LU decomposition of `self` with partial pivoting, returning the rows of
+    /// both factors packed into one array and the row permutation.
+    #[inline]
+    fn lu_packed(&self) -> ([[f32; 4]; 4], [usize; 4]) {
+        let mut a = self.transpose().to_cols_array_2d();
+        let mut perm = [0, 1, 2, 3];
+        for k in 0..4 {
+            let mut pivot = k;
+            for i in k + 1..4 {
+                if math::abs(a[i][k]) > math::abs(a[pivot][k]) {
+                    pivot = i;
+                }
+            }
+            a.swap(k, pivot);
+            perm.swap(k, pivot);
+
+            let pivot_row = a[k];
+            if pivot_row[k] == 0.0 {
+                continue;
+            }
+            for row in a.iter_mut().skip(k + 1) {
+                let factor = row[k] / pivot_row[k];
+                row[k] = factor;
+                for (x, y) in row.iter_mut().zip(pivot_row).skip(k + 1) {
+                    *x -= factor * y;
+                }
+            }
+        }
+        (a, perm)
+    }
+
+    /// Computes the QR decomposition of `self` using Householder reflections.
+    ///
+    /// Returns a tuple `(q, r)` where `q` is an orthonormal matrix and `r` is an upper
+    /// triangular matrix with a non-negative diagonal, such that `self == q * r`.
+    #[must_use]
+    pub fn qr(&self) -> (Self, Self) {
+        // `qt` accumulates the transpose of `q` by applying the reflections to the identity.
+        let mut r = self.to_cols_array_2d();
+        let mut qt = Self::IDENTITY.to_cols_array_2d();
+        for k in 0..3 {
+            let mut v = [0.0; 4];
+            v[k..].copy_from_slice(&r[k][k..]);
+            let norm = math::sqrt(v.iter().map(|x| x * x).sum());
+            if norm == 0.0 {
+                continue;
+            }
+            v[k] += math::copysign(norm, v[k]);
+            let v_length_squared: f32 = v.iter().map(|x| x * x).sum();
+
+            for col in r.iter_mut().chain(qt.iter_mut()) {
+                let d: f32 = col.iter().zip(v).map(|(x, y)| x * y).sum();
+                let d = 2.0 * d / v_length_squared;
+                for (x, y) in col.iter_mut().zip(v) {
+                    *x -= d * y;
+                }
+            }
+            for x in &mut r[k][k + 1..] {
+                *x = 0.0;
+            }
+        }
+
+        // Negate rows of `r` and the matching columns of `q` to make the diagonal non-negative.
+        for k in 0..4 {
+            if r[k][k] < 0.0 {
+                for col in r.iter_mut().chain(qt.iter_mut()) {
+                    col[k] = -col[k];
+                }
+            }
+        }
+
+        (
+            Self::from_cols_array_2d(&qt).transpose(),
+            Self::from_cols_array_2d(&r),
+        )
+    }
+
+    /// Computes the Cholesky decomposition of `self`, which must be symmetric
+    /// positive-definite.
+    ///
+    /// Returns a lower triangular matrix `l` with a positive diagonal such that
+    /// `self == l * l.transpose()`, or `None` if `self` is not positive-definite. Only the
+    /// lower triangle of `self` is read.
+    #[must_use]
+    pub fn cholesky(&self) -> Option<Self> {
+        let a = self.to_cols_array_2d();
+        let mut l = [[0.0; 4]; 4];
+        for j in 0..4 {
+            let d = a[j][j] - l[..j].iter().map(|col| col[j] * col[j]).sum::<f32>();
+            if d <= 0.0 || d.is_nan() {
+                return None;
+            }
+            let d = math::sqrt(d);
+            l[j][j] = d;
+
+            for i in j + 1..4 {
+                let sum = a[j][i] - l[..j].iter().map(|col| col[i] * col[j]).sum::<f32>();
+                l[j][i] = sum / d;
+            }
+        }
+        Some(Self::from_cols_array_2d(&l))
+    }
+
     /// Creates a left-handed view matrix using a camera position, a facing direction and an up
     /// direction
     ///
@@ -1386,6 +1698,118 @@ impl Mat4 {
         Vec3A::from_vec4(res)
     }
 
+    /// Returns the `x`, `y` and `z` components of each column splatted for transforming
+    /// vectors in [`Vec3x4`] layout.
+    #[inline(always)]
+    fn wide_axes3(&self) -> [Vec3x4; 4] {
+        [
+            Vec3x4::splat(self.x_axis.xyz()),
+            Vec3x4::splat(self.y_axis.xyz()),
+            Vec3x4::splat(self.z_axis.xyz()),
+            Vec3x4::splat(self.w_axis.xyz()),
+        ]
+    }
+
+    /// Transforms each point of `points` as if by [`Self::project_point3()`], writing the
+    /// results to `out`.
+    ///
+    /// The points are transformed four at a time in [`Vec3x4`] layout, giving the same results as
+    /// calling [`Self::project_point3()`] on each point without the per call overhead.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `points` and `out` are not the same length.
+    #[inline]
+    pub fn project_points3(&self, points: &[Vec3], out: &mut [Vec3]) {
+        let [x_axis, y_axis, z_axis, w_axis] = self.wide_axes3();
+        let [x_w, y_w, z_w, w_w] = self.row(3).to_array().map(Vec4::splat);
+        Vec3x4::map_slice(points, out, |v| {
+            let w = x_w * v.x + y_w * v.y + z_w * v.z + w_w;
+            (x_axis * v.x + y_axis * v.y + z_axis * v.z + w_axis) / w
+        });
+    }
+
+    /// Transforms each point of `points` in place as if by [`Self::project_point3()`].
+    ///
+    /// See [`Self::project_points3()`].
+    #[inline]
+    pub fn project_points3_in_place(&self, points: &mut [Vec3]) {
+        let [x_axis, y_axis, z_axis, w_axis] = self.wide_axes3();
+        let [x_w, y_w, z_w, w_w] = self.row(3).to_array().map(Vec4::splat);
+        Vec3x4::map_slice_in_place(points, |v| {
+            let w = x_w * v.x + y_w * v.y + z_w * v.z + w_w;
+            (x_axis * v.x + y_axis * v.y + z_axis * v.z + w_axis) / w
+        });
+    }
+
+    /// Transforms each point of `points` as if by [`Self::transform_point3()`], writing the
+    /// results to `out`.
+    ///
+    /// The points are transformed four at a time in [`Vec3x4`] layout, giving the same results as
+    /// calling [`Self::transform_point3()`] on each point without the per call overhead.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `points` and `out` are not the same length.
+    ///
+    /// Will panic if the 3rd row of `self` is not `(0, 0, 0, 1)` when `glam_assert` is enabled.
+    #[inline]
+    pub fn transform_points3(&self, points: &[Vec3], out: &mut [Vec3]) {
+        glam_assert!(self.row(3).abs_diff_eq(Vec4::W, 1e-6));
+        let [x_axis, y_axis, z_axis, w_axis] = self.wide_axes3();
+        Vec3x4::map_slice(points, out, |v| {
+            x_axis * v.x + y_axis * v.y + z_axis * v.z + w_axis
+        });
+    }
+
+    /// Transforms each point of `points` in place as if by [`Self::transform_point3()`].
+    ///
+    /// See [`Self::transform_points3()`].
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the 3rd row of `self` is not `(0, 0, 0, 1)` when `glam_assert` is enabled.
+    #[inline]
+    pub fn transform_points3_in_place(&self, points: &mut [Vec3]) {
+        glam_assert!(self.row(3).abs_diff_eq(Vec4::W, 1e-6));
+        let [x_axis, y_axis, z_axis, w_axis] = self.wide_axes3();
+        Vec3x4::map_slice_in_place(points, |v| {
+            x_axis * v.x + y_axis * v.y + z_axis * v.z + w_axis
+        });
+    }
+
+    /// Transforms each vector of `vectors` as if by [`Self::transform_vector3()`], writing the
+    /// results to `out`.
+    ///
+    /// The vectors are transformed four at a time in [`Vec3x4`] layout, giving the same results
+    /// as calling [`Self::transform_vector3()`] on each vector without the per call overhead.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `vectors` and `out` are not the same length.
+    ///
+    /// Will panic if the 3rd row of `self` is not `(0, 0, 0, 1)` when `glam_assert` is enabled.
+    #[inline]
+    pub fn transform_vectors3(&self, vectors: &[Vec3], out: &mut [Vec3]) {
+        glam_assert!(self.row(3).abs_diff_eq(Vec4::W, 1e-6));
+        let [x_axis, y_axis, z_axis, _] = self.wide_axes3();
+        Vec3x4::map_slice(vectors, out, |v| x_axis * v.x + y_axis * v.y + z_axis * v.z);
+    }
+
+    /// Transforms each vector of `vectors` in place as if by [`Self::transform_vector3()`].
+    ///
+    /// See [`Self::transform_vectors3()`].
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the 3rd row of `self` is not `(0, 0, 0, 1)` when `glam_assert` is enabled.
+    #[inline]
+    pub fn transform_vectors3_in_place(&self, vectors: &mut [Vec3]) {
+        glam_assert!(self.row(3).abs_diff_eq(Vec4::W, 1e-6));
+        let [x_axis, y_axis, z_axis, _] = self.wide_axes3();
+        Vec3x4::map_slice_in_place(vectors, |v| x_axis * v.x + y_axis * v.y + z_axis * v.z);
+    }
+
     /// Transforms a 4D vector.
     #[inline]
     #[must_use]
@@ -4,7 +4,7 @@ use crate::{
     coresimd::*,
     euler::{EulerRot, FromEuler, ToEuler},
     f32::math,
-    DQuat, Mat3, Mat3A, Mat4, Vec2, Vec3, Vec3A, Vec4,
+    DQuat, Mat3, Mat3A, Mat4, Quatx4, Vec2, Vec3, Vec3A, Vec3x4, Vec4,
 };
 
 use core::simd::*;
@@ -479,6 +479,63 @@ impl Quat {
         axis * angle
     }
 
+    /// Returns the exponential of `self`.
+    ///
+    /// For a pure quaternion (one where `w` is zero) with vector part `v` this is the rotation
+    /// of `2.0 * v.length()` radians around `v`, which makes it convenient for integrating
+    /// angular velocities. `exp` is the inverse of [`Self::ln()`].
+    #[inline]
+    #[must_use]
+    pub fn exp(self) -> Self {
+        let v = self.xyz();
+        let angle_squared = v.length_squared();
+        let angle = math::sqrt(angle_squared);
+        let (sin, cos) = math::sin_cos(angle);
+        // Use the Taylor series of `sin(x) / x` where the division would lose precision.
+        let sinc = if angle_squared < f32::EPSILON {
+            1.0 - angle_squared / 6.0
+        } else {
+            sin / angle
+        };
+        let scale = math::exp(self.w);
+        let v = v * (scale * sinc);
+        Self::from_xyzw(v.x, v.y, v.z, scale * cos)
+    }
+
+    /// Returns the natural logarithm of `self`.
+    ///
+    /// For a normalized quaternion the result is a pure quaternion whose vector part is half
+    /// of the rotation vector returned by [`Self::to_scaled_axis()`]. `ln` is the inverse of
+    /// [`Self::exp()`].
+    #[inline]
+    #[must_use]
+    pub fn ln(self) -> Self {
+        let v = self.xyz();
+        let v_length = v.length();
+        // `atan2` keeps full precision for small angles, unlike `acos(w / length)`.
+        let angle = math::atan2(v_length, self.w);
+        let v = if v_length > 0.0 {
+            v * (angle / v_length)
+        } else if self.w < 0.0 {
+            // Any axis is valid for a half turn, pick the same one as `to_axis_angle`.
+            Vec3::X * angle
+        } else {
+            Vec3::ZERO
+        };
+        Self::from_xyzw(v.x, v.y, v.z, math::ln(self.length()))
+    }
+
+    /// Returns `self` raised to the power of `n`.
+    ///
+    /// For a normalized quaternion this scales the rotation angle by `n`, for example
+    /// `q.powf(0.5)` rotates half as far as `q` around the same axis. Note that `q` and `-q`
+    /// represent the same rotation but `q.powf(n)` and `(-q).powf(n)` generally do not.
+    #[inline]
+    #[must_use]
+    pub fn powf(self, n: f32) -> Self {
+        (self.ln() * n).exp()
+    }
+
     /// Returns the rotation angles for the given euler rotation sequence.
     #[inline]
     #[must_use]
@@ -761,6 +818,22 @@ impl Quat {
         self.mul_vec3a(rhs.into()).into()
     }
 
+    /// Rotates each vector of `vectors` in place.
+    ///
+    /// The vectors are rotated four at a time in [`Vec3x4`] layout, which avoids the per call
+    /// overhead of [`Self::mul_vec3()`] in a loop. Results may differ from [`Self::mul_vec3()`]
+    /// by rounding.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `self` is not normalized when `glam_assert` is enabled.
+    #[inline]
+    pub fn rotate_slice(self, vectors: &mut [Vec3]) {
+        glam_assert!(self.is_normalized());
+        let q = Quatx4::splat(self);
+        Vec3x4::map_slice_in_place(vectors, |v| q.mul_vec3x4(v));
+    }
+
     /// Multiplies two quaternions. If they each represent a rotation, the result will
     /// represent the combined rotation.
     ///
@@ -801,8 +874,12 @@ impl Quat {
         let result0 = lxrw_lyrw_lzrw_lwrw + lwrx_nlzrx_lyrx_nlxrx;
 
         let nlyrz_lxrz_lwrz_wlzrz = lyrz_lxrz_lwrz_lzrz * CONTROL_YXWZ;
-        let result1 = lzry_lwry_nlxry_nlyry + nlyrz_lxrz_lwrz_wlzrz;
-        Self(result0 + result1)
+        #[cfg(not(feature = "deterministic"))]
+        let result = result0 + (lzry_lwry_nlxry_nlyry + nlyrz_lxrz_lwrz_wlzrz);
+        // Adds the terms in the same order as the scalar implementation.
+        #[cfg(feature = "deterministic")]
+        let result = result0 + lzry_lwry_nlxry_nlyry + nlyrz_lxrz_lwrz_wlzrz;
+        Self(result)
     }
 
     /// Creates a quaternion from a 3x3 rotation matrix inside a 3D affine transform.
@@ -0,0 +1,384 @@
+// Generated from isometry.rs.tera template. Edit the template, not the generated file.
+
+use crate::{f32::math, Affine2, Mat2, Mat3, Vec2};
+use core::fmt;
+use core::iter::Product;
+use core::ops::{Mul, MulAssign};
+
+/// A 2D isometry, or rigid transform, which can represent rotation and translation.
+///
+/// The `rotation` is stored as a [`Mat2`] which is expected to be a pure rotation matrix,
+/// that is its columns are normalized and orthogonal. It is applied before the `translation`.
+///
+/// Unlike [`Affine2`] an isometry cannot represent scale or shear, which makes
+/// [`Self::inverse()`] cheap and exact and allows the rotation to be interpolated directly.
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(
+    all(feature = "bytemuck", not(feature = "scalar-math")),
+    derive(bytemuck::AnyBitPattern)
+)]
+#[cfg_attr(
+    all(feature = "bytemuck", feature = "scalar-math"),
+    derive(bytemuck::Pod, bytemuck::Zeroable)
+)]
+#[repr(C)]
+pub struct Isometry2 {
+    pub rotation: Mat2,
+    pub translation: Vec2,
+}
+
+impl Isometry2 {
+    /// The identity transform.
+    ///
+    /// Multiplying a vector with this returns the same vector.
+    pub const IDENTITY: Self = Self {
+        rotation: Mat2::IDENTITY,
+        translation: Vec2::ZERO,
+    };
+
+    /// All NAN:s.
+    pub const NAN: Self = Self {
+        rotation: Mat2::NAN,
+        translation: Vec2::NAN,
+    };
+
+    /// Creates an isometry from a `[f32; 6]` array containing the columns of
+    /// the `rotation` matrix followed by the `translation`.
+    ///
+    /// This is the same layout as [`Affine2::from_cols_array()`].
+    #[inline]
+    #[must_use]
+    pub const fn from_array(a: [f32; 6]) -> Self {
+        Self {
+            rotation: Mat2::from_cols(Vec2::new(a[0], a[1]), Vec2::new(a[2], a[3])),
+            translation: Vec2::new(a[4], a[5]),
+        }
+    }
+
+    /// Returns the columns of the `rotation` matrix followed by the `translation` as a
+    /// `[f32; 6]` array.
+    #[inline]
+    #[must_use]
+    pub fn to_array(&self) -> [f32; 6] {
+        let x = self.rotation.x_axis;
+        let y = self.rotation.y_axis;
+        let t = self.translation;
+        [x.x, x.y, y.x, y.y, t.x, t.y]
+    }
+
+    /// Creates an isometry from the given rotation `angle` (in radians).
+    #[inline]
+    #[must_use]
+    pub fn from_angle(angle: f32) -> Self {
+        Self {
+            rotation: Mat2::from_angle(angle),
+            translation: Vec2::ZERO,
+        }
+    }
+
+    /// Creates an isometry from the given 2D `translation`.
+    #[inline]
+    #[must_use]
+    pub fn from_translation(translation: Vec2) -> Self {
+        Self {
+            rotation: Mat2::IDENTITY,
+            translation,
+        }
+    }
+
+    /// Creates an isometry from the given 2D rotation `angle` (in radians) and `translation`.
+    ///
+    /// Equivalent to `Isometry2::from_translation(translation) * Isometry2::from_angle(angle)`
+    #[inline]
+    #[must_use]
+    pub fn from_angle_translation(angle: f32, translation: Vec2) -> Self {
+        Self {
+            rotation: Mat2::from_angle(angle),
+            translation,
+        }
+    }
+
+    /// Creates an isometry from the rotation and translation of an affine transform.
+    ///
+    /// The transform must not contain scale or shear, or the output will be invalid.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the columns of `a.matrix2` are not normalized when `glam_assert` is
+    /// enabled.
+    #[inline]
+    #[must_use]
+    pub fn from_affine2(a: &Affine2) -> Self {
+        glam_assert!(a.matrix2.x_axis.is_normalized() && a.matrix2.y_axis.is_normalized());
+        Self {
+            rotation: a.matrix2,
+            translation: a.translation,
+        }
+    }
+
+    /// Creates an isometry from the rotation and translation of a 3x3 matrix.
+    ///
+    /// The matrix must be an affine transform without scale or shear, or the output will be
+    /// invalid.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the columns of the upper 2x2 part of the matrix are not normalized when
+    /// `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn from_mat3(m: &Mat3) -> Self {
+        Self::from_affine2(&Affine2::from_mat3(*m))
+    }
+
+    /// Returns the rotation angle of `self` (in radians) in the range `[-π, +π]`.
+    #[inline]
+    #[must_use]
+    pub fn angle(&self) -> f32 {
+        math::atan2(self.rotation.x_axis.y, self.rotation.x_axis.x)
+    }
+
+    /// Returns the rotation angle (in radians) and the translation of `self`.
+    #[inline]
+    #[must_use]
+    pub fn to_angle_translation(&self) -> (f32, Vec2) {
+        (self.angle(), self.translation)
+    }
+
+    /// Converts `self` to an [`Affine2`].
+    #[inline]
+    #[must_use]
+    pub fn to_affine2(&self) -> Affine2 {
+        Affine2::from_mat2_translation(self.rotation, self.translation)
+    }
+
+    /// Converts `self` to a 3x3 homogeneous transformation matrix.
+    #[inline]
+    #[must_use]
+    pub fn to_mat3(&self) -> Mat3 {
+        Mat3::from_cols(
+            self.rotation.x_axis.extend(0.0),
+            self.rotation.y_axis.extend(0.0),
+            self.translation.extend(1.0),
+        )
+    }
+
+    /// Transforms the given 2D point, applying rotation and translation.
+    #[inline]
+    #[must_use]
+    pub fn transform_point2(&self, rhs: Vec2) -> Vec2 {
+        self.rotation * rhs + self.translation
+    }
+
+    /// Transforms the given 2D vector, applying rotation (but NOT translation).
+    ///
+    /// To also apply translation, use [`Self::transform_point2()`] instead.
+    #[inline]
+    #[must_use]
+    pub fn transform_vector2(&self, rhs: Vec2) -> Vec2 {
+        self.rotation * rhs
+    }
+
+    /// Returns the inverse of `self`.
+    ///
+    /// The inverse of the rotation is its transpose so unlike [`Affine2::inverse()`] this
+    /// does not require a general matrix inverse.
+    #[inline]
+    #[must_use]
+    pub fn inverse(&self) -> Self {
+        let rotation = self.rotation.transpose();
+        Self {
+            rotation,
+            translation: -(rotation * self.translation),
+        }
+    }
+
+    /// Performs a linear interpolation between `self` and `end` based on the value `s`.
+    ///
+    /// The translation is interpolated linearly. The rotation is interpolated by normalizing the
+    /// linear interpolation of the rotated x axis, which does not have a constant angular
+    /// velocity, see [`Self::slerp()`]. The result is invalid if the rotations of `self` and
+    /// `end` are opposite.
+    ///
+    /// When `s` is `0.0`, the result will be equal to `self`.  When `s` is `1.0`, the result
+    /// will be equal to `end`.
+    #[inline]
+    #[must_use]
+    pub fn lerp(&self, end: Self, s: f32) -> Self {
+        let x_axis = self
+            .rotation
+            .x_axis
+            .lerp(end.rotation.x_axis, s)
+            .normalize();
+        Self {
+            rotation: Mat2::from_cols(x_axis, x_axis.perp()),
+            translation: self.translation.lerp(end.translation, s),
+        }
+    }
+
+    /// Performs a spherical linear interpolation between `self` and `end` based on the value
+    /// `s`.
+    ///
+    /// The translation is interpolated linearly and the rotation angle is interpolated at a
+    /// constant angular velocity, taking the shortest path.
+    ///
+    /// When `s` is `0.0`, the result will be equal to `self`.  When `s` is `1.0`, the result
+    /// will be equal to `end`.
+    #[inline]
+    #[must_use]
+    pub fn slerp(&self, end: Self, s: f32) -> Self {
+        let delta = self.rotation.transpose() * end.rotation;
+        let angle = math::atan2(delta.x_axis.y, delta.x_axis.x);
+        Self {
+            rotation: self.rotation * Mat2::from_angle(angle * s),
+            translation: self.translation.lerp(end.translation, s),
+        }
+    }
+
+    /// Returns `true` if, and only if, all elements are finite.
+    ///
+    /// If any element is either `NaN`, positive or negative infinity, this will return
+    /// `false`.
+    #[inline]
+    #[must_use]
+    pub fn is_finite(&self) -> bool {
+        self.rotation.is_finite() && self.translation.is_finite()
+    }
+
+    /// Returns `true` if any elements are `NaN`.
+    #[inline]
+    #[must_use]
+    pub fn is_nan(&self) -> bool {
+        self.rotation.is_nan() || self.translation.is_nan()
+    }
+
+    /// Returns true if the absolute difference of all elements between `self` and `rhs`
+    /// is less than or equal to `max_abs_diff`.
+    ///
+    /// This can be used to compare if two isometries contain similar elements. It works best
+    /// when comparing with a known value. The `max_abs_diff` that should be used used depends on
+    /// the values being compared against.
+    ///
+    /// For more see
+    /// [comparing floating point numbers](https://randomascii.wordpress.com/2012/02/25/comparing-floating-point-numbers-2012-edition/).
+    #[inline]
+    #[must_use]
+    pub fn abs_diff_eq(&self, rhs: Self, max_abs_diff: f32) -> bool {
+        self.rotation.abs_diff_eq(rhs.rotation, max_abs_diff)
+            && self.translation.abs_diff_eq(rhs.translation, max_abs_diff)
+    }
+}
+
+impl Default for Isometry2 {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl fmt::Debug for Isometry2 {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct(stringify!(Isometry2))
+            .field("rotation", &self.rotation)
+            .field("translation", &self.translation)
+            .finish()
+    }
+}
+
+impl fmt::Display for Isometry2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(p) = f.precision() {
+            write!(f, "[{:.*}, {:.*}]", p, self.rotation, p, self.translation)
+        } else {
+            write!(f, "[{}, {}]", self.rotation, self.translation)
+        }
+    }
+}
+
+impl<'a> Product<&'a Self> for Isometry2 {
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.fold(Self::IDENTITY, |a, &b| a * b)
+    }
+}
+
+impl Mul for Isometry2 {
+    type Output = Self;
+    /// Multiplies two isometries, the result will represent the combined transform, applying
+    /// `rhs` first.
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Self {
+            rotation: self.rotation * rhs.rotation,
+            translation: self.rotation * rhs.translation + self.translation,
+        }
+    }
+}
+
+impl Mul<&Self> for Isometry2 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: &Self) -> Self {
+        self.mul(*rhs)
+    }
+}
+
+impl Mul<&Isometry2> for &Isometry2 {
+    type Output = Isometry2;
+    #[inline]
+    fn mul(self, rhs: &Isometry2) -> Isometry2 {
+        (*self).mul(*rhs)
+    }
+}
+
+impl Mul<Isometry2> for &Isometry2 {
+    type Output = Isometry2;
+    #[inline]
+    fn mul(self, rhs: Isometry2) -> Isometry2 {
+        (*self).mul(rhs)
+    }
+}
+
+impl MulAssign for Isometry2 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.mul(rhs);
+    }
+}
+
+impl MulAssign<&Self> for Isometry2 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: &Self) {
+        self.mul_assign(*rhs);
+    }
+}
+
+impl From<[f32; 6]> for Isometry2 {
+    #[inline]
+    fn from(a: [f32; 6]) -> Self {
+        Self::from_array(a)
+    }
+}
+
+impl From<Isometry2> for [f32; 6] {
+    #[inline]
+    fn from(i: Isometry2) -> Self {
+        i.to_array()
+    }
+}
+
+impl From<Isometry2> for Affine2 {
+    #[inline]
+    fn from(i: Isometry2) -> Self {
+        i.to_affine2()
+    }
+}
+
+impl From<Isometry2> for Mat3 {
+    #[inline]
+    fn from(i: Isometry2) -> Self {
+        i.to_mat3()
+    }
+}
@@ -258,6 +258,41 @@ impl Mat3 {
         )
     }
 
+    /// Creates a 3D rotation matrix that rotates `v.length()` radians around `v.normalize()`.
+    ///
+    /// `from_scaled_axis(Vec3::ZERO)` results in the identity matrix.
+    #[inline]
+    #[must_use]
+    pub fn from_scaled_axis(v: Vec3) -> Self {
+        // Rodrigues' rotation formula written in terms of the half angle, which avoids the
+        // cancellation in `1.0 - cos(angle)` for small angles.
+        let angle_squared = v.length_squared();
+        let half_angle = 0.5 * math::sqrt(angle_squared);
+        let (sin, cos) = math::sin_cos(half_angle);
+        // Use the Taylor series of `sin(x) / x` where the division would lose precision.
+        let half_sinc = if angle_squared < f32::EPSILON {
+            1.0 - angle_squared / 24.0
+        } else {
+            sin / half_angle
+        };
+        // `sin(angle) / angle` and `(1.0 - cos(angle)) / angle^2`.
+        let a = half_sinc * cos;
+        let b = 0.5 * half_sinc * half_sinc;
+        let cos = 1.0 - b * angle_squared;
+
+        let (x, y, z) = v.into();
+        let (xsin, ysin, zsin) = v.mul(a).into();
+        let (x2, y2, z2) = v.mul(v).mul(b).into();
+        let xyomc = x * y * b;
+        let xzomc = x * z * b;
+        let yzomc = y * z * b;
+        Self::from_cols(
+            Vec3::new(x2 + cos, xyomc + zsin, xzomc - ysin),
+            Vec3::new(xyomc - zsin, y2 + cos, yzomc + xsin),
+            Vec3::new(xzomc + ysin, yzomc - xsin, z2 + cos),
+        )
+    }
+
     /// Creates a 3D rotation matrix from the given euler rotation sequence and the angles (in
     /// radians).
     #[inline]
@@ -285,6 +320,25 @@ impl Mat3 {
         self.to_euler_angles(order)
     }
 
+    /// Returns the rotation axis scaled by the rotation in radians, with the angle in the range
+    /// `[0, pi]`.
+    ///
+    /// Note if the input matrix contains scales, shears, or other non-rotation transformations then
+    /// the result will be ill-defined.
+    #[inline]
+    #[must_use]
+    pub fn to_scaled_axis(&self) -> Vec3 {
+        let rotation = Quat::from_mat3(self);
+
+        // `q` and `-q` are the same rotation, pick the one with the smaller angle.
+        let rotation = if rotation.w < 0.0 {
+            -rotation
+        } else {
+            rotation
+        };
+        rotation.ln().xyz() * 2.0
+    }
+
     /// Creates a 3D rotation matrix from `angle` (in radians) around the x axis.
     #[inline]
     #[must_use]
@@ -576,6 +630,513 @@ impl Mat3 {
         self.inverse_checked::<true>().0
     }
 
+    /// Solves the linear system `self * x == rhs` for `x`, returning `None` if `self` is not
+    /// invertible.
+    ///
+    /// This uses the [LU decomposition](Self::lu) of `self` which is faster and more accurate
+    /// than multiplying `rhs` by the inverse of `self`.
+    #[must_use]
+    pub fn solve(&self, rhs: Vec3) -> Option<Vec3> {
+        let (lu, perm) = self.lu_packed();
+        let b = rhs.to_array();
+
+        // Forward substitution with the unit lower triangular factor.
+        let mut x = [0.0; 3];
+        for i in 0..3 {
+            let mut sum = b[perm[i]];
+            for j in 0..i {
+                sum -= lu[i][j] * x[j];
+            }
+            x[i] = sum;
+        }
+
+        // Back substitution with the upper triangular factor.
+        for i in (0..3).rev() {
+            let pivot = lu[i][i];
+            if pivot == 0.0 {
+                return None;
+            }
+            let mut sum = x[i];
+            for j in i + 1..3 {
+                sum -= lu[i][j] * x[j];
+            }
+            x[i] = sum / pivot;
+        }
+
+        Some(Vec3::from_array(x))
+    }
+
+    /// Computes the LU decomposition of `self` with partial pivoting.
+    ///
+    /// Returns a tuple `(p, l, u)` where `p` is a permutation matrix, `l` is a lower triangular
+    /// matrix with a unit diagonal and `u` is an upper triangular matrix, such that
+    /// `p * self == l * u`.
+    ///
+    /// The decomposition always exists. If `self` is not invertible `u` has a zero on its
+    /// diagonal.
+    #[must_use]
+    pub fn lu(&self) -> (Self, Self, Self) {
+        let (lu, perm) = self.lu_packed();
+        let mut p = [[0.0; 3]; 3];
+        let mut l = [[0.0; 3]; 3];
+        let mut u = [[0.0; 3]; 3];
+        for (i, row) in lu.iter().enumerate() {
+            p[i][perm[i]] = 1.0;
+            l[i][i] = 1.0;
+            l[i][..i].copy_from_slice(&row[..i]);
+            u[i][i..].copy_from_slice(&row[i..]);
+        }
+        // The factors were built row by row.
+        (
+            Self::from_cols_array_2d(&p).transpose(),
+            Self::from_cols_array_2d(&l).transpose(),
+            Self::from_cols_array_2d(&u).transpose(),
+        )
+    }
+
+    /// Computes the LU decomposition of `self` with partial pivoting, returning the rows of
+    /// both factors packed into one array and the row permutation.
+    #[inline]
+    fn lu_packed(&self) -> ([[f32; 3]; 3], [usize; 3]) {
+        let mut a = self.transpose().to_cols_array_2d();
+        let mut perm = [0, 1, 2];
+        for k in 0..3 {
+            let mut pivot = k;
+            for i in k + 1..3 {
+                if math::abs(a[i][k]) > math::abs(a[pivot][k]) {
+                    pivot = i;
+                }
+            }
+            a.swap(k, pivot);
+            perm.swap(k, pivot);
+
+            let pivot_row = a[k];
+            if pivot_row[k] == 0.0 {
+                continue;
+            }
+            for row in a.iter_mut().skip(k + 1) {
+                let factor = row[k] / pivot_row[k];
+                row[k] = factor;
+                for (x, y) in row.iter_mut().zip(pivot_row).skip(k + 1) {
+                    *x -= factor * y;
+                }
+            }
+        }
+        (a, perm)
+    }
+
+    /// Computes the QR decomposition of `self` using Householder reflections.
+    ///
+    /// Returns a tuple `(q, r)` where `q` is an orthonormal matrix and `r` is an upper
+    /// triangular matrix with a non-negative diagonal, such that `self == q * r`.
+    #[must_use]
+    pub fn qr(&self) -> (Self, Self) {
+        // `qt` accumulates the transpose of `q` by applying the reflections to the identity.
+        let mut r = self.to_cols_array_2d();
+        let mut qt = Self::IDENTITY.to_cols_array_2d();
+        for k in 0..2 {
+            let mut v = [0.0; 3];
+            v[k..].copy_from_slice(&r[k][k..]);
+            let norm = math::sqrt(v.iter().map(|x| x * x).sum());
+            if norm == 0.0 {
+                continue;
+            }
+            v[k] += math::copysign(norm, v[k]);
+            let v_length_squared: f32 = v.iter().map(|x| x * x).sum();
+
+            for col in r.iter_mut().chain(qt.iter_mut()) {
+                let d: f32 = col.iter().zip(v).map(|(x, y)| x * y).sum();
+                let d = 2.0 * d / v_length_squared;
+                for (x, y) in col.iter_mut().zip(v) {
+                    *x -= d * y;
+                }
+            }
+            for x in &mut r[k][k + 1..] {
+                *x = 0.0;
+            }
+        }
+
+        // Negate rows of `r` and the matching columns of `q` to make the diagonal non-negative.
+        for k in 0..3 {
+            if r[k][k] < 0.0 {
+                for col in r.iter_mut().chain(qt.iter_mut()) {
+                    col[k] = -col[k];
+                }
+            }
+        }
+
+        (
+            Self::from_cols_array_2d(&qt).transpose(),
+            Self::from_cols_array_2d(&r),
+        )
+    }
+
+    /// Computes the Cholesky decomposition of `self`, which must be symmetric
+    /// positive-definite.
+    ///
+    /// Returns a lower triangular matrix `l` with a positive diagonal such that
+    /// `self == l * l.transpose()`, or `None` if `self` is not positive-definite. Only the
+    /// lower triangle of `self` is read.
+    #[must_use]
+    pub fn cholesky(&self) -> Option<Self> {
+        let a = self.to_cols_array_2d();
+        let mut l = [[0.0; 3]; 3];
+        for j in 0..3 {
+            let d = a[j][j] - l[..j].iter().map(|col| col[j] * col[j]).sum::<f32>();
+            if d <= 0.0 || d.is_nan() {
+                return None;
+            }
+            let d = math::sqrt(d);
+            l[j][j] = d;
+
+            for i in j + 1..3 {
+                let sum = a[j][i] - l[..j].iter().map(|col| col[i] * col[j]).sum::<f32>();
+                l[j][i] = sum / d;
+            }
+        }
+        Some(Self::from_cols_array_2d(&l))
+    }
+
+    /// Returns the largest absolute element of `self`, or `1.0` if that is zero or not finite.
+    ///
+    /// Decompositions divide `self` by this to avoid overflow and underflow.
+    #[inline]
+    fn decomposition_scale(&self) -> f32 {
+        let max = self
+            .x_axis
+            .abs()
+            .max(self.y_axis.abs())
+            .max(self.z_axis.abs())
+            .max_element();
+        if max > 0.0 && max.is_finite() {
+            max
+        } else {
+            1.0
+        }
+    }
+
+    /// Computes the eigen-decomposition of `self`, which is assumed to be symmetric.
+    ///
+    /// Returns a tuple containing the eigenvalues sorted in descending order and a rotation matrix
+    /// whose columns are the corresponding unit length eigenvectors, such that
+    /// `self == vectors * Self::from_diagonal(values) * vectors.transpose()`.
+    ///
+    /// The cyclic Jacobi method is used, which is robust for repeated and zero eigenvalues. Any
+    /// asymmetry in `self` is removed by averaging the off-diagonal pairs first.
+    #[must_use]
+    pub fn symmetric_eigen(&self) -> (Vec3, Self) {
+        const MAX_SWEEPS: usize = 32;
+
+        let scale = self.decomposition_scale();
+        let mut a = self.mul_scalar(scale.recip()).to_cols_array_2d();
+        let mut v = [Vec3::X, Vec3::Y, Vec3::Z];
+        for (p, q) in [(0, 1), (0, 2), (1, 2)] {
+            let apq = (a[p][q] + a[q][p]) * 0.5;
+            a[p][q] = apq;
+            a[q][p] = apq;
+        }
+
+        for _ in 0..MAX_SWEEPS {
+            let off = a[0][1] * a[0][1] + a[0][2] * a[0][2] + a[1][2] * a[1][2];
+            let diag = a[0][0] * a[0][0] + a[1][1] * a[1][1] + a[2][2] * a[2][2];
+            if off <= diag * f32::EPSILON * f32::EPSILON {
+                break;
+            }
+
+            for (p, q, r) in [(0, 1, 2), (0, 2, 1), (1, 2, 0)] {
+                let apq = a[p][q];
+                if apq == 0.0 {
+                    continue;
+                }
+
+                // Jacobi rotation zeroing a[p][q], choosing the smaller rotation angle.
+                let theta = (a[q][q] - a[p][p]) / (2.0 * apq);
+                let t = math::copysign(1.0, theta)
+                    / (math::abs(theta) + math::sqrt(theta * theta + 1.0));
+                let c = 1.0 / math::sqrt(t * t + 1.0);
+                let s = t * c;
+
+                a[p][p] -= t * apq;
+                a[q][q] += t * apq;
+                a[p][q] = 0.0;
+                a[q][p] = 0.0;
+
+                let arp = a[r][p];
+                let arq = a[r][q];
+                a[r][p] = c * arp - s * arq;
+                a[p][r] = a[r][p];
+                a[r][q] = s * arp + c * arq;
+                a[q][r] = a[r][q];
+
+                let vp = v[p];
+                let vq = v[q];
+                v[p] = vp * c - vq * s;
+                v[q] = vp * s + vq * c;
+            }
+        }
+
+        // Stable sort of the eigenvalues in descending order.
+        let mut order = [0, 1, 2];
+        if a[order[1]][order[1]] > a[order[0]][order[0]] {
+            order.swap(0, 1);
+        }
+        if a[order[2]][order[2]] > a[order[1]][order[1]] {
+            order.swap(1, 2);
+        }
+        if a[order[1]][order[1]] > a[order[0]][order[0]] {
+            order.swap(0, 1);
+        }
+        let [i, j, k] = order;
+
+        let x_axis = v[i];
+        let y_axis = v[j];
+        let mut z_axis = v[k];
+        if x_axis.cross(y_axis).dot(z_axis) < 0.0 {
+            z_axis = -z_axis;
+        }
+
+        (
+            Vec3::new(a[i][i], a[j][j], a[k][k]) * scale,
+            Self::from_cols(x_axis, y_axis, z_axis),
+        )
+    }
+
+    /// Computes the eigen-decomposition of `self`, which is assumed to be symmetric, returning
+    /// the eigenvectors as a rotation quaternion.
+    ///
+    /// See [`Self::symmetric_eigen()`] for details.
+    #[must_use]
+    pub fn symmetric_eigen_quat(&self) -> (Vec3, Quat) {
+        let (values, vectors) = self.symmetric_eigen();
+
+        (values, Quat::from_mat3(&vectors))
+    }
+
+    /// Computes the singular value decomposition of `self`.
+    ///
+    /// Returns a tuple `(u, sigma, v)` where `u` and `v` are orthonormal matrices and `sigma`
+    /// contains the singular values sorted in descending order, such that
+    /// `self == u * Self::from_diagonal(sigma) * v.transpose()`.
+    ///
+    /// The singular values are never negative. `v` is always a rotation, and `u` is a rotation
+    /// unless the determinant of `self` is negative, in which case it also contains a reflection.
+    ///
+    /// The one-sided Jacobi method is used, which rotates the columns of `self` until they are
+    /// orthogonal and computes small singular values to high relative accuracy. Rank deficient
+    /// matrices are handled by completing `u` with orthonormal vectors.
+    #[must_use]
+    pub fn svd(&self) -> (Self, Vec3, Self) {
+        const MAX_SWEEPS: usize = 32;
+
+        let scale = self.decomposition_scale();
+        let m = self.mul_scalar(scale.recip());
+        let mut b = [m.x_axis, m.y_axis, m.z_axis];
+        let mut v = [Vec3::X, Vec3::Y, Vec3::Z];
+
+        for _ in 0..MAX_SWEEPS {
+            let mut rotated = false;
+            for (p, q) in [(0, 1), (0, 2), (1, 2)] {
+                let alpha = b[p].length_squared();
+                let beta = b[q].length_squared();
+                let gamma = b[p].dot(b[q]);
+                if math::abs(gamma) <= f32::EPSILON * math::sqrt(alpha) * math::sqrt(beta) {
+                    continue;
+                }
+                rotated = true;
+
+                // Jacobi rotation making columns p and q orthogonal.
+                let zeta = (beta - alpha) / (2.0 * gamma);
+                let t =
+                    math::copysign(1.0, zeta) / (math::abs(zeta) + math::sqrt(zeta * zeta + 1.0));
+                let c = 1.0 / math::sqrt(t * t + 1.0);
+                let s = t * c;
+
+                let bp = b[p];
+                let bq = b[q];
+                b[p] = bp * c - bq * s;
+                b[q] = bp * s + bq * c;
+
+                let vp = v[p];
+                let vq = v[q];
+                v[p] = vp * c - vq * s;
+                v[q] = vp * s + vq * c;
+            }
+            if !rotated {
+                break;
+            }
+        }
+
+        // Stable sort of the columns by length in descending order.
+        let sigma = [b[0].length(), b[1].length(), b[2].length()];
+        let mut order = [0, 1, 2];
+        if sigma[order[1]] > sigma[order[0]] {
+            order.swap(0, 1);
+        }
+        if sigma[order[2]] > sigma[order[1]] {
+            order.swap(1, 2);
+        }
+        if sigma[order[1]] > sigma[order[0]] {
+            order.swap(0, 1);
+        }
+        let [i, j, k] = order;
+
+        let mut b_z = b[k];
+        let mut v_z = v[k];
+        if v[i].cross(v[j]).dot(v_z) < 0.0 {
+            b_z = -b_z;
+            v_z = -v_z;
+        }
+
+        let u_x = if sigma[i] > 0.0 {
+            b[i] / sigma[i]
+        } else {
+            Vec3::X
+        };
+        let u_y = if sigma[j] > 0.0 {
+            b[j] / sigma[j]
+        } else {
+            u_x.any_orthonormal_vector()
+        };
+        let mut u_z = u_x.cross(u_y);
+        if u_z.dot(b_z) < 0.0 {
+            u_z = -u_z;
+        }
+
+        (
+            Self::from_cols(u_x, u_y, u_z),
+            Vec3::new(sigma[i], sigma[j], sigma[k]) * scale,
+            Self::from_cols(v[i], v[j], v_z),
+        )
+    }
+
+    /// Computes the polar decomposition of `self`.
+    ///
+    /// Returns a tuple `(rotation, stretch)` where `rotation` is the closest rotation matrix to
+    /// `self` and `stretch` is a symmetric matrix, such that `self == rotation * stretch`.
+    ///
+    /// `stretch` is positive semi-definite unless the determinant of `self` is negative, in which
+    /// case the reflection is moved into `stretch` along the axis of its smallest singular value.
+    ///
+    /// The decomposition is computed from the [singular value decomposition](Self::svd).
+    #[must_use]
+    pub fn polar_decompose(&self) -> (Self, Self) {
+        let (mut u, mut sigma, v) = self.svd();
+        if u.determinant() < 0.0 {
+            u.z_axis = -u.z_axis;
+            sigma.z = -sigma.z;
+        }
+        let v_t = v.transpose();
+        (u.mul(v_t), v.mul(Self::from_diagonal(sigma)).mul(v_t))
+    }
+
+    /// Creates a 3x3 matrix from the `rotation` and `stretch` returned by
+    /// [`Self::polar_decompose()`].
+    ///
+    /// Equivalent to `rotation * stretch`.
+    #[inline]
+    #[must_use]
+    pub fn from_polar_decomposition(rotation: Self, stretch: Self) -> Self {
+        rotation.mul(stretch)
+    }
+
+    /// Creates a 3x3 matrix from the given `scale`, `shear` and `rotation`.
+    ///
+    /// The `shear` vector contains the `xy`, `xz` and `yz` shear factors, where for example `xy`
+    /// is the amount the y axis is sheared along the x axis. The scale is applied first, then
+    /// the shear and then the rotation.
+    ///
+    /// This is the inverse of [`Self::to_scale_shear_rotation()`].
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `rotation` is not normalized when `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn from_scale_shear_rotation(scale: Vec3, shear: Vec3, rotation: Quat) -> Self {
+        let rotation = Self::from_quat(rotation);
+        Self::from_cols(
+            rotation.x_axis * scale.x,
+            (rotation.x_axis * shear.x + rotation.y_axis) * scale.y,
+            (rotation.x_axis * shear.y + rotation.y_axis * shear.z + rotation.z_axis) * scale.z,
+        )
+    }
+
+    /// Extracts `scale`, `shear` and `rotation` from `self`.
+    ///
+    /// Unlike [`Self::to_scale_rotation_translation()`](crate::Mat4::to_scale_rotation_translation)
+    /// this handles matrices containing shear. The columns of `self` are orthogonalized in
+    /// order, so the x axis is never sheared. A negative determinant is represented by a
+    /// negative `scale.x`. See [`Self::from_scale_shear_rotation()`] for the meaning of `shear`.
+    ///
+    /// Returns `None` if `self` is not invertible.
+    #[must_use]
+    pub fn to_scale_shear_rotation(&self) -> Option<(Vec3, Vec3, Quat)> {
+        let det = self.determinant();
+        if det == 0.0 || !det.is_finite() {
+            return None;
+        }
+
+        let mut scale_x = self.x_axis.length();
+        let mut x_axis = self.x_axis / scale_x;
+
+        let mut shear_xy = x_axis.dot(self.y_axis);
+        let y_axis = self.y_axis - x_axis * shear_xy;
+        let scale_y = y_axis.length();
+        let y_axis = y_axis / scale_y;
+
+        let mut shear_xz = x_axis.dot(self.z_axis);
+        let z_axis = self.z_axis - x_axis * shear_xz;
+        let shear_yz = y_axis.dot(z_axis);
+        let z_axis = z_axis - y_axis * shear_yz;
+        let scale_z = z_axis.length();
+        let z_axis = z_axis / scale_z;
+
+        if det < 0.0 {
+            scale_x = -scale_x;
+            x_axis = -x_axis;
+            shear_xy = -shear_xy;
+            shear_xz = -shear_xz;
+        }
+
+        let rotation = Quat::from_mat3(&Self::from_cols(x_axis, y_axis, z_axis));
+
+        Some((
+            Vec3::new(scale_x, scale_y, scale_z),
+            Vec3::new(shear_xy / scale_y, shear_xz / scale_z, shear_yz / scale_z),
+            rotation,
+        ))
+    }
+
+    /// Returns the matrix exponential of `self`, which is assumed to be skew-symmetric.
+    ///
+    /// The exponential of the cross product matrix of a vector `v` is the rotation of
+    /// `v.length()` radians around `v`, see [`Self::from_scaled_axis()`]. Only the skew-symmetric
+    /// part of `self` is read. `exp` is the inverse of [`Self::ln()`].
+    #[inline]
+    #[must_use]
+    pub fn exp(&self) -> Self {
+        Self::from_scaled_axis(Vec3::new(
+            0.5 * (self.y_axis.z - self.z_axis.y),
+            0.5 * (self.z_axis.x - self.x_axis.z),
+            0.5 * (self.x_axis.y - self.y_axis.x),
+        ))
+    }
+
+    /// Returns the matrix logarithm of `self`, which must be a pure rotation.
+    ///
+    /// The result is the skew-symmetric cross product matrix of the rotation vector returned by
+    /// [`Self::to_scaled_axis()`]. `ln` is the inverse of [`Self::exp()`].
+    #[inline]
+    #[must_use]
+    pub fn ln(&self) -> Self {
+        let v = self.to_scaled_axis();
+        Self::from_cols(
+            Vec3::new(0.0, v.z, -v.y),
+            Vec3::new(-v.z, 0.0, v.x),
+            Vec3::new(v.y, -v.x, 0.0),
+        )
+    }
+
     /// Transforms the given 2D vector as a point.
     ///
     /// This is the equivalent of multiplying `rhs` as a 3D vector where `z` is `1`.
@@ -706,6 +1267,45 @@ impl Mat3 {
         self.mul(rhs)
     }
 
+    /// Returns the transpose of `self` in a `const` context.
+    #[inline]
+    #[must_use]
+    pub const fn const_transpose(&self) -> Self {
+        Self::from_cols(
+            Vec3::new(self.x_axis.x, self.y_axis.x, self.z_axis.x),
+            Vec3::new(self.x_axis.y, self.y_axis.y, self.z_axis.y),
+            Vec3::new(self.x_axis.z, self.y_axis.z, self.z_axis.z),
+        )
+    }
+
+    /// Transforms a 3D vector in a `const` context.
+    ///
+    /// This gives the same result as [`Self::mul_vec3()`].
+    #[cfg(feature = "const-float-arithmetic")]
+    #[inline]
+    #[must_use]
+    pub const fn const_mul_vec3(&self, rhs: Vec3) -> Vec3 {
+        let mut res = self.x_axis.const_mul(Vec3::splat(rhs.x));
+
+        res = res.const_add(self.y_axis.const_mul(Vec3::splat(rhs.y)));
+        res = res.const_add(self.z_axis.const_mul(Vec3::splat(rhs.z)));
+        res
+    }
+
+    /// Multiplies two 3x3 matrices in a `const` context.
+    ///
+    /// This gives the same result as [`Self::mul_mat3()`].
+    #[cfg(feature = "const-float-arithmetic")]
+    #[inline]
+    #[must_use]
+    pub const fn const_mul_mat3(&self, rhs: &Self) -> Self {
+        Self::from_cols(
+            self.const_mul_vec3(rhs.x_axis),
+            self.const_mul_vec3(rhs.y_axis),
+            self.const_mul_vec3(rhs.z_axis),
+        )
+    }
+
     /// Adds two 3x3 matrices.
     #[inline]
     #[must_use]
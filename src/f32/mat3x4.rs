@@ -0,0 +1,787 @@
+// Generated from mat_nonsquare.rs.tera template. Edit the template, not the generated file.
+
+use crate::{Affine3A, Mat3, Mat4, Mat4x3, Vec3, Vec4};
+use core::fmt;
+use core::iter::Sum;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+#[cfg(all(
+    feature = "zerocopy",
+    any(not(feature = "core-simd"), feature = "scalar-math")
+))]
+use zerocopy_derive::*;
+
+/// Creates a 3x4 matrix from three column vectors.
+#[inline(always)]
+#[must_use]
+pub const fn mat3x4(x_axis: Vec4, y_axis: Vec4, z_axis: Vec4) -> Mat3x4 {
+    Mat3x4::from_cols(x_axis, y_axis, z_axis)
+}
+
+/// A column major matrix with 3 columns and 4 rows.
+///
+/// Non-square matrices follow the WGSL `mat3x4<f32>` naming, where the first number is the
+/// number of columns and the second the number of rows, so this type holds three
+/// [`Vec4`] columns. Multiplying by a [`Vec3`] returns a [`Vec4`].
+///
+/// Multiplication follows the usual shape rules: a [`Mat4`] can be multiplied by a
+/// [`Mat3x4`] on the left, a [`Mat3`] on the right and [`Mat3x4`] times
+/// [`Mat4x3`] produces a [`Mat4`]. [`Self::transpose()`] returns a
+/// [`Mat4x3`].
+///
+/// Converting from an [`Affine3A`] stores the rows of the affine transform, which is the
+/// packed row major layout commonly used for transforms in GPU buffers. Multiplying a homogeneous
+/// point by the matrix on the right in a shader, e.g. `vec4(p, 1.0) * m`, applies the
+/// transform.
+#[derive(Clone, Copy)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[cfg_attr(
+    all(
+        feature = "zerocopy",
+        any(not(feature = "core-simd"), feature = "scalar-math")
+    ),
+    derive(FromBytes, Immutable, IntoBytes, KnownLayout)
+)]
+#[repr(C)]
+pub struct Mat3x4 {
+    pub x_axis: Vec4,
+    pub y_axis: Vec4,
+    pub z_axis: Vec4,
+}
+
+impl Mat3x4 {
+    /// A 3x4 matrix with all elements set to `0.0`.
+    pub const ZERO: Self = Self::from_cols(Vec4::ZERO, Vec4::ZERO, Vec4::ZERO);
+
+    /// All NAN:s.
+    pub const NAN: Self = Self::from_cols(Vec4::NAN, Vec4::NAN, Vec4::NAN);
+
+    /// Creates a 3x4 matrix from three column vectors.
+    #[inline(always)]
+    #[must_use]
+    pub const fn from_cols(x_axis: Vec4, y_axis: Vec4, z_axis: Vec4) -> Self {
+        Self {
+            x_axis,
+            y_axis,
+            z_axis,
+        }
+    }
+
+    /// Creates a 3x4 matrix from a `[f32; 12]` array stored in column major order.
+    #[inline]
+    #[must_use]
+    pub const fn from_cols_array(m: &[f32; 12]) -> Self {
+        Self::from_cols(
+            Vec4::new(m[0], m[1], m[2], m[3]),
+            Vec4::new(m[4], m[5], m[6], m[7]),
+            Vec4::new(m[8], m[9], m[10], m[11]),
+        )
+    }
+
+    /// Creates a `[f32; 12]` array storing data in column major order.
+    #[inline]
+    #[must_use]
+    pub const fn to_cols_array(&self) -> [f32; 12] {
+        let [x_axis_x, x_axis_y, x_axis_z, x_axis_w] = self.x_axis.to_array();
+        let [y_axis_x, y_axis_y, y_axis_z, y_axis_w] = self.y_axis.to_array();
+        let [z_axis_x, z_axis_y, z_axis_z, z_axis_w] = self.z_axis.to_array();
+
+        [
+            x_axis_x, x_axis_y, x_axis_z, x_axis_w, y_axis_x, y_axis_y, y_axis_z, y_axis_w,
+            z_axis_x, z_axis_y, z_axis_z, z_axis_w,
+        ]
+    }
+
+    /// Creates a 3x4 matrix from a `[[f32; 4]; 3]` array stored in column
+    /// major order.
+    #[inline]
+    #[must_use]
+    pub const fn from_cols_array_2d(m: &[[f32; 4]; 3]) -> Self {
+        Self::from_cols(
+            Vec4::from_array(m[0]),
+            Vec4::from_array(m[1]),
+            Vec4::from_array(m[2]),
+        )
+    }
+
+    /// Creates a `[[f32; 4]; 3]` array storing data in column major order.
+    #[inline]
+    #[must_use]
+    pub const fn to_cols_array_2d(&self) -> [[f32; 4]; 3] {
+        [
+            self.x_axis.to_array(),
+            self.y_axis.to_array(),
+            self.z_axis.to_array(),
+        ]
+    }
+
+    /// Creates a 3x4 matrix from the first 12 values in `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than 12 elements long.
+    #[inline]
+    #[must_use]
+    pub const fn from_cols_slice(slice: &[f32]) -> Self {
+        Self::from_cols(
+            Vec4::new(slice[0], slice[1], slice[2], slice[3]),
+            Vec4::new(slice[4], slice[5], slice[6], slice[7]),
+            Vec4::new(slice[8], slice[9], slice[10], slice[11]),
+        )
+    }
+
+    /// Writes the columns of `self` to the first 12 elements in `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than 12 elements long.
+    #[inline]
+    pub fn write_cols_to_slice(&self, slice: &mut [f32]) {
+        slice[..12].copy_from_slice(&self.to_cols_array());
+    }
+
+    /// Returns the matrix column for the given `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than 2.
+    #[inline]
+    #[must_use]
+    pub fn col(&self, index: usize) -> Vec4 {
+        match index {
+            0 => self.x_axis,
+            1 => self.y_axis,
+            2 => self.z_axis,
+            _ => panic!("index out of bounds"),
+        }
+    }
+
+    /// Returns a mutable reference to the matrix column for the given `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than 2.
+    #[inline]
+    pub fn col_mut(&mut self, index: usize) -> &mut Vec4 {
+        match index {
+            0 => &mut self.x_axis,
+            1 => &mut self.y_axis,
+            2 => &mut self.z_axis,
+            _ => panic!("index out of bounds"),
+        }
+    }
+
+    /// Returns the matrix row for the given `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than 3.
+    #[inline]
+    #[must_use]
+    pub fn row(&self, index: usize) -> Vec3 {
+        match index {
+            0 => Vec3::new(self.x_axis.x, self.y_axis.x, self.z_axis.x),
+            1 => Vec3::new(self.x_axis.y, self.y_axis.y, self.z_axis.y),
+            2 => Vec3::new(self.x_axis.z, self.y_axis.z, self.z_axis.z),
+            3 => Vec3::new(self.x_axis.w, self.y_axis.w, self.z_axis.w),
+            _ => panic!("index out of bounds"),
+        }
+    }
+
+    /// Returns `true` if, and only if, all elements are finite.
+    /// If any element is either `NaN`, positive or negative infinity, this will return `false`.
+    #[inline]
+    #[must_use]
+    pub fn is_finite(&self) -> bool {
+        self.x_axis.is_finite() && self.y_axis.is_finite() && self.z_axis.is_finite()
+    }
+
+    /// Returns `true` if any elements are `NaN`.
+    #[inline]
+    #[must_use]
+    pub fn is_nan(&self) -> bool {
+        self.x_axis.is_nan() || self.y_axis.is_nan() || self.z_axis.is_nan()
+    }
+
+    /// Returns the transpose of `self`, a 4x3 matrix.
+    #[inline]
+    #[must_use]
+    pub fn transpose(&self) -> Mat4x3 {
+        Mat4x3::from_cols(self.row(0), self.row(1), self.row(2), self.row(3))
+    }
+
+    /// Transforms a 3D vector, returning a 4D vector.
+    #[inline]
+    #[must_use]
+    pub fn mul_vec3(&self, rhs: Vec3) -> Vec4 {
+        let mut res = self.x_axis.mul(rhs.x);
+        res = res.add(self.y_axis.mul(rhs.y));
+        res = res.add(self.z_axis.mul(rhs.z));
+        res
+    }
+
+    /// Transforms a 4D vector by the transpose of `self`, returning a 3D vector.
+    #[inline]
+    #[must_use]
+    pub fn mul_transpose_vec4(&self, rhs: Vec4) -> Vec3 {
+        Vec3::new(
+            self.x_axis.dot(rhs),
+            self.y_axis.dot(rhs),
+            self.z_axis.dot(rhs),
+        )
+    }
+
+    /// Multiplies `self` by a 3x3 matrix, returning a 3x4 matrix.
+    #[inline]
+    #[must_use]
+    pub fn mul_mat3(&self, rhs: &Mat3) -> Self {
+        Self::from_cols(
+            self.mul_vec3(rhs.x_axis),
+            self.mul_vec3(rhs.y_axis),
+            self.mul_vec3(rhs.z_axis),
+        )
+    }
+
+    /// Multiplies `self` by a 4x3 matrix, returning a 4x4 matrix.
+    #[inline]
+    #[must_use]
+    pub fn mul_mat4x3(&self, rhs: &Mat4x3) -> Mat4 {
+        Mat4::from_cols(
+            self.mul_vec3(rhs.x_axis),
+            self.mul_vec3(rhs.y_axis),
+            self.mul_vec3(rhs.z_axis),
+            self.mul_vec3(rhs.w_axis),
+        )
+    }
+
+    /// Adds two 3x4 matrices.
+    #[inline]
+    #[must_use]
+    pub fn add_mat3x4(&self, rhs: &Self) -> Self {
+        Self::from_cols(
+            self.x_axis.add(rhs.x_axis),
+            self.y_axis.add(rhs.y_axis),
+            self.z_axis.add(rhs.z_axis),
+        )
+    }
+
+    /// Subtracts two 3x4 matrices.
+    #[inline]
+    #[must_use]
+    pub fn sub_mat3x4(&self, rhs: &Self) -> Self {
+        Self::from_cols(
+            self.x_axis.sub(rhs.x_axis),
+            self.y_axis.sub(rhs.y_axis),
+            self.z_axis.sub(rhs.z_axis),
+        )
+    }
+
+    /// Multiplies a 3x4 matrix by a scalar.
+    #[inline]
+    #[must_use]
+    pub fn mul_scalar(&self, rhs: f32) -> Self {
+        Self::from_cols(
+            self.x_axis.mul(rhs),
+            self.y_axis.mul(rhs),
+            self.z_axis.mul(rhs),
+        )
+    }
+
+    /// Divides a 3x4 matrix by a scalar.
+    #[inline]
+    #[must_use]
+    pub fn div_scalar(&self, rhs: f32) -> Self {
+        let rhs = Vec4::splat(rhs);
+        Self::from_cols(
+            self.x_axis.div(rhs),
+            self.y_axis.div(rhs),
+            self.z_axis.div(rhs),
+        )
+    }
+
+    /// Returns true if the absolute difference of all elements between `self` and `rhs`
+    /// is less than or equal to `max_abs_diff`.
+    ///
+    /// This can be used to compare if two matrices contain similar elements. It works best
+    /// when comparing with a known value. The `max_abs_diff` that should be used used
+    /// depends on the values being compared against.
+    ///
+    /// For more see
+    /// [comparing floating point numbers](https://randomascii.wordpress.com/2012/02/25/comparing-floating-point-numbers-2012-edition/).
+    #[inline]
+    #[must_use]
+    pub fn abs_diff_eq(&self, rhs: Self, max_abs_diff: f32) -> bool {
+        self.x_axis.abs_diff_eq(rhs.x_axis, max_abs_diff)
+            && self.y_axis.abs_diff_eq(rhs.y_axis, max_abs_diff)
+            && self.z_axis.abs_diff_eq(rhs.z_axis, max_abs_diff)
+    }
+
+    /// Takes the absolute value of each element in `self`
+    #[inline]
+    #[must_use]
+    pub fn abs(&self) -> Self {
+        Self::from_cols(self.x_axis.abs(), self.y_axis.abs(), self.z_axis.abs())
+    }
+}
+
+impl Default for Mat3x4 {
+    #[inline]
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl Add for Mat3x4 {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        self.add_mat3x4(&rhs)
+    }
+}
+
+impl Add<&Self> for Mat3x4 {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: &Self) -> Self {
+        self.add(*rhs)
+    }
+}
+
+impl Add<&Mat3x4> for &Mat3x4 {
+    type Output = Mat3x4;
+    #[inline]
+    fn add(self, rhs: &Mat3x4) -> Mat3x4 {
+        (*self).add(*rhs)
+    }
+}
+
+impl Add<Mat3x4> for &Mat3x4 {
+    type Output = Mat3x4;
+    #[inline]
+    fn add(self, rhs: Mat3x4) -> Mat3x4 {
+        (*self).add(rhs)
+    }
+}
+
+impl AddAssign for Mat3x4 {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = self.add(rhs);
+    }
+}
+
+impl AddAssign<&Self> for Mat3x4 {
+    #[inline]
+    fn add_assign(&mut self, rhs: &Self) {
+        self.add_assign(*rhs);
+    }
+}
+
+impl Sub for Mat3x4 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        self.sub_mat3x4(&rhs)
+    }
+}
+
+impl Sub<&Self> for Mat3x4 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: &Self) -> Self {
+        self.sub(*rhs)
+    }
+}
+
+impl Sub<&Mat3x4> for &Mat3x4 {
+    type Output = Mat3x4;
+    #[inline]
+    fn sub(self, rhs: &Mat3x4) -> Mat3x4 {
+        (*self).sub(*rhs)
+    }
+}
+
+impl Sub<Mat3x4> for &Mat3x4 {
+    type Output = Mat3x4;
+    #[inline]
+    fn sub(self, rhs: Mat3x4) -> Mat3x4 {
+        (*self).sub(rhs)
+    }
+}
+
+impl SubAssign for Mat3x4 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = self.sub(rhs);
+    }
+}
+
+impl SubAssign<&Self> for Mat3x4 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: &Self) {
+        self.sub_assign(*rhs);
+    }
+}
+
+impl Neg for Mat3x4 {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self::from_cols(self.x_axis.neg(), self.y_axis.neg(), self.z_axis.neg())
+    }
+}
+
+impl Neg for &Mat3x4 {
+    type Output = Mat3x4;
+    #[inline]
+    fn neg(self) -> Mat3x4 {
+        (*self).neg()
+    }
+}
+
+impl Mul<Mat3> for Mat3x4 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Mat3) -> Self {
+        self.mul_mat3(&rhs)
+    }
+}
+
+impl Mul<&Mat3> for Mat3x4 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: &Mat3) -> Self {
+        self.mul(*rhs)
+    }
+}
+
+impl Mul<&Mat3> for &Mat3x4 {
+    type Output = Mat3x4;
+    #[inline]
+    fn mul(self, rhs: &Mat3) -> Mat3x4 {
+        (*self).mul(*rhs)
+    }
+}
+
+impl Mul<Mat3> for &Mat3x4 {
+    type Output = Mat3x4;
+    #[inline]
+    fn mul(self, rhs: Mat3) -> Mat3x4 {
+        (*self).mul(rhs)
+    }
+}
+
+impl MulAssign<Mat3> for Mat3x4 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Mat3) {
+        *self = self.mul(rhs);
+    }
+}
+
+impl MulAssign<&Mat3> for Mat3x4 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: &Mat3) {
+        self.mul_assign(*rhs);
+    }
+}
+
+impl Mul<Mat4x3> for Mat3x4 {
+    type Output = Mat4;
+    #[inline]
+    fn mul(self, rhs: Mat4x3) -> Self::Output {
+        self.mul_mat4x3(&rhs)
+    }
+}
+
+impl Mul<&Mat4x3> for Mat3x4 {
+    type Output = Mat4;
+    #[inline]
+    fn mul(self, rhs: &Mat4x3) -> Mat4 {
+        self.mul(*rhs)
+    }
+}
+
+impl Mul<&Mat4x3> for &Mat3x4 {
+    type Output = Mat4;
+    #[inline]
+    fn mul(self, rhs: &Mat4x3) -> Mat4 {
+        (*self).mul(*rhs)
+    }
+}
+
+impl Mul<Mat4x3> for &Mat3x4 {
+    type Output = Mat4;
+    #[inline]
+    fn mul(self, rhs: Mat4x3) -> Mat4 {
+        (*self).mul(rhs)
+    }
+}
+
+impl Mul<Mat3x4> for Mat4 {
+    type Output = Mat3x4;
+    #[inline]
+    fn mul(self, rhs: Mat3x4) -> Self::Output {
+        Mat3x4::from_cols(
+            self.mul_vec4(rhs.x_axis),
+            self.mul_vec4(rhs.y_axis),
+            self.mul_vec4(rhs.z_axis),
+        )
+    }
+}
+
+impl Mul<&Mat3x4> for Mat4 {
+    type Output = Mat3x4;
+    #[inline]
+    fn mul(self, rhs: &Mat3x4) -> Mat3x4 {
+        self.mul(*rhs)
+    }
+}
+
+impl Mul<&Mat3x4> for &Mat4 {
+    type Output = Mat3x4;
+    #[inline]
+    fn mul(self, rhs: &Mat3x4) -> Mat3x4 {
+        (*self).mul(*rhs)
+    }
+}
+
+impl Mul<Mat3x4> for &Mat4 {
+    type Output = Mat3x4;
+    #[inline]
+    fn mul(self, rhs: Mat3x4) -> Mat3x4 {
+        (*self).mul(rhs)
+    }
+}
+
+impl Mul<Vec3> for Mat3x4 {
+    type Output = Vec4;
+    #[inline]
+    fn mul(self, rhs: Vec3) -> Self::Output {
+        self.mul_vec3(rhs)
+    }
+}
+
+impl Mul<&Vec3> for Mat3x4 {
+    type Output = Vec4;
+    #[inline]
+    fn mul(self, rhs: &Vec3) -> Vec4 {
+        self.mul(*rhs)
+    }
+}
+
+impl Mul<&Vec3> for &Mat3x4 {
+    type Output = Vec4;
+    #[inline]
+    fn mul(self, rhs: &Vec3) -> Vec4 {
+        (*self).mul(*rhs)
+    }
+}
+
+impl Mul<Vec3> for &Mat3x4 {
+    type Output = Vec4;
+    #[inline]
+    fn mul(self, rhs: Vec3) -> Vec4 {
+        (*self).mul(rhs)
+    }
+}
+
+impl Mul<Mat3x4> for f32 {
+    type Output = Mat3x4;
+    #[inline]
+    fn mul(self, rhs: Mat3x4) -> Self::Output {
+        rhs.mul_scalar(self)
+    }
+}
+
+impl Mul<&Mat3x4> for f32 {
+    type Output = Mat3x4;
+    #[inline]
+    fn mul(self, rhs: &Mat3x4) -> Mat3x4 {
+        self.mul(*rhs)
+    }
+}
+
+impl Mul<&Mat3x4> for &f32 {
+    type Output = Mat3x4;
+    #[inline]
+    fn mul(self, rhs: &Mat3x4) -> Mat3x4 {
+        (*self).mul(*rhs)
+    }
+}
+
+impl Mul<Mat3x4> for &f32 {
+    type Output = Mat3x4;
+    #[inline]
+    fn mul(self, rhs: Mat3x4) -> Mat3x4 {
+        (*self).mul(rhs)
+    }
+}
+
+impl Mul<f32> for Mat3x4 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: f32) -> Self {
+        self.mul_scalar(rhs)
+    }
+}
+
+impl Mul<&f32> for Mat3x4 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: &f32) -> Self {
+        self.mul(*rhs)
+    }
+}
+
+impl Mul<&f32> for &Mat3x4 {
+    type Output = Mat3x4;
+    #[inline]
+    fn mul(self, rhs: &f32) -> Mat3x4 {
+        (*self).mul(*rhs)
+    }
+}
+
+impl Mul<f32> for &Mat3x4 {
+    type Output = Mat3x4;
+    #[inline]
+    fn mul(self, rhs: f32) -> Mat3x4 {
+        (*self).mul(rhs)
+    }
+}
+
+impl MulAssign<f32> for Mat3x4 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: f32) {
+        *self = self.mul(rhs);
+    }
+}
+
+impl MulAssign<&f32> for Mat3x4 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: &f32) {
+        self.mul_assign(*rhs);
+    }
+}
+
+impl Div<f32> for Mat3x4 {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: f32) -> Self {
+        self.div_scalar(rhs)
+    }
+}
+
+impl Div<&f32> for Mat3x4 {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: &f32) -> Self {
+        self.div(*rhs)
+    }
+}
+
+impl Div<&f32> for &Mat3x4 {
+    type Output = Mat3x4;
+    #[inline]
+    fn div(self, rhs: &f32) -> Mat3x4 {
+        (*self).div(*rhs)
+    }
+}
+
+impl Div<f32> for &Mat3x4 {
+    type Output = Mat3x4;
+    #[inline]
+    fn div(self, rhs: f32) -> Mat3x4 {
+        (*self).div(rhs)
+    }
+}
+
+impl DivAssign<f32> for Mat3x4 {
+    #[inline]
+    fn div_assign(&mut self, rhs: f32) {
+        *self = self.div(rhs);
+    }
+}
+
+impl DivAssign<&f32> for Mat3x4 {
+    #[inline]
+    fn div_assign(&mut self, rhs: &f32) {
+        self.div_assign(*rhs);
+    }
+}
+
+impl From<Affine3A> for Mat3x4 {
+    #[inline]
+    fn from(m: Affine3A) -> Self {
+        Mat4x3::from(m).transpose()
+    }
+}
+
+impl From<Mat3x4> for Affine3A {
+    #[inline]
+    fn from(m: Mat3x4) -> Self {
+        Self::from(m.transpose())
+    }
+}
+
+impl Sum<Self> for Mat3x4 {
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        iter.fold(Self::ZERO, Self::add)
+    }
+}
+
+impl<'a> Sum<&'a Self> for Mat3x4 {
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.fold(Self::ZERO, |a, &b| Self::add(a, b))
+    }
+}
+
+impl PartialEq for Mat3x4 {
+    #[inline]
+    fn eq(&self, rhs: &Self) -> bool {
+        self.x_axis.eq(&rhs.x_axis) && self.y_axis.eq(&rhs.y_axis) && self.z_axis.eq(&rhs.z_axis)
+    }
+}
+
+impl AsRef<[f32; 12]> for Mat3x4 {
+    #[inline]
+    fn as_ref(&self) -> &[f32; 12] {
+        unsafe { &*(self as *const Self as *const [f32; 12]) }
+    }
+}
+
+impl AsMut<[f32; 12]> for Mat3x4 {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [f32; 12] {
+        unsafe { &mut *(self as *mut Self as *mut [f32; 12]) }
+    }
+}
+
+impl fmt::Debug for Mat3x4 {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct(stringify!(Mat3x4))
+            .field("x_axis", &self.x_axis)
+            .field("y_axis", &self.y_axis)
+            .field("z_axis", &self.z_axis)
+            .finish()
+    }
+}
+
+impl fmt::Display for Mat3x4 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(p) = f.precision() {
+            write!(
+                f,
+                "[{:.*}, {:.*}, {:.*}]",
+                p, self.x_axis, p, self.y_axis, p, self.z_axis,
+            )
+        } else {
+            write!(f, "[{}, {}, {}]", self.x_axis, self.y_axis, self.z_axis,)
+        }
+    }
+}
@@ -2,5 +2,7 @@ pub mod mat2;
 pub mod mat3a;
 pub mod mat4;
 pub mod quat;
+pub mod quatx4;
 pub mod vec3a;
+pub mod vec3x4;
 pub mod vec4;
@@ -379,6 +379,173 @@ impl Mat2 {
         self.inverse_checked::<true>().0
     }
 
+    /// Solves the linear system `self * x == rhs` for `x`, returning `None` if `self` is not
+    /// invertible.
+    ///
+    /// This uses the [LU decomposition](Self::lu) of `self` which is faster and more accurate
+    /// than multiplying `rhs` by the inverse of `self`.
+    #[must_use]
+    pub fn solve(&self, rhs: Vec2) -> Option<Vec2> {
+        let (lu, perm) = self.lu_packed();
+        let b = rhs.to_array();
+
+        // Forward substitution with the unit lower triangular factor.
+        let mut x = [0.0; 2];
+        for i in 0..2 {
+            let mut sum = b[perm[i]];
+            for j in 0..i {
+                sum -= lu[i][j] * x[j];
+            }
+            x[i] = sum;
+        }
+
+        // Back substitution with the upper triangular factor.
+        for i in (0..2).rev() {
+            let pivot = lu[i][i];
+            if pivot == 0.0 {
+                return None;
+            }
+            let mut sum = x[i];
+            for j in i + 1..2 {
+                sum -= lu[i][j] * x[j];
+            }
+            x[i] = sum / pivot;
+        }
+
+        Some(Vec2::from_array(x))
+    }
+
+    /// Computes the LU decomposition of `self` with partial pivoting.
+    ///
+    /// Returns a tuple `(p, l, u)` where `p` is a permutation matrix, `l` is a lower triangular
+    /// matrix with a unit diagonal and `u` is an upper triangular matrix, such that
+    /// `p * self == l * u`.
+    ///
+    /// The decomposition always exists. If `self` is not invertible `u` has a zero on its
+    /// diagonal.
+    #[must_use]
+    pub fn lu(&self) -> (Self, Self, Self) {
+        let (lu, perm) = self.lu_packed();
+        let mut p = [[0.0; 2]; 2];
+        let mut l = [[0.0; 2]; 2];
+        let mut u = [[0.0; 2]; 2];
+        for (i, row) in lu.iter().enumerate() {
+            p[i][perm[i]] = 1.0;
+            l[i][i] = 1.0;
+            l[i][..i].copy_from_slice(&row[..i]);
+            u[i][i..].copy_from_slice(&row[i..]);
+        }
+        // The factors were built row by row.
+        (
+            Self::from_cols_array_2d(&p).transpose(),
+            Self::from_cols_array_2d(&l).transpose(),
+            Self::from_cols_array_2d(&u).transpose(),
+        )
+    }
+
+    /// Computes the LU decomposition of `self` with partial pivoting, returning the rows of
+    /// both factors packed into one array and the row permutation.
+    #[inline]
+    fn lu_packed(&self) -> ([[f32; 2]; 2], [usize; 2]) {
+        let mut a = self.transpose().to_cols_array_2d();
+        let mut perm = [0, 1];
+        for k in 0..2 {
+            let mut pivot = k;
+            for i in k + 1..2 {
+                if math::abs(a[i][k]) > math::abs(a[pivot][k]) {
+                    pivot = i;
+                }
+            }
+            a.swap(k, pivot);
+            perm.swap(k, pivot);
+
+            let pivot_row = a[k];
+            if pivot_row[k] == 0.0 {
+                continue;
+            }
+            for row in a.iter_mut().skip(k + 1) {
+                let factor = row[k] / pivot_row[k];
+                row[k] = factor;
+                for (x, y) in row.iter_mut().zip(pivot_row).skip(k + 1) {
+                    *x -= factor * y;
+                }
+            }
+        }
+        (a, perm)
+    }
+
+    /// Computes the QR decomposition of `self` using Householder reflections.
+    ///
+    /// Returns a tuple `(q, r)` where `q` is an orthonormal matrix and `r` is an upper
+    /// triangular matrix with a non-negative diagonal, such that `self == q * r`.
+    #[must_use]
+    pub fn qr(&self) -> (Self, Self) {
+        // `qt` accumulates the transpose of `q` by applying the reflections to the identity.
+        let mut r = self.to_cols_array_2d();
+        let mut qt = Self::IDENTITY.to_cols_array_2d();
+        for k in 0..1 {
+            let mut v = [0.0; 2];
+            v[k..].copy_from_slice(&r[k][k..]);
+            let norm = math::sqrt(v.iter().map(|x| x * x).sum());
+            if norm == 0.0 {
+                continue;
+            }
+            v[k] += math::copysign(norm, v[k]);
+            let v_length_squared: f32 = v.iter().map(|x| x * x).sum();
+
+            for col in r.iter_mut().chain(qt.iter_mut()) {
+                let d: f32 = col.iter().zip(v).map(|(x, y)| x * y).sum();
+                let d = 2.0 * d / v_length_squared;
+                for (x, y) in col.iter_mut().zip(v) {
+                    *x -= d * y;
+                }
+            }
+            for x in &mut r[k][k + 1..] {
+                *x = 0.0;
+            }
+        }
+
+        // Negate rows of `r` and the matching columns of `q` to make the diagonal non-negative.
+        for k in 0..2 {
+            if r[k][k] < 0.0 {
+                for col in r.iter_mut().chain(qt.iter_mut()) {
+                    col[k] = -col[k];
+                }
+            }
+        }
+
+        (
+            Self::from_cols_array_2d(&qt).transpose(),
+            Self::from_cols_array_2d(&r),
+        )
+    }
+
+    /// Computes the Cholesky decomposition of `self`, which must be symmetric
+    /// positive-definite.
+    ///
+    /// Returns a lower triangular matrix `l` with a positive diagonal such that
+    /// `self == l * l.transpose()`, or `None` if `self` is not positive-definite. Only the
+    /// lower triangle of `self` is read.
+    #[must_use]
+    pub fn cholesky(&self) -> Option<Self> {
+        let a = self.to_cols_array_2d();
+        let mut l = [[0.0; 2]; 2];
+        for j in 0..2 {
+            let d = a[j][j] - l[..j].iter().map(|col| col[j] * col[j]).sum::<f32>();
+            if d <= 0.0 || d.is_nan() {
+                return None;
+            }
+            let d = math::sqrt(d);
+            l[j][j] = d;
+
+            for i in j + 1..2 {
+                let sum = a[j][i] - l[..j].iter().map(|col| col[i] * col[j]).sum::<f32>();
+                l[j][i] = sum / d;
+            }
+        }
+        Some(Self::from_cols_array_2d(&l))
+    }
+
     /// Transforms a 2D vector.
     #[inline]
     #[must_use]
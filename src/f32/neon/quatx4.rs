@@ -0,0 +1,365 @@
+// Generated from quat_wide.rs.tera template. Edit the template, not the generated file.
+
+use crate::BVec4A;
+
+use crate::{Quat, Vec3x4, Vec3x8, Vec4};
+
+use core::arch::aarch64::*;
+
+use core::ops::*;
+
+/// Transposes four SIMD registers, converting between one quaternion per register and one
+/// component per register.
+#[inline(always)]
+fn transpose(r: [float32x4_t; 4]) -> [float32x4_t; 4] {
+    unsafe {
+        let x0x1y0y1 = vzip1q_f32(r[0], r[1]);
+        let z0z1w0w1 = vzip2q_f32(r[0], r[1]);
+        let x2x3y2y3 = vzip1q_f32(r[2], r[3]);
+        let z2z3w2w3 = vzip2q_f32(r[2], r[3]);
+        [
+            vcombine_f32(vget_low_f32(x0x1y0y1), vget_low_f32(x2x3y2y3)),
+            vcombine_f32(vget_high_f32(x0x1y0y1), vget_high_f32(x2x3y2y3)),
+            vcombine_f32(vget_low_f32(z0z1w0w1), vget_low_f32(z2z3w2w3)),
+            vcombine_f32(vget_high_f32(z0z1w0w1), vget_high_f32(z2z3w2w3)),
+        ]
+    }
+}
+
+/// Four quaternions stored in structure of arrays layout.
+///
+/// Each field holds one component of all four quaternions, so on SIMD targets the quaternions are
+/// stored in four SIMD registers and every operation processes all four quaternions at once.
+/// Operations follow the scalar [`Quat`] implementation, so results may differ in the last bits
+/// from the SIMD [`Quat`] methods.
+///
+/// Besides rotating a [`Vec3x4`] by four different rotations, a single [`Quat`] can rotate all of
+/// the vectors of a [`Vec3x4`] or [`Vec3x8`].
+///
+/// This type is 16 byte aligned.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(C)]
+pub struct Quatx4 {
+    pub x: Vec4,
+    pub y: Vec4,
+    pub z: Vec4,
+    pub w: Vec4,
+}
+
+impl Quatx4 {
+    /// All four quaternions are the identity quaternion.
+    pub const IDENTITY: Self = Self::new(Vec4::ZERO, Vec4::ZERO, Vec4::ZERO, Vec4::ONE);
+
+    /// All `f32::NAN`.
+    pub const NAN: Self = Self::new(Vec4::NAN, Vec4::NAN, Vec4::NAN, Vec4::NAN);
+
+    /// Creates four quaternions from lane registers holding their `x`, `y`, `z` and `w`
+    /// components.
+    ///
+    /// This should generally not be called manually unless you know what you are doing. Use
+    /// [`Self::from_array()`] or [`Self::splat()`] instead.
+    #[inline(always)]
+    #[must_use]
+    pub const fn new(x: Vec4, y: Vec4, z: Vec4, w: Vec4) -> Self {
+        Self { x, y, z, w }
+    }
+
+    /// Creates four copies of `q`.
+    #[inline]
+    #[must_use]
+    pub fn splat(q: Quat) -> Self {
+        let [x, y, z, w] = q.to_array();
+        Self::new(
+            Vec4::splat(x),
+            Vec4::splat(y),
+            Vec4::splat(z),
+            Vec4::splat(w),
+        )
+    }
+
+    /// Creates four quaternions from an array, converting to structure of arrays layout.
+    #[inline]
+    #[must_use]
+    pub fn from_array(a: [Quat; 4]) -> Self {
+        let [x, y, z, w] = transpose(a.map(|q| q.0));
+        Self::new(Vec4(x), Vec4(y), Vec4(z), Vec4(w))
+    }
+
+    /// Converts `self` to an array of four quaternions.
+    #[inline]
+    #[must_use]
+    pub fn to_array(self) -> [Quat; 4] {
+        transpose([self.x.0, self.y.0, self.z.0, self.w.0]).map(Quat)
+    }
+
+    /// Creates four quaternions from the first four values in `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than four elements long.
+    #[inline]
+    #[must_use]
+    pub fn from_slice(slice: &[Quat]) -> Self {
+        assert!(slice.len() >= 4);
+        Self::from_array([slice[0], slice[1], slice[2], slice[3]])
+    }
+
+    /// Writes the four quaternions of `self` to the first four elements in `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than four elements long.
+    #[inline]
+    pub fn write_to_slice(self, slice: &mut [Quat]) {
+        slice[..4].copy_from_slice(&self.to_array());
+    }
+
+    /// Creates four quaternions by choosing between the quaternions of `if_true` and `if_false`
+    /// depending on the value of the corresponding lane of `mask`.
+    #[inline]
+    #[must_use]
+    pub fn select(mask: BVec4A, if_true: Self, if_false: Self) -> Self {
+        Self::new(
+            Vec4::select(mask, if_true.x, if_false.x),
+            Vec4::select(mask, if_true.y, if_false.y),
+            Vec4::select(mask, if_true.z, if_false.z),
+            Vec4::select(mask, if_true.w, if_false.w),
+        )
+    }
+
+    /// Returns the vector part of each quaternion.
+    #[inline]
+    #[must_use]
+    pub fn xyz(self) -> Vec3x4 {
+        Vec3x4::new(self.x, self.y, self.z)
+    }
+
+    /// Returns the quaternion conjugate of each quaternion of `self`.
+    #[inline]
+    #[must_use]
+    pub fn conjugate(self) -> Self {
+        Self::new(-self.x, -self.y, -self.z, self.w)
+    }
+
+    /// Returns the inverse of each normalized quaternion of `self`.
+    ///
+    /// Typically quaternion inverse returns the conjugate of a normalized quaternion. Because
+    /// `self` is assumed to already be unit length this method *does not* normalize before
+    /// returning the conjugate.
+    #[inline]
+    #[must_use]
+    pub fn inverse(self) -> Self {
+        self.conjugate()
+    }
+
+    /// Computes the dot product of each quaternion of `self` and `rhs`.
+    #[inline]
+    #[must_use]
+    pub fn dot(self, rhs: Self) -> Vec4 {
+        (self.x * rhs.x) + (self.y * rhs.y) + (self.z * rhs.z) + (self.w * rhs.w)
+    }
+
+    /// Computes the length of each quaternion of `self`.
+    #[doc(alias = "magnitude")]
+    #[inline]
+    #[must_use]
+    pub fn length(self) -> Vec4 {
+        self.dot(self).sqrt()
+    }
+
+    /// Computes the squared length of each quaternion of `self`.
+    #[doc(alias = "magnitude2")]
+    #[inline]
+    #[must_use]
+    pub fn length_squared(self) -> Vec4 {
+        self.dot(self)
+    }
+
+    /// Computes `1.0 / length()` for each quaternion of `self`.
+    #[inline]
+    #[must_use]
+    pub fn length_recip(self) -> Vec4 {
+        self.length().recip()
+    }
+
+    /// Returns each quaternion of `self` normalized to length 1.0.
+    ///
+    /// For valid results, the quaternions must _not_ be of length zero.
+    #[inline]
+    #[must_use]
+    pub fn normalize(self) -> Self {
+        self * self.length_recip()
+    }
+
+    /// Performs a normalized linear interpolation between each quaternion of `self` and `end`
+    /// based on the value `s`, taking the shortest path like [`Quat::lerp()`].
+    #[doc(alias = "mix")]
+    #[inline]
+    #[must_use]
+    pub fn lerp(self, end: Self, s: f32) -> Self {
+        let bias = Vec4::select(self.dot(end).cmpge(Vec4::ZERO), Vec4::ONE, Vec4::NEG_ONE);
+        (self * (1.0 - s) + end * bias * s).normalize()
+    }
+
+    /// Multiplies each quaternion of `self` and `rhs`. If they each represent a rotation, the
+    /// results will represent the combined rotations.
+    ///
+    /// Note that due to floating point rounding the results may not be perfectly normalized.
+    #[inline]
+    #[must_use]
+    pub fn mul_quat(self, rhs: Self) -> Self {
+        let Self {
+            x: x0,
+            y: y0,
+            z: z0,
+            w: w0,
+        } = self;
+        let Self {
+            x: x1,
+            y: y1,
+            z: z1,
+            w: w1,
+        } = rhs;
+        Self::new(
+            w0 * x1 + x0 * w1 + y0 * z1 - z0 * y1,
+            w0 * y1 - x0 * z1 + y0 * w1 + z0 * x1,
+            w0 * z1 + x0 * y1 - y0 * x1 + z0 * w1,
+            w0 * w1 - x0 * x1 - y0 * y1 - z0 * z1,
+        )
+    }
+
+    /// Rotates each vector of `rhs` by the corresponding quaternion of `self`.
+    ///
+    /// The quaternions are assumed to be normalized.
+    #[inline]
+    #[must_use]
+    pub fn mul_vec3x4(self, rhs: Vec3x4) -> Vec3x4 {
+        let w = self.w;
+        let b = self.xyz();
+        let b2 = b.dot(b);
+        rhs * (w * w - b2) + b * (rhs.dot(b) * 2.0) + b.cross(rhs) * (w * 2.0)
+    }
+}
+
+impl Default for Quatx4 {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl From<[Quat; 4]> for Quatx4 {
+    #[inline]
+    fn from(a: [Quat; 4]) -> Self {
+        Self::from_array(a)
+    }
+}
+
+impl From<Quatx4> for [Quat; 4] {
+    #[inline]
+    fn from(q: Quatx4) -> Self {
+        q.to_array()
+    }
+}
+
+impl Add for Quatx4 {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self::new(
+            self.x + rhs.x,
+            self.y + rhs.y,
+            self.z + rhs.z,
+            self.w + rhs.w,
+        )
+    }
+}
+
+impl Sub for Quatx4 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(
+            self.x - rhs.x,
+            self.y - rhs.y,
+            self.z - rhs.z,
+            self.w - rhs.w,
+        )
+    }
+}
+
+impl Mul<f32> for Quatx4 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: f32) -> Self {
+        Self::new(self.x * rhs, self.y * rhs, self.z * rhs, self.w * rhs)
+    }
+}
+
+/// Scales each quaternion of `self` by the corresponding lane of `rhs`.
+impl Mul<Vec4> for Quatx4 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Vec4) -> Self {
+        Self::new(self.x * rhs, self.y * rhs, self.z * rhs, self.w * rhs)
+    }
+}
+
+impl Neg for Quatx4 {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y, -self.z, -self.w)
+    }
+}
+
+impl Mul for Quatx4 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        self.mul_quat(rhs)
+    }
+}
+
+impl MulAssign for Quatx4 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.mul_quat(rhs);
+    }
+}
+
+impl Mul<Vec3x4> for Quatx4 {
+    type Output = Vec3x4;
+    #[inline]
+    fn mul(self, rhs: Vec3x4) -> Vec3x4 {
+        self.mul_vec3x4(rhs)
+    }
+}
+
+/// Rotates all of the vectors of `rhs` by `self`.
+impl Mul<Vec3x4> for Quat {
+    type Output = Vec3x4;
+    #[inline]
+    fn mul(self, rhs: Vec3x4) -> Vec3x4 {
+        Quatx4::splat(self).mul_vec3x4(rhs)
+    }
+}
+
+/// Rotates all of the vectors of `rhs` by `self`.
+impl Mul<Vec3x8> for Quat {
+    type Output = Vec3x8;
+    #[inline]
+    fn mul(self, rhs: Vec3x8) -> Vec3x8 {
+        let q = Quatx4::splat(self);
+        let [lo, hi] = rhs.to_halves();
+        Vec3x8::from_halves([q.mul_vec3x4(lo), q.mul_vec3x4(hi)])
+    }
+}
+
+impl From<Quat> for Quatx4 {
+    #[inline]
+    fn from(q: Quat) -> Self {
+        Self::splat(q)
+    }
+}
@@ -0,0 +1,470 @@
+// Generated from vec3_wide.rs.tera template. Edit the template, not the generated file.
+
+use crate::BVec4A;
+
+use crate::{Vec3, Vec3x8, Vec4};
+
+use core::arch::aarch64::*;
+
+use core::ops::*;
+
+/// Four 3-dimensional vectors stored in structure of arrays layout.
+///
+/// Each field holds one component of all four vectors, so on SIMD targets the vectors are stored
+/// in three SIMD registers and every operation processes all four vectors at once. Results are
+/// the same as calling the corresponding [`Vec3`] method on each vector.
+///
+/// Methods which return a scalar per vector, such as [`Self::dot()`], return a [`Vec4`] holding
+/// one result per vector, and [`Self::select()`] takes a [`BVec4A`] mask, so these can be
+/// combined with the [`Vec4`] comparison methods.
+///
+/// This type is 16 byte aligned.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(C)]
+pub struct Vec3x4 {
+    pub x: Vec4,
+    pub y: Vec4,
+    pub z: Vec4,
+}
+
+impl Vec3x4 {
+    /// All zeroes.
+    pub const ZERO: Self = Self::splat(Vec3::ZERO);
+
+    /// All ones.
+    pub const ONE: Self = Self::splat(Vec3::ONE);
+
+    /// All `f32::NAN`.
+    pub const NAN: Self = Self::splat(Vec3::NAN);
+
+    /// All vectors are a unit vector pointing along the positive X axis.
+    pub const X: Self = Self::splat(Vec3::X);
+
+    /// All vectors are a unit vector pointing along the positive Y axis.
+    pub const Y: Self = Self::splat(Vec3::Y);
+
+    /// All vectors are a unit vector pointing along the positive Z axis.
+    pub const Z: Self = Self::splat(Vec3::Z);
+
+    /// Creates 4 vectors from lane registers holding their `x`, `y` and `z` components.
+    #[inline(always)]
+    #[must_use]
+    pub const fn new(x: Vec4, y: Vec4, z: Vec4) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Creates 4 copies of `v`.
+    #[inline]
+    #[must_use]
+    pub const fn splat(v: Vec3) -> Self {
+        Self::new(Vec4::splat(v.x), Vec4::splat(v.y), Vec4::splat(v.z))
+    }
+
+    /// Creates 4 vectors from an array, converting to structure of arrays layout.
+    #[inline]
+    #[must_use]
+    pub fn from_array(a: [Vec3; 4]) -> Self {
+        Self::from_slice(&a)
+    }
+
+    /// Converts `self` to an array of 4 vectors.
+    #[inline]
+    #[must_use]
+    pub fn to_array(self) -> [Vec3; 4] {
+        let mut a = [Vec3::ZERO; 4];
+        self.write_to_slice(&mut a);
+        a
+    }
+
+    /// Creates 4 vectors from the first 4 values in `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than 4 elements long.
+    #[inline]
+    #[must_use]
+    pub fn from_slice(slice: &[Vec3]) -> Self {
+        assert!(slice.len() >= 4);
+
+        // `Vec3` is `repr(C)`, so four of them are twelve consecutive `f32` values
+        let ptr = slice.as_ptr() as *const f32;
+
+        unsafe {
+            // de-interleaving load
+            let float32x4x3_t(x, y, z) = vld3q_f32(ptr);
+            Self::new(Vec4(x), Vec4(y), Vec4(z))
+        }
+    }
+
+    /// Writes the 4 vectors of `self` to the first 4 elements in `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than 4 elements long.
+    #[inline]
+    pub fn write_to_slice(self, slice: &mut [Vec3]) {
+        assert!(slice.len() >= 4);
+
+        let ptr = slice.as_mut_ptr() as *mut f32;
+
+        unsafe {
+            // interleaving store
+            vst3q_f32(ptr, float32x4x3_t(self.x.0, self.y.0, self.z.0));
+        }
+    }
+
+    /// Applies `f` to the vectors of `src` four at a time, writing the results to `dst`.
+    ///
+    /// A final partial chunk is padded with zero vectors, the padding results are discarded.
+    #[inline(always)]
+    pub(crate) fn map_slice(src: &[Vec3], dst: &mut [Vec3], f: impl Fn(Self) -> Self) {
+        assert_eq!(src.len(), dst.len());
+        let mut src = src.chunks_exact(4);
+        let mut dst = dst.chunks_exact_mut(4);
+        for (s, d) in (&mut src).zip(&mut dst) {
+            f(Self::from_slice(s)).write_to_slice(d);
+        }
+        let rem = src.remainder();
+        if !rem.is_empty() {
+            let mut tmp = [Vec3::ZERO; 4];
+            tmp[..rem.len()].copy_from_slice(rem);
+            f(Self::from_array(tmp)).write_to_slice(&mut tmp);
+            dst.into_remainder().copy_from_slice(&tmp[..rem.len()]);
+        }
+    }
+
+    /// Applies `f` to the vectors of `slice` four at a time, replacing them with the results.
+    #[inline(always)]
+    pub(crate) fn map_slice_in_place(slice: &mut [Vec3], f: impl Fn(Self) -> Self) {
+        let mut chunks = slice.chunks_exact_mut(4);
+        for c in &mut chunks {
+            f(Self::from_slice(c)).write_to_slice(c);
+        }
+        let rem = chunks.into_remainder();
+        if !rem.is_empty() {
+            let mut tmp = [Vec3::ZERO; 4];
+            tmp[..rem.len()].copy_from_slice(rem);
+            f(Self::from_array(tmp)).write_to_slice(&mut tmp);
+            rem.copy_from_slice(&tmp[..rem.len()]);
+        }
+    }
+
+    /// Creates 4 vectors by choosing between the vectors of `if_true` and `if_false`
+    /// depending on the value of the corresponding lane of `mask`.
+    #[inline]
+    #[must_use]
+    pub fn select(mask: BVec4A, if_true: Self, if_false: Self) -> Self {
+        Self::new(
+            Vec4::select(mask, if_true.x, if_false.x),
+            Vec4::select(mask, if_true.y, if_false.y),
+            Vec4::select(mask, if_true.z, if_false.z),
+        )
+    }
+
+    /// Computes the dot product of each vector of `self` and `rhs`.
+    #[inline]
+    #[must_use]
+    pub fn dot(self, rhs: Self) -> Vec4 {
+        (self.x * rhs.x) + (self.y * rhs.y) + (self.z * rhs.z)
+    }
+
+    /// Computes the cross product of each vector of `self` and `rhs`.
+    #[inline]
+    #[must_use]
+    pub fn cross(self, rhs: Self) -> Self {
+        Self::new(
+            self.y * rhs.z - rhs.y * self.z,
+            self.z * rhs.x - rhs.z * self.x,
+            self.x * rhs.y - rhs.x * self.y,
+        )
+    }
+
+    /// Returns the element-wise minimum of each vector of `self` and `rhs`.
+    ///
+    /// NaN propogation does not follow IEEE 754-2008 semantics for minNum and may differ on
+    /// different SIMD architectures.
+    #[inline]
+    #[must_use]
+    pub fn min(self, rhs: Self) -> Self {
+        Self::new(self.x.min(rhs.x), self.y.min(rhs.y), self.z.min(rhs.z))
+    }
+
+    /// Returns the element-wise maximum of each vector of `self` and `rhs`.
+    ///
+    /// NaN propogation does not follow IEEE 754-2008 semantics for maxNum and may differ on
+    /// different SIMD architectures.
+    #[inline]
+    #[must_use]
+    pub fn max(self, rhs: Self) -> Self {
+        Self::new(self.x.max(rhs.x), self.y.max(rhs.y), self.z.max(rhs.z))
+    }
+
+    /// Returns the absolute value of each element of `self`.
+    #[inline]
+    #[must_use]
+    pub fn abs(self) -> Self {
+        Self::new(self.x.abs(), self.y.abs(), self.z.abs())
+    }
+
+    /// Computes the length of each vector of `self`.
+    #[doc(alias = "magnitude")]
+    #[inline]
+    #[must_use]
+    pub fn length(self) -> Vec4 {
+        self.dot(self).sqrt()
+    }
+
+    /// Computes the squared length of each vector of `self`.
+    #[doc(alias = "magnitude2")]
+    #[inline]
+    #[must_use]
+    pub fn length_squared(self) -> Vec4 {
+        self.dot(self)
+    }
+
+    /// Computes `1.0 / length()` for each vector of `self`.
+    #[inline]
+    #[must_use]
+    pub fn length_recip(self) -> Vec4 {
+        self.length().recip()
+    }
+
+    /// Computes the Euclidean distance between each vector of `self` and `rhs`.
+    #[inline]
+    #[must_use]
+    pub fn distance(self, rhs: Self) -> Vec4 {
+        (self - rhs).length()
+    }
+
+    /// Computes the squared Euclidean distance between each vector of `self` and `rhs`.
+    #[inline]
+    #[must_use]
+    pub fn distance_squared(self, rhs: Self) -> Vec4 {
+        (self - rhs).length_squared()
+    }
+
+    /// Returns each vector of `self` normalized to length 1.0.
+    ///
+    /// For valid results, the vectors must _not_ be of length zero, nor very close to zero.
+    ///
+    /// See also [`Self::normalize_or_zero()`].
+    #[inline]
+    #[must_use]
+    pub fn normalize(self) -> Self {
+        self * self.length_recip()
+    }
+
+    /// Returns each vector of `self` normalized to length 1.0 if possible, else zero.
+    ///
+    /// In particular, if a vector is zero (or very close to zero), or non-finite, it is replaced
+    /// by zero.
+    #[inline]
+    #[must_use]
+    pub fn normalize_or_zero(self) -> Self {
+        let rcp = self.length_recip();
+        let mask = rcp.is_finite_mask() & rcp.cmpgt(Vec4::ZERO);
+        Self::select(mask, self * rcp, Self::ZERO)
+    }
+
+    /// Performs a linear interpolation between each vector of `self` and `rhs` based on the value
+    /// `s`.
+    ///
+    /// When `s` is `0.0`, the result will be equal to `self`. When `s` is `1.0`, the result will
+    /// be equal to `rhs`. When `s` is outside of range `[0, 1]`, the result is linearly
+    /// extrapolated.
+    #[doc(alias = "mix")]
+    #[inline]
+    #[must_use]
+    pub fn lerp(self, rhs: Self, s: f32) -> Self {
+        self * (1.0 - s) + rhs * s
+    }
+}
+
+impl Default for Vec3x4 {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl From<[Vec3; 4]> for Vec3x4 {
+    #[inline]
+    fn from(a: [Vec3; 4]) -> Self {
+        Self::from_array(a)
+    }
+}
+
+impl From<Vec3x4> for [Vec3; 4] {
+    #[inline]
+    fn from(v: Vec3x4) -> Self {
+        v.to_array()
+    }
+}
+
+impl Neg for Vec3x4 {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl Add for Vec3x4 {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.x.add(rhs.x), self.y.add(rhs.y), self.z.add(rhs.z))
+    }
+}
+
+impl AddAssign for Vec3x4 {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = self.add(rhs);
+    }
+}
+
+impl Add<f32> for Vec3x4 {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: f32) -> Self {
+        Self::new(self.x.add(rhs), self.y.add(rhs), self.z.add(rhs))
+    }
+}
+
+impl AddAssign<f32> for Vec3x4 {
+    #[inline]
+    fn add_assign(&mut self, rhs: f32) {
+        *self = self.add(rhs);
+    }
+}
+
+impl Sub for Vec3x4 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.x.sub(rhs.x), self.y.sub(rhs.y), self.z.sub(rhs.z))
+    }
+}
+
+impl SubAssign for Vec3x4 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = self.sub(rhs);
+    }
+}
+
+impl Sub<f32> for Vec3x4 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: f32) -> Self {
+        Self::new(self.x.sub(rhs), self.y.sub(rhs), self.z.sub(rhs))
+    }
+}
+
+impl SubAssign<f32> for Vec3x4 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: f32) {
+        *self = self.sub(rhs);
+    }
+}
+
+impl Mul for Vec3x4 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(self.x.mul(rhs.x), self.y.mul(rhs.y), self.z.mul(rhs.z))
+    }
+}
+
+impl MulAssign for Vec3x4 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.mul(rhs);
+    }
+}
+
+impl Mul<f32> for Vec3x4 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: f32) -> Self {
+        Self::new(self.x.mul(rhs), self.y.mul(rhs), self.z.mul(rhs))
+    }
+}
+
+impl MulAssign<f32> for Vec3x4 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: f32) {
+        *self = self.mul(rhs);
+    }
+}
+
+impl Div for Vec3x4 {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: Self) -> Self {
+        Self::new(self.x.div(rhs.x), self.y.div(rhs.y), self.z.div(rhs.z))
+    }
+}
+
+impl DivAssign for Vec3x4 {
+    #[inline]
+    fn div_assign(&mut self, rhs: Self) {
+        *self = self.div(rhs);
+    }
+}
+
+impl Div<f32> for Vec3x4 {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: f32) -> Self {
+        Self::new(self.x.div(rhs), self.y.div(rhs), self.z.div(rhs))
+    }
+}
+
+impl DivAssign<f32> for Vec3x4 {
+    #[inline]
+    fn div_assign(&mut self, rhs: f32) {
+        *self = self.div(rhs);
+    }
+}
+
+/// Scales each vector of `self` by the corresponding lane of `rhs`.
+impl Mul<Vec4> for Vec3x4 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Vec4) -> Self {
+        Self::new(self.x.mul(rhs), self.y.mul(rhs), self.z.mul(rhs))
+    }
+}
+
+impl MulAssign<Vec4> for Vec3x4 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Vec4) {
+        *self = self.mul(rhs);
+    }
+}
+
+/// Scales each vector of `self` by the corresponding lane of `rhs`.
+impl Div<Vec4> for Vec3x4 {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: Vec4) -> Self {
+        Self::new(self.x.div(rhs), self.y.div(rhs), self.z.div(rhs))
+    }
+}
+
+impl DivAssign<Vec4> for Vec3x4 {
+    #[inline]
+    fn div_assign(&mut self, rhs: Vec4) {
+        *self = self.div(rhs);
+    }
+}
+
+impl From<Vec3x8> for [Vec3x4; 2] {
+    #[inline]
+    fn from(v: Vec3x8) -> Self {
+        v.to_halves()
+    }
+}
@@ -0,0 +1,458 @@
+// Generated from rot2.rs.tera template. Edit the template, not the generated file.
+
+use crate::{f32::math, Affine2, DRot2, Mat2, Vec2};
+use core::fmt;
+use core::iter::Product;
+use core::ops::{Mul, MulAssign};
+
+#[cfg(feature = "zerocopy")]
+use zerocopy_derive::*;
+
+/// A 2D rotation stored as a unit complex number.
+///
+/// The rotation is represented by the cosine and sine of its angle, which is the same layout as
+/// a [`Vec2`] holding the rotated x axis. Rotating a vector is a complex multiplication
+/// and composing rotations is a complex multiplication of the two rotations.
+///
+/// This type is 8 byte aligned unless the `scalar-math` feature is enabled, so that it
+/// can be loaded with a single SIMD load.
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(FromBytes, Immutable, IntoBytes, KnownLayout)
+)]
+#[cfg_attr(
+    not(any(feature = "scalar-math", target_arch = "spirv")),
+    repr(align(8))
+)]
+#[repr(C)]
+pub struct Rot2 {
+    /// The cosine of the rotation angle.
+    pub cos: f32,
+    /// The sine of the rotation angle.
+    pub sin: f32,
+}
+
+impl Rot2 {
+    /// The identity rotation.
+    pub const IDENTITY: Self = Self { cos: 1.0, sin: 0.0 };
+
+    /// All NAN:s.
+    pub const NAN: Self = Self {
+        cos: f32::NAN,
+        sin: f32::NAN,
+    };
+
+    /// Creates a rotation from a `[cos, sin]` array.
+    ///
+    /// This function does not check if the input is normalized, it is up to the user to
+    /// provide normalized input or to normalize the resulting rotation.
+    #[inline]
+    #[must_use]
+    pub const fn from_array(a: [f32; 2]) -> Self {
+        Self {
+            cos: a[0],
+            sin: a[1],
+        }
+    }
+
+    /// Returns the rotation as a `[cos, sin]` array.
+    #[inline]
+    #[must_use]
+    pub const fn to_array(self) -> [f32; 2] {
+        [self.cos, self.sin]
+    }
+
+    /// Creates a rotation from the sine and cosine of an angle.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `sin` and `cos` do not form a unit vector when `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn from_sin_cos(sin: f32, cos: f32) -> Self {
+        let r = Self { cos, sin };
+        glam_assert!(r.is_normalized());
+        r
+    }
+
+    /// Creates a rotation from an `angle` (in radians).
+    #[inline]
+    #[must_use]
+    pub fn from_angle(angle: f32) -> Self {
+        let (sin, cos) = math::sin_cos(angle);
+        Self { cos, sin }
+    }
+
+    /// Gets the minimal rotation for transforming `from` to `to`. Will rotate at most 180
+    /// degrees.
+    ///
+    /// The inputs must be unit vectors.
+    ///
+    /// `Rot2::from_rotation_arc(from, to) * from ≈ to`.
+    ///
+    /// Unlike [`Quat::from_rotation_arc_2d()`](crate::Quat::from_rotation_arc_2d)
+    /// this has no singular cases, as the rotation is the complex quotient of `to` and `from`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `from` or `to` are not normalized when `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn from_rotation_arc(from: Vec2, to: Vec2) -> Self {
+        glam_assert!(from.is_normalized());
+        glam_assert!(to.is_normalized());
+        Self {
+            cos: from.dot(to),
+            sin: from.perp_dot(to),
+        }
+    }
+
+    /// Creates a rotation from a 2x2 rotation matrix.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the x axis of `mat` is not normalized when `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn from_mat2(mat: &Mat2) -> Self {
+        Self::from_sin_cos(mat.x_axis.y, mat.x_axis.x)
+    }
+
+    /// Creates a rotation from the 2x2 part of an affine transform.
+    ///
+    /// The transform must not contain scale or shear, or the output will be invalid.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the x axis of `a.matrix2` is not normalized when `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn from_affine2(a: &Affine2) -> Self {
+        Self::from_mat2(&a.matrix2)
+    }
+
+    /// Returns the rotation angle of `self` (in radians) in the range `[-π, +π]`.
+    #[inline]
+    #[must_use]
+    pub fn to_angle(self) -> f32 {
+        math::atan2(self.sin, self.cos)
+    }
+
+    /// Returns the 2x2 rotation matrix of `self`.
+    #[inline]
+    #[must_use]
+    pub fn to_mat2(self) -> Mat2 {
+        Mat2::from_cols_array(&[self.cos, self.sin, -self.sin, self.cos])
+    }
+
+    /// Returns an affine transform that applies `self` without translation.
+    #[inline]
+    #[must_use]
+    pub fn to_affine2(self) -> Affine2 {
+        Affine2::from_mat2(self.to_mat2())
+    }
+
+    /// Returns the inverse of `self`, which rotates by the same angle in the opposite direction.
+    ///
+    /// This is the complex conjugate, which is only the inverse if `self` is normalized.
+    #[inline]
+    #[must_use]
+    pub fn inverse(self) -> Self {
+        Self {
+            cos: self.cos,
+            sin: -self.sin,
+        }
+    }
+
+    /// Computes the squared length of `self`.
+    #[inline]
+    #[must_use]
+    pub fn length_squared(self) -> f32 {
+        self.cos * self.cos + self.sin * self.sin
+    }
+
+    /// Computes the length of `self`.
+    #[inline]
+    #[must_use]
+    pub fn length(self) -> f32 {
+        math::sqrt(self.length_squared())
+    }
+
+    /// Returns whether `self` is of length `1.0` or not.
+    ///
+    /// Uses a precision threshold of `2e-4`.
+    #[inline]
+    #[must_use]
+    pub fn is_normalized(self) -> bool {
+        math::abs(self.length_squared() - 1.0) <= 2e-4
+    }
+
+    /// Returns `self` normalized to length 1.0.
+    ///
+    /// Repeatedly composing rotations accumulates rounding error, which this can be used to
+    /// correct.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `self` is zero length when `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn normalize(self) -> Self {
+        let length_recip = self.length().recip();
+        glam_assert!(length_recip.is_finite());
+        Self {
+            cos: self.cos * length_recip,
+            sin: self.sin * length_recip,
+        }
+    }
+
+    /// Returns the signed angle (in radians) of the rotation from `self` to `rhs`, in the range
+    /// `[-π, +π]`.
+    ///
+    /// The inputs do not need to be unit length however they must be non-zero.
+    #[inline]
+    #[must_use]
+    pub fn angle_between(self, rhs: Self) -> f32 {
+        math::atan2(
+            self.cos * rhs.sin - self.sin * rhs.cos,
+            self.cos * rhs.cos + self.sin * rhs.sin,
+        )
+    }
+
+    /// Performs a spherical linear interpolation between `self` and `end` based on the value
+    /// `s`.
+    ///
+    /// The angle is interpolated at a constant angular velocity, taking the shortest path.
+    ///
+    /// When `s` is `0.0`, the result will be equal to `self`.  When `s` is `1.0`, the result
+    /// will be equal to `end`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `self` or `end` are not normalized when `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn slerp(self, end: Self, s: f32) -> Self {
+        glam_assert!(self.is_normalized());
+        glam_assert!(end.is_normalized());
+        self.mul_rot2(Self::from_angle(self.angle_between(end) * s))
+    }
+
+    /// Rotates the given vector by `self`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `self` is not normalized when `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn mul_vec2(self, rhs: Vec2) -> Vec2 {
+        glam_assert!(self.is_normalized());
+        Vec2::new(
+            self.cos * rhs.x - self.sin * rhs.y,
+            self.sin * rhs.x + self.cos * rhs.y,
+        )
+    }
+
+    /// Multiplies two rotations. The result rotates by the sum of both angles.
+    ///
+    /// Note that due to floating point rounding the result may not be perfectly normalized.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `self` or `rhs` are not normalized when `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn mul_rot2(self, rhs: Self) -> Self {
+        glam_assert!(self.is_normalized());
+        glam_assert!(rhs.is_normalized());
+        Self {
+            cos: self.cos * rhs.cos - self.sin * rhs.sin,
+            sin: self.sin * rhs.cos + self.cos * rhs.sin,
+        }
+    }
+
+    /// Returns `true` if, and only if, all elements are finite.
+    ///
+    /// If any element is either `NaN`, positive or negative infinity, this will return
+    /// `false`.
+    #[inline]
+    #[must_use]
+    pub fn is_finite(self) -> bool {
+        self.cos.is_finite() && self.sin.is_finite()
+    }
+
+    /// Returns `true` if any elements are `NaN`.
+    #[inline]
+    #[must_use]
+    pub fn is_nan(self) -> bool {
+        self.cos.is_nan() || self.sin.is_nan()
+    }
+
+    /// Returns true if the absolute difference of all elements between `self` and `rhs`
+    /// is less than or equal to `max_abs_diff`.
+    ///
+    /// This can be used to compare if two rotations contain similar elements. It works best
+    /// when comparing with a known value. The `max_abs_diff` that should be used used depends on
+    /// the values being compared against.
+    ///
+    /// For more see
+    /// [comparing floating point numbers](https://randomascii.wordpress.com/2012/02/25/comparing-floating-point-numbers-2012-edition/).
+    #[inline]
+    #[must_use]
+    pub fn abs_diff_eq(self, rhs: Self, max_abs_diff: f32) -> bool {
+        math::abs(self.cos - rhs.cos) <= max_abs_diff
+            && math::abs(self.sin - rhs.sin) <= max_abs_diff
+    }
+
+    /// Casts all elements of `self` to `f64`.
+    #[inline]
+    #[must_use]
+    pub fn as_drot2(self) -> DRot2 {
+        DRot2::from_array([self.cos as f64, self.sin as f64])
+    }
+}
+
+impl Default for Rot2 {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl fmt::Debug for Rot2 {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct(stringify!(Rot2))
+            .field("cos", &self.cos)
+            .field("sin", &self.sin)
+            .finish()
+    }
+}
+
+impl fmt::Display for Rot2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(p) = f.precision() {
+            write!(f, "[{:.*}, {:.*}]", p, self.cos, p, self.sin)
+        } else {
+            write!(f, "[{}, {}]", self.cos, self.sin)
+        }
+    }
+}
+
+impl<'a> Product<&'a Self> for Rot2 {
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.fold(Self::IDENTITY, |a, &b| a.mul_rot2(b))
+    }
+}
+
+impl Mul for Rot2 {
+    type Output = Self;
+    /// Multiplies two rotations, see [`Self::mul_rot2()`].
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        self.mul_rot2(rhs)
+    }
+}
+
+impl Mul<&Self> for Rot2 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: &Self) -> Self {
+        self.mul(*rhs)
+    }
+}
+
+impl Mul<&Rot2> for &Rot2 {
+    type Output = Rot2;
+    #[inline]
+    fn mul(self, rhs: &Rot2) -> Rot2 {
+        (*self).mul(*rhs)
+    }
+}
+
+impl Mul<Rot2> for &Rot2 {
+    type Output = Rot2;
+    #[inline]
+    fn mul(self, rhs: Rot2) -> Rot2 {
+        (*self).mul(rhs)
+    }
+}
+
+impl MulAssign for Rot2 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.mul(rhs);
+    }
+}
+
+impl MulAssign<&Self> for Rot2 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: &Self) {
+        self.mul_assign(*rhs);
+    }
+}
+
+impl Mul<Vec2> for Rot2 {
+    type Output = Vec2;
+    /// Rotates the given vector by `self`, see [`Self::mul_vec2()`].
+    #[inline]
+    fn mul(self, rhs: Vec2) -> Vec2 {
+        self.mul_vec2(rhs)
+    }
+}
+
+impl Mul<&Vec2> for Rot2 {
+    type Output = Vec2;
+    #[inline]
+    fn mul(self, rhs: &Vec2) -> Vec2 {
+        self.mul(*rhs)
+    }
+}
+
+impl Mul<&Vec2> for &Rot2 {
+    type Output = Vec2;
+    #[inline]
+    fn mul(self, rhs: &Vec2) -> Vec2 {
+        (*self).mul(*rhs)
+    }
+}
+
+impl Mul<Vec2> for &Rot2 {
+    type Output = Vec2;
+    #[inline]
+    fn mul(self, rhs: Vec2) -> Vec2 {
+        (*self).mul(rhs)
+    }
+}
+
+impl From<[f32; 2]> for Rot2 {
+    #[inline]
+    fn from(a: [f32; 2]) -> Self {
+        Self::from_array(a)
+    }
+}
+
+impl From<Rot2> for [f32; 2] {
+    #[inline]
+    fn from(r: Rot2) -> Self {
+        r.to_array()
+    }
+}
+
+impl From<Rot2> for Mat2 {
+    #[inline]
+    fn from(r: Rot2) -> Self {
+        r.to_mat2()
+    }
+}
+
+impl From<Rot2> for Affine2 {
+    #[inline]
+    fn from(r: Rot2) -> Self {
+        r.to_affine2()
+    }
+}
@@ -4,7 +4,7 @@ use crate::{
     euler::{FromEuler, ToEuler},
     f32::math,
     swizzles::*,
-    DMat4, EulerRot, Mat3, Mat3A, Quat, Vec3, Vec3A, Vec4,
+    DMat4, EulerRot, Mat3, Mat3A, Quat, Vec3, Vec3A, Vec3x4, Vec4,
 };
 use core::fmt;
 use core::iter::{Product, Sum};
@@ -254,6 +254,9 @@ impl Mat4 {
     /// Extracts `scale`, `rotation` and `translation` from `self`. The input matrix is
     /// expected to be a 3D affine transformation matrix otherwise the output will be invalid.
     ///
+    /// The output will also be invalid if `self` contains shear, use [`Self::decompose()`] for
+    /// such matrices.
+    ///
     /// # Panics
     ///
     /// Will panic if the determinant of `self` is zero or if the resulting scale vector
@@ -285,6 +288,64 @@ impl Mat4 {
         (scale, rotation, translation)
     }
 
+    /// Extracts `scale`, `shear`, `rotation`, `translation` and `perspective` from `self`.
+    ///
+    /// This is a full decomposition in the style of the classic unmatrix algorithm which,
+    /// unlike [`Self::to_scale_rotation_translation()`], handles matrices containing shear and a
+    /// perspective part. `self` is split into a perspective matrix and an affine transform,
+    /// and the upper 3x3 of the affine transform is decomposed using
+    /// [`Mat3::to_scale_shear_rotation()`].
+    ///
+    /// The `perspective` vector is the bottom row of the perspective matrix, which is
+    /// `Vec4::W` if `self` is an affine transform.
+    ///
+    /// Returns `None` if the upper 3x3 of `self` is not invertible.
+    #[must_use]
+    pub fn decompose(&self) -> Option<(Vec3, Vec3, Quat, Vec3, Vec4)> {
+        let (scale, shear, rotation) = Mat3::from_mat4(*self).to_scale_shear_rotation()?;
+
+        let affine = Self::from_cols(
+            self.x_axis.with_w(0.0),
+            self.y_axis.with_w(0.0),
+            self.z_axis.with_w(0.0),
+            self.w_axis.with_w(1.0),
+        );
+        let perspective = affine.inverse().transpose().mul_vec4(self.row(3));
+
+        Some((scale, shear, rotation, self.w_axis.xyz(), perspective))
+    }
+
+    /// Creates a 4x4 matrix from the given `scale`, `shear`, `rotation`, `translation` and
+    /// `perspective`.
+    ///
+    /// This is the inverse of [`Self::decompose()`]. See
+    /// [`Mat3::from_scale_shear_rotation()`] for the meaning of `shear`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `rotation` is not normalized when `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn recompose(
+        scale: Vec3,
+        shear: Vec3,
+        rotation: Quat,
+        translation: Vec3,
+        perspective: Vec4,
+    ) -> Self {
+        let affine = Self::from_mat3_translation(
+            Mat3::from_scale_shear_rotation(scale, shear, rotation),
+            translation,
+        );
+        let perspective = Self::from_cols(
+            Vec4::new(1.0, 0.0, 0.0, perspective.x),
+            Vec4::new(0.0, 1.0, 0.0, perspective.y),
+            Vec4::new(0.0, 0.0, 1.0, perspective.z),
+            Vec4::new(0.0, 0.0, 0.0, perspective.w),
+        );
+        perspective.mul_mat4(&affine)
+    }
+
     /// Creates an affine transformation matrix from the given `rotation` quaternion.
     ///
     /// The resulting matrix can be used to transform 3D points and vectors. See
@@ -778,6 +839,173 @@ impl Mat4 {
         self.inverse_checked::<true>().0
     }
 
+    /// Solves the linear system `self * x == rhs` for `x`, returning `None` if `self` is not
+    /// invertible.
+    ///
+    /// This uses the [LU decomposition](Self::lu) of `self` which is faster and more accurate
+    /// than multiplying `rhs` by the inverse of `self`.
+    #[must_use]
+    pub fn solve(&self, rhs: Vec4) -> Option<Vec4> {
+        let (lu, perm) = self.lu_packed();
+        let b = rhs.to_array();
+
+        // Forward substitution with the unit lower triangular factor.
+        let mut x = [0.0; 4];
+        for i in 0..4 {
+            let mut sum = b[perm[i]];
+            for j in 0..i {
+                sum -= lu[i][j] * x[j];
+            }
+            x[i] = sum;
+        }
+
+        // Back substitution with the upper triangular factor.
+        for i in (0..4).rev() {
+            let pivot = lu[i][i];
+            if pivot == 0.0 {
+                return None;
+            }
+            let mut sum = x[i];
+            for j in i + 1..4 {
+                sum -= lu[i][j] * x[j];
+            }
+            x[i] = sum / pivot;
+        }
+
+        Some(Vec4::from_array(x))
+    }
+
+    /// Computes the LU decomposition of `self` with partial pivoting.
+    ///
+    /// Returns a tuple `(p, l, u)` where `p` is a permutation matrix, `l` is a lower triangular
+    /// matrix with a unit diagonal and `u` is an upper triangular matrix, such that
+    /// `p * self == l * u`.
+    ///
+    /// The decomposition always exists. If `self` is not invertible `u` has a zero on its
+    /// diagonal.
+    #[must_use]
+    pub fn lu(&self) -> (Self, Self, Self) {
+        let (lu, perm) = self.lu_packed();
+        let mut p = [[0.0; 4]; 4];
+        let mut l = [[0.0; 4]; 4];
+        let mut u = [[0.0; 4]; 4];
+        for (i, row) in lu.iter().enumerate() {
+            p[i][perm[i]] = 1.0;
+            l[i][i] = 1.0;
+            l[i][..i].copy_from_slice(&row[..i]);
+            u[i][i..].copy_from_slice(&row[i..]);
+        }
+        // The factors were built row by row.
+        (
+            Self::from_cols_array_2d(&p).transpose(),
+            Self::from_cols_array_2d(&l).transpose(),
+            Self::from_cols_array_2d(&u).transpose(),
+        )
+    }
+
+    /// Computes the LU decomposition of `self` with partial pivoting, returning the rows of
+    /// both factors packed into one array and the row permutation.
+    #[inline]
+    fn lu_packed(&self) -> ([[f32; 4]; 4], [usize; 4]) {
+        let mut a = self.transpose().to_cols_array_2d();
+        let mut perm = [0, 1, 2, 3];
+        for k in 0..4 {
+            let mut pivot = k;
+            for i in k + 1..4 {
+                if math::abs(a[i][k]) > math::abs(a[pivot][k]) {
+                    pivot = i;
+                }
+            }
+            a.swap(k, pivot);
+            perm.swap(k, pivot);
+
+            let pivot_row = a[k];
+            if pivot_row[k] == 0.0 {
+                continue;
+            }
+            for row in a.iter_mut().skip(k + 1) {
+                let factor = row[k] / pivot_row[k];
+                row[k] = factor;
+                for (x, y) in row.iter_mut().zip(pivot_row).skip(k + 1) {
+                    *x -= factor * y;
+                }
+            }
+        }
+        (a, perm)
+    }
+
+    /// Computes the QR decomposition of `self` using Householder reflections.
+    ///
+    /// Returns a tuple `(q, r)` where `q` is an orthonormal matrix and `r` is an upper
+    /// triangular matrix with a non-negative diagonal, such that `self == q * r`.
+    #[must_use]
+    pub fn qr(&self) -> (Self, Self) {
+        // `qt` accumulates the transpose of `q` by applying the reflections to the identity.
+        let mut r = self.to_cols_array_2d();
+        let mut qt = Self::IDENTITY.to_cols_array_2d();
+        for k in 0..3 {
+            let mut v = [0.0; 4];
+            v[k..].copy_from_slice(&r[k][k..]);
+            let norm = math::sqrt(v.iter().map(|x| x * x).sum());
+            if norm == 0.0 {
+                continue;
+            }
+            v[k] += math::copysign(norm, v[k]);
+            let v_length_squared: f32 = v.iter().map(|x| x * x).sum();
+
+            for col in r.iter_mut().chain(qt.iter_mut()) {
+                let d: f32 = col.iter().zip(v).map(|(x, y)| x * y).sum();
+                let d = 2.0 * d / v_length_squared;
+                for (x, y) in col.iter_mut().zip(v) {
+                    *x -= d * y;
+                }
+            }
+            for x in &mut r[k][k + 1..] {
+                *x = 0.0;
+            }
+        }
+
+        // Negate rows of `r` and the matching columns of `q` to make the diagonal non-negative.
+        for k in 0..4 {
+            if r[k][k] < 0.0 {
+                for col in r.iter_mut().chain(qt.iter_mut()) {
+                    col[k] = -col[k];
+                }
+            }
+        }
+
+        (
+            Self::from_cols_array_2d(&qt).transpose(),
+            Self::from_cols_array_2d(&r),
+        )
+    }
+
+    /// Computes the Cholesky decomposition of `self`, which must be symmetric
+    /// positive-definite.
+    ///
+    /// Returns a lower triangular matrix `l` with a positive diagonal such that
+    /// `self == l * l.transpose()`, or `None` if `self` is not positive-definite. Only the
+    /// lower triangle of `self` is read.
+    #[must_use]
+    pub fn cholesky(&self) -> Option<Self> {
+        let a = self.to_cols_array_2d();
+        let mut l = [[0.0; 4]; 4];
+        for j in 0..4 {
+            let d = a[j][j] - l[..j].iter().map(|col| col[j] * col[j]).sum::<f32>();
+            if d <= 0.0 || d.is_nan() {
+                return None;
+            }
+            let d = math::sqrt(d);
+            l[j][j] = d;
+
+            for i in j + 1..4 {
+                let sum = a[j][i] - l[..j].iter().map(|col| col[i] * col[j]).sum::<f32>();
+                l[j][i] = sum / d;
+            }
+        }
+        Some(Self::from_cols_array_2d(&l))
+    }
+
     /// Creates a left-handed view matrix using a camera position, a facing direction and an up
     /// direction
     ///
@@ -1291,6 +1519,118 @@ impl Mat4 {
         self.transform_vector3(rhs.into()).into()
     }
 
+    /// Returns the `x`, `y` and `z` components of each column splatted for transforming
+    /// vectors in [`Vec3x4`] layout.
+    #[inline(always)]
+    fn wide_axes3(&self) -> [Vec3x4; 4] {
+        [
+            Vec3x4::splat(self.x_axis.xyz()),
+            Vec3x4::splat(self.y_axis.xyz()),
+            Vec3x4::splat(self.z_axis.xyz()),
+            Vec3x4::splat(self.w_axis.xyz()),
+        ]
+    }
+
+    /// Transforms each point of `points` as if by [`Self::project_point3()`], writing the
+    /// results to `out`.
+    ///
+    /// The points are transformed four at a time in [`Vec3x4`] layout, giving the same results as
+    /// calling [`Self::project_point3()`] on each point without the per call overhead.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `points` and `out` are not the same length.
+    #[inline]
+    pub fn project_points3(&self, points: &[Vec3], out: &mut [Vec3]) {
+        let [x_axis, y_axis, z_axis, w_axis] = self.wide_axes3();
+        let [x_w, y_w, z_w, w_w] = self.row(3).to_array().map(Vec4::splat);
+        Vec3x4::map_slice(points, out, |v| {
+            let w = x_w * v.x + y_w * v.y + z_w * v.z + w_w;
+            (x_axis * v.x + y_axis * v.y + z_axis * v.z + w_axis) / w
+        });
+    }
+
+    /// Transforms each point of `points` in place as if by [`Self::project_point3()`].
+    ///
+    /// See [`Self::project_points3()`].
+    #[inline]
+    pub fn project_points3_in_place(&self, points: &mut [Vec3]) {
+        let [x_axis, y_axis, z_axis, w_axis] = self.wide_axes3();
+        let [x_w, y_w, z_w, w_w] = self.row(3).to_array().map(Vec4::splat);
+        Vec3x4::map_slice_in_place(points, |v| {
+            let w = x_w * v.x + y_w * v.y + z_w * v.z + w_w;
+            (x_axis * v.x + y_axis * v.y + z_axis * v.z + w_axis) / w
+        });
+    }
+
+    /// Transforms each point of `points` as if by [`Self::transform_point3()`], writing the
+    /// results to `out`.
+    ///
+    /// The points are transformed four at a time in [`Vec3x4`] layout, giving the same results as
+    /// calling [`Self::transform_point3()`] on each point without the per call overhead.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `points` and `out` are not the same length.
+    ///
+    /// Will panic if the 3rd row of `self` is not `(0, 0, 0, 1)` when `glam_assert` is enabled.
+    #[inline]
+    pub fn transform_points3(&self, points: &[Vec3], out: &mut [Vec3]) {
+        glam_assert!(self.row(3).abs_diff_eq(Vec4::W, 1e-6));
+        let [x_axis, y_axis, z_axis, w_axis] = self.wide_axes3();
+        Vec3x4::map_slice(points, out, |v| {
+            x_axis * v.x + y_axis * v.y + z_axis * v.z + w_axis
+        });
+    }
+
+    /// Transforms each point of `points` in place as if by [`Self::transform_point3()`].
+    ///
+    /// See [`Self::transform_points3()`].
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the 3rd row of `self` is not `(0, 0, 0, 1)` when `glam_assert` is enabled.
+    #[inline]
+    pub fn transform_points3_in_place(&self, points: &mut [Vec3]) {
+        glam_assert!(self.row(3).abs_diff_eq(Vec4::W, 1e-6));
+        let [x_axis, y_axis, z_axis, w_axis] = self.wide_axes3();
+        Vec3x4::map_slice_in_place(points, |v| {
+            x_axis * v.x + y_axis * v.y + z_axis * v.z + w_axis
+        });
+    }
+
+    /// Transforms each vector of `vectors` as if by [`Self::transform_vector3()`], writing the
+    /// results to `out`.
+    ///
+    /// The vectors are transformed four at a time in [`Vec3x4`] layout, giving the same results
+    /// as calling [`Self::transform_vector3()`] on each vector without the per call overhead.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `vectors` and `out` are not the same length.
+    ///
+    /// Will panic if the 3rd row of `self` is not `(0, 0, 0, 1)` when `glam_assert` is enabled.
+    #[inline]
+    pub fn transform_vectors3(&self, vectors: &[Vec3], out: &mut [Vec3]) {
+        glam_assert!(self.row(3).abs_diff_eq(Vec4::W, 1e-6));
+        let [x_axis, y_axis, z_axis, _] = self.wide_axes3();
+        Vec3x4::map_slice(vectors, out, |v| x_axis * v.x + y_axis * v.y + z_axis * v.z);
+    }
+
+    /// Transforms each vector of `vectors` in place as if by [`Self::transform_vector3()`].
+    ///
+    /// See [`Self::transform_vectors3()`].
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the 3rd row of `self` is not `(0, 0, 0, 1)` when `glam_assert` is enabled.
+    #[inline]
+    pub fn transform_vectors3_in_place(&self, vectors: &mut [Vec3]) {
+        glam_assert!(self.row(3).abs_diff_eq(Vec4::W, 1e-6));
+        let [x_axis, y_axis, z_axis, _] = self.wide_axes3();
+        Vec3x4::map_slice_in_place(vectors, |v| x_axis * v.x + y_axis * v.y + z_axis * v.z);
+    }
+
     /// Transforms a 4D vector.
     #[inline]
     #[must_use]
@@ -9,6 +9,12 @@ use core::{f32, ops::*};
 #[cfg(feature = "zerocopy")]
 use zerocopy_derive::*;
 
+// `ln(2)` and `pi / 2` split into a leading part with few enough significant bits that
+// multiplying it by the integers used in range reduction is exact, followed by the remainder.
+const LN_2_HI: f32 = 0.693_359_4;
+const LN_2_LO: f32 = -2.121_944_4e-4;
+const FRAC_PI_2_PARTS: [f32; 4] = [1.570_312_5, 4.837_513e-4, 7.549_533_6e-8, 2.563_344e-12];
+
 /// Creates a 3-dimensional vector.
 #[inline(always)]
 #[must_use]
@@ -861,12 +867,29 @@ impl Vec3A {
 
     /// Returns a vector containing `e^self` (the exponential function) for each element of
     /// `self`.
+    ///
+    /// See [`Self::fast_exp()`] for a faster but less accurate version.
     #[inline]
     #[must_use]
     pub fn exp(self) -> Self {
         Self::new(math::exp(self.x), math::exp(self.y), math::exp(self.z))
     }
 
+    /// Returns a vector containing an approximation of `e^self` for each element of `self`.
+    ///
+    /// This is faster than [`Self::exp()`] and has a maximum error of 70 ULP. Elements are
+    /// clamped to `[-87.3, 88.3]` so results saturate instead of reaching zero or infinity. The
+    /// result for NaN elements is unspecified.
+    #[inline]
+    #[must_use]
+    pub fn fast_exp(self) -> Self {
+        let (q, r) = self
+            .clamp(Self::splat(-87.3), Self::splat(88.3))
+            .reduce_ln_2();
+        let p = Self::polynomial(r, &[4.127_774_8e-2, 0.167_535_14, 0.500_051_14]);
+        (p * (r * r) + r + 1.0) * q.pow2i()
+    }
+
     /// Returns a vector containing `2^self` for each element of `self`.
     #[inline]
     #[must_use]
@@ -876,12 +899,36 @@ impl Vec3A {
 
     /// Returns a vector containing the natural logarithm for each element of `self`.
     /// This returns NaN when the element is negative and negative infinity when the element is zero.
+    ///
+    /// See [`Self::fast_ln()`] for a faster but less accurate version.
     #[inline]
     #[must_use]
     pub fn ln(self) -> Self {
         Self::new(math::ln(self.x), math::ln(self.y), math::ln(self.z))
     }
 
+    /// Returns a vector containing an approximation of the natural logarithm for each element of
+    /// `self`.
+    ///
+    /// This is faster than [`Self::ln()`] and has a maximum error of 26 ULP. The result for
+    /// elements that are not positive and finite is unspecified.
+    #[inline]
+    #[must_use]
+    pub fn fast_ln(self) -> Self {
+        let (t, e) = self.reduce_log();
+        Self::ln_reduced(
+            t,
+            e,
+            &[
+                0.117_818_96,
+                -0.184_071_9,
+                0.204_421_88,
+                -0.249_438_33,
+                0.333_208_62,
+            ],
+        )
+    }
+
     /// Returns a vector containing the base 2 logarithm for each element of `self`.
     /// This returns NaN when the element is negative and negative infinity when the element is zero.
     #[inline]
@@ -891,6 +938,8 @@ impl Vec3A {
     }
 
     /// Returns a vector containing each element of `self` raised to the power of `n`.
+    ///
+    /// See [`Self::fast_powf()`] for a faster but less accurate version.
     #[inline]
     #[must_use]
     pub fn powf(self, n: f32) -> Self {
@@ -901,6 +950,18 @@ impl Vec3A {
         )
     }
 
+    /// Returns a vector containing an approximation of each element of `self` raised to the
+    /// power of `n`, computed as `(self.fast_ln() * n).fast_exp()`.
+    ///
+    /// This is faster than [`Self::powf()`]. The relative error is below
+    /// `5e-6 * (1 + |n * ln(self)|)` for results in the normal range. The result for elements
+    /// that are not positive and finite is unspecified.
+    #[inline]
+    #[must_use]
+    pub fn fast_powf(self, n: f32) -> Self {
+        (self.fast_ln() * n).fast_exp()
+    }
+
     /// Returns a vector containing the square root for each element of `self`.
     /// This returns NaN when the element is negative.
     #[inline]
@@ -924,6 +985,8 @@ impl Vec3A {
     }
 
     /// Returns a tuple of two vectors containing the sine and cosine for each element of `self`.
+    ///
+    /// See [`Self::fast_sin_cos()`] for a faster but less accurate version.
     #[inline]
     #[must_use]
     pub fn sin_cos(self) -> (Self, Self) {
@@ -937,6 +1000,142 @@ impl Vec3A {
         )
     }
 
+    /// Returns a vector containing an approximation of the cosine for each element of `self`.
+    ///
+    /// See [`Self::fast_sin_cos()`] for accuracy.
+    #[inline]
+    #[must_use]
+    pub fn fast_cos(self) -> Self {
+        self.fast_sin_cos().1
+    }
+
+    /// Returns a vector containing an approximation of the sine for each element of `self`.
+    ///
+    /// See [`Self::fast_sin_cos()`] for accuracy.
+    #[inline]
+    #[must_use]
+    pub fn fast_sin(self) -> Self {
+        self.fast_sin_cos().0
+    }
+
+    /// Returns a tuple of two vectors containing an approximation of the sine and cosine for
+    /// each element of `self`.
+    ///
+    /// This is faster than [`Self::sin_cos()`] and has a maximum error of 27 ULP for elements
+    /// with a magnitude of at most 8192. The result for larger and non-finite elements is
+    /// unspecified.
+    #[inline]
+    #[must_use]
+    pub fn fast_sin_cos(self) -> (Self, Self) {
+        self.sin_cos_reduced(
+            &[8.163_282e-3, -0.166_633_9],
+            &[-1.364_871_4e-3, 4.166_107_3e-2],
+        )
+    }
+
+    /// Rounds each element to the nearest integer with ties to even. Only valid for elements
+    /// with a magnitude below `2^22`.
+    #[inline]
+    fn round_small(self) -> Self {
+        const ROUND_MAGIC: f32 = 12_582_912.0; // 1.5 * 2^23
+        self + ROUND_MAGIC - ROUND_MAGIC
+    }
+
+    /// Returns `2^self` for elements that are integers in `-126..=127`.
+    #[inline]
+    fn pow2i(self) -> Self {
+        Self::new(
+            f32::from_bits(((self.x as i32).wrapping_add(127) << 23) as u32),
+            f32::from_bits(((self.y as i32).wrapping_add(127) << 23) as u32),
+            f32::from_bits(((self.z as i32).wrapping_add(127) << 23) as u32),
+        )
+    }
+
+    /// Splits positive normal elements into a mantissa in `[1, 2)` and an unbiased exponent.
+    #[inline]
+    fn mantissa_exponent(self) -> (Self, Self) {
+        let x = self.x.to_bits();
+        let y = self.y.to_bits();
+        let z = self.z.to_bits();
+        (
+            Self::new(
+                f32::from_bits((x & 0x007f_ffff) | 0x3f80_0000),
+                f32::from_bits((y & 0x007f_ffff) | 0x3f80_0000),
+                f32::from_bits((z & 0x007f_ffff) | 0x3f80_0000),
+            ),
+            Self::new(
+                ((x >> 23) as i32 - 127) as f32,
+                ((y >> 23) as i32 - 127) as f32,
+                ((z >> 23) as i32 - 127) as f32,
+            ),
+        )
+    }
+
+    /// Evaluates the polynomial with the given coefficients, highest degree first, at `x`.
+    #[inline]
+    fn polynomial<const N: usize>(x: Self, coefficients: &[f32; N]) -> Self {
+        let mut p = Self::splat(coefficients[0]);
+        for c in &coefficients[1..] {
+            p = p * x + *c;
+        }
+        p
+    }
+
+    /// Splits elements into `q * ln(2) + r` with an integer `q` and `|r| <= ln(2) / 2`.
+    #[inline]
+    fn reduce_ln_2(self) -> (Self, Self) {
+        let q = (self * f32::consts::LOG2_E).round_small();
+        (q, self - q * LN_2_HI - q * LN_2_LO)
+    }
+
+    /// Splits positive finite elements into `2^e * (1 + t)` with `sqrt(0.5) <= 1 + t < sqrt(2)`.
+    #[inline]
+    fn reduce_log(self) -> (Self, Self) {
+        // scale subnormals up so they have an exponent
+        let subnormal = self.cmplt(Self::splat(f32::MIN_POSITIVE));
+        let (m, e) = Self::select(subnormal, self * 33_554_432.0, self).mantissa_exponent();
+        let e = Self::select(subnormal, e - 25.0, e);
+        let high = m.cmpgt(Self::splat(f32::consts::SQRT_2));
+        (
+            Self::select(high, m * 0.5, m) - 1.0,
+            Self::select(high, e + 1.0, e),
+        )
+    }
+
+    /// Returns `ln(2^e * (1 + t))` using the polynomial `p` for `(ln(1 + t) - t + t^2 / 2) / t^3`.
+    #[inline]
+    fn ln_reduced<const N: usize>(t: Self, e: Self, p: &[f32; N]) -> Self {
+        let z = t * t;
+        let y = Self::polynomial(t, p) * t * z + e * LN_2_LO - z * 0.5;
+        t + y + e * LN_2_HI
+    }
+
+    /// Returns the sine and cosine of elements with a magnitude of at most 8192 using the
+    /// polynomials `sin` for `(sin(r) - r) / r^3` and `cos` for `(cos(r) - 1 + r^2 / 2) / r^4`
+    /// on `[-pi/4, pi/4]`.
+    #[inline]
+    fn sin_cos_reduced<const S: usize, const C: usize>(
+        self,
+        sin: &[f32; S],
+        cos: &[f32; C],
+    ) -> (Self, Self) {
+        let q = (self * f32::consts::FRAC_2_PI).round_small();
+        let [pi_2_0, pi_2_1, pi_2_2, pi_2_3] = FRAC_PI_2_PARTS;
+        let r = self - q * pi_2_0 - q * pi_2_1 - q * pi_2_2 - q * pi_2_3;
+        let z = r * r;
+        let s = Self::polynomial(z, sin) * z * r + r;
+        let c = Self::polynomial(z, cos) * z * z - z * 0.5 + 1.0;
+        // the quadrant `q mod 4` swaps and negates the results
+        let quadrant = q - (q * 0.25 - 0.375).round_small() * 4.0;
+        let odd = quadrant.cmpeq(Self::ONE) | quadrant.cmpeq(Self::splat(3.0));
+        let (s, c) = (Self::select(odd, c, s), Self::select(odd, s, c));
+        let negate_cos = quadrant.cmpeq(Self::ONE) | quadrant.cmpeq(Self::splat(2.0));
+        (
+            Self::select(quadrant.cmpge(Self::splat(2.0)), -s, s),
+            Self::select(negate_cos, -c, c),
+        )
+    }
+
     /// Returns a vector containing the reciprocal `1.0/n` of each element of `self`.
     #[inline]
     #[must_use]
@@ -1293,6 +1492,18 @@ impl Vec3A {
         crate::DVec3::new(self.x as f64, self.y as f64, self.z as f64)
     }
 
+    /// Converts all elements of `self` to half-precision, rounding to the nearest representable
+    /// value with ties to even.
+    #[inline]
+    #[must_use]
+    pub fn as_hvec3(self) -> crate::HVec3 {
+        crate::HVec3::new(
+            crate::F16::from_f32(self.x),
+            crate::F16::from_f32(self.y),
+            crate::F16::from_f32(self.z),
+        )
+    }
+
     /// Casts all elements of `self` to `i8`.
     #[inline]
     #[must_use]
@@ -14,6 +14,12 @@ use core::arch::x86_64::*;
 #[cfg(feature = "zerocopy")]
 use zerocopy_derive::*;
 
+// `ln(2)` and `pi / 2` split into a leading part with few enough significant bits that
+// multiplying it by the integers used in range reduction is exact, followed by the remainder.
+const LN_2_HI: f32 = 0.693_359_4;
+const LN_2_LO: f32 = -2.121_944_4e-4;
+const FRAC_PI_2_PARTS: [f32; 4] = [1.570_312_5, 4.837_513e-4, 7.549_533_6e-8, 2.563_344e-12];
+
 #[repr(C)]
 union UnionCast {
     a: [f32; 4],
@@ -649,13 +655,20 @@ impl Vec3A {
     #[inline]
     #[must_use]
     pub fn normalize(self) -> Self {
-        unsafe {
-            let length = _mm_sqrt_ps(dot3_into_m128(self.0, self.0));
-            #[allow(clippy::let_and_return)]
-            let normalized = Self(_mm_div_ps(self.0, length));
-            glam_assert!(normalized.is_finite());
-            normalized
-        }
+        #[cfg(not(feature = "deterministic"))]
+        #[allow(clippy::let_and_return)]
+        let normalized = {
+            unsafe {
+                let length = _mm_sqrt_ps(dot3_into_m128(self.0, self.0));
+                Self(_mm_div_ps(self.0, length))
+            }
+        };
+        // Dividing by the length doesn't round the same as the scalar implementation.
+        #[cfg(feature = "deterministic")]
+        #[allow(clippy::let_and_return)]
+        let normalized = self.mul(self.length_recip());
+        glam_assert!(normalized.is_finite());
+        normalized
     }
 
     /// Returns `self` normalized to length 1.0 if possible, else returns `None`.
@@ -866,10 +879,56 @@ impl Vec3A {
 
     /// Returns a vector containing `e^self` (the exponential function) for each element of
     /// `self`.
+    ///
+    /// This uses a SIMD polynomial approximation with a maximum error of 1 ULP, or the scalar
+    /// implementation when the `deterministic` feature is enabled. See [`Self::fast_exp()`] for a
+    /// faster but less accurate version.
     #[inline]
     #[must_use]
     pub fn exp(self) -> Self {
-        Self::new(math::exp(self.x), math::exp(self.y), math::exp(self.z))
+        #[cfg(not(feature = "deterministic"))]
+        {
+            let (q, r) = self.reduce_ln_2();
+            // Cephes `expf` polynomial
+            let p = Self::polynomial(
+                r,
+                &[
+                    1.987_569_1e-4,
+                    1.398_199_9e-3,
+                    8.333_452e-3,
+                    4.166_579_6e-2,
+                    0.166_666_66,
+                    0.5,
+                ],
+            );
+            let y = p * (r * r) + r + 1.0;
+            // scale by `2^q` in two steps so the result can become subnormal without `2^q`
+            // underflowing first
+            let q_half = (q * 0.5 - 0.25).round_small();
+            let y = y * q_half.pow2i() * (q - q_half).pow2i();
+            // beyond these limits `q` is out of the range supported by `pow2i`
+            let y = Self::select(self.cmpgt(Self::splat(89.0)), Self::INFINITY, y);
+            Self::select(self.cmplt(Self::splat(-104.0)), Self::ZERO, y)
+        }
+        #[cfg(feature = "deterministic")]
+        {
+            Self::new(math::exp(self.x), math::exp(self.y), math::exp(self.z))
+        }
+    }
+
+    /// Returns a vector containing an approximation of `e^self` for each element of `self`.
+    ///
+    /// This is faster than [`Self::exp()`] and has a maximum error of 70 ULP. Elements are
+    /// clamped to `[-87.3, 88.3]` so results saturate instead of reaching zero or infinity. The
+    /// result for NaN elements is unspecified.
+    #[inline]
+    #[must_use]
+    pub fn fast_exp(self) -> Self {
+        let (q, r) = self
+            .clamp(Self::splat(-87.3), Self::splat(88.3))
+            .reduce_ln_2();
+        let p = Self::polynomial(r, &[4.127_774_8e-2, 0.167_535_14, 0.500_051_14]);
+        (p * (r * r) + r + 1.0) * q.pow2i()
     }
 
     /// Returns a vector containing `2^self` for each element of `self`.
@@ -881,10 +940,62 @@ impl Vec3A {
 
     /// Returns a vector containing the natural logarithm for each element of `self`.
     /// This returns NaN when the element is negative and negative infinity when the element is zero.
+    ///
+    /// This uses a SIMD polynomial approximation with a maximum error of 1 ULP, or the scalar
+    /// implementation when the `deterministic` feature is enabled. See [`Self::fast_ln()`] for a
+    /// faster but less accurate version.
     #[inline]
     #[must_use]
     pub fn ln(self) -> Self {
-        Self::new(math::ln(self.x), math::ln(self.y), math::ln(self.z))
+        #[cfg(not(feature = "deterministic"))]
+        {
+            let (t, e) = self.reduce_log();
+            // Cephes `logf` polynomial
+            let y = Self::ln_reduced(
+                t,
+                e,
+                &[
+                    7.037_683_6e-2,
+                    -0.115_146_1,
+                    0.116_769_984,
+                    -0.124_201_41,
+                    0.142_493_23,
+                    -0.166_680_57,
+                    0.200_007_14,
+                    -0.249_999_94,
+                    0.333_333_3,
+                ],
+            );
+            let y = Self::select(self.cmpeq(Self::INFINITY), Self::INFINITY, y);
+            let y = Self::select(self.cmpeq(Self::ZERO), Self::NEG_INFINITY, y);
+            Self::select(self.cmplt(Self::ZERO) | self.is_nan_mask(), Self::NAN, y)
+        }
+        #[cfg(feature = "deterministic")]
+        {
+            Self::new(math::ln(self.x), math::ln(self.y), math::ln(self.z))
+        }
+    }
+
+    /// Returns a vector containing an approximation of the natural logarithm for each element of
+    /// `self`.
+    ///
+    /// This is faster than [`Self::ln()`] and has a maximum error of 26 ULP. The result for
+    /// elements that are not positive and finite is unspecified.
+    #[inline]
+    #[must_use]
+    pub fn fast_ln(self) -> Self {
+        let (t, e) = self.reduce_log();
+        Self::ln_reduced(
+            t,
+            e,
+            &[
+                0.117_818_96,
+                -0.184_071_9,
+                0.204_421_88,
+                -0.249_438_33,
+                0.333_208_62,
+            ],
+        )
     }
 
     /// Returns a vector containing the base 2 logarithm for each element of `self`.
@@ -896,14 +1007,97 @@ impl Vec3A {
     }
 
     /// Returns a vector containing each element of `self` raised to the power of `n`.
+    ///
+    /// Elements with a positive finite base are evaluated with SIMD operations in double precision
+    /// and have a maximum error of 1 ULP. Other elements, all elements when `n` is not finite and
+    /// all elements when the `deterministic` feature is enabled use the scalar implementation. See
+    /// [`Self::fast_powf()`] for a faster but less accurate version.
     #[inline]
     #[must_use]
     pub fn powf(self, n: f32) -> Self {
-        Self::new(
-            math::powf(self.x, n),
-            math::powf(self.y, n),
-            math::powf(self.z, n),
-        )
+        #[cfg(not(feature = "deterministic"))]
+        {
+            // elements with a non-positive or non-finite base and non-finite exponents use the
+            // scalar implementation
+            let in_range = if n.is_finite() {
+                self.cmpgt(Self::ZERO) & self.is_finite_mask()
+            } else {
+                BVec3A::FALSE
+            };
+            // `2^(n * log2(self))` with `self = 2^e * (1 + t)` and
+            // `ln(1 + t) = 2 * atanh(t / (t + 2))`
+            let (t, e) = Self::select(in_range, self, Self::ONE).reduce_log();
+            let t = t.as_dvec3();
+            let s = t / (t + 2.0);
+            let z = s * s;
+            // `atanh(s) / s` evaluated with Estrin's scheme to shorten the dependency chain
+            let z2 = z * z;
+            let atanh = (z * (1.0 / 3.0) + 1.0)
+                + z2 * (z * (1.0 / 7.0) + 1.0 / 5.0)
+                + z2 * z2 * ((z * (1.0 / 11.0) + 1.0 / 9.0) + z2 * (1.0 / 13.0));
+            let log2 = e.as_dvec3() + atanh * s * (2.0 * core::f64::consts::LOG2_E);
+            // clamped to where the result is zero or infinity
+            let y =
+                (log2 * n as f64).clamp(crate::DVec3::splat(-160.0), crate::DVec3::splat(130.0));
+            // `2^y = 2^k * 2^f` with an integer `k` and `|f| <= 0.5`
+            const ROUND_MAGIC: f64 = 6_755_399_441_055_744.0; // 1.5 * 2^52
+            let k = y + ROUND_MAGIC - ROUND_MAGIC;
+            let f = y - k;
+            // Taylor series of `2^f` up to `f^9`, also evaluated with Estrin's scheme
+            const C1: f64 = core::f64::consts::LN_2;
+            const C2: f64 = C1 * C1 / 2.0;
+            const C3: f64 = C2 * C1 / 3.0;
+            const C4: f64 = C3 * C1 / 4.0;
+            const C5: f64 = C4 * C1 / 5.0;
+            const C6: f64 = C5 * C1 / 6.0;
+            const C7: f64 = C6 * C1 / 7.0;
+            const C8: f64 = C7 * C1 / 8.0;
+            const C9: f64 = C8 * C1 / 9.0;
+            let f2 = f * f;
+            let f4 = f2 * f2;
+            let exp2 = (f * C1 + 1.0)
+                + f2 * (f * C3 + C2)
+                + f4 * ((f * C5 + C4) + f2 * (f * C7 + C6))
+                + f4 * f4 * (f * C9 + C8);
+            // `2^f` is rounded to single precision before scaling, which is exact unless the
+            // result is subnormal
+            let k = Self::from(k.as_vec3());
+            let exp2 = Self::from(exp2.as_vec3());
+            let k_half = (k * 0.5 - 0.25).round_small();
+            let y = exp2 * k_half.pow2i() * (k - k_half).pow2i();
+            if in_range.all() {
+                return y;
+            }
+            Self::select(
+                in_range,
+                y,
+                Self::new(
+                    math::powf(self.x, n),
+                    math::powf(self.y, n),
+                    math::powf(self.z, n),
+                ),
+            )
+        }
+        #[cfg(feature = "deterministic")]
+        {
+            Self::new(
+                math::powf(self.x, n),
+                math::powf(self.y, n),
+                math::powf(self.z, n),
+            )
+        }
+    }
+
+    /// Returns a vector containing an approximation of each element of `self` raised to the
+    /// power of `n`, computed as `(self.fast_ln() * n).fast_exp()`.
+    ///
+    /// This is faster than [`Self::powf()`]. The relative error is below
+    /// `5e-6 * (1 + |n * ln(self)|)` for results in the normal range. The result for elements
+    /// that are not positive and finite is unspecified.
+    #[inline]
+    #[must_use]
+    pub fn fast_powf(self, n: f32) -> Self {
+        (self.fast_ln() * n).fast_exp()
     }
 
     /// Returns a vector containing the square root for each element of `self`.
@@ -915,30 +1109,209 @@ impl Vec3A {
     }
 
     /// Returns a vector containing the cosine for each element of `self`.
+    ///
+    /// See [`Self::sin_cos()`] for accuracy.
     #[inline]
     #[must_use]
     pub fn cos(self) -> Self {
-        Self::new(math::cos(self.x), math::cos(self.y), math::cos(self.z))
+        #[cfg(not(feature = "deterministic"))]
+        {
+            self.sin_cos().1
+        }
+        #[cfg(feature = "deterministic")]
+        {
+            Self::new(math::cos(self.x), math::cos(self.y), math::cos(self.z))
+        }
     }
 
     /// Returns a vector containing the sine for each element of `self`.
+    ///
+    /// See [`Self::sin_cos()`] for accuracy.
     #[inline]
     #[must_use]
     pub fn sin(self) -> Self {
-        Self::new(math::sin(self.x), math::sin(self.y), math::sin(self.z))
+        #[cfg(not(feature = "deterministic"))]
+        {
+            self.sin_cos().0
+        }
+        #[cfg(feature = "deterministic")]
+        {
+            Self::new(math::sin(self.x), math::sin(self.y), math::sin(self.z))
+        }
     }
 
     /// Returns a tuple of two vectors containing the sine and cosine for each element of `self`.
+    ///
+    /// Elements with a magnitude of at most 8192 use a SIMD polynomial approximation with a
+    /// maximum error of 2.5 ULP. Larger and non-finite elements, and all elements when the
+    /// `deterministic` feature is enabled, use the scalar implementation. See
+    /// [`Self::fast_sin_cos()`] for a faster but less accurate version.
     #[inline]
     #[must_use]
     pub fn sin_cos(self) -> (Self, Self) {
-        let (sin_x, cos_x) = math::sin_cos(self.x);
-        let (sin_y, cos_y) = math::sin_cos(self.y);
-        let (sin_z, cos_z) = math::sin_cos(self.z);
+        #[cfg(not(feature = "deterministic"))]
+        {
+            // Cephes `sinf` and `cosf` polynomials
+            let (sin, cos) = self.sin_cos_reduced(
+                &[-1.951_529_6e-4, 8.332_161e-3, -0.166_666_55],
+                &[2.443_315_7e-5, -1.388_731_6e-3, 4.166_664_6e-2],
+            );
+            let in_range = self.abs().cmple(Self::splat(8192.0));
+            if in_range.all() {
+                return (sin, cos);
+            }
+
+            let (sin_x, cos_x) = math::sin_cos(self.x);
+            let (sin_y, cos_y) = math::sin_cos(self.y);
+            let (sin_z, cos_z) = math::sin_cos(self.z);
+            (
+                Self::select(in_range, sin, Self::new(sin_x, sin_y, sin_z)),
+                Self::select(in_range, cos, Self::new(cos_x, cos_y, cos_z)),
+            )
+        }
+        #[cfg(feature = "deterministic")]
+        {
+            let (sin_x, cos_x) = math::sin_cos(self.x);
+            let (sin_y, cos_y) = math::sin_cos(self.y);
+            let (sin_z, cos_z) = math::sin_cos(self.z);
+            (
+                Self::new(sin_x, sin_y, sin_z),
+                Self::new(cos_x, cos_y, cos_z),
+            )
+        }
+    }
 
+    /// Returns a vector containing an approximation of the cosine for each element of `self`.
+    ///
+    /// See [`Self::fast_sin_cos()`] for accuracy.
+    #[inline]
+    #[must_use]
+    pub fn fast_cos(self) -> Self {
+        self.fast_sin_cos().1
+    }
+
+    /// Returns a vector containing an approximation of the sine for each element of `self`.
+    ///
+    /// See [`Self::fast_sin_cos()`] for accuracy.
+    #[inline]
+    #[must_use]
+    pub fn fast_sin(self) -> Self {
+        self.fast_sin_cos().0
+    }
+
+    /// Returns a tuple of two vectors containing an approximation of the sine and cosine for
+    /// each element of `self`.
+    ///
+    /// This is faster than [`Self::sin_cos()`] and has a maximum error of 27 ULP for elements
+    /// with a magnitude of at most 8192. The result for larger and non-finite elements is
+    /// unspecified.
+    #[inline]
+    #[must_use]
+    pub fn fast_sin_cos(self) -> (Self, Self) {
+        self.sin_cos_reduced(
+            &[8.163_282e-3, -0.166_633_9],
+            &[-1.364_871_4e-3, 4.166_107_3e-2],
+        )
+    }
+
+    /// Rounds each element to the nearest integer with ties to even. Only valid for elements
+    /// with a magnitude below `2^22`.
+    #[inline]
+    fn round_small(self) -> Self {
+        const ROUND_MAGIC: f32 = 12_582_912.0; // 1.5 * 2^23
+        self + ROUND_MAGIC - ROUND_MAGIC
+    }
+
+    /// Returns `2^self` for elements that are integers in `-126..=127`.
+    #[inline]
+    fn pow2i(self) -> Self {
+        Self(unsafe {
+            _mm_castsi128_ps(_mm_slli_epi32(
+                _mm_add_epi32(_mm_cvttps_epi32(self.0), _mm_set1_epi32(127)),
+                23,
+            ))
+        })
+    }
+
+    /// Splits positive normal elements into a mantissa in `[1, 2)` and an unbiased exponent.
+    #[inline]
+    fn mantissa_exponent(self) -> (Self, Self) {
+        unsafe {
+            let bits = _mm_castps_si128(self.0);
+            let mantissa = _mm_or_si128(
+                _mm_and_si128(bits, _mm_set1_epi32(0x007f_ffff)),
+                _mm_set1_epi32(0x3f80_0000),
+            );
+            let exponent = _mm_sub_epi32(_mm_srli_epi32(bits, 23), _mm_set1_epi32(127));
+            (
+                Self(_mm_castsi128_ps(mantissa)),
+                Self(_mm_cvtepi32_ps(exponent)),
+            )
+        }
+    }
+
+    /// Evaluates the polynomial with the given coefficients, highest degree first, at `x`.
+    #[inline]
+    fn polynomial<const N: usize>(x: Self, coefficients: &[f32; N]) -> Self {
+        let mut p = Self::splat(coefficients[0]);
+        for c in &coefficients[1..] {
+            p = p * x + *c;
+        }
+        p
+    }
+
+    /// Splits elements into `q * ln(2) + r` with an integer `q` and `|r| <= ln(2) / 2`.
+    #[inline]
+    fn reduce_ln_2(self) -> (Self, Self) {
+        let q = (self * f32::consts::LOG2_E).round_small();
+        (q, self - q * LN_2_HI - q * LN_2_LO)
+    }
+
+    /// Splits positive finite elements into `2^e * (1 + t)` with `sqrt(0.5) <= 1 + t < sqrt(2)`.
+    #[inline]
+    fn reduce_log(self) -> (Self, Self) {
+        // scale subnormals up so they have an exponent
+        let subnormal = self.cmplt(Self::splat(f32::MIN_POSITIVE));
+        let (m, e) = Self::select(subnormal, self * 33_554_432.0, self).mantissa_exponent();
+        let e = Self::select(subnormal, e - 25.0, e);
+        let high = m.cmpgt(Self::splat(f32::consts::SQRT_2));
+        (
+            Self::select(high, m * 0.5, m) - 1.0,
+            Self::select(high, e + 1.0, e),
+        )
+    }
+
+    /// Returns `ln(2^e * (1 + t))` using the polynomial `p` for `(ln(1 + t) - t + t^2 / 2) / t^3`.
+    #[inline]
+    fn ln_reduced<const N: usize>(t: Self, e: Self, p: &[f32; N]) -> Self {
+        let z = t * t;
+        let y = Self::polynomial(t, p) * t * z + e * LN_2_LO - z * 0.5;
+        t + y + e * LN_2_HI
+    }
+
+    /// Returns the sine and cosine of elements with a magnitude of at most 8192 using the
+    /// polynomials `sin` for `(sin(r) - r) / r^3` and `cos` for `(cos(r) - 1 + r^2 / 2) / r^4`
+    /// on `[-pi/4, pi/4]`.
+    #[inline]
+    fn sin_cos_reduced<const S: usize, const C: usize>(
+        self,
+        sin: &[f32; S],
+        cos: &[f32; C],
+    ) -> (Self, Self) {
+        let q = (self * f32::consts::FRAC_2_PI).round_small();
+        let [pi_2_0, pi_2_1, pi_2_2, pi_2_3] = FRAC_PI_2_PARTS;
+        let r = self - q * pi_2_0 - q * pi_2_1 - q * pi_2_2 - q * pi_2_3;
+        let z = r * r;
+        let s = Self::polynomial(z, sin) * z * r + r;
+        let c = Self::polynomial(z, cos) * z * z - z * 0.5 + 1.0;
+        // the quadrant `q mod 4` swaps and negates the results
+        let quadrant = q - (q * 0.25 - 0.375).round_small() * 4.0;
+        let odd = quadrant.cmpeq(Self::ONE) | quadrant.cmpeq(Self::splat(3.0));
+        let (s, c) = (Self::select(odd, c, s), Self::select(odd, s, c));
+        let negate_cos = quadrant.cmpeq(Self::ONE) | quadrant.cmpeq(Self::splat(2.0));
         (
-            Self::new(sin_x, sin_y, sin_z),
-            Self::new(cos_x, cos_y, cos_z),
+            Self::select(quadrant.cmpge(Self::splat(2.0)), -s, s),
+            Self::select(negate_cos, -c, c),
         )
     }
 
@@ -1299,6 +1672,18 @@ impl Vec3A {
         crate::DVec3::new(self.x as f64, self.y as f64, self.z as f64)
     }
 
+    /// Converts all elements of `self` to half-precision, rounding to the nearest representable
+    /// value with ties to even.
+    #[inline]
+    #[must_use]
+    pub fn as_hvec3(self) -> crate::HVec3 {
+        crate::HVec3::new(
+            crate::F16::from_f32(self.x),
+            crate::F16::from_f32(self.y),
+            crate::F16::from_f32(self.z),
+        )
+    }
+
     /// Casts all elements of `self` to `i8`.
     #[inline]
     #[must_use]
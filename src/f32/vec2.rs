@@ -195,6 +195,46 @@ impl Vec2 {
         Self::splat(self.dot(rhs))
     }
 
+    /// Returns `self + rhs`.
+    ///
+    /// Unlike the [`Add`] implementation this can be called in a `const` context.
+    #[cfg(feature = "const-float-arithmetic")]
+    #[inline]
+    #[must_use]
+    pub const fn const_add(self, rhs: Self) -> Self {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+
+    /// Returns `self - rhs`.
+    ///
+    /// Unlike the [`Sub`] implementation this can be called in a `const` context.
+    #[cfg(feature = "const-float-arithmetic")]
+    #[inline]
+    #[must_use]
+    pub const fn const_sub(self, rhs: Self) -> Self {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+
+    /// Returns `self * rhs`, multiplying each element of `self` by the same element of `rhs`.
+    ///
+    /// Unlike the [`Mul`] implementation this can be called in a `const` context.
+    #[cfg(feature = "const-float-arithmetic")]
+    #[inline]
+    #[must_use]
+    pub const fn const_mul(self, rhs: Self) -> Self {
+        Self::new(self.x * rhs.x, self.y * rhs.y)
+    }
+
+    /// Computes the dot product of `self` and `rhs` in a `const` context.
+    ///
+    /// This gives the same result as [`Self::dot()`].
+    #[cfg(feature = "const-float-arithmetic")]
+    #[inline]
+    #[must_use]
+    pub const fn const_dot(self, rhs: Self) -> f32 {
+        (self.x * rhs.x) + (self.y * rhs.y)
+    }
+
     /// Returns a vector containing the minimum values for each element of `self` and `rhs`.
     ///
     /// In other words this computes `[min(x, rhs.x), min(self.y, rhs.y), ..]`.
@@ -1087,6 +1127,14 @@ impl Vec2 {
         crate::DVec2::new(self.x as f64, self.y as f64)
     }
 
+    /// Converts all elements of `self` to half-precision, rounding to the nearest representable
+    /// value with ties to even.
+    #[inline]
+    #[must_use]
+    pub fn as_hvec2(self) -> crate::HVec2 {
+        crate::HVec2::new(crate::F16::from_f32(self.x), crate::F16::from_f32(self.y))
+    }
+
     /// Casts all elements of `self` to `i8`.
     #[inline]
     #[must_use]
@@ -269,6 +269,60 @@ impl Vec3 {
         }
     }
 
+    /// Returns `self + rhs`.
+    ///
+    /// Unlike the [`Add`] implementation this can be called in a `const` context.
+    #[cfg(feature = "const-float-arithmetic")]
+    #[inline]
+    #[must_use]
+    pub const fn const_add(self, rhs: Self) -> Self {
+        Self::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+
+    /// Returns `self - rhs`.
+    ///
+    /// Unlike the [`Sub`] implementation this can be called in a `const` context.
+    #[cfg(feature = "const-float-arithmetic")]
+    #[inline]
+    #[must_use]
+    pub const fn const_sub(self, rhs: Self) -> Self {
+        Self::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+
+    /// Returns `self * rhs`, multiplying each element of `self` by the same element of `rhs`.
+    ///
+    /// Unlike the [`Mul`] implementation this can be called in a `const` context.
+    #[cfg(feature = "const-float-arithmetic")]
+    #[inline]
+    #[must_use]
+    pub const fn const_mul(self, rhs: Self) -> Self {
+        Self::new(self.x * rhs.x, self.y * rhs.y, self.z * rhs.z)
+    }
+
+    /// Computes the dot product of `self` and `rhs` in a `const` context.
+    ///
+    /// This gives the same result as [`Self::dot()`].
+    #[cfg(feature = "const-float-arithmetic")]
+    #[inline]
+    #[must_use]
+    pub const fn const_dot(self, rhs: Self) -> f32 {
+        (self.x * rhs.x) + (self.y * rhs.y) + (self.z * rhs.z)
+    }
+
+    /// Computes the cross product of `self` and `rhs` in a `const` context.
+    ///
+    /// This gives the same result as [`Self::cross()`].
+    #[cfg(feature = "const-float-arithmetic")]
+    #[inline]
+    #[must_use]
+    pub const fn const_cross(self, rhs: Self) -> Self {
+        Self::new(
+            self.y * rhs.z - rhs.y * self.z,
+            self.z * rhs.x - rhs.z * self.x,
+            self.x * rhs.y - rhs.x * self.y,
+        )
+    }
+
     /// Returns a vector containing the minimum values for each element of `self` and `rhs`.
     ///
     /// In other words this computes `[min(x, rhs.x), min(self.y, rhs.y), ..]`.
@@ -1286,6 +1340,18 @@ impl Vec3 {
         crate::DVec3::new(self.x as f64, self.y as f64, self.z as f64)
     }
 
+    /// Converts all elements of `self` to half-precision, rounding to the nearest representable
+    /// value with ties to even.
+    #[inline]
+    #[must_use]
+    pub fn as_hvec3(self) -> crate::HVec3 {
+        crate::HVec3::new(
+            crate::F16::from_f32(self.x),
+            crate::F16::from_f32(self.y),
+            crate::F16::from_f32(self.z),
+        )
+    }
+
     /// Casts all elements of `self` to `i8`.
     #[inline]
     #[must_use]
@@ -0,0 +1,470 @@
+// Generated from vec3_wide.rs.tera template. Edit the template, not the generated file.
+
+#[cfg(feature = "scalar-math")]
+use crate::BVec4 as BVec4A;
+#[cfg(not(feature = "scalar-math"))]
+use crate::BVec4A;
+
+use crate::{Vec3, Vec3x4, Vec4};
+
+use core::ops::*;
+
+/// Eight 3-dimensional vectors stored in structure of arrays layout.
+///
+/// Each field holds one component of all eight vectors as two [`Vec4`] lane registers, the first
+/// containing vectors `0..4` and the second vectors `4..8`. Processing eight vectors at a time
+/// gives the CPU two independent chains of operations to interleave. Results are the same as
+/// calling the corresponding [`Vec3`] method on each vector.
+///
+/// Methods which return a scalar per vector, such as [`Self::dot()`], return a `[Vec4; 2]`
+/// holding one result per vector, and [`Self::select()`] takes a `[BVec4A; 2]` mask.
+///
+/// This type is 16 byte aligned.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(C)]
+pub struct Vec3x8 {
+    pub x: [Vec4; 2],
+    pub y: [Vec4; 2],
+    pub z: [Vec4; 2],
+}
+
+impl Vec3x8 {
+    /// All zeroes.
+    pub const ZERO: Self = Self::splat(Vec3::ZERO);
+
+    /// All ones.
+    pub const ONE: Self = Self::splat(Vec3::ONE);
+
+    /// All `f32::NAN`.
+    pub const NAN: Self = Self::splat(Vec3::NAN);
+
+    /// All vectors are a unit vector pointing along the positive X axis.
+    pub const X: Self = Self::splat(Vec3::X);
+
+    /// All vectors are a unit vector pointing along the positive Y axis.
+    pub const Y: Self = Self::splat(Vec3::Y);
+
+    /// All vectors are a unit vector pointing along the positive Z axis.
+    pub const Z: Self = Self::splat(Vec3::Z);
+
+    /// Creates 8 vectors from lane registers holding their `x`, `y` and `z` components.
+    #[inline(always)]
+    #[must_use]
+    pub const fn new(x: [Vec4; 2], y: [Vec4; 2], z: [Vec4; 2]) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Creates 8 copies of `v`.
+    #[inline]
+    #[must_use]
+    pub const fn splat(v: Vec3) -> Self {
+        let v = Vec3x4::splat(v);
+        Self::from_halves([v, v])
+    }
+
+    /// Creates eight vectors from two sets of four, the first holding vectors `0..4`.
+    #[inline]
+    #[must_use]
+    pub const fn from_halves(halves: [Vec3x4; 2]) -> Self {
+        let [lo, hi] = halves;
+        Self::new([lo.x, hi.x], [lo.y, hi.y], [lo.z, hi.z])
+    }
+
+    /// Splits `self` into two sets of four vectors, the first holding vectors `0..4`.
+    #[inline]
+    #[must_use]
+    pub const fn to_halves(self) -> [Vec3x4; 2] {
+        [
+            Vec3x4::new(self.x[0], self.y[0], self.z[0]),
+            Vec3x4::new(self.x[1], self.y[1], self.z[1]),
+        ]
+    }
+
+    #[inline(always)]
+    fn map(self, f: impl Fn(Vec3x4) -> Vec3x4) -> Self {
+        let [lo, hi] = self.to_halves();
+        Self::from_halves([f(lo), f(hi)])
+    }
+
+    #[inline(always)]
+    fn zip_map(self, rhs: Self, f: impl Fn(Vec3x4, Vec3x4) -> Vec3x4) -> Self {
+        let [lo, hi] = self.to_halves();
+        let [rhs_lo, rhs_hi] = rhs.to_halves();
+        Self::from_halves([f(lo, rhs_lo), f(hi, rhs_hi)])
+    }
+
+    #[inline(always)]
+    fn zip_lanes(self, rhs: Self, f: impl Fn(Vec3x4, Vec3x4) -> Vec4) -> [Vec4; 2] {
+        let [lo, hi] = self.to_halves();
+        let [rhs_lo, rhs_hi] = rhs.to_halves();
+        [f(lo, rhs_lo), f(hi, rhs_hi)]
+    }
+
+    #[inline(always)]
+    fn map_lanes(self, f: impl Fn(Vec3x4) -> Vec4) -> [Vec4; 2] {
+        let [lo, hi] = self.to_halves();
+        [f(lo), f(hi)]
+    }
+
+    /// Creates 8 vectors from an array, converting to structure of arrays layout.
+    #[inline]
+    #[must_use]
+    pub fn from_array(a: [Vec3; 8]) -> Self {
+        Self::from_slice(&a)
+    }
+
+    /// Converts `self` to an array of 8 vectors.
+    #[inline]
+    #[must_use]
+    pub fn to_array(self) -> [Vec3; 8] {
+        let mut a = [Vec3::ZERO; 8];
+        self.write_to_slice(&mut a);
+        a
+    }
+
+    /// Creates 8 vectors from the first 8 values in `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than 8 elements long.
+    #[inline]
+    #[must_use]
+    pub fn from_slice(slice: &[Vec3]) -> Self {
+        assert!(slice.len() >= 8);
+
+        Self::from_halves([
+            Vec3x4::from_slice(&slice[..4]),
+            Vec3x4::from_slice(&slice[4..]),
+        ])
+    }
+
+    /// Writes the 8 vectors of `self` to the first 8 elements in `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than 8 elements long.
+    #[inline]
+    pub fn write_to_slice(self, slice: &mut [Vec3]) {
+        assert!(slice.len() >= 8);
+
+        let [lo, hi] = self.to_halves();
+        lo.write_to_slice(&mut slice[..4]);
+        hi.write_to_slice(&mut slice[4..]);
+    }
+
+    /// Creates 8 vectors by choosing between the vectors of `if_true` and `if_false`
+    /// depending on the value of the corresponding lane of `mask`.
+    #[inline]
+    #[must_use]
+    pub fn select(mask: [BVec4A; 2], if_true: Self, if_false: Self) -> Self {
+        let [t_lo, t_hi] = if_true.to_halves();
+        let [f_lo, f_hi] = if_false.to_halves();
+        Self::from_halves([
+            Vec3x4::select(mask[0], t_lo, f_lo),
+            Vec3x4::select(mask[1], t_hi, f_hi),
+        ])
+    }
+
+    /// Computes the dot product of each vector of `self` and `rhs`.
+    #[inline]
+    #[must_use]
+    pub fn dot(self, rhs: Self) -> [Vec4; 2] {
+        self.zip_lanes(rhs, Vec3x4::dot)
+    }
+
+    /// Computes the cross product of each vector of `self` and `rhs`.
+    #[inline]
+    #[must_use]
+    pub fn cross(self, rhs: Self) -> Self {
+        self.zip_map(rhs, Vec3x4::cross)
+    }
+
+    /// Returns the element-wise minimum of each vector of `self` and `rhs`.
+    ///
+    /// NaN propogation does not follow IEEE 754-2008 semantics for minNum and may differ on
+    /// different SIMD architectures.
+    #[inline]
+    #[must_use]
+    pub fn min(self, rhs: Self) -> Self {
+        self.zip_map(rhs, Vec3x4::min)
+    }
+
+    /// Returns the element-wise maximum of each vector of `self` and `rhs`.
+    ///
+    /// NaN propogation does not follow IEEE 754-2008 semantics for maxNum and may differ on
+    /// different SIMD architectures.
+    #[inline]
+    #[must_use]
+    pub fn max(self, rhs: Self) -> Self {
+        self.zip_map(rhs, Vec3x4::max)
+    }
+
+    /// Returns the absolute value of each element of `self`.
+    #[inline]
+    #[must_use]
+    pub fn abs(self) -> Self {
+        self.map(Vec3x4::abs)
+    }
+
+    /// Computes the length of each vector of `self`.
+    #[doc(alias = "magnitude")]
+    #[inline]
+    #[must_use]
+    pub fn length(self) -> [Vec4; 2] {
+        self.map_lanes(Vec3x4::length)
+    }
+
+    /// Computes the squared length of each vector of `self`.
+    #[doc(alias = "magnitude2")]
+    #[inline]
+    #[must_use]
+    pub fn length_squared(self) -> [Vec4; 2] {
+        self.dot(self)
+    }
+
+    /// Computes `1.0 / length()` for each vector of `self`.
+    #[inline]
+    #[must_use]
+    pub fn length_recip(self) -> [Vec4; 2] {
+        self.map_lanes(Vec3x4::length_recip)
+    }
+
+    /// Computes the Euclidean distance between each vector of `self` and `rhs`.
+    #[inline]
+    #[must_use]
+    pub fn distance(self, rhs: Self) -> [Vec4; 2] {
+        (self - rhs).length()
+    }
+
+    /// Computes the squared Euclidean distance between each vector of `self` and `rhs`.
+    #[inline]
+    #[must_use]
+    pub fn distance_squared(self, rhs: Self) -> [Vec4; 2] {
+        (self - rhs).length_squared()
+    }
+
+    /// Returns each vector of `self` normalized to length 1.0.
+    ///
+    /// For valid results, the vectors must _not_ be of length zero, nor very close to zero.
+    ///
+    /// See also [`Self::normalize_or_zero()`].
+    #[inline]
+    #[must_use]
+    pub fn normalize(self) -> Self {
+        self.map(Vec3x4::normalize)
+    }
+
+    /// Returns each vector of `self` normalized to length 1.0 if possible, else zero.
+    ///
+    /// In particular, if a vector is zero (or very close to zero), or non-finite, it is replaced
+    /// by zero.
+    #[inline]
+    #[must_use]
+    pub fn normalize_or_zero(self) -> Self {
+        self.map(Vec3x4::normalize_or_zero)
+    }
+
+    /// Performs a linear interpolation between each vector of `self` and `rhs` based on the value
+    /// `s`.
+    ///
+    /// When `s` is `0.0`, the result will be equal to `self`. When `s` is `1.0`, the result will
+    /// be equal to `rhs`. When `s` is outside of range `[0, 1]`, the result is linearly
+    /// extrapolated.
+    #[doc(alias = "mix")]
+    #[inline]
+    #[must_use]
+    pub fn lerp(self, rhs: Self, s: f32) -> Self {
+        self * (1.0 - s) + rhs * s
+    }
+}
+
+impl Default for Vec3x8 {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl From<[Vec3; 8]> for Vec3x8 {
+    #[inline]
+    fn from(a: [Vec3; 8]) -> Self {
+        Self::from_array(a)
+    }
+}
+
+impl From<Vec3x8> for [Vec3; 8] {
+    #[inline]
+    fn from(v: Vec3x8) -> Self {
+        v.to_array()
+    }
+}
+
+impl Neg for Vec3x8 {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        self.map(Vec3x4::neg)
+    }
+}
+
+impl Add for Vec3x8 {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        self.zip_map(rhs, Vec3x4::add)
+    }
+}
+
+impl AddAssign for Vec3x8 {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = self.add(rhs);
+    }
+}
+
+impl Add<f32> for Vec3x8 {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: f32) -> Self {
+        self.map(|v| v.add(rhs))
+    }
+}
+
+impl AddAssign<f32> for Vec3x8 {
+    #[inline]
+    fn add_assign(&mut self, rhs: f32) {
+        *self = self.add(rhs);
+    }
+}
+
+impl Sub for Vec3x8 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        self.zip_map(rhs, Vec3x4::sub)
+    }
+}
+
+impl SubAssign for Vec3x8 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = self.sub(rhs);
+    }
+}
+
+impl Sub<f32> for Vec3x8 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: f32) -> Self {
+        self.map(|v| v.sub(rhs))
+    }
+}
+
+impl SubAssign<f32> for Vec3x8 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: f32) {
+        *self = self.sub(rhs);
+    }
+}
+
+impl Mul for Vec3x8 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        self.zip_map(rhs, Vec3x4::mul)
+    }
+}
+
+impl MulAssign for Vec3x8 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.mul(rhs);
+    }
+}
+
+impl Mul<f32> for Vec3x8 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: f32) -> Self {
+        self.map(|v| v.mul(rhs))
+    }
+}
+
+impl MulAssign<f32> for Vec3x8 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: f32) {
+        *self = self.mul(rhs);
+    }
+}
+
+impl Div for Vec3x8 {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: Self) -> Self {
+        self.zip_map(rhs, Vec3x4::div)
+    }
+}
+
+impl DivAssign for Vec3x8 {
+    #[inline]
+    fn div_assign(&mut self, rhs: Self) {
+        *self = self.div(rhs);
+    }
+}
+
+impl Div<f32> for Vec3x8 {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: f32) -> Self {
+        self.map(|v| v.div(rhs))
+    }
+}
+
+impl DivAssign<f32> for Vec3x8 {
+    #[inline]
+    fn div_assign(&mut self, rhs: f32) {
+        *self = self.div(rhs);
+    }
+}
+
+/// Scales each vector of `self` by the corresponding lane of `rhs`.
+impl Mul<[Vec4; 2]> for Vec3x8 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: [Vec4; 2]) -> Self {
+        let [lo, hi] = self.to_halves();
+        Self::from_halves([lo.mul(rhs[0]), hi.mul(rhs[1])])
+    }
+}
+
+impl MulAssign<[Vec4; 2]> for Vec3x8 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: [Vec4; 2]) {
+        *self = self.mul(rhs);
+    }
+}
+
+/// Scales each vector of `self` by the corresponding lane of `rhs`.
+impl Div<[Vec4; 2]> for Vec3x8 {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: [Vec4; 2]) -> Self {
+        let [lo, hi] = self.to_halves();
+        Self::from_halves([lo.div(rhs[0]), hi.div(rhs[1])])
+    }
+}
+
+impl DivAssign<[Vec4; 2]> for Vec3x8 {
+    #[inline]
+    fn div_assign(&mut self, rhs: [Vec4; 2]) {
+        *self = self.div(rhs);
+    }
+}
+
+impl From<[Vec3x4; 2]> for Vec3x8 {
+    #[inline]
+    fn from(halves: [Vec3x4; 2]) -> Self {
+        Self::from_halves(halves)
+    }
+}
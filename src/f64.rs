@@ -1,21 +1,64 @@
 mod daffine2;
 mod daffine3;
+mod ddualquat;
+mod disometry3;
 mod dmat2;
 mod dmat3;
-mod dmat4;
-mod dquat;
-mod dvec2;
-mod dvec3;
-mod dvec4;
+mod drot2;
+mod dtransform2;
+mod dtransform3;
 mod float;
 pub(crate) mod math;
 
+#[cfg(any(
+    not(any(target_arch = "aarch64", target_feature = "sse2")),
+    feature = "core-simd",
+    feature = "scalar-math"
+))]
+mod scalar;
+
+#[cfg(all(
+    target_arch = "aarch64",
+    not(any(feature = "core-simd", feature = "scalar-math"))
+))]
+mod neon;
+
+#[cfg(all(
+    target_feature = "sse2",
+    not(any(feature = "core-simd", feature = "scalar-math"))
+))]
+mod sse2;
+
+#[cfg(any(
+    not(any(target_arch = "aarch64", target_feature = "sse2")),
+    feature = "core-simd",
+    feature = "scalar-math"
+))]
+use scalar::*;
+
+#[cfg(all(
+    target_arch = "aarch64",
+    not(any(feature = "core-simd", feature = "scalar-math"))
+))]
+use neon::*;
+
+#[cfg(all(
+    target_feature = "sse2",
+    not(any(feature = "core-simd", feature = "scalar-math"))
+))]
+use sse2::*;
+
 pub use daffine2::DAffine2;
 pub use daffine3::DAffine3;
+pub use ddualquat::{ddualquat, DDualQuat};
+pub use disometry3::DIsometry3;
 pub use dmat2::{dmat2, DMat2};
 pub use dmat3::{dmat3, DMat3};
 pub use dmat4::{dmat4, DMat4};
 pub use dquat::{dquat, DQuat};
+pub use drot2::DRot2;
+pub use dtransform2::DTransform2;
+pub use dtransform3::DTransform3;
 pub use dvec2::{dvec2, DVec2};
 pub use dvec3::{dvec3, DVec3};
 pub use dvec4::{dvec4, DVec4};
@@ -31,6 +74,22 @@ mod test {
         const_assert_eq!(48, core::mem::size_of::<super::DAffine2>());
     }
 
+    mod const_test_ddualquat {
+        const_assert_eq!(
+            core::mem::align_of::<super::DQuat>(),
+            core::mem::align_of::<super::DDualQuat>()
+        );
+        const_assert_eq!(64, core::mem::size_of::<super::DDualQuat>());
+    }
+
+    mod const_test_disometry3 {
+        const_assert_eq!(
+            core::mem::align_of::<super::DQuat>(),
+            core::mem::align_of::<super::DIsometry3>()
+        );
+        const_assert_eq!(56, core::mem::size_of::<super::DIsometry3>());
+    }
+
     mod const_test_dmat2 {
         const_assert_eq!(
             core::mem::align_of::<super::DVec2>(),
@@ -66,6 +125,33 @@ mod test {
         const_assert_eq!(32, core::mem::size_of::<super::DQuat>());
     }
 
+    mod const_test_drot2 {
+        #[cfg(feature = "scalar-math")]
+        const_assert_eq!(
+            core::mem::align_of::<f64>(),
+            core::mem::align_of::<super::DRot2>()
+        );
+        #[cfg(not(any(feature = "scalar-math", target_arch = "spirv")))]
+        const_assert_eq!(16, core::mem::align_of::<super::DRot2>());
+        const_assert_eq!(16, core::mem::size_of::<super::DRot2>());
+    }
+
+    mod const_test_dtransform2 {
+        const_assert_eq!(
+            core::mem::align_of::<f64>(),
+            core::mem::align_of::<super::DTransform2>()
+        );
+        const_assert_eq!(40, core::mem::size_of::<super::DTransform2>());
+    }
+
+    mod const_test_dtransform3 {
+        const_assert_eq!(
+            core::mem::align_of::<super::DQuat>(),
+            core::mem::align_of::<super::DTransform3>()
+        );
+        const_assert_eq!(80, core::mem::size_of::<super::DTransform3>());
+    }
+
     mod const_test_dvec2 {
         #[cfg(not(any(feature = "cuda", target_arch = "spirv")))]
         const_assert_eq!(
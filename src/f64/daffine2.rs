@@ -311,6 +311,39 @@ impl DAffine2 {
         }
     }
 
+    /// Transforms the given 2D point in a `const` context.
+    ///
+    /// This gives the same result as [`Self::transform_point2()`].
+    #[cfg(feature = "const-float-arithmetic")]
+    #[inline]
+    #[must_use]
+    pub const fn const_transform_point2(&self, rhs: DVec2) -> DVec2 {
+        self.matrix2.const_mul_vec2(rhs).const_add(self.translation)
+    }
+
+    /// Transforms the given 2D vector in a `const` context.
+    ///
+    /// This gives the same result as [`Self::transform_vector2()`].
+    #[cfg(feature = "const-float-arithmetic")]
+    #[inline]
+    #[must_use]
+    pub const fn const_transform_vector2(&self, rhs: DVec2) -> DVec2 {
+        self.matrix2.const_mul_vec2(rhs)
+    }
+
+    /// Composes two affine transforms in a `const` context, applying `rhs` first.
+    ///
+    /// This gives the same result as `self * rhs`.
+    #[cfg(feature = "const-float-arithmetic")]
+    #[inline]
+    #[must_use]
+    pub const fn const_mul_affine(&self, rhs: &Self) -> Self {
+        Self {
+            matrix2: self.matrix2.const_mul_mat2(&rhs.matrix2),
+            translation: self.const_transform_point2(rhs.translation),
+        }
+    }
+
     /// Casts all elements of `self` to `f32`.
     #[inline]
     #[must_use]
@@ -284,7 +284,8 @@ impl DAffine3 {
     /// Extracts `scale`, `rotation` and `translation` from `self`.
     ///
     /// The transform is expected to be non-degenerate and without shearing, or the output
-    /// will be invalid.
+    /// will be invalid. Use [`Self::to_scale_shear_rotation_translation()`] for transforms
+    /// containing shear.
     ///
     /// # Panics
     ///
@@ -318,6 +319,44 @@ impl DAffine3 {
         (scale, rotation, self.translation.into())
     }
 
+    /// Creates an affine transform from the given 3D `scale`, `shear`, `rotation` and
+    /// `translation`.
+    ///
+    /// This is the inverse of [`Self::to_scale_shear_rotation_translation()`]. See
+    /// [`DMat3::from_scale_shear_rotation()`] for the meaning of `shear`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `rotation` is not normalized when `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn from_scale_shear_rotation_translation(
+        scale: DVec3,
+        shear: DVec3,
+        rotation: DQuat,
+        translation: DVec3,
+    ) -> Self {
+        #[allow(clippy::useless_conversion)]
+        Self {
+            matrix3: DMat3::from_scale_shear_rotation(scale, shear, rotation),
+            translation: translation.into(),
+        }
+    }
+
+    /// Extracts `scale`, `shear`, `rotation` and `translation` from `self`.
+    ///
+    /// Unlike [`Self::to_scale_rotation_translation()`] this handles transforms containing
+    /// shear. See [`DMat3::to_scale_shear_rotation()`] for details.
+    ///
+    /// Returns `None` if `self.matrix3` is not invertible.
+    #[inline]
+    #[must_use]
+    pub fn to_scale_shear_rotation_translation(&self) -> Option<(DVec3, DVec3, DQuat, DVec3)> {
+        let (scale, shear, rotation) = self.matrix3.to_scale_shear_rotation()?;
+        #[allow(clippy::useless_conversion)]
+        Some((scale, shear, rotation, self.translation.into()))
+    }
+
     /// Creates a left-handed view transform using a camera position, an up direction, and a facing
     /// direction.
     ///
@@ -451,6 +490,39 @@ impl DAffine3 {
         }
     }
 
+    /// Transforms the given 3D point in a `const` context.
+    ///
+    /// This gives the same result as [`Self::transform_point3()`].
+    #[cfg(feature = "const-float-arithmetic")]
+    #[inline]
+    #[must_use]
+    pub const fn const_transform_point3(&self, rhs: DVec3) -> DVec3 {
+        self.matrix3.const_mul_vec3(rhs).const_add(self.translation)
+    }
+
+    /// Transforms the given 3D vector in a `const` context.
+    ///
+    /// This gives the same result as [`Self::transform_vector3()`].
+    #[cfg(feature = "const-float-arithmetic")]
+    #[inline]
+    #[must_use]
+    pub const fn const_transform_vector3(&self, rhs: DVec3) -> DVec3 {
+        self.matrix3.const_mul_vec3(rhs)
+    }
+
+    /// Composes two affine transforms in a `const` context, applying `rhs` first.
+    ///
+    /// This gives the same result as `self * rhs`.
+    #[cfg(feature = "const-float-arithmetic")]
+    #[inline]
+    #[must_use]
+    pub const fn const_mul_affine(&self, rhs: &Self) -> Self {
+        Self {
+            matrix3: self.matrix3.const_mul_mat3(&rhs.matrix3),
+            translation: self.const_transform_point3(rhs.translation),
+        }
+    }
+
     /// Casts all elements of `self` to `f32`.
     #[inline]
     #[must_use]
@@ -0,0 +1,709 @@
+// Generated from dualquat.rs.tera template. Edit the template, not the generated file.
+
+use crate::{f64::math, DAffine3, DMat4, DQuat, DVec3, DualQuat};
+
+use core::fmt;
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// Creates a dual quaternion from a `rotation` and a `translation`.
+#[inline]
+#[must_use]
+pub fn ddualquat(rotation: DQuat, translation: DVec3) -> DDualQuat {
+    DDualQuat::from_rotation_translation(rotation, translation)
+}
+
+/// A dual quaternion representing a rigid transform, a rotation followed by a translation.
+///
+/// The `real` part holds the rotation and the `dual` part holds the translation `t` encoded as
+/// `0.5 * t * real`, where `t` is a pure quaternion.
+///
+/// A dual quaternion representing a rigid transform is intended to be of unit length, that is its
+/// `real` part is normalized and orthogonal to its `dual` part. Like [`DQuat`] it may
+/// denormalize due to floating point "error creep", use [`Self::normalize()`] to correct this.
+///
+/// Dual quaternions cannot represent scale or shear.
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(C)]
+pub struct DDualQuat {
+    pub real: DQuat,
+    pub dual: DQuat,
+}
+
+impl DDualQuat {
+    /// All zeros.
+    pub const ZERO: Self =
+        Self::from_real_dual(DQuat::from_array([0.0; 4]), DQuat::from_array([0.0; 4]));
+
+    /// The identity transform.
+    pub const IDENTITY: Self = Self::from_real_dual(DQuat::IDENTITY, DQuat::from_array([0.0; 4]));
+
+    /// All NANs.
+    pub const NAN: Self = Self::from_real_dual(DQuat::NAN, DQuat::NAN);
+
+    /// Creates a dual quaternion from its `real` and `dual` parts.
+    ///
+    /// This should generally not be called manually unless you know what you are doing. Use
+    /// one of the other constructors instead such as `from_rotation_translation`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn from_real_dual(real: DQuat, dual: DQuat) -> Self {
+        Self { real, dual }
+    }
+
+    /// Creates a dual quaternion from a `[f64; 8]` array containing the `real` part
+    /// followed by the `dual` part, each in `x`, `y`, `z`, `w` order.
+    #[inline]
+    #[must_use]
+    pub const fn from_array(a: [f64; 8]) -> Self {
+        Self::from_real_dual(
+            DQuat::from_xyzw(a[0], a[1], a[2], a[3]),
+            DQuat::from_xyzw(a[4], a[5], a[6], a[7]),
+        )
+    }
+
+    /// Returns the `real` part followed by the `dual` part as a `[f64; 8]` array.
+    #[inline]
+    #[must_use]
+    pub fn to_array(&self) -> [f64; 8] {
+        let [x0, y0, z0, w0] = self.real.to_array();
+        let [x1, y1, z1, w1] = self.dual.to_array();
+        [x0, y0, z0, w0, x1, y1, z1, w1]
+    }
+
+    /// Creates a dual quaternion from the given `rotation` followed by the given `translation`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `rotation` is not normalized when `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn from_rotation_translation(rotation: DQuat, translation: DVec3) -> Self {
+        glam_assert!(rotation.is_normalized());
+        let t = DQuat::from_xyzw(translation.x, translation.y, translation.z, 0.0);
+        Self::from_real_dual(rotation, t.mul_quat(rotation) * 0.5)
+    }
+
+    /// Creates a dual quaternion from the given `rotation`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `rotation` is not normalized when `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn from_quat(rotation: DQuat) -> Self {
+        glam_assert!(rotation.is_normalized());
+        Self::from_real_dual(rotation, Self::ZERO.dual)
+    }
+
+    /// Creates a dual quaternion from the given `translation`.
+    #[inline]
+    #[must_use]
+    pub fn from_translation(translation: DVec3) -> Self {
+        Self::from_real_dual(
+            DQuat::IDENTITY,
+            DQuat::from_xyzw(
+                translation.x * 0.5,
+                translation.y * 0.5,
+                translation.z * 0.5,
+                0.0,
+            ),
+        )
+    }
+
+    /// Creates a dual quaternion from the rotation and translation of an affine transform.
+    ///
+    /// The transform must not contain scale or shear, or the output will be invalid.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if any column of the 3x3 part of the transform is not normalized when
+    /// `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn from_affine3(a: &DAffine3) -> Self {
+        #[allow(clippy::useless_conversion)]
+        Self::from_rotation_translation(DQuat::from_affine3(a), a.translation.into())
+    }
+
+    /// Creates a dual quaternion from the rotation and translation of a 4x4 matrix.
+    ///
+    /// The matrix must be an affine transform without scale or shear, or the output will be
+    /// invalid.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if any column of the upper 3x3 part of the matrix is not normalized when
+    /// `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn from_mat4(m: &DMat4) -> Self {
+        Self::from_rotation_translation(DQuat::from_mat4(m), m.w_axis.truncate())
+    }
+
+    /// Returns the rotation of `self`.
+    #[inline]
+    #[must_use]
+    pub fn rotation(&self) -> DQuat {
+        self.real
+    }
+
+    /// Returns the translation of `self`.
+    #[inline]
+    #[must_use]
+    pub fn translation(&self) -> DVec3 {
+        (self.dual.mul_quat(self.real.conjugate()) * 2.0).xyz()
+    }
+
+    /// Returns the rotation and translation of `self`.
+    #[inline]
+    #[must_use]
+    pub fn to_rotation_translation(&self) -> (DQuat, DVec3) {
+        (self.rotation(), self.translation())
+    }
+
+    /// Creates an affine transform from `self`.
+    #[inline]
+    #[must_use]
+    pub fn to_affine3(&self) -> DAffine3 {
+        DAffine3::from_rotation_translation(self.real, self.translation())
+    }
+
+    /// Creates a 4x4 homogeneous transformation matrix from `self`.
+    #[inline]
+    #[must_use]
+    pub fn to_mat4(&self) -> DMat4 {
+        DMat4::from_rotation_translation(self.real, self.translation())
+    }
+
+    /// Returns the quaternion conjugate of `self`, conjugating both the `real` and the `dual`
+    /// part.
+    ///
+    /// For a unit dual quaternion this is the inverse transform.
+    #[inline]
+    #[must_use]
+    pub fn conjugate(self) -> Self {
+        Self::from_real_dual(self.real.conjugate(), self.dual.conjugate())
+    }
+
+    /// Returns the dual number conjugate of `self`, negating the `dual` part.
+    #[inline]
+    #[must_use]
+    pub fn dual_conjugate(self) -> Self {
+        Self::from_real_dual(self.real, -self.dual)
+    }
+
+    /// Returns the combined quaternion and dual number conjugate of `self`.
+    ///
+    /// A point `p` is transformed by a unit dual quaternion `q` as `q * (1 + εp) * q.combined_conjugate()`.
+    #[inline]
+    #[must_use]
+    pub fn combined_conjugate(self) -> Self {
+        Self::from_real_dual(self.real.conjugate(), -self.dual.conjugate())
+    }
+
+    /// Returns the inverse of a normalized dual quaternion.
+    ///
+    /// Typically dual quaternion inverse returns the conjugate of a normalized dual quaternion.
+    /// Because `self` is assumed to already be unit length this method *does not* normalize
+    /// before returning the conjugate.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `self` is not normalized when `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn inverse(self) -> Self {
+        glam_assert!(self.is_normalized());
+        self.conjugate()
+    }
+
+    /// Computes the dot product of the `real` parts of `self` and `rhs`.
+    ///
+    /// This is the cosine of half the angle between the rotations of `self` and `rhs`.
+    #[inline]
+    #[must_use]
+    pub fn dot(self, rhs: Self) -> f64 {
+        self.real.dot(rhs.real)
+    }
+
+    /// Computes the length of `self`, which is the length of the `real` part.
+    #[inline]
+    #[must_use]
+    pub fn length(self) -> f64 {
+        self.real.length()
+    }
+
+    /// Returns `self` with a normalized `real` part and a `dual` part orthogonal to it.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the `real` part of `self` is zero length when `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn normalize(self) -> Self {
+        let length_recip = self.real.length_recip();
+        glam_assert!(length_recip.is_finite());
+        let real = self.real * length_recip;
+        let dual = self.dual * length_recip;
+        Self::from_real_dual(real, dual - real * real.dot(dual))
+    }
+
+    /// Returns whether `self` is of unit length, that is its `real` part is of length `1.0` and
+    /// orthogonal to its `dual` part.
+    ///
+    /// Uses a precision threshold of `1e-6`. The `dual` part grows with the translation, so the
+    /// orthogonality threshold is scaled by its length when that is larger than `1.0`.
+    #[inline]
+    #[must_use]
+    pub fn is_normalized(self) -> bool {
+        self.real.is_normalized()
+            && math::abs(self.real.dot(self.dual)) <= 1e-6 * self.dual.length().max(1.0)
+    }
+
+    /// Returns `true` if, and only if, all elements are finite.
+    /// If any element is either `NaN`, positive or negative infinity, this will return `false`.
+    #[inline]
+    #[must_use]
+    pub fn is_finite(self) -> bool {
+        self.real.is_finite() && self.dual.is_finite()
+    }
+
+    /// Returns `true` if any elements are `NAN`.
+    #[inline]
+    #[must_use]
+    pub fn is_nan(self) -> bool {
+        self.real.is_nan() || self.dual.is_nan()
+    }
+
+    /// Returns true if the absolute difference of all elements between `self` and `rhs`
+    /// is less than or equal to `max_abs_diff`.
+    ///
+    /// This can be used to compare if two dual quaternions contain similar elements. It works
+    /// best when comparing with a known value. The `max_abs_diff` that should be used used
+    /// depends on the values being compared against.
+    ///
+    /// For more see
+    /// [comparing floating point numbers](https://randomascii.wordpress.com/2012/02/25/comparing-floating-point-numbers-2012-edition/).
+    #[inline]
+    #[must_use]
+    pub fn abs_diff_eq(self, rhs: Self, max_abs_diff: f64) -> bool {
+        self.real.abs_diff_eq(rhs.real, max_abs_diff)
+            && self.dual.abs_diff_eq(rhs.dual, max_abs_diff)
+    }
+
+    /// Multiplies two dual quaternions. If they each represent a rigid transform, the result will
+    /// represent the combined transform, applying `rhs` first.
+    ///
+    /// Note that due to floating point rounding the result may not be perfectly normalized.
+    #[inline]
+    #[must_use]
+    pub fn mul_dual_quat(self, rhs: Self) -> Self {
+        Self::from_real_dual(
+            self.real.mul_quat(rhs.real),
+            self.real.mul_quat(rhs.dual) + self.dual.mul_quat(rhs.real),
+        )
+    }
+
+    /// Transforms the given 3D point, applying rotation and translation.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `self` is not normalized when `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn transform_point3(&self, rhs: DVec3) -> DVec3 {
+        glam_assert!(self.is_normalized());
+        self.real.mul_vec3(rhs) + self.translation()
+    }
+
+    /// Transforms the given 3D vector, applying rotation (but NOT translation).
+    ///
+    /// To also apply translation, use [`Self::transform_point3()`] instead.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `self` is not normalized when `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn transform_vector3(&self, rhs: DVec3) -> DVec3 {
+        glam_assert!(self.is_normalized());
+        self.real.mul_vec3(rhs)
+    }
+
+    /// Blends the weighted dual quaternions in `weighted` using dual quaternion linear blending
+    /// (DLB), returning the normalized weighted sum.
+    ///
+    /// Each dual quaternion is flipped into the hemisphere of the first one before summing, so
+    /// that rotations are always blended along the shortest path. This is the blending used for
+    /// dual quaternion skinning.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `weighted` is empty or the weighted sum has a zero length `real` part when
+    /// `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn dlb(weighted: &[(Self, f64)]) -> Self {
+        let pivot = weighted.first().map_or(DQuat::IDENTITY, |(dq, _)| dq.real);
+        let mut sum = Self::ZERO;
+        for (dq, weight) in weighted {
+            if pivot.dot(dq.real) < 0.0 {
+                sum -= *dq * *weight;
+            } else {
+                sum += *dq * *weight;
+            }
+        }
+        sum.normalize()
+    }
+
+    /// Performs a linear interpolation between `self` and `end` using dual quaternion linear
+    /// blending (DLB), see [`Self::dlb()`].
+    ///
+    /// When `s` is `0.0`, the result will be equal to `self`.  When `s` is `1.0`, the result
+    /// will be equal to `end`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `self` or `end` are not normalized when `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn lerp(self, end: Self, s: f64) -> Self {
+        glam_assert!(self.is_normalized());
+        glam_assert!(end.is_normalized());
+        Self::dlb(&[(self, 1.0 - s), (end, s)])
+    }
+
+    /// Performs a screw linear interpolation (ScLERP) between `self` and `end`.
+    ///
+    /// The relative transform from `self` to `end` is interpolated as a constant speed rotation
+    /// about and translation along a single screw axis, taking the shortest path.
+    ///
+    /// When `s` is `0.0`, the result will be equal to `self`.  When `s` is `1.0`, the result
+    /// will be equal to `end`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `self` or `end` are not normalized when `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn sclerp(self, mut end: Self, s: f64) -> Self {
+        glam_assert!(self.is_normalized());
+        glam_assert!(end.is_normalized());
+
+        if self.dot(end) < 0.0 {
+            end = -end;
+        }
+        self.mul_dual_quat(self.conjugate().mul_dual_quat(end).powf(s))
+    }
+
+    /// Raises the unit dual quaternion `self` to the power `s` by scaling its screw parameters.
+    #[inline]
+    #[must_use]
+    fn powf(self, s: f64) -> Self {
+        let axis = self.real.xyz();
+        let sin_half_angle = axis.length();
+        if sin_half_angle < 1e-6 {
+            // pure translation, the dual part scales linearly
+            return Self::from_real_dual(self.real, self.dual * s);
+        }
+        let half_angle = math::atan2(sin_half_angle, self.real.w);
+        let axis = axis / sin_half_angle;
+        // half the translation along the screw axis and the moment of the axis
+        let half_pitch = -self.dual.w / sin_half_angle;
+        let moment = (self.dual.xyz() - axis * (half_pitch * self.real.w)) / sin_half_angle;
+
+        let half_angle = half_angle * s;
+        let half_pitch = half_pitch * s;
+        let (sin, cos) = math::sin_cos(half_angle);
+        let real = DQuat::from_vec4((axis * sin).extend(cos));
+        let dual =
+            DQuat::from_vec4((moment * sin + axis * (half_pitch * cos)).extend(-half_pitch * sin));
+        Self::from_real_dual(real, dual)
+    }
+
+    /// Casts all elements of `self` to `f32`.
+    #[inline]
+    #[must_use]
+    pub fn as_dualquat(&self) -> DualQuat {
+        DualQuat::from_real_dual(self.real.as_quat(), self.dual.as_quat())
+    }
+}
+
+impl Default for DDualQuat {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl fmt::Debug for DDualQuat {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct(stringify!(DDualQuat))
+            .field("real", &self.real)
+            .field("dual", &self.dual)
+            .finish()
+    }
+}
+
+impl fmt::Display for DDualQuat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(p) = f.precision() {
+            write!(f, "[{:.*}, {:.*}]", p, self.real, p, self.dual)
+        } else {
+            write!(f, "[{}, {}]", self.real, self.dual)
+        }
+    }
+}
+
+impl Add for DDualQuat {
+    type Output = Self;
+    /// Adds two dual quaternions component-wise.
+    ///
+    /// The sum is not guaranteed to be normalized.
+    ///
+    /// Note that addition is not the same as combining the transforms represented by the
+    /// two dual quaternions! That corresponds to multiplication.
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self::from_real_dual(self.real + rhs.real, self.dual + rhs.dual)
+    }
+}
+
+impl Add<&Self> for DDualQuat {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: &Self) -> Self {
+        self.add(*rhs)
+    }
+}
+
+impl Add<&DDualQuat> for &DDualQuat {
+    type Output = DDualQuat;
+    #[inline]
+    fn add(self, rhs: &DDualQuat) -> DDualQuat {
+        (*self).add(*rhs)
+    }
+}
+
+impl Add<DDualQuat> for &DDualQuat {
+    type Output = DDualQuat;
+    #[inline]
+    fn add(self, rhs: DDualQuat) -> DDualQuat {
+        (*self).add(rhs)
+    }
+}
+
+impl AddAssign for DDualQuat {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = self.add(rhs);
+    }
+}
+
+impl AddAssign<&Self> for DDualQuat {
+    #[inline]
+    fn add_assign(&mut self, rhs: &Self) {
+        self.add_assign(*rhs);
+    }
+}
+
+impl Sub for DDualQuat {
+    type Output = Self;
+    /// Subtracts the `rhs` dual quaternion from `self` component-wise.
+    ///
+    /// The difference is not guaranteed to be normalized.
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self::from_real_dual(self.real - rhs.real, self.dual - rhs.dual)
+    }
+}
+
+impl Sub<&Self> for DDualQuat {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: &Self) -> Self {
+        self.sub(*rhs)
+    }
+}
+
+impl Sub<&DDualQuat> for &DDualQuat {
+    type Output = DDualQuat;
+    #[inline]
+    fn sub(self, rhs: &DDualQuat) -> DDualQuat {
+        (*self).sub(*rhs)
+    }
+}
+
+impl Sub<DDualQuat> for &DDualQuat {
+    type Output = DDualQuat;
+    #[inline]
+    fn sub(self, rhs: DDualQuat) -> DDualQuat {
+        (*self).sub(rhs)
+    }
+}
+
+impl SubAssign for DDualQuat {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = self.sub(rhs);
+    }
+}
+
+impl SubAssign<&Self> for DDualQuat {
+    #[inline]
+    fn sub_assign(&mut self, rhs: &Self) {
+        self.sub_assign(*rhs);
+    }
+}
+
+impl Mul<f64> for DDualQuat {
+    type Output = Self;
+    /// Multiplies a dual quaternion by a scalar value.
+    ///
+    /// The product is not guaranteed to be normalized.
+    #[inline]
+    fn mul(self, rhs: f64) -> Self {
+        Self::from_real_dual(self.real * rhs, self.dual * rhs)
+    }
+}
+
+impl Mul<&f64> for DDualQuat {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: &f64) -> Self {
+        self.mul(*rhs)
+    }
+}
+
+impl Mul<&f64> for &DDualQuat {
+    type Output = DDualQuat;
+    #[inline]
+    fn mul(self, rhs: &f64) -> DDualQuat {
+        (*self).mul(*rhs)
+    }
+}
+
+impl Mul<f64> for &DDualQuat {
+    type Output = DDualQuat;
+    #[inline]
+    fn mul(self, rhs: f64) -> DDualQuat {
+        (*self).mul(rhs)
+    }
+}
+
+impl MulAssign<f64> for DDualQuat {
+    #[inline]
+    fn mul_assign(&mut self, rhs: f64) {
+        *self = self.mul(rhs);
+    }
+}
+
+impl MulAssign<&f64> for DDualQuat {
+    #[inline]
+    fn mul_assign(&mut self, rhs: &f64) {
+        self.mul_assign(*rhs);
+    }
+}
+
+impl Mul for DDualQuat {
+    type Output = Self;
+    /// Multiplies two dual quaternions. If they each represent a rigid transform, the result
+    /// will represent the combined transform, applying `rhs` first.
+    ///
+    /// Note that due to floating point rounding the result may not be perfectly
+    /// normalized.
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        self.mul_dual_quat(rhs)
+    }
+}
+
+impl Mul<&Self> for DDualQuat {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: &Self) -> Self {
+        self.mul(*rhs)
+    }
+}
+
+impl Mul<&DDualQuat> for &DDualQuat {
+    type Output = DDualQuat;
+    #[inline]
+    fn mul(self, rhs: &DDualQuat) -> DDualQuat {
+        (*self).mul(*rhs)
+    }
+}
+
+impl Mul<DDualQuat> for &DDualQuat {
+    type Output = DDualQuat;
+    #[inline]
+    fn mul(self, rhs: DDualQuat) -> DDualQuat {
+        (*self).mul(rhs)
+    }
+}
+
+impl MulAssign for DDualQuat {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.mul(rhs);
+    }
+}
+
+impl MulAssign<&Self> for DDualQuat {
+    #[inline]
+    fn mul_assign(&mut self, rhs: &Self) {
+        self.mul_assign(*rhs);
+    }
+}
+
+impl Neg for DDualQuat {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        self * -1.0
+    }
+}
+
+impl Neg for &DDualQuat {
+    type Output = DDualQuat;
+    #[inline]
+    fn neg(self) -> DDualQuat {
+        (*self).neg()
+    }
+}
+
+impl From<DQuat> for DDualQuat {
+    #[inline]
+    fn from(q: DQuat) -> Self {
+        Self::from_quat(q)
+    }
+}
+
+impl From<DDualQuat> for [f64; 8] {
+    #[inline]
+    fn from(dq: DDualQuat) -> Self {
+        dq.to_array()
+    }
+}
+
+impl From<[f64; 8]> for DDualQuat {
+    #[inline]
+    fn from(a: [f64; 8]) -> Self {
+        Self::from_array(a)
+    }
+}
+
+impl From<DDualQuat> for DMat4 {
+    #[inline]
+    fn from(dq: DDualQuat) -> Self {
+        dq.to_mat4()
+    }
+}
+
+impl From<DDualQuat> for DAffine3 {
+    #[inline]
+    fn from(dq: DDualQuat) -> Self {
+        dq.to_affine3()
+    }
+}
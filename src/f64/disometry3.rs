@@ -0,0 +1,392 @@
+// Generated from isometry.rs.tera template. Edit the template, not the generated file.
+
+use crate::{DAffine3, DMat4, DQuat, DVec3, Isometry3A};
+use core::fmt;
+use core::iter::Product;
+use core::ops::{Mul, MulAssign};
+
+/// A 3D isometry, or rigid transform, which can represent rotation and translation.
+///
+/// The `rotation` is stored as a [`DQuat`] which is expected to be normalized. It is applied
+/// before the `translation`.
+///
+/// Unlike [`DAffine3`] an isometry cannot represent scale or shear, which makes
+/// [`Self::inverse()`] cheap and exact and allows the rotation to be interpolated directly.
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(C)]
+pub struct DIsometry3 {
+    pub rotation: DQuat,
+    pub translation: DVec3,
+}
+
+impl DIsometry3 {
+    /// The identity transform.
+    ///
+    /// Multiplying a vector with this returns the same vector.
+    pub const IDENTITY: Self = Self {
+        rotation: DQuat::IDENTITY,
+        translation: DVec3::ZERO,
+    };
+
+    /// All NAN:s.
+    pub const NAN: Self = Self {
+        rotation: DQuat::NAN,
+        translation: DVec3::NAN,
+    };
+
+    /// Creates an isometry from a `[f64; 7]` array containing the `rotation`
+    /// in `x`, `y`, `z`, `w` order followed by the `translation`.
+    #[inline]
+    #[must_use]
+    pub const fn from_array(a: [f64; 7]) -> Self {
+        Self {
+            rotation: DQuat::from_xyzw(a[0], a[1], a[2], a[3]),
+            translation: DVec3::new(a[4], a[5], a[6]),
+        }
+    }
+
+    /// Returns the `rotation` in `x`, `y`, `z`, `w` order followed by the `translation` as a
+    /// `[f64; 7]` array.
+    #[inline]
+    #[must_use]
+    pub fn to_array(&self) -> [f64; 7] {
+        let [x, y, z, w] = self.rotation.to_array();
+        let t = self.translation;
+        [x, y, z, w, t.x, t.y, t.z]
+    }
+
+    /// Creates an isometry from the given `rotation` followed by the given `translation`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `rotation` is not normalized when `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn from_rotation_translation(rotation: DQuat, translation: DVec3) -> Self {
+        glam_assert!(rotation.is_normalized());
+        #[allow(clippy::useless_conversion)]
+        Self {
+            rotation,
+            translation: translation.into(),
+        }
+    }
+
+    /// Creates an isometry from the given `rotation` quaternion.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `rotation` is not normalized when `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn from_quat(rotation: DQuat) -> Self {
+        glam_assert!(rotation.is_normalized());
+        Self {
+            rotation,
+            translation: DVec3::ZERO,
+        }
+    }
+
+    /// Creates an isometry from the given 3D `translation`.
+    #[inline]
+    #[must_use]
+    pub fn from_translation(translation: DVec3) -> Self {
+        #[allow(clippy::useless_conversion)]
+        Self {
+            rotation: DQuat::IDENTITY,
+            translation: translation.into(),
+        }
+    }
+
+    /// Creates an isometry from the rotation and translation of an affine transform.
+    ///
+    /// The transform must not contain scale or shear, or the output will be invalid.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if any column of the 3x3 part of the transform is not normalized when
+    /// `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn from_affine3(a: &DAffine3) -> Self {
+        Self {
+            rotation: DQuat::from_affine3(a),
+            translation: a.translation,
+        }
+    }
+
+    /// Creates an isometry from the rotation and translation of a 4x4 matrix.
+    ///
+    /// The matrix must be an affine transform without scale or shear, or the output will be
+    /// invalid.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if any column of the upper 3x3 part of the matrix is not normalized when
+    /// `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn from_mat4(m: &DMat4) -> Self {
+        Self {
+            rotation: DQuat::from_mat4(m),
+            translation: m.w_axis.truncate(),
+        }
+    }
+
+    /// Returns the rotation and translation of `self`.
+    #[inline]
+    #[must_use]
+    pub fn to_rotation_translation(&self) -> (DQuat, DVec3) {
+        #[allow(clippy::useless_conversion)]
+        (self.rotation, self.translation.into())
+    }
+
+    /// Converts `self` to an [`DAffine3`].
+    #[inline]
+    #[must_use]
+    pub fn to_affine3(&self) -> DAffine3 {
+        #[allow(clippy::useless_conversion)]
+        DAffine3::from_rotation_translation(self.rotation, self.translation.into())
+    }
+
+    /// Converts `self` to a 4x4 homogeneous transformation matrix.
+    #[inline]
+    #[must_use]
+    pub fn to_mat4(&self) -> DMat4 {
+        #[allow(clippy::useless_conversion)]
+        DMat4::from_rotation_translation(self.rotation, self.translation.into())
+    }
+
+    /// Transforms the given 3D point, applying rotation and translation.
+    #[inline]
+    #[must_use]
+    pub fn transform_point3(&self, rhs: DVec3) -> DVec3 {
+        self.rotation * rhs + self.translation
+    }
+
+    /// Transforms the given 3D vector, applying rotation (but NOT translation).
+    ///
+    /// To also apply translation, use [`Self::transform_point3()`] instead.
+    #[inline]
+    #[must_use]
+    pub fn transform_vector3(&self, rhs: DVec3) -> DVec3 {
+        self.rotation * rhs
+    }
+
+    /// Returns the inverse of `self`.
+    ///
+    /// The inverse of the rotation is its conjugate so unlike [`DAffine3::inverse()`] this
+    /// does not require a general matrix inverse.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `self.rotation` is not normalized when `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn inverse(&self) -> Self {
+        let rotation = self.rotation.inverse();
+        Self {
+            rotation,
+            translation: -(rotation * self.translation),
+        }
+    }
+
+    /// Performs a linear interpolation between `self` and `end` based on the value `s`.
+    ///
+    /// The translation is interpolated linearly and the rotation using [`DQuat::lerp()`],
+    /// which is faster than [`Self::slerp()`] but does not have a constant angular velocity.
+    ///
+    /// When `s` is `0.0`, the result will be equal to `self`.  When `s` is `1.0`, the result
+    /// will be equal to `end`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `self.rotation` or `end.rotation` are not normalized when `glam_assert` is
+    /// enabled.
+    #[inline]
+    #[must_use]
+    pub fn lerp(&self, end: Self, s: f64) -> Self {
+        Self {
+            rotation: self.rotation.lerp(end.rotation, s),
+            translation: self.translation.lerp(end.translation, s),
+        }
+    }
+
+    /// Performs a spherical linear interpolation between `self` and `end` based on the value
+    /// `s`.
+    ///
+    /// The translation is interpolated linearly and the rotation using [`DQuat::slerp()`].
+    ///
+    /// When `s` is `0.0`, the result will be equal to `self`.  When `s` is `1.0`, the result
+    /// will be equal to `end`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `self.rotation` or `end.rotation` are not normalized when `glam_assert` is
+    /// enabled.
+    #[inline]
+    #[must_use]
+    pub fn slerp(&self, end: Self, s: f64) -> Self {
+        Self {
+            rotation: self.rotation.slerp(end.rotation, s),
+            translation: self.translation.lerp(end.translation, s),
+        }
+    }
+
+    /// Returns `true` if, and only if, all elements are finite.
+    ///
+    /// If any element is either `NaN`, positive or negative infinity, this will return
+    /// `false`.
+    #[inline]
+    #[must_use]
+    pub fn is_finite(&self) -> bool {
+        self.rotation.is_finite() && self.translation.is_finite()
+    }
+
+    /// Returns `true` if any elements are `NaN`.
+    #[inline]
+    #[must_use]
+    pub fn is_nan(&self) -> bool {
+        self.rotation.is_nan() || self.translation.is_nan()
+    }
+
+    /// Returns true if the absolute difference of all elements between `self` and `rhs`
+    /// is less than or equal to `max_abs_diff`.
+    ///
+    /// This can be used to compare if two isometries contain similar elements. It works best
+    /// when comparing with a known value. The `max_abs_diff` that should be used used depends on
+    /// the values being compared against.
+    ///
+    /// For more see
+    /// [comparing floating point numbers](https://randomascii.wordpress.com/2012/02/25/comparing-floating-point-numbers-2012-edition/).
+    #[inline]
+    #[must_use]
+    pub fn abs_diff_eq(&self, rhs: Self, max_abs_diff: f64) -> bool {
+        self.rotation.abs_diff_eq(rhs.rotation, max_abs_diff)
+            && self.translation.abs_diff_eq(rhs.translation, max_abs_diff)
+    }
+
+    /// Casts all elements of `self` to `f32`.
+    #[inline]
+    #[must_use]
+    pub fn as_isometry3a(&self) -> Isometry3A {
+        Isometry3A {
+            rotation: self.rotation.as_quat(),
+            translation: self.translation.as_vec3a(),
+        }
+    }
+}
+
+impl Default for DIsometry3 {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl fmt::Debug for DIsometry3 {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct(stringify!(DIsometry3))
+            .field("rotation", &self.rotation)
+            .field("translation", &self.translation)
+            .finish()
+    }
+}
+
+impl fmt::Display for DIsometry3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(p) = f.precision() {
+            write!(f, "[{:.*}, {:.*}]", p, self.rotation, p, self.translation)
+        } else {
+            write!(f, "[{}, {}]", self.rotation, self.translation)
+        }
+    }
+}
+
+impl<'a> Product<&'a Self> for DIsometry3 {
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.fold(Self::IDENTITY, |a, &b| a * b)
+    }
+}
+
+impl Mul for DIsometry3 {
+    type Output = Self;
+    /// Multiplies two isometries, the result will represent the combined transform, applying
+    /// `rhs` first.
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Self {
+            rotation: self.rotation * rhs.rotation,
+            translation: self.rotation * rhs.translation + self.translation,
+        }
+    }
+}
+
+impl Mul<&Self> for DIsometry3 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: &Self) -> Self {
+        self.mul(*rhs)
+    }
+}
+
+impl Mul<&DIsometry3> for &DIsometry3 {
+    type Output = DIsometry3;
+    #[inline]
+    fn mul(self, rhs: &DIsometry3) -> DIsometry3 {
+        (*self).mul(*rhs)
+    }
+}
+
+impl Mul<DIsometry3> for &DIsometry3 {
+    type Output = DIsometry3;
+    #[inline]
+    fn mul(self, rhs: DIsometry3) -> DIsometry3 {
+        (*self).mul(rhs)
+    }
+}
+
+impl MulAssign for DIsometry3 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.mul(rhs);
+    }
+}
+
+impl MulAssign<&Self> for DIsometry3 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: &Self) {
+        self.mul_assign(*rhs);
+    }
+}
+
+impl From<[f64; 7]> for DIsometry3 {
+    #[inline]
+    fn from(a: [f64; 7]) -> Self {
+        Self::from_array(a)
+    }
+}
+
+impl From<DIsometry3> for [f64; 7] {
+    #[inline]
+    fn from(i: DIsometry3) -> Self {
+        i.to_array()
+    }
+}
+
+impl From<DIsometry3> for DAffine3 {
+    #[inline]
+    fn from(i: DIsometry3) -> Self {
+        i.to_affine3()
+    }
+}
+
+impl From<DIsometry3> for DMat4 {
+    #[inline]
+    fn from(i: DIsometry3) -> Self {
+        i.to_mat4()
+    }
+}
@@ -0,0 +1,408 @@
+// Generated from transform.rs.tera template. Edit the template, not the generated file.
+
+use crate::{f64::math, DAffine2, DMat3, DVec2, Transform2};
+use core::fmt;
+use core::iter::Product;
+use core::ops::{Mul, MulAssign};
+
+/// A 2D transform made of a `scale`, followed by a `rotation` and then a `translation`.
+///
+/// This is the decomposed form of an [`DAffine2`] without shear. It is convenient for
+/// building transform hierarchies as the components can be edited and interpolated
+/// independently.
+///
+/// The rotation is stored as an `angle` in radians.
+///
+/// # Non-uniform scale
+///
+/// A scale-rotation-translation transform is not closed under composition: the product of two
+/// transforms where the first has a non-uniform `scale` and the second is rotated contains shear
+/// which cannot be stored in this type. [`Self::mul_transform()`] and [`Self::inverse()`]
+/// multiply the scales, rotations and translations component-wise and discard that shear, so
+/// they are only exact when `scale` is uniform or does not change the rotated axes, for example
+/// when the rotation is the identity. Convert to [`DAffine2`] when the exact product of
+/// non-uniformly scaled transforms is needed.
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(C)]
+pub struct DTransform2 {
+    pub scale: DVec2,
+    pub angle: f64,
+    pub translation: DVec2,
+}
+
+impl DTransform2 {
+    /// The identity transform.
+    ///
+    /// Multiplying a vector with this returns the same vector.
+    pub const IDENTITY: Self = Self {
+        scale: DVec2::ONE,
+        angle: 0.0,
+        translation: DVec2::ZERO,
+    };
+
+    /// All NAN:s.
+    pub const NAN: Self = Self {
+        scale: DVec2::NAN,
+        angle: f64::NAN,
+        translation: DVec2::NAN,
+    };
+
+    /// Creates a transform from a `[f64; 5]` array containing the `scale`,
+    /// the `angle` and the `translation`.
+    #[inline]
+    #[must_use]
+    pub const fn from_array(a: [f64; 5]) -> Self {
+        Self {
+            scale: DVec2::new(a[0], a[1]),
+            angle: a[2],
+            translation: DVec2::new(a[3], a[4]),
+        }
+    }
+
+    /// Returns the `scale`, the `angle` and the `translation` as a `[f64; 5]`
+    /// array.
+    #[inline]
+    #[must_use]
+    pub const fn to_array(&self) -> [f64; 5] {
+        let s = self.scale;
+        let t = self.translation;
+        [s.x, s.y, self.angle, t.x, t.y]
+    }
+
+    /// Creates a transform from the given non-uniform 2D `scale`.
+    #[inline]
+    #[must_use]
+    pub const fn from_scale(scale: DVec2) -> Self {
+        Self {
+            scale,
+            angle: 0.0,
+            translation: DVec2::ZERO,
+        }
+    }
+
+    /// Creates a transform from the given rotation `angle` (in radians).
+    #[inline]
+    #[must_use]
+    pub const fn from_angle(angle: f64) -> Self {
+        Self {
+            scale: DVec2::ONE,
+            angle,
+            translation: DVec2::ZERO,
+        }
+    }
+
+    /// Creates a transform from the given 2D `translation`.
+    #[inline]
+    #[must_use]
+    pub const fn from_translation(translation: DVec2) -> Self {
+        Self {
+            scale: DVec2::ONE,
+            angle: 0.0,
+            translation,
+        }
+    }
+
+    /// Creates a transform from the given 2D `scale`, rotation `angle` (in radians) and
+    /// `translation`.
+    #[inline]
+    #[must_use]
+    pub const fn from_scale_angle_translation(
+        scale: DVec2,
+        angle: f64,
+        translation: DVec2,
+    ) -> Self {
+        Self {
+            scale,
+            angle,
+            translation,
+        }
+    }
+
+    /// Creates a transform from the scale, rotation and translation of an affine transform
+    /// using [`DAffine2::to_scale_angle_translation()`].
+    ///
+    /// The transform is expected to be non-degenerate and without shearing, or the output
+    /// will be invalid.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the determinant of `a.matrix2` is zero or if the resulting scale vector
+    /// contains any zero elements when `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn from_affine2(a: &DAffine2) -> Self {
+        let (scale, angle, translation) = a.to_scale_angle_translation();
+        Self {
+            scale,
+            angle,
+            translation,
+        }
+    }
+
+    /// Creates a transform from the scale, rotation and translation of a 3x3 matrix.
+    ///
+    /// The matrix is expected to be a non-degenerate affine transform without shearing, or the
+    /// output will be invalid.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the determinant of the upper 2x2 part of the matrix is zero or if the
+    /// resulting scale vector contains any zero elements when `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn from_mat3(m: &DMat3) -> Self {
+        Self::from_affine2(&DAffine2::from_mat3(*m))
+    }
+
+    /// Returns the scale, rotation angle (in radians) and translation of `self`.
+    #[inline]
+    #[must_use]
+    pub fn to_scale_angle_translation(&self) -> (DVec2, f64, DVec2) {
+        (self.scale, self.angle, self.translation)
+    }
+
+    /// Converts `self` to an [`DAffine2`].
+    #[inline]
+    #[must_use]
+    pub fn to_affine2(&self) -> DAffine2 {
+        DAffine2::from_scale_angle_translation(self.scale, self.angle, self.translation)
+    }
+
+    /// Converts `self` to a 3x3 homogeneous transformation matrix.
+    #[inline]
+    #[must_use]
+    pub fn to_mat3(&self) -> DMat3 {
+        DMat3::from_scale_angle_translation(self.scale, self.angle, self.translation)
+    }
+
+    /// Transforms the given 2D point, applying scale, rotation and translation.
+    #[inline]
+    #[must_use]
+    pub fn transform_point2(&self, rhs: DVec2) -> DVec2 {
+        self.transform_vector2(rhs) + self.translation
+    }
+
+    /// Transforms the given 2D vector, applying scale and rotation (but NOT translation).
+    ///
+    /// To also apply translation, use [`Self::transform_point2()`] instead.
+    #[inline]
+    #[must_use]
+    pub fn transform_vector2(&self, rhs: DVec2) -> DVec2 {
+        DVec2::from_angle(self.angle).rotate(self.scale * rhs)
+    }
+
+    /// Returns the transform that applies `rhs` first and then `self`.
+    ///
+    /// The scales are multiplied, the angles are added and the translation of `rhs` is
+    /// transformed by `self`. See the [non-uniform scale](Self#non-uniform-scale) section for
+    /// when this is exact.
+    #[inline]
+    #[must_use]
+    pub fn mul_transform(&self, rhs: &Self) -> Self {
+        Self {
+            scale: self.scale * rhs.scale,
+            angle: self.angle + rhs.angle,
+            translation: self.transform_point2(rhs.translation),
+        }
+    }
+
+    /// Returns the inverse of `self`.
+    ///
+    /// See the [non-uniform scale](Self#non-uniform-scale) section for when this is exact.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if any element of `self.scale` is zero when `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn inverse(&self) -> Self {
+        glam_assert!(self.scale.cmpne(DVec2::ZERO).all());
+        let scale = self.scale.recip();
+        let angle = -self.angle;
+        Self {
+            scale,
+            angle,
+            translation: -(scale * DVec2::from_angle(angle).rotate(self.translation)),
+        }
+    }
+
+    /// Performs a linear interpolation between `self` and `end` based on the value `s`.
+    ///
+    /// The scale and translation are interpolated linearly and the angle is interpolated along
+    /// the shortest path.
+    ///
+    /// When `s` is `0.0`, the result will be equal to `self`.  When `s` is `1.0`, the result
+    /// will be equal to `end` up to a multiple of `2π` in the angle.
+    #[inline]
+    #[must_use]
+    pub fn lerp(&self, end: Self, s: f64) -> Self {
+        let (sin, cos) = math::sin_cos(end.angle - self.angle);
+        let delta = math::atan2(sin, cos);
+        Self {
+            scale: self.scale.lerp(end.scale, s),
+            angle: self.angle + delta * s,
+            translation: self.translation.lerp(end.translation, s),
+        }
+    }
+
+    /// Returns `true` if, and only if, all elements are finite.
+    ///
+    /// If any element is either `NaN`, positive or negative infinity, this will return
+    /// `false`.
+    #[inline]
+    #[must_use]
+    pub fn is_finite(&self) -> bool {
+        self.scale.is_finite() && self.angle.is_finite() && self.translation.is_finite()
+    }
+
+    /// Returns `true` if any elements are `NaN`.
+    #[inline]
+    #[must_use]
+    pub fn is_nan(&self) -> bool {
+        self.scale.is_nan() || self.angle.is_nan() || self.translation.is_nan()
+    }
+
+    /// Returns true if the absolute difference of all elements between `self` and `rhs`
+    /// is less than or equal to `max_abs_diff`.
+    ///
+    /// This can be used to compare if two transforms contain similar elements. It works best
+    /// when comparing with a known value. The `max_abs_diff` that should be used used depends on
+    /// the values being compared against.
+    ///
+    /// For more see
+    /// [comparing floating point numbers](https://randomascii.wordpress.com/2012/02/25/comparing-floating-point-numbers-2012-edition/).
+    #[inline]
+    #[must_use]
+    pub fn abs_diff_eq(&self, rhs: Self, max_abs_diff: f64) -> bool {
+        self.scale.abs_diff_eq(rhs.scale, max_abs_diff)
+            && math::abs(self.angle - rhs.angle) <= max_abs_diff
+            && self.translation.abs_diff_eq(rhs.translation, max_abs_diff)
+    }
+
+    /// Casts all elements of `self` to `f32`.
+    #[inline]
+    #[must_use]
+    pub fn as_transform2(&self) -> Transform2 {
+        Transform2 {
+            scale: self.scale.as_vec2(),
+            angle: self.angle as f32,
+            translation: self.translation.as_vec2(),
+        }
+    }
+}
+
+impl Default for DTransform2 {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl fmt::Debug for DTransform2 {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct(stringify!(DTransform2))
+            .field("scale", &self.scale)
+            .field("angle", &self.angle)
+            .field("translation", &self.translation)
+            .finish()
+    }
+}
+
+impl fmt::Display for DTransform2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(p) = f.precision() {
+            write!(
+                f,
+                "[{:.*}, {:.*}, {:.*}]",
+                p, self.scale, p, self.angle, p, self.translation
+            )
+        } else {
+            write!(f, "[{}, {}, {}]", self.scale, self.angle, self.translation)
+        }
+    }
+}
+
+impl<'a> Product<&'a Self> for DTransform2 {
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.fold(Self::IDENTITY, |a, b| a.mul_transform(b))
+    }
+}
+
+impl Mul for DTransform2 {
+    type Output = Self;
+    /// Multiplies two transforms, see [`Self::mul_transform()`].
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        self.mul_transform(&rhs)
+    }
+}
+
+impl Mul<&Self> for DTransform2 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: &Self) -> Self {
+        self.mul(*rhs)
+    }
+}
+
+impl Mul<&DTransform2> for &DTransform2 {
+    type Output = DTransform2;
+    #[inline]
+    fn mul(self, rhs: &DTransform2) -> DTransform2 {
+        (*self).mul(*rhs)
+    }
+}
+
+impl Mul<DTransform2> for &DTransform2 {
+    type Output = DTransform2;
+    #[inline]
+    fn mul(self, rhs: DTransform2) -> DTransform2 {
+        (*self).mul(rhs)
+    }
+}
+
+impl MulAssign for DTransform2 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.mul(rhs);
+    }
+}
+
+impl MulAssign<&Self> for DTransform2 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: &Self) {
+        self.mul_assign(*rhs);
+    }
+}
+
+impl From<[f64; 5]> for DTransform2 {
+    #[inline]
+    fn from(a: [f64; 5]) -> Self {
+        Self::from_array(a)
+    }
+}
+
+impl From<DTransform2> for [f64; 5] {
+    #[inline]
+    fn from(t: DTransform2) -> Self {
+        t.to_array()
+    }
+}
+
+impl From<DTransform2> for DAffine2 {
+    #[inline]
+    fn from(t: DTransform2) -> Self {
+        t.to_affine2()
+    }
+}
+
+impl From<DTransform2> for DMat3 {
+    #[inline]
+    fn from(t: DTransform2) -> Self {
+        t.to_mat3()
+    }
+}
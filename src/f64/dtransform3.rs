@@ -0,0 +1,495 @@
+// Generated from transform.rs.tera template. Edit the template, not the generated file.
+
+use crate::{DAffine3, DMat4, DQuat, DVec3, Transform3};
+use core::fmt;
+use core::iter::Product;
+use core::ops::{Mul, MulAssign};
+
+/// A 3D transform made of a `scale`, followed by a `rotation` and then a `translation`.
+///
+/// This is the decomposed form of an [`DAffine3`] without shear. It is convenient for
+/// building transform hierarchies as the components can be edited and interpolated
+/// independently.
+///
+/// The `rotation` is expected to be normalized.
+///
+/// # Non-uniform scale
+///
+/// A scale-rotation-translation transform is not closed under composition: the product of two
+/// transforms where the first has a non-uniform `scale` and the second is rotated contains shear
+/// which cannot be stored in this type. [`Self::mul_transform()`] and [`Self::inverse()`]
+/// multiply the scales, rotations and translations component-wise and discard that shear, so
+/// they are only exact when `scale` is uniform or does not change the rotated axes, for example
+/// when the rotation is the identity. Convert to [`DAffine3`] when the exact product of
+/// non-uniformly scaled transforms is needed.
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(C)]
+pub struct DTransform3 {
+    pub scale: DVec3,
+    pub rotation: DQuat,
+    pub translation: DVec3,
+}
+
+impl DTransform3 {
+    /// The identity transform.
+    ///
+    /// Multiplying a vector with this returns the same vector.
+    pub const IDENTITY: Self = Self {
+        scale: DVec3::ONE,
+        rotation: DQuat::IDENTITY,
+        translation: DVec3::ZERO,
+    };
+
+    /// All NAN:s.
+    pub const NAN: Self = Self {
+        scale: DVec3::NAN,
+        rotation: DQuat::NAN,
+        translation: DVec3::NAN,
+    };
+
+    /// Creates a transform from a `[f64; 10]` array containing the `scale`,
+    /// the `rotation` in `x`, `y`, `z`, `w` order and the `translation`.
+    #[inline]
+    #[must_use]
+    pub const fn from_array(a: [f64; 10]) -> Self {
+        Self {
+            scale: DVec3::new(a[0], a[1], a[2]),
+            rotation: DQuat::from_xyzw(a[3], a[4], a[5], a[6]),
+            translation: DVec3::new(a[7], a[8], a[9]),
+        }
+    }
+
+    /// Returns the `scale`, the `rotation` in `x`, `y`, `z`, `w` order and the `translation` as a
+    /// `[f64; 10]` array.
+    #[inline]
+    #[must_use]
+    pub fn to_array(&self) -> [f64; 10] {
+        let s = self.scale;
+        let [x, y, z, w] = self.rotation.to_array();
+        let t = self.translation;
+        [s.x, s.y, s.z, x, y, z, w, t.x, t.y, t.z]
+    }
+
+    /// Creates a transform from the given non-uniform 3D `scale`.
+    #[inline]
+    #[must_use]
+    pub const fn from_scale(scale: DVec3) -> Self {
+        Self {
+            scale,
+            rotation: DQuat::IDENTITY,
+            translation: DVec3::ZERO,
+        }
+    }
+
+    /// Creates a transform from the given `rotation` quaternion.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `rotation` is not normalized when `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn from_quat(rotation: DQuat) -> Self {
+        glam_assert!(rotation.is_normalized());
+        Self {
+            scale: DVec3::ONE,
+            rotation,
+            translation: DVec3::ZERO,
+        }
+    }
+
+    /// Creates a transform from the given 3D `translation`.
+    #[inline]
+    #[must_use]
+    pub const fn from_translation(translation: DVec3) -> Self {
+        Self {
+            scale: DVec3::ONE,
+            rotation: DQuat::IDENTITY,
+            translation,
+        }
+    }
+
+    /// Creates a transform from the given `rotation` followed by the given `translation`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `rotation` is not normalized when `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn from_rotation_translation(rotation: DQuat, translation: DVec3) -> Self {
+        Self::from_scale_rotation_translation(DVec3::ONE, rotation, translation)
+    }
+
+    /// Creates a transform from the given 3D `scale`, `rotation` and `translation`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `rotation` is not normalized when `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn from_scale_rotation_translation(
+        scale: DVec3,
+        rotation: DQuat,
+        translation: DVec3,
+    ) -> Self {
+        glam_assert!(rotation.is_normalized());
+        Self {
+            scale,
+            rotation,
+            translation,
+        }
+    }
+
+    /// Creates a transform from the scale, rotation and translation of an affine transform
+    /// using [`DAffine3::to_scale_rotation_translation()`].
+    ///
+    /// The transform is expected to be non-degenerate and without shearing, or the output
+    /// will be invalid.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the determinant of `a.matrix3` is zero or if the resulting scale vector
+    /// contains any zero elements when `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn from_affine3(a: &DAffine3) -> Self {
+        let (scale, rotation, translation) = a.to_scale_rotation_translation();
+        Self {
+            scale,
+            rotation,
+            translation,
+        }
+    }
+
+    /// Creates a transform from the scale, rotation and translation of a 4x4 matrix using
+    /// [`DMat4::to_scale_rotation_translation()`].
+    ///
+    /// The matrix is expected to be a non-degenerate affine transform without shearing, or the
+    /// output will be invalid.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the determinant of the upper 3x3 part of the matrix is zero or if the
+    /// resulting scale vector contains any zero elements when `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn from_mat4(m: &DMat4) -> Self {
+        let (scale, rotation, translation) = m.to_scale_rotation_translation();
+        Self {
+            scale,
+            rotation,
+            translation,
+        }
+    }
+
+    /// Returns the scale, rotation and translation of `self`.
+    #[inline]
+    #[must_use]
+    pub fn to_scale_rotation_translation(&self) -> (DVec3, DQuat, DVec3) {
+        (self.scale, self.rotation, self.translation)
+    }
+
+    /// Converts `self` to an [`DAffine3`].
+    #[inline]
+    #[must_use]
+    pub fn to_affine3(&self) -> DAffine3 {
+        DAffine3::from_scale_rotation_translation(self.scale, self.rotation, self.translation)
+    }
+
+    /// Converts `self` to a 4x4 homogeneous transformation matrix.
+    #[inline]
+    #[must_use]
+    pub fn to_mat4(&self) -> DMat4 {
+        DMat4::from_scale_rotation_translation(self.scale, self.rotation, self.translation)
+    }
+
+    /// Rotates `self` so that its local `-Z` axis points towards `target` and its local `+Y`
+    /// axis points towards `up` as much as possible. The scale and translation are unchanged.
+    ///
+    /// This follows the right-handed convention of [`DAffine3::look_at_rh()`], but where
+    /// that creates a view transform this sets the rotation of the object itself, which is the
+    /// inverse of the view rotation.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `up` is not normalized when `glam_assert` is enabled.
+    #[inline]
+    pub fn look_at(&mut self, target: DVec3, up: DVec3) {
+        self.look_to(target - self.translation, up);
+    }
+
+    /// Rotates `self` so that its local `-Z` axis points in `direction` and its local `+Y`
+    /// axis points towards `up` as much as possible. The scale and translation are unchanged.
+    ///
+    /// See [`Self::look_at()`] for the conventions used.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `up` is not normalized when `glam_assert` is enabled.
+    #[inline]
+    pub fn look_to(&mut self, direction: DVec3, up: DVec3) {
+        self.rotation = DQuat::look_to_rh(direction.normalize(), up).inverse();
+    }
+
+    /// Transforms the given 3D point, applying scale, rotation and translation.
+    #[inline]
+    #[must_use]
+    pub fn transform_point3(&self, rhs: DVec3) -> DVec3 {
+        self.transform_vector3(rhs) + self.translation
+    }
+
+    /// Transforms the given 3D vector, applying scale and rotation (but NOT translation).
+    ///
+    /// To also apply translation, use [`Self::transform_point3()`] instead.
+    #[inline]
+    #[must_use]
+    pub fn transform_vector3(&self, rhs: DVec3) -> DVec3 {
+        self.rotation * (self.scale * rhs)
+    }
+
+    /// Returns the transform that applies `rhs` first and then `self`.
+    ///
+    /// The scales and rotations are multiplied and the translation of `rhs` is transformed by
+    /// `self`. See the [non-uniform scale](Self#non-uniform-scale) section for when this is
+    /// exact.
+    #[inline]
+    #[must_use]
+    pub fn mul_transform(&self, rhs: &Self) -> Self {
+        Self {
+            scale: self.scale * rhs.scale,
+            rotation: self.rotation * rhs.rotation,
+            translation: self.transform_point3(rhs.translation),
+        }
+    }
+
+    /// Returns the inverse of `self`.
+    ///
+    /// See the [non-uniform scale](Self#non-uniform-scale) section for when this is exact.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if any element of `self.scale` is zero or if `self.rotation` is not
+    /// normalized when `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn inverse(&self) -> Self {
+        glam_assert!(self.scale.cmpne(DVec3::ZERO).all());
+        let scale = self.scale.recip();
+        let rotation = self.rotation.inverse();
+        Self {
+            scale,
+            rotation,
+            translation: -(scale * (rotation * self.translation)),
+        }
+    }
+
+    /// Performs a linear interpolation between `self` and `end` based on the value `s`.
+    ///
+    /// The scale and translation are interpolated linearly and the rotation using
+    /// [`DQuat::lerp()`], which is faster than [`Self::slerp()`] but does not have a
+    /// constant angular velocity.
+    ///
+    /// When `s` is `0.0`, the result will be equal to `self`.  When `s` is `1.0`, the result
+    /// will be equal to `end`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `self.rotation` or `end.rotation` are not normalized when `glam_assert` is
+    /// enabled.
+    #[inline]
+    #[must_use]
+    pub fn lerp(&self, end: Self, s: f64) -> Self {
+        Self {
+            scale: self.scale.lerp(end.scale, s),
+            rotation: self.rotation.lerp(end.rotation, s),
+            translation: self.translation.lerp(end.translation, s),
+        }
+    }
+
+    /// Performs a spherical linear interpolation between `self` and `end` based on the value
+    /// `s`.
+    ///
+    /// The scale and translation are interpolated linearly and the rotation using
+    /// [`DQuat::slerp()`].
+    ///
+    /// When `s` is `0.0`, the result will be equal to `self`.  When `s` is `1.0`, the result
+    /// will be equal to `end`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `self.rotation` or `end.rotation` are not normalized when `glam_assert` is
+    /// enabled.
+    #[inline]
+    #[must_use]
+    pub fn slerp(&self, end: Self, s: f64) -> Self {
+        Self {
+            scale: self.scale.lerp(end.scale, s),
+            rotation: self.rotation.slerp(end.rotation, s),
+            translation: self.translation.lerp(end.translation, s),
+        }
+    }
+
+    /// Returns `true` if, and only if, all elements are finite.
+    ///
+    /// If any element is either `NaN`, positive or negative infinity, this will return
+    /// `false`.
+    #[inline]
+    #[must_use]
+    pub fn is_finite(&self) -> bool {
+        self.scale.is_finite() && self.rotation.is_finite() && self.translation.is_finite()
+    }
+
+    /// Returns `true` if any elements are `NaN`.
+    #[inline]
+    #[must_use]
+    pub fn is_nan(&self) -> bool {
+        self.scale.is_nan() || self.rotation.is_nan() || self.translation.is_nan()
+    }
+
+    /// Returns true if the absolute difference of all elements between `self` and `rhs`
+    /// is less than or equal to `max_abs_diff`.
+    ///
+    /// This can be used to compare if two transforms contain similar elements. It works best
+    /// when comparing with a known value. The `max_abs_diff` that should be used used depends on
+    /// the values being compared against.
+    ///
+    /// For more see
+    /// [comparing floating point numbers](https://randomascii.wordpress.com/2012/02/25/comparing-floating-point-numbers-2012-edition/).
+    #[inline]
+    #[must_use]
+    pub fn abs_diff_eq(&self, rhs: Self, max_abs_diff: f64) -> bool {
+        self.scale.abs_diff_eq(rhs.scale, max_abs_diff)
+            && self.rotation.abs_diff_eq(rhs.rotation, max_abs_diff)
+            && self.translation.abs_diff_eq(rhs.translation, max_abs_diff)
+    }
+
+    /// Casts all elements of `self` to `f32`.
+    #[inline]
+    #[must_use]
+    pub fn as_transform3(&self) -> Transform3 {
+        Transform3 {
+            scale: self.scale.as_vec3(),
+            rotation: self.rotation.as_quat(),
+            translation: self.translation.as_vec3(),
+        }
+    }
+}
+
+impl Default for DTransform3 {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl fmt::Debug for DTransform3 {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct(stringify!(DTransform3))
+            .field("scale", &self.scale)
+            .field("rotation", &self.rotation)
+            .field("translation", &self.translation)
+            .finish()
+    }
+}
+
+impl fmt::Display for DTransform3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(p) = f.precision() {
+            write!(
+                f,
+                "[{:.*}, {:.*}, {:.*}]",
+                p, self.scale, p, self.rotation, p, self.translation
+            )
+        } else {
+            write!(
+                f,
+                "[{}, {}, {}]",
+                self.scale, self.rotation, self.translation
+            )
+        }
+    }
+}
+
+impl<'a> Product<&'a Self> for DTransform3 {
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.fold(Self::IDENTITY, |a, b| a.mul_transform(b))
+    }
+}
+
+impl Mul for DTransform3 {
+    type Output = Self;
+    /// Multiplies two transforms, see [`Self::mul_transform()`].
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        self.mul_transform(&rhs)
+    }
+}
+
+impl Mul<&Self> for DTransform3 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: &Self) -> Self {
+        self.mul(*rhs)
+    }
+}
+
+impl Mul<&DTransform3> for &DTransform3 {
+    type Output = DTransform3;
+    #[inline]
+    fn mul(self, rhs: &DTransform3) -> DTransform3 {
+        (*self).mul(*rhs)
+    }
+}
+
+impl Mul<DTransform3> for &DTransform3 {
+    type Output = DTransform3;
+    #[inline]
+    fn mul(self, rhs: DTransform3) -> DTransform3 {
+        (*self).mul(rhs)
+    }
+}
+
+impl MulAssign for DTransform3 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.mul(rhs);
+    }
+}
+
+impl MulAssign<&Self> for DTransform3 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: &Self) {
+        self.mul_assign(*rhs);
+    }
+}
+
+impl From<[f64; 10]> for DTransform3 {
+    #[inline]
+    fn from(a: [f64; 10]) -> Self {
+        Self::from_array(a)
+    }
+}
+
+impl From<DTransform3> for [f64; 10] {
+    #[inline]
+    fn from(t: DTransform3) -> Self {
+        t.to_array()
+    }
+}
+
+impl From<DTransform3> for DAffine3 {
+    #[inline]
+    fn from(t: DTransform3) -> Self {
+        t.to_affine3()
+    }
+}
+
+impl From<DTransform3> for DMat4 {
+    #[inline]
+    fn from(t: DTransform3) -> Self {
+        t.to_mat4()
+    }
+}
@@ -0,0 +1,5 @@
+pub mod dmat4;
+pub mod dquat;
+pub mod dvec2;
+pub mod dvec3;
+pub mod dvec4;
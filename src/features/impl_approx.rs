@@ -1,6 +1,7 @@
 use crate::{
-    Affine2, Affine3, Affine3A, DAffine2, DAffine3, DMat2, DMat3, DMat4, DQuat, DVec2, DVec3,
-    DVec4, Mat2, Mat3, Mat3A, Mat4, Quat, Vec2, Vec3, Vec3A, Vec4,
+    Affine2, Affine3, Affine3A, DAffine2, DAffine3, DIsometry3, DMat2, DMat3, DMat4, DQuat, DRot2,
+    DTransform2, DTransform3, DVec2, DVec3, DVec4, Isometry2, Isometry3A, Mat2, Mat3, Mat3A, Mat4,
+    Quat, Rot2, Transform2, Transform3, Vec2, Vec3, Vec3A, Vec4,
 };
 use approx::{AbsDiffEq, RelativeEq, UlpsEq};
 
@@ -131,6 +132,143 @@ macro_rules! impl_approx_xzyw_axes {
     };
 }
 
+macro_rules! impl_approx_rot2 {
+    ($prim:ident, $type:ty) => {
+        impl AbsDiffEq for $type {
+            type Epsilon = <$prim as AbsDiffEq>::Epsilon;
+            fn default_epsilon() -> Self::Epsilon {
+                $prim::default_epsilon()
+            }
+            fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+                self.to_array().abs_diff_eq(&other.to_array(), epsilon)
+            }
+        }
+
+        impl RelativeEq for $type {
+            fn default_max_relative() -> Self::Epsilon {
+                $prim::default_max_relative()
+            }
+            fn relative_eq(
+                &self,
+                other: &Self,
+                epsilon: Self::Epsilon,
+                max_relative: Self::Epsilon,
+            ) -> bool {
+                self.to_array()
+                    .relative_eq(&other.to_array(), epsilon, max_relative)
+            }
+        }
+
+        impl UlpsEq for $type {
+            fn default_max_ulps() -> u32 {
+                $prim::default_max_ulps()
+            }
+            fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+                self.to_array()
+                    .ulps_eq(&other.to_array(), epsilon, max_ulps)
+            }
+        }
+    };
+}
+
+macro_rules! impl_approx_isometry {
+    ($prim:ident, $type:ty) => {
+        impl AbsDiffEq for $type {
+            type Epsilon = <$prim as AbsDiffEq>::Epsilon;
+            fn default_epsilon() -> Self::Epsilon {
+                $prim::default_epsilon()
+            }
+            fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+                AbsDiffEq::abs_diff_eq(&self.rotation, &other.rotation, epsilon)
+                    && AbsDiffEq::abs_diff_eq(&self.translation, &other.translation, epsilon)
+            }
+        }
+
+        impl RelativeEq for $type {
+            fn default_max_relative() -> Self::Epsilon {
+                $prim::default_max_relative()
+            }
+            fn relative_eq(
+                &self,
+                other: &Self,
+                epsilon: Self::Epsilon,
+                max_relative: Self::Epsilon,
+            ) -> bool {
+                RelativeEq::relative_eq(&self.rotation, &other.rotation, epsilon, max_relative)
+                    && RelativeEq::relative_eq(
+                        &self.translation,
+                        &other.translation,
+                        epsilon,
+                        max_relative,
+                    )
+            }
+        }
+
+        impl UlpsEq for $type {
+            fn default_max_ulps() -> u32 {
+                $prim::default_max_ulps()
+            }
+            fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+                UlpsEq::ulps_eq(&self.rotation, &other.rotation, epsilon, max_ulps)
+                    && UlpsEq::ulps_eq(&self.translation, &other.translation, epsilon, max_ulps)
+            }
+        }
+    };
+}
+
+macro_rules! impl_approx_transform {
+    ($prim:ident, $type:ty, $rotation:ident) => {
+        impl AbsDiffEq for $type {
+            type Epsilon = <$prim as AbsDiffEq>::Epsilon;
+            fn default_epsilon() -> Self::Epsilon {
+                $prim::default_epsilon()
+            }
+            fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+                AbsDiffEq::abs_diff_eq(&self.scale, &other.scale, epsilon)
+                    && AbsDiffEq::abs_diff_eq(&self.$rotation, &other.$rotation, epsilon)
+                    && AbsDiffEq::abs_diff_eq(&self.translation, &other.translation, epsilon)
+            }
+        }
+
+        impl RelativeEq for $type {
+            fn default_max_relative() -> Self::Epsilon {
+                $prim::default_max_relative()
+            }
+            fn relative_eq(
+                &self,
+                other: &Self,
+                epsilon: Self::Epsilon,
+                max_relative: Self::Epsilon,
+            ) -> bool {
+                RelativeEq::relative_eq(&self.scale, &other.scale, epsilon, max_relative)
+                    && RelativeEq::relative_eq(
+                        &self.$rotation,
+                        &other.$rotation,
+                        epsilon,
+                        max_relative,
+                    )
+                    && RelativeEq::relative_eq(
+                        &self.translation,
+                        &other.translation,
+                        epsilon,
+                        max_relative,
+                    )
+            }
+        }
+
+        impl UlpsEq for $type {
+            fn default_max_ulps() -> u32 {
+                $prim::default_max_ulps()
+            }
+            fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+                UlpsEq::ulps_eq(&self.scale, &other.scale, epsilon, max_ulps)
+                    && UlpsEq::ulps_eq(&self.$rotation, &other.$rotation, epsilon, max_ulps)
+                    && UlpsEq::ulps_eq(&self.translation, &other.translation, epsilon, max_ulps)
+            }
+        }
+    };
+}
+
 impl_approx_as_ref!(f32, Mat2);
 impl_approx_as_ref!(f32, Mat3);
 impl_approx_as_ref!(f32, Mat4);
@@ -144,9 +282,18 @@ impl_approx_xzy_axes!(f32, Affine2);
 impl_approx_xzyw_axes!(f32, Affine3);
 impl_approx_xzyw_axes!(f32, Affine3A);
 impl_approx_xzy_axes!(f32, Mat3A);
+impl_approx_rot2!(f32, Rot2);
+impl_approx_isometry!(f32, Isometry2);
+impl_approx_isometry!(f32, Isometry3A);
+impl_approx_transform!(f32, Transform2, angle);
+impl_approx_transform!(f32, Transform3, rotation);
 
 impl_approx_xzy_axes!(f64, DAffine2);
 impl_approx_xzyw_axes!(f64, DAffine3);
+impl_approx_rot2!(f64, DRot2);
+impl_approx_isometry!(f64, DIsometry3);
+impl_approx_transform!(f64, DTransform2, angle);
+impl_approx_transform!(f64, DTransform3, rotation);
 impl_approx_as_ref!(f64, DMat2);
 impl_approx_as_ref!(f64, DMat3);
 impl_approx_as_ref!(f64, DMat4);
@@ -211,6 +358,30 @@ mod test {
             approx::assert_ulps_ne!($type::$from(ones), $type::$from(four_ulp));
         };
     }
+    macro_rules! impl_array_approx_test {
+        ($prim:ident, $type:ident, $n:literal) => {
+            let ones = |v: $prim| $type::from_array([v; $n]);
+            let one_eps = $prim::default_epsilon();
+            let two_eps = one_eps + one_eps;
+
+            let one_ulp = $prim::from_bits($prim::to_bits(1.0) + 1);
+            let four_ulp = $prim::from_bits($prim::to_bits(1.0) + 16);
+
+            approx::assert_abs_diff_eq!(ones(1.0), ones(1.0));
+            approx::assert_abs_diff_eq!(ones(1.0), ones(1.0 + one_eps));
+            approx::assert_abs_diff_eq!(ones(1.0), ones(1.0 - one_eps));
+
+            approx::assert_abs_diff_ne!(ones(1.0), ones(1.0 + two_eps));
+            approx::assert_abs_diff_ne!(ones(1.0), ones(1.0 - two_eps));
+
+            approx::assert_relative_eq!(ones(1.0), ones(1.0));
+            approx::assert_relative_ne!(ones(1.0), ones(0.0));
+
+            approx::assert_ulps_eq!(ones(1.0), ones(one_ulp));
+            approx::assert_ulps_ne!(ones(1.0), ones(four_ulp));
+        };
+    }
+
     #[test]
     fn test_approx() {
         const ONESF32: [f32; 16] = [1.0; 16];
@@ -245,6 +416,11 @@ mod test {
             Mat4,
             Affine3A::from_cols_slice(&ONESF32)
         );
+        impl_array_approx_test!(f32, Rot2, 2);
+        impl_array_approx_test!(f32, Isometry2, 6);
+        impl_array_approx_test!(f32, Isometry3A, 7);
+        impl_array_approx_test!(f32, Transform2, 5);
+        impl_array_approx_test!(f32, Transform3, 10);
 
         const ONESF64: [f64; 16] = [1.0; 16];
         impl_approx_test!(f64, DVec2);
@@ -268,5 +444,9 @@ mod test {
             DMat4,
             DAffine3::from_cols_slice(&ONESF64)
         );
+        impl_array_approx_test!(f64, DRot2, 2);
+        impl_array_approx_test!(f64, DIsometry3, 7);
+        impl_array_approx_test!(f64, DTransform2, 5);
+        impl_array_approx_test!(f64, DTransform3, 10);
     }
 }
@@ -1,8 +1,9 @@
 use crate::{
     BVec2, BVec3, BVec3A, BVec4, BVec4A, DQuat, DVec2, DVec3, DVec4, I16Vec2, I16Vec3, I16Vec4,
     I64Vec2, I64Vec3, I64Vec4, I8Vec2, I8Vec3, I8Vec4, ISizeVec2, ISizeVec3, ISizeVec4, IVec2,
-    IVec3, IVec4, Quat, U16Vec2, U16Vec3, U16Vec4, U64Vec2, U64Vec3, U64Vec4, U8Vec2, U8Vec3,
-    U8Vec4, USizeVec2, USizeVec3, USizeVec4, UVec2, UVec3, UVec4, Vec2, Vec3, Vec3A, Vec4,
+    IVec3, IVec3A, IVec4, Quat, U16Vec2, U16Vec3, U16Vec4, U64Vec2, U64Vec3, U64Vec4, U8Vec2,
+    U8Vec3, U8Vec4, USizeVec2, USizeVec3, USizeVec4, UVec2, UVec3, UVec3A, UVec4, Vec2, Vec3,
+    Vec3A, Vec4,
 };
 
 macro_rules! arbitrary_vector_impl {
@@ -19,9 +20,10 @@ macro_rules! arbitrary_vector_impl {
 
 arbitrary_vector_impl!(
     BVec2, BVec3, BVec3A, BVec4, BVec4A, DQuat, DVec2, DVec3, DVec4, I16Vec2, I16Vec3, I16Vec4,
-    I64Vec2, I64Vec3, I64Vec4, I8Vec2, I8Vec3, I8Vec4, IVec2, IVec3, IVec4, Quat, U16Vec2, U16Vec3,
-    U16Vec4, U64Vec2, U64Vec3, U64Vec4, U8Vec2, U8Vec3, U8Vec4, USizeVec2, USizeVec3, USizeVec4,
-    UVec2, UVec3, UVec4, Vec2, Vec3, Vec3A, Vec4, ISizeVec2, ISizeVec3, ISizeVec4
+    I64Vec2, I64Vec3, I64Vec4, I8Vec2, I8Vec3, I8Vec4, IVec2, IVec3, IVec3A, IVec4, Quat, U16Vec2,
+    U16Vec3, U16Vec4, U64Vec2, U64Vec3, U64Vec4, U8Vec2, U8Vec3, U8Vec4, USizeVec2, USizeVec3,
+    USizeVec4, UVec2, UVec3, UVec3A, UVec4, Vec2, Vec3, Vec3A, Vec4, ISizeVec2, ISizeVec3,
+    ISizeVec4
 );
 
 use crate::{
@@ -53,10 +55,10 @@ mod test {
     use crate::{
         Affine2, Affine3, Affine3A, BVec2, BVec3, BVec3A, BVec4, BVec4A, DAffine2, DAffine3, DMat2,
         DMat3, DMat4, DQuat, DVec2, DVec3, DVec4, I16Vec2, I16Vec3, I16Vec4, I64Vec2, I64Vec3,
-        I64Vec4, I8Vec2, I8Vec3, I8Vec4, ISizeVec2, ISizeVec3, ISizeVec4, IVec2, IVec3, IVec4,
-        Mat2, Mat3, Mat3A, Mat4, Quat, U16Vec2, U16Vec3, U16Vec4, U64Vec2, U64Vec3, U64Vec4,
-        U8Vec2, U8Vec3, U8Vec4, USizeVec2, USizeVec3, USizeVec4, UVec2, UVec3, UVec4, Vec2, Vec3,
-        Vec3A, Vec4,
+        I64Vec4, I8Vec2, I8Vec3, I8Vec4, ISizeVec2, ISizeVec3, ISizeVec4, IVec2, IVec3, IVec3A,
+        IVec4, Mat2, Mat3, Mat3A, Mat4, Quat, U16Vec2, U16Vec3, U16Vec4, U64Vec2, U64Vec3, U64Vec4,
+        U8Vec2, U8Vec3, U8Vec4, USizeVec2, USizeVec3, USizeVec4, UVec2, UVec3, UVec3A, UVec4, Vec2,
+        Vec3, Vec3A, Vec4,
     };
 
     #[test]
@@ -331,6 +333,10 @@ mod test {
             UVec3::new(1, 2, 3),
             Unstructured::new(&bytes).arbitrary().unwrap()
         );
+        assert_eq!(
+            UVec3A::new(1, 2, 3),
+            Unstructured::new(&bytes).arbitrary().unwrap()
+        );
         assert_eq!(
             UVec4::new(1, 2, 3, 4),
             Unstructured::new(&bytes).arbitrary().unwrap()
@@ -353,6 +359,10 @@ mod test {
             IVec3::new(1, 2, 3),
             Unstructured::new(&bytes).arbitrary().unwrap()
         );
+        assert_eq!(
+            IVec3A::new(1, 2, 3),
+            Unstructured::new(&bytes).arbitrary().unwrap()
+        );
         assert_eq!(
             IVec4::new(1, 2, 3, 4),
             Unstructured::new(&bytes).arbitrary().unwrap()
@@ -1,10 +1,13 @@
 #[cfg(test)]
 mod test {
     use crate::{
-        Affine2, Affine3, Affine3A, DAffine2, DAffine3, DMat2, DMat3, DMat4, DQuat, DVec2, DVec3,
-        DVec4, I16Vec2, I16Vec3, I16Vec4, I64Vec2, I64Vec3, I64Vec4, I8Vec2, I8Vec3, I8Vec4, IVec2,
-        IVec3, IVec4, Mat2, Mat3, Mat3A, Mat4, Quat, U16Vec2, U16Vec3, U16Vec4, U64Vec2, U64Vec3,
-        U64Vec4, U8Vec2, U8Vec3, U8Vec4, UVec2, UVec3, UVec4, Vec2, Vec3, Vec3A, Vec4,
+        Affine2, Affine3, Affine3A, DAffine2, DAffine3, DIsometry3, DMat2, DMat3, DMat4, DQuat,
+        DRot2, DTransform2, DTransform3, DVec2, DVec3, DVec4, HVec2, HVec3, HVec4, I16Vec2,
+        I16Vec3, I16Vec4, I64Mat2, I64Mat3, I64Mat4, I64Vec2, I64Vec3, I64Vec4, I8Vec2, I8Vec3,
+        I8Vec4, IMat2, IMat3, IMat4, IVec2, IVec3, IVec3A, IVec4, Isometry2, Isometry3A, Mat2,
+        Mat2x3, Mat3, Mat3A, Mat3x2, Mat3x4, Mat4, Mat4x3, Quat, Rot2, Transform2, Transform3,
+        U16Vec2, U16Vec3, U16Vec4, U64Vec2, U64Vec3, U64Vec4, U8Vec2, U8Vec3, U8Vec4, UVec2, UVec3,
+        UVec3A, UVec4, Vec2, Vec3, Vec3A, Vec4, F16,
     };
     use core::mem;
 
@@ -40,14 +43,37 @@ mod test {
         };
     }
 
+    macro_rules! test_any_bit_pattern_array_t {
+        ($name:ident, $t:ident) => {
+            #[test]
+            fn $name() {
+                let b = [0_u8; mem::size_of::<$t>()];
+                let t: $t = bytemuck::pod_read_unaligned(&b);
+                // should be the same size
+                assert_eq!(b.len(), mem::size_of_val(&t));
+                // should be zero
+                assert!(t.to_array().iter().all(|v| *v == 0.0));
+            }
+        };
+    }
+
     test_any_bit_pattern_t!(affine2, Affine2);
     test_pod_t!(affine3, Affine3);
     test_any_bit_pattern_t!(affine3a, Affine3A);
+    test_any_bit_pattern_array_t!(isometry2, Isometry2);
+    test_any_bit_pattern_array_t!(isometry3a, Isometry3A);
     test_pod_t!(mat2, Mat2);
+    test_pod_t!(mat2x3, Mat2x3);
     test_pod_t!(mat3, Mat3);
     test_any_bit_pattern_t!(mat3a, Mat3A);
+    test_pod_t!(mat3x2, Mat3x2);
+    test_pod_t!(mat3x4, Mat3x4);
     test_pod_t!(mat4, Mat4);
+    test_pod_t!(mat4x3, Mat4x3);
     test_pod_t!(quat, Quat);
+    test_pod_t!(rot2, Rot2);
+    test_pod_t!(transform2, Transform2);
+    test_any_bit_pattern_array_t!(transform3, Transform3);
     test_pod_t!(vec2, Vec2);
     test_pod_t!(vec3, Vec3);
     test_any_bit_pattern_t!(vec3a, Vec3A);
@@ -55,14 +81,23 @@ mod test {
 
     test_pod_t!(daffine2, DAffine2);
     test_pod_t!(daffine3, DAffine3);
+    test_pod_t!(disometry3, DIsometry3);
     test_pod_t!(dmat2, DMat2);
     test_pod_t!(dmat3, DMat3);
     test_pod_t!(dmat4, DMat4);
     test_pod_t!(dquat, DQuat);
+    test_pod_t!(drot2, DRot2);
+    test_pod_t!(dtransform2, DTransform2);
+    test_pod_t!(dtransform3, DTransform3);
     test_pod_t!(dvec2, DVec2);
     test_pod_t!(dvec3, DVec3);
     test_pod_t!(dvec4, DVec4);
 
+    test_pod_t!(f16, F16);
+    test_pod_t!(hvec2, HVec2);
+    test_pod_t!(hvec3, HVec3);
+    test_pod_t!(hvec4, HVec4);
+
     test_pod_t!(i8vec2, I8Vec2);
     test_pod_t!(i8vec3, I8Vec3);
     test_pod_t!(i8vec4, I8Vec4);
@@ -81,16 +116,26 @@ mod test {
 
     test_pod_t!(ivec2, IVec2);
     test_pod_t!(ivec3, IVec3);
+    test_any_bit_pattern_t!(ivec3a, IVec3A);
     test_pod_t!(ivec4, IVec4);
 
+    test_pod_t!(imat2, IMat2);
+    test_pod_t!(imat3, IMat3);
+    test_pod_t!(imat4, IMat4);
+
     test_pod_t!(uvec2, UVec2);
     test_pod_t!(uvec3, UVec3);
+    test_any_bit_pattern_t!(uvec3a, UVec3A);
     test_pod_t!(uvec4, UVec4);
 
     test_pod_t!(i64vec2, I64Vec2);
     test_pod_t!(i64vec3, I64Vec3);
     test_pod_t!(i64vec4, I64Vec4);
 
+    test_pod_t!(i64mat2, I64Mat2);
+    test_pod_t!(i64mat3, I64Mat3);
+    test_pod_t!(i64mat4, I64Mat4);
+
     test_pod_t!(u64vec2, U64Vec2);
     test_pod_t!(u64vec3, U64Vec3);
     test_pod_t!(u64vec4, U64Vec4);
@@ -1,9 +1,15 @@
-use crate::{IVec2, IVec3, IVec4, Mat2, Mat3, Mat4, UVec2, UVec3, UVec4, Vec2, Vec3, Vec4};
+use crate::{
+    IVec2, IVec3, IVec4, Mat2, Mat2x3, Mat3, Mat3x2, Mat3x4, Mat4, Mat4x3, UVec2, UVec3, UVec4,
+    Vec2, Vec3, Vec4,
+};
 use encase::{
     matrix::{impl_matrix, AsMutMatrixParts, AsRefMatrixParts, FromMatrixParts, MatrixScalar},
     vector::impl_vector,
 };
 
+// `F16` and the `HVec` types are not supported: `encase` only exposes the metadata required to
+// implement `ShaderType` for a new scalar type through its private API.
+
 impl_vector!(2, Vec2, f32; using AsRef AsMut From);
 impl_vector!(2, UVec2, u32; using AsRef AsMut From);
 impl_vector!(2, IVec2, i32; using AsRef AsMut From);
@@ -55,10 +61,18 @@ macro_rules! impl_matrix_traits {
 impl_matrix_traits!(2, 2, Mat2, f32);
 impl_matrix_traits!(3, 3, Mat3, f32);
 impl_matrix_traits!(4, 4, Mat4, f32);
+impl_matrix_traits!(2, 3, Mat2x3, f32);
+impl_matrix_traits!(3, 2, Mat3x2, f32);
+impl_matrix_traits!(3, 4, Mat3x4, f32);
+impl_matrix_traits!(4, 3, Mat4x3, f32);
 
 impl_matrix!(2, 2, Mat2, f32);
 impl_matrix!(3, 3, Mat3, f32);
 impl_matrix!(4, 4, Mat4, f32);
+impl_matrix!(2, 3, Mat2x3, f32);
+impl_matrix!(3, 2, Mat3x2, f32);
+impl_matrix!(3, 4, Mat3x4, f32);
+impl_matrix!(4, 3, Mat4x3, f32);
 
 #[cfg(test)]
 macro_rules! impl_vec_test {
@@ -86,17 +100,17 @@ macro_rules! impl_vec_test {
 
 #[cfg(test)]
 macro_rules! impl_mat_test {
-    ($dim:literal, $stride:literal, $test:ident, $ty:ty, $value:expr) => {
+    ($c:literal, $r:literal, $stride:literal, $test:ident, $ty:ty, $value:expr) => {
         #[test]
         fn $test() {
             let m = <$ty>::from_cols_array_2d(&$value);
 
-            let mut buf = [255u8; $dim * $stride * 4];
+            let mut buf = [255u8; $c * $stride * 4];
             let mut s_buf = StorageBuffer::new(&mut buf);
 
             s_buf.write(&m).unwrap();
-            for i in 0..$dim {
-                for j in 0..$dim {
+            for i in 0..$r {
+                for j in 0..$c {
                     let stride = j * $stride * 4;
                     let offset = i * 4;
                     assert_eq!(
@@ -117,7 +131,10 @@ macro_rules! impl_mat_test {
 
 #[cfg(test)]
 mod test {
-    use crate::{IVec2, IVec3, IVec4, Mat2, Mat3, Mat4, UVec2, UVec3, UVec4, Vec2, Vec3, Vec4};
+    use crate::{
+        IVec2, IVec3, IVec4, Mat2, Mat2x3, Mat3, Mat3x2, Mat3x4, Mat4, Mat4x3, UVec2, UVec3, UVec4,
+        Vec2, Vec3, Vec4,
+    };
     use encase::StorageBuffer;
 
     impl_vec_test!(2, vec2, Vec2, [1.12, 3.04]);
@@ -132,9 +149,10 @@ mod test {
     impl_vec_test!(4, ivec4, IVec4, [1, 3, 6, 9]);
     impl_vec_test!(4, uvec4, UVec4, [1, 3, 6, 9]);
 
-    impl_mat_test!(2, 2, mat2, Mat2, [[1.12, 3.04], [8.65, 2.34]]);
+    impl_mat_test!(2, 2, 2, mat2, Mat2, [[1.12, 3.04], [8.65, 2.34]]);
 
     impl_mat_test!(
+        3,
         3,
         4,
         mat3,
@@ -143,6 +161,7 @@ mod test {
     );
 
     impl_mat_test!(
+        4,
         4,
         4,
         mat4,
@@ -154,4 +173,49 @@ mod test {
             [3.56, 5.43, 77.90, 140.67]
         ]
     );
+
+    impl_mat_test!(
+        2,
+        3,
+        4,
+        mat2x3,
+        Mat2x3,
+        [[1.12, 3.04, 6.75], [8.65, 2.34, 94.6]]
+    );
+
+    impl_mat_test!(
+        3,
+        2,
+        2,
+        mat3x2,
+        Mat3x2,
+        [[1.12, 3.04], [8.65, 2.34], [5.45, 6.34]]
+    );
+
+    impl_mat_test!(
+        3,
+        4,
+        4,
+        mat3x4,
+        Mat3x4,
+        [
+            [1.12, 3.04, 6.75, 9.99],
+            [8.65, 2.34, 94.6, 113.3],
+            [5.45, 6.34, 89.41, 185.1]
+        ]
+    );
+
+    impl_mat_test!(
+        4,
+        3,
+        4,
+        mat4x3,
+        Mat4x3,
+        [
+            [1.12, 3.04, 6.75],
+            [8.65, 2.34, 94.6],
+            [5.45, 6.34, 89.41],
+            [3.56, 5.43, 77.90]
+        ]
+    );
 }
@@ -1,10 +1,11 @@
 use mint::IntoMint;
 
 use crate::{
-    DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4, I16Vec2, I16Vec3, I16Vec4, I64Vec2, I64Vec3,
-    I64Vec4, I8Vec2, I8Vec3, I8Vec4, ISizeVec2, ISizeVec3, ISizeVec4, IVec2, IVec3, IVec4, Mat2,
-    Mat3, Mat3A, Mat4, Quat, U16Vec2, U16Vec3, U16Vec4, U64Vec2, U64Vec3, U64Vec4, U8Vec2, U8Vec3,
-    U8Vec4, USizeVec2, USizeVec3, USizeVec4, UVec2, UVec3, UVec4, Vec2, Vec3, Vec3A, Vec4,
+    DMat2, DMat3, DMat4, DQuat, DRot2, DVec2, DVec3, DVec4, I16Vec2, I16Vec3, I16Vec4, I64Vec2,
+    I64Vec3, I64Vec4, I8Vec2, I8Vec3, I8Vec4, ISizeVec2, ISizeVec3, ISizeVec4, IVec2, IVec3,
+    IVec3A, IVec4, Mat2, Mat3, Mat3A, Mat4, Quat, Rot2, U16Vec2, U16Vec3, U16Vec4, U64Vec2,
+    U64Vec3, U64Vec4, U8Vec2, U8Vec3, U8Vec4, USizeVec2, USizeVec3, USizeVec4, UVec2, UVec3,
+    UVec3A, UVec4, Vec2, Vec3, Vec3A, Vec4,
 };
 
 macro_rules! impl_vec_types {
@@ -236,42 +237,50 @@ macro_rules! impl_float_types {
     };
 }
 
-impl From<mint::Point3<f32>> for Vec3A {
-    fn from(v: mint::Point3<f32>) -> Self {
-        Self::new(v.x, v.y, v.z)
-    }
-}
+macro_rules! impl_vec3a {
+    ($t:ty, $vec3a:ty) => {
+        impl From<mint::Point3<$t>> for $vec3a {
+            fn from(v: mint::Point3<$t>) -> Self {
+                Self::new(v.x, v.y, v.z)
+            }
+        }
 
-impl From<Vec3A> for mint::Point3<f32> {
-    fn from(v: Vec3A) -> Self {
-        Self {
-            x: v.x,
-            y: v.y,
-            z: v.z,
+        impl From<$vec3a> for mint::Point3<$t> {
+            fn from(v: $vec3a) -> Self {
+                Self {
+                    x: v.x,
+                    y: v.y,
+                    z: v.z,
+                }
+            }
         }
-    }
-}
 
-impl From<mint::Vector3<f32>> for Vec3A {
-    fn from(v: mint::Vector3<f32>) -> Self {
-        Self::new(v.x, v.y, v.z)
-    }
-}
+        impl From<mint::Vector3<$t>> for $vec3a {
+            fn from(v: mint::Vector3<$t>) -> Self {
+                Self::new(v.x, v.y, v.z)
+            }
+        }
 
-impl From<Vec3A> for mint::Vector3<f32> {
-    fn from(v: Vec3A) -> Self {
-        Self {
-            x: v.x,
-            y: v.y,
-            z: v.z,
+        impl From<$vec3a> for mint::Vector3<$t> {
+            fn from(v: $vec3a) -> Self {
+                Self {
+                    x: v.x,
+                    y: v.y,
+                    z: v.z,
+                }
+            }
         }
-    }
-}
 
-impl IntoMint for Vec3A {
-    type MintType = mint::Vector3<f32>;
+        impl IntoMint for $vec3a {
+            type MintType = mint::Vector3<$t>;
+        }
+    };
 }
 
+impl_vec3a!(f32, Vec3A);
+impl_vec3a!(i32, IVec3A);
+impl_vec3a!(u32, UVec3A);
+
 impl From<mint::RowMatrix3<f32>> for Mat3A {
     fn from(m: mint::RowMatrix3<f32>) -> Self {
         Self::from_cols(m.x.into(), m.y.into(), m.z.into()).transpose()
@@ -309,8 +318,32 @@ impl IntoMint for Mat3A {
     type MintType = mint::ColumnMatrix3<f32>;
 }
 
+/// `mint` has no 2D rotation type, so rotations convert to and from a column major rotation
+/// matrix.
+macro_rules! impl_rot2 {
+    ($t:ty, $rot2:ty, $mat2:ty) => {
+        impl From<mint::ColumnMatrix2<$t>> for $rot2 {
+            fn from(m: mint::ColumnMatrix2<$t>) -> Self {
+                Self::from_mat2(&<$mat2>::from(m))
+            }
+        }
+
+        impl From<$rot2> for mint::ColumnMatrix2<$t> {
+            fn from(r: $rot2) -> Self {
+                r.to_mat2().into()
+            }
+        }
+
+        impl IntoMint for $rot2 {
+            type MintType = mint::ColumnMatrix2<$t>;
+        }
+    };
+}
+
 impl_float_types!(f32, Mat2, Mat3, Mat4, Quat, Vec2, Vec3, Vec4);
+impl_rot2!(f32, Rot2, Mat2);
 impl_float_types!(f64, DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4);
+impl_rot2!(f64, DRot2, DMat2);
 impl_vec_types!(i8, I8Vec2, I8Vec3, I8Vec4);
 impl_vec_types!(u8, U8Vec2, U8Vec3, U8Vec4);
 impl_vec_types!(i16, I16Vec2, I16Vec3, I16Vec4);
@@ -324,6 +357,36 @@ impl_vec_types!(usize, USizeVec2, USizeVec3, USizeVec4);
 
 #[cfg(test)]
 mod test {
+    macro_rules! impl_vec3a_tests {
+        ($t:ty, $vec3a:ident) => {
+            #[test]
+            fn test_point3a() {
+                use crate::$vec3a;
+                let m = mint::Point3 {
+                    x: 1 as $t,
+                    y: 2 as $t,
+                    z: 3 as $t,
+                };
+                let g = $vec3a::from(m);
+                assert_eq!(g, $vec3a::new(1 as $t, 2 as $t, 3 as $t));
+                assert_eq!(m, g.into());
+            }
+
+            #[test]
+            fn test_vector3a() {
+                use crate::$vec3a;
+                let m = mint::Vector3 {
+                    x: 1 as $t,
+                    y: 2 as $t,
+                    z: 3 as $t,
+                };
+                let g = $vec3a::from(m);
+                assert_eq!(g, $vec3a::new(1 as $t, 2 as $t, 3 as $t));
+                assert_eq!(m, g.into());
+            }
+        };
+    }
+
     macro_rules! impl_vec_tests {
         ($t:ty, $vec2:ident, $vec3:ident, $vec4:ident) => {
             use crate::{$vec2, $vec3, $vec4};
@@ -390,6 +453,20 @@ mod test {
         };
     }
 
+    macro_rules! impl_rot2_tests {
+        ($rot2:ident, $mat2:ident) => {
+            #[test]
+            fn test_rot2() {
+                use crate::{$mat2, $rot2};
+                let g = $rot2::from_angle(0.5);
+                let m = mint::ColumnMatrix2::from(g);
+                assert_eq!(mint::ColumnMatrix2::from($mat2::from_angle(0.5)), m);
+                assert_eq!(g, $rot2::from(m));
+                assert_eq!(m, g.into());
+            }
+        };
+    }
+
     macro_rules! impl_float_tests {
         ($t:ty, $mat2:ident, $mat3:ident, $mat4:ident, $quat:ident, $vec2:ident, $vec3:ident, $vec4:ident) => {
             impl_vec_tests!($t, $vec2, $vec3, $vec4);
@@ -462,32 +539,9 @@ mod test {
 
     mod f32 {
         impl_float_tests!(f32, Mat2, Mat3, Mat4, Quat, Vec2, Vec3, Vec4);
+        impl_rot2_tests!(Rot2, Mat2);
 
-        #[test]
-        fn test_point3a() {
-            use crate::Vec3A;
-            let m = mint::Point3 {
-                x: 1.0,
-                y: 2.0,
-                z: 3.0,
-            };
-            let g = Vec3A::from(m);
-            assert_eq!(g, Vec3A::new(1.0, 2.0, 3.0));
-            assert_eq!(m, g.into());
-        }
-
-        #[test]
-        fn test_vector3a() {
-            use crate::Vec3A;
-            let m = mint::Vector3 {
-                x: 1.0,
-                y: 2.0,
-                z: 3.0,
-            };
-            let g = Vec3A::from(m);
-            assert_eq!(g, Vec3A::new(1.0, 2.0, 3.0));
-            assert_eq!(m, g.into());
-        }
+        impl_vec3a_tests!(f32, Vec3A);
 
         #[test]
         fn test_mat3a_col_major() {
@@ -526,6 +580,7 @@ mod test {
 
     mod f64 {
         impl_float_tests!(f64, DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4);
+        impl_rot2_tests!(DRot2, DMat2);
     }
 
     mod i8 {
@@ -546,10 +601,12 @@ mod test {
 
     mod i32 {
         impl_vec_tests!(i32, IVec2, IVec3, IVec4);
+        impl_vec3a_tests!(i32, IVec3A);
     }
 
     mod u32 {
         impl_vec_tests!(u32, UVec2, UVec3, UVec4);
+        impl_vec3a_tests!(u32, UVec3A);
     }
 
     mod i64 {
@@ -1,18 +1,626 @@
+use alloc::{format, string::String};
+
+/// Controls how float components are read when deserializing `glam` types from nanoserde JSON
+/// and RON.
+///
+/// `DeJson` and `DeRon` use [`NanoserdePolicy::DEFAULT`]. Other policies can be used with
+/// [`DeJsonWith`] and [`DeRonWith`], or for fields of derived types with the [`Finite`] and
+/// [`Lenient`] wrappers. The binary format is not affected by the policy.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct NanoserdePolicy {
+    /// Rejects NaN and infinite float components.
+    pub finite: bool,
+    /// Accepts integers such as `1` for float components.
+    pub integers: bool,
+    /// Accepts float components given as strings such as `"NaN"`, `"inf"` or `"-1.5"`, and in RON
+    /// also as identifiers such as `NaN`.
+    pub strings: bool,
+}
+
+impl NanoserdePolicy {
+    /// Accepts integers for float components, rejects strings and passes NaN and infinite values
+    /// through.
+    pub const DEFAULT: Self = Self {
+        finite: false,
+        integers: true,
+        strings: false,
+    };
+
+    /// The default policy, but NaN and infinite float components are rejected.
+    pub const FINITE: Self = Self {
+        finite: true,
+        ..Self::DEFAULT
+    };
+
+    /// The default policy, but float components may also be given as strings.
+    pub const LENIENT: Self = Self {
+        strings: true,
+        ..Self::DEFAULT
+    };
+}
+
+impl Default for NanoserdePolicy {
+    #[inline]
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Deserializes a `glam` type from nanoserde JSON with a [`NanoserdePolicy`].
+pub trait DeJsonWith: Sized {
+    /// Deserializes `Self` from the current token of `s` using `policy`.
+    fn de_json_with(
+        s: &mut nanoserde::DeJsonState,
+        i: &mut core::str::Chars,
+        policy: NanoserdePolicy,
+    ) -> Result<Self, nanoserde::DeJsonErr>;
+
+    /// Deserializes `Self` from the JSON string `input` using `policy`.
+    fn deserialize_json_with(
+        input: &str,
+        policy: NanoserdePolicy,
+    ) -> Result<Self, nanoserde::DeJsonErr> {
+        let mut s = nanoserde::DeJsonState::default();
+        let mut i = input.chars();
+        s.next(&mut i);
+        s.next_tok(&mut i)?;
+        Self::de_json_with(&mut s, &mut i, policy)
+    }
+}
+
+/// Deserializes a `glam` type from nanoserde RON with a [`NanoserdePolicy`].
+pub trait DeRonWith: Sized {
+    /// Deserializes `Self` from the current token of `s` using `policy`.
+    fn de_ron_with(
+        s: &mut nanoserde::DeRonState,
+        i: &mut core::str::Chars,
+        policy: NanoserdePolicy,
+    ) -> Result<Self, nanoserde::DeRonErr>;
+
+    /// Deserializes `Self` from the RON string `input` using `policy`.
+    fn deserialize_ron_with(
+        input: &str,
+        policy: NanoserdePolicy,
+    ) -> Result<Self, nanoserde::DeRonErr> {
+        let mut s = nanoserde::DeRonState::default();
+        let mut i = input.chars();
+        s.next(&mut i);
+        s.next_tok(&mut i)?;
+        Self::de_ron_with(&mut s, &mut i, policy)
+    }
+}
+
+/// Serializes a `glam` type as nanoserde JSON objects with named fields.
+///
+/// `SerJson` writes flat arrays, e.g. `[1.0,2.0]` for vectors. This trait writes objects instead,
+/// e.g. `{"x":1.0,"y":2.0}` for vectors and `{"x_axis":..,"y_axis":..}` for matrices. `DeJson`
+/// accepts both forms. For fields of derived types use the [`Named`] wrapper.
+pub trait SerJsonNamed {
+    /// Serializes `self` as a named JSON object into `s`.
+    fn ser_json_named(&self, d: usize, s: &mut nanoserde::SerJsonState);
+
+    /// Serializes `self` as a named JSON object string.
+    fn serialize_json_named(&self) -> String {
+        nanoserde::SerJson::serialize_json(&Named(self))
+    }
+}
+
+impl<T: SerJsonNamed + ?Sized> SerJsonNamed for &T {
+    fn ser_json_named(&self, d: usize, s: &mut nanoserde::SerJsonState) {
+        (**self).ser_json_named(d, s);
+    }
+}
+
+/// Serializes the wrapped `glam` type with [`SerJsonNamed`], e.g. as a field of a type that derives
+/// `SerJson`. All other formats are passed through to the wrapped type.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[repr(transparent)]
+pub struct Named<T>(pub T);
+
+impl<T: SerJsonNamed> nanoserde::SerJson for Named<T> {
+    fn ser_json(&self, d: usize, s: &mut nanoserde::SerJsonState) {
+        self.0.ser_json_named(d, s);
+    }
+}
+
+impl<T: nanoserde::DeJson> nanoserde::DeJson for Named<T> {
+    fn de_json(
+        s: &mut nanoserde::DeJsonState,
+        i: &mut core::str::Chars,
+    ) -> Result<Self, nanoserde::DeJsonErr> {
+        T::de_json(s, i).map(Named)
+    }
+}
+
+impl<T: nanoserde::SerRon> nanoserde::SerRon for Named<T> {
+    fn ser_ron(&self, d: usize, s: &mut nanoserde::SerRonState) {
+        self.0.ser_ron(d, s);
+    }
+}
+
+impl<T: nanoserde::DeRon> nanoserde::DeRon for Named<T> {
+    fn de_ron(
+        s: &mut nanoserde::DeRonState,
+        i: &mut core::str::Chars,
+    ) -> Result<Self, nanoserde::DeRonErr> {
+        T::de_ron(s, i).map(Named)
+    }
+}
+
+macro_rules! impl_policy_wrapper {
+    ($(#[$meta:meta])* $wrapper:ident, $policy:ident) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug, Default, PartialEq)]
+        #[repr(transparent)]
+        pub struct $wrapper<T>(pub T);
+
+        impl<T: DeJsonWith> nanoserde::DeJson for $wrapper<T> {
+            fn de_json(
+                s: &mut nanoserde::DeJsonState,
+                i: &mut core::str::Chars,
+            ) -> Result<Self, nanoserde::DeJsonErr> {
+                T::de_json_with(s, i, NanoserdePolicy::$policy).map($wrapper)
+            }
+        }
+
+        impl<T: nanoserde::SerJson> nanoserde::SerJson for $wrapper<T> {
+            fn ser_json(&self, d: usize, s: &mut nanoserde::SerJsonState) {
+                self.0.ser_json(d, s);
+            }
+        }
+
+        impl<T: DeRonWith> nanoserde::DeRon for $wrapper<T> {
+            fn de_ron(
+                s: &mut nanoserde::DeRonState,
+                i: &mut core::str::Chars,
+            ) -> Result<Self, nanoserde::DeRonErr> {
+                T::de_ron_with(s, i, NanoserdePolicy::$policy).map($wrapper)
+            }
+        }
+
+        impl<T: nanoserde::SerRon> nanoserde::SerRon for $wrapper<T> {
+            fn ser_ron(&self, d: usize, s: &mut nanoserde::SerRonState) {
+                self.0.ser_ron(d, s);
+            }
+        }
+    };
+}
+
+impl_policy_wrapper!(
+    /// Deserializes the wrapped `glam` type with [`NanoserdePolicy::FINITE`], e.g. as a field of a
+    /// type that derives `DeJson` or `DeRon`.
+    Finite,
+    FINITE
+);
+
+impl_policy_wrapper!(
+    /// Deserializes the wrapped `glam` type with [`NanoserdePolicy::LENIENT`], e.g. as a field of a
+    /// type that derives `DeJson` or `DeRon`.
+    Lenient,
+    LENIENT
+);
+
+/// Implements `DeJson` and `DeRon` with the default policy for a type implementing `DeJsonWith`
+/// and `DeRonWith`.
+macro_rules! impl_de_with {
+    ($t:ident) => {
+        impl nanoserde::DeJson for $t {
+            fn de_json(
+                s: &mut nanoserde::DeJsonState,
+                i: &mut core::str::Chars,
+            ) -> Result<Self, nanoserde::DeJsonErr> {
+                super::DeJsonWith::de_json_with(s, i, super::NanoserdePolicy::DEFAULT)
+            }
+        }
+
+        impl nanoserde::DeRon for $t {
+            fn de_ron(
+                s: &mut nanoserde::DeRonState,
+                i: &mut core::str::Chars,
+            ) -> Result<Self, nanoserde::DeRonErr> {
+                super::DeRonWith::de_ron_with(s, i, super::NanoserdePolicy::DEFAULT)
+            }
+        }
+    };
+}
+
+/// A field of a named JSON object, scalars are written as they are and `glam` types with
+/// [`SerJsonNamed`].
+trait SerJsonField {
+    fn ser_json_field(&self, d: usize, s: &mut nanoserde::SerJsonState);
+}
+
+impl<T: SerJsonNamed> SerJsonField for T {
+    fn ser_json_field(&self, d: usize, s: &mut nanoserde::SerJsonState) {
+        self.ser_json_named(d, s);
+    }
+}
+
+macro_rules! impl_ser_json_field {
+    ($($t:ty),+) => {
+        $(
+            impl SerJsonField for $t {
+                fn ser_json_field(&self, d: usize, s: &mut nanoserde::SerJsonState) {
+                    nanoserde::SerJson::ser_json(self, d, s);
+                }
+            }
+        )+
+    };
+}
+
+impl_ser_json_field!(bool, f32, f64, i8, i16, i32, i64, u8, u16, u32, u64, usize);
+
+/// Serializes `vals` as a JSON object with the given `keys`, e.g. `{"x":1.0,"y":2.0}`.
+fn ser_json_object<T: SerJsonField>(
+    keys: &[&str],
+    vals: &[T],
+    d: usize,
+    s: &mut nanoserde::SerJsonState,
+) {
+    s.out.push('{');
+    for (idx, (key, val)) in keys.iter().zip(vals).enumerate() {
+        if idx != 0 {
+            s.out.push(',');
+        }
+        s.field(d + 1, key);
+        val.ser_json_field(d + 1, s);
+    }
+    s.out.push('}');
+}
+
+/// Deserializes a JSON object which must contain each of `keys` exactly once, in any order.
+///
+/// `de_field` is called with the index of each key to deserialize the associated value.
+fn de_json_object<F>(
+    s: &mut nanoserde::DeJsonState,
+    i: &mut core::str::Chars,
+    keys: &[&str],
+    de_field: F,
+) -> Result<(), nanoserde::DeJsonErr>
+where
+    F: FnMut(
+        usize,
+        &mut nanoserde::DeJsonState,
+        &mut core::str::Chars,
+    ) -> Result<(), nanoserde::DeJsonErr>,
+{
+    let found = de_json_fields(s, i, keys, de_field)?;
+    match (0..keys.len()).find(|idx| found & (1 << idx) == 0) {
+        Some(idx) => Err(s.err_nf(keys[idx])),
+        None => Ok(()),
+    }
+}
+
+/// Deserializes a JSON object which may contain each of `keys` at most once, in any order.
+///
+/// `de_field` is called with the index of each key to deserialize the associated value. Returns
+/// a bit mask of the indices of the keys that were found.
+fn de_json_fields<F>(
+    s: &mut nanoserde::DeJsonState,
+    i: &mut core::str::Chars,
+    keys: &[&str],
+    mut de_field: F,
+) -> Result<u32, nanoserde::DeJsonErr>
+where
+    F: FnMut(
+        usize,
+        &mut nanoserde::DeJsonState,
+        &mut core::str::Chars,
+    ) -> Result<(), nanoserde::DeJsonErr>,
+{
+    debug_assert!(keys.len() <= 32);
+    let mut found = 0_u32;
+    s.curly_open(i)?;
+    while s.next_str().is_some() {
+        let idx = match keys.iter().position(|key| *key == s.strbuf) {
+            Some(idx) if found & (1 << idx) == 0 => idx,
+            _ => return Err(s.err_exp(&s.strbuf)),
+        };
+        s.next_colon(i)?;
+        de_field(idx, s, i)?;
+        found |= 1 << idx;
+        s.eat_comma_curly(i)?;
+    }
+    s.curly_close(i)?;
+    Ok(found)
+}
+
+const AXES: [&str; 4] = ["x_axis", "y_axis", "z_axis", "w_axis"];
+const XYZW: [&str; 4] = ["x", "y", "z", "w"];
+
+/// Scalar component types of vectors, matrices and masks.
+trait Component: Copy + Default + core::fmt::Debug + nanoserde::DeJson + nanoserde::DeRon {
+    /// The type name used in error messages.
+    const NAME: &'static str;
+
+    /// Whether [`NanoserdePolicy`] applies to this type.
+    const IS_FLOAT: bool = false;
+
+    /// Parses a component from a string, only used for float types.
+    fn parse_str(_s: &str) -> Option<Self> {
+        None
+    }
+
+    /// Returns `false` for values rejected by [`NanoserdePolicy::finite`].
+    fn is_finite(self) -> bool {
+        true
+    }
+}
+
+macro_rules! impl_component {
+    (float: $($t:ty),+) => {
+        $(
+            impl Component for $t {
+                const NAME: &'static str = stringify!($t);
+
+                const IS_FLOAT: bool = true;
+
+                fn parse_str(s: &str) -> Option<Self> {
+                    s.parse().ok()
+                }
+
+                fn is_finite(self) -> bool {
+                    <$t>::is_finite(self)
+                }
+            }
+        )+
+    };
+    ($($t:ty),+) => {
+        $(
+            impl Component for $t {
+                const NAME: &'static str = stringify!($t);
+            }
+        )+
+    };
+}
+
+impl_component!(float: f32, f64);
+impl_component!(bool, i8, i16, i32, i64, u8, u16, u32, u64, usize);
+
+/// Checks a deserialized component against `policy`, returning the error message for the
+/// component at `path` if it is rejected or could not be parsed.
+fn check_component<T: Component>(
+    val: Option<T>,
+    path: core::fmt::Arguments,
+    policy: NanoserdePolicy,
+    found: &dyn core::fmt::Debug,
+) -> Result<T, String> {
+    match val {
+        Some(val) if !policy.finite || val.is_finite() => Ok(val),
+        Some(val) => Err(format!(
+            "{}, expected finite {}, found {:?}",
+            path,
+            T::NAME,
+            val
+        )),
+        None => Err(format!("{}, expected {}, found {:?}", path, T::NAME, found)),
+    }
+}
+
+/// Deserializes the component at `path`, e.g. `Vec3.y` or `Mat2[3]`, following `policy` for
+/// float components.
+fn de_json_component<T: Component>(
+    s: &mut nanoserde::DeJsonState,
+    i: &mut core::str::Chars,
+    path: core::fmt::Arguments,
+    policy: NanoserdePolicy,
+) -> Result<T, nanoserde::DeJsonErr> {
+    let found = s.tok.clone();
+    let val = match s.tok {
+        nanoserde::DeJsonTok::Str if T::IS_FLOAT && policy.strings => {
+            let val = T::parse_str(&s.strbuf);
+            if val.is_some() {
+                s.next_tok(i)?;
+            }
+            val
+        }
+        nanoserde::DeJsonTok::U64(_) | nanoserde::DeJsonTok::I64(_)
+            if T::IS_FLOAT && !policy.integers =>
+        {
+            None
+        }
+        _ => T::de_json(s, i).ok(),
+    };
+    check_component(val, path, policy, &found).map_err(|msg| s.err_parse(&msg))
+}
+
+/// Deserializes `N` components from a JSON array, e.g. `[1.0,2.0]`.
+fn de_json_array<T: Component, const N: usize>(
+    s: &mut nanoserde::DeJsonState,
+    i: &mut core::str::Chars,
+    path: core::fmt::Arguments,
+    policy: NanoserdePolicy,
+) -> Result<[T; N], nanoserde::DeJsonErr> {
+    let mut vals = [T::default(); N];
+    s.block_open(i)?;
+    for (idx, val) in vals.iter_mut().enumerate() {
+        *val = de_json_component(s, i, format_args!("{}[{}]", path, idx), policy)?;
+        s.eat_comma_block(i)?;
+    }
+    s.block_close(i)?;
+    Ok(vals)
+}
+
+/// Deserializes `N` components from either a JSON array or a JSON object with the keys `x`, `y`,
+/// `z` and `w`.
+fn de_json_vector<T: Component, const N: usize>(
+    s: &mut nanoserde::DeJsonState,
+    i: &mut core::str::Chars,
+    path: core::fmt::Arguments,
+    policy: NanoserdePolicy,
+) -> Result<[T; N], nanoserde::DeJsonErr> {
+    if s.tok != nanoserde::DeJsonTok::CurlyOpen {
+        return de_json_array(s, i, path, policy);
+    }
+    let mut vals = [T::default(); N];
+    de_json_object(s, i, &XYZW[..N], |idx, s, i| {
+        vals[idx] = de_json_component(s, i, format_args!("{}.{}", path, XYZW[idx]), policy)?;
+        Ok(())
+    })?;
+    Ok(vals)
+}
+
+/// Deserializes `C` columns of `R` components from either a flat JSON array in column major order
+/// or a JSON object with the keys `x_axis`, `y_axis`, `z_axis` and `w_axis`.
+fn de_json_matrix<T: Component, const C: usize, const R: usize>(
+    s: &mut nanoserde::DeJsonState,
+    i: &mut core::str::Chars,
+    path: core::fmt::Arguments,
+    policy: NanoserdePolicy,
+) -> Result<[[T; R]; C], nanoserde::DeJsonErr> {
+    let mut cols = [[T::default(); R]; C];
+    if s.tok == nanoserde::DeJsonTok::CurlyOpen {
+        de_json_object(s, i, &AXES[..C], |idx, s, i| {
+            cols[idx] = de_json_vector(s, i, format_args!("{}.{}", path, AXES[idx]), policy)?;
+            Ok(())
+        })?;
+        return Ok(cols);
+    }
+    s.block_open(i)?;
+    for (c, col) in cols.iter_mut().enumerate() {
+        for (r, val) in col.iter_mut().enumerate() {
+            *val = de_json_component(s, i, format_args!("{}[{}]", path, c * R + r), policy)?;
+            s.eat_comma_block(i)?;
+        }
+    }
+    s.block_close(i)?;
+    Ok(cols)
+}
+
+const EULER_ROT_NAMES: [(crate::EulerRot, &str); 24] = {
+    use crate::EulerRot::*;
+    [
+        (ZYX, "ZYX"),
+        (ZXY, "ZXY"),
+        (YXZ, "YXZ"),
+        (YZX, "YZX"),
+        (XYZ, "XYZ"),
+        (XZY, "XZY"),
+        (ZYZ, "ZYZ"),
+        (ZXZ, "ZXZ"),
+        (YXY, "YXY"),
+        (YZY, "YZY"),
+        (XYX, "XYX"),
+        (XZX, "XZX"),
+        (ZYXEx, "ZYXEx"),
+        (ZXYEx, "ZXYEx"),
+        (YXZEx, "YXZEx"),
+        (YZXEx, "YZXEx"),
+        (XYZEx, "XYZEx"),
+        (XZYEx, "XZYEx"),
+        (ZYZEx, "ZYZEx"),
+        (ZXZEx, "ZXZEx"),
+        (YXYEx, "YXYEx"),
+        (YZYEx, "YZYEx"),
+        (XYXEx, "XYXEx"),
+        (XZXEx, "XZXEx"),
+    ]
+};
+
+/// Deserializes an `EulerRot` from a JSON string containing the variant name, e.g. `"YXZ"`.
+fn de_json_euler_rot(
+    s: &mut nanoserde::DeJsonState,
+    i: &mut core::str::Chars,
+) -> Result<crate::EulerRot, nanoserde::DeJsonErr> {
+    if s.tok != nanoserde::DeJsonTok::Str {
+        return Err(s.err_token("EulerRot name"));
+    }
+    match EULER_ROT_NAMES.iter().find(|(_, name)| *name == s.strbuf) {
+        Some((euler, _)) => {
+            s.next_tok(i)?;
+            Ok(*euler)
+        }
+        None => Err(s.err_enum(&s.strbuf)),
+    }
+}
+
+/// Serializes `vals` as a RON tuple, e.g. `(1.0, 2.0, 3.0)`.
+fn ser_ron_tuple<T: nanoserde::SerRon>(vals: &[T], d: usize, s: &mut nanoserde::SerRonState) {
+    s.out.push('(');
+    for (idx, val) in vals.iter().enumerate() {
+        if idx != 0 {
+            s.out.push_str(", ");
+        }
+        val.ser_ron(d, s);
+    }
+    s.out.push(')');
+}
+
+/// Deserializes the component at `path`, e.g. `Vec3[1]`, following `policy` for float components
+/// like [`de_json_component`].
+fn de_ron_component<T: Component>(
+    s: &mut nanoserde::DeRonState,
+    i: &mut core::str::Chars,
+    path: core::fmt::Arguments,
+    policy: NanoserdePolicy,
+) -> Result<T, nanoserde::DeRonErr> {
+    let found = s.tok.clone();
+    let val = match s.tok {
+        nanoserde::DeRonTok::Str | nanoserde::DeRonTok::Ident if T::IS_FLOAT && policy.strings => {
+            let val = if s.tok == nanoserde::DeRonTok::Str {
+                T::parse_str(&s.strbuf)
+            } else {
+                T::parse_str(&s.identbuf)
+            };
+            if val.is_some() {
+                s.next_tok(i)?;
+            }
+            val
+        }
+        nanoserde::DeRonTok::U64(_) | nanoserde::DeRonTok::I64(_)
+            if T::IS_FLOAT && !policy.integers =>
+        {
+            None
+        }
+        _ => T::de_ron(s, i).ok(),
+    };
+    check_component(val, path, policy, &found).map_err(|msg| s.err_parse(&msg))
+}
+
+/// Deserializes exactly `N` components from either a RON tuple `(a, b, ..)` or a RON list
+/// `[a, b, ..]`.
+fn de_ron_tuple<T: Component, const N: usize>(
+    s: &mut nanoserde::DeRonState,
+    i: &mut core::str::Chars,
+    path: core::fmt::Arguments,
+    policy: NanoserdePolicy,
+) -> Result<[T; N], nanoserde::DeRonErr> {
+    let is_tuple = s.tok == nanoserde::DeRonTok::ParenOpen;
+    if is_tuple {
+        s.paren_open(i)?;
+    } else {
+        s.block_open(i)?;
+    }
+    let mut vals = [T::default(); N];
+    for (idx, val) in vals.iter_mut().enumerate() {
+        *val = de_ron_component(s, i, format_args!("{}[{}]", path, idx), policy)?;
+        if is_tuple {
+            s.eat_comma_paren(i)?;
+        } else {
+            s.eat_comma_block(i)?;
+        }
+    }
+    if is_tuple {
+        s.paren_close(i)?;
+    } else {
+        s.block_close(i)?;
+    }
+    Ok(vals)
+}
+
 macro_rules! impl_serde_vec2 {
     ($t:ty, $vec2:ident) => {
-        impl nanoserde::DeJson for $vec2 {
-            fn de_json(
+        impl_de_with!($vec2);
+
+        impl super::DeJsonWith for $vec2 {
+            fn de_json_with(
                 s: &mut nanoserde::DeJsonState,
                 i: &mut core::str::Chars,
+                policy: super::NanoserdePolicy,
             ) -> Result<Self, nanoserde::DeJsonErr> {
-                Ok({
-                    s.block_open(i)?;
-                    let x: $t = nanoserde::DeJson::de_json(s, i)?;
-                    s.eat_comma_block(i)?;
-                    let y: $t = nanoserde::DeJson::de_json(s, i)?;
-                    s.block_close(i)?;
-                    $vec2 { x, y }
-                })
+                let vals: [$t; 2] =
+                    super::de_json_vector(s, i, format_args!(stringify!($vec2)), policy)?;
+                Ok($vec2::from_array(vals))
             }
         }
 
@@ -26,13 +634,61 @@ macro_rules! impl_serde_vec2 {
             }
         }
 
+        impl super::SerJsonNamed for $vec2 {
+            fn ser_json_named(&self, d: usize, s: &mut nanoserde::SerJsonState) {
+                super::ser_json_object(&["x", "y"], &[self.x, self.y], d, s);
+            }
+        }
+
+        impl nanoserde::DeBin for $vec2 {
+            fn de_bin(o: &mut usize, d: &[u8]) -> Result<Self, nanoserde::DeBinErr> {
+                let x: $t = nanoserde::DeBin::de_bin(o, d)?;
+                let y: $t = nanoserde::DeBin::de_bin(o, d)?;
+                Ok($vec2 { x, y })
+            }
+        }
+
+        impl nanoserde::SerBin for $vec2 {
+            fn ser_bin(&self, s: &mut Vec<u8>) {
+                self.x.ser_bin(s);
+                self.y.ser_bin(s);
+            }
+        }
+
+        impl super::DeRonWith for $vec2 {
+            fn de_ron_with(
+                s: &mut nanoserde::DeRonState,
+                i: &mut core::str::Chars,
+                policy: super::NanoserdePolicy,
+            ) -> Result<Self, nanoserde::DeRonErr> {
+                let vals: [$t; 2] =
+                    super::de_ron_tuple(s, i, format_args!(stringify!($vec2)), policy)?;
+                Ok($vec2::from_array(vals))
+            }
+        }
+
+        impl nanoserde::SerRon for $vec2 {
+            fn ser_ron(&self, d: usize, s: &mut nanoserde::SerRonState) {
+                super::ser_ron_tuple(&self.to_array(), d, s);
+            }
+        }
+
         #[test]
         fn test_vec2_serde() {
             let a = $vec2::new(V1, V2);
             let serialized = a.serialize_json();
             assert_eq!(SX2, serialized);
+            assert_eq!(ST2, a.serialize_json_named());
             let deserialized = $vec2::deserialize_json(&serialized).unwrap();
             assert_eq!(a, deserialized);
+            let deserialized = $vec2::deserialize_json(SX2).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $vec2::deserialize_json(ST2).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $vec2::deserialize_json(ST1);
+            assert!(deserialized.is_err());
+            let deserialized = $vec2::deserialize_json(ST3);
+            assert!(deserialized.is_err());
             let deserialized = $vec2::deserialize_json(SX0);
             assert!(deserialized.is_err());
             let deserialized = $vec2::deserialize_json(SX1);
@@ -43,147 +699,230 @@ macro_rules! impl_serde_vec2 {
             let deserialized = $vec2::deserialize_json(ST0);
             assert!(deserialized.is_err());
         }
-    };
-}
-
-macro_rules! impl_serde_vec3 {
-    ($t:ty, $vec3:ident) => {
-        impl_serde_vec3!($t, $vec3, test_vec3_serde);
-    };
-    ($t:ty, $vec3:ident, $test_name:ident) => {
-        impl nanoserde::DeJson for $vec3 {
-            fn de_json(
-                s: &mut nanoserde::DeJsonState,
-                i: &mut core::str::Chars,
-            ) -> Result<Self, nanoserde::DeJsonErr> {
-                Ok({
-                    s.block_open(i)?;
-                    let x: $t = nanoserde::DeJson::de_json(s, i)?;
-                    s.eat_comma_block(i)?;
-                    let y: $t = nanoserde::DeJson::de_json(s, i)?;
-                    s.eat_comma_block(i)?;
-                    let z: $t = nanoserde::DeJson::de_json(s, i)?;
-                    s.block_close(i)?;
-                    $vec3::new(x, y, z)
-                })
-            }
-        }
 
-        impl nanoserde::SerJson for $vec3 {
-            fn ser_json(&self, d: usize, s: &mut nanoserde::SerJsonState) {
-                s.out.push('[');
-                self.x.ser_json(d, s);
-                s.out.push(',');
-                self.y.ser_json(d, s);
-                s.out.push(',');
-                self.z.ser_json(d, s);
-                s.out.push(']');
+        #[test]
+        fn test_vec2_serde_bin() {
+            let a = $vec2::new(V1, V2);
+            let serialized = a.serialize_bin();
+            let mut expected = Vec::new();
+            for v in [V1, V2] {
+                v.ser_bin(&mut expected);
             }
+            assert_eq!(expected, serialized);
+            let deserialized = $vec2::deserialize_bin(&serialized).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $vec2::deserialize_bin(&serialized[..serialized.len() - 1]);
+            assert!(deserialized.is_err());
+            let deserialized = $vec2::deserialize_bin(&[]);
+            assert!(deserialized.is_err());
         }
 
         #[test]
-        fn $test_name() {
-            let a = $vec3::new(V1, V2, V3);
-            let serialized = a.serialize_json();
-            assert_eq!(SX3, serialized);
-            let deserialized = $vec3::deserialize_json(&serialized).unwrap();
+        fn test_vec2_serde_ron() {
+            let a = $vec2::new(V1, V2);
+            let serialized = a.serialize_ron();
+            assert_eq!(SR2, serialized);
+            let deserialized = $vec2::deserialize_ron(&serialized).unwrap();
             assert_eq!(a, deserialized);
-            let deserialized = $vec3::deserialize_json(SX0);
+            let deserialized = $vec2::deserialize_ron(SX2).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $vec2::deserialize_ron(SR0);
             assert!(deserialized.is_err());
-            let deserialized = $vec3::deserialize_json(SX1);
+            let deserialized = $vec2::deserialize_ron(SR1);
             assert!(deserialized.is_err());
-            let deserialized = $vec3::deserialize_json(SX2);
+            let deserialized = $vec2::deserialize_ron(SR3);
             assert!(deserialized.is_err());
-            let deserialized = $vec3::deserialize_json(SX4);
+            let deserialized = $vec2::deserialize_ron(SX3);
             assert!(deserialized.is_err());
-            let deserialized = $vec3::deserialize_json(ST0);
+            let deserialized = $vec2::deserialize_ron(ST0);
             assert!(deserialized.is_err());
         }
     };
 }
 
-macro_rules! impl_serde_vec4 {
-    ($t:ty, $vec4:ident) => {
-        impl nanoserde::DeJson for $vec4 {
-            fn de_json(
+const ROT2_KEYS: [&str; 3] = ["cos", "sin", "angle"];
+
+macro_rules! impl_serde_rot2 {
+    ($t:ty, $rot2:ident) => {
+        impl_de_with!($rot2);
+
+        /// Deserializes a `[cos, sin]` array, a `{"cos":..,"sin":..}` object or an
+        /// `{"angle":..}` object with the angle in radians.
+        impl super::DeJsonWith for $rot2 {
+            fn de_json_with(
                 s: &mut nanoserde::DeJsonState,
                 i: &mut core::str::Chars,
+                policy: super::NanoserdePolicy,
             ) -> Result<Self, nanoserde::DeJsonErr> {
-                Ok({
-                    s.block_open(i)?;
-                    let x: $t = nanoserde::DeJson::de_json(s, i)?;
-                    s.eat_comma_block(i)?;
-                    let y: $t = nanoserde::DeJson::de_json(s, i)?;
-                    s.eat_comma_block(i)?;
-                    let z: $t = nanoserde::DeJson::de_json(s, i)?;
-                    s.eat_comma_block(i)?;
-                    let w: $t = nanoserde::DeJson::de_json(s, i)?;
-                    s.block_close(i)?;
-                    $vec4::new(x, y, z, w)
-                })
+                if s.tok != nanoserde::DeJsonTok::CurlyOpen {
+                    let vals: [$t; 2] =
+                        super::de_json_array(s, i, format_args!(stringify!($rot2)), policy)?;
+                    return Ok($rot2::from_array(vals));
+                }
+                let mut vals: [$t; 3] = [0.0; 3];
+                let found = super::de_json_fields(s, i, &super::ROT2_KEYS, |idx, s, i| {
+                    vals[idx] = super::de_json_component(
+                        s,
+                        i,
+                        format_args!("{}.{}", stringify!($rot2), super::ROT2_KEYS[idx]),
+                        policy,
+                    )?;
+                    Ok(())
+                })?;
+                match found {
+                    0b011 => Ok($rot2::from_array([vals[0], vals[1]])),
+                    0b100 => Ok($rot2::from_angle(vals[2])),
+                    0b000 | 0b010 => Err(s.err_nf("cos")),
+                    0b001 => Err(s.err_nf("sin")),
+                    _ => Err(s.err_exp("angle")),
+                }
             }
         }
 
-        impl nanoserde::SerJson for $vec4 {
+        impl nanoserde::SerJson for $rot2 {
             fn ser_json(&self, d: usize, s: &mut nanoserde::SerJsonState) {
                 s.out.push('[');
-                self.x.ser_json(d, s);
-                s.out.push(',');
-                self.y.ser_json(d, s);
+                self.cos.ser_json(d, s);
                 s.out.push(',');
-                self.z.ser_json(d, s);
-                s.out.push(',');
-                self.w.ser_json(d, s);
+                self.sin.ser_json(d, s);
                 s.out.push(']');
             }
         }
 
+        impl super::SerJsonNamed for $rot2 {
+            fn ser_json_named(&self, d: usize, s: &mut nanoserde::SerJsonState) {
+                super::ser_json_object(&super::ROT2_KEYS[..2], &self.to_array(), d, s);
+            }
+        }
+
+        impl nanoserde::DeBin for $rot2 {
+            fn de_bin(o: &mut usize, d: &[u8]) -> Result<Self, nanoserde::DeBinErr> {
+                let cos: $t = nanoserde::DeBin::de_bin(o, d)?;
+                let sin: $t = nanoserde::DeBin::de_bin(o, d)?;
+                Ok($rot2 { cos, sin })
+            }
+        }
+
+        impl nanoserde::SerBin for $rot2 {
+            fn ser_bin(&self, s: &mut Vec<u8>) {
+                self.cos.ser_bin(s);
+                self.sin.ser_bin(s);
+            }
+        }
+
+        impl super::DeRonWith for $rot2 {
+            fn de_ron_with(
+                s: &mut nanoserde::DeRonState,
+                i: &mut core::str::Chars,
+                policy: super::NanoserdePolicy,
+            ) -> Result<Self, nanoserde::DeRonErr> {
+                let vals: [$t; 2] =
+                    super::de_ron_tuple(s, i, format_args!(stringify!($rot2)), policy)?;
+                Ok($rot2::from_array(vals))
+            }
+        }
+
+        impl nanoserde::SerRon for $rot2 {
+            fn ser_ron(&self, d: usize, s: &mut nanoserde::SerRonState) {
+                super::ser_ron_tuple(&self.to_array(), d, s);
+            }
+        }
+
         #[test]
-        fn test_vec4_serde() {
-            let a = $vec4::new(V1, V2, V3, V4);
+        fn test_rot2_serde() {
+            const NAMED: &str = r#"{"cos":1.0,"sin":2.0}"#;
+            let a = $rot2::from_array([1.0, 2.0]);
             let serialized = a.serialize_json();
-            assert_eq!(SX4, serialized);
-            let deserialized = $vec4::deserialize_json(&serialized).unwrap();
+            assert_eq!("[1.0,2.0]", serialized);
+            assert_eq!(NAMED, a.serialize_json_named());
+            let deserialized = $rot2::deserialize_json(&serialized).unwrap();
             assert_eq!(a, deserialized);
-            let deserialized = $vec4::deserialize_json(SX0);
+            let deserialized = $rot2::deserialize_json(SX2).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $rot2::deserialize_json(NAMED).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $rot2::deserialize_json(r#"{"sin":2,"cos":1}"#).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $rot2::deserialize_json(r#"{"angle":1.5}"#).unwrap();
+            assert_eq!($rot2::from_angle(1.5), deserialized);
+            let deserialized = $rot2::deserialize_json(r#"{"cos":1.0}"#);
             assert!(deserialized.is_err());
-            let deserialized = $vec4::deserialize_json(SX1);
+            let deserialized = $rot2::deserialize_json(r#"{"sin":1.0}"#);
             assert!(deserialized.is_err());
-            let deserialized = $vec4::deserialize_json(SX2);
+            let deserialized = $rot2::deserialize_json(r#"{"cos":1.0,"sin":0.0,"angle":0.0}"#);
             assert!(deserialized.is_err());
-            let deserialized = $vec4::deserialize_json(SX3);
+            let deserialized = $rot2::deserialize_json(ST2);
             assert!(deserialized.is_err());
-            let deserialized = $vec4::deserialize_json(SX5);
+            let deserialized = $rot2::deserialize_json(SX1);
             assert!(deserialized.is_err());
-            let deserialized = $vec4::deserialize_json(ST0);
+            let deserialized = $rot2::deserialize_json(SX3);
+            assert!(deserialized.is_err());
+            let deserialized = $rot2::deserialize_json(ST0);
+            assert!(deserialized.is_err());
+        }
+
+        #[test]
+        fn test_rot2_serde_bin() {
+            let a = $rot2::from_array([1.0, 2.0]);
+            let serialized = a.serialize_bin();
+            let mut expected = Vec::new();
+            for v in a.to_array() {
+                v.ser_bin(&mut expected);
+            }
+            assert_eq!(expected, serialized);
+            let deserialized = $rot2::deserialize_bin(&serialized).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $rot2::deserialize_bin(&serialized[..serialized.len() - 1]);
+            assert!(deserialized.is_err());
+            let deserialized = $rot2::deserialize_bin(&[]);
+            assert!(deserialized.is_err());
+        }
+
+        #[test]
+        fn test_rot2_serde_ron() {
+            let a = $rot2::from_array([1.0, 2.0]);
+            let serialized = a.serialize_ron();
+            assert_eq!("(1.0, 2.0)", serialized);
+            let deserialized = $rot2::deserialize_ron(&serialized).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $rot2::deserialize_ron(SX2).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $rot2::deserialize_ron(SR1);
+            assert!(deserialized.is_err());
+            let deserialized = $rot2::deserialize_ron(SR3);
+            assert!(deserialized.is_err());
+            let deserialized = $rot2::deserialize_ron(ST0);
             assert!(deserialized.is_err());
         }
     };
 }
 
-macro_rules! impl_serde_quat {
-    ($t:ty, $quat:ident) => {
-        impl nanoserde::DeJson for $quat {
-            fn de_json(
+macro_rules! impl_serde_vec3 {
+    ($t:ty, $vec3:ident) => {
+        impl_serde_vec3!(
+            $t,
+            $vec3,
+            test_vec3_serde,
+            test_vec3_serde_bin,
+            test_vec3_serde_ron
+        );
+    };
+    ($t:ty, $vec3:ident, $test_name:ident, $bin_test_name:ident, $ron_test_name:ident) => {
+        impl_de_with!($vec3);
+
+        impl super::DeJsonWith for $vec3 {
+            fn de_json_with(
                 s: &mut nanoserde::DeJsonState,
                 i: &mut core::str::Chars,
+                policy: super::NanoserdePolicy,
             ) -> Result<Self, nanoserde::DeJsonErr> {
-                Ok({
-                    s.block_open(i)?;
-                    let x: $t = nanoserde::DeJson::de_json(s, i)?;
-                    s.eat_comma_block(i)?;
-                    let y: $t = nanoserde::DeJson::de_json(s, i)?;
-                    s.eat_comma_block(i)?;
-                    let z: $t = nanoserde::DeJson::de_json(s, i)?;
-                    s.eat_comma_block(i)?;
-                    let w: $t = nanoserde::DeJson::de_json(s, i)?;
-                    s.block_close(i)?;
-                    $quat::from_xyzw(x, y, z, w)
-                })
+                let vals: [$t; 3] =
+                    super::de_json_vector(s, i, format_args!(stringify!($vec3)), policy)?;
+                Ok($vec3::from_array(vals))
             }
         }
 
-        impl nanoserde::SerJson for $quat {
+        impl nanoserde::SerJson for $vec3 {
             fn ser_json(&self, d: usize, s: &mut nanoserde::SerJsonState) {
                 s.out.push('[');
                 self.x.ser_json(d, s);
@@ -191,139 +930,1589 @@ macro_rules! impl_serde_quat {
                 self.y.ser_json(d, s);
                 s.out.push(',');
                 self.z.ser_json(d, s);
-                s.out.push(',');
-                self.w.ser_json(d, s);
                 s.out.push(']');
             }
         }
 
-        #[test]
-        fn test_quat_serde() {
+        impl super::SerJsonNamed for $vec3 {
+            fn ser_json_named(&self, d: usize, s: &mut nanoserde::SerJsonState) {
+                super::ser_json_object(&["x", "y", "z"], &[self.x, self.y, self.z], d, s);
+            }
+        }
+
+        impl nanoserde::DeBin for $vec3 {
+            fn de_bin(o: &mut usize, d: &[u8]) -> Result<Self, nanoserde::DeBinErr> {
+                let x: $t = nanoserde::DeBin::de_bin(o, d)?;
+                let y: $t = nanoserde::DeBin::de_bin(o, d)?;
+                let z: $t = nanoserde::DeBin::de_bin(o, d)?;
+                Ok($vec3::new(x, y, z))
+            }
+        }
+
+        impl nanoserde::SerBin for $vec3 {
+            fn ser_bin(&self, s: &mut Vec<u8>) {
+                self.x.ser_bin(s);
+                self.y.ser_bin(s);
+                self.z.ser_bin(s);
+            }
+        }
+
+        impl super::DeRonWith for $vec3 {
+            fn de_ron_with(
+                s: &mut nanoserde::DeRonState,
+                i: &mut core::str::Chars,
+                policy: super::NanoserdePolicy,
+            ) -> Result<Self, nanoserde::DeRonErr> {
+                let vals: [$t; 3] =
+                    super::de_ron_tuple(s, i, format_args!(stringify!($vec3)), policy)?;
+                Ok($vec3::from_array(vals))
+            }
+        }
+
+        impl nanoserde::SerRon for $vec3 {
+            fn ser_ron(&self, d: usize, s: &mut nanoserde::SerRonState) {
+                super::ser_ron_tuple(&self.to_array(), d, s);
+            }
+        }
+
+        #[test]
+        fn $test_name() {
+            let a = $vec3::new(V1, V2, V3);
+            let serialized = a.serialize_json();
+            assert_eq!(SX3, serialized);
+            assert_eq!(ST3, a.serialize_json_named());
+            let deserialized = $vec3::deserialize_json(&serialized).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $vec3::deserialize_json(SX3).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $vec3::deserialize_json(ST3).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $vec3::deserialize_json(ST2);
+            assert!(deserialized.is_err());
+            let deserialized = $vec3::deserialize_json(ST4);
+            assert!(deserialized.is_err());
+            let deserialized = $vec3::deserialize_json(SX0);
+            assert!(deserialized.is_err());
+            let deserialized = $vec3::deserialize_json(SX1);
+            assert!(deserialized.is_err());
+            let deserialized = $vec3::deserialize_json(SX2);
+            assert!(deserialized.is_err());
+            let deserialized = $vec3::deserialize_json(SX4);
+            assert!(deserialized.is_err());
+            let deserialized = $vec3::deserialize_json(ST0);
+            assert!(deserialized.is_err());
+        }
+
+        #[test]
+        fn $bin_test_name() {
+            let a = $vec3::new(V1, V2, V3);
+            let serialized = a.serialize_bin();
+            let mut expected = Vec::new();
+            for v in [V1, V2, V3] {
+                v.ser_bin(&mut expected);
+            }
+            assert_eq!(expected, serialized);
+            let deserialized = $vec3::deserialize_bin(&serialized).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $vec3::deserialize_bin(&serialized[..serialized.len() - 1]);
+            assert!(deserialized.is_err());
+            let deserialized = $vec3::deserialize_bin(&[]);
+            assert!(deserialized.is_err());
+        }
+
+        #[test]
+        fn $ron_test_name() {
+            let a = $vec3::new(V1, V2, V3);
+            let serialized = a.serialize_ron();
+            assert_eq!(SR3, serialized);
+            let deserialized = $vec3::deserialize_ron(&serialized).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $vec3::deserialize_ron(SX3).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $vec3::deserialize_ron(SR0);
+            assert!(deserialized.is_err());
+            let deserialized = $vec3::deserialize_ron(SR1);
+            assert!(deserialized.is_err());
+            let deserialized = $vec3::deserialize_ron(SR2);
+            assert!(deserialized.is_err());
+            let deserialized = $vec3::deserialize_ron(SR4);
+            assert!(deserialized.is_err());
+            let deserialized = $vec3::deserialize_ron(SX4);
+            assert!(deserialized.is_err());
+            let deserialized = $vec3::deserialize_ron(ST0);
+            assert!(deserialized.is_err());
+        }
+    };
+}
+
+macro_rules! impl_serde_vec4 {
+    ($t:ty, $vec4:ident) => {
+        impl_de_with!($vec4);
+
+        impl super::DeJsonWith for $vec4 {
+            fn de_json_with(
+                s: &mut nanoserde::DeJsonState,
+                i: &mut core::str::Chars,
+                policy: super::NanoserdePolicy,
+            ) -> Result<Self, nanoserde::DeJsonErr> {
+                let vals: [$t; 4] =
+                    super::de_json_vector(s, i, format_args!(stringify!($vec4)), policy)?;
+                Ok($vec4::from_array(vals))
+            }
+        }
+
+        impl nanoserde::SerJson for $vec4 {
+            fn ser_json(&self, d: usize, s: &mut nanoserde::SerJsonState) {
+                s.out.push('[');
+                self.x.ser_json(d, s);
+                s.out.push(',');
+                self.y.ser_json(d, s);
+                s.out.push(',');
+                self.z.ser_json(d, s);
+                s.out.push(',');
+                self.w.ser_json(d, s);
+                s.out.push(']');
+            }
+        }
+
+        impl super::SerJsonNamed for $vec4 {
+            fn ser_json_named(&self, d: usize, s: &mut nanoserde::SerJsonState) {
+                super::ser_json_object(
+                    &["x", "y", "z", "w"],
+                    &[self.x, self.y, self.z, self.w],
+                    d,
+                    s,
+                );
+            }
+        }
+
+        impl nanoserde::DeBin for $vec4 {
+            fn de_bin(o: &mut usize, d: &[u8]) -> Result<Self, nanoserde::DeBinErr> {
+                let x: $t = nanoserde::DeBin::de_bin(o, d)?;
+                let y: $t = nanoserde::DeBin::de_bin(o, d)?;
+                let z: $t = nanoserde::DeBin::de_bin(o, d)?;
+                let w: $t = nanoserde::DeBin::de_bin(o, d)?;
+                Ok($vec4::new(x, y, z, w))
+            }
+        }
+
+        impl nanoserde::SerBin for $vec4 {
+            fn ser_bin(&self, s: &mut Vec<u8>) {
+                self.x.ser_bin(s);
+                self.y.ser_bin(s);
+                self.z.ser_bin(s);
+                self.w.ser_bin(s);
+            }
+        }
+
+        impl super::DeRonWith for $vec4 {
+            fn de_ron_with(
+                s: &mut nanoserde::DeRonState,
+                i: &mut core::str::Chars,
+                policy: super::NanoserdePolicy,
+            ) -> Result<Self, nanoserde::DeRonErr> {
+                let vals: [$t; 4] =
+                    super::de_ron_tuple(s, i, format_args!(stringify!($vec4)), policy)?;
+                Ok($vec4::from_array(vals))
+            }
+        }
+
+        impl nanoserde::SerRon for $vec4 {
+            fn ser_ron(&self, d: usize, s: &mut nanoserde::SerRonState) {
+                super::ser_ron_tuple(&self.to_array(), d, s);
+            }
+        }
+
+        #[test]
+        fn test_vec4_serde() {
+            let a = $vec4::new(V1, V2, V3, V4);
+            let serialized = a.serialize_json();
+            assert_eq!(SX4, serialized);
+            assert_eq!(ST4, a.serialize_json_named());
+            let deserialized = $vec4::deserialize_json(&serialized).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $vec4::deserialize_json(SX4).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $vec4::deserialize_json(ST4).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $vec4::deserialize_json(ST3);
+            assert!(deserialized.is_err());
+            let deserialized = $vec4::deserialize_json(ST5);
+            assert!(deserialized.is_err());
+            let deserialized = $vec4::deserialize_json(SX0);
+            assert!(deserialized.is_err());
+            let deserialized = $vec4::deserialize_json(SX1);
+            assert!(deserialized.is_err());
+            let deserialized = $vec4::deserialize_json(SX2);
+            assert!(deserialized.is_err());
+            let deserialized = $vec4::deserialize_json(SX3);
+            assert!(deserialized.is_err());
+            let deserialized = $vec4::deserialize_json(SX5);
+            assert!(deserialized.is_err());
+            let deserialized = $vec4::deserialize_json(ST0);
+            assert!(deserialized.is_err());
+        }
+
+        #[test]
+        fn test_vec4_serde_bin() {
+            let a = $vec4::new(V1, V2, V3, V4);
+            let serialized = a.serialize_bin();
+            let mut expected = Vec::new();
+            for v in [V1, V2, V3, V4] {
+                v.ser_bin(&mut expected);
+            }
+            assert_eq!(expected, serialized);
+            let deserialized = $vec4::deserialize_bin(&serialized).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $vec4::deserialize_bin(&serialized[..serialized.len() - 1]);
+            assert!(deserialized.is_err());
+            let deserialized = $vec4::deserialize_bin(&[]);
+            assert!(deserialized.is_err());
+        }
+
+        #[test]
+        fn test_vec4_serde_ron() {
+            let a = $vec4::new(V1, V2, V3, V4);
+            let serialized = a.serialize_ron();
+            assert_eq!(SR4, serialized);
+            let deserialized = $vec4::deserialize_ron(&serialized).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $vec4::deserialize_ron(SX4).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $vec4::deserialize_ron(SR0);
+            assert!(deserialized.is_err());
+            let deserialized = $vec4::deserialize_ron(SR1);
+            assert!(deserialized.is_err());
+            let deserialized = $vec4::deserialize_ron(SR2);
+            assert!(deserialized.is_err());
+            let deserialized = $vec4::deserialize_ron(SR3);
+            assert!(deserialized.is_err());
+            let deserialized = $vec4::deserialize_ron(SR5);
+            assert!(deserialized.is_err());
+            let deserialized = $vec4::deserialize_ron(SX5);
+            assert!(deserialized.is_err());
+            let deserialized = $vec4::deserialize_ron(ST0);
+            assert!(deserialized.is_err());
+        }
+    };
+}
+
+macro_rules! impl_serde_quat {
+    ($t:ty, $quat:ident, $vec3:ident) => {
+        /// Deserializes a rotation from a JSON object. The object may contain either the
+        /// components `{"x":..,"y":..,"z":..,"w":..}`, Euler angles in degrees
+        /// `{"euler":"YXZ","deg":[..]}` or radians `{"euler":"YXZ","rad":[..]}`, or a rotation
+        /// axis and an angle in radians `{"axis":[..],"angle":..}`.
+        ///
+        /// Euler angles are given in the order of the rotation sequence. Rotations given as
+        /// Euler angles or axis-angle are normalized, components are used as is.
+        fn de_json_rotation(
+            s: &mut nanoserde::DeJsonState,
+            i: &mut core::str::Chars,
+            policy: super::NanoserdePolicy,
+        ) -> Result<$quat, nanoserde::DeJsonErr> {
+            const KEYS: [&str; 9] = ["x", "y", "z", "w", "euler", "deg", "rad", "axis", "angle"];
+            const XYZW: u32 = 0b1111;
+            const EULER: u32 = 1 << 4;
+            const DEG: u32 = 1 << 5;
+            const RAD: u32 = 1 << 6;
+            const AXIS: u32 = 1 << 7;
+            const ANGLE: u32 = 1 << 8;
+
+            let mut xyzw: [$t; 4] = [0.0; 4];
+            let mut euler = crate::EulerRot::default();
+            let mut angles: [$t; 3] = [0.0; 3];
+            let mut axis: [$t; 3] = [0.0; 3];
+            let mut angle: $t = 0.0;
+            let found = super::de_json_fields(s, i, &KEYS, |idx, s, i| {
+                let path = format_args!("{}.{}", stringify!($quat), KEYS[idx]);
+                match idx {
+                    0..=3 => xyzw[idx] = super::de_json_component(s, i, path, policy)?,
+                    4 => euler = super::de_json_euler_rot(s, i)?,
+                    5 | 6 => angles = super::de_json_vector(s, i, path, policy)?,
+                    7 => axis = super::de_json_vector(s, i, path, policy)?,
+                    _ => angle = super::de_json_component(s, i, path, policy)?,
+                }
+                Ok(())
+            })?;
+
+            match found {
+                XYZW => Ok($quat::from_array(xyzw)),
+                f if f == EULER | DEG => {
+                    let [a, b, c] = angles.map(<$t>::to_radians);
+                    Ok($quat::from_euler(euler, a, b, c).normalize())
+                }
+                f if f == EULER | RAD => {
+                    let [a, b, c] = angles;
+                    Ok($quat::from_euler(euler, a, b, c).normalize())
+                }
+                f if f == AXIS | ANGLE => match $vec3::from_array(axis).try_normalize() {
+                    Some(axis) => Ok($quat::from_axis_angle(axis, angle).normalize()),
+                    None => Err(s.err_parse("axis")),
+                },
+                _ => Err(s.err_parse(
+                    "rotation, expected keys x/y/z/w, euler/deg, euler/rad or axis/angle",
+                )),
+            }
+        }
+
+        impl_de_with!($quat);
+
+        impl super::DeJsonWith for $quat {
+            fn de_json_with(
+                s: &mut nanoserde::DeJsonState,
+                i: &mut core::str::Chars,
+                policy: super::NanoserdePolicy,
+            ) -> Result<Self, nanoserde::DeJsonErr> {
+                if s.tok == nanoserde::DeJsonTok::CurlyOpen {
+                    return de_json_rotation(s, i, policy);
+                }
+                let vals: [$t; 4] =
+                    super::de_json_array(s, i, format_args!(stringify!($quat)), policy)?;
+                Ok($quat::from_array(vals))
+            }
+        }
+
+        impl nanoserde::SerJson for $quat {
+            fn ser_json(&self, d: usize, s: &mut nanoserde::SerJsonState) {
+                s.out.push('[');
+                self.x.ser_json(d, s);
+                s.out.push(',');
+                self.y.ser_json(d, s);
+                s.out.push(',');
+                self.z.ser_json(d, s);
+                s.out.push(',');
+                self.w.ser_json(d, s);
+                s.out.push(']');
+            }
+        }
+
+        impl super::SerJsonNamed for $quat {
+            fn ser_json_named(&self, d: usize, s: &mut nanoserde::SerJsonState) {
+                super::ser_json_object(
+                    &["x", "y", "z", "w"],
+                    &[self.x, self.y, self.z, self.w],
+                    d,
+                    s,
+                );
+            }
+        }
+
+        impl nanoserde::DeBin for $quat {
+            fn de_bin(o: &mut usize, d: &[u8]) -> Result<Self, nanoserde::DeBinErr> {
+                let x: $t = nanoserde::DeBin::de_bin(o, d)?;
+                let y: $t = nanoserde::DeBin::de_bin(o, d)?;
+                let z: $t = nanoserde::DeBin::de_bin(o, d)?;
+                let w: $t = nanoserde::DeBin::de_bin(o, d)?;
+                Ok($quat::from_xyzw(x, y, z, w))
+            }
+        }
+
+        impl nanoserde::SerBin for $quat {
+            fn ser_bin(&self, s: &mut Vec<u8>) {
+                self.x.ser_bin(s);
+                self.y.ser_bin(s);
+                self.z.ser_bin(s);
+                self.w.ser_bin(s);
+            }
+        }
+
+        impl super::DeRonWith for $quat {
+            fn de_ron_with(
+                s: &mut nanoserde::DeRonState,
+                i: &mut core::str::Chars,
+                policy: super::NanoserdePolicy,
+            ) -> Result<Self, nanoserde::DeRonErr> {
+                let vals: [$t; 4] =
+                    super::de_ron_tuple(s, i, format_args!(stringify!($quat)), policy)?;
+                Ok($quat::from_array(vals))
+            }
+        }
+
+        impl nanoserde::SerRon for $quat {
+            fn ser_ron(&self, d: usize, s: &mut nanoserde::SerRonState) {
+                super::ser_ron_tuple(&self.to_array(), d, s);
+            }
+        }
+
+        #[test]
+        fn test_quat_serde() {
             let a = $quat::from_xyzw(1.0, 2.0, 3.0, 4.0);
             let serialized = a.serialize_json();
             assert_eq!(serialized, "[1.0,2.0,3.0,4.0]");
+            assert_eq!(
+                a.serialize_json_named(),
+                r#"{"x":1.0,"y":2.0,"z":3.0,"w":4.0}"#
+            );
             let deserialized = $quat::deserialize_json(&serialized).unwrap();
             assert_eq!(a, deserialized);
-            let deserialized = $quat::deserialize_json("[]");
+            let deserialized = $quat::deserialize_json("[1.0,2.0,3.0,4.0]").unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized =
+                $quat::deserialize_json(r#"{"x":1.0,"y":2.0,"z":3.0,"w":4.0}"#).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $quat::deserialize_json(r#"{"x":1.0,"y":2.0,"z":3.0}"#);
+            assert!(deserialized.is_err());
+            let deserialized =
+                $quat::deserialize_json(r#"{"x":1.0,"y":2.0,"z":3.0,"w":4.0,"x":1.0}"#);
+            assert!(deserialized.is_err());
+            let deserialized = $quat::deserialize_json(r#"{"x":1.0,"y":2.0,"z":3.0,"v":4.0}"#);
+            assert!(deserialized.is_err());
+            let deserialized = $quat::deserialize_json("[]");
+            assert!(deserialized.is_err());
+            let deserialized = $quat::deserialize_json("[1.0]");
+            assert!(deserialized.is_err());
+            let deserialized = $quat::deserialize_json("[1.0,2.0]");
+            assert!(deserialized.is_err());
+            let deserialized = $quat::deserialize_json("[1.0,2.0,3.0]");
+            assert!(deserialized.is_err());
+            let deserialized = $quat::deserialize_json("[1.0,2.0,3.0,4.0,5.0]");
+            assert!(deserialized.is_err());
+            let deserialized = $quat::deserialize_json("{}");
+            assert!(deserialized.is_err());
+        }
+
+        #[test]
+        fn test_quat_serde_rotation() {
+            use crate::EulerRot;
+            let expected = $quat::from_euler(
+                EulerRot::YXZ,
+                (30.0 as $t).to_radians(),
+                (45.0 as $t).to_radians(),
+                (60.0 as $t).to_radians(),
+            );
+            let q = $quat::deserialize_json(r#"{"euler":"YXZ","deg":[30.0,45.0,60.0]}"#).unwrap();
+            assert!(q.abs_diff_eq(expected, 1e-6));
+            assert!(q.is_normalized());
+            let q = $quat::deserialize_json(r#"{"deg":[30,45,60],"euler":"YXZ"}"#).unwrap();
+            assert!(q.abs_diff_eq(expected, 1e-6));
+
+            let expected = $quat::from_euler(EulerRot::XYZEx, 0.5, 1.0, 1.5);
+            let q = $quat::deserialize_json(r#"{"euler":"XYZEx","rad":[0.5,1.0,1.5]}"#).unwrap();
+            assert!(q.abs_diff_eq(expected, 1e-6));
+            assert!(q.is_normalized());
+
+            let expected = $quat::from_axis_angle($vec3::Y, 1.0);
+            let q = $quat::deserialize_json(r#"{"axis":[0.0,2.0,0.0],"angle":1.0}"#).unwrap();
+            assert!(q.abs_diff_eq(expected, 1e-6));
+            assert!(q.is_normalized());
+            let q = $quat::deserialize_json(r#"{"angle":1.0,"axis":{"x":0.0,"y":0.5,"z":0.0}}"#)
+                .unwrap();
+            assert!(q.abs_diff_eq(expected, 1e-6));
+
+            let q = $quat::deserialize_json(r#"{"x":0.0,"y":0.0,"z":0.0,"w":2.0}"#).unwrap();
+            assert_eq!(q, $quat::from_xyzw(0.0, 0.0, 0.0, 2.0));
+
+            let q = $quat::deserialize_json(r#"{"euler":"ABC","deg":[30.0,45.0,60.0]}"#);
+            assert!(q.is_err());
+            let q = $quat::deserialize_json(r#"{"euler":1,"deg":[30.0,45.0,60.0]}"#);
+            assert!(q.is_err());
+            let q = $quat::deserialize_json(r#"{"euler":"YXZ"}"#);
+            assert!(q.is_err());
+            let q = $quat::deserialize_json(r#"{"euler":"YXZ","deg":[30.0,45.0]}"#);
+            assert!(q.is_err());
+            let q = $quat::deserialize_json(
+                r#"{"euler":"YXZ","deg":[1.0,2.0,3.0],"rad":[1.0,2.0,3.0]}"#,
+            );
+            assert!(q.is_err());
+            let q = $quat::deserialize_json(r#"{"axis":[0.0,0.0,0.0],"angle":1.0}"#);
+            assert!(q.is_err());
+            let q = $quat::deserialize_json(r#"{"axis":[0.0,1.0,0.0]}"#);
+            assert!(q.is_err());
+            let q = $quat::deserialize_json(r#"{"axis":[0.0,1.0,0.0],"angle":1.0,"w":1.0}"#);
+            assert!(q.is_err());
+            let q = $quat::deserialize_json(
+                r#"{"axis":[0.0,1.0,0.0],"angle":1.0,"axis":[1.0,0.0,0.0]}"#,
+            );
+            assert!(q.is_err());
+        }
+
+        #[test]
+        fn test_quat_serde_bin() {
+            let a = $quat::from_xyzw(1.0, 2.0, 3.0, 4.0);
+            let serialized = a.serialize_bin();
+            let mut expected = Vec::new();
+            for v in [1.0 as $t, 2.0, 3.0, 4.0] {
+                v.ser_bin(&mut expected);
+            }
+            assert_eq!(expected, serialized);
+            let deserialized = $quat::deserialize_bin(&serialized).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $quat::deserialize_bin(&serialized[..serialized.len() - 1]);
+            assert!(deserialized.is_err());
+            let deserialized = $quat::deserialize_bin(&[]);
+            assert!(deserialized.is_err());
+        }
+
+        #[test]
+        fn test_quat_serde_ron() {
+            let a = $quat::from_xyzw(1.0, 2.0, 3.0, 4.0);
+            let serialized = a.serialize_ron();
+            assert_eq!(serialized, "(1.0, 2.0, 3.0, 4.0)");
+            let deserialized = $quat::deserialize_ron(&serialized).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $quat::deserialize_ron("[1.0,2.0,3.0,4.0]").unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $quat::deserialize_ron("()");
+            assert!(deserialized.is_err());
+            let deserialized = $quat::deserialize_ron("(1.0)");
+            assert!(deserialized.is_err());
+            let deserialized = $quat::deserialize_ron("(1.0, 2.0)");
+            assert!(deserialized.is_err());
+            let deserialized = $quat::deserialize_ron("(1.0, 2.0, 3.0)");
+            assert!(deserialized.is_err());
+            let deserialized = $quat::deserialize_ron("(1.0, 2.0, 3.0, 4.0, 5.0)");
+            assert!(deserialized.is_err());
+            let deserialized = $quat::deserialize_ron("[1.0, 2.0, 3.0, 4.0)");
+            assert!(deserialized.is_err());
+            let deserialized = $quat::deserialize_ron("{}");
+            assert!(deserialized.is_err());
+        }
+    };
+}
+
+macro_rules! impl_serde_dualquat {
+    ($t:ty, $dualquat:ident) => {
+        impl_de_with!($dualquat);
+
+        impl super::DeJsonWith for $dualquat {
+            fn de_json_with(
+                s: &mut nanoserde::DeJsonState,
+                i: &mut core::str::Chars,
+                policy: super::NanoserdePolicy,
+            ) -> Result<Self, nanoserde::DeJsonErr> {
+                if s.tok == nanoserde::DeJsonTok::CurlyOpen {
+                    let mut vals: [$t; 8] = [0.0; 8];
+                    let keys = ["real", "dual"];
+                    super::de_json_object(s, i, &keys, |idx, s, i| {
+                        let part: [$t; 4] = super::de_json_vector(
+                            s,
+                            i,
+                            format_args!("{}.{}", stringify!($dualquat), keys[idx]), policy,
+                        )?;
+                        vals[idx * 4..idx * 4 + 4].copy_from_slice(&part);
+                        Ok(())
+                    })?;
+                    return Ok($dualquat::from_array(vals));
+                }
+                let vals: [$t; 8] =
+                    super::de_json_array(s, i, format_args!(stringify!($dualquat)), policy)?;
+                Ok($dualquat::from_array(vals))
+            }
+        }
+
+        impl nanoserde::SerJson for $dualquat {
+            fn ser_json(&self, d: usize, s: &mut nanoserde::SerJsonState) {
+                s.out.push('[');
+                for (idx, val) in self.to_array().iter().enumerate() {
+                    if idx != 0 {
+                        s.out.push(',');
+                    }
+                    val.ser_json(d, s);
+                }
+                s.out.push(']');
+            }
+        }
+
+        impl super::SerJsonNamed for $dualquat {
+            fn ser_json_named(&self, d: usize, s: &mut nanoserde::SerJsonState) {
+                super::ser_json_object(&["real", "dual"], &[self.real, self.dual], d, s);
+            }
+        }
+
+        impl nanoserde::DeBin for $dualquat {
+            fn de_bin(o: &mut usize, d: &[u8]) -> Result<Self, nanoserde::DeBinErr> {
+                let mut vals: [$t; 8] = [0.0; 8];
+                for val in vals.iter_mut() {
+                    *val = nanoserde::DeBin::de_bin(o, d)?;
+                }
+                Ok($dualquat::from_array(vals))
+            }
+        }
+
+        impl nanoserde::SerBin for $dualquat {
+            fn ser_bin(&self, s: &mut Vec<u8>) {
+                for val in self.to_array() {
+                    val.ser_bin(s);
+                }
+            }
+        }
+
+        impl super::DeRonWith for $dualquat {
+            fn de_ron_with(
+                s: &mut nanoserde::DeRonState,
+                i: &mut core::str::Chars,
+                policy: super::NanoserdePolicy,
+            ) -> Result<Self, nanoserde::DeRonErr> {
+                let vals: [$t; 8] =
+                    super::de_ron_tuple(s, i, format_args!(stringify!($dualquat)), policy)?;
+                Ok($dualquat::from_array(vals))
+            }
+        }
+
+        impl nanoserde::SerRon for $dualquat {
+            fn ser_ron(&self, d: usize, s: &mut nanoserde::SerRonState) {
+                super::ser_ron_tuple(&self.to_array(), d, s);
+            }
+        }
+
+        #[test]
+        fn test_dualquat_serde() {
+            let a = $dualquat::from_array([1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+            let sx = "[1.0,2.0,3.0,4.0,5.0,6.0,7.0,8.0]";
+            let st = r#"{"real":{"x":1.0,"y":2.0,"z":3.0,"w":4.0},"dual":{"x":5.0,"y":6.0,"z":7.0,"w":8.0}}"#;
+            let serialized = a.serialize_json();
+            assert_eq!(sx, serialized);
+            assert_eq!(st, a.serialize_json_named());
+            let deserialized = $dualquat::deserialize_json(&serialized).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $dualquat::deserialize_json(sx).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $dualquat::deserialize_json(st).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized =
+                $dualquat::deserialize_json(r#"{"dual":[5.0,6.0,7.0,8.0],"real":[1,2,3,4]}"#)
+                    .unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $dualquat::deserialize_json(SX4);
+            assert!(deserialized.is_err());
+            let deserialized = $dualquat::deserialize_json("[1.0,2.0,3.0,4.0,5.0,6.0,7.0]");
+            assert!(deserialized.is_err());
+            let deserialized =
+                $dualquat::deserialize_json("[1.0,2.0,3.0,4.0,5.0,6.0,7.0,8.0,9.0]");
+            assert!(deserialized.is_err());
+            let deserialized = $dualquat::deserialize_json(r#"{"real":[1.0,2.0,3.0,4.0]}"#);
+            assert!(deserialized.is_err());
+            let deserialized = $dualquat::deserialize_json(
+                r#"{"real":{"euler":"XYZ","rad":[0.0,0.0,0.0]},"dual":[5.0,6.0,7.0,8.0]}"#,
+            );
+            assert!(deserialized.is_err());
+            let deserialized = $dualquat::deserialize_json(ST0);
+            assert!(deserialized.is_err());
+        }
+
+        #[test]
+        fn test_dualquat_serde_bin() {
+            let a = $dualquat::from_array([1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+            let serialized = a.serialize_bin();
+            let mut expected = Vec::new();
+            for v in a.to_array() {
+                v.ser_bin(&mut expected);
+            }
+            assert_eq!(expected, serialized);
+            let deserialized = $dualquat::deserialize_bin(&serialized).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $dualquat::deserialize_bin(&serialized[..serialized.len() - 1]);
+            assert!(deserialized.is_err());
+            let deserialized = $dualquat::deserialize_bin(&[]);
+            assert!(deserialized.is_err());
+        }
+
+        #[test]
+        fn test_dualquat_serde_ron() {
+            let a = $dualquat::from_array([1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+            let serialized = a.serialize_ron();
+            assert_eq!(serialized, "(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0)");
+            let deserialized = $dualquat::deserialize_ron(&serialized).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized =
+                $dualquat::deserialize_ron("[1.0,2.0,3.0,4.0,5.0,6.0,7.0,8.0]").unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $dualquat::deserialize_ron(SR4);
+            assert!(deserialized.is_err());
+            let deserialized =
+                $dualquat::deserialize_ron("(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0)");
+            assert!(deserialized.is_err());
+            let deserialized = $dualquat::deserialize_ron(ST0);
+            assert!(deserialized.is_err());
+        }
+    };
+}
+
+macro_rules! de_json_isometry_rotation {
+    (quat, $t:ty, $s:ident, $i:ident, $path:expr, $policy:ident) => {
+        super::de_json_vector::<$t, 4>($s, $i, $path, $policy)
+    };
+    (mat2, $t:ty, $s:ident, $i:ident, $path:expr, $policy:ident) => {
+        super::de_json_matrix::<$t, 2, 2>($s, $i, $path, $policy)
+            .map(|[x, y]| [x[0], x[1], y[0], y[1]])
+    };
+}
+
+macro_rules! impl_serde_isometry {
+    ($testname:ident, $bin_testname:ident, $ron_testname:ident, $t:ty, $isometry:ident, $n:literal, $rotation:ident, $sx:literal, $st:literal, $sr:literal) => {
+        impl_de_with!($isometry);
+
+        impl super::DeJsonWith for $isometry {
+            fn de_json_with(
+                s: &mut nanoserde::DeJsonState,
+                i: &mut core::str::Chars,
+                policy: super::NanoserdePolicy,
+            ) -> Result<Self, nanoserde::DeJsonErr> {
+                if s.tok == nanoserde::DeJsonTok::CurlyOpen {
+                    let mut vals: [$t; $n] = [0.0; $n];
+                    super::de_json_object(s, i, &["rotation", "translation"], |idx, s, i| {
+                        if idx == 0 {
+                            let rotation = de_json_isometry_rotation!(
+                                $rotation,
+                                $t,
+                                s,
+                                i,
+                                format_args!("{}.rotation", stringify!($isometry)),
+                                policy
+                            )?;
+                            vals[..4].copy_from_slice(&rotation);
+                        } else {
+                            let translation: [$t; $n - 4] = super::de_json_vector(
+                                s,
+                                i,
+                                format_args!("{}.translation", stringify!($isometry)),
+                                policy,
+                            )?;
+                            vals[4..].copy_from_slice(&translation);
+                        }
+                        Ok(())
+                    })?;
+                    return Ok($isometry::from_array(vals));
+                }
+                let vals: [$t; $n] =
+                    super::de_json_array(s, i, format_args!(stringify!($isometry)), policy)?;
+                Ok($isometry::from_array(vals))
+            }
+        }
+
+        impl nanoserde::SerJson for $isometry {
+            fn ser_json(&self, d: usize, s: &mut nanoserde::SerJsonState) {
+                s.out.push('[');
+                for (idx, val) in self.to_array().iter().enumerate() {
+                    if idx != 0 {
+                        s.out.push(',');
+                    }
+                    val.ser_json(d, s);
+                }
+                s.out.push(']');
+            }
+        }
+
+        impl super::SerJsonNamed for $isometry {
+            fn ser_json_named(&self, d: usize, s: &mut nanoserde::SerJsonState) {
+                s.out.push('{');
+                s.field(d + 1, "rotation");
+                super::SerJsonField::ser_json_field(&self.rotation, d + 1, s);
+                s.out.push(',');
+                s.field(d + 1, "translation");
+                super::SerJsonField::ser_json_field(&self.translation, d + 1, s);
+                s.out.push('}');
+            }
+        }
+
+        impl nanoserde::DeBin for $isometry {
+            fn de_bin(o: &mut usize, d: &[u8]) -> Result<Self, nanoserde::DeBinErr> {
+                let mut vals: [$t; $n] = [0.0; $n];
+                for val in vals.iter_mut() {
+                    *val = nanoserde::DeBin::de_bin(o, d)?;
+                }
+                Ok($isometry::from_array(vals))
+            }
+        }
+
+        impl nanoserde::SerBin for $isometry {
+            fn ser_bin(&self, s: &mut Vec<u8>) {
+                for val in self.to_array() {
+                    val.ser_bin(s);
+                }
+            }
+        }
+
+        impl super::DeRonWith for $isometry {
+            fn de_ron_with(
+                s: &mut nanoserde::DeRonState,
+                i: &mut core::str::Chars,
+                policy: super::NanoserdePolicy,
+            ) -> Result<Self, nanoserde::DeRonErr> {
+                let vals: [$t; $n] =
+                    super::de_ron_tuple(s, i, format_args!(stringify!($isometry)), policy)?;
+                Ok($isometry::from_array(vals))
+            }
+        }
+
+        impl nanoserde::SerRon for $isometry {
+            fn ser_ron(&self, d: usize, s: &mut nanoserde::SerRonState) {
+                super::ser_ron_tuple(&self.to_array(), d, s);
+            }
+        }
+
+        #[test]
+        fn $testname() {
+            let a = $isometry::deserialize_json($sx).unwrap();
+            let serialized = a.serialize_json();
+            assert_eq!($sx, serialized);
+            assert_eq!($st, a.serialize_json_named());
+            let deserialized = $isometry::deserialize_json(&serialized).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $isometry::deserialize_json($st).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $isometry::deserialize_json(r#"{"rotation":[1,2,3,4]}"#);
+            assert!(deserialized.is_err());
+            let deserialized = $isometry::deserialize_json(SX0);
+            assert!(deserialized.is_err());
+            let deserialized = $isometry::deserialize_json(SX4);
+            assert!(deserialized.is_err());
+            let deserialized = $isometry::deserialize_json(SX5);
+            assert!(deserialized.is_err());
+            let deserialized = $isometry::deserialize_json("[1.0,2.0,3.0,4.0,5.0,6.0,7.0,8.0]");
+            assert!(deserialized.is_err());
+            let deserialized = $isometry::deserialize_json(ST0);
+            assert!(deserialized.is_err());
+        }
+
+        #[test]
+        fn $bin_testname() {
+            let a = $isometry::deserialize_json($sx).unwrap();
+            let serialized = a.serialize_bin();
+            let mut expected = Vec::new();
+            for v in a.to_array() {
+                v.ser_bin(&mut expected);
+            }
+            assert_eq!(expected, serialized);
+            let deserialized = $isometry::deserialize_bin(&serialized).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $isometry::deserialize_bin(&serialized[..serialized.len() - 1]);
+            assert!(deserialized.is_err());
+            let deserialized = $isometry::deserialize_bin(&[]);
+            assert!(deserialized.is_err());
+        }
+
+        #[test]
+        fn $ron_testname() {
+            let a = $isometry::deserialize_json($sx).unwrap();
+            let serialized = a.serialize_ron();
+            assert_eq!($sr, serialized);
+            let deserialized = $isometry::deserialize_ron(&serialized).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $isometry::deserialize_ron($sx).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $isometry::deserialize_ron(SR0);
+            assert!(deserialized.is_err());
+            let deserialized = $isometry::deserialize_ron(SR5);
+            assert!(deserialized.is_err());
+            let deserialized = $isometry::deserialize_ron(ST0);
+            assert!(deserialized.is_err());
+        }
+    };
+}
+
+macro_rules! de_json_transform_rotation {
+    (angle, $t:ty, $s:ident, $i:ident, $path:expr, $policy:ident) => {
+        super::de_json_component::<$t>($s, $i, $path, $policy).map(|angle| [angle])
+    };
+    (rotation, $t:ty, $s:ident, $i:ident, $path:expr, $policy:ident) => {
+        super::de_json_vector::<$t, 4>($s, $i, $path, $policy)
+    };
+}
+
+macro_rules! impl_serde_transform {
+    ($testname:ident, $bin_testname:ident, $ron_testname:ident, $t:ty, $transform:ident, $d:literal, $rotation:ident, $r:literal, $sx:literal, $st:literal, $sr:literal) => {
+        impl_de_with!($transform);
+
+        impl super::DeJsonWith for $transform {
+            fn de_json_with(
+                s: &mut nanoserde::DeJsonState,
+                i: &mut core::str::Chars,
+                policy: super::NanoserdePolicy,
+            ) -> Result<Self, nanoserde::DeJsonErr> {
+                if s.tok == nanoserde::DeJsonTok::CurlyOpen {
+                    let mut vals: [$t; 2 * $d + $r] = [0.0; 2 * $d + $r];
+                    let keys = ["scale", stringify!($rotation), "translation"];
+                    super::de_json_object(s, i, &keys, |idx, s, i| {
+                        match idx {
+                            0 => {
+                                let scale: [$t; $d] = super::de_json_vector(
+                                    s,
+                                    i,
+                                    format_args!("{}.scale", stringify!($transform)),
+                                    policy,
+                                )?;
+                                vals[..$d].copy_from_slice(&scale);
+                            }
+                            1 => {
+                                let rotation = de_json_transform_rotation!(
+                                    $rotation,
+                                    $t,
+                                    s,
+                                    i,
+                                    format_args!(
+                                        "{}.{}",
+                                        stringify!($transform),
+                                        stringify!($rotation)
+                                    ),
+                                    policy
+                                )?;
+                                vals[$d..$d + $r].copy_from_slice(&rotation);
+                            }
+                            _ => {
+                                let translation: [$t; $d] = super::de_json_vector(
+                                    s,
+                                    i,
+                                    format_args!("{}.translation", stringify!($transform)),
+                                    policy,
+                                )?;
+                                vals[$d + $r..].copy_from_slice(&translation);
+                            }
+                        }
+                        Ok(())
+                    })?;
+                    return Ok($transform::from_array(vals));
+                }
+                let vals: [$t; 2 * $d + $r] =
+                    super::de_json_array(s, i, format_args!(stringify!($transform)), policy)?;
+                Ok($transform::from_array(vals))
+            }
+        }
+
+        impl nanoserde::SerJson for $transform {
+            fn ser_json(&self, d: usize, s: &mut nanoserde::SerJsonState) {
+                s.out.push('[');
+                for (idx, val) in self.to_array().iter().enumerate() {
+                    if idx != 0 {
+                        s.out.push(',');
+                    }
+                    val.ser_json(d, s);
+                }
+                s.out.push(']');
+            }
+        }
+
+        impl super::SerJsonNamed for $transform {
+            fn ser_json_named(&self, d: usize, s: &mut nanoserde::SerJsonState) {
+                s.out.push('{');
+                s.field(d + 1, "scale");
+                super::SerJsonField::ser_json_field(&self.scale, d + 1, s);
+                s.out.push(',');
+                s.field(d + 1, stringify!($rotation));
+                super::SerJsonField::ser_json_field(&self.$rotation, d + 1, s);
+                s.out.push(',');
+                s.field(d + 1, "translation");
+                super::SerJsonField::ser_json_field(&self.translation, d + 1, s);
+                s.out.push('}');
+            }
+        }
+
+        impl nanoserde::DeBin for $transform {
+            fn de_bin(o: &mut usize, d: &[u8]) -> Result<Self, nanoserde::DeBinErr> {
+                let mut vals: [$t; 2 * $d + $r] = [0.0; 2 * $d + $r];
+                for val in vals.iter_mut() {
+                    *val = nanoserde::DeBin::de_bin(o, d)?;
+                }
+                Ok($transform::from_array(vals))
+            }
+        }
+
+        impl nanoserde::SerBin for $transform {
+            fn ser_bin(&self, s: &mut Vec<u8>) {
+                for val in self.to_array() {
+                    val.ser_bin(s);
+                }
+            }
+        }
+
+        impl super::DeRonWith for $transform {
+            fn de_ron_with(
+                s: &mut nanoserde::DeRonState,
+                i: &mut core::str::Chars,
+                policy: super::NanoserdePolicy,
+            ) -> Result<Self, nanoserde::DeRonErr> {
+                let vals: [$t; 2 * $d + $r] =
+                    super::de_ron_tuple(s, i, format_args!(stringify!($transform)), policy)?;
+                Ok($transform::from_array(vals))
+            }
+        }
+
+        impl nanoserde::SerRon for $transform {
+            fn ser_ron(&self, d: usize, s: &mut nanoserde::SerRonState) {
+                super::ser_ron_tuple(&self.to_array(), d, s);
+            }
+        }
+
+        #[test]
+        fn $testname() {
+            let a = $transform::deserialize_json($sx).unwrap();
+            let serialized = a.serialize_json();
+            assert_eq!($sx, serialized);
+            assert_eq!($st, a.serialize_json_named());
+            let deserialized = $transform::deserialize_json(&serialized).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $transform::deserialize_json($st).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $transform::deserialize_json(r#"{"scale":[1,2,3]}"#);
+            assert!(deserialized.is_err());
+            let deserialized = $transform::deserialize_json(SX0);
+            assert!(deserialized.is_err());
+            let deserialized = $transform::deserialize_json(SX4);
+            assert!(deserialized.is_err());
+            let deserialized =
+                $transform::deserialize_json("[1.0,2.0,3.0,4.0,5.0,6.0,7.0,8.0,9.0,10.0,11.0]");
+            assert!(deserialized.is_err());
+            let deserialized = $transform::deserialize_json(ST0);
+            assert!(deserialized.is_err());
+        }
+
+        #[test]
+        fn $bin_testname() {
+            let a = $transform::deserialize_json($sx).unwrap();
+            let serialized = a.serialize_bin();
+            let mut expected = Vec::new();
+            for v in a.to_array() {
+                v.ser_bin(&mut expected);
+            }
+            assert_eq!(expected, serialized);
+            let deserialized = $transform::deserialize_bin(&serialized).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $transform::deserialize_bin(&serialized[..serialized.len() - 1]);
+            assert!(deserialized.is_err());
+            let deserialized = $transform::deserialize_bin(&[]);
+            assert!(deserialized.is_err());
+        }
+
+        #[test]
+        fn $ron_testname() {
+            let a = $transform::deserialize_json($sx).unwrap();
+            let serialized = a.serialize_ron();
+            assert_eq!($sr, serialized);
+            let deserialized = $transform::deserialize_ron(&serialized).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $transform::deserialize_ron($sx).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $transform::deserialize_ron(SR0);
+            assert!(deserialized.is_err());
+            let deserialized = $transform::deserialize_ron(SR4);
+            assert!(deserialized.is_err());
+            let deserialized = $transform::deserialize_ron(ST0);
+            assert!(deserialized.is_err());
+        }
+    };
+}
+
+macro_rules! impl_serde_mat2 {
+    ($t:ty, $mat2:ident) => {
+        impl_de_with!($mat2);
+
+        impl super::DeJsonWith for $mat2 {
+            fn de_json_with(
+                s: &mut nanoserde::DeJsonState,
+                i: &mut core::str::Chars,
+                policy: super::NanoserdePolicy,
+            ) -> Result<Self, nanoserde::DeJsonErr> {
+                let cols: [[$t; 2]; 2] =
+                    super::de_json_matrix(s, i, format_args!(stringify!($mat2)), policy)?;
+                Ok($mat2::from_cols_array_2d(&cols))
+            }
+        }
+
+        impl nanoserde::SerJson for $mat2 {
+            fn ser_json(&self, d: usize, s: &mut nanoserde::SerJsonState) {
+                let cols: [$t; 4] = self.to_cols_array();
+                s.out.push('[');
+                cols[0].ser_json(d, s);
+                s.out.push(',');
+                cols[1].ser_json(d, s);
+                s.out.push(',');
+                cols[2].ser_json(d, s);
+                s.out.push(',');
+                cols[3].ser_json(d, s);
+                s.out.push(']');
+            }
+        }
+
+        impl super::SerJsonNamed for $mat2 {
+            fn ser_json_named(&self, d: usize, s: &mut nanoserde::SerJsonState) {
+                super::ser_json_object(&["x_axis", "y_axis"], &[self.x_axis, self.y_axis], d, s);
+            }
+        }
+
+        impl nanoserde::DeBin for $mat2 {
+            fn de_bin(o: &mut usize, d: &[u8]) -> Result<Self, nanoserde::DeBinErr> {
+                let mut vals: [$t; 4] = [0.0; 4];
+                for val in vals.iter_mut() {
+                    *val = nanoserde::DeBin::de_bin(o, d)?;
+                }
+                Ok($mat2::from_cols_array(&vals))
+            }
+        }
+
+        impl nanoserde::SerBin for $mat2 {
+            fn ser_bin(&self, s: &mut Vec<u8>) {
+                for val in self.to_cols_array() {
+                    val.ser_bin(s);
+                }
+            }
+        }
+
+        impl super::DeRonWith for $mat2 {
+            fn de_ron_with(
+                s: &mut nanoserde::DeRonState,
+                i: &mut core::str::Chars,
+                policy: super::NanoserdePolicy,
+            ) -> Result<Self, nanoserde::DeRonErr> {
+                let vals: [$t; 4] =
+                    super::de_ron_tuple(s, i, format_args!(stringify!($mat2)), policy)?;
+                Ok($mat2::from_cols_array(&vals))
+            }
+        }
+
+        impl nanoserde::SerRon for $mat2 {
+            fn ser_ron(&self, d: usize, s: &mut nanoserde::SerRonState) {
+                super::ser_ron_tuple(&self.to_cols_array(), d, s);
+            }
+        }
+
+        #[test]
+        fn test_mat2_serde() {
+            let a = $mat2::from_cols_array(&[1.0, 2.0, 3.0, 4.0]);
+            let serialized = a.serialize_json();
+            assert_eq!(serialized, "[1.0,2.0,3.0,4.0]");
+            assert_eq!(
+                a.serialize_json_named(),
+                r#"{"x_axis":{"x":1.0,"y":2.0},"y_axis":{"x":3.0,"y":4.0}}"#
+            );
+            let deserialized = $mat2::deserialize_json(&serialized).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $mat2::deserialize_json("[1.0,2.0,3.0,4.0]").unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $mat2::deserialize_json(
+                r#"{"x_axis":{"x":1.0,"y":2.0},"y_axis":{"x":3.0,"y":4.0}}"#,
+            )
+            .unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $mat2::deserialize_json(r#"{"x_axis":{"x":1.0,"y":2.0}}"#);
+            assert!(deserialized.is_err());
+            let deserialized =
+                $mat2::deserialize_json(r#"{"x_axis":[1.0,2.0],"y_axis":[3.0,4.0,5.0]}"#);
+            assert!(deserialized.is_err());
+            let deserialized = $mat2::deserialize_json("[]");
+            assert!(deserialized.is_err());
+            let deserialized = $mat2::deserialize_json("[1.0]");
+            assert!(deserialized.is_err());
+            let deserialized = $mat2::deserialize_json("[1.0,2.0]");
+            assert!(deserialized.is_err());
+            let deserialized = $mat2::deserialize_json("[1.0,2.0,3.0]");
+            assert!(deserialized.is_err());
+            let deserialized = $mat2::deserialize_json("[1.0,2.0,3.0,4.0,5.0]");
+            assert!(deserialized.is_err());
+            let deserialized = $mat2::deserialize_json("[[1.0,2.0],[3.0,4.0]]");
+            assert!(deserialized.is_err());
+            let deserialized = $mat2::deserialize_json("{}");
+            assert!(deserialized.is_err());
+        }
+
+        #[test]
+        fn test_mat2_serde_bin() {
+            let a = $mat2::from_cols_array(&[1.0, 2.0, 3.0, 4.0]);
+            let serialized = a.serialize_bin();
+            let mut expected = Vec::new();
+            for v in a.to_cols_array() {
+                v.ser_bin(&mut expected);
+            }
+            assert_eq!(expected, serialized);
+            let deserialized = $mat2::deserialize_bin(&serialized).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $mat2::deserialize_bin(&serialized[..serialized.len() - 1]);
+            assert!(deserialized.is_err());
+            let deserialized = $mat2::deserialize_bin(&[]);
+            assert!(deserialized.is_err());
+        }
+
+        #[test]
+        fn test_mat2_serde_ron() {
+            let a = $mat2::from_cols_array(&[1.0, 2.0, 3.0, 4.0]);
+            let serialized = a.serialize_ron();
+            assert_eq!(serialized, "(1.0, 2.0, 3.0, 4.0)");
+            let deserialized = $mat2::deserialize_ron(&serialized).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $mat2::deserialize_ron("[1.0,2.0,3.0,4.0]").unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $mat2::deserialize_ron("()");
+            assert!(deserialized.is_err());
+            let deserialized = $mat2::deserialize_ron("(1.0)");
+            assert!(deserialized.is_err());
+            let deserialized = $mat2::deserialize_ron("(1.0, 2.0, 3.0)");
+            assert!(deserialized.is_err());
+            let deserialized = $mat2::deserialize_ron("(1.0, 2.0, 3.0, 4.0, 5.0)");
+            assert!(deserialized.is_err());
+            let deserialized = $mat2::deserialize_ron("((1.0, 2.0), (3.0, 4.0))");
+            assert!(deserialized.is_err());
+            let deserialized = $mat2::deserialize_ron("{}");
+            assert!(deserialized.is_err());
+        }
+    };
+}
+
+macro_rules! impl_serde_mat3 {
+    ($t:ty, $mat3:ident) => {
+        impl_serde_mat3!(
+            $t,
+            $mat3,
+            test_mat3_serde,
+            test_mat3_serde_bin,
+            test_mat3_serde_ron
+        );
+    };
+    ($t:ty, $mat3:ident, $test_name:ident, $bin_test_name:ident, $ron_test_name:ident) => {
+        impl_de_with!($mat3);
+
+        impl super::DeJsonWith for $mat3 {
+            fn de_json_with(
+                s: &mut nanoserde::DeJsonState,
+                i: &mut core::str::Chars,
+                policy: super::NanoserdePolicy,
+            ) -> Result<Self, nanoserde::DeJsonErr> {
+                let cols: [[$t; 3]; 3] =
+                    super::de_json_matrix(s, i, format_args!(stringify!($mat3)), policy)?;
+                Ok($mat3::from_cols_array_2d(&cols))
+            }
+        }
+
+        impl nanoserde::SerJson for $mat3 {
+            fn ser_json(&self, d: usize, s: &mut nanoserde::SerJsonState) {
+                let cols: [$t; 9] = self.to_cols_array();
+                s.out.push('[');
+                for i in 0..9 {
+                    cols[i].ser_json(d, s);
+                    if i != 8 {
+                        s.out.push(',');
+                    }
+                }
+                s.out.push(']');
+            }
+        }
+
+        impl super::SerJsonNamed for $mat3 {
+            fn ser_json_named(&self, d: usize, s: &mut nanoserde::SerJsonState) {
+                super::ser_json_object(
+                    &["x_axis", "y_axis", "z_axis"],
+                    &[self.x_axis, self.y_axis, self.z_axis],
+                    d,
+                    s,
+                );
+            }
+        }
+
+        impl nanoserde::DeBin for $mat3 {
+            fn de_bin(o: &mut usize, d: &[u8]) -> Result<Self, nanoserde::DeBinErr> {
+                let mut vals: [$t; 9] = [0.0; 9];
+                for val in vals.iter_mut() {
+                    *val = nanoserde::DeBin::de_bin(o, d)?;
+                }
+                Ok($mat3::from_cols_array(&vals))
+            }
+        }
+
+        impl nanoserde::SerBin for $mat3 {
+            fn ser_bin(&self, s: &mut Vec<u8>) {
+                for val in self.to_cols_array() {
+                    val.ser_bin(s);
+                }
+            }
+        }
+
+        impl super::DeRonWith for $mat3 {
+            fn de_ron_with(
+                s: &mut nanoserde::DeRonState,
+                i: &mut core::str::Chars,
+                policy: super::NanoserdePolicy,
+            ) -> Result<Self, nanoserde::DeRonErr> {
+                let vals: [$t; 9] = super::de_ron_tuple(s, i, format_args!(stringify!($mat3)), policy)?;
+                Ok($mat3::from_cols_array(&vals))
+            }
+        }
+
+        impl nanoserde::SerRon for $mat3 {
+            fn ser_ron(&self, d: usize, s: &mut nanoserde::SerRonState) {
+                super::ser_ron_tuple(&self.to_cols_array(), d, s);
+            }
+        }
+
+        #[test]
+        fn $test_name() {
+            let a = $mat3::from_cols_array(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+            let serialized = a.serialize_json();
+            assert_eq!(serialized, "[1.0,2.0,3.0,4.0,5.0,6.0,7.0,8.0,9.0]");
+            assert_eq!(a.serialize_json_named(), r#"{"x_axis":{"x":1.0,"y":2.0,"z":3.0},"y_axis":{"x":4.0,"y":5.0,"z":6.0},"z_axis":{"x":7.0,"y":8.0,"z":9.0}}"#);
+            let deserialized = $mat3::deserialize_json(&serialized).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $mat3::deserialize_json("[1.0,2.0,3.0,4.0,5.0,6.0,7.0,8.0,9.0]").unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $mat3::deserialize_json(r#"{"x_axis":{"x":1.0,"y":2.0,"z":3.0},"y_axis":{"x":4.0,"y":5.0,"z":6.0},"z_axis":{"x":7.0,"y":8.0,"z":9.0}}"#).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $mat3::deserialize_json(r#"{"x_axis":[1.0,2.0,3.0],"y_axis":[4.0,5.0,6.0]}"#);
+            assert!(deserialized.is_err());
+            let deserialized = $mat3::deserialize_json(r#"{"x_axis":[1.0,2.0,3.0],"y_axis":[4.0,5.0,6.0],"z_axis":[7.0,8.0,9.0],"w_axis":[0.0,0.0,0.0]}"#);
+            assert!(deserialized.is_err());
+            let deserialized = $mat3::deserialize_json("[]");
+            assert!(deserialized.is_err());
+            let deserialized = $mat3::deserialize_json("[1.0]");
+            assert!(deserialized.is_err());
+            let deserialized = $mat3::deserialize_json("[1.0,2.0]");
+            assert!(deserialized.is_err());
+            let deserialized = $mat3::deserialize_json("[1.0,2.0,3.0]");
+            assert!(deserialized.is_err());
+            let deserialized = $mat3::deserialize_json("[1.0,2.0,3.0,4.0,5.0]");
+            assert!(deserialized.is_err());
+            let deserialized =
+                $mat3::deserialize_json("[[1.0,2.0,3.0],[4.0,5.0,6.0],[7.0,8.0,9.0]]");
+            assert!(deserialized.is_err());
+            let deserialized = $mat3::deserialize_json("{}");
+            assert!(deserialized.is_err());
+        }
+
+        #[test]
+        fn $bin_test_name() {
+            let a = $mat3::from_cols_array(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+            let serialized = a.serialize_bin();
+            let mut expected = Vec::new();
+            for v in a.to_cols_array() {
+                v.ser_bin(&mut expected);
+            }
+            assert_eq!(expected, serialized);
+            let deserialized = $mat3::deserialize_bin(&serialized).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $mat3::deserialize_bin(&serialized[..serialized.len() - 1]);
+            assert!(deserialized.is_err());
+            let deserialized = $mat3::deserialize_bin(&[]);
+            assert!(deserialized.is_err());
+        }
+
+        #[test]
+        fn $ron_test_name() {
+            let a = $mat3::from_cols_array(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+            let serialized = a.serialize_ron();
+            assert_eq!(serialized, "(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0)");
+            let deserialized = $mat3::deserialize_ron(&serialized).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized =
+                $mat3::deserialize_ron("[1.0,2.0,3.0,4.0,5.0,6.0,7.0,8.0,9.0]").unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $mat3::deserialize_ron("()");
             assert!(deserialized.is_err());
-            let deserialized = $quat::deserialize_json("[1.0]");
+            let deserialized = $mat3::deserialize_ron("(1.0)");
             assert!(deserialized.is_err());
-            let deserialized = $quat::deserialize_json("[1.0,2.0]");
+            let deserialized = $mat3::deserialize_ron("(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0)");
             assert!(deserialized.is_err());
-            let deserialized = $quat::deserialize_json("[1.0,2.0,3.0]");
+            let deserialized =
+                $mat3::deserialize_ron("(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0)");
             assert!(deserialized.is_err());
-            let deserialized = $quat::deserialize_json("[1.0,2.0,3.0,4.0,5.0]");
+            let deserialized =
+                $mat3::deserialize_ron("((1.0, 2.0, 3.0), (4.0, 5.0, 6.0), (7.0, 8.0, 9.0))");
             assert!(deserialized.is_err());
-            let deserialized = $quat::deserialize_json("{}");
+            let deserialized = $mat3::deserialize_ron("{}");
             assert!(deserialized.is_err());
         }
     };
 }
 
-macro_rules! impl_serde_mat2 {
-    ($t:ty, $mat2:ident) => {
-        impl nanoserde::DeJson for $mat2 {
-            fn de_json(
+macro_rules! impl_serde_mat4 {
+    ($t:ty, $mat4:ident) => {
+        impl_de_with!($mat4);
+
+        impl super::DeJsonWith for $mat4 {
+            fn de_json_with(
                 s: &mut nanoserde::DeJsonState,
                 i: &mut core::str::Chars,
+                policy: super::NanoserdePolicy,
             ) -> Result<Self, nanoserde::DeJsonErr> {
-                Ok({
-                    s.block_open(i)?;
-                    let m00: $t = nanoserde::DeJson::de_json(s, i)?;
-                    s.eat_comma_block(i)?;
-                    let m01: $t = nanoserde::DeJson::de_json(s, i)?;
-                    s.eat_comma_block(i)?;
-                    let m10: $t = nanoserde::DeJson::de_json(s, i)?;
-                    s.eat_comma_block(i)?;
-                    let m11: $t = nanoserde::DeJson::de_json(s, i)?;
-                    s.block_close(i)?;
-                    $mat2::from_cols_array(&[m00, m01, m10, m11])
-                })
+                let cols: [[$t; 4]; 4] =
+                    super::de_json_matrix(s, i, format_args!(stringify!($mat4)), policy)?;
+                Ok($mat4::from_cols_array_2d(&cols))
             }
         }
 
-        impl nanoserde::SerJson for $mat2 {
+        impl nanoserde::SerJson for $mat4 {
             fn ser_json(&self, d: usize, s: &mut nanoserde::SerJsonState) {
-                let cols: [$t; 4] = self.to_cols_array();
+                let cols: [$t; 16] = self.to_cols_array();
                 s.out.push('[');
-                cols[0].ser_json(d, s);
-                s.out.push(',');
-                cols[1].ser_json(d, s);
-                s.out.push(',');
-                cols[2].ser_json(d, s);
-                s.out.push(',');
-                cols[3].ser_json(d, s);
+                for i in 0..16 {
+                    cols[i].ser_json(d, s);
+                    if i != 15 {
+                        s.out.push(',');
+                    }
+                }
                 s.out.push(']');
             }
         }
 
+        impl super::SerJsonNamed for $mat4 {
+            fn ser_json_named(&self, d: usize, s: &mut nanoserde::SerJsonState) {
+                super::ser_json_object(
+                    &["x_axis", "y_axis", "z_axis", "w_axis"],
+                    &[self.x_axis, self.y_axis, self.z_axis, self.w_axis],
+                    d,
+                    s,
+                );
+            }
+        }
+
+        impl nanoserde::DeBin for $mat4 {
+            fn de_bin(o: &mut usize, d: &[u8]) -> Result<Self, nanoserde::DeBinErr> {
+                let mut vals: [$t; 16] = [0.0; 16];
+                for val in vals.iter_mut() {
+                    *val = nanoserde::DeBin::de_bin(o, d)?;
+                }
+                Ok($mat4::from_cols_array(&vals))
+            }
+        }
+
+        impl nanoserde::SerBin for $mat4 {
+            fn ser_bin(&self, s: &mut Vec<u8>) {
+                for val in self.to_cols_array() {
+                    val.ser_bin(s);
+                }
+            }
+        }
+
+        impl super::DeRonWith for $mat4 {
+            fn de_ron_with(
+                s: &mut nanoserde::DeRonState,
+                i: &mut core::str::Chars,
+                policy: super::NanoserdePolicy,
+            ) -> Result<Self, nanoserde::DeRonErr> {
+                let vals: [$t; 16] = super::de_ron_tuple(s, i, format_args!(stringify!($mat4)), policy)?;
+                Ok($mat4::from_cols_array(&vals))
+            }
+        }
+
+        impl nanoserde::SerRon for $mat4 {
+            fn ser_ron(&self, d: usize, s: &mut nanoserde::SerRonState) {
+                super::ser_ron_tuple(&self.to_cols_array(), d, s);
+            }
+        }
+
         #[test]
-        fn test_mat2_serde() {
-            let a = $mat2::from_cols_array(&[1.0, 2.0, 3.0, 4.0]);
+        fn test_mat4_serde() {
+            let a = $mat4::from_cols_array(&[
+                1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0,
+                16.0,
+            ]);
             let serialized = a.serialize_json();
-            assert_eq!(serialized, "[1.0,2.0,3.0,4.0]");
-            let deserialized = $mat2::deserialize_json(&serialized).unwrap();
+            assert_eq!(serialized, "[1.0,2.0,3.0,4.0,5.0,6.0,7.0,8.0,9.0,10.0,11.0,12.0,13.0,14.0,15.0,16.0]");
+            assert_eq!(a.serialize_json_named(), r#"{"x_axis":{"x":1.0,"y":2.0,"z":3.0,"w":4.0},"y_axis":{"x":5.0,"y":6.0,"z":7.0,"w":8.0},"z_axis":{"x":9.0,"y":10.0,"z":11.0,"w":12.0},"w_axis":{"x":13.0,"y":14.0,"z":15.0,"w":16.0}}"#);
+            let deserialized = $mat4::deserialize_json(&serialized).unwrap();
             assert_eq!(a, deserialized);
-            let deserialized = $mat2::deserialize_json("[]");
+            let deserialized = $mat4::deserialize_json("[1.0,2.0,3.0,4.0,5.0,6.0,7.0,8.0,9.0,10.0,11.0,12.0,13.0,14.0,15.0,16.0]").unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $mat4::deserialize_json(r#"{"x_axis":{"x":1.0,"y":2.0,"z":3.0,"w":4.0},"y_axis":{"x":5.0,"y":6.0,"z":7.0,"w":8.0},"z_axis":{"x":9.0,"y":10.0,"z":11.0,"w":12.0},"w_axis":{"x":13.0,"y":14.0,"z":15.0,"w":16.0}}"#).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $mat4::deserialize_json(r#"{"x_axis":[1.0,2.0,3.0,4.0],"y_axis":[5.0,6.0,7.0,8.0],"z_axis":[9.0,10.0,11.0,12.0]}"#);
             assert!(deserialized.is_err());
-            let deserialized = $mat2::deserialize_json("[1.0]");
+            let deserialized = $mat4::deserialize_json("[]");
             assert!(deserialized.is_err());
-            let deserialized = $mat2::deserialize_json("[1.0,2.0]");
+            let deserialized = $mat4::deserialize_json("[1.0]");
             assert!(deserialized.is_err());
-            let deserialized = $mat2::deserialize_json("[1.0,2.0,3.0]");
+            let deserialized = $mat4::deserialize_json("[1.0,2.0]");
             assert!(deserialized.is_err());
-            let deserialized = $mat2::deserialize_json("[1.0,2.0,3.0,4.0,5.0]");
+            let deserialized = $mat4::deserialize_json("[1.0,2.0,3.0]");
             assert!(deserialized.is_err());
-            let deserialized = $mat2::deserialize_json("[[1.0,2.0],[3.0,4.0]]");
+            let deserialized = $mat4::deserialize_json("[1.0,2.0,3.0,4.0,5.0]");
             assert!(deserialized.is_err());
-            let deserialized = $mat2::deserialize_json("{}");
+            let deserialized =
+                $mat4::deserialize_json("[[1.0,2.0,3.0],[4.0,5.0,6.0],[7.0,8.0,9.0]]");
+            assert!(deserialized.is_err());
+            let deserialized = $mat4::deserialize_json(
+                "[[1.0,2.0,3.0,4.0],[5.0,6.0,7.0,8.0],[9.0,10.0,11.0,12.0][13.0,14.0,15.0,16.0]]",
+            );
+            assert!(deserialized.is_err());
+            let deserialized = $mat4::deserialize_json("{}");
+            assert!(deserialized.is_err());
+        }
+
+        #[test]
+        fn test_mat4_serde_bin() {
+            let a = $mat4::from_cols_array(&[
+                1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0,
+                16.0,
+            ]);
+            let serialized = a.serialize_bin();
+            let mut expected = Vec::new();
+            for v in a.to_cols_array() {
+                v.ser_bin(&mut expected);
+            }
+            assert_eq!(expected, serialized);
+            let deserialized = $mat4::deserialize_bin(&serialized).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $mat4::deserialize_bin(&serialized[..serialized.len() - 1]);
+            assert!(deserialized.is_err());
+            let deserialized = $mat4::deserialize_bin(&[]);
+            assert!(deserialized.is_err());
+        }
+
+        #[test]
+        fn test_mat4_serde_ron() {
+            let a = $mat4::from_cols_array(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0]);
+            let serialized = a.serialize_ron();
+            assert_eq!(serialized, "(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0)");
+            let deserialized = $mat4::deserialize_ron(&serialized).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $mat4::deserialize_ron("[1.0,2.0,3.0,4.0,5.0,6.0,7.0,8.0,9.0,10.0,11.0,12.0,13.0,14.0,15.0,16.0]").unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $mat4::deserialize_ron("()");
+            assert!(deserialized.is_err());
+            let deserialized = $mat4::deserialize_ron("(1.0)");
+            assert!(deserialized.is_err());
+            let deserialized = $mat4::deserialize_ron("(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0)");
+            assert!(deserialized.is_err());
+            let deserialized = $mat4::deserialize_ron("(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0, 17.0)");
+            assert!(deserialized.is_err());
+            let deserialized = $mat4::deserialize_ron("((1.0, 2.0, 3.0, 4.0), (5.0, 6.0, 7.0, 8.0), (9.0, 10.0, 11.0, 12.0), (13.0, 14.0, 15.0, 16.0))");
+            assert!(deserialized.is_err());
+            let deserialized = $mat4::deserialize_ron("{}");
             assert!(deserialized.is_err());
         }
     };
 }
 
-macro_rules! impl_serde_mat3 {
-    ($t:ty, $mat3:ident) => {
-        impl_serde_mat3!($t, $mat3, test_mat3_serde);
-    };
-    ($t:ty, $mat3:ident, $test_name:ident) => {
-        impl nanoserde::DeJson for $mat3 {
-            fn de_json(
+macro_rules! impl_serde_affine2 {
+    ($t:ty, $affine2:ident) => {
+        impl_de_with!($affine2);
+
+        impl super::DeJsonWith for $affine2 {
+            fn de_json_with(
                 s: &mut nanoserde::DeJsonState,
                 i: &mut core::str::Chars,
+                policy: super::NanoserdePolicy,
             ) -> Result<Self, nanoserde::DeJsonErr> {
-                Ok({
-                    s.block_open(i)?;
-                    let v1: $t = nanoserde::DeJson::de_json(s, i)?;
-                    s.eat_comma_block(i)?;
-                    let v2: $t = nanoserde::DeJson::de_json(s, i)?;
-                    s.eat_comma_block(i)?;
-                    let v3: $t = nanoserde::DeJson::de_json(s, i)?;
-                    s.eat_comma_block(i)?;
-                    let v4: $t = nanoserde::DeJson::de_json(s, i)?;
-                    s.eat_comma_block(i)?;
-                    let v5: $t = nanoserde::DeJson::de_json(s, i)?;
-                    s.eat_comma_block(i)?;
-                    let v6: $t = nanoserde::DeJson::de_json(s, i)?;
-                    s.eat_comma_block(i)?;
-                    let v7: $t = nanoserde::DeJson::de_json(s, i)?;
-                    s.eat_comma_block(i)?;
-                    let v8: $t = nanoserde::DeJson::de_json(s, i)?;
-                    s.eat_comma_block(i)?;
-                    let v9: $t = nanoserde::DeJson::de_json(s, i)?;
-                    s.block_close(i)?;
-                    $mat3::from_cols_array(&[v1, v2, v3, v4, v5, v6, v7, v8, v9])
-                })
+                if s.tok == nanoserde::DeJsonTok::CurlyOpen {
+                    let mut cols = [[<$t>::default(); 2]; 3];
+                    super::de_json_object(s, i, &["matrix2", "translation"], |idx, s, i| {
+                        if idx == 0 {
+                            let matrix: [[$t; 2]; 2] = super::de_json_matrix(
+                                s,
+                                i,
+                                format_args!("{}.matrix2", stringify!($affine2)), policy,
+                            )?;
+                            cols[..2].copy_from_slice(&matrix);
+                        } else {
+                            cols[2] = super::de_json_vector(
+                                s,
+                                i,
+                                format_args!("{}.translation", stringify!($affine2)), policy,
+                            )?;
+                        }
+                        Ok(())
+                    })?;
+                    return Ok($affine2::from_cols_array_2d(&cols));
+                }
+                let cols: [[$t; 2]; 3] =
+                    super::de_json_matrix(s, i, format_args!(stringify!($affine2)), policy)?;
+                Ok($affine2::from_cols_array_2d(&cols))
             }
         }
 
-        impl nanoserde::SerJson for $mat3 {
+        impl nanoserde::SerJson for $affine2 {
             fn ser_json(&self, d: usize, s: &mut nanoserde::SerJsonState) {
-                let cols: [$t; 9] = self.to_cols_array();
+                let cols: [$t; 6] = self.to_cols_array();
                 s.out.push('[');
-                for i in 0..9 {
+                for i in 0..6 {
                     cols[i].ser_json(d, s);
-                    if i != 8 {
+                    if i != 5 {
                         s.out.push(',');
                     }
                 }
@@ -331,235 +2520,623 @@ macro_rules! impl_serde_mat3 {
             }
         }
 
+        impl super::SerJsonNamed for $affine2 {
+            fn ser_json_named(&self, d: usize, s: &mut nanoserde::SerJsonState) {
+                s.out.push('{');
+                s.field(d + 1, "matrix2");
+                super::SerJsonField::ser_json_field(&self.matrix2, d + 1, s);
+                s.out.push(',');
+                s.field(d + 1, "translation");
+                super::SerJsonField::ser_json_field(&self.translation, d + 1, s);
+                s.out.push('}');
+            }
+        }
+
+        impl nanoserde::DeBin for $affine2 {
+            fn de_bin(o: &mut usize, d: &[u8]) -> Result<Self, nanoserde::DeBinErr> {
+                let mut vals: [$t; 6] = [0.0; 6];
+                for val in vals.iter_mut() {
+                    *val = nanoserde::DeBin::de_bin(o, d)?;
+                }
+                Ok($affine2::from_cols_array(&vals))
+            }
+        }
+
+        impl nanoserde::SerBin for $affine2 {
+            fn ser_bin(&self, s: &mut Vec<u8>) {
+                for val in self.to_cols_array() {
+                    val.ser_bin(s);
+                }
+            }
+        }
+
+        impl super::DeRonWith for $affine2 {
+            fn de_ron_with(
+                s: &mut nanoserde::DeRonState,
+                i: &mut core::str::Chars,
+                policy: super::NanoserdePolicy,
+            ) -> Result<Self, nanoserde::DeRonErr> {
+                let vals: [$t; 6] = super::de_ron_tuple(s, i, format_args!(stringify!($affine2)), policy)?;
+                Ok($affine2::from_cols_array(&vals))
+            }
+        }
+
+        impl nanoserde::SerRon for $affine2 {
+            fn ser_ron(&self, d: usize, s: &mut nanoserde::SerRonState) {
+                super::ser_ron_tuple(&self.to_cols_array(), d, s);
+            }
+        }
+
         #[test]
-        fn $test_name() {
-            let a = $mat3::from_cols_array(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+        fn test_affine2_serde() {
+            let a = $affine2::from_cols_array(&[1.0, 0.0, 2.0, 0.0, 3.0, 4.0]);
             let serialized = a.serialize_json();
-            assert_eq!(serialized, "[1.0,2.0,3.0,4.0,5.0,6.0,7.0,8.0,9.0]");
-            let deserialized = $mat3::deserialize_json(&serialized).unwrap();
+            assert_eq!(serialized, "[1.0,0.0,2.0,0.0,3.0,4.0]");
+            assert_eq!(a.serialize_json_named(), r#"{"matrix2":{"x_axis":{"x":1.0,"y":0.0},"y_axis":{"x":2.0,"y":0.0}},"translation":{"x":3.0,"y":4.0}}"#);
+            let deserialized = $affine2::deserialize_json(&serialized).unwrap();
             assert_eq!(a, deserialized);
-            let deserialized = $mat3::deserialize_json("[]");
+            let deserialized = $affine2::deserialize_json("[1.0,0.0,2.0,0.0,3.0,4.0]").unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $affine2::deserialize_json(r#"{"matrix2":{"x_axis":{"x":1.0,"y":0.0},"y_axis":{"x":2.0,"y":0.0}},"translation":{"x":3.0,"y":4.0}}"#).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $affine2::deserialize_json(r#"{"matrix2":[1.0,0.0,2.0,0.0]}"#);
             assert!(deserialized.is_err());
-            let deserialized = $mat3::deserialize_json("[1.0]");
+            let deserialized = $affine2::deserialize_json(r#"{"matrix2":[1.0,0.0,2.0,0.0],"translation":[3.0,4.0],"matrix3":[]}"#);
             assert!(deserialized.is_err());
-            let deserialized = $mat3::deserialize_json("[1.0,2.0]");
+
+            let deserialized = $affine2::deserialize_json("[]");
             assert!(deserialized.is_err());
-            let deserialized = $mat3::deserialize_json("[1.0,2.0,3.0]");
+            let deserialized = $affine2::deserialize_json("[1.0]");
             assert!(deserialized.is_err());
-            let deserialized = $mat3::deserialize_json("[1.0,2.0,3.0,4.0,5.0]");
+            let deserialized = $affine2::deserialize_json("[1.0,2.0]");
             assert!(deserialized.is_err());
-            let deserialized =
-                $mat3::deserialize_json("[[1.0,2.0,3.0],[4.0,5.0,6.0],[7.0,8.0,9.0]]");
+            let deserialized = $affine2::deserialize_json("[1.0,2.0,3.0]");
             assert!(deserialized.is_err());
-            let deserialized = $mat3::deserialize_json("{}");
+            let deserialized = $affine2::deserialize_json("[1.0,2.0,3.0,4.0,5.0]");
+            assert!(deserialized.is_err());
+            let deserialized = $affine2::deserialize_json("[[1.0,2.0],[3.0,4.0],[5.0,6.0]]");
+            assert!(deserialized.is_err());
+            let deserialized = $affine2::deserialize_json(
+                "[[1.0,2.0,3.0,4.0],[5.0,6.0,7.0,8.0],[9.0,10.0,11.0,12.0][13.0,14.0,15.0,16.0]]",
+            );
+            assert!(deserialized.is_err());
+            let deserialized = $affine2::deserialize_json("{}");
+            assert!(deserialized.is_err());
+        }
+
+        #[test]
+        fn test_affine2_serde_bin() {
+            let a = $affine2::from_cols_array(&[1.0, 0.0, 2.0, 0.0, 3.0, 4.0]);
+            let serialized = a.serialize_bin();
+            let mut expected = Vec::new();
+            for v in a.to_cols_array() {
+                v.ser_bin(&mut expected);
+            }
+            assert_eq!(expected, serialized);
+            let deserialized = $affine2::deserialize_bin(&serialized).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $affine2::deserialize_bin(&serialized[..serialized.len() - 1]);
+            assert!(deserialized.is_err());
+            let deserialized = $affine2::deserialize_bin(&[]);
+            assert!(deserialized.is_err());
+        }
+
+        #[test]
+        fn test_affine2_serde_ron() {
+            let a = $affine2::from_cols_array(&[1.0, 0.0, 2.0, 0.0, 3.0, 4.0]);
+            let serialized = a.serialize_ron();
+            assert_eq!(serialized, "(1.0, 0.0, 2.0, 0.0, 3.0, 4.0)");
+            let deserialized = $affine2::deserialize_ron(&serialized).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $affine2::deserialize_ron("[1.0,0.0,2.0,0.0,3.0,4.0]").unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $affine2::deserialize_ron("()");
+            assert!(deserialized.is_err());
+            let deserialized = $affine2::deserialize_ron("(1.0)");
+            assert!(deserialized.is_err());
+            let deserialized = $affine2::deserialize_ron("(1.0, 0.0, 2.0, 0.0, 3.0)");
+            assert!(deserialized.is_err());
+            let deserialized = $affine2::deserialize_ron("(1.0, 0.0, 2.0, 0.0, 3.0, 4.0, 7.0)");
+            assert!(deserialized.is_err());
+            let deserialized = $affine2::deserialize_ron("((1.0, 0.0), (2.0, 0.0), (3.0, 4.0))");
+            assert!(deserialized.is_err());
+            let deserialized = $affine2::deserialize_ron("{}");
             assert!(deserialized.is_err());
         }
     };
 }
 
-macro_rules! impl_serde_mat4 {
-    ($t:ty, $mat4:ident) => {
-        impl nanoserde::DeJson for $mat4 {
-            fn de_json(
+macro_rules! impl_serde_affine3 {
+    ($testname:ident, $bin_testname:ident, $ron_testname:ident, $t:ty, $affine3:ident) => {
+        impl_de_with!($affine3);
+
+        /// Serialize as a sequence of 12 values.
+        impl super::DeJsonWith for $affine3 {
+            fn de_json_with(
                 s: &mut nanoserde::DeJsonState,
                 i: &mut core::str::Chars,
+                policy: super::NanoserdePolicy,
             ) -> Result<Self, nanoserde::DeJsonErr> {
-                Ok({
-                    s.block_open(i)?;
-                    let mut vals: [$t; 16] = [0.0; 16];
-                    for idx in 0..16 {
-                        vals[idx] = nanoserde::DeJson::de_json(s, i)?;
-                        if idx != 15 {
-                            s.eat_comma_block(i)?;
+                if s.tok == nanoserde::DeJsonTok::CurlyOpen {
+                    let mut cols = [[<$t>::default(); 3]; 4];
+                    super::de_json_object(s, i, &["matrix3", "translation"], |idx, s, i| {
+                        if idx == 0 {
+                            let matrix: [[$t; 3]; 3] = super::de_json_matrix(
+                                s,
+                                i,
+                                format_args!("{}.matrix3", stringify!($affine3)), policy,
+                            )?;
+                            cols[..3].copy_from_slice(&matrix);
+                        } else {
+                            cols[3] = super::de_json_vector(
+                                s,
+                                i,
+                                format_args!("{}.translation", stringify!($affine3)), policy,
+                            )?;
                         }
+                        Ok(())
+                    })?;
+                    return Ok($affine3::from_cols_array_2d(&cols));
+                }
+                let cols: [[$t; 3]; 4] =
+                    super::de_json_matrix(s, i, format_args!(stringify!($affine3)), policy)?;
+                Ok($affine3::from_cols_array_2d(&cols))
+            }
+        }
+
+        impl nanoserde::SerJson for $affine3 {
+            fn ser_json(&self, d: usize, s: &mut nanoserde::SerJsonState) {
+                let cols: [$t; 12] = self.to_cols_array();
+                s.out.push('[');
+                for i in 0..12 {
+                    cols[i].ser_json(d, s);
+                    if i != 11 {
+                        s.out.push(',');
                     }
-                    $mat4::from_cols_array(&vals)
-                })
+                }
+                s.out.push(']');
+            }
+        }
+
+        impl super::SerJsonNamed for $affine3 {
+            fn ser_json_named(&self, d: usize, s: &mut nanoserde::SerJsonState) {
+                s.out.push('{');
+                s.field(d + 1, "matrix3");
+                super::SerJsonField::ser_json_field(&self.matrix3, d + 1, s);
+                s.out.push(',');
+                s.field(d + 1, "translation");
+                super::SerJsonField::ser_json_field(&self.translation, d + 1, s);
+                s.out.push('}');
+            }
+        }
+
+        impl nanoserde::DeBin for $affine3 {
+            fn de_bin(o: &mut usize, d: &[u8]) -> Result<Self, nanoserde::DeBinErr> {
+                let mut vals: [$t; 12] = [0.0; 12];
+                for val in vals.iter_mut() {
+                    *val = nanoserde::DeBin::de_bin(o, d)?;
+                }
+                Ok($affine3::from_cols_array(&vals))
+            }
+        }
+
+        impl nanoserde::SerBin for $affine3 {
+            fn ser_bin(&self, s: &mut Vec<u8>) {
+                for val in self.to_cols_array() {
+                    val.ser_bin(s);
+                }
+            }
+        }
+
+        impl super::DeRonWith for $affine3 {
+            fn de_ron_with(
+                s: &mut nanoserde::DeRonState,
+                i: &mut core::str::Chars,
+                policy: super::NanoserdePolicy,
+            ) -> Result<Self, nanoserde::DeRonErr> {
+                let vals: [$t; 12] = super::de_ron_tuple(s, i, format_args!(stringify!($affine3)), policy)?;
+                Ok($affine3::from_cols_array(&vals))
+            }
+        }
+
+        impl nanoserde::SerRon for $affine3 {
+            fn ser_ron(&self, d: usize, s: &mut nanoserde::SerRonState) {
+                super::ser_ron_tuple(&self.to_cols_array(), d, s);
             }
         }
 
-        impl nanoserde::SerJson for $mat4 {
-            fn ser_json(&self, d: usize, s: &mut nanoserde::SerJsonState) {
-                let cols: [$t; 16] = self.to_cols_array();
-                s.out.push('[');
-                for i in 0..16 {
-                    cols[i].ser_json(d, s);
-                    if i != 15 {
-                        s.out.push(',');
-                    }
-                }
-                s.out.push(']');
+        #[test]
+        fn $testname() {
+            let a = $affine3::from_cols_array(&[
+                1.0, 0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 3.0, 4.0, 5.0, 6.0,
+            ]);
+            let serialized = a.serialize_json();
+            assert_eq!(serialized, "[1.0,0.0,0.0,0.0,2.0,0.0,0.0,0.0,3.0,4.0,5.0,6.0]");
+            assert_eq!(a.serialize_json_named(), r#"{"matrix3":{"x_axis":{"x":1.0,"y":0.0,"z":0.0},"y_axis":{"x":0.0,"y":2.0,"z":0.0},"z_axis":{"x":0.0,"y":0.0,"z":3.0}},"translation":{"x":4.0,"y":5.0,"z":6.0}}"#);
+            let deserialized = $affine3::deserialize_json(&serialized).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $affine3::deserialize_json("[1.0,0.0,0.0,0.0,2.0,0.0,0.0,0.0,3.0,4.0,5.0,6.0]").unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $affine3::deserialize_json(r#"{"matrix3":{"x_axis":{"x":1.0,"y":0.0,"z":0.0},"y_axis":{"x":0.0,"y":2.0,"z":0.0},"z_axis":{"x":0.0,"y":0.0,"z":3.0}},"translation":{"x":4.0,"y":5.0,"z":6.0}}"#).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $affine3::deserialize_json(r#"{"translation":[4.0,5.0,6.0]}"#);
+            assert!(deserialized.is_err());
+            let deserialized = $affine3::deserialize_json(r#"{"matrix3":[1.0,0.0,0.0,0.0,2.0,0.0,0.0,0.0,3.0],"translation":[4.0,5.0]}"#);
+            assert!(deserialized.is_err());
+
+            let deserialized = $affine3::deserialize_json("[]");
+            assert!(deserialized.is_err());
+            let deserialized = $affine3::deserialize_json("[1.0]");
+            assert!(deserialized.is_err());
+            let deserialized = $affine3::deserialize_json("[1.0,2.0]");
+            assert!(deserialized.is_err());
+            let deserialized = $affine3::deserialize_json("[1.0,2.0,3.0]");
+            assert!(deserialized.is_err());
+            let deserialized = $affine3::deserialize_json("[1.0,2.0,3.0,4.0,5.0]");
+            assert!(deserialized.is_err());
+            let deserialized =
+                $affine3::deserialize_json("[[1.0,2.0,3.0],[4.0,5.0,6.0],[7.0,8.0,9.0]]");
+            assert!(deserialized.is_err());
+            let deserialized = $affine3::deserialize_json(
+                "[[1.0,2.0,3.0,4.0],[5.0,6.0,7.0,8.0],[9.0,10.0,11.0,12.0][13.0,14.0,15.0,16.0]]",
+            );
+            assert!(deserialized.is_err());
+            let deserialized = $affine3::deserialize_json("{}");
+            assert!(deserialized.is_err());
+        }
+
+        #[test]
+        fn $bin_testname() {
+            let a = $affine3::from_cols_array(&[
+                1.0, 0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 3.0, 4.0, 5.0, 6.0,
+            ]);
+            let serialized = a.serialize_bin();
+            let mut expected = Vec::new();
+            for v in a.to_cols_array() {
+                v.ser_bin(&mut expected);
             }
+            assert_eq!(expected, serialized);
+            let deserialized = $affine3::deserialize_bin(&serialized).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $affine3::deserialize_bin(&serialized[..serialized.len() - 1]);
+            assert!(deserialized.is_err());
+            let deserialized = $affine3::deserialize_bin(&[]);
+            assert!(deserialized.is_err());
         }
 
         #[test]
-        fn test_mat4_serde() {
-            let a = $mat4::from_cols_array(&[
-                1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0,
-                16.0,
+        fn $ron_testname() {
+            let a = $affine3::from_cols_array(&[
+                1.0, 0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 3.0, 4.0, 5.0, 6.0,
             ]);
-            let serialized = a.serialize_json();
+            let serialized = a.serialize_ron();
             assert_eq!(
                 serialized,
-                "[1.0,2.0,3.0,4.0,5.0,6.0,7.0,8.0,9.0,10.0,11.0,12.0,13.0,14.0,15.0,16.0]"
+                "(1.0, 0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 3.0, 4.0, 5.0, 6.0)"
             );
-            let deserialized = $mat4::deserialize_json(&serialized).unwrap();
+            let deserialized = $affine3::deserialize_ron(&serialized).unwrap();
             assert_eq!(a, deserialized);
-            let deserialized = $mat4::deserialize_json("[]");
-            assert!(deserialized.is_err());
-            let deserialized = $mat4::deserialize_json("[1.0]");
-            assert!(deserialized.is_err());
-            let deserialized = $mat4::deserialize_json("[1.0,2.0]");
+            let deserialized =
+                $affine3::deserialize_ron("[1.0,0.0,0.0,0.0,2.0,0.0,0.0,0.0,3.0,4.0,5.0,6.0]")
+                    .unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $affine3::deserialize_ron("()");
             assert!(deserialized.is_err());
-            let deserialized = $mat4::deserialize_json("[1.0,2.0,3.0]");
+            let deserialized = $affine3::deserialize_ron("(1.0)");
             assert!(deserialized.is_err());
-            let deserialized = $mat4::deserialize_json("[1.0,2.0,3.0,4.0,5.0]");
+            let deserialized = $affine3::deserialize_ron(
+                "(1.0, 0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 3.0, 4.0, 5.0)",
+            );
             assert!(deserialized.is_err());
-            let deserialized =
-                $mat4::deserialize_json("[[1.0,2.0,3.0],[4.0,5.0,6.0],[7.0,8.0,9.0]]");
+            let deserialized = $affine3::deserialize_ron(
+                "(1.0, 0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 3.0, 4.0, 5.0, 6.0, 13.0)",
+            );
             assert!(deserialized.is_err());
-            let deserialized = $mat4::deserialize_json(
-                "[[1.0,2.0,3.0,4.0],[5.0,6.0,7.0,8.0],[9.0,10.0,11.0,12.0][13.0,14.0,15.0,16.0]]",
+            let deserialized = $affine3::deserialize_ron(
+                "((1.0, 0.0, 0.0), (0.0, 2.0, 0.0), (0.0, 0.0, 3.0), (4.0, 5.0, 6.0))",
             );
             assert!(deserialized.is_err());
-            let deserialized = $mat4::deserialize_json("{}");
+            let deserialized = $affine3::deserialize_ron("{}");
             assert!(deserialized.is_err());
         }
     };
 }
 
-macro_rules! impl_serde_affine2 {
-    ($t:ty, $affine2:ident) => {
-        impl nanoserde::DeJson for $affine2 {
-            fn de_json(
+macro_rules! impl_serde_mat_nonsquare {
+    (
+        $testname:ident,
+        $bin_testname:ident,
+        $ron_testname:ident,
+        $t:ty,
+        $mat:ident,
+        $c:literal,
+        $r:literal,
+        $sx:literal,
+        $named_sx:literal,
+        $ron_sx:literal
+    ) => {
+        impl_de_with!($mat);
+
+        impl super::DeJsonWith for $mat {
+            fn de_json_with(
                 s: &mut nanoserde::DeJsonState,
                 i: &mut core::str::Chars,
+                policy: super::NanoserdePolicy,
             ) -> Result<Self, nanoserde::DeJsonErr> {
-                Ok({
-                    s.block_open(i)?;
-                    let mut vals: [$t; 6] = [0.0; 6];
-                    for idx in 0..6 {
-                        vals[idx] = nanoserde::DeJson::de_json(s, i)?;
-                        if idx != 5 {
-                            s.eat_comma_block(i)?;
-                        }
-                    }
-                    $affine2::from_cols_array(&vals)
-                })
+                let cols: [[$t; $r]; $c] =
+                    super::de_json_matrix(s, i, format_args!(stringify!($mat)), policy)?;
+                Ok($mat::from_cols_array_2d(&cols))
             }
         }
 
-        impl nanoserde::SerJson for $affine2 {
+        impl nanoserde::SerJson for $mat {
             fn ser_json(&self, d: usize, s: &mut nanoserde::SerJsonState) {
-                let cols: [$t; 6] = self.to_cols_array();
+                let vals: &[$t; $c * $r] = self.as_ref();
                 s.out.push('[');
-                for i in 0..6 {
-                    cols[i].ser_json(d, s);
-                    if i != 5 {
+                for (i, val) in vals.iter().enumerate() {
+                    if i != 0 {
                         s.out.push(',');
                     }
+                    val.ser_json(d, s);
                 }
                 s.out.push(']');
             }
         }
 
+        impl super::SerJsonNamed for $mat {
+            fn ser_json_named(&self, d: usize, s: &mut nanoserde::SerJsonState) {
+                let cols: [_; $c] = core::array::from_fn(|c| self.col(c));
+                super::ser_json_object(&super::AXES[..$c], &cols, d, s);
+            }
+        }
+
+        impl nanoserde::DeBin for $mat {
+            fn de_bin(o: &mut usize, d: &[u8]) -> Result<Self, nanoserde::DeBinErr> {
+                let mut vals: [$t; $c * $r] = [0.0; $c * $r];
+                for val in vals.iter_mut() {
+                    *val = nanoserde::DeBin::de_bin(o, d)?;
+                }
+                Ok($mat::from_cols_array(&vals))
+            }
+        }
+
+        impl nanoserde::SerBin for $mat {
+            fn ser_bin(&self, s: &mut Vec<u8>) {
+                for val in self.to_cols_array() {
+                    val.ser_bin(s);
+                }
+            }
+        }
+
+        impl super::DeRonWith for $mat {
+            fn de_ron_with(
+                s: &mut nanoserde::DeRonState,
+                i: &mut core::str::Chars,
+                policy: super::NanoserdePolicy,
+            ) -> Result<Self, nanoserde::DeRonErr> {
+                let vals: [$t; $c * $r] =
+                    super::de_ron_tuple(s, i, format_args!(stringify!($mat)), policy)?;
+                Ok($mat::from_cols_array(&vals))
+            }
+        }
+
+        impl nanoserde::SerRon for $mat {
+            fn ser_ron(&self, d: usize, s: &mut nanoserde::SerRonState) {
+                super::ser_ron_tuple(&self.to_cols_array(), d, s);
+            }
+        }
+
         #[test]
-        fn test_affine2_serde() {
-            let a = $affine2::from_cols_array(&[1.0, 0.0, 2.0, 0.0, 3.0, 4.0]);
+        fn $testname() {
+            let mut f = [0.0; $c * $r];
+            for (i, v) in f.iter_mut().enumerate() {
+                *v = (i + 1) as $t;
+            }
+            let a = $mat::from_cols_array(&f);
             let serialized = a.serialize_json();
-            assert_eq!(serialized, "[1.0,0.0,2.0,0.0,3.0,4.0]");
-            let deserialized = $affine2::deserialize_json(&serialized).unwrap();
+            assert_eq!(serialized, $sx);
+            assert_eq!(a.serialize_json_named(), $named_sx);
+            let deserialized = $mat::deserialize_json(&serialized).unwrap();
             assert_eq!(a, deserialized);
-
-            let deserialized = $affine2::deserialize_json("[]");
+            let deserialized = $mat::deserialize_json($sx).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $mat::deserialize_json($named_sx).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $mat::deserialize_json("[]");
             assert!(deserialized.is_err());
-            let deserialized = $affine2::deserialize_json("[1.0]");
+            let deserialized = $mat::deserialize_json("[1.0,2.0,3.0,4.0,5.0]");
             assert!(deserialized.is_err());
-            let deserialized = $affine2::deserialize_json("[1.0,2.0]");
+            let deserialized = $mat::deserialize_json(&$sx.replace("]", ",0.0]"));
             assert!(deserialized.is_err());
-            let deserialized = $affine2::deserialize_json("[1.0,2.0,3.0]");
+            let deserialized = $mat::deserialize_json("[[1.0,2.0,3.0],[4.0,5.0,6.0]]");
             assert!(deserialized.is_err());
-            let deserialized = $affine2::deserialize_json("[1.0,2.0,3.0,4.0,5.0]");
+            let deserialized = $mat::deserialize_json("{}");
             assert!(deserialized.is_err());
-            let deserialized = $affine2::deserialize_json("[[1.0,2.0],[3.0,4.0],[5.0,6.0]]");
+        }
+
+        #[test]
+        fn $bin_testname() {
+            let mut f = [0.0; $c * $r];
+            for (i, v) in f.iter_mut().enumerate() {
+                *v = (i + 1) as $t;
+            }
+            let a = $mat::from_cols_array(&f);
+            let serialized = a.serialize_bin();
+            let mut expected = Vec::new();
+            for v in a.to_cols_array() {
+                v.ser_bin(&mut expected);
+            }
+            assert_eq!(expected, serialized);
+            let deserialized = $mat::deserialize_bin(&serialized).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $mat::deserialize_bin(&serialized[..serialized.len() - 1]);
             assert!(deserialized.is_err());
-            let deserialized = $affine2::deserialize_json(
-                "[[1.0,2.0,3.0,4.0],[5.0,6.0,7.0,8.0],[9.0,10.0,11.0,12.0][13.0,14.0,15.0,16.0]]",
-            );
+            let deserialized = $mat::deserialize_bin(&[]);
             assert!(deserialized.is_err());
-            let deserialized = $affine2::deserialize_json("{}");
+        }
+
+        #[test]
+        fn $ron_testname() {
+            let mut f = [0.0; $c * $r];
+            for (i, v) in f.iter_mut().enumerate() {
+                *v = (i + 1) as $t;
+            }
+            let a = $mat::from_cols_array(&f);
+            let serialized = a.serialize_ron();
+            assert_eq!(serialized, $ron_sx);
+            let deserialized = $mat::deserialize_ron(&serialized).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $mat::deserialize_ron($sx).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $mat::deserialize_ron("()");
+            assert!(deserialized.is_err());
+            let deserialized = $mat::deserialize_ron("(1.0, 2.0, 3.0, 4.0, 5.0)");
+            assert!(deserialized.is_err());
+            let deserialized = $mat::deserialize_ron(&$ron_sx.replace(")", ", 0.0)"));
+            assert!(deserialized.is_err());
+            let deserialized = $mat::deserialize_ron("((1.0, 2.0, 3.0), (4.0, 5.0, 6.0))");
+            assert!(deserialized.is_err());
+            let deserialized = $mat::deserialize_ron("{}");
             assert!(deserialized.is_err());
         }
     };
 }
 
-macro_rules! impl_serde_affine3 {
-    ($testname:ident, $t:ty, $affine3:ident) => {
-        /// Serialize as a sequence of 12 values.
-        impl nanoserde::DeJson for $affine3 {
-            fn de_json(
+macro_rules! impl_serde_bool_mask {
+    ($bvec:ident, $n:literal, $test_name:ident, $bin_test_name:ident, $ron_test_name:ident) => {
+        impl_de_with!($bvec);
+
+        impl super::DeJsonWith for $bvec {
+            fn de_json_with(
                 s: &mut nanoserde::DeJsonState,
                 i: &mut core::str::Chars,
+                policy: super::NanoserdePolicy,
             ) -> Result<Self, nanoserde::DeJsonErr> {
-                Ok({
-                    s.block_open(i)?;
-                    let mut vals: [$t; 12] = [0.0; 12];
-                    for idx in 0..12 {
-                        vals[idx] = nanoserde::DeJson::de_json(s, i)?;
-                        if idx != 11 {
-                            s.eat_comma_block(i)?;
-                        }
-                    }
-                    $affine3::from_cols_array(&vals)
-                })
+                let vals: [bool; $n] =
+                    super::de_json_vector(s, i, format_args!(stringify!($bvec)), policy)?;
+                Ok($bvec::from_array(vals))
             }
         }
 
-        impl nanoserde::SerJson for $affine3 {
+        impl nanoserde::SerJson for $bvec {
             fn ser_json(&self, d: usize, s: &mut nanoserde::SerJsonState) {
-                let cols: [$t; 12] = self.to_cols_array();
+                let vals: [bool; $n] = (*self).into();
                 s.out.push('[');
-                for i in 0..12 {
-                    cols[i].ser_json(d, s);
-                    if i != 11 {
+                for (idx, val) in vals.iter().enumerate() {
+                    if idx != 0 {
                         s.out.push(',');
                     }
+                    val.ser_json(d, s);
                 }
                 s.out.push(']');
             }
         }
 
+        impl super::SerJsonNamed for $bvec {
+            fn ser_json_named(&self, d: usize, s: &mut nanoserde::SerJsonState) {
+                let vals: [bool; $n] = (*self).into();
+                super::ser_json_object(&["x", "y", "z", "w"][..$n], &vals, d, s);
+            }
+        }
+
+        impl nanoserde::DeBin for $bvec {
+            fn de_bin(o: &mut usize, d: &[u8]) -> Result<Self, nanoserde::DeBinErr> {
+                let mut mask = $bvec::FALSE;
+                for idx in 0..$n {
+                    mask.set(idx, nanoserde::DeBin::de_bin(o, d)?);
+                }
+                Ok(mask)
+            }
+        }
+
+        impl nanoserde::SerBin for $bvec {
+            fn ser_bin(&self, s: &mut Vec<u8>) {
+                let vals: [bool; $n] = (*self).into();
+                for val in vals {
+                    val.ser_bin(s);
+                }
+            }
+        }
+
+        impl super::DeRonWith for $bvec {
+            fn de_ron_with(
+                s: &mut nanoserde::DeRonState,
+                i: &mut core::str::Chars,
+                policy: super::NanoserdePolicy,
+            ) -> Result<Self, nanoserde::DeRonErr> {
+                let vals: [bool; $n] =
+                    super::de_ron_tuple(s, i, format_args!(stringify!($bvec)), policy)?;
+                Ok($bvec::from_array(vals))
+            }
+        }
+
+        impl nanoserde::SerRon for $bvec {
+            fn ser_ron(&self, d: usize, s: &mut nanoserde::SerRonState) {
+                let vals: [bool; $n] = (*self).into();
+                super::ser_ron_tuple(&vals, d, s);
+            }
+        }
+
         #[test]
-        fn $testname() {
-            let a = $affine3::from_cols_array(&[
-                1.0, 0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 3.0, 4.0, 5.0, 6.0,
-            ]);
+        fn $test_name() {
+            let a = $bvec::TRUE;
             let serialized = a.serialize_json();
-            assert_eq!(
-                serialized,
-                "[1.0,0.0,0.0,0.0,2.0,0.0,0.0,0.0,3.0,4.0,5.0,6.0]"
-            );
-            let deserialized = $affine3::deserialize_json(&serialized).unwrap();
+            assert_eq!(SX[$n], serialized);
+            assert_eq!(ST[$n], a.serialize_json_named());
+            let deserialized = $bvec::deserialize_json(&serialized).unwrap();
             assert_eq!(a, deserialized);
-
-            let deserialized = $affine3::deserialize_json("[]");
+            let deserialized = $bvec::deserialize_json(SX[$n]).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $bvec::deserialize_json(ST[$n]).unwrap();
+            assert_eq!(a, deserialized);
+            let b = $bvec::from_array(core::array::from_fn(|idx| idx % 2 == 1));
+            let deserialized = $bvec::deserialize_json(&b.serialize_json()).unwrap();
+            assert_eq!(b, deserialized);
+            let deserialized = $bvec::deserialize_json(SX[$n - 1]);
             assert!(deserialized.is_err());
-            let deserialized = $affine3::deserialize_json("[1.0]");
+            let deserialized = $bvec::deserialize_json(SX[$n + 1]);
             assert!(deserialized.is_err());
-            let deserialized = $affine3::deserialize_json("[1.0,2.0]");
+            let deserialized = $bvec::deserialize_json(ST[$n - 1]);
             assert!(deserialized.is_err());
-            let deserialized = $affine3::deserialize_json("[1.0,2.0,3.0]");
+            let deserialized = $bvec::deserialize_json(ST[$n + 1]);
             assert!(deserialized.is_err());
-            let deserialized = $affine3::deserialize_json("[1.0,2.0,3.0,4.0,5.0]");
+            let deserialized = $bvec::deserialize_json(SX[0]);
             assert!(deserialized.is_err());
-            let deserialized =
-                $affine3::deserialize_json("[[1.0,2.0,3.0],[4.0,5.0,6.0],[7.0,8.0,9.0]]");
+            let deserialized = $bvec::deserialize_json(ST[0]);
             assert!(deserialized.is_err());
-            let deserialized = $affine3::deserialize_json(
-                "[[1.0,2.0,3.0,4.0],[5.0,6.0,7.0,8.0],[9.0,10.0,11.0,12.0][13.0,14.0,15.0,16.0]]",
-            );
+        }
+
+        #[test]
+        fn $bin_test_name() {
+            let a = $bvec::from_array(core::array::from_fn(|idx| idx % 2 == 1));
+            let serialized = a.serialize_bin();
+            let expected: [u8; $n] = core::array::from_fn(|idx| (idx % 2) as u8);
+            assert_eq!(&expected[..], &serialized[..]);
+            let deserialized = $bvec::deserialize_bin(&serialized).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $bvec::deserialize_bin(&serialized[..serialized.len() - 1]);
             assert!(deserialized.is_err());
-            let deserialized = $affine3::deserialize_json("{}");
+            let deserialized = $bvec::deserialize_bin(&[]);
+            assert!(deserialized.is_err());
+        }
+
+        #[test]
+        fn $ron_test_name() {
+            let a = $bvec::TRUE;
+            let serialized = a.serialize_ron();
+            assert_eq!(SR[$n], serialized);
+            let deserialized = $bvec::deserialize_ron(&serialized).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = $bvec::deserialize_ron(SX[$n]).unwrap();
+            assert_eq!(a, deserialized);
+            let b = $bvec::from_array(core::array::from_fn(|idx| idx % 2 == 1));
+            let deserialized = $bvec::deserialize_ron(&b.serialize_ron()).unwrap();
+            assert_eq!(b, deserialized);
+            let deserialized = $bvec::deserialize_ron(SR[$n - 1]);
+            assert!(deserialized.is_err());
+            let deserialized = $bvec::deserialize_ron(SR[$n + 1]);
+            assert!(deserialized.is_err());
+            let deserialized = $bvec::deserialize_ron(ST[0]);
             assert!(deserialized.is_err());
         }
     };
@@ -576,11 +3153,17 @@ macro_rules! impl_serde_vec_types {
 macro_rules! impl_serde_float_types {
     ($t:ty, $affine2:ident, $affine3:ident, $mat2:ident, $mat3:ident, $mat4:ident, $quat:ident, $vec2:ident, $vec3:ident, $vec4:ident) => {
         impl_serde_affine2!($t, $affine2);
-        impl_serde_affine3!(test_affine3_serde, $t, $affine3);
+        impl_serde_affine3!(
+            test_affine3_serde,
+            test_affine3_serde_bin,
+            test_affine3_serde_ron,
+            $t,
+            $affine3
+        );
         impl_serde_mat2!($t, $mat2);
         impl_serde_mat3!($t, $mat3);
         impl_serde_mat4!($t, $mat4);
-        impl_serde_quat!($t, $quat);
+        impl_serde_quat!($t, $quat, $vec3);
         impl_serde_vec_types!($t, $vec2, $vec3, $vec4);
     };
 }
@@ -682,6 +3265,17 @@ mod test_float {
     pub const SX4: &str = "[1.0,2.0,3.0,4.0]";
     pub const SX5: &str = "[1.0,2.0,3.0,4.0,5.0]";
     pub const ST0: &str = "{}";
+    pub const ST1: &str = r#"{"x":1.0}"#;
+    pub const ST2: &str = r#"{"x":1.0,"y":2.0}"#;
+    pub const ST3: &str = r#"{"x":1.0,"y":2.0,"z":3.0}"#;
+    pub const ST4: &str = r#"{"x":1.0,"y":2.0,"z":3.0,"w":4.0}"#;
+    pub const ST5: &str = r#"{"x":1.0,"y":2.0,"z":3.0,"w":4.0,"v":5.0}"#;
+    pub const SR0: &str = "()";
+    pub const SR1: &str = "(1.0)";
+    pub const SR2: &str = "(1.0, 2.0)";
+    pub const SR3: &str = "(1.0, 2.0, 3.0)";
+    pub const SR4: &str = "(1.0, 2.0, 3.0, 4.0)";
+    pub const SR5: &str = "(1.0, 2.0, 3.0, 4.0, 5.0)";
 }
 
 #[cfg(test)]
@@ -693,21 +3287,45 @@ mod test_int {
     pub const SX4: &str = "[1,2,3,4]";
     pub const SX5: &str = "[1,2,3,4,5]";
     pub const ST0: &str = "{}";
+    pub const ST1: &str = r#"{"x":1}"#;
+    pub const ST2: &str = r#"{"x":1,"y":2}"#;
+    pub const ST3: &str = r#"{"x":1,"y":2,"z":3}"#;
+    pub const ST4: &str = r#"{"x":1,"y":2,"z":3,"w":4}"#;
+    pub const ST5: &str = r#"{"x":1,"y":2,"z":3,"w":4,"v":5}"#;
+    pub const SR0: &str = "()";
+    pub const SR1: &str = "(1)";
+    pub const SR2: &str = "(1, 2)";
+    pub const SR3: &str = "(1, 2, 3)";
+    pub const SR4: &str = "(1, 2, 3, 4)";
+    pub const SR5: &str = "(1, 2, 3, 4, 5)";
 }
 
 #[cfg(test)]
 mod test_bool_mask {
-    pub const SX0: &str = "[]";
-    pub const SX1: &str = "[true]";
-    pub const SX2: &str = "[true,true]";
-    pub const SX3: &str = "[true,true,true]";
-    pub const SX4: &str = "[true,true,true,true]";
-    pub const SX5: &str = "[true,true,true,true,true]";
-    pub const ST0: &str = "{}";
-    pub const V1: bool = true;
-    pub const V2: bool = true;
-    pub const V3: bool = true;
-    pub const V4: bool = true;
+    pub const SX: [&str; 6] = [
+        "[]",
+        "[true]",
+        "[true,true]",
+        "[true,true,true]",
+        "[true,true,true,true]",
+        "[true,true,true,true,true]",
+    ];
+    pub const ST: [&str; 6] = [
+        "{}",
+        r#"{"x":true}"#,
+        r#"{"x":true,"y":true}"#,
+        r#"{"x":true,"y":true,"z":true}"#,
+        r#"{"x":true,"y":true,"z":true,"w":true}"#,
+        r#"{"x":true,"y":true,"z":true,"w":true,"v":true}"#,
+    ];
+    pub const SR: [&str; 6] = [
+        "()",
+        "(true)",
+        "(true, true)",
+        "(true, true, true)",
+        "(true, true, true, true)",
+        "(true, true, true, true, true)",
+    ];
 }
 
 mod f32 {
@@ -715,16 +3333,339 @@ mod f32 {
     use super::test_f32::*;
     #[cfg(test)]
     use super::test_float::*;
+    #[cfg(test)]
+    use super::SerJsonNamed;
     use crate::{
-        Affine2, Affine3, Affine3A, Mat2, Mat3, Mat3A, Mat4, Quat, Vec2, Vec3, Vec3A, Vec4,
+        Affine2, Affine3, Affine3A, DualQuat, Isometry2, Isometry3A, Mat2, Mat2x3, Mat3, Mat3A,
+        Mat3x2, Mat3x4, Mat4, Mat4x3, Quat, Rot2, Transform2, Transform3, Vec2, Vec3, Vec3A, Vec4,
     };
-    use core::fmt;
-    use nanoserde::{DeJson, SerJson};
+    use alloc::vec::Vec;
+    #[cfg(test)]
+    use nanoserde::{DeBin, DeJson, DeRon, SerBin, SerJson, SerRon};
 
     impl_serde_float_types!(f32, Affine2, Affine3, Mat2, Mat3, Mat4, Quat, Vec2, Vec3, Vec4);
-    impl_serde_affine3!(test_affine3a_serde, f32, Affine3A);
-    impl_serde_mat3!(f32, Mat3A, test_mat3a_serde);
-    impl_serde_vec3!(f32, Vec3A, test_vec3a_serde);
+    impl_serde_affine3!(
+        test_affine3a_serde,
+        test_affine3a_serde_bin,
+        test_affine3a_serde_ron,
+        f32,
+        Affine3A
+    );
+    impl_serde_mat3!(
+        f32,
+        Mat3A,
+        test_mat3a_serde,
+        test_mat3a_serde_bin,
+        test_mat3a_serde_ron
+    );
+    impl_serde_vec3!(
+        f32,
+        Vec3A,
+        test_vec3a_serde,
+        test_vec3a_serde_bin,
+        test_vec3a_serde_ron
+    );
+    impl_serde_dualquat!(f32, DualQuat);
+    impl_serde_rot2!(f32, Rot2);
+    impl_serde_isometry!(
+        test_isometry2_serde,
+        test_isometry2_serde_bin,
+        test_isometry2_serde_ron,
+        f32,
+        Isometry2,
+        6,
+        mat2,
+        "[1.0,2.0,3.0,4.0,5.0,6.0]",
+        r#"{"rotation":{"x_axis":{"x":1.0,"y":2.0},"y_axis":{"x":3.0,"y":4.0}},"translation":{"x":5.0,"y":6.0}}"#,
+        "(1.0, 2.0, 3.0, 4.0, 5.0, 6.0)"
+    );
+    impl_serde_isometry!(
+        test_isometry3a_serde,
+        test_isometry3a_serde_bin,
+        test_isometry3a_serde_ron,
+        f32,
+        Isometry3A,
+        7,
+        quat,
+        "[1.0,2.0,3.0,4.0,5.0,6.0,7.0]",
+        r#"{"rotation":{"x":1.0,"y":2.0,"z":3.0,"w":4.0},"translation":{"x":5.0,"y":6.0,"z":7.0}}"#,
+        "(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0)"
+    );
+    impl_serde_transform!(
+        test_transform2_serde,
+        test_transform2_serde_bin,
+        test_transform2_serde_ron,
+        f32,
+        Transform2,
+        2,
+        angle,
+        1,
+        "[1.0,2.0,3.0,4.0,5.0]",
+        r#"{"scale":{"x":1.0,"y":2.0},"angle":3.0,"translation":{"x":4.0,"y":5.0}}"#,
+        "(1.0, 2.0, 3.0, 4.0, 5.0)"
+    );
+    impl_serde_transform!(
+        test_transform3_serde,
+        test_transform3_serde_bin,
+        test_transform3_serde_ron,
+        f32,
+        Transform3,
+        3,
+        rotation,
+        4,
+        "[1.0,2.0,3.0,4.0,5.0,6.0,7.0,8.0,9.0,10.0]",
+        r#"{"scale":{"x":1.0,"y":2.0,"z":3.0},"rotation":{"x":4.0,"y":5.0,"z":6.0,"w":7.0},"translation":{"x":8.0,"y":9.0,"z":10.0}}"#,
+        "(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0)"
+    );
+    impl_serde_mat_nonsquare!(
+        test_mat2x3_serde,
+        test_mat2x3_serde_bin,
+        test_mat2x3_serde_ron,
+        f32,
+        Mat2x3,
+        2,
+        3,
+        "[1.0,2.0,3.0,4.0,5.0,6.0]",
+        r#"{"x_axis":{"x":1.0,"y":2.0,"z":3.0},"y_axis":{"x":4.0,"y":5.0,"z":6.0}}"#,
+        "(1.0, 2.0, 3.0, 4.0, 5.0, 6.0)"
+    );
+    impl_serde_mat_nonsquare!(
+        test_mat3x2_serde,
+        test_mat3x2_serde_bin,
+        test_mat3x2_serde_ron,
+        f32,
+        Mat3x2,
+        3,
+        2,
+        "[1.0,2.0,3.0,4.0,5.0,6.0]",
+        r#"{"x_axis":{"x":1.0,"y":2.0},"y_axis":{"x":3.0,"y":4.0},"z_axis":{"x":5.0,"y":6.0}}"#,
+        "(1.0, 2.0, 3.0, 4.0, 5.0, 6.0)"
+    );
+    impl_serde_mat_nonsquare!(
+        test_mat3x4_serde,
+        test_mat3x4_serde_bin,
+        test_mat3x4_serde_ron,
+        f32,
+        Mat3x4,
+        3,
+        4,
+        "[1.0,2.0,3.0,4.0,5.0,6.0,7.0,8.0,9.0,10.0,11.0,12.0]",
+        r#"{"x_axis":{"x":1.0,"y":2.0,"z":3.0,"w":4.0},"y_axis":{"x":5.0,"y":6.0,"z":7.0,"w":8.0},"z_axis":{"x":9.0,"y":10.0,"z":11.0,"w":12.0}}"#,
+        "(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0)"
+    );
+    impl_serde_mat_nonsquare!(
+        test_mat4x3_serde,
+        test_mat4x3_serde_bin,
+        test_mat4x3_serde_ron,
+        f32,
+        Mat4x3,
+        4,
+        3,
+        "[1.0,2.0,3.0,4.0,5.0,6.0,7.0,8.0,9.0,10.0,11.0,12.0]",
+        r#"{"x_axis":{"x":1.0,"y":2.0,"z":3.0},"y_axis":{"x":4.0,"y":5.0,"z":6.0},"z_axis":{"x":7.0,"y":8.0,"z":9.0},"w_axis":{"x":10.0,"y":11.0,"z":12.0}}"#,
+        "(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0)"
+    );
+
+    #[cfg(test)]
+    fn json_err<T: DeJson + core::fmt::Debug>(input: &str) -> alloc::string::String {
+        alloc::format!("{:?}", T::deserialize_json(input).unwrap_err().msg)
+    }
+
+    #[cfg(test)]
+    fn ron_err<T: DeRon + core::fmt::Debug>(input: &str) -> alloc::string::String {
+        alloc::format!("{:?}", T::deserialize_ron(input).unwrap_err().msg)
+    }
+
+    #[test]
+    fn test_serde_error_messages() {
+        let msg = json_err::<Vec3>(r#"[1.0,"a",3.0]"#);
+        assert!(
+            msg.starts_with("Cannot parse Vec3[1], expected f32"),
+            "{}",
+            msg
+        );
+        let msg = json_err::<Vec3>(r#"{"x":1.0,"y":true,"z":3.0}"#);
+        assert!(
+            msg.starts_with("Cannot parse Vec3.y, expected f32"),
+            "{}",
+            msg
+        );
+        let msg = json_err::<Mat3>(r#"{"x_axis":[1,0,0],"y_axis":[0,1,false],"z_axis":[0,0,1]}"#);
+        assert!(
+            msg.starts_with("Cannot parse Mat3.y_axis[2], expected f32"),
+            "{}",
+            msg
+        );
+        let msg = json_err::<Mat2>("[1.0,0.0,null,1.0]");
+        assert!(
+            msg.starts_with("Cannot parse Mat2[2], expected f32"),
+            "{}",
+            msg
+        );
+        let msg = json_err::<Affine3A>(
+            r#"{"matrix3":[1,0,0,0,1,0,0,0,1],"translation":{"x":1,"y":2,"z":[]}}"#,
+        );
+        assert!(
+            msg.starts_with("Cannot parse Affine3A.translation.z, expected f32"),
+            "{}",
+            msg
+        );
+        let msg = json_err::<Isometry3A>(r#"{"rotation":[0,0,0,1],"translation":[1.0,"a",3.0]}"#);
+        assert!(
+            msg.starts_with("Cannot parse Isometry3A.translation[1], expected f32"),
+            "{}",
+            msg
+        );
+        let msg = json_err::<Isometry2>(r#"{"rotation":{"x_axis":[1,0],"y_axis":[0,null]}}"#);
+        assert!(
+            msg.starts_with("Cannot parse Isometry2.rotation.y_axis[1], expected f32"),
+            "{}",
+            msg
+        );
+        let msg = json_err::<Rot2>(r#"{"angle":[0.5]}"#);
+        assert!(
+            msg.starts_with("Cannot parse Rot2.angle, expected f32"),
+            "{}",
+            msg
+        );
+        let msg = json_err::<Transform2>(r#"{"scale":[1,1],"angle":"x"}"#);
+        assert!(
+            msg.starts_with("Cannot parse Transform2.angle, expected f32"),
+            "{}",
+            msg
+        );
+        let msg = json_err::<Transform3>(r#"{"rotation":{"x":0,"y":0,"z":0,"w":null}}"#);
+        assert!(
+            msg.starts_with("Cannot parse Transform3.rotation.w, expected f32"),
+            "{}",
+            msg
+        );
+        let msg = json_err::<Quat>(r#"{"euler":"XYZ","deg":[0.0,"x",0.0]}"#);
+        assert!(
+            msg.starts_with("Cannot parse Quat.deg[1], expected f32"),
+            "{}",
+            msg
+        );
+        let msg = json_err::<crate::IVec2>("[1,2.5]");
+        assert!(
+            msg.starts_with("Cannot parse IVec2[1], expected i32"),
+            "{}",
+            msg
+        );
+        let msg = json_err::<crate::BVec2>("[true,1]");
+        assert!(
+            msg.starts_with("Cannot parse BVec2[1], expected bool"),
+            "{}",
+            msg
+        );
+        let msg = ron_err::<Vec3>(r#"(1.0, "a", 3.0)"#);
+        assert!(
+            msg.starts_with("Cannot parse Vec3[1], expected f32"),
+            "{}",
+            msg
+        );
+    }
+
+    #[test]
+    fn test_serde_numeric_policy() {
+        use crate::{DeJsonWith, DeRonWith, Finite, Lenient, NanoserdePolicy};
+
+        fn json_msg(v: Result<Vec3, nanoserde::DeJsonErr>) -> alloc::string::String {
+            alloc::format!("{:?}", v.unwrap_err().msg)
+        }
+
+        // integers are accepted for float components unless the policy rejects them
+        let v = Vec3::deserialize_json("[1,-2,3]").unwrap();
+        assert_eq!(Vec3::new(1.0, -2.0, 3.0), v);
+        let v = Vec3::deserialize_ron("(1, -2, 3)").unwrap();
+        assert_eq!(Vec3::new(1.0, -2.0, 3.0), v);
+        let strict = NanoserdePolicy {
+            integers: false,
+            ..NanoserdePolicy::DEFAULT
+        };
+        let v = Vec3::deserialize_json_with("[1.0,-2,3.0]", strict);
+        assert!(json_msg(v).starts_with("Cannot parse Vec3[1], expected f32, found I64(-2)"));
+        assert!(Vec3::deserialize_ron_with("(1.0, 2, 3.0)", strict).is_err());
+        assert!(crate::IVec3::deserialize_json_with("[1,-2,3]", strict).is_ok());
+
+        let v = Vec3::deserialize_json("[1e39,0.0,0.0]");
+        assert_eq!(f32::INFINITY, v.unwrap().x);
+        let v = Vec3::deserialize_json_with("[1e39,0.0,0.0]", NanoserdePolicy::FINITE);
+        assert!(json_msg(v).starts_with("Cannot parse Vec3[0], expected finite f32, found inf"));
+        let v = Mat2::deserialize_json_with(
+            r#"{"x_axis":[1.0,2.0],"y_axis":[3.0,1e39]}"#,
+            NanoserdePolicy::FINITE,
+        );
+        assert!(v.is_err());
+
+        let v = Vec3::deserialize_json(r#"["1.5",2.0,"-3"]"#);
+        assert!(v.is_err());
+        let v = Vec3::deserialize_json_with(r#"["1.5",2.0,"-3"]"#, NanoserdePolicy::LENIENT);
+        assert_eq!(Vec3::new(1.5, 2.0, -3.0), v.unwrap());
+
+        let v = Vec3::deserialize_json_with(r#"["NaN","inf","-inf"]"#, NanoserdePolicy::LENIENT)
+            .unwrap();
+        assert!(v.x.is_nan());
+        assert_eq!(f32::INFINITY, v.y);
+        assert_eq!(f32::NEG_INFINITY, v.z);
+        let both = NanoserdePolicy {
+            finite: true,
+            ..NanoserdePolicy::LENIENT
+        };
+        let v = Vec3::deserialize_json_with(r#"["NaN","inf","-inf"]"#, both);
+        assert!(json_msg(v).starts_with("Cannot parse Vec3[0], expected finite f32, found NaN"));
+
+        let v = Vec3::deserialize_ron(r#"(NaN, inf, "-inf")"#);
+        assert!(v.is_err());
+        let v = Vec3::deserialize_ron_with(r#"(NaN, inf, "-inf")"#, NanoserdePolicy::LENIENT);
+        assert!(!v.unwrap().is_finite());
+        let v = Vec3::deserialize_ron_with(r#"(NaN, inf, "-inf")"#, both);
+        assert!(v.is_err());
+
+        // strings are never accepted for integer components
+        let v = crate::IVec2::deserialize_json_with(r#"["1",2]"#, NanoserdePolicy::LENIENT);
+        assert!(v.is_err());
+
+        // the wrappers apply their policy to a single value
+        let v = Finite::<Vec3>::deserialize_json("[1.0,2.0,3.0]").unwrap();
+        assert_eq!(Vec3::new(1.0, 2.0, 3.0), v.0);
+        assert_eq!(v.0.serialize_json(), v.serialize_json());
+        assert!(Finite::<Quat>::deserialize_json("[0.0,0.0,0.0,1e39]").is_err());
+        assert!(Finite::<Vec3>::deserialize_ron("(1.0, 2.0, 1e39)").is_err());
+        let v = Lenient::<Vec2>::deserialize_json(r#"["inf",2]"#).unwrap();
+        assert_eq!(Vec2::new(f32::INFINITY, 2.0), v.0);
+        let v = Lenient::<Vec2>::deserialize_ron(r#"(NaN, "-inf")"#).unwrap();
+        assert!(v.0.x.is_nan());
+        assert_eq!(f32::NEG_INFINITY, v.0.y);
+
+        // the binary format is not affected by the policy
+        let v = Vec3::new(f32::NAN, 0.0, 0.0).serialize_bin();
+        assert!(Vec3::deserialize_bin(&v).unwrap().x.is_nan());
+    }
+
+    #[test]
+    fn test_serde_named() {
+        use crate::Named;
+
+        // the named form is opt in per value and does not change `SerJson`
+        let v = Vec2::new(1.0, 2.0);
+        assert_eq!("[1.0,2.0]", v.serialize_json());
+        assert_eq!(r#"{"x":1.0,"y":2.0}"#, v.serialize_json_named());
+        assert_eq!(v.serialize_json_named(), Named(&v).serialize_json());
+
+        let v = Named(Affine2::from_cols_array(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]));
+        let serialized = v.serialize_json();
+        assert_eq!(
+            r#"{"matrix2":{"x_axis":{"x":1.0,"y":2.0},"y_axis":{"x":3.0,"y":4.0}},"translation":{"x":5.0,"y":6.0}}"#,
+            serialized
+        );
+        assert_eq!(v, Named::<Affine2>::deserialize_json(&serialized).unwrap());
+        assert_eq!(
+            v,
+            Named::<Affine2>::deserialize_json(&v.0.serialize_json()).unwrap()
+        );
+        assert_eq!(v.0.serialize_ron(), v.serialize_ron());
+    }
 }
 
 mod f64 {
@@ -732,13 +3673,59 @@ mod f64 {
     use super::test_f64::*;
     #[cfg(test)]
     use super::test_float::*;
-    use crate::{DAffine2, DAffine3, DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4};
-    use core::fmt;
-    use nanoserde::{DeJson, SerJson};
+    #[cfg(test)]
+    use super::SerJsonNamed;
+    use crate::{
+        DAffine2, DAffine3, DDualQuat, DIsometry3, DMat2, DMat3, DMat4, DQuat, DRot2, DTransform2,
+        DTransform3, DVec2, DVec3, DVec4,
+    };
+    use alloc::vec::Vec;
+    #[cfg(test)]
+    use nanoserde::{DeBin, DeJson, DeRon, SerBin, SerJson, SerRon};
 
     impl_serde_float_types!(
         f64, DAffine2, DAffine3, DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4
     );
+    impl_serde_dualquat!(f64, DDualQuat);
+    impl_serde_rot2!(f64, DRot2);
+    impl_serde_isometry!(
+        test_disometry3_serde,
+        test_disometry3_serde_bin,
+        test_disometry3_serde_ron,
+        f64,
+        DIsometry3,
+        7,
+        quat,
+        "[1.0,2.0,3.0,4.0,5.0,6.0,7.0]",
+        r#"{"rotation":{"x":1.0,"y":2.0,"z":3.0,"w":4.0},"translation":{"x":5.0,"y":6.0,"z":7.0}}"#,
+        "(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0)"
+    );
+    impl_serde_transform!(
+        test_dtransform2_serde,
+        test_dtransform2_serde_bin,
+        test_dtransform2_serde_ron,
+        f64,
+        DTransform2,
+        2,
+        angle,
+        1,
+        "[1.0,2.0,3.0,4.0,5.0]",
+        r#"{"scale":{"x":1.0,"y":2.0},"angle":3.0,"translation":{"x":4.0,"y":5.0}}"#,
+        "(1.0, 2.0, 3.0, 4.0, 5.0)"
+    );
+    impl_serde_transform!(
+        test_dtransform3_serde,
+        test_dtransform3_serde_bin,
+        test_dtransform3_serde_ron,
+        f64,
+        DTransform3,
+        3,
+        rotation,
+        4,
+        "[1.0,2.0,3.0,4.0,5.0,6.0,7.0,8.0,9.0,10.0]",
+        r#"{"scale":{"x":1.0,"y":2.0,"z":3.0},"rotation":{"x":4.0,"y":5.0,"z":6.0,"w":7.0},"translation":{"x":8.0,"y":9.0,"z":10.0}}"#,
+        "(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0)"
+    );
 }
 
 mod i8 {
@@ -746,9 +3733,12 @@ mod i8 {
     use super::test_i8::*;
     #[cfg(test)]
     use super::test_int::*;
+    #[cfg(test)]
+    use super::SerJsonNamed;
     use crate::{I8Vec2, I8Vec3, I8Vec4};
-    use core::fmt;
-    use nanoserde::{DeJson, SerJson};
+    use alloc::vec::Vec;
+    #[cfg(test)]
+    use nanoserde::{DeBin, DeJson, DeRon, SerBin, SerJson, SerRon};
 
     impl_serde_vec_types!(i8, I8Vec2, I8Vec3, I8Vec4);
 }
@@ -758,9 +3748,12 @@ mod i16 {
     use super::test_i16::*;
     #[cfg(test)]
     use super::test_int::*;
+    #[cfg(test)]
+    use super::SerJsonNamed;
     use crate::{I16Vec2, I16Vec3, I16Vec4};
-    use core::fmt;
-    use nanoserde::{DeJson, SerJson};
+    use alloc::vec::Vec;
+    #[cfg(test)]
+    use nanoserde::{DeBin, DeJson, DeRon, SerBin, SerJson, SerRon};
 
     impl_serde_vec_types!(i16, I16Vec2, I16Vec3, I16Vec4);
 }
@@ -770,11 +3763,21 @@ mod i32 {
     use super::test_i32::*;
     #[cfg(test)]
     use super::test_int::*;
-    use crate::{IVec2, IVec3, IVec4};
-    use core::fmt;
-    use nanoserde::{DeJson, SerJson};
+    #[cfg(test)]
+    use super::SerJsonNamed;
+    use crate::{IVec2, IVec3, IVec3A, IVec4};
+    use alloc::vec::Vec;
+    #[cfg(test)]
+    use nanoserde::{DeBin, DeJson, DeRon, SerBin, SerJson, SerRon};
 
     impl_serde_vec_types!(i32, IVec2, IVec3, IVec4);
+    impl_serde_vec3!(
+        i32,
+        IVec3A,
+        test_ivec3a_serde,
+        test_ivec3a_serde_bin,
+        test_ivec3a_serde_ron
+    );
 }
 
 mod i64 {
@@ -782,9 +3785,12 @@ mod i64 {
     use super::test_i64::*;
     #[cfg(test)]
     use super::test_int::*;
+    #[cfg(test)]
+    use super::SerJsonNamed;
     use crate::{I64Vec2, I64Vec3, I64Vec4};
-    use core::fmt;
-    use nanoserde::{DeJson, SerJson};
+    use alloc::vec::Vec;
+    #[cfg(test)]
+    use nanoserde::{DeBin, DeJson, DeRon, SerBin, SerJson, SerRon};
 
     impl_serde_vec_types!(i64, I64Vec2, I64Vec3, I64Vec4);
 }
@@ -794,9 +3800,12 @@ mod u8 {
     use super::test_int::*;
     #[cfg(test)]
     use super::test_u8::*;
+    #[cfg(test)]
+    use super::SerJsonNamed;
     use crate::{U8Vec2, U8Vec3, U8Vec4};
-    use core::fmt;
-    use nanoserde::{DeJson, SerJson};
+    use alloc::vec::Vec;
+    #[cfg(test)]
+    use nanoserde::{DeBin, DeJson, DeRon, SerBin, SerJson, SerRon};
 
     impl_serde_vec_types!(u8, U8Vec2, U8Vec3, U8Vec4);
 }
@@ -806,9 +3815,12 @@ mod u16 {
     use super::test_int::*;
     #[cfg(test)]
     use super::test_u16::*;
+    #[cfg(test)]
+    use super::SerJsonNamed;
     use crate::{U16Vec2, U16Vec3, U16Vec4};
-    use core::fmt;
-    use nanoserde::{DeJson, SerJson};
+    use alloc::vec::Vec;
+    #[cfg(test)]
+    use nanoserde::{DeBin, DeJson, DeRon, SerBin, SerJson, SerRon};
 
     impl_serde_vec_types!(u16, U16Vec2, U16Vec3, U16Vec4);
 }
@@ -818,11 +3830,21 @@ mod u32 {
     use super::test_int::*;
     #[cfg(test)]
     use super::test_u32::*;
-    use crate::{UVec2, UVec3, UVec4};
-    use core::fmt;
-    use nanoserde::{DeJson, SerJson};
+    #[cfg(test)]
+    use super::SerJsonNamed;
+    use crate::{UVec2, UVec3, UVec3A, UVec4};
+    use alloc::vec::Vec;
+    #[cfg(test)]
+    use nanoserde::{DeBin, DeJson, DeRon, SerBin, SerJson, SerRon};
 
     impl_serde_vec_types!(u32, UVec2, UVec3, UVec4);
+    impl_serde_vec3!(
+        u32,
+        UVec3A,
+        test_uvec3a_serde,
+        test_uvec3a_serde_bin,
+        test_uvec3a_serde_ron
+    );
 }
 
 mod u64 {
@@ -830,9 +3852,12 @@ mod u64 {
     use super::test_int::*;
     #[cfg(test)]
     use super::test_u64::*;
+    #[cfg(test)]
+    use super::SerJsonNamed;
     use crate::{U64Vec2, U64Vec3, U64Vec4};
-    use core::fmt;
-    use nanoserde::{DeJson, SerJson};
+    use alloc::vec::Vec;
+    #[cfg(test)]
+    use nanoserde::{DeBin, DeJson, DeRon, SerBin, SerJson, SerRon};
 
     impl_serde_vec_types!(u64, U64Vec2, U64Vec3, U64Vec4);
 }
@@ -842,9 +3867,179 @@ mod usize {
     use super::test_int::*;
     #[cfg(test)]
     use super::test_usize::*;
+    #[cfg(test)]
+    use super::SerJsonNamed;
     use crate::{USizeVec2, USizeVec3, USizeVec4};
-    use core::fmt;
-    use nanoserde::{DeJson, SerJson};
+    use alloc::vec::Vec;
+    #[cfg(test)]
+    use nanoserde::{DeBin, DeJson, DeRon, SerBin, SerJson, SerRon};
 
     impl_serde_vec_types!(usize, USizeVec2, USizeVec3, USizeVec4);
 }
+
+mod bool {
+    #[cfg(test)]
+    use super::test_bool_mask::*;
+    #[cfg(test)]
+    use super::SerJsonNamed;
+    use crate::{BVec2, BVec3, BVec3A, BVec4, BVec4A};
+    use alloc::vec::Vec;
+    #[cfg(test)]
+    use nanoserde::{DeBin, DeJson, DeRon, SerBin, SerJson, SerRon};
+
+    impl_serde_bool_mask!(
+        BVec2,
+        2,
+        test_bvec2_serde,
+        test_bvec2_serde_bin,
+        test_bvec2_serde_ron
+    );
+    impl_serde_bool_mask!(
+        BVec3,
+        3,
+        test_bvec3_serde,
+        test_bvec3_serde_bin,
+        test_bvec3_serde_ron
+    );
+    impl_serde_bool_mask!(
+        BVec4,
+        4,
+        test_bvec4_serde,
+        test_bvec4_serde_bin,
+        test_bvec4_serde_ron
+    );
+    impl_serde_bool_mask!(
+        BVec3A,
+        3,
+        test_bvec3a_serde,
+        test_bvec3a_serde_bin,
+        test_bvec3a_serde_ron
+    );
+    impl_serde_bool_mask!(
+        BVec4A,
+        4,
+        test_bvec4a_serde,
+        test_bvec4a_serde_bin,
+        test_bvec4a_serde_ron
+    );
+}
+
+mod euler {
+    use super::EULER_ROT_NAMES;
+    use crate::EulerRot;
+    use alloc::vec::Vec;
+
+    fn euler_rot_name(euler: EulerRot) -> &'static str {
+        EULER_ROT_NAMES
+            .iter()
+            .find(|(e, _)| *e == euler)
+            .map(|(_, name)| *name)
+            .unwrap()
+    }
+
+    impl nanoserde::DeJson for EulerRot {
+        fn de_json(
+            s: &mut nanoserde::DeJsonState,
+            i: &mut core::str::Chars,
+        ) -> Result<Self, nanoserde::DeJsonErr> {
+            super::de_json_euler_rot(s, i)
+        }
+    }
+
+    impl nanoserde::SerJson for EulerRot {
+        fn ser_json(&self, _d: usize, s: &mut nanoserde::SerJsonState) {
+            s.label(euler_rot_name(*self));
+        }
+    }
+
+    /// Encoded as the `u16` index of the variant, matching `#[derive(SerBin)]` on enums.
+    impl nanoserde::DeBin for EulerRot {
+        fn de_bin(o: &mut usize, d: &[u8]) -> Result<Self, nanoserde::DeBinErr> {
+            let idx: u16 = nanoserde::DeBin::de_bin(o, d)?;
+            match EULER_ROT_NAMES.get(idx as usize) {
+                Some((euler, _)) => Ok(*euler),
+                None => Err(nanoserde::DeBinErr::new(*o, 0, d.len())),
+            }
+        }
+    }
+
+    impl nanoserde::SerBin for EulerRot {
+        fn ser_bin(&self, s: &mut Vec<u8>) {
+            let idx = EULER_ROT_NAMES.iter().position(|(e, _)| e == self).unwrap() as u16;
+            idx.ser_bin(s);
+        }
+    }
+
+    impl nanoserde::DeRon for EulerRot {
+        fn de_ron(
+            s: &mut nanoserde::DeRonState,
+            i: &mut core::str::Chars,
+        ) -> Result<Self, nanoserde::DeRonErr> {
+            if s.tok != nanoserde::DeRonTok::Ident {
+                return Err(s.err_token("EulerRot name"));
+            }
+            match EULER_ROT_NAMES.iter().find(|(_, name)| *name == s.identbuf) {
+                Some((euler, _)) => {
+                    s.next_tok(i)?;
+                    Ok(*euler)
+                }
+                None => Err(s.err_enum(&s.identbuf)),
+            }
+        }
+    }
+
+    impl nanoserde::SerRon for EulerRot {
+        fn ser_ron(&self, _d: usize, s: &mut nanoserde::SerRonState) {
+            s.out.push_str(euler_rot_name(*self));
+        }
+    }
+
+    #[test]
+    fn test_euler_rot_serde() {
+        use nanoserde::{DeJson, SerJson};
+        for (idx, (euler, name)) in EULER_ROT_NAMES.iter().enumerate() {
+            let serialized = euler.serialize_json();
+            assert_eq!(alloc::format!("\"{}\"", name), serialized);
+            let deserialized = EulerRot::deserialize_json(&serialized).unwrap();
+            assert_eq!(*euler, deserialized);
+            // the table must be in declaration order for the binary encoding
+            assert_eq!(idx, *euler as usize);
+        }
+        let deserialized = EulerRot::deserialize_json(r#""xyz""#);
+        assert!(deserialized.is_err());
+        let deserialized = EulerRot::deserialize_json("2");
+        assert!(deserialized.is_err());
+        let deserialized = EulerRot::deserialize_json("YXZ");
+        assert!(deserialized.is_err());
+    }
+
+    #[test]
+    fn test_euler_rot_serde_bin() {
+        use nanoserde::{DeBin, SerBin};
+        for (idx, (euler, _)) in EULER_ROT_NAMES.iter().enumerate() {
+            let serialized = euler.serialize_bin();
+            assert_eq!((idx as u16).to_le_bytes().as_slice(), serialized.as_slice());
+            let deserialized = EulerRot::deserialize_bin(&serialized).unwrap();
+            assert_eq!(*euler, deserialized);
+        }
+        let deserialized = EulerRot::deserialize_bin(&24_u16.to_le_bytes());
+        assert!(deserialized.is_err());
+        let deserialized = EulerRot::deserialize_bin(&[0]);
+        assert!(deserialized.is_err());
+    }
+
+    #[test]
+    fn test_euler_rot_serde_ron() {
+        use nanoserde::{DeRon, SerRon};
+        for (euler, name) in EULER_ROT_NAMES.iter() {
+            let serialized = euler.serialize_ron();
+            assert_eq!(*name, serialized);
+            let deserialized = EulerRot::deserialize_ron(&serialized).unwrap();
+            assert_eq!(*euler, deserialized);
+        }
+        let deserialized = EulerRot::deserialize_ron("xyz");
+        assert!(deserialized.is_err());
+        let deserialized = EulerRot::deserialize_ron(r#""YXZ""#);
+        assert!(deserialized.is_err());
+    }
+}
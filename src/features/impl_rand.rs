@@ -516,6 +516,28 @@ macro_rules! test_vec_type_uniform {
     };
 }
 
+macro_rules! impl_vec3a {
+    ($t:ident, $vec3a:ident) => {
+        impl Distribution<$vec3a> for StandardUniform {
+            #[inline]
+            fn sample<R: RngExt + ?Sized>(&self, rng: &mut R) -> $vec3a {
+                rng.random::<[$t; 3]>().into()
+            }
+        }
+
+        #[test]
+        fn test_vec3a_rand_standard() {
+            use rand::{RngExt, SeedableRng};
+            use rand_xoshiro::Xoshiro256Plus;
+            let mut rng1 = Xoshiro256Plus::seed_from_u64(0);
+            let a: ($t, $t, $t) = rng1.random();
+            let mut rng2 = Xoshiro256Plus::seed_from_u64(0);
+            let b: $vec3a = rng2.random();
+            assert_eq!(a, b.into());
+        }
+    };
+}
+
 macro_rules! impl_int_types {
     ($t:ty, $vec2:ident, $vec3:ident, $vec4:ident) => {
         use rand::distr::uniform::UniformInt;
@@ -638,23 +660,7 @@ mod f32 {
 
     impl_float_types!(f32, Mat2, Mat3, Mat4, Quat, Vec2, Vec3, Vec4);
 
-    impl Distribution<Vec3A> for StandardUniform {
-        #[inline]
-        fn sample<R: RngExt + ?Sized>(&self, rng: &mut R) -> Vec3A {
-            rng.random::<[f32; 3]>().into()
-        }
-    }
-
-    #[test]
-    fn test_vec3a_rand_standard() {
-        use rand::{RngExt, SeedableRng};
-        use rand_xoshiro::Xoshiro256Plus;
-        let mut rng1 = Xoshiro256Plus::seed_from_u64(0);
-        let a: (f32, f32, f32) = rng1.random();
-        let mut rng2 = Xoshiro256Plus::seed_from_u64(0);
-        let b: Vec3A = rng2.random();
-        assert_eq!(a, b.into());
-    }
+    impl_vec3a!(f32, Vec3A);
 }
 
 mod f64 {
@@ -678,9 +684,10 @@ mod i16 {
 }
 
 mod i32 {
-    use crate::{IVec2, IVec3, IVec4};
+    use crate::{IVec2, IVec3, IVec3A, IVec4};
 
     impl_int_types!(i32, IVec2, IVec3, IVec4);
+    impl_vec3a!(i32, IVec3A);
 }
 
 mod i64 {
@@ -702,9 +709,10 @@ mod u16 {
 }
 
 mod u32 {
-    use crate::{UVec2, UVec3, UVec4};
+    use crate::{UVec2, UVec3, UVec3A, UVec4};
 
     impl_int_types!(u32, UVec2, UVec3, UVec4);
+    impl_vec3a!(u32, UVec3A);
 }
 
 mod u64 {
@@ -117,11 +117,12 @@ mod i16 {
 }
 
 mod i32 {
-    use crate::{IVec2, IVec3, IVec4};
+    use crate::{IVec2, IVec3, IVec3A, IVec4};
     use rkyv::{rancor::Fallible, Archive, Deserialize, Place, Serialize};
 
     impl_rkyv!(IVec2);
     impl_rkyv!(IVec3);
+    impl_rkyv!(IVec3A);
     impl_rkyv!(IVec4);
 }
 
@@ -153,11 +154,12 @@ mod u16 {
 }
 
 mod u32 {
-    use crate::{UVec2, UVec3, UVec4};
+    use crate::{UVec2, UVec3, UVec3A, UVec4};
     use rkyv::{rancor::Fallible, Archive, Deserialize, Place, Serialize};
 
     impl_rkyv!(UVec2);
     impl_rkyv!(UVec3);
+    impl_rkyv!(UVec3A);
     impl_rkyv!(UVec4);
 }
 
@@ -256,9 +258,10 @@ mod test {
         test_archive(&I16Vec3::new(-1, 2, 3));
         test_archive(&I16Vec4::new(-1, 2, 3, 4));
 
-        use crate::{IVec2, IVec3, IVec4};
+        use crate::{IVec2, IVec3, IVec3A, IVec4};
         test_archive(&IVec2::new(-1, 2));
         test_archive(&IVec3::new(-1, 2, 3));
+        test_archive(&IVec3A::new(-1, 2, 3));
         test_archive(&IVec4::new(-1, 2, 3, 4));
 
         use crate::{I64Vec2, I64Vec3, I64Vec4};
@@ -276,9 +279,10 @@ mod test {
         test_archive(&U16Vec3::new(1, 2, 3));
         test_archive(&U16Vec4::new(1, 2, 3, 4));
 
-        use crate::{UVec2, UVec3, UVec4};
+        use crate::{UVec2, UVec3, UVec3A, UVec4};
         test_archive(&UVec2::new(1, 2));
         test_archive(&UVec3::new(1, 2, 3));
+        test_archive(&UVec3A::new(1, 2, 3));
         test_archive(&UVec4::new(1, 2, 3, 4));
 
         use crate::{U64Vec2, U64Vec3, U64Vec4};
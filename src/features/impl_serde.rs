@@ -300,6 +300,313 @@ macro_rules! impl_serde_quat {
     };
 }
 
+macro_rules! impl_serde_rot2 {
+    ($t:ty, $rot2:ident) => {
+        /// Serialize as a sequence of 2 values, the `cos` followed by the `sin`.
+        impl Serialize for $rot2 {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                let mut state = serializer.serialize_tuple_struct(stringify!($rot2), 2)?;
+                state.serialize_field(&self.cos)?;
+                state.serialize_field(&self.sin)?;
+                state.end()
+            }
+        }
+
+        /// Deserialize expects a sequence of 2 values, the `cos` followed by the `sin`.
+        impl<'de> Deserialize<'de> for $rot2 {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                struct Rot2Visitor;
+
+                impl<'de> Visitor<'de> for Rot2Visitor {
+                    type Value = $rot2;
+
+                    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                        formatter.write_str(&concat!("a sequence of 2 ", stringify!($t), " values"))
+                    }
+
+                    fn visit_seq<V>(self, mut seq: V) -> Result<$rot2, V::Error>
+                    where
+                        V: SeqAccess<'de>,
+                    {
+                        let cos = seq
+                            .next_element()?
+                            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+                        let sin = seq
+                            .next_element()?
+                            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+                        Ok($rot2::from_array([cos, sin]))
+                    }
+                }
+
+                deserializer.deserialize_tuple_struct(stringify!($rot2), 2, Rot2Visitor)
+            }
+        }
+
+        #[test]
+        fn test_rot2_serde() {
+            let a = $rot2::from_array([1.0, 2.0]);
+            let serialized = serde_json::to_string(&a).unwrap();
+            assert_eq!(serialized, "[1.0,2.0]");
+            let deserialized = serde_json::from_str(&serialized).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = serde_json::from_str::<$rot2>("[]");
+            assert!(deserialized.is_err());
+            let deserialized = serde_json::from_str::<$rot2>("[1.0]");
+            assert!(deserialized.is_err());
+            let deserialized = serde_json::from_str::<$rot2>("[1.0,2.0,3.0]");
+            assert!(deserialized.is_err());
+            let deserialized = serde_json::from_str::<$rot2>("{}");
+            assert!(deserialized.is_err());
+        }
+    };
+}
+
+macro_rules! impl_serde_dualquat {
+    ($t:ty, $dualquat:ident) => {
+        /// Serialize as a sequence of 8 values, the `real` part followed by the `dual` part.
+        impl Serialize for $dualquat {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                let mut state = serializer.serialize_tuple_struct(stringify!($dualquat), 8)?;
+                for v in self.to_array() {
+                    state.serialize_field(&v)?;
+                }
+                state.end()
+            }
+        }
+
+        /// Deserialize expects a sequence of 8 values.
+        impl<'de> Deserialize<'de> for $dualquat {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                struct DualQuatVisitor;
+
+                impl<'de> Visitor<'de> for DualQuatVisitor {
+                    type Value = $dualquat;
+
+                    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                        formatter.write_str(&concat!("a sequence of 8 ", stringify!($t), " values"))
+                    }
+
+                    fn visit_seq<V>(self, mut seq: V) -> Result<$dualquat, V::Error>
+                    where
+                        V: SeqAccess<'de>,
+                    {
+                        let mut f = [0.0; 8];
+                        for (i, v) in f.iter_mut().enumerate() {
+                            *v = seq
+                                .next_element()?
+                                .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+                        }
+                        Ok($dualquat::from_array(f))
+                    }
+                }
+
+                deserializer.deserialize_tuple_struct(stringify!($dualquat), 8, DualQuatVisitor)
+            }
+        }
+
+        #[test]
+        fn test_dualquat_serde() {
+            let a = $dualquat::from_array([1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+            let serialized = serde_json::to_string(&a).unwrap();
+            assert_eq!(serialized, "[1.0,2.0,3.0,4.0,5.0,6.0,7.0,8.0]");
+            let deserialized = serde_json::from_str(&serialized).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = serde_json::from_str::<$dualquat>("[]");
+            assert!(deserialized.is_err());
+            let deserialized = serde_json::from_str::<$dualquat>("[1.0,2.0,3.0,4.0]");
+            assert!(deserialized.is_err());
+            let deserialized = serde_json::from_str::<$dualquat>("[1.0,2.0,3.0,4.0,5.0,6.0,7.0]");
+            assert!(deserialized.is_err());
+            let deserialized =
+                serde_json::from_str::<$dualquat>("[1.0,2.0,3.0,4.0,5.0,6.0,7.0,8.0,9.0]");
+            assert!(deserialized.is_err());
+            let deserialized =
+                serde_json::from_str::<$dualquat>("[[1.0,2.0,3.0,4.0],[5.0,6.0,7.0,8.0]]");
+            assert!(deserialized.is_err());
+            let deserialized = serde_json::from_str::<$dualquat>("{}");
+            assert!(deserialized.is_err());
+        }
+    };
+}
+
+macro_rules! impl_serde_isometry {
+    ($testname:ident, $t:ty, $isometry:ident, $n:literal, $sx:literal) => {
+        /// Serialize as a sequence of the rotation values followed by the translation values.
+        impl Serialize for $isometry {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                let mut state = serializer.serialize_tuple_struct(stringify!($isometry), $n)?;
+                for v in self.to_array() {
+                    state.serialize_field(&v)?;
+                }
+                state.end()
+            }
+        }
+
+        /// Deserialize expects a sequence of the rotation values followed by the translation
+        /// values.
+        impl<'de> Deserialize<'de> for $isometry {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                struct IsometryVisitor;
+
+                impl<'de> Visitor<'de> for IsometryVisitor {
+                    type Value = $isometry;
+
+                    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                        formatter.write_str(&concat!(
+                            "a sequence of ",
+                            stringify!($n),
+                            " ",
+                            stringify!($t),
+                            " values"
+                        ))
+                    }
+
+                    fn visit_seq<V>(self, mut seq: V) -> Result<$isometry, V::Error>
+                    where
+                        V: SeqAccess<'de>,
+                    {
+                        let mut f = [0.0; $n];
+                        for (i, v) in f.iter_mut().enumerate() {
+                            *v = seq
+                                .next_element()?
+                                .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+                        }
+                        Ok($isometry::from_array(f))
+                    }
+                }
+
+                deserializer.deserialize_tuple_struct(stringify!($isometry), $n, IsometryVisitor)
+            }
+        }
+
+        #[test]
+        fn $testname() {
+            let mut f = [0.0; $n];
+            for (i, v) in f.iter_mut().enumerate() {
+                *v = (i + 1) as $t;
+            }
+            let a = $isometry::from_array(f);
+            let serialized = serde_json::to_string(&a).unwrap();
+            assert_eq!(serialized, $sx);
+            let deserialized = serde_json::from_str(&serialized).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = serde_json::from_str::<$isometry>("[]");
+            assert!(deserialized.is_err());
+            let deserialized = serde_json::from_str::<$isometry>("[1.0,2.0,3.0,4.0,5.0]");
+            assert!(deserialized.is_err());
+            let deserialized =
+                serde_json::from_str::<$isometry>("[1.0,2.0,3.0,4.0,5.0,6.0,7.0,8.0]");
+            assert!(deserialized.is_err());
+            let deserialized = serde_json::from_str::<$isometry>("[[1.0,2.0],[3.0,4.0]]");
+            assert!(deserialized.is_err());
+            let deserialized = serde_json::from_str::<$isometry>("{}");
+            assert!(deserialized.is_err());
+        }
+    };
+}
+
+macro_rules! impl_serde_transform {
+    ($testname:ident, $t:ty, $transform:ident, $n:literal, $sx:literal) => {
+        /// Serialize as a sequence of the scale values followed by the rotation values and the
+        /// translation values.
+        impl Serialize for $transform {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                let mut state = serializer.serialize_tuple_struct(stringify!($transform), $n)?;
+                for v in self.to_array() {
+                    state.serialize_field(&v)?;
+                }
+                state.end()
+            }
+        }
+
+        /// Deserialize expects a sequence of the scale values followed by the rotation values
+        /// and the translation values.
+        impl<'de> Deserialize<'de> for $transform {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                struct TransformVisitor;
+
+                impl<'de> Visitor<'de> for TransformVisitor {
+                    type Value = $transform;
+
+                    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                        formatter.write_str(&concat!(
+                            "a sequence of ",
+                            stringify!($n),
+                            " ",
+                            stringify!($t),
+                            " values"
+                        ))
+                    }
+
+                    fn visit_seq<V>(self, mut seq: V) -> Result<$transform, V::Error>
+                    where
+                        V: SeqAccess<'de>,
+                    {
+                        let mut f = [0.0; $n];
+                        for (i, v) in f.iter_mut().enumerate() {
+                            *v = seq
+                                .next_element()?
+                                .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+                        }
+                        Ok($transform::from_array(f))
+                    }
+                }
+
+                deserializer.deserialize_tuple_struct(stringify!($transform), $n, TransformVisitor)
+            }
+        }
+
+        #[test]
+        fn $testname() {
+            let mut f = [0.0; $n];
+            for (i, v) in f.iter_mut().enumerate() {
+                *v = (i + 1) as $t;
+            }
+            let a = $transform::from_array(f);
+            let serialized = serde_json::to_string(&a).unwrap();
+            assert_eq!(serialized, $sx);
+            let deserialized = serde_json::from_str(&serialized).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = serde_json::from_str::<$transform>("[]");
+            assert!(deserialized.is_err());
+            let deserialized = serde_json::from_str::<$transform>("[1.0,2.0,3.0,4.0]");
+            assert!(deserialized.is_err());
+            let deserialized = serde_json::from_str::<$transform>(
+                "[1.0,2.0,3.0,4.0,5.0,6.0,7.0,8.0,9.0,10.0,11.0]",
+            );
+            assert!(deserialized.is_err());
+            let deserialized = serde_json::from_str::<$transform>("[[1.0,2.0],[3.0,4.0]]");
+            assert!(deserialized.is_err());
+            let deserialized = serde_json::from_str::<$transform>("{}");
+            assert!(deserialized.is_err());
+        }
+    };
+}
+
 macro_rules! impl_serde_mat2 {
     ($t:ty, $mat2:ident) => {
         /// Serialize as a sequence of 4 values.
@@ -727,6 +1034,87 @@ macro_rules! impl_serde_affine3 {
     };
 }
 
+macro_rules! impl_serde_mat_nonsquare {
+    ($testname:ident, $t:ty, $mat:ident, $n:literal, $sx:literal) => {
+        /// Serialize as a sequence of values in column major order.
+        impl Serialize for $mat {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                let f: &[_; $n] = self.as_ref();
+                let mut state = serializer.serialize_tuple_struct(stringify!($mat), $n)?;
+                for v in f {
+                    state.serialize_field(v)?;
+                }
+                state.end()
+            }
+        }
+
+        /// Deserialize expects a sequence of values in column major order.
+        impl<'de> Deserialize<'de> for $mat {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                struct MatVisitor;
+
+                impl<'de> Visitor<'de> for MatVisitor {
+                    type Value = $mat;
+
+                    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                        formatter.write_str(&concat!(
+                            "a sequence of ",
+                            stringify!($n),
+                            " ",
+                            stringify!($t),
+                            " values"
+                        ))
+                    }
+
+                    fn visit_seq<V>(self, mut seq: V) -> Result<$mat, V::Error>
+                    where
+                        V: SeqAccess<'de>,
+                    {
+                        let mut f = [0.0; $n];
+                        for (i, v) in f.iter_mut().enumerate() {
+                            *v = seq
+                                .next_element()?
+                                .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+                        }
+                        Ok($mat::from_cols_array(&f))
+                    }
+                }
+
+                deserializer.deserialize_tuple_struct(stringify!($mat), $n, MatVisitor)
+            }
+        }
+
+        #[test]
+        fn $testname() {
+            let mut f = [0.0; $n];
+            for (i, v) in f.iter_mut().enumerate() {
+                *v = (i + 1) as $t;
+            }
+            let a = $mat::from_cols_array(&f);
+            let serialized = serde_json::to_string(&a).unwrap();
+            assert_eq!(serialized, $sx);
+            let deserialized = serde_json::from_str(&serialized).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = serde_json::from_str::<$mat>("[]");
+            assert!(deserialized.is_err());
+            let deserialized = serde_json::from_str::<$mat>("[1.0,2.0,3.0,4.0,5.0]");
+            assert!(deserialized.is_err());
+            let deserialized = serde_json::from_str::<$mat>(&$sx.replace("]", ",0.0]"));
+            assert!(deserialized.is_err());
+            let deserialized = serde_json::from_str::<$mat>("[[1.0,2.0,3.0],[4.0,5.0,6.0]]");
+            assert!(deserialized.is_err());
+            let deserialized = serde_json::from_str::<$mat>("{}");
+            assert!(deserialized.is_err());
+        }
+    };
+}
+
 macro_rules! impl_serde_vec_types {
     ($t:ty, $vec2:ident, $vec3:ident, $vec4:ident) => {
         impl_serde_vec2!($t, $vec2);
@@ -1047,7 +1435,8 @@ mod f32 {
     #[cfg(test)]
     use super::test_float::*;
     use crate::{
-        Affine2, Affine3, Affine3A, Mat2, Mat3, Mat3A, Mat4, Quat, Vec2, Vec3, Vec3A, Vec4,
+        Affine2, Affine3, Affine3A, DualQuat, Isometry2, Isometry3A, Mat2, Mat2x3, Mat3, Mat3A,
+        Mat3x2, Mat3x4, Mat4, Mat4x3, Quat, Rot2, Transform2, Transform3, Vec2, Vec3, Vec3A, Vec4,
     };
     use core::fmt;
     use serde_core::{
@@ -1059,6 +1448,64 @@ mod f32 {
     impl_serde_affine3!(test_affine3a_serde, f32, Affine3A);
     impl_serde_mat3!(f32, Mat3A, test_mat3a_serde);
     impl_serde_vec3!(f32, Vec3A, test_vec3a_serde);
+    impl_serde_dualquat!(f32, DualQuat);
+    impl_serde_rot2!(f32, Rot2);
+    impl_serde_isometry!(
+        test_isometry2_serde,
+        f32,
+        Isometry2,
+        6,
+        "[1.0,2.0,3.0,4.0,5.0,6.0]"
+    );
+    impl_serde_isometry!(
+        test_isometry3a_serde,
+        f32,
+        Isometry3A,
+        7,
+        "[1.0,2.0,3.0,4.0,5.0,6.0,7.0]"
+    );
+    impl_serde_transform!(
+        test_transform2_serde,
+        f32,
+        Transform2,
+        5,
+        "[1.0,2.0,3.0,4.0,5.0]"
+    );
+    impl_serde_transform!(
+        test_transform3_serde,
+        f32,
+        Transform3,
+        10,
+        "[1.0,2.0,3.0,4.0,5.0,6.0,7.0,8.0,9.0,10.0]"
+    );
+    impl_serde_mat_nonsquare!(
+        test_mat2x3_serde,
+        f32,
+        Mat2x3,
+        6,
+        "[1.0,2.0,3.0,4.0,5.0,6.0]"
+    );
+    impl_serde_mat_nonsquare!(
+        test_mat3x2_serde,
+        f32,
+        Mat3x2,
+        6,
+        "[1.0,2.0,3.0,4.0,5.0,6.0]"
+    );
+    impl_serde_mat_nonsquare!(
+        test_mat3x4_serde,
+        f32,
+        Mat3x4,
+        12,
+        "[1.0,2.0,3.0,4.0,5.0,6.0,7.0,8.0,9.0,10.0,11.0,12.0]"
+    );
+    impl_serde_mat_nonsquare!(
+        test_mat4x3_serde,
+        f32,
+        Mat4x3,
+        12,
+        "[1.0,2.0,3.0,4.0,5.0,6.0,7.0,8.0,9.0,10.0,11.0,12.0]"
+    );
 }
 
 mod f64 {
@@ -1066,7 +1513,10 @@ mod f64 {
     use super::test_f64::*;
     #[cfg(test)]
     use super::test_float::*;
-    use crate::{DAffine2, DAffine3, DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4};
+    use crate::{
+        DAffine2, DAffine3, DDualQuat, DIsometry3, DMat2, DMat3, DMat4, DQuat, DRot2, DTransform2,
+        DTransform3, DVec2, DVec3, DVec4,
+    };
     use core::fmt;
     use serde_core::{
         de::{self, Deserialize, Deserializer, SeqAccess, Visitor},
@@ -1076,6 +1526,29 @@ mod f64 {
     impl_serde_float_types!(
         f64, DAffine2, DAffine3, DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4
     );
+    impl_serde_dualquat!(f64, DDualQuat);
+    impl_serde_rot2!(f64, DRot2);
+    impl_serde_isometry!(
+        test_disometry3_serde,
+        f64,
+        DIsometry3,
+        7,
+        "[1.0,2.0,3.0,4.0,5.0,6.0,7.0]"
+    );
+    impl_serde_transform!(
+        test_dtransform2_serde,
+        f64,
+        DTransform2,
+        5,
+        "[1.0,2.0,3.0,4.0,5.0]"
+    );
+    impl_serde_transform!(
+        test_dtransform3_serde,
+        f64,
+        DTransform3,
+        10,
+        "[1.0,2.0,3.0,4.0,5.0,6.0,7.0,8.0,9.0,10.0]"
+    );
 }
 
 mod i8 {
@@ -1113,7 +1586,7 @@ mod i32 {
     use super::test_i32::*;
     #[cfg(test)]
     use super::test_int::*;
-    use crate::{IVec2, IVec3, IVec4};
+    use crate::{IVec2, IVec3, IVec3A, IVec4};
     use core::fmt;
     use serde_core::{
         de::{self, Deserialize, Deserializer, SeqAccess, Visitor},
@@ -1121,6 +1594,7 @@ mod i32 {
     };
 
     impl_serde_vec_types!(i32, IVec2, IVec3, IVec4);
+    impl_serde_vec3!(i32, IVec3A, test_ivec3a_serde);
 }
 
 mod i64 {
@@ -1188,7 +1662,7 @@ mod u32 {
     use super::test_int::*;
     #[cfg(test)]
     use super::test_u32::*;
-    use crate::{UVec2, UVec3, UVec4};
+    use crate::{UVec2, UVec3, UVec3A, UVec4};
     use core::fmt;
     use serde_core::{
         de::{self, Deserialize, Deserializer, SeqAccess, Visitor},
@@ -1196,6 +1670,7 @@ mod u32 {
     };
 
     impl_serde_vec_types!(u32, UVec2, UVec3, UVec4);
+    impl_serde_vec3!(u32, UVec3A, test_uvec3a_serde);
 }
 
 mod u64 {
@@ -2,9 +2,11 @@
 mod test {
     use crate::{
         Affine2, Affine3, Affine3A, DAffine2, DAffine3, DMat2, DMat3, DMat4, DQuat, DVec2, DVec3,
-        DVec4, I16Vec2, I16Vec3, I16Vec4, I64Vec2, I64Vec3, I64Vec4, I8Vec2, I8Vec3, I8Vec4, IVec2,
-        IVec3, IVec4, Mat2, Mat3, Mat3A, Mat4, Quat, U16Vec2, U16Vec3, U16Vec4, U64Vec2, U64Vec3,
-        U64Vec4, U8Vec2, U8Vec3, U8Vec4, UVec2, UVec3, UVec4, Vec2, Vec3, Vec3A, Vec4,
+        DVec4, HVec2, HVec3, HVec4, I16Vec2, I16Vec3, I16Vec4, I64Mat2, I64Mat3, I64Mat4, I64Vec2,
+        I64Vec3, I64Vec4, I8Vec2, I8Vec3, I8Vec4, IMat2, IMat3, IMat4, IVec2, IVec3, IVec3A, IVec4,
+        Mat2, Mat2x3, Mat3, Mat3A, Mat3x2, Mat3x4, Mat4, Mat4x3, Quat, U16Vec2, U16Vec3, U16Vec4,
+        U64Vec2, U64Vec3, U64Vec4, U8Vec2, U8Vec3, U8Vec4, UVec2, UVec3, UVec3A, UVec4, Vec2, Vec3,
+        Vec3A, Vec4, F16,
     };
     use core::mem;
 
@@ -70,6 +72,14 @@ mod test {
     test_into_bytes_t!(mat3a_into, Mat3A);
     test_from_bytes_t!(mat4_from, Mat4);
     test_into_bytes_t!(mat4_into, Mat4);
+    test_from_bytes_t!(mat2x3_from, Mat2x3);
+    test_into_bytes_t!(mat2x3_into, Mat2x3);
+    test_from_bytes_t!(mat3x2_from, Mat3x2);
+    test_into_bytes_t!(mat3x2_into, Mat3x2);
+    test_from_bytes_t!(mat3x4_from, Mat3x4);
+    test_into_bytes_t!(mat3x4_into, Mat3x4);
+    test_from_bytes_t!(mat4x3_from, Mat4x3);
+    test_into_bytes_t!(mat4x3_into, Mat4x3);
     test_from_bytes_t!(quat_from, Quat);
     test_into_bytes_t!(quat_into, Quat);
     test_from_bytes_t!(vec2_from, Vec2);
@@ -123,6 +133,15 @@ mod test {
     test_from_bytes_t!(u8vec4_from, U8Vec4);
     test_into_bytes_t!(u8vec4_into, U8Vec4);
 
+    test_from_bytes_t!(f16_from, F16);
+    test_into_bytes_t!(f16_into, F16);
+    test_from_bytes_t!(hvec2_from, HVec2);
+    test_into_bytes_t!(hvec2_into, HVec2);
+    test_from_bytes_t!(hvec3_from, HVec3);
+    test_into_bytes_t!(hvec3_into, HVec3);
+    test_from_bytes_t!(hvec4_from, HVec4);
+    test_into_bytes_t!(hvec4_into, HVec4);
+
     test_from_bytes_t!(i16vec2_from, I16Vec2);
     test_into_bytes_t!(i16vec2_into, I16Vec2);
     test_from_bytes_t!(i16vec3_from, I16Vec3);
@@ -141,13 +160,40 @@ mod test {
     test_into_bytes_t!(ivec2_into, IVec2);
     test_from_bytes_t!(ivec3_from, IVec3);
     test_into_bytes_t!(ivec3_into, IVec3);
+    test_from_bytes_t!(ivec3a_from, IVec3A);
+    #[cfg(all(
+        any(
+            target_arch = "aarch64",
+            target_feature = "sse2",
+            target_feature = "simd128"
+        ),
+        not(any(feature = "core-simd", feature = "scalar-math"))
+    ))]
+    test_into_bytes_t!(ivec3a_into, IVec3A);
     test_from_bytes_t!(ivec4_from, IVec4);
     test_into_bytes_t!(ivec4_into, IVec4);
 
+    test_from_bytes_t!(imat2_from, IMat2);
+    test_into_bytes_t!(imat2_into, IMat2);
+    test_from_bytes_t!(imat3_from, IMat3);
+    test_into_bytes_t!(imat3_into, IMat3);
+    test_from_bytes_t!(imat4_from, IMat4);
+    test_into_bytes_t!(imat4_into, IMat4);
+
     test_from_bytes_t!(uvec2_from, UVec2);
     test_into_bytes_t!(uvec2_into, UVec2);
     test_from_bytes_t!(uvec3_from, UVec3);
     test_into_bytes_t!(uvec3_into, UVec3);
+    test_from_bytes_t!(uvec3a_from, UVec3A);
+    #[cfg(all(
+        any(
+            target_arch = "aarch64",
+            target_feature = "sse2",
+            target_feature = "simd128"
+        ),
+        not(any(feature = "core-simd", feature = "scalar-math"))
+    ))]
+    test_into_bytes_t!(uvec3a_into, UVec3A);
     test_from_bytes_t!(uvec4_from, UVec4);
     test_into_bytes_t!(uvec4_into, UVec4);
 
@@ -158,6 +204,13 @@ mod test {
     test_from_bytes_t!(i64vec4_from, I64Vec4);
     test_into_bytes_t!(i64vec4_into, I64Vec4);
 
+    test_from_bytes_t!(i64mat2_from, I64Mat2);
+    test_into_bytes_t!(i64mat2_into, I64Mat2);
+    test_from_bytes_t!(i64mat3_from, I64Mat3);
+    test_into_bytes_t!(i64mat3_into, I64Mat3);
+    test_from_bytes_t!(i64mat4_from, I64Mat4);
+    test_into_bytes_t!(i64mat4_into, I64Mat4);
+
     test_from_bytes_t!(u64vec2_from, U64Vec2);
     test_into_bytes_t!(u64vec2_into, U64Vec2);
     test_from_bytes_t!(u64vec3_from, U64Vec3);
@@ -1,15 +1,115 @@
+mod imat2;
+mod imat3;
+mod imat4;
 mod ivec2;
 mod ivec3;
-mod ivec4;
 
+#[cfg(any(
+    not(any(
+        target_arch = "aarch64",
+        target_feature = "sse2",
+        target_feature = "simd128"
+    )),
+    not(feature = "int-simd"),
+    feature = "core-simd",
+    feature = "scalar-math"
+))]
+mod scalar;
+
+#[cfg(all(
+    target_arch = "aarch64",
+    feature = "int-simd",
+    not(any(feature = "core-simd", feature = "scalar-math"))
+))]
+mod neon;
+
+#[cfg(all(
+    target_feature = "sse2",
+    feature = "int-simd",
+    not(any(feature = "core-simd", feature = "scalar-math"))
+))]
+mod sse2;
+
+#[cfg(all(
+    target_feature = "simd128",
+    feature = "int-simd",
+    not(any(feature = "core-simd", feature = "scalar-math"))
+))]
+mod wasm;
+
+#[cfg(any(
+    not(any(
+        target_arch = "aarch64",
+        target_feature = "sse2",
+        target_feature = "simd128"
+    )),
+    not(feature = "int-simd"),
+    feature = "core-simd",
+    feature = "scalar-math"
+))]
+use scalar::*;
+
+#[cfg(all(
+    target_arch = "aarch64",
+    feature = "int-simd",
+    not(any(feature = "core-simd", feature = "scalar-math"))
+))]
+use neon::*;
+
+#[cfg(all(
+    target_feature = "sse2",
+    feature = "int-simd",
+    not(any(feature = "core-simd", feature = "scalar-math"))
+))]
+use sse2::*;
+
+#[cfg(all(
+    target_feature = "simd128",
+    feature = "int-simd",
+    not(any(feature = "core-simd", feature = "scalar-math"))
+))]
+use wasm::*;
+
+pub use imat2::{imat2, IMat2};
+pub use imat3::{imat3, IMat3};
+pub use imat4::{imat4, IMat4};
 pub use ivec2::{ivec2, IVec2};
 pub use ivec3::{ivec3, IVec3};
+pub use ivec3a::{ivec3a, IVec3A};
 pub use ivec4::{ivec4, IVec4};
 
 #[cfg(not(target_arch = "spirv"))]
 mod test {
     use super::*;
 
+    mod const_test_imat2 {
+        const_assert_eq!(16, core::mem::size_of::<super::IMat2>());
+
+        #[cfg(not(feature = "cuda"))]
+        const_assert_eq!(
+            core::mem::align_of::<i32>(),
+            core::mem::align_of::<super::IMat2>()
+        );
+        #[cfg(feature = "cuda")]
+        const_assert_eq!(8, core::mem::align_of::<super::IMat2>());
+    }
+
+    mod const_test_imat3 {
+        const_assert_eq!(
+            core::mem::align_of::<i32>(),
+            core::mem::align_of::<super::IMat3>()
+        );
+        const_assert_eq!(36, core::mem::size_of::<super::IMat3>());
+    }
+
+    mod const_test_imat4 {
+        const_assert_eq!(
+            core::mem::align_of::<super::IVec4>(),
+            core::mem::align_of::<super::IMat4>()
+        );
+        const_assert_eq!(64, core::mem::size_of::<super::IMat4>());
+    }
+
     mod const_test_ivec2 {
         const_assert_eq!(8, core::mem::size_of::<super::IVec2>());
 
@@ -30,15 +130,26 @@ mod test {
         const_assert_eq!(12, core::mem::size_of::<super::IVec3>());
     }
 
+    mod const_test_ivec3a {
+        const_assert_eq!(16, core::mem::align_of::<super::IVec3A>());
+        const_assert_eq!(16, core::mem::size_of::<super::IVec3A>());
+    }
+
     mod const_test_ivec4 {
         const_assert_eq!(16, core::mem::size_of::<super::IVec4>());
 
-        #[cfg(not(feature = "cuda"))]
+        #[cfg(not(any(
+            all(feature = "int-simd", not(feature = "scalar-math")),
+            feature = "cuda"
+        )))]
         const_assert_eq!(
             core::mem::align_of::<i32>(),
             core::mem::align_of::<super::IVec4>()
         );
-        #[cfg(feature = "cuda")]
+        #[cfg(any(
+            all(feature = "int-simd", not(feature = "scalar-math")),
+            feature = "cuda"
+        ))]
         const_assert_eq!(16, core::mem::align_of::<super::IVec4>());
     }
 }
@@ -0,0 +1,847 @@
+// Generated from imat.rs.tera template. Edit the template, not the generated file.
+
+use crate::{DMat3, IMat2, IVec2, IVec3, Mat3};
+use core::fmt;
+use core::iter::{Product, Sum};
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+#[cfg(feature = "zerocopy")]
+use zerocopy_derive::*;
+
+/// Creates a 3x3 integer matrix from three column vectors.
+#[inline(always)]
+#[must_use]
+pub const fn imat3(x_axis: IVec3, y_axis: IVec3, z_axis: IVec3) -> IMat3 {
+    IMat3::from_cols(x_axis, y_axis, z_axis)
+}
+
+/// A 3x3 column major `i32` matrix.
+///
+/// Integer matrices are exact, which makes them suitable for transforming lattices and grids,
+/// for example rotating and mirroring tiles or voxels by multiples of 90 degrees or applying
+/// shears.
+/// See [`Self::from_quarter_turns_x()`] and [`Self::CUBE_ROTATIONS`].
+///
+/// Arithmetic operators follow the overflow behavior of `i32`, panicking in debug
+/// builds and wrapping in release builds. Use the `checked_` and `wrapping_` methods, such as
+/// [`Self::checked_mul_imat3()`], when a specific behavior is needed.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(FromBytes, Immutable, IntoBytes, KnownLayout)
+)]
+#[repr(C)]
+pub struct IMat3 {
+    pub x_axis: IVec3,
+    pub y_axis: IVec3,
+    pub z_axis: IVec3,
+}
+
+impl IMat3 {
+    /// A 3x3 matrix with all elements set to `0`.
+    pub const ZERO: Self = Self::from_cols(IVec3::ZERO, IVec3::ZERO, IVec3::ZERO);
+
+    /// A 3x3 identity matrix, where all diagonal elements are `1`, and all off-diagonal
+    /// elements are `0`.
+    pub const IDENTITY: Self = Self::from_cols(IVec3::X, IVec3::Y, IVec3::Z);
+
+    /// The 24 rotations that map the axis aligned unit cube onto itself.
+    ///
+    /// These are all of the matrices with a single `1` or `-1` in each row and column and a
+    /// determinant of `1`. The first element is [`Self::IDENTITY`].
+    pub const CUBE_ROTATIONS: [Self; 24] = [
+        Self::from_cols(IVec3::X, IVec3::Y, IVec3::Z),
+        Self::from_cols(IVec3::NEG_X, IVec3::NEG_Y, IVec3::Z),
+        Self::from_cols(IVec3::NEG_X, IVec3::Y, IVec3::NEG_Z),
+        Self::from_cols(IVec3::X, IVec3::NEG_Y, IVec3::NEG_Z),
+        Self::from_cols(IVec3::Y, IVec3::Z, IVec3::X),
+        Self::from_cols(IVec3::NEG_Y, IVec3::NEG_Z, IVec3::X),
+        Self::from_cols(IVec3::NEG_Y, IVec3::Z, IVec3::NEG_X),
+        Self::from_cols(IVec3::Y, IVec3::NEG_Z, IVec3::NEG_X),
+        Self::from_cols(IVec3::Z, IVec3::X, IVec3::Y),
+        Self::from_cols(IVec3::NEG_Z, IVec3::NEG_X, IVec3::Y),
+        Self::from_cols(IVec3::NEG_Z, IVec3::X, IVec3::NEG_Y),
+        Self::from_cols(IVec3::Z, IVec3::NEG_X, IVec3::NEG_Y),
+        Self::from_cols(IVec3::NEG_X, IVec3::Z, IVec3::Y),
+        Self::from_cols(IVec3::X, IVec3::NEG_Z, IVec3::Y),
+        Self::from_cols(IVec3::X, IVec3::Z, IVec3::NEG_Y),
+        Self::from_cols(IVec3::NEG_X, IVec3::NEG_Z, IVec3::NEG_Y),
+        Self::from_cols(IVec3::NEG_Z, IVec3::Y, IVec3::X),
+        Self::from_cols(IVec3::Z, IVec3::NEG_Y, IVec3::X),
+        Self::from_cols(IVec3::Z, IVec3::Y, IVec3::NEG_X),
+        Self::from_cols(IVec3::NEG_Z, IVec3::NEG_Y, IVec3::NEG_X),
+        Self::from_cols(IVec3::NEG_Y, IVec3::X, IVec3::Z),
+        Self::from_cols(IVec3::Y, IVec3::NEG_X, IVec3::Z),
+        Self::from_cols(IVec3::Y, IVec3::X, IVec3::NEG_Z),
+        Self::from_cols(IVec3::NEG_Y, IVec3::NEG_X, IVec3::NEG_Z),
+    ];
+
+    /// Creates a 3x3 matrix from three column vectors.
+    #[inline(always)]
+    #[must_use]
+    pub const fn from_cols(x_axis: IVec3, y_axis: IVec3, z_axis: IVec3) -> Self {
+        Self {
+            x_axis,
+            y_axis,
+            z_axis,
+        }
+    }
+
+    /// Creates a 3x3 matrix from a `[i32; 9]` array stored in column major order.
+    /// If your data is stored in row major you will need to `transpose` the returned
+    /// matrix.
+    #[inline]
+    #[must_use]
+    pub const fn from_cols_array(m: &[i32; 9]) -> Self {
+        Self::from_cols(
+            IVec3::new(m[0], m[1], m[2]),
+            IVec3::new(m[3], m[4], m[5]),
+            IVec3::new(m[6], m[7], m[8]),
+        )
+    }
+
+    /// Creates a `[i32; 9]` array storing data in column major order.
+    /// If you require data in row major order `transpose` the matrix first.
+    #[inline]
+    #[must_use]
+    pub const fn to_cols_array(&self) -> [i32; 9] {
+        let [x_axis_x, x_axis_y, x_axis_z] = self.x_axis.to_array();
+        let [y_axis_x, y_axis_y, y_axis_z] = self.y_axis.to_array();
+        let [z_axis_x, z_axis_y, z_axis_z] = self.z_axis.to_array();
+
+        [
+            x_axis_x, x_axis_y, x_axis_z, y_axis_x, y_axis_y, y_axis_z, z_axis_x, z_axis_y,
+            z_axis_z,
+        ]
+    }
+
+    /// Creates a 3x3 matrix from a `[[i32; 3]; 3]` 3D array stored in column major order.
+    /// If your data is in row major order you will need to `transpose` the returned
+    /// matrix.
+    #[inline]
+    #[must_use]
+    pub const fn from_cols_array_2d(m: &[[i32; 3]; 3]) -> Self {
+        Self::from_cols(
+            IVec3::from_array(m[0]),
+            IVec3::from_array(m[1]),
+            IVec3::from_array(m[2]),
+        )
+    }
+
+    /// Creates a `[[i32; 3]; 3]` 3D array storing data in column major order.
+    /// If you require data in row major order `transpose` the matrix first.
+    #[inline]
+    #[must_use]
+    pub const fn to_cols_array_2d(&self) -> [[i32; 3]; 3] {
+        [
+            self.x_axis.to_array(),
+            self.y_axis.to_array(),
+            self.z_axis.to_array(),
+        ]
+    }
+
+    /// Creates a 3x3 matrix with its diagonal set to `diagonal` and all other entries set
+    /// to 0.
+    ///
+    /// A diagonal of `1`s and `-1`s mirrors along the axes with a `-1`.
+    #[doc(alias = "scale")]
+    #[inline]
+    #[must_use]
+    pub const fn from_diagonal(diagonal: IVec3) -> Self {
+        let [x, y, z] = diagonal.to_array();
+        Self::from_cols(
+            IVec3::new(x, 0, 0),
+            IVec3::new(0, y, 0),
+            IVec3::new(0, 0, z),
+        )
+    }
+
+    /// Creates a 3D rotation matrix rotating by `turns` quarter turns around the x axis.
+    ///
+    /// Positive values rotate counter-clockwise when looking down the axis towards the origin.
+    #[inline]
+    #[must_use]
+    pub const fn from_quarter_turns_x(turns: i32) -> Self {
+        let (sin, cos) = quarter_turn_sin_cos(turns);
+        Self::from_cols(IVec3::X, IVec3::new(0, cos, sin), IVec3::new(0, -sin, cos))
+    }
+
+    /// Creates a 3D rotation matrix rotating by `turns` quarter turns around the y axis.
+    ///
+    /// Positive values rotate counter-clockwise when looking down the axis towards the origin.
+    #[inline]
+    #[must_use]
+    pub const fn from_quarter_turns_y(turns: i32) -> Self {
+        let (sin, cos) = quarter_turn_sin_cos(turns);
+        Self::from_cols(IVec3::new(cos, 0, -sin), IVec3::Y, IVec3::new(sin, 0, cos))
+    }
+
+    /// Creates a 3D rotation matrix rotating by `turns` quarter turns around the z axis.
+    ///
+    /// Positive values rotate counter-clockwise when looking down the axis towards the origin.
+    #[inline]
+    #[must_use]
+    pub const fn from_quarter_turns_z(turns: i32) -> Self {
+        let (sin, cos) = quarter_turn_sin_cos(turns);
+        Self::from_cols(IVec3::new(cos, sin, 0), IVec3::new(-sin, cos, 0), IVec3::Z)
+    }
+
+    /// Creates an affine transformation matrix from the given 2D `translation`.
+    ///
+    /// The resulting matrix can be used to transform 2D points and vectors. See
+    /// [`Self::transform_point2()`] and [`Self::transform_vector2()`].
+    #[inline]
+    #[must_use]
+    pub const fn from_translation(translation: IVec2) -> Self {
+        Self::from_cols(
+            IVec3::X,
+            IVec3::Y,
+            IVec3::new(translation.x, translation.y, 1),
+        )
+    }
+
+    /// Creates an affine transformation matrix from the given 2x2 matrix.
+    ///
+    /// The resulting matrix can be used to transform 2D points and vectors. See
+    /// [`Self::transform_point2()`] and [`Self::transform_vector2()`].
+    #[inline]
+    #[must_use]
+    pub const fn from_imat2(m: IMat2) -> Self {
+        Self::from_cols(m.x_axis.extend(0), m.y_axis.extend(0), IVec3::Z)
+    }
+
+    /// Creates a 3x3 matrix from the first 9 values in `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than 9 elements long.
+    #[inline]
+    #[must_use]
+    pub const fn from_cols_slice(slice: &[i32]) -> Self {
+        Self::from_cols(
+            IVec3::new(slice[0], slice[1], slice[2]),
+            IVec3::new(slice[3], slice[4], slice[5]),
+            IVec3::new(slice[6], slice[7], slice[8]),
+        )
+    }
+
+    /// Writes the columns of `self` to the first 9 elements in `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than 9 elements long.
+    #[inline]
+    pub fn write_cols_to_slice(&self, slice: &mut [i32]) {
+        slice[..9].copy_from_slice(&self.to_cols_array());
+    }
+
+    /// Returns the matrix column for the given `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than 2.
+    #[inline]
+    #[must_use]
+    pub fn col(&self, index: usize) -> IVec3 {
+        match index {
+            0 => self.x_axis,
+            1 => self.y_axis,
+            2 => self.z_axis,
+            _ => panic!("index out of bounds"),
+        }
+    }
+
+    /// Returns a mutable reference to the matrix column for the given `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than 2.
+    #[inline]
+    pub fn col_mut(&mut self, index: usize) -> &mut IVec3 {
+        match index {
+            0 => &mut self.x_axis,
+            1 => &mut self.y_axis,
+            2 => &mut self.z_axis,
+            _ => panic!("index out of bounds"),
+        }
+    }
+
+    /// Returns the matrix row for the given `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than 2.
+    #[inline]
+    #[must_use]
+    pub fn row(&self, index: usize) -> IVec3 {
+        match index {
+            0 => IVec3::new(self.x_axis.x, self.y_axis.x, self.z_axis.x),
+            1 => IVec3::new(self.x_axis.y, self.y_axis.y, self.z_axis.y),
+            2 => IVec3::new(self.x_axis.z, self.y_axis.z, self.z_axis.z),
+            _ => panic!("index out of bounds"),
+        }
+    }
+
+    /// Returns the transpose of `self`.
+    #[inline]
+    #[must_use]
+    pub fn transpose(&self) -> Self {
+        Self::from_cols(self.row(0), self.row(1), self.row(2))
+    }
+
+    /// Returns the diagonal of `self`.
+    #[inline]
+    #[must_use]
+    pub fn diagonal(&self) -> IVec3 {
+        IVec3::new(self.x_axis.x, self.y_axis.y, self.z_axis.z)
+    }
+
+    /// Returns the determinant of `self`.
+    #[inline]
+    #[must_use]
+    pub fn determinant(&self) -> i32 {
+        self.z_axis.dot(self.x_axis.cross(self.y_axis))
+    }
+
+    /// Transforms the given 2D point, applying shear, scale, rotation and translation.
+    ///
+    /// This is the equivalent of multiplying `rhs` as a 3D vector where `z` is `1`.
+    ///
+    /// This method assumes that `self` contains a valid affine transform.
+    #[inline]
+    #[must_use]
+    pub fn transform_point2(&self, rhs: IVec2) -> IVec2 {
+        IMat2::from_cols(self.x_axis.truncate(), self.y_axis.truncate()) * rhs
+            + self.z_axis.truncate()
+    }
+
+    /// Transforms the given 2D vector, applying shear, scale and rotation (but NOT
+    /// translation).
+    ///
+    /// This is the equivalent of multiplying `rhs` as a 3D vector where `z` is `0`.
+    ///
+    /// This method assumes that `self` contains a valid affine transform.
+    #[inline]
+    #[must_use]
+    pub fn transform_vector2(&self, rhs: IVec2) -> IVec2 {
+        IMat2::from_cols(self.x_axis.truncate(), self.y_axis.truncate()) * rhs
+    }
+
+    /// Transforms a 3D vector.
+    #[inline]
+    #[must_use]
+    pub fn mul_ivec3(&self, rhs: IVec3) -> IVec3 {
+        let mut res = self.x_axis.mul(rhs.x);
+        res = res.add(self.y_axis.mul(rhs.y));
+        res = res.add(self.z_axis.mul(rhs.z));
+        res
+    }
+
+    /// Transforms a 3D vector, returning `None` if overflow occurred.
+    #[inline]
+    #[must_use]
+    pub fn checked_mul_ivec3(&self, rhs: IVec3) -> Option<IVec3> {
+        let mut res = self.x_axis.checked_mul(IVec3::splat(rhs.x))?;
+        res = res.checked_add(self.y_axis.checked_mul(IVec3::splat(rhs.y))?)?;
+        res = res.checked_add(self.z_axis.checked_mul(IVec3::splat(rhs.z))?)?;
+        Some(res)
+    }
+
+    /// Transforms a 3D vector, wrapping around on overflow.
+    #[inline]
+    #[must_use]
+    pub fn wrapping_mul_ivec3(&self, rhs: IVec3) -> IVec3 {
+        let mut res = self.x_axis.wrapping_mul(IVec3::splat(rhs.x));
+        res = res.wrapping_add(self.y_axis.wrapping_mul(IVec3::splat(rhs.y)));
+        res = res.wrapping_add(self.z_axis.wrapping_mul(IVec3::splat(rhs.z)));
+        res
+    }
+
+    /// Multiplies two 3x3 matrices.
+    #[inline]
+    #[must_use]
+    pub fn mul_imat3(&self, rhs: &Self) -> Self {
+        Self::from_cols(
+            self.mul_ivec3(rhs.x_axis),
+            self.mul_ivec3(rhs.y_axis),
+            self.mul_ivec3(rhs.z_axis),
+        )
+    }
+
+    /// Multiplies two 3x3 matrices, returning `None` if overflow occurred.
+    #[inline]
+    #[must_use]
+    pub fn checked_mul_imat3(&self, rhs: &Self) -> Option<Self> {
+        Some(Self::from_cols(
+            self.checked_mul_ivec3(rhs.x_axis)?,
+            self.checked_mul_ivec3(rhs.y_axis)?,
+            self.checked_mul_ivec3(rhs.z_axis)?,
+        ))
+    }
+
+    /// Multiplies two 3x3 matrices, wrapping around on overflow.
+    #[inline]
+    #[must_use]
+    pub fn wrapping_mul_imat3(&self, rhs: &Self) -> Self {
+        Self::from_cols(
+            self.wrapping_mul_ivec3(rhs.x_axis),
+            self.wrapping_mul_ivec3(rhs.y_axis),
+            self.wrapping_mul_ivec3(rhs.z_axis),
+        )
+    }
+
+    /// Adds two 3x3 matrices.
+    #[inline]
+    #[must_use]
+    pub fn add_imat3(&self, rhs: &Self) -> Self {
+        Self::from_cols(
+            self.x_axis.add(rhs.x_axis),
+            self.y_axis.add(rhs.y_axis),
+            self.z_axis.add(rhs.z_axis),
+        )
+    }
+
+    /// Adds two 3x3 matrices, returning `None` if overflow occurred.
+    #[inline]
+    #[must_use]
+    pub fn checked_add_imat3(&self, rhs: &Self) -> Option<Self> {
+        Some(Self::from_cols(
+            self.x_axis.checked_add(rhs.x_axis)?,
+            self.y_axis.checked_add(rhs.y_axis)?,
+            self.z_axis.checked_add(rhs.z_axis)?,
+        ))
+    }
+
+    /// Adds two 3x3 matrices, wrapping around on overflow.
+    #[inline]
+    #[must_use]
+    pub fn wrapping_add_imat3(&self, rhs: &Self) -> Self {
+        Self::from_cols(
+            self.x_axis.wrapping_add(rhs.x_axis),
+            self.y_axis.wrapping_add(rhs.y_axis),
+            self.z_axis.wrapping_add(rhs.z_axis),
+        )
+    }
+
+    /// Subtracts two 3x3 matrices.
+    #[inline]
+    #[must_use]
+    pub fn sub_imat3(&self, rhs: &Self) -> Self {
+        Self::from_cols(
+            self.x_axis.sub(rhs.x_axis),
+            self.y_axis.sub(rhs.y_axis),
+            self.z_axis.sub(rhs.z_axis),
+        )
+    }
+
+    /// Subtracts two 3x3 matrices, returning `None` if overflow occurred.
+    #[inline]
+    #[must_use]
+    pub fn checked_sub_imat3(&self, rhs: &Self) -> Option<Self> {
+        Some(Self::from_cols(
+            self.x_axis.checked_sub(rhs.x_axis)?,
+            self.y_axis.checked_sub(rhs.y_axis)?,
+            self.z_axis.checked_sub(rhs.z_axis)?,
+        ))
+    }
+
+    /// Subtracts two 3x3 matrices, wrapping around on overflow.
+    #[inline]
+    #[must_use]
+    pub fn wrapping_sub_imat3(&self, rhs: &Self) -> Self {
+        Self::from_cols(
+            self.x_axis.wrapping_sub(rhs.x_axis),
+            self.y_axis.wrapping_sub(rhs.y_axis),
+            self.z_axis.wrapping_sub(rhs.z_axis),
+        )
+    }
+
+    /// Multiplies a 3x3 matrix by a scalar.
+    #[inline]
+    #[must_use]
+    pub fn mul_scalar(&self, rhs: i32) -> Self {
+        Self::from_cols(
+            self.x_axis.mul(rhs),
+            self.y_axis.mul(rhs),
+            self.z_axis.mul(rhs),
+        )
+    }
+
+    /// Takes the absolute value of each element in `self`
+    #[inline]
+    #[must_use]
+    pub fn abs(&self) -> Self {
+        Self::from_cols(self.x_axis.abs(), self.y_axis.abs(), self.z_axis.abs())
+    }
+
+    /// Casts all elements of `self` to `f32`.
+    #[inline]
+    #[must_use]
+    pub fn as_mat3(&self) -> Mat3 {
+        Mat3::from_cols(
+            self.x_axis.as_vec3(),
+            self.y_axis.as_vec3(),
+            self.z_axis.as_vec3(),
+        )
+    }
+
+    /// Casts all elements of `self` to `f64`.
+    #[inline]
+    #[must_use]
+    pub fn as_dmat3(&self) -> DMat3 {
+        DMat3::from_cols(
+            self.x_axis.as_dvec3(),
+            self.y_axis.as_dvec3(),
+            self.z_axis.as_dvec3(),
+        )
+    }
+}
+
+/// Returns the sine and cosine of `turns` quarter turns.
+#[inline]
+const fn quarter_turn_sin_cos(turns: i32) -> (i32, i32) {
+    match turns.rem_euclid(4) {
+        0 => (0, 1),
+        1 => (1, 0),
+        2 => (0, -1),
+        _ => (-1, 0),
+    }
+}
+
+impl Default for IMat3 {
+    #[inline]
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Add for IMat3 {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        self.add_imat3(&rhs)
+    }
+}
+
+impl Add<&Self> for IMat3 {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: &Self) -> Self {
+        self.add(*rhs)
+    }
+}
+
+impl Add<&IMat3> for &IMat3 {
+    type Output = IMat3;
+    #[inline]
+    fn add(self, rhs: &IMat3) -> IMat3 {
+        (*self).add(*rhs)
+    }
+}
+
+impl Add<IMat3> for &IMat3 {
+    type Output = IMat3;
+    #[inline]
+    fn add(self, rhs: IMat3) -> IMat3 {
+        (*self).add(rhs)
+    }
+}
+
+impl AddAssign for IMat3 {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = self.add(rhs);
+    }
+}
+
+impl AddAssign<&Self> for IMat3 {
+    #[inline]
+    fn add_assign(&mut self, rhs: &Self) {
+        self.add_assign(*rhs);
+    }
+}
+
+impl Sub for IMat3 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        self.sub_imat3(&rhs)
+    }
+}
+
+impl Sub<&Self> for IMat3 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: &Self) -> Self {
+        self.sub(*rhs)
+    }
+}
+
+impl Sub<&IMat3> for &IMat3 {
+    type Output = IMat3;
+    #[inline]
+    fn sub(self, rhs: &IMat3) -> IMat3 {
+        (*self).sub(*rhs)
+    }
+}
+
+impl Sub<IMat3> for &IMat3 {
+    type Output = IMat3;
+    #[inline]
+    fn sub(self, rhs: IMat3) -> IMat3 {
+        (*self).sub(rhs)
+    }
+}
+
+impl SubAssign for IMat3 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = self.sub(rhs);
+    }
+}
+
+impl SubAssign<&Self> for IMat3 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: &Self) {
+        self.sub_assign(*rhs);
+    }
+}
+
+impl Neg for IMat3 {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self::from_cols(self.x_axis.neg(), self.y_axis.neg(), self.z_axis.neg())
+    }
+}
+
+impl Neg for &IMat3 {
+    type Output = IMat3;
+    #[inline]
+    fn neg(self) -> IMat3 {
+        (*self).neg()
+    }
+}
+
+impl Mul for IMat3 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        self.mul_imat3(&rhs)
+    }
+}
+
+impl Mul<&Self> for IMat3 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: &Self) -> Self {
+        self.mul(*rhs)
+    }
+}
+
+impl Mul<&IMat3> for &IMat3 {
+    type Output = IMat3;
+    #[inline]
+    fn mul(self, rhs: &IMat3) -> IMat3 {
+        (*self).mul(*rhs)
+    }
+}
+
+impl Mul<IMat3> for &IMat3 {
+    type Output = IMat3;
+    #[inline]
+    fn mul(self, rhs: IMat3) -> IMat3 {
+        (*self).mul(rhs)
+    }
+}
+
+impl MulAssign for IMat3 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.mul(rhs);
+    }
+}
+
+impl MulAssign<&Self> for IMat3 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: &Self) {
+        self.mul_assign(*rhs);
+    }
+}
+
+impl Mul<IVec3> for IMat3 {
+    type Output = IVec3;
+    #[inline]
+    fn mul(self, rhs: IVec3) -> Self::Output {
+        self.mul_ivec3(rhs)
+    }
+}
+
+impl Mul<&IVec3> for IMat3 {
+    type Output = IVec3;
+    #[inline]
+    fn mul(self, rhs: &IVec3) -> IVec3 {
+        self.mul(*rhs)
+    }
+}
+
+impl Mul<&IVec3> for &IMat3 {
+    type Output = IVec3;
+    #[inline]
+    fn mul(self, rhs: &IVec3) -> IVec3 {
+        (*self).mul(*rhs)
+    }
+}
+
+impl Mul<IVec3> for &IMat3 {
+    type Output = IVec3;
+    #[inline]
+    fn mul(self, rhs: IVec3) -> IVec3 {
+        (*self).mul(rhs)
+    }
+}
+
+impl Mul<IMat3> for i32 {
+    type Output = IMat3;
+    #[inline]
+    fn mul(self, rhs: IMat3) -> Self::Output {
+        rhs.mul_scalar(self)
+    }
+}
+
+impl Mul<&IMat3> for i32 {
+    type Output = IMat3;
+    #[inline]
+    fn mul(self, rhs: &IMat3) -> IMat3 {
+        self.mul(*rhs)
+    }
+}
+
+impl Mul<&IMat3> for &i32 {
+    type Output = IMat3;
+    #[inline]
+    fn mul(self, rhs: &IMat3) -> IMat3 {
+        (*self).mul(*rhs)
+    }
+}
+
+impl Mul<IMat3> for &i32 {
+    type Output = IMat3;
+    #[inline]
+    fn mul(self, rhs: IMat3) -> IMat3 {
+        (*self).mul(rhs)
+    }
+}
+
+impl Mul<i32> for IMat3 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: i32) -> Self {
+        self.mul_scalar(rhs)
+    }
+}
+
+impl Mul<&i32> for IMat3 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: &i32) -> Self {
+        self.mul(*rhs)
+    }
+}
+
+impl Mul<&i32> for &IMat3 {
+    type Output = IMat3;
+    #[inline]
+    fn mul(self, rhs: &i32) -> IMat3 {
+        (*self).mul(*rhs)
+    }
+}
+
+impl Mul<i32> for &IMat3 {
+    type Output = IMat3;
+    #[inline]
+    fn mul(self, rhs: i32) -> IMat3 {
+        (*self).mul(rhs)
+    }
+}
+
+impl MulAssign<i32> for IMat3 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: i32) {
+        *self = self.mul(rhs);
+    }
+}
+
+impl MulAssign<&i32> for IMat3 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: &i32) {
+        self.mul_assign(*rhs);
+    }
+}
+
+impl Sum<Self> for IMat3 {
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        iter.fold(Self::ZERO, Self::add)
+    }
+}
+
+impl<'a> Sum<&'a Self> for IMat3 {
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.fold(Self::ZERO, |a, &b| Self::add(a, b))
+    }
+}
+
+impl Product for IMat3 {
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        iter.fold(Self::IDENTITY, Self::mul)
+    }
+}
+
+impl<'a> Product<&'a Self> for IMat3 {
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.fold(Self::IDENTITY, |a, &b| Self::mul(a, b))
+    }
+}
+
+impl AsRef<[i32; 9]> for IMat3 {
+    #[inline]
+    fn as_ref(&self) -> &[i32; 9] {
+        unsafe { &*(self as *const Self as *const [i32; 9]) }
+    }
+}
+
+impl AsMut<[i32; 9]> for IMat3 {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [i32; 9] {
+        unsafe { &mut *(self as *mut Self as *mut [i32; 9]) }
+    }
+}
+
+impl fmt::Debug for IMat3 {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct(stringify!(IMat3))
+            .field("x_axis", &self.x_axis)
+            .field("y_axis", &self.y_axis)
+            .field("z_axis", &self.z_axis)
+            .finish()
+    }
+}
+
+impl fmt::Display for IMat3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}, {}, {}]", self.x_axis, self.y_axis, self.z_axis,)
+    }
+}
@@ -0,0 +1,2 @@
+pub mod ivec3a;
+pub mod ivec4;
@@ -1,10 +1,13 @@
 // Generated from vec.rs.tera template. Edit the template, not the generated file.
 
-#[cfg(not(feature = "scalar-math"))]
+#[cfg(any(feature = "scalar-math", not(feature = "int-simd")))]
+use crate::BVec4 as BVec4A;
+
+#[cfg(all(feature = "int-simd", not(feature = "scalar-math")))]
 use crate::BVec4A;
 use crate::{
-    BVec4, I16Vec4, I64Vec4, I8Vec4, ISizeVec4, IVec2, IVec3, U16Vec4, U64Vec4, U8Vec4, USizeVec4,
-    UVec4,
+    BVec4, I16Vec4, I64Vec4, I8Vec4, ISizeVec4, IVec2, IVec3, IVec3A, U16Vec4, U64Vec4, U8Vec4,
+    USizeVec4, UVec4,
 };
 
 use core::fmt;
@@ -28,7 +31,13 @@ pub const fn ivec4(x: i32, y: i32, z: i32, w: i32) -> IVec4 {
     feature = "zerocopy",
     derive(FromBytes, Immutable, IntoBytes, KnownLayout)
 )]
-#[cfg_attr(feature = "cuda", repr(align(16)))]
+#[cfg_attr(
+    any(
+        all(feature = "int-simd", not(feature = "scalar-math")),
+        feature = "cuda"
+    ),
+    repr(align(16))
+)]
 #[repr(C)]
 #[cfg_attr(target_arch = "spirv", rust_gpu::vector::v1)]
 pub struct IVec4 {
@@ -120,7 +129,7 @@ impl IVec4 {
     /// uses the element from `if_false`.
     #[inline]
     #[must_use]
-    pub fn select(mask: BVec4, if_true: Self, if_false: Self) -> Self {
+    pub fn select(mask: BVec4A, if_true: Self, if_false: Self) -> Self {
         Self {
             x: if mask.test(0) { if_true.x } else { if_false.x },
             y: if mask.test(1) { if_true.y } else { if_false.y },
@@ -168,6 +177,8 @@ impl IVec4 {
     /// Creates a 3D vector from the `x`, `y` and `z` elements of `self`, discarding `w`.
     ///
     /// Truncation to [`IVec3`] may also be performed by using [`self.xyz()`][crate::swizzles::Vec4Swizzles::xyz()].
+    ///
+    /// To truncate to [`IVec3A`] use [`IVec3A::from_vec4()`].
     #[inline]
     #[must_use]
     pub fn truncate(self) -> IVec3 {
@@ -350,8 +361,8 @@ impl IVec4 {
     /// elements.
     #[inline]
     #[must_use]
-    pub fn cmpeq(self, rhs: Self) -> BVec4 {
-        BVec4::new(
+    pub fn cmpeq(self, rhs: Self) -> BVec4A {
+        BVec4A::new(
             self.x.eq(&rhs.x),
             self.y.eq(&rhs.y),
             self.z.eq(&rhs.z),
@@ -366,8 +377,8 @@ impl IVec4 {
     /// elements.
     #[inline]
     #[must_use]
-    pub fn cmpne(self, rhs: Self) -> BVec4 {
-        BVec4::new(
+    pub fn cmpne(self, rhs: Self) -> BVec4A {
+        BVec4A::new(
             self.x.ne(&rhs.x),
             self.y.ne(&rhs.y),
             self.z.ne(&rhs.z),
@@ -382,8 +393,8 @@ impl IVec4 {
     /// elements.
     #[inline]
     #[must_use]
-    pub fn cmpge(self, rhs: Self) -> BVec4 {
-        BVec4::new(
+    pub fn cmpge(self, rhs: Self) -> BVec4A {
+        BVec4A::new(
             self.x.ge(&rhs.x),
             self.y.ge(&rhs.y),
             self.z.ge(&rhs.z),
@@ -398,8 +409,8 @@ impl IVec4 {
     /// elements.
     #[inline]
     #[must_use]
-    pub fn cmpgt(self, rhs: Self) -> BVec4 {
-        BVec4::new(
+    pub fn cmpgt(self, rhs: Self) -> BVec4A {
+        BVec4A::new(
             self.x.gt(&rhs.x),
             self.y.gt(&rhs.y),
             self.z.gt(&rhs.z),
@@ -414,8 +425,8 @@ impl IVec4 {
     /// elements.
     #[inline]
     #[must_use]
-    pub fn cmple(self, rhs: Self) -> BVec4 {
-        BVec4::new(
+    pub fn cmple(self, rhs: Self) -> BVec4A {
+        BVec4A::new(
             self.x.le(&rhs.x),
             self.y.le(&rhs.y),
             self.z.le(&rhs.z),
@@ -430,8 +441,8 @@ impl IVec4 {
     /// elements.
     #[inline]
     #[must_use]
-    pub fn cmplt(self, rhs: Self) -> BVec4 {
-        BVec4::new(
+    pub fn cmplt(self, rhs: Self) -> BVec4A {
+        BVec4A::new(
             self.x.lt(&rhs.x),
             self.y.lt(&rhs.y),
             self.z.lt(&rhs.z),
@@ -3161,6 +3172,20 @@ impl From<IVec4> for (i32, i32, i32, i32) {
     }
 }
 
+impl From<(IVec3A, i32)> for IVec4 {
+    #[inline]
+    fn from((v, w): (IVec3A, i32)) -> Self {
+        v.extend(w)
+    }
+}
+
+impl From<(i32, IVec3A)> for IVec4 {
+    #[inline]
+    fn from((x, v): (i32, IVec3A)) -> Self {
+        Self::new(x, v.x, v.y, v.z)
+    }
+}
+
 impl From<(IVec3, i32)> for IVec4 {
     #[inline]
     fn from((v, w): (IVec3, i32)) -> Self {
@@ -3320,9 +3345,9 @@ impl From<BVec4> for IVec4 {
 }
 
 #[cfg(not(feature = "scalar-math"))]
-impl From<BVec4A> for IVec4 {
+impl From<crate::BVec4A> for IVec4 {
     #[inline]
-    fn from(v: BVec4A) -> Self {
+    fn from(v: crate::BVec4A) -> Self {
         let bool_array: [bool; 4] = v.into();
         Self::new(
             i32::from(bool_array[0]),
@@ -0,0 +1,3424 @@
+// Generated from vec.rs.tera template. Edit the template, not the generated file.
+
+use crate::{
+    sse2::*, BVec4, BVec4A, I16Vec4, I64Vec4, I8Vec4, ISizeVec4, IVec2, IVec3, IVec3A, U16Vec4,
+    U64Vec4, U8Vec4, USizeVec4, UVec4,
+};
+
+use core::fmt;
+use core::iter::{Product, Sum};
+use core::{f32, ops::*};
+
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+#[cfg(feature = "zerocopy")]
+use zerocopy_derive::*;
+
+#[repr(C)]
+union UnionCast {
+    a: [i32; 4],
+    v: IVec4,
+}
+
+/// Creates a 4-dimensional vector.
+#[inline(always)]
+#[must_use]
+pub const fn ivec4(x: i32, y: i32, z: i32, w: i32) -> IVec4 {
+    IVec4::new(x, y, z, w)
+}
+
+/// A 4-dimensional vector.
+///
+/// SIMD vector types are used for storage on supported platforms.
+///
+/// This type is 16 byte aligned.
+#[derive(Clone, Copy)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(FromBytes, Immutable, IntoBytes, KnownLayout)
+)]
+#[repr(transparent)]
+pub struct IVec4(pub(crate) __m128i);
+
+impl IVec4 {
+    /// All zeroes.
+    pub const ZERO: Self = Self::splat(0);
+
+    /// All ones.
+    pub const ONE: Self = Self::splat(1);
+
+    /// All negative ones.
+    pub const NEG_ONE: Self = Self::splat(-1);
+
+    /// All `i32::MIN`.
+    pub const MIN: Self = Self::splat(i32::MIN);
+
+    /// All `i32::MAX`.
+    pub const MAX: Self = Self::splat(i32::MAX);
+
+    /// A unit vector pointing along the positive X axis.
+    pub const X: Self = Self::new(1, 0, 0, 0);
+
+    /// A unit vector pointing along the positive Y axis.
+    pub const Y: Self = Self::new(0, 1, 0, 0);
+
+    /// A unit vector pointing along the positive Z axis.
+    pub const Z: Self = Self::new(0, 0, 1, 0);
+
+    /// A unit vector pointing along the positive W axis.
+    pub const W: Self = Self::new(0, 0, 0, 1);
+
+    /// A unit vector pointing along the negative X axis.
+    pub const NEG_X: Self = Self::new(-1, 0, 0, 0);
+
+    /// A unit vector pointing along the negative Y axis.
+    pub const NEG_Y: Self = Self::new(0, -1, 0, 0);
+
+    /// A unit vector pointing along the negative Z axis.
+    pub const NEG_Z: Self = Self::new(0, 0, -1, 0);
+
+    /// A unit vector pointing along the negative W axis.
+    pub const NEG_W: Self = Self::new(0, 0, 0, -1);
+
+    /// The unit axes.
+    pub const AXES: [Self; 4] = [Self::X, Self::Y, Self::Z, Self::W];
+
+    /// Creates a new vector.
+    #[inline(always)]
+    #[must_use]
+    pub const fn new(x: i32, y: i32, z: i32, w: i32) -> Self {
+        unsafe { UnionCast { a: [x, y, z, w] }.v }
+    }
+
+    /// Creates a vector with all elements set to `v`.
+    #[inline]
+    #[must_use]
+    pub const fn splat(v: i32) -> Self {
+        unsafe { UnionCast { a: [v; 4] }.v }
+    }
+
+    /// Returns a vector containing each element of `self` modified by a mapping function `f`.
+    #[inline]
+    #[must_use]
+    pub fn map<F>(self, f: F) -> Self
+    where
+        F: Fn(i32) -> i32,
+    {
+        Self::new(f(self.x), f(self.y), f(self.z), f(self.w))
+    }
+
+    /// Creates a vector from the elements in `if_true` and `if_false`, selecting which to use
+    /// for each element of `self`.
+    ///
+    /// A true element in the mask uses the corresponding element from `if_true`, and false
+    /// uses the element from `if_false`.
+    #[inline]
+    #[must_use]
+    pub fn select(mask: BVec4A, if_true: Self, if_false: Self) -> Self {
+        Self(unsafe { m128i_select(_mm_castps_si128(mask.0), if_true.0, if_false.0) })
+    }
+
+    /// Creates a new vector from an array.
+    #[inline]
+    #[must_use]
+    pub const fn from_array(a: [i32; 4]) -> Self {
+        Self::new(a[0], a[1], a[2], a[3])
+    }
+
+    /// Converts `self` to `[x, y, z, w]`
+    #[inline]
+    #[must_use]
+    pub const fn to_array(&self) -> [i32; 4] {
+        unsafe { *(self as *const Self as *const [i32; 4]) }
+    }
+
+    /// Creates a vector from the first 4 values in `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than 4 elements long.
+    #[inline]
+    #[must_use]
+    pub const fn from_slice(slice: &[i32]) -> Self {
+        assert!(slice.len() >= 4);
+        Self::new(slice[0], slice[1], slice[2], slice[3])
+    }
+
+    /// Writes the elements of `self` to the first 4 elements in `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than 4 elements long.
+    #[inline]
+    pub fn write_to_slice(self, slice: &mut [i32]) {
+        slice[..4].copy_from_slice(&self.to_array());
+    }
+
+    /// Creates a 3D vector from the `x`, `y` and `z` elements of `self`, discarding `w`.
+    ///
+    /// Truncation to [`IVec3`] may also be performed by using [`self.xyz()`][crate::swizzles::Vec4Swizzles::xyz()].
+    ///
+    /// To truncate to [`IVec3A`] use [`IVec3A::from_vec4()`].
+    #[inline]
+    #[must_use]
+    pub fn truncate(self) -> IVec3 {
+        use crate::swizzles::Vec4Swizzles;
+        self.xyz()
+    }
+
+    /// Creates a 4D vector from `self` with the given value of `x`.
+    #[inline]
+    #[must_use]
+    pub fn with_x(mut self, x: i32) -> Self {
+        self.x = x;
+        self
+    }
+
+    /// Creates a 4D vector from `self` with the given value of `y`.
+    #[inline]
+    #[must_use]
+    pub fn with_y(mut self, y: i32) -> Self {
+        self.y = y;
+        self
+    }
+
+    /// Creates a 4D vector from `self` with the given value of `z`.
+    #[inline]
+    #[must_use]
+    pub fn with_z(mut self, z: i32) -> Self {
+        self.z = z;
+        self
+    }
+
+    /// Creates a 4D vector from `self` with the given value of `w`.
+    #[inline]
+    #[must_use]
+    pub fn with_w(mut self, w: i32) -> Self {
+        self.w = w;
+        self
+    }
+
+    /// Computes the dot product of `self` and `rhs`.
+    #[inline]
+    #[must_use]
+    pub fn dot(self, rhs: Self) -> i32 {
+        (self.x * rhs.x) + (self.y * rhs.y) + (self.z * rhs.z) + (self.w * rhs.w)
+    }
+
+    /// Returns a vector where every component is the dot product of `self` and `rhs`.
+    #[inline]
+    #[must_use]
+    pub fn dot_into_vec(self, rhs: Self) -> Self {
+        Self::splat(self.dot(rhs))
+    }
+
+    /// Returns a vector containing the minimum values for each element of `self` and `rhs`.
+    ///
+    /// In other words this computes `[min(x, rhs.x), min(self.y, rhs.y), ..]`.
+    #[inline]
+    #[must_use]
+    pub fn min(self, rhs: Self) -> Self {
+        Self(unsafe { m128i_min_epi32(self.0, rhs.0) })
+    }
+
+    /// Returns a vector containing the maximum values for each element of `self` and `rhs`.
+    ///
+    /// In other words this computes `[max(self.x, rhs.x), max(self.y, rhs.y), ..]`.
+    #[inline]
+    #[must_use]
+    pub fn max(self, rhs: Self) -> Self {
+        Self(unsafe { m128i_max_epi32(self.0, rhs.0) })
+    }
+
+    /// Component-wise clamping of values, similar to [`i32::clamp`].
+    ///
+    /// Each element in `min` must be less-or-equal to the corresponding element in `max`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `min` is greater than `max` when `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        glam_assert!(min.cmple(max).all(), "clamp: expected min <= max");
+        self.max(min).min(max)
+    }
+
+    /// Returns the horizontal minimum of `self`.
+    ///
+    /// In other words this computes `min(x, y, ..)`.
+    #[inline]
+    #[must_use]
+    pub fn min_element(self) -> i32 {
+        let min = |a, b| if a < b { a } else { b };
+        min(self.x, min(self.y, min(self.z, self.w)))
+    }
+
+    /// Returns the horizontal maximum of `self`.
+    ///
+    /// In other words this computes `max(x, y, ..)`.
+    #[inline]
+    #[must_use]
+    pub fn max_element(self) -> i32 {
+        let max = |a, b| if a > b { a } else { b };
+        max(self.x, max(self.y, max(self.z, self.w)))
+    }
+
+    /// Returns the index of the first minimum element of `self`.
+    #[doc(alias = "argmin")]
+    #[inline]
+    #[must_use]
+    pub fn min_position(self) -> usize {
+        let mut min = self.x;
+        let mut index = 0;
+        if self.y < min {
+            min = self.y;
+            index = 1;
+        }
+        if self.z < min {
+            min = self.z;
+            index = 2;
+        }
+        if self.w < min {
+            index = 3;
+        }
+        index
+    }
+
+    /// Returns the index of the first maximum element of `self`.
+    #[doc(alias = "argmax")]
+    #[inline]
+    #[must_use]
+    pub fn max_position(self) -> usize {
+        let mut max = self.x;
+        let mut index = 0;
+        if self.y > max {
+            max = self.y;
+            index = 1;
+        }
+        if self.z > max {
+            max = self.z;
+            index = 2;
+        }
+        if self.w > max {
+            index = 3;
+        }
+        index
+    }
+
+    /// Returns the sum of all elements of `self`.
+    ///
+    /// In other words, this computes `self.x + self.y + ..`.
+    #[inline]
+    #[must_use]
+    pub fn element_sum(self) -> i32 {
+        self.x + self.y + self.z + self.w
+    }
+
+    /// Returns the product of all elements of `self`.
+    ///
+    /// In other words, this computes `self.x * self.y * ..`.
+    #[inline]
+    #[must_use]
+    pub fn element_product(self) -> i32 {
+        self.x * self.y * self.z * self.w
+    }
+
+    /// Returns a vector mask containing the result of a `==` comparison for each element of
+    /// `self` and `rhs`.
+    ///
+    /// In other words, this computes `[self.x == rhs.x, self.y == rhs.y, ..]` for all
+    /// elements.
+    #[inline]
+    #[must_use]
+    pub fn cmpeq(self, rhs: Self) -> BVec4A {
+        BVec4A(unsafe { _mm_castsi128_ps(_mm_cmpeq_epi32(self.0, rhs.0)) })
+    }
+
+    /// Returns a vector mask containing the result of a `!=` comparison for each element of
+    /// `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x != rhs.x, self.y != rhs.y, ..]` for all
+    /// elements.
+    #[inline]
+    #[must_use]
+    pub fn cmpne(self, rhs: Self) -> BVec4A {
+        BVec4A(unsafe { _mm_castsi128_ps(m128i_not(_mm_cmpeq_epi32(self.0, rhs.0))) })
+    }
+
+    /// Returns a vector mask containing the result of a `>=` comparison for each element of
+    /// `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x >= rhs.x, self.y >= rhs.y, ..]` for all
+    /// elements.
+    #[inline]
+    #[must_use]
+    pub fn cmpge(self, rhs: Self) -> BVec4A {
+        BVec4A(unsafe { _mm_castsi128_ps(m128i_not(_mm_cmplt_epi32(self.0, rhs.0))) })
+    }
+
+    /// Returns a vector mask containing the result of a `>` comparison for each element of
+    /// `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x > rhs.x, self.y > rhs.y, ..]` for all
+    /// elements.
+    #[inline]
+    #[must_use]
+    pub fn cmpgt(self, rhs: Self) -> BVec4A {
+        BVec4A(unsafe { _mm_castsi128_ps(_mm_cmpgt_epi32(self.0, rhs.0)) })
+    }
+
+    /// Returns a vector mask containing the result of a `<=` comparison for each element of
+    /// `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x <= rhs.x, self.y <= rhs.y, ..]` for all
+    /// elements.
+    #[inline]
+    #[must_use]
+    pub fn cmple(self, rhs: Self) -> BVec4A {
+        BVec4A(unsafe { _mm_castsi128_ps(m128i_not(_mm_cmpgt_epi32(self.0, rhs.0))) })
+    }
+
+    /// Returns a vector mask containing the result of a `<` comparison for each element of
+    /// `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x < rhs.x, self.y < rhs.y, ..]` for all
+    /// elements.
+    #[inline]
+    #[must_use]
+    pub fn cmplt(self, rhs: Self) -> BVec4A {
+        BVec4A(unsafe { _mm_castsi128_ps(_mm_cmplt_epi32(self.0, rhs.0)) })
+    }
+
+    /// Returns a vector containing the absolute value of each element of `self`.
+    #[inline]
+    #[must_use]
+    pub fn abs(self) -> Self {
+        Self::new(self.x.abs(), self.y.abs(), self.z.abs(), self.w.abs())
+    }
+
+    /// Returns a vector with elements representing the sign of `self`.
+    ///
+    ///  - `0` if the number is zero
+    ///  - `1` if the number is positive
+    ///  - `-1` if the number is negative
+    #[inline]
+    #[must_use]
+    pub fn signum(self) -> Self {
+        // the arithmetic shift gives -1 for negative elements, the logical shift of the negated
+        // elements gives 1 for positive elements
+        Self(unsafe {
+            _mm_or_si128(
+                _mm_srai_epi32(self.0, 31),
+                _mm_srli_epi32(_mm_sub_epi32(_mm_setzero_si128(), self.0), 31),
+            )
+        })
+    }
+
+    /// Returns a bitmask with the lowest 4 bits set to the sign bits from the elements of `self`.
+    ///
+    /// A negative element results in a `1` bit and a positive element in a `0` bit.  Element `x` goes
+    /// into the first lowest bit, element `y` into the second, etc.
+    ///
+    /// An element is negative if it has a negative sign, including -0.0, NaNs with negative sign
+    /// bit and negative infinity.
+    #[inline]
+    #[must_use]
+    pub fn is_negative_bitmask(self) -> u32 {
+        unsafe { _mm_movemask_ps(_mm_castsi128_ps(self.0)) as u32 }
+    }
+
+    /// Computes the squared length of `self`.
+    #[doc(alias = "magnitude2")]
+    #[inline]
+    #[must_use]
+    pub fn length_squared(self) -> i32 {
+        self.dot(self)
+    }
+
+    /// Compute the squared euclidean distance between two points in space.
+    #[inline]
+    #[must_use]
+    pub fn distance_squared(self, rhs: Self) -> i32 {
+        (self - rhs).length_squared()
+    }
+
+    /// Returns the element-wise quotient of [Euclidean division] of `self` by `rhs`.
+    ///
+    /// # Panics
+    /// This function will panic if any `rhs` element is 0 or the division results in overflow.
+    #[inline]
+    #[must_use]
+    pub fn div_euclid(self, rhs: Self) -> Self {
+        Self::new(
+            self.x.div_euclid(rhs.x),
+            self.y.div_euclid(rhs.y),
+            self.z.div_euclid(rhs.z),
+            self.w.div_euclid(rhs.w),
+        )
+    }
+
+    /// Returns the element-wise remainder of [Euclidean division] of `self` by `rhs`.
+    ///
+    /// # Panics
+    /// This function will panic if any `rhs` element is 0 or the division results in overflow.
+    ///
+    /// [Euclidean division]: i32::rem_euclid
+    #[inline]
+    #[must_use]
+    pub fn rem_euclid(self, rhs: Self) -> Self {
+        Self::new(
+            self.x.rem_euclid(rhs.x),
+            self.y.rem_euclid(rhs.y),
+            self.z.rem_euclid(rhs.z),
+            self.w.rem_euclid(rhs.w),
+        )
+    }
+
+    /// Computes the [manhattan distance] between two points.
+    ///
+    /// # Overflow
+    /// This method may overflow if the result is greater than [`u32::MAX`].
+    ///
+    /// See also [`checked_manhattan_distance`][IVec4::checked_manhattan_distance].
+    ///
+    /// [manhattan distance]: https://en.wikipedia.org/wiki/Taxicab_geometry
+    #[inline]
+    #[must_use]
+    pub fn manhattan_distance(self, rhs: Self) -> u32 {
+        self.x.abs_diff(rhs.x)
+            + self.y.abs_diff(rhs.y)
+            + self.z.abs_diff(rhs.z)
+            + self.w.abs_diff(rhs.w)
+    }
+
+    /// Computes the [manhattan distance] between two points.
+    ///
+    /// This will returns [`None`] if the result is greater than [`u32::MAX`].
+    ///
+    /// [manhattan distance]: https://en.wikipedia.org/wiki/Taxicab_geometry
+    #[inline]
+    #[must_use]
+    pub fn checked_manhattan_distance(self, rhs: Self) -> Option<u32> {
+        let d = self.x.abs_diff(rhs.x);
+        let d = d.checked_add(self.y.abs_diff(rhs.y))?;
+        let d = d.checked_add(self.z.abs_diff(rhs.z))?;
+        d.checked_add(self.w.abs_diff(rhs.w))
+    }
+
+    /// Computes the [chebyshev distance] between two points.
+    ///
+    /// [chebyshev distance]: https://en.wikipedia.org/wiki/Chebyshev_distance
+    #[inline]
+    #[must_use]
+    pub fn chebyshev_distance(self, rhs: Self) -> u32 {
+        // Note: the compiler will eventually optimize out the loop
+        [
+            self.x.abs_diff(rhs.x),
+            self.y.abs_diff(rhs.y),
+            self.z.abs_diff(rhs.z),
+            self.w.abs_diff(rhs.w),
+        ]
+        .into_iter()
+        .max()
+        .unwrap()
+    }
+
+    /// Casts all elements of `self` to `f32`.
+    #[inline]
+    #[must_use]
+    pub fn as_vec4(self) -> crate::Vec4 {
+        crate::Vec4::new(self.x as f32, self.y as f32, self.z as f32, self.w as f32)
+    }
+
+    /// Casts all elements of `self` to `f64`.
+    #[inline]
+    #[must_use]
+    pub fn as_dvec4(self) -> crate::DVec4 {
+        crate::DVec4::new(self.x as f64, self.y as f64, self.z as f64, self.w as f64)
+    }
+
+    /// Casts all elements of `self` to `i8`.
+    #[inline]
+    #[must_use]
+    pub fn as_i8vec4(self) -> crate::I8Vec4 {
+        crate::I8Vec4::new(self.x as i8, self.y as i8, self.z as i8, self.w as i8)
+    }
+
+    /// Casts all elements of `self` to `u8`.
+    #[inline]
+    #[must_use]
+    pub fn as_u8vec4(self) -> crate::U8Vec4 {
+        crate::U8Vec4::new(self.x as u8, self.y as u8, self.z as u8, self.w as u8)
+    }
+
+    /// Casts all elements of `self` to `i16`.
+    #[inline]
+    #[must_use]
+    pub fn as_i16vec4(self) -> crate::I16Vec4 {
+        crate::I16Vec4::new(self.x as i16, self.y as i16, self.z as i16, self.w as i16)
+    }
+
+    /// Casts all elements of `self` to `u16`.
+    #[inline]
+    #[must_use]
+    pub fn as_u16vec4(self) -> crate::U16Vec4 {
+        crate::U16Vec4::new(self.x as u16, self.y as u16, self.z as u16, self.w as u16)
+    }
+
+    /// Casts all elements of `self` to `u32`.
+    #[inline]
+    #[must_use]
+    pub fn as_uvec4(self) -> crate::UVec4 {
+        crate::UVec4::new(self.x as u32, self.y as u32, self.z as u32, self.w as u32)
+    }
+
+    /// Casts all elements of `self` to `i64`.
+    #[inline]
+    #[must_use]
+    pub fn as_i64vec4(self) -> crate::I64Vec4 {
+        crate::I64Vec4::new(self.x as i64, self.y as i64, self.z as i64, self.w as i64)
+    }
+
+    /// Casts all elements of `self` to `u64`.
+    #[inline]
+    #[must_use]
+    pub fn as_u64vec4(self) -> crate::U64Vec4 {
+        crate::U64Vec4::new(self.x as u64, self.y as u64, self.z as u64, self.w as u64)
+    }
+
+    /// Casts all elements of `self` to `isize`.
+    #[inline]
+    #[must_use]
+    pub fn as_isizevec4(self) -> crate::ISizeVec4 {
+        crate::ISizeVec4::new(
+            self.x as isize,
+            self.y as isize,
+            self.z as isize,
+            self.w as isize,
+        )
+    }
+
+    /// Casts all elements of `self` to `usize`.
+    #[inline]
+    #[must_use]
+    pub fn as_usizevec4(self) -> crate::USizeVec4 {
+        crate::USizeVec4::new(
+            self.x as usize,
+            self.y as usize,
+            self.z as usize,
+            self.w as usize,
+        )
+    }
+
+    /// Returns a vector containing the wrapping addition of `self` and `rhs`.
+    ///
+    /// In other words this computes `Some([self.x + rhs.x, self.y + rhs.y, ..])` but returns `None` on any overflow.
+    #[inline]
+    #[must_use]
+    pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+        let (lhs, rhs) = (self.to_array(), rhs.to_array());
+
+        let v0 = match lhs[0].checked_add(rhs[0]) {
+            Some(v) => v,
+            None => return None,
+        };
+
+        let v1 = match lhs[1].checked_add(rhs[1]) {
+            Some(v) => v,
+            None => return None,
+        };
+
+        let v2 = match lhs[2].checked_add(rhs[2]) {
+            Some(v) => v,
+            None => return None,
+        };
+
+        let v3 = match lhs[3].checked_add(rhs[3]) {
+            Some(v) => v,
+            None => return None,
+        };
+
+        Some(Self::new(v0, v1, v2, v3))
+    }
+
+    /// Returns a vector containing the wrapping subtraction of `self` and `rhs`.
+    ///
+    /// In other words this computes `Some([self.x - rhs.x, self.y - rhs.y, ..])` but returns `None` on any overflow.
+    #[inline]
+    #[must_use]
+    pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+        let (lhs, rhs) = (self.to_array(), rhs.to_array());
+
+        let v0 = match lhs[0].checked_sub(rhs[0]) {
+            Some(v) => v,
+            None => return None,
+        };
+
+        let v1 = match lhs[1].checked_sub(rhs[1]) {
+            Some(v) => v,
+            None => return None,
+        };
+
+        let v2 = match lhs[2].checked_sub(rhs[2]) {
+            Some(v) => v,
+            None => return None,
+        };
+
+        let v3 = match lhs[3].checked_sub(rhs[3]) {
+            Some(v) => v,
+            None => return None,
+        };
+
+        Some(Self::new(v0, v1, v2, v3))
+    }
+
+    /// Returns a vector containing the wrapping multiplication of `self` and `rhs`.
+    ///
+    /// In other words this computes `Some([self.x * rhs.x, self.y * rhs.y, ..])` but returns `None` on any overflow.
+    #[inline]
+    #[must_use]
+    pub const fn checked_mul(self, rhs: Self) -> Option<Self> {
+        let (lhs, rhs) = (self.to_array(), rhs.to_array());
+
+        let v0 = match lhs[0].checked_mul(rhs[0]) {
+            Some(v) => v,
+            None => return None,
+        };
+
+        let v1 = match lhs[1].checked_mul(rhs[1]) {
+            Some(v) => v,
+            None => return None,
+        };
+
+        let v2 = match lhs[2].checked_mul(rhs[2]) {
+            Some(v) => v,
+            None => return None,
+        };
+
+        let v3 = match lhs[3].checked_mul(rhs[3]) {
+            Some(v) => v,
+            None => return None,
+        };
+
+        Some(Self::new(v0, v1, v2, v3))
+    }
+
+    /// Returns a vector containing the wrapping division of `self` and `rhs`.
+    ///
+    /// In other words this computes `Some([self.x / rhs.x, self.y / rhs.y, ..])` but returns `None` on any division by zero.
+    #[inline]
+    #[must_use]
+    pub const fn checked_div(self, rhs: Self) -> Option<Self> {
+        let (lhs, rhs) = (self.to_array(), rhs.to_array());
+
+        let v0 = match lhs[0].checked_div(rhs[0]) {
+            Some(v) => v,
+            None => return None,
+        };
+
+        let v1 = match lhs[1].checked_div(rhs[1]) {
+            Some(v) => v,
+            None => return None,
+        };
+
+        let v2 = match lhs[2].checked_div(rhs[2]) {
+            Some(v) => v,
+            None => return None,
+        };
+
+        let v3 = match lhs[3].checked_div(rhs[3]) {
+            Some(v) => v,
+            None => return None,
+        };
+
+        Some(Self::new(v0, v1, v2, v3))
+    }
+
+    /// Returns a vector containing the wrapping addition of `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x.wrapping_add(rhs.x), self.y.wrapping_add(rhs.y), ..]`.
+    #[inline]
+    #[must_use]
+    pub const fn wrapping_add(self, rhs: Self) -> Self {
+        let (lhs, rhs) = (self.to_array(), rhs.to_array());
+        Self::new(
+            lhs[0].wrapping_add(rhs[0]),
+            lhs[1].wrapping_add(rhs[1]),
+            lhs[2].wrapping_add(rhs[2]),
+            lhs[3].wrapping_add(rhs[3]),
+        )
+    }
+
+    /// Returns a vector containing the wrapping subtraction of `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x.wrapping_sub(rhs.x), self.y.wrapping_sub(rhs.y), ..]`.
+    #[inline]
+    #[must_use]
+    pub const fn wrapping_sub(self, rhs: Self) -> Self {
+        let (lhs, rhs) = (self.to_array(), rhs.to_array());
+        Self::new(
+            lhs[0].wrapping_sub(rhs[0]),
+            lhs[1].wrapping_sub(rhs[1]),
+            lhs[2].wrapping_sub(rhs[2]),
+            lhs[3].wrapping_sub(rhs[3]),
+        )
+    }
+
+    /// Returns a vector containing the wrapping multiplication of `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x.wrapping_mul(rhs.x), self.y.wrapping_mul(rhs.y), ..]`.
+    #[inline]
+    #[must_use]
+    pub const fn wrapping_mul(self, rhs: Self) -> Self {
+        let (lhs, rhs) = (self.to_array(), rhs.to_array());
+        Self::new(
+            lhs[0].wrapping_mul(rhs[0]),
+            lhs[1].wrapping_mul(rhs[1]),
+            lhs[2].wrapping_mul(rhs[2]),
+            lhs[3].wrapping_mul(rhs[3]),
+        )
+    }
+
+    /// Returns a vector containing the wrapping division of `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x.wrapping_div(rhs.x), self.y.wrapping_div(rhs.y), ..]`.
+    #[inline]
+    #[must_use]
+    pub const fn wrapping_div(self, rhs: Self) -> Self {
+        let (lhs, rhs) = (self.to_array(), rhs.to_array());
+        Self::new(
+            lhs[0].wrapping_div(rhs[0]),
+            lhs[1].wrapping_div(rhs[1]),
+            lhs[2].wrapping_div(rhs[2]),
+            lhs[3].wrapping_div(rhs[3]),
+        )
+    }
+
+    /// Returns a vector containing the saturating addition of `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x.saturating_add(rhs.x), self.y.saturating_add(rhs.y), ..]`.
+    #[inline]
+    #[must_use]
+    pub const fn saturating_add(self, rhs: Self) -> Self {
+        let (lhs, rhs) = (self.to_array(), rhs.to_array());
+        Self::new(
+            lhs[0].saturating_add(rhs[0]),
+            lhs[1].saturating_add(rhs[1]),
+            lhs[2].saturating_add(rhs[2]),
+            lhs[3].saturating_add(rhs[3]),
+        )
+    }
+
+    /// Returns a vector containing the saturating subtraction of `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x.saturating_sub(rhs.x), self.y.saturating_sub(rhs.y), ..]`.
+    #[inline]
+    #[must_use]
+    pub const fn saturating_sub(self, rhs: Self) -> Self {
+        let (lhs, rhs) = (self.to_array(), rhs.to_array());
+        Self::new(
+            lhs[0].saturating_sub(rhs[0]),
+            lhs[1].saturating_sub(rhs[1]),
+            lhs[2].saturating_sub(rhs[2]),
+            lhs[3].saturating_sub(rhs[3]),
+        )
+    }
+
+    /// Returns a vector containing the saturating multiplication of `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x.saturating_mul(rhs.x), self.y.saturating_mul(rhs.y), ..]`.
+    #[inline]
+    #[must_use]
+    pub const fn saturating_mul(self, rhs: Self) -> Self {
+        let (lhs, rhs) = (self.to_array(), rhs.to_array());
+        Self::new(
+            lhs[0].saturating_mul(rhs[0]),
+            lhs[1].saturating_mul(rhs[1]),
+            lhs[2].saturating_mul(rhs[2]),
+            lhs[3].saturating_mul(rhs[3]),
+        )
+    }
+
+    /// Returns a vector containing the saturating division of `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x.saturating_div(rhs.x), self.y.saturating_div(rhs.y), ..]`.
+    #[inline]
+    #[must_use]
+    pub const fn saturating_div(self, rhs: Self) -> Self {
+        let (lhs, rhs) = (self.to_array(), rhs.to_array());
+        Self::new(
+            lhs[0].saturating_div(rhs[0]),
+            lhs[1].saturating_div(rhs[1]),
+            lhs[2].saturating_div(rhs[2]),
+            lhs[3].saturating_div(rhs[3]),
+        )
+    }
+
+    /// Returns a vector containing the wrapping addition of `self` and unsigned vector `rhs`.
+    ///
+    /// In other words this computes `Some([self.x + rhs.x, self.y + rhs.y, ..])` but returns `None` on any overflow.
+    #[inline]
+    #[must_use]
+    pub const fn checked_add_unsigned(self, rhs: UVec4) -> Option<Self> {
+        let (lhs, rhs) = (self.to_array(), rhs.to_array());
+
+        let v0 = match lhs[0].checked_add_unsigned(rhs[0]) {
+            Some(v) => v,
+            None => return None,
+        };
+
+        let v1 = match lhs[1].checked_add_unsigned(rhs[1]) {
+            Some(v) => v,
+            None => return None,
+        };
+
+        let v2 = match lhs[2].checked_add_unsigned(rhs[2]) {
+            Some(v) => v,
+            None => return None,
+        };
+
+        let v3 = match lhs[3].checked_add_unsigned(rhs[3]) {
+            Some(v) => v,
+            None => return None,
+        };
+
+        Some(Self::new(v0, v1, v2, v3))
+    }
+
+    /// Returns a vector containing the wrapping subtraction of `self` and unsigned vector `rhs`.
+    ///
+    /// In other words this computes `Some([self.x - rhs.x, self.y - rhs.y, ..])` but returns `None` on any overflow.
+    #[inline]
+    #[must_use]
+    pub const fn checked_sub_unsigned(self, rhs: UVec4) -> Option<Self> {
+        let (lhs, rhs) = (self.to_array(), rhs.to_array());
+
+        let v0 = match lhs[0].checked_sub_unsigned(rhs[0]) {
+            Some(v) => v,
+            None => return None,
+        };
+
+        let v1 = match lhs[1].checked_sub_unsigned(rhs[1]) {
+            Some(v) => v,
+            None => return None,
+        };
+
+        let v2 = match lhs[2].checked_sub_unsigned(rhs[2]) {
+            Some(v) => v,
+            None => return None,
+        };
+
+        let v3 = match lhs[3].checked_sub_unsigned(rhs[3]) {
+            Some(v) => v,
+            None => return None,
+        };
+
+        Some(Self::new(v0, v1, v2, v3))
+    }
+
+    /// Returns a vector containing the wrapping addition of `self` and unsigned vector `rhs`.
+    ///
+    /// In other words this computes `[self.x.wrapping_add_unsigned(rhs.x), self.y.wrapping_add_unsigned(rhs.y), ..]`.
+    #[inline]
+    #[must_use]
+    pub const fn wrapping_add_unsigned(self, rhs: UVec4) -> Self {
+        let (lhs, rhs) = (self.to_array(), rhs.to_array());
+        Self::new(
+            lhs[0].wrapping_add_unsigned(rhs[0]),
+            lhs[1].wrapping_add_unsigned(rhs[1]),
+            lhs[2].wrapping_add_unsigned(rhs[2]),
+            lhs[3].wrapping_add_unsigned(rhs[3]),
+        )
+    }
+
+    /// Returns a vector containing the wrapping subtraction of `self` and unsigned vector `rhs`.
+    ///
+    /// In other words this computes `[self.x.wrapping_sub_unsigned(rhs.x), self.y.wrapping_sub_unsigned(rhs.y), ..]`.
+    #[inline]
+    #[must_use]
+    pub const fn wrapping_sub_unsigned(self, rhs: UVec4) -> Self {
+        let (lhs, rhs) = (self.to_array(), rhs.to_array());
+        Self::new(
+            lhs[0].wrapping_sub_unsigned(rhs[0]),
+            lhs[1].wrapping_sub_unsigned(rhs[1]),
+            lhs[2].wrapping_sub_unsigned(rhs[2]),
+            lhs[3].wrapping_sub_unsigned(rhs[3]),
+        )
+    }
+
+    // Returns a vector containing the saturating addition of `self` and unsigned vector `rhs`.
+    ///
+    /// In other words this computes `[self.x.saturating_add_unsigned(rhs.x), self.y.saturating_add_unsigned(rhs.y), ..]`.
+    #[inline]
+    #[must_use]
+    pub const fn saturating_add_unsigned(self, rhs: UVec4) -> Self {
+        let (lhs, rhs) = (self.to_array(), rhs.to_array());
+        Self::new(
+            lhs[0].saturating_add_unsigned(rhs[0]),
+            lhs[1].saturating_add_unsigned(rhs[1]),
+            lhs[2].saturating_add_unsigned(rhs[2]),
+            lhs[3].saturating_add_unsigned(rhs[3]),
+        )
+    }
+
+    /// Returns a vector containing the saturating subtraction of `self` and unsigned vector `rhs`.
+    ///
+    /// In other words this computes `[self.x.saturating_sub_unsigned(rhs.x), self.y.saturating_sub_unsigned(rhs.y), ..]`.
+    #[inline]
+    #[must_use]
+    pub const fn saturating_sub_unsigned(self, rhs: UVec4) -> Self {
+        let (lhs, rhs) = (self.to_array(), rhs.to_array());
+        Self::new(
+            lhs[0].saturating_sub_unsigned(rhs[0]),
+            lhs[1].saturating_sub_unsigned(rhs[1]),
+            lhs[2].saturating_sub_unsigned(rhs[2]),
+            lhs[3].saturating_sub_unsigned(rhs[3]),
+        )
+    }
+}
+
+impl Default for IVec4 {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl PartialEq for IVec4 {
+    #[inline]
+    fn eq(&self, rhs: &Self) -> bool {
+        self.cmpeq(*rhs).all()
+    }
+}
+
+impl Eq for IVec4 {}
+
+impl core::hash::Hash for IVec4 {
+    #[inline]
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.x.hash(state);
+        self.y.hash(state);
+        self.z.hash(state);
+        self.w.hash(state);
+    }
+}
+
+impl Div for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: Self) -> Self {
+        Self::new(
+            self.x.div(rhs.x),
+            self.y.div(rhs.y),
+            self.z.div(rhs.z),
+            self.w.div(rhs.w),
+        )
+    }
+}
+
+impl Div<&Self> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: &Self) -> Self {
+        self.div(*rhs)
+    }
+}
+
+impl Div<&IVec4> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn div(self, rhs: &IVec4) -> IVec4 {
+        (*self).div(*rhs)
+    }
+}
+
+impl Div<IVec4> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn div(self, rhs: IVec4) -> IVec4 {
+        (*self).div(rhs)
+    }
+}
+
+impl DivAssign for IVec4 {
+    #[inline]
+    fn div_assign(&mut self, rhs: Self) {
+        self.x.div_assign(rhs.x);
+        self.y.div_assign(rhs.y);
+        self.z.div_assign(rhs.z);
+        self.w.div_assign(rhs.w);
+    }
+}
+
+impl DivAssign<&Self> for IVec4 {
+    #[inline]
+    fn div_assign(&mut self, rhs: &Self) {
+        self.div_assign(*rhs);
+    }
+}
+
+impl Div<i32> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: i32) -> Self {
+        Self::new(
+            self.x.div(rhs),
+            self.y.div(rhs),
+            self.z.div(rhs),
+            self.w.div(rhs),
+        )
+    }
+}
+
+impl Div<&i32> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: &i32) -> Self {
+        self.div(*rhs)
+    }
+}
+
+impl Div<&i32> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn div(self, rhs: &i32) -> IVec4 {
+        (*self).div(*rhs)
+    }
+}
+
+impl Div<i32> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn div(self, rhs: i32) -> IVec4 {
+        (*self).div(rhs)
+    }
+}
+
+impl DivAssign<i32> for IVec4 {
+    #[inline]
+    fn div_assign(&mut self, rhs: i32) {
+        self.x.div_assign(rhs);
+        self.y.div_assign(rhs);
+        self.z.div_assign(rhs);
+        self.w.div_assign(rhs);
+    }
+}
+
+impl DivAssign<&i32> for IVec4 {
+    #[inline]
+    fn div_assign(&mut self, rhs: &i32) {
+        self.div_assign(*rhs);
+    }
+}
+
+impl Div<IVec4> for i32 {
+    type Output = IVec4;
+    #[inline]
+    fn div(self, rhs: IVec4) -> IVec4 {
+        IVec4::new(
+            self.div(rhs.x),
+            self.div(rhs.y),
+            self.div(rhs.z),
+            self.div(rhs.w),
+        )
+    }
+}
+
+impl Div<&IVec4> for i32 {
+    type Output = IVec4;
+    #[inline]
+    fn div(self, rhs: &IVec4) -> IVec4 {
+        self.div(*rhs)
+    }
+}
+
+impl Div<&IVec4> for &i32 {
+    type Output = IVec4;
+    #[inline]
+    fn div(self, rhs: &IVec4) -> IVec4 {
+        (*self).div(*rhs)
+    }
+}
+
+impl Div<IVec4> for &i32 {
+    type Output = IVec4;
+    #[inline]
+    fn div(self, rhs: IVec4) -> IVec4 {
+        (*self).div(rhs)
+    }
+}
+
+impl Mul for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        // Lanes that overflow, or might overflow for multiplication, are redone with the scalar
+        // operators so overflow follows the `overflow-checks` profile setting.
+        match unsafe { m128i_checked_mul_epi32(self.0, rhs.0) } {
+            Some(v) => Self(v),
+            None => Self::new(
+                self.x.mul(rhs.x),
+                self.y.mul(rhs.y),
+                self.z.mul(rhs.z),
+                self.w.mul(rhs.w),
+            ),
+        }
+    }
+}
+
+impl Mul<&Self> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: &Self) -> Self {
+        self.mul(*rhs)
+    }
+}
+
+impl Mul<&IVec4> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn mul(self, rhs: &IVec4) -> IVec4 {
+        (*self).mul(*rhs)
+    }
+}
+
+impl Mul<IVec4> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn mul(self, rhs: IVec4) -> IVec4 {
+        (*self).mul(rhs)
+    }
+}
+
+impl MulAssign for IVec4 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.mul(rhs);
+    }
+}
+
+impl MulAssign<&Self> for IVec4 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: &Self) {
+        self.mul_assign(*rhs);
+    }
+}
+
+impl Mul<i32> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: i32) -> Self {
+        match unsafe { m128i_checked_mul_epi32(self.0, Self::splat(rhs).0) } {
+            Some(v) => Self(v),
+            None => Self::new(
+                self.x.mul(rhs),
+                self.y.mul(rhs),
+                self.z.mul(rhs),
+                self.w.mul(rhs),
+            ),
+        }
+    }
+}
+
+impl Mul<&i32> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: &i32) -> Self {
+        self.mul(*rhs)
+    }
+}
+
+impl Mul<&i32> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn mul(self, rhs: &i32) -> IVec4 {
+        (*self).mul(*rhs)
+    }
+}
+
+impl Mul<i32> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn mul(self, rhs: i32) -> IVec4 {
+        (*self).mul(rhs)
+    }
+}
+
+impl MulAssign<i32> for IVec4 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: i32) {
+        *self = self.mul(rhs);
+    }
+}
+
+impl MulAssign<&i32> for IVec4 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: &i32) {
+        self.mul_assign(*rhs);
+    }
+}
+
+impl Mul<IVec4> for i32 {
+    type Output = IVec4;
+    #[inline]
+    fn mul(self, rhs: IVec4) -> IVec4 {
+        match unsafe { m128i_checked_mul_epi32(IVec4::splat(self).0, rhs.0) } {
+            Some(v) => IVec4(v),
+            None => IVec4::new(
+                self.mul(rhs.x),
+                self.mul(rhs.y),
+                self.mul(rhs.z),
+                self.mul(rhs.w),
+            ),
+        }
+    }
+}
+
+impl Mul<&IVec4> for i32 {
+    type Output = IVec4;
+    #[inline]
+    fn mul(self, rhs: &IVec4) -> IVec4 {
+        self.mul(*rhs)
+    }
+}
+
+impl Mul<&IVec4> for &i32 {
+    type Output = IVec4;
+    #[inline]
+    fn mul(self, rhs: &IVec4) -> IVec4 {
+        (*self).mul(*rhs)
+    }
+}
+
+impl Mul<IVec4> for &i32 {
+    type Output = IVec4;
+    #[inline]
+    fn mul(self, rhs: IVec4) -> IVec4 {
+        (*self).mul(rhs)
+    }
+}
+
+impl Add for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        match unsafe { m128i_checked_add_epi32(self.0, rhs.0) } {
+            Some(v) => Self(v),
+            None => Self::new(
+                self.x.add(rhs.x),
+                self.y.add(rhs.y),
+                self.z.add(rhs.z),
+                self.w.add(rhs.w),
+            ),
+        }
+    }
+}
+
+impl Add<&Self> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: &Self) -> Self {
+        self.add(*rhs)
+    }
+}
+
+impl Add<&IVec4> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn add(self, rhs: &IVec4) -> IVec4 {
+        (*self).add(*rhs)
+    }
+}
+
+impl Add<IVec4> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn add(self, rhs: IVec4) -> IVec4 {
+        (*self).add(rhs)
+    }
+}
+
+impl AddAssign for IVec4 {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = self.add(rhs);
+    }
+}
+
+impl AddAssign<&Self> for IVec4 {
+    #[inline]
+    fn add_assign(&mut self, rhs: &Self) {
+        self.add_assign(*rhs);
+    }
+}
+
+impl Add<i32> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: i32) -> Self {
+        match unsafe { m128i_checked_add_epi32(self.0, Self::splat(rhs).0) } {
+            Some(v) => Self(v),
+            None => Self::new(
+                self.x.add(rhs),
+                self.y.add(rhs),
+                self.z.add(rhs),
+                self.w.add(rhs),
+            ),
+        }
+    }
+}
+
+impl Add<&i32> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: &i32) -> Self {
+        self.add(*rhs)
+    }
+}
+
+impl Add<&i32> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn add(self, rhs: &i32) -> IVec4 {
+        (*self).add(*rhs)
+    }
+}
+
+impl Add<i32> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn add(self, rhs: i32) -> IVec4 {
+        (*self).add(rhs)
+    }
+}
+
+impl AddAssign<i32> for IVec4 {
+    #[inline]
+    fn add_assign(&mut self, rhs: i32) {
+        *self = self.add(rhs);
+    }
+}
+
+impl AddAssign<&i32> for IVec4 {
+    #[inline]
+    fn add_assign(&mut self, rhs: &i32) {
+        self.add_assign(*rhs);
+    }
+}
+
+impl Add<IVec4> for i32 {
+    type Output = IVec4;
+    #[inline]
+    fn add(self, rhs: IVec4) -> IVec4 {
+        match unsafe { m128i_checked_add_epi32(IVec4::splat(self).0, rhs.0) } {
+            Some(v) => IVec4(v),
+            None => IVec4::new(
+                self.add(rhs.x),
+                self.add(rhs.y),
+                self.add(rhs.z),
+                self.add(rhs.w),
+            ),
+        }
+    }
+}
+
+impl Add<&IVec4> for i32 {
+    type Output = IVec4;
+    #[inline]
+    fn add(self, rhs: &IVec4) -> IVec4 {
+        self.add(*rhs)
+    }
+}
+
+impl Add<&IVec4> for &i32 {
+    type Output = IVec4;
+    #[inline]
+    fn add(self, rhs: &IVec4) -> IVec4 {
+        (*self).add(*rhs)
+    }
+}
+
+impl Add<IVec4> for &i32 {
+    type Output = IVec4;
+    #[inline]
+    fn add(self, rhs: IVec4) -> IVec4 {
+        (*self).add(rhs)
+    }
+}
+
+impl Sub for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        match unsafe { m128i_checked_sub_epi32(self.0, rhs.0) } {
+            Some(v) => Self(v),
+            None => Self::new(
+                self.x.sub(rhs.x),
+                self.y.sub(rhs.y),
+                self.z.sub(rhs.z),
+                self.w.sub(rhs.w),
+            ),
+        }
+    }
+}
+
+impl Sub<&Self> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: &Self) -> Self {
+        self.sub(*rhs)
+    }
+}
+
+impl Sub<&IVec4> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn sub(self, rhs: &IVec4) -> IVec4 {
+        (*self).sub(*rhs)
+    }
+}
+
+impl Sub<IVec4> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn sub(self, rhs: IVec4) -> IVec4 {
+        (*self).sub(rhs)
+    }
+}
+
+impl SubAssign for IVec4 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = self.sub(rhs);
+    }
+}
+
+impl SubAssign<&Self> for IVec4 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: &Self) {
+        self.sub_assign(*rhs);
+    }
+}
+
+impl Sub<i32> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: i32) -> Self {
+        match unsafe { m128i_checked_sub_epi32(self.0, Self::splat(rhs).0) } {
+            Some(v) => Self(v),
+            None => Self::new(
+                self.x.sub(rhs),
+                self.y.sub(rhs),
+                self.z.sub(rhs),
+                self.w.sub(rhs),
+            ),
+        }
+    }
+}
+
+impl Sub<&i32> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: &i32) -> Self {
+        self.sub(*rhs)
+    }
+}
+
+impl Sub<&i32> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn sub(self, rhs: &i32) -> IVec4 {
+        (*self).sub(*rhs)
+    }
+}
+
+impl Sub<i32> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn sub(self, rhs: i32) -> IVec4 {
+        (*self).sub(rhs)
+    }
+}
+
+impl SubAssign<i32> for IVec4 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: i32) {
+        *self = self.sub(rhs);
+    }
+}
+
+impl SubAssign<&i32> for IVec4 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: &i32) {
+        self.sub_assign(*rhs);
+    }
+}
+
+impl Sub<IVec4> for i32 {
+    type Output = IVec4;
+    #[inline]
+    fn sub(self, rhs: IVec4) -> IVec4 {
+        match unsafe { m128i_checked_sub_epi32(IVec4::splat(self).0, rhs.0) } {
+            Some(v) => IVec4(v),
+            None => IVec4::new(
+                self.sub(rhs.x),
+                self.sub(rhs.y),
+                self.sub(rhs.z),
+                self.sub(rhs.w),
+            ),
+        }
+    }
+}
+
+impl Sub<&IVec4> for i32 {
+    type Output = IVec4;
+    #[inline]
+    fn sub(self, rhs: &IVec4) -> IVec4 {
+        self.sub(*rhs)
+    }
+}
+
+impl Sub<&IVec4> for &i32 {
+    type Output = IVec4;
+    #[inline]
+    fn sub(self, rhs: &IVec4) -> IVec4 {
+        (*self).sub(*rhs)
+    }
+}
+
+impl Sub<IVec4> for &i32 {
+    type Output = IVec4;
+    #[inline]
+    fn sub(self, rhs: IVec4) -> IVec4 {
+        (*self).sub(rhs)
+    }
+}
+
+impl Rem for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn rem(self, rhs: Self) -> Self {
+        Self::new(
+            self.x.rem(rhs.x),
+            self.y.rem(rhs.y),
+            self.z.rem(rhs.z),
+            self.w.rem(rhs.w),
+        )
+    }
+}
+
+impl Rem<&Self> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn rem(self, rhs: &Self) -> Self {
+        self.rem(*rhs)
+    }
+}
+
+impl Rem<&IVec4> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn rem(self, rhs: &IVec4) -> IVec4 {
+        (*self).rem(*rhs)
+    }
+}
+
+impl Rem<IVec4> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn rem(self, rhs: IVec4) -> IVec4 {
+        (*self).rem(rhs)
+    }
+}
+
+impl RemAssign for IVec4 {
+    #[inline]
+    fn rem_assign(&mut self, rhs: Self) {
+        *self = self.rem(rhs);
+    }
+}
+
+impl RemAssign<&Self> for IVec4 {
+    #[inline]
+    fn rem_assign(&mut self, rhs: &Self) {
+        self.rem_assign(*rhs);
+    }
+}
+
+impl Rem<i32> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn rem(self, rhs: i32) -> Self {
+        self.rem(Self::splat(rhs))
+    }
+}
+
+impl Rem<&i32> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn rem(self, rhs: &i32) -> Self {
+        self.rem(*rhs)
+    }
+}
+
+impl Rem<&i32> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn rem(self, rhs: &i32) -> IVec4 {
+        (*self).rem(*rhs)
+    }
+}
+
+impl Rem<i32> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn rem(self, rhs: i32) -> IVec4 {
+        (*self).rem(rhs)
+    }
+}
+
+impl RemAssign<i32> for IVec4 {
+    #[inline]
+    fn rem_assign(&mut self, rhs: i32) {
+        *self = self.rem(Self::splat(rhs));
+    }
+}
+
+impl RemAssign<&i32> for IVec4 {
+    #[inline]
+    fn rem_assign(&mut self, rhs: &i32) {
+        self.rem_assign(*rhs);
+    }
+}
+
+impl Rem<IVec4> for i32 {
+    type Output = IVec4;
+    #[inline]
+    fn rem(self, rhs: IVec4) -> IVec4 {
+        IVec4::splat(self).rem(rhs)
+    }
+}
+
+impl Rem<&IVec4> for i32 {
+    type Output = IVec4;
+    #[inline]
+    fn rem(self, rhs: &IVec4) -> IVec4 {
+        self.rem(*rhs)
+    }
+}
+
+impl Rem<&IVec4> for &i32 {
+    type Output = IVec4;
+    #[inline]
+    fn rem(self, rhs: &IVec4) -> IVec4 {
+        (*self).rem(*rhs)
+    }
+}
+
+impl Rem<IVec4> for &i32 {
+    type Output = IVec4;
+    #[inline]
+    fn rem(self, rhs: IVec4) -> IVec4 {
+        (*self).rem(rhs)
+    }
+}
+
+impl AsRef<[i32; 4]> for IVec4 {
+    #[inline]
+    fn as_ref(&self) -> &[i32; 4] {
+        unsafe { &*(self as *const Self as *const [i32; 4]) }
+    }
+}
+
+impl AsMut<[i32; 4]> for IVec4 {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [i32; 4] {
+        unsafe { &mut *(self as *mut Self as *mut [i32; 4]) }
+    }
+}
+
+impl Sum for IVec4 {
+    #[inline]
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        iter.fold(Self::ZERO, Self::add)
+    }
+}
+
+impl<'a> Sum<&'a Self> for IVec4 {
+    #[inline]
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.fold(Self::ZERO, |a, &b| Self::add(a, b))
+    }
+}
+
+impl Product for IVec4 {
+    #[inline]
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        iter.fold(Self::ONE, Self::mul)
+    }
+}
+
+impl<'a> Product<&'a Self> for IVec4 {
+    #[inline]
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.fold(Self::ONE, |a, &b| Self::mul(a, b))
+    }
+}
+
+impl Neg for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self::new(self.x.neg(), self.y.neg(), self.z.neg(), self.w.neg())
+    }
+}
+
+impl Neg for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn neg(self) -> IVec4 {
+        (*self).neg()
+    }
+}
+
+impl Not for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn not(self) -> Self {
+        Self(unsafe { m128i_not(self.0) })
+    }
+}
+
+impl Not for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn not(self) -> IVec4 {
+        (*self).not()
+    }
+}
+
+impl BitAnd for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn bitand(self, rhs: Self) -> Self::Output {
+        Self(unsafe { _mm_and_si128(self.0, rhs.0) })
+    }
+}
+
+impl BitAnd<&Self> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn bitand(self, rhs: &Self) -> Self {
+        self.bitand(*rhs)
+    }
+}
+
+impl BitAnd<&IVec4> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn bitand(self, rhs: &IVec4) -> IVec4 {
+        (*self).bitand(*rhs)
+    }
+}
+
+impl BitAnd<IVec4> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn bitand(self, rhs: IVec4) -> IVec4 {
+        (*self).bitand(rhs)
+    }
+}
+
+impl BitAndAssign for IVec4 {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: Self) {
+        *self = self.bitand(rhs);
+    }
+}
+
+impl BitAndAssign<&Self> for IVec4 {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: &Self) {
+        self.bitand_assign(*rhs);
+    }
+}
+
+impl BitOr for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self(unsafe { _mm_or_si128(self.0, rhs.0) })
+    }
+}
+
+impl BitOr<&Self> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn bitor(self, rhs: &Self) -> Self {
+        self.bitor(*rhs)
+    }
+}
+
+impl BitOr<&IVec4> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn bitor(self, rhs: &IVec4) -> IVec4 {
+        (*self).bitor(*rhs)
+    }
+}
+
+impl BitOr<IVec4> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn bitor(self, rhs: IVec4) -> IVec4 {
+        (*self).bitor(rhs)
+    }
+}
+
+impl BitOrAssign for IVec4 {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = self.bitor(rhs);
+    }
+}
+
+impl BitOrAssign<&Self> for IVec4 {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: &Self) {
+        self.bitor_assign(*rhs);
+    }
+}
+
+impl BitXor for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        Self(unsafe { _mm_xor_si128(self.0, rhs.0) })
+    }
+}
+
+impl BitXor<&Self> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn bitxor(self, rhs: &Self) -> Self {
+        self.bitxor(*rhs)
+    }
+}
+
+impl BitXor<&IVec4> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn bitxor(self, rhs: &IVec4) -> IVec4 {
+        (*self).bitxor(*rhs)
+    }
+}
+
+impl BitXor<IVec4> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn bitxor(self, rhs: IVec4) -> IVec4 {
+        (*self).bitxor(rhs)
+    }
+}
+
+impl BitXorAssign for IVec4 {
+    #[inline]
+    fn bitxor_assign(&mut self, rhs: Self) {
+        *self = self.bitxor(rhs);
+    }
+}
+
+impl BitXorAssign<&Self> for IVec4 {
+    #[inline]
+    fn bitxor_assign(&mut self, rhs: &Self) {
+        self.bitxor_assign(*rhs);
+    }
+}
+
+impl BitAnd<i32> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn bitand(self, rhs: i32) -> Self::Output {
+        self.bitand(Self::splat(rhs))
+    }
+}
+
+impl BitAnd<&i32> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn bitand(self, rhs: &i32) -> Self {
+        self.bitand(*rhs)
+    }
+}
+
+impl BitAnd<&i32> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn bitand(self, rhs: &i32) -> IVec4 {
+        (*self).bitand(*rhs)
+    }
+}
+
+impl BitAnd<i32> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn bitand(self, rhs: i32) -> IVec4 {
+        (*self).bitand(rhs)
+    }
+}
+
+impl BitAndAssign<i32> for IVec4 {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: i32) {
+        *self = self.bitand(rhs);
+    }
+}
+
+impl BitAndAssign<&i32> for IVec4 {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: &i32) {
+        self.bitand_assign(*rhs);
+    }
+}
+
+impl BitOr<i32> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn bitor(self, rhs: i32) -> Self::Output {
+        self.bitor(Self::splat(rhs))
+    }
+}
+
+impl BitOr<&i32> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn bitor(self, rhs: &i32) -> Self {
+        self.bitor(*rhs)
+    }
+}
+
+impl BitOr<&i32> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn bitor(self, rhs: &i32) -> IVec4 {
+        (*self).bitor(*rhs)
+    }
+}
+
+impl BitOr<i32> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn bitor(self, rhs: i32) -> IVec4 {
+        (*self).bitor(rhs)
+    }
+}
+
+impl BitOrAssign<i32> for IVec4 {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: i32) {
+        *self = self.bitor(rhs);
+    }
+}
+
+impl BitOrAssign<&i32> for IVec4 {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: &i32) {
+        self.bitor_assign(*rhs);
+    }
+}
+
+impl BitXor<i32> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn bitxor(self, rhs: i32) -> Self::Output {
+        self.bitxor(Self::splat(rhs))
+    }
+}
+
+impl BitXor<&i32> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn bitxor(self, rhs: &i32) -> Self {
+        self.bitxor(*rhs)
+    }
+}
+
+impl BitXor<&i32> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn bitxor(self, rhs: &i32) -> IVec4 {
+        (*self).bitxor(*rhs)
+    }
+}
+
+impl BitXor<i32> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn bitxor(self, rhs: i32) -> IVec4 {
+        (*self).bitxor(rhs)
+    }
+}
+
+impl BitXorAssign<i32> for IVec4 {
+    #[inline]
+    fn bitxor_assign(&mut self, rhs: i32) {
+        *self = self.bitxor(rhs);
+    }
+}
+
+impl BitXorAssign<&i32> for IVec4 {
+    #[inline]
+    fn bitxor_assign(&mut self, rhs: &i32) {
+        self.bitxor_assign(*rhs);
+    }
+}
+
+impl Shl<i8> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn shl(self, rhs: i8) -> Self::Output {
+        // Counts outside of `0..32` overflow, they are shifted lane by lane with the
+        // scalar operators so overflow follows the `overflow-checks` profile setting.
+        if (0..32).contains(&rhs) {
+            let count = rhs as i32;
+            Self(unsafe { _mm_sll_epi32(self.0, _mm_cvtsi32_si128(count)) })
+        } else {
+            Self::new(
+                self.x.shl(rhs),
+                self.y.shl(rhs),
+                self.z.shl(rhs),
+                self.w.shl(rhs),
+            )
+        }
+    }
+}
+
+impl Shl<&i8> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn shl(self, rhs: &i8) -> Self {
+        self.shl(*rhs)
+    }
+}
+
+impl Shl<&i8> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn shl(self, rhs: &i8) -> IVec4 {
+        (*self).shl(*rhs)
+    }
+}
+
+impl Shl<i8> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn shl(self, rhs: i8) -> IVec4 {
+        (*self).shl(rhs)
+    }
+}
+
+impl ShlAssign<i8> for IVec4 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: i8) {
+        *self = self.shl(rhs);
+    }
+}
+
+impl ShlAssign<&i8> for IVec4 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: &i8) {
+        self.shl_assign(*rhs);
+    }
+}
+
+impl Shr<i8> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn shr(self, rhs: i8) -> Self::Output {
+        if (0..32).contains(&rhs) {
+            let count = rhs as i32;
+            Self(unsafe { _mm_sra_epi32(self.0, _mm_cvtsi32_si128(count)) })
+        } else {
+            Self::new(
+                self.x.shr(rhs),
+                self.y.shr(rhs),
+                self.z.shr(rhs),
+                self.w.shr(rhs),
+            )
+        }
+    }
+}
+
+impl Shr<&i8> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn shr(self, rhs: &i8) -> Self {
+        self.shr(*rhs)
+    }
+}
+
+impl Shr<&i8> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn shr(self, rhs: &i8) -> IVec4 {
+        (*self).shr(*rhs)
+    }
+}
+
+impl Shr<i8> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn shr(self, rhs: i8) -> IVec4 {
+        (*self).shr(rhs)
+    }
+}
+
+impl ShrAssign<i8> for IVec4 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: i8) {
+        *self = self.shr(rhs);
+    }
+}
+
+impl ShrAssign<&i8> for IVec4 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: &i8) {
+        self.shr_assign(*rhs);
+    }
+}
+
+impl Shl<i16> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn shl(self, rhs: i16) -> Self::Output {
+        if (0..32).contains(&rhs) {
+            let count = rhs as i32;
+            Self(unsafe { _mm_sll_epi32(self.0, _mm_cvtsi32_si128(count)) })
+        } else {
+            Self::new(
+                self.x.shl(rhs),
+                self.y.shl(rhs),
+                self.z.shl(rhs),
+                self.w.shl(rhs),
+            )
+        }
+    }
+}
+
+impl Shl<&i16> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn shl(self, rhs: &i16) -> Self {
+        self.shl(*rhs)
+    }
+}
+
+impl Shl<&i16> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn shl(self, rhs: &i16) -> IVec4 {
+        (*self).shl(*rhs)
+    }
+}
+
+impl Shl<i16> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn shl(self, rhs: i16) -> IVec4 {
+        (*self).shl(rhs)
+    }
+}
+
+impl ShlAssign<i16> for IVec4 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: i16) {
+        *self = self.shl(rhs);
+    }
+}
+
+impl ShlAssign<&i16> for IVec4 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: &i16) {
+        self.shl_assign(*rhs);
+    }
+}
+
+impl Shr<i16> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn shr(self, rhs: i16) -> Self::Output {
+        if (0..32).contains(&rhs) {
+            let count = rhs as i32;
+            Self(unsafe { _mm_sra_epi32(self.0, _mm_cvtsi32_si128(count)) })
+        } else {
+            Self::new(
+                self.x.shr(rhs),
+                self.y.shr(rhs),
+                self.z.shr(rhs),
+                self.w.shr(rhs),
+            )
+        }
+    }
+}
+
+impl Shr<&i16> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn shr(self, rhs: &i16) -> Self {
+        self.shr(*rhs)
+    }
+}
+
+impl Shr<&i16> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn shr(self, rhs: &i16) -> IVec4 {
+        (*self).shr(*rhs)
+    }
+}
+
+impl Shr<i16> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn shr(self, rhs: i16) -> IVec4 {
+        (*self).shr(rhs)
+    }
+}
+
+impl ShrAssign<i16> for IVec4 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: i16) {
+        *self = self.shr(rhs);
+    }
+}
+
+impl ShrAssign<&i16> for IVec4 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: &i16) {
+        self.shr_assign(*rhs);
+    }
+}
+
+impl Shl<i32> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn shl(self, rhs: i32) -> Self::Output {
+        if (0..32).contains(&rhs) {
+            let count = rhs;
+            Self(unsafe { _mm_sll_epi32(self.0, _mm_cvtsi32_si128(count)) })
+        } else {
+            Self::new(
+                self.x.shl(rhs),
+                self.y.shl(rhs),
+                self.z.shl(rhs),
+                self.w.shl(rhs),
+            )
+        }
+    }
+}
+
+impl Shl<&i32> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn shl(self, rhs: &i32) -> Self {
+        self.shl(*rhs)
+    }
+}
+
+impl Shl<&i32> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn shl(self, rhs: &i32) -> IVec4 {
+        (*self).shl(*rhs)
+    }
+}
+
+impl Shl<i32> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn shl(self, rhs: i32) -> IVec4 {
+        (*self).shl(rhs)
+    }
+}
+
+impl ShlAssign<i32> for IVec4 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: i32) {
+        *self = self.shl(rhs);
+    }
+}
+
+impl ShlAssign<&i32> for IVec4 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: &i32) {
+        self.shl_assign(*rhs);
+    }
+}
+
+impl Shr<i32> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn shr(self, rhs: i32) -> Self::Output {
+        if (0..32).contains(&rhs) {
+            let count = rhs;
+            Self(unsafe { _mm_sra_epi32(self.0, _mm_cvtsi32_si128(count)) })
+        } else {
+            Self::new(
+                self.x.shr(rhs),
+                self.y.shr(rhs),
+                self.z.shr(rhs),
+                self.w.shr(rhs),
+            )
+        }
+    }
+}
+
+impl Shr<&i32> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn shr(self, rhs: &i32) -> Self {
+        self.shr(*rhs)
+    }
+}
+
+impl Shr<&i32> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn shr(self, rhs: &i32) -> IVec4 {
+        (*self).shr(*rhs)
+    }
+}
+
+impl Shr<i32> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn shr(self, rhs: i32) -> IVec4 {
+        (*self).shr(rhs)
+    }
+}
+
+impl ShrAssign<i32> for IVec4 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: i32) {
+        *self = self.shr(rhs);
+    }
+}
+
+impl ShrAssign<&i32> for IVec4 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: &i32) {
+        self.shr_assign(*rhs);
+    }
+}
+
+impl Shl<i64> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn shl(self, rhs: i64) -> Self::Output {
+        if (0..32).contains(&rhs) {
+            let count = rhs as i32;
+            Self(unsafe { _mm_sll_epi32(self.0, _mm_cvtsi32_si128(count)) })
+        } else {
+            Self::new(
+                self.x.shl(rhs),
+                self.y.shl(rhs),
+                self.z.shl(rhs),
+                self.w.shl(rhs),
+            )
+        }
+    }
+}
+
+impl Shl<&i64> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn shl(self, rhs: &i64) -> Self {
+        self.shl(*rhs)
+    }
+}
+
+impl Shl<&i64> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn shl(self, rhs: &i64) -> IVec4 {
+        (*self).shl(*rhs)
+    }
+}
+
+impl Shl<i64> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn shl(self, rhs: i64) -> IVec4 {
+        (*self).shl(rhs)
+    }
+}
+
+impl ShlAssign<i64> for IVec4 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: i64) {
+        *self = self.shl(rhs);
+    }
+}
+
+impl ShlAssign<&i64> for IVec4 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: &i64) {
+        self.shl_assign(*rhs);
+    }
+}
+
+impl Shr<i64> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn shr(self, rhs: i64) -> Self::Output {
+        if (0..32).contains(&rhs) {
+            let count = rhs as i32;
+            Self(unsafe { _mm_sra_epi32(self.0, _mm_cvtsi32_si128(count)) })
+        } else {
+            Self::new(
+                self.x.shr(rhs),
+                self.y.shr(rhs),
+                self.z.shr(rhs),
+                self.w.shr(rhs),
+            )
+        }
+    }
+}
+
+impl Shr<&i64> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn shr(self, rhs: &i64) -> Self {
+        self.shr(*rhs)
+    }
+}
+
+impl Shr<&i64> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn shr(self, rhs: &i64) -> IVec4 {
+        (*self).shr(*rhs)
+    }
+}
+
+impl Shr<i64> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn shr(self, rhs: i64) -> IVec4 {
+        (*self).shr(rhs)
+    }
+}
+
+impl ShrAssign<i64> for IVec4 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: i64) {
+        *self = self.shr(rhs);
+    }
+}
+
+impl ShrAssign<&i64> for IVec4 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: &i64) {
+        self.shr_assign(*rhs);
+    }
+}
+
+impl Shl<u8> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn shl(self, rhs: u8) -> Self::Output {
+        if (0..32).contains(&rhs) {
+            let count = rhs as i32;
+            Self(unsafe { _mm_sll_epi32(self.0, _mm_cvtsi32_si128(count)) })
+        } else {
+            Self::new(
+                self.x.shl(rhs),
+                self.y.shl(rhs),
+                self.z.shl(rhs),
+                self.w.shl(rhs),
+            )
+        }
+    }
+}
+
+impl Shl<&u8> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn shl(self, rhs: &u8) -> Self {
+        self.shl(*rhs)
+    }
+}
+
+impl Shl<&u8> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn shl(self, rhs: &u8) -> IVec4 {
+        (*self).shl(*rhs)
+    }
+}
+
+impl Shl<u8> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn shl(self, rhs: u8) -> IVec4 {
+        (*self).shl(rhs)
+    }
+}
+
+impl ShlAssign<u8> for IVec4 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: u8) {
+        *self = self.shl(rhs);
+    }
+}
+
+impl ShlAssign<&u8> for IVec4 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: &u8) {
+        self.shl_assign(*rhs);
+    }
+}
+
+impl Shr<u8> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn shr(self, rhs: u8) -> Self::Output {
+        if (0..32).contains(&rhs) {
+            let count = rhs as i32;
+            Self(unsafe { _mm_sra_epi32(self.0, _mm_cvtsi32_si128(count)) })
+        } else {
+            Self::new(
+                self.x.shr(rhs),
+                self.y.shr(rhs),
+                self.z.shr(rhs),
+                self.w.shr(rhs),
+            )
+        }
+    }
+}
+
+impl Shr<&u8> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn shr(self, rhs: &u8) -> Self {
+        self.shr(*rhs)
+    }
+}
+
+impl Shr<&u8> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn shr(self, rhs: &u8) -> IVec4 {
+        (*self).shr(*rhs)
+    }
+}
+
+impl Shr<u8> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn shr(self, rhs: u8) -> IVec4 {
+        (*self).shr(rhs)
+    }
+}
+
+impl ShrAssign<u8> for IVec4 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: u8) {
+        *self = self.shr(rhs);
+    }
+}
+
+impl ShrAssign<&u8> for IVec4 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: &u8) {
+        self.shr_assign(*rhs);
+    }
+}
+
+impl Shl<u16> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn shl(self, rhs: u16) -> Self::Output {
+        if (0..32).contains(&rhs) {
+            let count = rhs as i32;
+            Self(unsafe { _mm_sll_epi32(self.0, _mm_cvtsi32_si128(count)) })
+        } else {
+            Self::new(
+                self.x.shl(rhs),
+                self.y.shl(rhs),
+                self.z.shl(rhs),
+                self.w.shl(rhs),
+            )
+        }
+    }
+}
+
+impl Shl<&u16> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn shl(self, rhs: &u16) -> Self {
+        self.shl(*rhs)
+    }
+}
+
+impl Shl<&u16> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn shl(self, rhs: &u16) -> IVec4 {
+        (*self).shl(*rhs)
+    }
+}
+
+impl Shl<u16> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn shl(self, rhs: u16) -> IVec4 {
+        (*self).shl(rhs)
+    }
+}
+
+impl ShlAssign<u16> for IVec4 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: u16) {
+        *self = self.shl(rhs);
+    }
+}
+
+impl ShlAssign<&u16> for IVec4 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: &u16) {
+        self.shl_assign(*rhs);
+    }
+}
+
+impl Shr<u16> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn shr(self, rhs: u16) -> Self::Output {
+        if (0..32).contains(&rhs) {
+            let count = rhs as i32;
+            Self(unsafe { _mm_sra_epi32(self.0, _mm_cvtsi32_si128(count)) })
+        } else {
+            Self::new(
+                self.x.shr(rhs),
+                self.y.shr(rhs),
+                self.z.shr(rhs),
+                self.w.shr(rhs),
+            )
+        }
+    }
+}
+
+impl Shr<&u16> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn shr(self, rhs: &u16) -> Self {
+        self.shr(*rhs)
+    }
+}
+
+impl Shr<&u16> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn shr(self, rhs: &u16) -> IVec4 {
+        (*self).shr(*rhs)
+    }
+}
+
+impl Shr<u16> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn shr(self, rhs: u16) -> IVec4 {
+        (*self).shr(rhs)
+    }
+}
+
+impl ShrAssign<u16> for IVec4 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: u16) {
+        *self = self.shr(rhs);
+    }
+}
+
+impl ShrAssign<&u16> for IVec4 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: &u16) {
+        self.shr_assign(*rhs);
+    }
+}
+
+impl Shl<u32> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn shl(self, rhs: u32) -> Self::Output {
+        if (0..32).contains(&rhs) {
+            let count = rhs as i32;
+            Self(unsafe { _mm_sll_epi32(self.0, _mm_cvtsi32_si128(count)) })
+        } else {
+            Self::new(
+                self.x.shl(rhs),
+                self.y.shl(rhs),
+                self.z.shl(rhs),
+                self.w.shl(rhs),
+            )
+        }
+    }
+}
+
+impl Shl<&u32> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn shl(self, rhs: &u32) -> Self {
+        self.shl(*rhs)
+    }
+}
+
+impl Shl<&u32> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn shl(self, rhs: &u32) -> IVec4 {
+        (*self).shl(*rhs)
+    }
+}
+
+impl Shl<u32> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn shl(self, rhs: u32) -> IVec4 {
+        (*self).shl(rhs)
+    }
+}
+
+impl ShlAssign<u32> for IVec4 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: u32) {
+        *self = self.shl(rhs);
+    }
+}
+
+impl ShlAssign<&u32> for IVec4 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: &u32) {
+        self.shl_assign(*rhs);
+    }
+}
+
+impl Shr<u32> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn shr(self, rhs: u32) -> Self::Output {
+        if (0..32).contains(&rhs) {
+            let count = rhs as i32;
+            Self(unsafe { _mm_sra_epi32(self.0, _mm_cvtsi32_si128(count)) })
+        } else {
+            Self::new(
+                self.x.shr(rhs),
+                self.y.shr(rhs),
+                self.z.shr(rhs),
+                self.w.shr(rhs),
+            )
+        }
+    }
+}
+
+impl Shr<&u32> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn shr(self, rhs: &u32) -> Self {
+        self.shr(*rhs)
+    }
+}
+
+impl Shr<&u32> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn shr(self, rhs: &u32) -> IVec4 {
+        (*self).shr(*rhs)
+    }
+}
+
+impl Shr<u32> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn shr(self, rhs: u32) -> IVec4 {
+        (*self).shr(rhs)
+    }
+}
+
+impl ShrAssign<u32> for IVec4 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: u32) {
+        *self = self.shr(rhs);
+    }
+}
+
+impl ShrAssign<&u32> for IVec4 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: &u32) {
+        self.shr_assign(*rhs);
+    }
+}
+
+impl Shl<u64> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn shl(self, rhs: u64) -> Self::Output {
+        if (0..32).contains(&rhs) {
+            let count = rhs as i32;
+            Self(unsafe { _mm_sll_epi32(self.0, _mm_cvtsi32_si128(count)) })
+        } else {
+            Self::new(
+                self.x.shl(rhs),
+                self.y.shl(rhs),
+                self.z.shl(rhs),
+                self.w.shl(rhs),
+            )
+        }
+    }
+}
+
+impl Shl<&u64> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn shl(self, rhs: &u64) -> Self {
+        self.shl(*rhs)
+    }
+}
+
+impl Shl<&u64> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn shl(self, rhs: &u64) -> IVec4 {
+        (*self).shl(*rhs)
+    }
+}
+
+impl Shl<u64> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn shl(self, rhs: u64) -> IVec4 {
+        (*self).shl(rhs)
+    }
+}
+
+impl ShlAssign<u64> for IVec4 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: u64) {
+        *self = self.shl(rhs);
+    }
+}
+
+impl ShlAssign<&u64> for IVec4 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: &u64) {
+        self.shl_assign(*rhs);
+    }
+}
+
+impl Shr<u64> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn shr(self, rhs: u64) -> Self::Output {
+        if (0..32).contains(&rhs) {
+            let count = rhs as i32;
+            Self(unsafe { _mm_sra_epi32(self.0, _mm_cvtsi32_si128(count)) })
+        } else {
+            Self::new(
+                self.x.shr(rhs),
+                self.y.shr(rhs),
+                self.z.shr(rhs),
+                self.w.shr(rhs),
+            )
+        }
+    }
+}
+
+impl Shr<&u64> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn shr(self, rhs: &u64) -> Self {
+        self.shr(*rhs)
+    }
+}
+
+impl Shr<&u64> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn shr(self, rhs: &u64) -> IVec4 {
+        (*self).shr(*rhs)
+    }
+}
+
+impl Shr<u64> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn shr(self, rhs: u64) -> IVec4 {
+        (*self).shr(rhs)
+    }
+}
+
+impl ShrAssign<u64> for IVec4 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: u64) {
+        *self = self.shr(rhs);
+    }
+}
+
+impl ShrAssign<&u64> for IVec4 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: &u64) {
+        self.shr_assign(*rhs);
+    }
+}
+
+impl Shl for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn shl(self, rhs: Self) -> Self {
+        Self::new(
+            self.x.shl(rhs.x),
+            self.y.shl(rhs.y),
+            self.z.shl(rhs.z),
+            self.w.shl(rhs.w),
+        )
+    }
+}
+
+impl Shl<&Self> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn shl(self, rhs: &Self) -> Self {
+        self.shl(*rhs)
+    }
+}
+
+impl Shl<&IVec4> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn shl(self, rhs: &IVec4) -> IVec4 {
+        (*self).shl(*rhs)
+    }
+}
+
+impl Shl<IVec4> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn shl(self, rhs: IVec4) -> IVec4 {
+        (*self).shl(rhs)
+    }
+}
+
+impl Shr for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn shr(self, rhs: Self) -> Self {
+        Self::new(
+            self.x.shr(rhs.x),
+            self.y.shr(rhs.y),
+            self.z.shr(rhs.z),
+            self.w.shr(rhs.w),
+        )
+    }
+}
+
+impl Shr<&Self> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn shr(self, rhs: &Self) -> Self {
+        self.shr(*rhs)
+    }
+}
+
+impl Shr<&IVec4> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn shr(self, rhs: &IVec4) -> IVec4 {
+        (*self).shr(*rhs)
+    }
+}
+
+impl Shr<IVec4> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn shr(self, rhs: IVec4) -> IVec4 {
+        (*self).shr(rhs)
+    }
+}
+
+impl Shl<UVec4> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn shl(self, rhs: UVec4) -> Self {
+        Self::new(
+            self.x.shl(rhs.x),
+            self.y.shl(rhs.y),
+            self.z.shl(rhs.z),
+            self.w.shl(rhs.w),
+        )
+    }
+}
+
+impl Shl<&UVec4> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn shl(self, rhs: &UVec4) -> Self {
+        self.shl(*rhs)
+    }
+}
+
+impl Shl<&UVec4> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn shl(self, rhs: &UVec4) -> IVec4 {
+        (*self).shl(*rhs)
+    }
+}
+
+impl Shl<UVec4> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn shl(self, rhs: UVec4) -> IVec4 {
+        (*self).shl(rhs)
+    }
+}
+
+impl Shr<UVec4> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn shr(self, rhs: UVec4) -> Self {
+        Self::new(
+            self.x.shr(rhs.x),
+            self.y.shr(rhs.y),
+            self.z.shr(rhs.z),
+            self.w.shr(rhs.w),
+        )
+    }
+}
+
+impl Shr<&UVec4> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn shr(self, rhs: &UVec4) -> Self {
+        self.shr(*rhs)
+    }
+}
+
+impl Shr<&UVec4> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn shr(self, rhs: &UVec4) -> IVec4 {
+        (*self).shr(*rhs)
+    }
+}
+
+impl Shr<UVec4> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn shr(self, rhs: UVec4) -> IVec4 {
+        (*self).shr(rhs)
+    }
+}
+
+impl Index<usize> for IVec4 {
+    type Output = i32;
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            3 => &self.w,
+            _ => panic!("index out of bounds"),
+        }
+    }
+}
+
+impl IndexMut<usize> for IVec4 {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            3 => &mut self.w,
+            _ => panic!("index out of bounds"),
+        }
+    }
+}
+
+impl fmt::Display for IVec4 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}, {}, {}, {}]", self.x, self.y, self.z, self.w)
+    }
+}
+
+impl fmt::Debug for IVec4 {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_tuple(stringify!(IVec4))
+            .field(&self.x)
+            .field(&self.y)
+            .field(&self.z)
+            .field(&self.w)
+            .finish()
+    }
+}
+
+impl From<IVec4> for __m128i {
+    #[inline(always)]
+    fn from(t: IVec4) -> Self {
+        t.0
+    }
+}
+
+impl From<__m128i> for IVec4 {
+    #[inline(always)]
+    fn from(t: __m128i) -> Self {
+        Self(t)
+    }
+}
+
+impl From<[i32; 4]> for IVec4 {
+    #[inline]
+    fn from(a: [i32; 4]) -> Self {
+        Self::new(a[0], a[1], a[2], a[3])
+    }
+}
+
+impl From<IVec4> for [i32; 4] {
+    #[inline]
+    fn from(v: IVec4) -> Self {
+        v.to_array()
+    }
+}
+
+impl From<(i32, i32, i32, i32)> for IVec4 {
+    #[inline]
+    fn from(t: (i32, i32, i32, i32)) -> Self {
+        Self::new(t.0, t.1, t.2, t.3)
+    }
+}
+
+impl From<IVec4> for (i32, i32, i32, i32) {
+    #[inline]
+    fn from(v: IVec4) -> Self {
+        (v.x, v.y, v.z, v.w)
+    }
+}
+
+impl From<(IVec3A, i32)> for IVec4 {
+    #[inline]
+    fn from((v, w): (IVec3A, i32)) -> Self {
+        v.extend(w)
+    }
+}
+
+impl From<(i32, IVec3A)> for IVec4 {
+    #[inline]
+    fn from((x, v): (i32, IVec3A)) -> Self {
+        Self::new(x, v.x, v.y, v.z)
+    }
+}
+
+impl From<(IVec3, i32)> for IVec4 {
+    #[inline]
+    fn from((v, w): (IVec3, i32)) -> Self {
+        Self::new(v.x, v.y, v.z, w)
+    }
+}
+
+impl From<(i32, IVec3)> for IVec4 {
+    #[inline]
+    fn from((x, v): (i32, IVec3)) -> Self {
+        Self::new(x, v.x, v.y, v.z)
+    }
+}
+
+impl From<(IVec2, i32, i32)> for IVec4 {
+    #[inline]
+    fn from((v, z, w): (IVec2, i32, i32)) -> Self {
+        Self::new(v.x, v.y, z, w)
+    }
+}
+
+impl From<(IVec2, IVec2)> for IVec4 {
+    #[inline]
+    fn from((v, u): (IVec2, IVec2)) -> Self {
+        Self::new(v.x, v.y, u.x, u.y)
+    }
+}
+
+impl Deref for IVec4 {
+    type Target = crate::deref::Vec4<i32>;
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        unsafe { &*(self as *const Self).cast() }
+    }
+}
+
+impl DerefMut for IVec4 {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unsafe { &mut *(self as *mut Self).cast() }
+    }
+}
+
+impl From<I8Vec4> for IVec4 {
+    #[inline]
+    fn from(v: I8Vec4) -> Self {
+        Self::new(
+            i32::from(v.x),
+            i32::from(v.y),
+            i32::from(v.z),
+            i32::from(v.w),
+        )
+    }
+}
+
+impl From<U8Vec4> for IVec4 {
+    #[inline]
+    fn from(v: U8Vec4) -> Self {
+        Self::new(
+            i32::from(v.x),
+            i32::from(v.y),
+            i32::from(v.z),
+            i32::from(v.w),
+        )
+    }
+}
+
+impl From<I16Vec4> for IVec4 {
+    #[inline]
+    fn from(v: I16Vec4) -> Self {
+        Self::new(
+            i32::from(v.x),
+            i32::from(v.y),
+            i32::from(v.z),
+            i32::from(v.w),
+        )
+    }
+}
+
+impl From<U16Vec4> for IVec4 {
+    #[inline]
+    fn from(v: U16Vec4) -> Self {
+        Self::new(
+            i32::from(v.x),
+            i32::from(v.y),
+            i32::from(v.z),
+            i32::from(v.w),
+        )
+    }
+}
+
+impl TryFrom<UVec4> for IVec4 {
+    type Error = core::num::TryFromIntError;
+
+    #[inline]
+    fn try_from(v: UVec4) -> Result<Self, Self::Error> {
+        Ok(Self::new(
+            i32::try_from(v.x)?,
+            i32::try_from(v.y)?,
+            i32::try_from(v.z)?,
+            i32::try_from(v.w)?,
+        ))
+    }
+}
+
+impl TryFrom<I64Vec4> for IVec4 {
+    type Error = core::num::TryFromIntError;
+
+    #[inline]
+    fn try_from(v: I64Vec4) -> Result<Self, Self::Error> {
+        Ok(Self::new(
+            i32::try_from(v.x)?,
+            i32::try_from(v.y)?,
+            i32::try_from(v.z)?,
+            i32::try_from(v.w)?,
+        ))
+    }
+}
+
+impl TryFrom<U64Vec4> for IVec4 {
+    type Error = core::num::TryFromIntError;
+
+    #[inline]
+    fn try_from(v: U64Vec4) -> Result<Self, Self::Error> {
+        Ok(Self::new(
+            i32::try_from(v.x)?,
+            i32::try_from(v.y)?,
+            i32::try_from(v.z)?,
+            i32::try_from(v.w)?,
+        ))
+    }
+}
+
+impl TryFrom<ISizeVec4> for IVec4 {
+    type Error = core::num::TryFromIntError;
+
+    #[inline]
+    fn try_from(v: ISizeVec4) -> Result<Self, Self::Error> {
+        Ok(Self::new(
+            i32::try_from(v.x)?,
+            i32::try_from(v.y)?,
+            i32::try_from(v.z)?,
+            i32::try_from(v.w)?,
+        ))
+    }
+}
+
+impl TryFrom<USizeVec4> for IVec4 {
+    type Error = core::num::TryFromIntError;
+
+    #[inline]
+    fn try_from(v: USizeVec4) -> Result<Self, Self::Error> {
+        Ok(Self::new(
+            i32::try_from(v.x)?,
+            i32::try_from(v.y)?,
+            i32::try_from(v.z)?,
+            i32::try_from(v.w)?,
+        ))
+    }
+}
+
+impl From<BVec4> for IVec4 {
+    #[inline]
+    fn from(v: BVec4) -> Self {
+        Self::new(
+            i32::from(v.x),
+            i32::from(v.y),
+            i32::from(v.z),
+            i32::from(v.w),
+        )
+    }
+}
+
+#[cfg(not(feature = "scalar-math"))]
+impl From<BVec4A> for IVec4 {
+    #[inline]
+    fn from(v: BVec4A) -> Self {
+        let bool_array: [bool; 4] = v.into();
+        Self::new(
+            i32::from(bool_array[0]),
+            i32::from(bool_array[1]),
+            i32::from(bool_array[2]),
+            i32::from(bool_array[3]),
+        )
+    }
+}
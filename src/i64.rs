@@ -1,7 +1,13 @@
+mod i64mat2;
+mod i64mat3;
+mod i64mat4;
 mod i64vec2;
 mod i64vec3;
 mod i64vec4;
 
+pub use i64mat2::{i64mat2, I64Mat2};
+pub use i64mat3::{i64mat3, I64Mat3};
+pub use i64mat4::{i64mat4, I64Mat4};
 pub use i64vec2::{i64vec2, I64Vec2};
 pub use i64vec3::{i64vec3, I64Vec3};
 pub use i64vec4::{i64vec4, I64Vec4};
@@ -10,6 +16,39 @@ pub use i64vec4::{i64vec4, I64Vec4};
 mod test {
     use super::*;
 
+    mod const_test_i64mat2 {
+        const_assert_eq!(32, core::mem::size_of::<super::I64Mat2>());
+
+        #[cfg(not(feature = "cuda"))]
+        const_assert_eq!(
+            core::mem::align_of::<i64>(),
+            core::mem::align_of::<super::I64Mat2>()
+        );
+        #[cfg(feature = "cuda")]
+        const_assert_eq!(16, core::mem::align_of::<super::I64Mat2>());
+    }
+
+    mod const_test_i64mat3 {
+        const_assert_eq!(72, core::mem::size_of::<super::I64Mat3>());
+
+        const_assert_eq!(
+            core::mem::align_of::<i64>(),
+            core::mem::align_of::<super::I64Mat3>()
+        );
+    }
+
+    mod const_test_i64mat4 {
+        const_assert_eq!(128, core::mem::size_of::<super::I64Mat4>());
+
+        #[cfg(not(feature = "cuda"))]
+        const_assert_eq!(
+            core::mem::align_of::<i64>(),
+            core::mem::align_of::<super::I64Mat4>()
+        );
+        #[cfg(feature = "cuda")]
+        const_assert_eq!(16, core::mem::align_of::<super::I64Mat4>());
+    }
+
     mod const_test_i64vec2 {
         const_assert_eq!(16, core::mem::size_of::<super::I64Vec2>());
 
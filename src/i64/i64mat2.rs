@@ -0,0 +1,685 @@
+// Generated from imat.rs.tera template. Edit the template, not the generated file.
+
+use crate::{DMat2, I64Vec2, Mat2};
+use core::fmt;
+use core::iter::{Product, Sum};
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+#[cfg(feature = "zerocopy")]
+use zerocopy_derive::*;
+
+/// Creates a 2x2 integer matrix from two column vectors.
+#[inline(always)]
+#[must_use]
+pub const fn i64mat2(x_axis: I64Vec2, y_axis: I64Vec2) -> I64Mat2 {
+    I64Mat2::from_cols(x_axis, y_axis)
+}
+
+/// A 2x2 column major `i64` matrix.
+///
+/// Integer matrices are exact, which makes them suitable for transforming lattices and grids,
+/// for example rotating and mirroring tiles or voxels by multiples of 90 degrees or applying
+/// shears.
+/// See [`Self::from_quarter_turns()`].
+///
+/// Arithmetic operators follow the overflow behavior of `i64`, panicking in debug
+/// builds and wrapping in release builds. Use the `checked_` and `wrapping_` methods, such as
+/// [`Self::checked_mul_i64mat2()`], when a specific behavior is needed.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(FromBytes, Immutable, IntoBytes, KnownLayout)
+)]
+#[repr(C)]
+pub struct I64Mat2 {
+    pub x_axis: I64Vec2,
+    pub y_axis: I64Vec2,
+}
+
+impl I64Mat2 {
+    /// A 2x2 matrix with all elements set to `0`.
+    pub const ZERO: Self = Self::from_cols(I64Vec2::ZERO, I64Vec2::ZERO);
+
+    /// A 2x2 identity matrix, where all diagonal elements are `1`, and all off-diagonal
+    /// elements are `0`.
+    pub const IDENTITY: Self = Self::from_cols(I64Vec2::X, I64Vec2::Y);
+
+    /// Creates a 2x2 matrix from two column vectors.
+    #[inline(always)]
+    #[must_use]
+    pub const fn from_cols(x_axis: I64Vec2, y_axis: I64Vec2) -> Self {
+        Self { x_axis, y_axis }
+    }
+
+    /// Creates a 2x2 matrix from a `[i64; 4]` array stored in column major order.
+    /// If your data is stored in row major you will need to `transpose` the returned
+    /// matrix.
+    #[inline]
+    #[must_use]
+    pub const fn from_cols_array(m: &[i64; 4]) -> Self {
+        Self::from_cols(I64Vec2::new(m[0], m[1]), I64Vec2::new(m[2], m[3]))
+    }
+
+    /// Creates a `[i64; 4]` array storing data in column major order.
+    /// If you require data in row major order `transpose` the matrix first.
+    #[inline]
+    #[must_use]
+    pub const fn to_cols_array(&self) -> [i64; 4] {
+        let [x_axis_x, x_axis_y] = self.x_axis.to_array();
+        let [y_axis_x, y_axis_y] = self.y_axis.to_array();
+
+        [x_axis_x, x_axis_y, y_axis_x, y_axis_y]
+    }
+
+    /// Creates a 2x2 matrix from a `[[i64; 2]; 2]` 2D array stored in column major order.
+    /// If your data is in row major order you will need to `transpose` the returned
+    /// matrix.
+    #[inline]
+    #[must_use]
+    pub const fn from_cols_array_2d(m: &[[i64; 2]; 2]) -> Self {
+        Self::from_cols(I64Vec2::from_array(m[0]), I64Vec2::from_array(m[1]))
+    }
+
+    /// Creates a `[[i64; 2]; 2]` 2D array storing data in column major order.
+    /// If you require data in row major order `transpose` the matrix first.
+    #[inline]
+    #[must_use]
+    pub const fn to_cols_array_2d(&self) -> [[i64; 2]; 2] {
+        [self.x_axis.to_array(), self.y_axis.to_array()]
+    }
+
+    /// Creates a 2x2 matrix with its diagonal set to `diagonal` and all other entries set
+    /// to 0.
+    ///
+    /// A diagonal of `1`s and `-1`s mirrors along the axes with a `-1`.
+    #[doc(alias = "scale")]
+    #[inline]
+    #[must_use]
+    pub const fn from_diagonal(diagonal: I64Vec2) -> Self {
+        let [x, y] = diagonal.to_array();
+        Self::from_cols(I64Vec2::new(x, 0), I64Vec2::new(0, y))
+    }
+
+    /// Creates a 2x2 matrix rotating by `turns` counter-clockwise quarter turns.
+    ///
+    /// Negative values rotate clockwise.
+    #[inline]
+    #[must_use]
+    pub const fn from_quarter_turns(turns: i32) -> Self {
+        let (sin, cos) = quarter_turn_sin_cos(turns);
+        Self::from_cols(I64Vec2::new(cos, sin), I64Vec2::new(-sin, cos))
+    }
+
+    /// Creates a 2x2 matrix from the first 4 values in `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than 4 elements long.
+    #[inline]
+    #[must_use]
+    pub const fn from_cols_slice(slice: &[i64]) -> Self {
+        Self::from_cols(
+            I64Vec2::new(slice[0], slice[1]),
+            I64Vec2::new(slice[2], slice[3]),
+        )
+    }
+
+    /// Writes the columns of `self` to the first 4 elements in `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than 4 elements long.
+    #[inline]
+    pub fn write_cols_to_slice(&self, slice: &mut [i64]) {
+        slice[..4].copy_from_slice(&self.to_cols_array());
+    }
+
+    /// Returns the matrix column for the given `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than 1.
+    #[inline]
+    #[must_use]
+    pub fn col(&self, index: usize) -> I64Vec2 {
+        match index {
+            0 => self.x_axis,
+            1 => self.y_axis,
+            _ => panic!("index out of bounds"),
+        }
+    }
+
+    /// Returns a mutable reference to the matrix column for the given `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than 1.
+    #[inline]
+    pub fn col_mut(&mut self, index: usize) -> &mut I64Vec2 {
+        match index {
+            0 => &mut self.x_axis,
+            1 => &mut self.y_axis,
+            _ => panic!("index out of bounds"),
+        }
+    }
+
+    /// Returns the matrix row for the given `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than 1.
+    #[inline]
+    #[must_use]
+    pub fn row(&self, index: usize) -> I64Vec2 {
+        match index {
+            0 => I64Vec2::new(self.x_axis.x, self.y_axis.x),
+            1 => I64Vec2::new(self.x_axis.y, self.y_axis.y),
+            _ => panic!("index out of bounds"),
+        }
+    }
+
+    /// Returns the transpose of `self`.
+    #[inline]
+    #[must_use]
+    pub fn transpose(&self) -> Self {
+        Self::from_cols(self.row(0), self.row(1))
+    }
+
+    /// Returns the diagonal of `self`.
+    #[inline]
+    #[must_use]
+    pub fn diagonal(&self) -> I64Vec2 {
+        I64Vec2::new(self.x_axis.x, self.y_axis.y)
+    }
+
+    /// Returns the determinant of `self`.
+    #[inline]
+    #[must_use]
+    pub fn determinant(&self) -> i64 {
+        self.x_axis.x * self.y_axis.y - self.x_axis.y * self.y_axis.x
+    }
+
+    /// Transforms a 2D vector.
+    #[inline]
+    #[must_use]
+    pub fn mul_i64vec2(&self, rhs: I64Vec2) -> I64Vec2 {
+        let mut res = self.x_axis.mul(rhs.x);
+        res = res.add(self.y_axis.mul(rhs.y));
+        res
+    }
+
+    /// Transforms a 2D vector, returning `None` if overflow occurred.
+    #[inline]
+    #[must_use]
+    pub fn checked_mul_i64vec2(&self, rhs: I64Vec2) -> Option<I64Vec2> {
+        let mut res = self.x_axis.checked_mul(I64Vec2::splat(rhs.x))?;
+        res = res.checked_add(self.y_axis.checked_mul(I64Vec2::splat(rhs.y))?)?;
+        Some(res)
+    }
+
+    /// Transforms a 2D vector, wrapping around on overflow.
+    #[inline]
+    #[must_use]
+    pub fn wrapping_mul_i64vec2(&self, rhs: I64Vec2) -> I64Vec2 {
+        let mut res = self.x_axis.wrapping_mul(I64Vec2::splat(rhs.x));
+        res = res.wrapping_add(self.y_axis.wrapping_mul(I64Vec2::splat(rhs.y)));
+        res
+    }
+
+    /// Multiplies two 2x2 matrices.
+    #[inline]
+    #[must_use]
+    pub fn mul_i64mat2(&self, rhs: &Self) -> Self {
+        Self::from_cols(self.mul_i64vec2(rhs.x_axis), self.mul_i64vec2(rhs.y_axis))
+    }
+
+    /// Multiplies two 2x2 matrices, returning `None` if overflow occurred.
+    #[inline]
+    #[must_use]
+    pub fn checked_mul_i64mat2(&self, rhs: &Self) -> Option<Self> {
+        Some(Self::from_cols(
+            self.checked_mul_i64vec2(rhs.x_axis)?,
+            self.checked_mul_i64vec2(rhs.y_axis)?,
+        ))
+    }
+
+    /// Multiplies two 2x2 matrices, wrapping around on overflow.
+    #[inline]
+    #[must_use]
+    pub fn wrapping_mul_i64mat2(&self, rhs: &Self) -> Self {
+        Self::from_cols(
+            self.wrapping_mul_i64vec2(rhs.x_axis),
+            self.wrapping_mul_i64vec2(rhs.y_axis),
+        )
+    }
+
+    /// Adds two 2x2 matrices.
+    #[inline]
+    #[must_use]
+    pub fn add_i64mat2(&self, rhs: &Self) -> Self {
+        Self::from_cols(self.x_axis.add(rhs.x_axis), self.y_axis.add(rhs.y_axis))
+    }
+
+    /// Adds two 2x2 matrices, returning `None` if overflow occurred.
+    #[inline]
+    #[must_use]
+    pub fn checked_add_i64mat2(&self, rhs: &Self) -> Option<Self> {
+        Some(Self::from_cols(
+            self.x_axis.checked_add(rhs.x_axis)?,
+            self.y_axis.checked_add(rhs.y_axis)?,
+        ))
+    }
+
+    /// Adds two 2x2 matrices, wrapping around on overflow.
+    #[inline]
+    #[must_use]
+    pub fn wrapping_add_i64mat2(&self, rhs: &Self) -> Self {
+        Self::from_cols(
+            self.x_axis.wrapping_add(rhs.x_axis),
+            self.y_axis.wrapping_add(rhs.y_axis),
+        )
+    }
+
+    /// Subtracts two 2x2 matrices.
+    #[inline]
+    #[must_use]
+    pub fn sub_i64mat2(&self, rhs: &Self) -> Self {
+        Self::from_cols(self.x_axis.sub(rhs.x_axis), self.y_axis.sub(rhs.y_axis))
+    }
+
+    /// Subtracts two 2x2 matrices, returning `None` if overflow occurred.
+    #[inline]
+    #[must_use]
+    pub fn checked_sub_i64mat2(&self, rhs: &Self) -> Option<Self> {
+        Some(Self::from_cols(
+            self.x_axis.checked_sub(rhs.x_axis)?,
+            self.y_axis.checked_sub(rhs.y_axis)?,
+        ))
+    }
+
+    /// Subtracts two 2x2 matrices, wrapping around on overflow.
+    #[inline]
+    #[must_use]
+    pub fn wrapping_sub_i64mat2(&self, rhs: &Self) -> Self {
+        Self::from_cols(
+            self.x_axis.wrapping_sub(rhs.x_axis),
+            self.y_axis.wrapping_sub(rhs.y_axis),
+        )
+    }
+
+    /// Multiplies a 2x2 matrix by a scalar.
+    #[inline]
+    #[must_use]
+    pub fn mul_scalar(&self, rhs: i64) -> Self {
+        Self::from_cols(self.x_axis.mul(rhs), self.y_axis.mul(rhs))
+    }
+
+    /// Takes the absolute value of each element in `self`
+    #[inline]
+    #[must_use]
+    pub fn abs(&self) -> Self {
+        Self::from_cols(self.x_axis.abs(), self.y_axis.abs())
+    }
+
+    /// Casts all elements of `self` to `f32`.
+    #[inline]
+    #[must_use]
+    pub fn as_mat2(&self) -> Mat2 {
+        Mat2::from_cols(self.x_axis.as_vec2(), self.y_axis.as_vec2())
+    }
+
+    /// Casts all elements of `self` to `f64`.
+    #[inline]
+    #[must_use]
+    pub fn as_dmat2(&self) -> DMat2 {
+        DMat2::from_cols(self.x_axis.as_dvec2(), self.y_axis.as_dvec2())
+    }
+}
+
+/// Returns the sine and cosine of `turns` quarter turns.
+#[inline]
+const fn quarter_turn_sin_cos(turns: i32) -> (i64, i64) {
+    match turns.rem_euclid(4) {
+        0 => (0, 1),
+        1 => (1, 0),
+        2 => (0, -1),
+        _ => (-1, 0),
+    }
+}
+
+impl Default for I64Mat2 {
+    #[inline]
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Add for I64Mat2 {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        self.add_i64mat2(&rhs)
+    }
+}
+
+impl Add<&Self> for I64Mat2 {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: &Self) -> Self {
+        self.add(*rhs)
+    }
+}
+
+impl Add<&I64Mat2> for &I64Mat2 {
+    type Output = I64Mat2;
+    #[inline]
+    fn add(self, rhs: &I64Mat2) -> I64Mat2 {
+        (*self).add(*rhs)
+    }
+}
+
+impl Add<I64Mat2> for &I64Mat2 {
+    type Output = I64Mat2;
+    #[inline]
+    fn add(self, rhs: I64Mat2) -> I64Mat2 {
+        (*self).add(rhs)
+    }
+}
+
+impl AddAssign for I64Mat2 {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = self.add(rhs);
+    }
+}
+
+impl AddAssign<&Self> for I64Mat2 {
+    #[inline]
+    fn add_assign(&mut self, rhs: &Self) {
+        self.add_assign(*rhs);
+    }
+}
+
+impl Sub for I64Mat2 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        self.sub_i64mat2(&rhs)
+    }
+}
+
+impl Sub<&Self> for I64Mat2 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: &Self) -> Self {
+        self.sub(*rhs)
+    }
+}
+
+impl Sub<&I64Mat2> for &I64Mat2 {
+    type Output = I64Mat2;
+    #[inline]
+    fn sub(self, rhs: &I64Mat2) -> I64Mat2 {
+        (*self).sub(*rhs)
+    }
+}
+
+impl Sub<I64Mat2> for &I64Mat2 {
+    type Output = I64Mat2;
+    #[inline]
+    fn sub(self, rhs: I64Mat2) -> I64Mat2 {
+        (*self).sub(rhs)
+    }
+}
+
+impl SubAssign for I64Mat2 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = self.sub(rhs);
+    }
+}
+
+impl SubAssign<&Self> for I64Mat2 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: &Self) {
+        self.sub_assign(*rhs);
+    }
+}
+
+impl Neg for I64Mat2 {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self::from_cols(self.x_axis.neg(), self.y_axis.neg())
+    }
+}
+
+impl Neg for &I64Mat2 {
+    type Output = I64Mat2;
+    #[inline]
+    fn neg(self) -> I64Mat2 {
+        (*self).neg()
+    }
+}
+
+impl Mul for I64Mat2 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        self.mul_i64mat2(&rhs)
+    }
+}
+
+impl Mul<&Self> for I64Mat2 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: &Self) -> Self {
+        self.mul(*rhs)
+    }
+}
+
+impl Mul<&I64Mat2> for &I64Mat2 {
+    type Output = I64Mat2;
+    #[inline]
+    fn mul(self, rhs: &I64Mat2) -> I64Mat2 {
+        (*self).mul(*rhs)
+    }
+}
+
+impl Mul<I64Mat2> for &I64Mat2 {
+    type Output = I64Mat2;
+    #[inline]
+    fn mul(self, rhs: I64Mat2) -> I64Mat2 {
+        (*self).mul(rhs)
+    }
+}
+
+impl MulAssign for I64Mat2 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.mul(rhs);
+    }
+}
+
+impl MulAssign<&Self> for I64Mat2 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: &Self) {
+        self.mul_assign(*rhs);
+    }
+}
+
+impl Mul<I64Vec2> for I64Mat2 {
+    type Output = I64Vec2;
+    #[inline]
+    fn mul(self, rhs: I64Vec2) -> Self::Output {
+        self.mul_i64vec2(rhs)
+    }
+}
+
+impl Mul<&I64Vec2> for I64Mat2 {
+    type Output = I64Vec2;
+    #[inline]
+    fn mul(self, rhs: &I64Vec2) -> I64Vec2 {
+        self.mul(*rhs)
+    }
+}
+
+impl Mul<&I64Vec2> for &I64Mat2 {
+    type Output = I64Vec2;
+    #[inline]
+    fn mul(self, rhs: &I64Vec2) -> I64Vec2 {
+        (*self).mul(*rhs)
+    }
+}
+
+impl Mul<I64Vec2> for &I64Mat2 {
+    type Output = I64Vec2;
+    #[inline]
+    fn mul(self, rhs: I64Vec2) -> I64Vec2 {
+        (*self).mul(rhs)
+    }
+}
+
+impl Mul<I64Mat2> for i64 {
+    type Output = I64Mat2;
+    #[inline]
+    fn mul(self, rhs: I64Mat2) -> Self::Output {
+        rhs.mul_scalar(self)
+    }
+}
+
+impl Mul<&I64Mat2> for i64 {
+    type Output = I64Mat2;
+    #[inline]
+    fn mul(self, rhs: &I64Mat2) -> I64Mat2 {
+        self.mul(*rhs)
+    }
+}
+
+impl Mul<&I64Mat2> for &i64 {
+    type Output = I64Mat2;
+    #[inline]
+    fn mul(self, rhs: &I64Mat2) -> I64Mat2 {
+        (*self).mul(*rhs)
+    }
+}
+
+impl Mul<I64Mat2> for &i64 {
+    type Output = I64Mat2;
+    #[inline]
+    fn mul(self, rhs: I64Mat2) -> I64Mat2 {
+        (*self).mul(rhs)
+    }
+}
+
+impl Mul<i64> for I64Mat2 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: i64) -> Self {
+        self.mul_scalar(rhs)
+    }
+}
+
+impl Mul<&i64> for I64Mat2 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: &i64) -> Self {
+        self.mul(*rhs)
+    }
+}
+
+impl Mul<&i64> for &I64Mat2 {
+    type Output = I64Mat2;
+    #[inline]
+    fn mul(self, rhs: &i64) -> I64Mat2 {
+        (*self).mul(*rhs)
+    }
+}
+
+impl Mul<i64> for &I64Mat2 {
+    type Output = I64Mat2;
+    #[inline]
+    fn mul(self, rhs: i64) -> I64Mat2 {
+        (*self).mul(rhs)
+    }
+}
+
+impl MulAssign<i64> for I64Mat2 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: i64) {
+        *self = self.mul(rhs);
+    }
+}
+
+impl MulAssign<&i64> for I64Mat2 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: &i64) {
+        self.mul_assign(*rhs);
+    }
+}
+
+impl Sum<Self> for I64Mat2 {
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        iter.fold(Self::ZERO, Self::add)
+    }
+}
+
+impl<'a> Sum<&'a Self> for I64Mat2 {
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.fold(Self::ZERO, |a, &b| Self::add(a, b))
+    }
+}
+
+impl Product for I64Mat2 {
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        iter.fold(Self::IDENTITY, Self::mul)
+    }
+}
+
+impl<'a> Product<&'a Self> for I64Mat2 {
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.fold(Self::IDENTITY, |a, &b| Self::mul(a, b))
+    }
+}
+
+impl AsRef<[i64; 4]> for I64Mat2 {
+    #[inline]
+    fn as_ref(&self) -> &[i64; 4] {
+        unsafe { &*(self as *const Self as *const [i64; 4]) }
+    }
+}
+
+impl AsMut<[i64; 4]> for I64Mat2 {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [i64; 4] {
+        unsafe { &mut *(self as *mut Self as *mut [i64; 4]) }
+    }
+}
+
+impl fmt::Debug for I64Mat2 {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct(stringify!(I64Mat2))
+            .field("x_axis", &self.x_axis)
+            .field("y_axis", &self.y_axis)
+            .finish()
+    }
+}
+
+impl fmt::Display for I64Mat2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}, {}]", self.x_axis, self.y_axis,)
+    }
+}
@@ -0,0 +1,854 @@
+// Generated from imat.rs.tera template. Edit the template, not the generated file.
+
+use crate::{DMat4, I64Mat3, I64Vec3, I64Vec4, Mat4};
+use core::fmt;
+use core::iter::{Product, Sum};
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+#[cfg(feature = "zerocopy")]
+use zerocopy_derive::*;
+
+/// Creates a 4x4 integer matrix from four column vectors.
+#[inline(always)]
+#[must_use]
+pub const fn i64mat4(
+    x_axis: I64Vec4,
+    y_axis: I64Vec4,
+    z_axis: I64Vec4,
+    w_axis: I64Vec4,
+) -> I64Mat4 {
+    I64Mat4::from_cols(x_axis, y_axis, z_axis, w_axis)
+}
+
+/// A 4x4 column major `i64` matrix.
+///
+/// Integer matrices are exact, which makes them suitable for transforming lattices and grids,
+/// for example rotating and mirroring tiles or voxels by multiples of 90 degrees or applying
+/// shears.
+///
+/// Arithmetic operators follow the overflow behavior of `i64`, panicking in debug
+/// builds and wrapping in release builds. Use the `checked_` and `wrapping_` methods, such as
+/// [`Self::checked_mul_i64mat4()`], when a specific behavior is needed.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(FromBytes, Immutable, IntoBytes, KnownLayout)
+)]
+#[repr(C)]
+pub struct I64Mat4 {
+    pub x_axis: I64Vec4,
+    pub y_axis: I64Vec4,
+    pub z_axis: I64Vec4,
+    pub w_axis: I64Vec4,
+}
+
+impl I64Mat4 {
+    /// A 4x4 matrix with all elements set to `0`.
+    pub const ZERO: Self =
+        Self::from_cols(I64Vec4::ZERO, I64Vec4::ZERO, I64Vec4::ZERO, I64Vec4::ZERO);
+
+    /// A 4x4 identity matrix, where all diagonal elements are `1`, and all off-diagonal
+    /// elements are `0`.
+    pub const IDENTITY: Self = Self::from_cols(I64Vec4::X, I64Vec4::Y, I64Vec4::Z, I64Vec4::W);
+
+    /// Creates a 4x4 matrix from four column vectors.
+    #[inline(always)]
+    #[must_use]
+    pub const fn from_cols(
+        x_axis: I64Vec4,
+        y_axis: I64Vec4,
+        z_axis: I64Vec4,
+        w_axis: I64Vec4,
+    ) -> Self {
+        Self {
+            x_axis,
+            y_axis,
+            z_axis,
+            w_axis,
+        }
+    }
+
+    /// Creates a 4x4 matrix from a `[i64; 16]` array stored in column major order.
+    /// If your data is stored in row major you will need to `transpose` the returned
+    /// matrix.
+    #[inline]
+    #[must_use]
+    pub const fn from_cols_array(m: &[i64; 16]) -> Self {
+        Self::from_cols(
+            I64Vec4::new(m[0], m[1], m[2], m[3]),
+            I64Vec4::new(m[4], m[5], m[6], m[7]),
+            I64Vec4::new(m[8], m[9], m[10], m[11]),
+            I64Vec4::new(m[12], m[13], m[14], m[15]),
+        )
+    }
+
+    /// Creates a `[i64; 16]` array storing data in column major order.
+    /// If you require data in row major order `transpose` the matrix first.
+    #[inline]
+    #[must_use]
+    pub const fn to_cols_array(&self) -> [i64; 16] {
+        let [x_axis_x, x_axis_y, x_axis_z, x_axis_w] = self.x_axis.to_array();
+        let [y_axis_x, y_axis_y, y_axis_z, y_axis_w] = self.y_axis.to_array();
+        let [z_axis_x, z_axis_y, z_axis_z, z_axis_w] = self.z_axis.to_array();
+        let [w_axis_x, w_axis_y, w_axis_z, w_axis_w] = self.w_axis.to_array();
+
+        [
+            x_axis_x, x_axis_y, x_axis_z, x_axis_w, y_axis_x, y_axis_y, y_axis_z, y_axis_w,
+            z_axis_x, z_axis_y, z_axis_z, z_axis_w, w_axis_x, w_axis_y, w_axis_z, w_axis_w,
+        ]
+    }
+
+    /// Creates a 4x4 matrix from a `[[i64; 4]; 4]` 4D array stored in column major order.
+    /// If your data is in row major order you will need to `transpose` the returned
+    /// matrix.
+    #[inline]
+    #[must_use]
+    pub const fn from_cols_array_2d(m: &[[i64; 4]; 4]) -> Self {
+        Self::from_cols(
+            I64Vec4::from_array(m[0]),
+            I64Vec4::from_array(m[1]),
+            I64Vec4::from_array(m[2]),
+            I64Vec4::from_array(m[3]),
+        )
+    }
+
+    /// Creates a `[[i64; 4]; 4]` 4D array storing data in column major order.
+    /// If you require data in row major order `transpose` the matrix first.
+    #[inline]
+    #[must_use]
+    pub const fn to_cols_array_2d(&self) -> [[i64; 4]; 4] {
+        [
+            self.x_axis.to_array(),
+            self.y_axis.to_array(),
+            self.z_axis.to_array(),
+            self.w_axis.to_array(),
+        ]
+    }
+
+    /// Creates a 4x4 matrix with its diagonal set to `diagonal` and all other entries set
+    /// to 0.
+    ///
+    /// A diagonal of `1`s and `-1`s mirrors along the axes with a `-1`.
+    #[doc(alias = "scale")]
+    #[inline]
+    #[must_use]
+    pub const fn from_diagonal(diagonal: I64Vec4) -> Self {
+        let [x, y, z, w] = diagonal.to_array();
+        Self::from_cols(
+            I64Vec4::new(x, 0, 0, 0),
+            I64Vec4::new(0, y, 0, 0),
+            I64Vec4::new(0, 0, z, 0),
+            I64Vec4::new(0, 0, 0, w),
+        )
+    }
+
+    /// Creates an affine transformation matrix from the given 3D `translation`.
+    ///
+    /// The resulting matrix can be used to transform 3D points and vectors. See
+    /// [`Self::transform_point3()`] and [`Self::transform_vector3()`].
+    #[inline]
+    #[must_use]
+    pub const fn from_translation(translation: I64Vec3) -> Self {
+        Self::from_cols(
+            I64Vec4::X,
+            I64Vec4::Y,
+            I64Vec4::Z,
+            I64Vec4::new(translation.x, translation.y, translation.z, 1),
+        )
+    }
+
+    /// Creates an affine transformation matrix from the given 3x3 matrix, for example one of
+    /// the [`I64Mat3::CUBE_ROTATIONS`].
+    ///
+    /// The resulting matrix can be used to transform 3D points and vectors. See
+    /// [`Self::transform_point3()`] and [`Self::transform_vector3()`].
+    #[inline]
+    #[must_use]
+    pub const fn from_i64mat3(m: I64Mat3) -> Self {
+        Self::from_cols(
+            I64Vec4::new(m.x_axis.x, m.x_axis.y, m.x_axis.z, 0),
+            I64Vec4::new(m.y_axis.x, m.y_axis.y, m.y_axis.z, 0),
+            I64Vec4::new(m.z_axis.x, m.z_axis.y, m.z_axis.z, 0),
+            I64Vec4::W,
+        )
+    }
+
+    /// Creates a 4x4 matrix from the first 16 values in `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than 16 elements long.
+    #[inline]
+    #[must_use]
+    pub const fn from_cols_slice(slice: &[i64]) -> Self {
+        Self::from_cols(
+            I64Vec4::new(slice[0], slice[1], slice[2], slice[3]),
+            I64Vec4::new(slice[4], slice[5], slice[6], slice[7]),
+            I64Vec4::new(slice[8], slice[9], slice[10], slice[11]),
+            I64Vec4::new(slice[12], slice[13], slice[14], slice[15]),
+        )
+    }
+
+    /// Writes the columns of `self` to the first 16 elements in `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than 16 elements long.
+    #[inline]
+    pub fn write_cols_to_slice(&self, slice: &mut [i64]) {
+        slice[..16].copy_from_slice(&self.to_cols_array());
+    }
+
+    /// Returns the matrix column for the given `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than 3.
+    #[inline]
+    #[must_use]
+    pub fn col(&self, index: usize) -> I64Vec4 {
+        match index {
+            0 => self.x_axis,
+            1 => self.y_axis,
+            2 => self.z_axis,
+            3 => self.w_axis,
+            _ => panic!("index out of bounds"),
+        }
+    }
+
+    /// Returns a mutable reference to the matrix column for the given `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than 3.
+    #[inline]
+    pub fn col_mut(&mut self, index: usize) -> &mut I64Vec4 {
+        match index {
+            0 => &mut self.x_axis,
+            1 => &mut self.y_axis,
+            2 => &mut self.z_axis,
+            3 => &mut self.w_axis,
+            _ => panic!("index out of bounds"),
+        }
+    }
+
+    /// Returns the matrix row for the given `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than 3.
+    #[inline]
+    #[must_use]
+    pub fn row(&self, index: usize) -> I64Vec4 {
+        match index {
+            0 => I64Vec4::new(self.x_axis.x, self.y_axis.x, self.z_axis.x, self.w_axis.x),
+            1 => I64Vec4::new(self.x_axis.y, self.y_axis.y, self.z_axis.y, self.w_axis.y),
+            2 => I64Vec4::new(self.x_axis.z, self.y_axis.z, self.z_axis.z, self.w_axis.z),
+            3 => I64Vec4::new(self.x_axis.w, self.y_axis.w, self.z_axis.w, self.w_axis.w),
+            _ => panic!("index out of bounds"),
+        }
+    }
+
+    /// Returns the transpose of `self`.
+    #[inline]
+    #[must_use]
+    pub fn transpose(&self) -> Self {
+        Self::from_cols(self.row(0), self.row(1), self.row(2), self.row(3))
+    }
+
+    /// Returns the diagonal of `self`.
+    #[inline]
+    #[must_use]
+    pub fn diagonal(&self) -> I64Vec4 {
+        I64Vec4::new(self.x_axis.x, self.y_axis.y, self.z_axis.z, self.w_axis.w)
+    }
+
+    /// Returns the determinant of `self`.
+    #[inline]
+    #[must_use]
+    pub fn determinant(&self) -> i64 {
+        let [m00, m01, m02, m03] = self.x_axis.to_array();
+        let [m10, m11, m12, m13] = self.y_axis.to_array();
+        let [m20, m21, m22, m23] = self.z_axis.to_array();
+        let [m30, m31, m32, m33] = self.w_axis.to_array();
+
+        let a2323 = m22 * m33 - m23 * m32;
+        let a1323 = m21 * m33 - m23 * m31;
+        let a1223 = m21 * m32 - m22 * m31;
+        let a0323 = m20 * m33 - m23 * m30;
+        let a0223 = m20 * m32 - m22 * m30;
+        let a0123 = m20 * m31 - m21 * m30;
+
+        m00 * (m11 * a2323 - m12 * a1323 + m13 * a1223)
+            - m01 * (m10 * a2323 - m12 * a0323 + m13 * a0223)
+            + m02 * (m10 * a1323 - m11 * a0323 + m13 * a0123)
+            - m03 * (m10 * a1223 - m11 * a0223 + m12 * a0123)
+    }
+
+    /// Transforms the given 3D point, applying shear, scale, rotation and translation.
+    ///
+    /// This is the equivalent of multiplying `rhs` as a 4D vector where `w` is `1`.
+    ///
+    /// This method assumes that `self` contains a valid affine transform.
+    #[inline]
+    #[must_use]
+    pub fn transform_point3(&self, rhs: I64Vec3) -> I64Vec3 {
+        let mut res = self.x_axis.mul(rhs.x);
+        res = self.y_axis.mul(rhs.y).add(res);
+        res = self.z_axis.mul(rhs.z).add(res);
+        res = self.w_axis.add(res);
+        res.truncate()
+    }
+
+    /// Transforms the given 3D vector, applying shear, scale and rotation (but NOT
+    /// translation).
+    ///
+    /// This is the equivalent of multiplying `rhs` as a 4D vector where `w` is `0`.
+    ///
+    /// This method assumes that `self` contains a valid affine transform.
+    #[inline]
+    #[must_use]
+    pub fn transform_vector3(&self, rhs: I64Vec3) -> I64Vec3 {
+        let mut res = self.x_axis.mul(rhs.x);
+        res = self.y_axis.mul(rhs.y).add(res);
+        res = self.z_axis.mul(rhs.z).add(res);
+        res.truncate()
+    }
+
+    /// Transforms a 4D vector.
+    #[inline]
+    #[must_use]
+    pub fn mul_i64vec4(&self, rhs: I64Vec4) -> I64Vec4 {
+        let mut res = self.x_axis.mul(rhs.x);
+        res = res.add(self.y_axis.mul(rhs.y));
+        res = res.add(self.z_axis.mul(rhs.z));
+        res = res.add(self.w_axis.mul(rhs.w));
+        res
+    }
+
+    /// Transforms a 4D vector, returning `None` if overflow occurred.
+    #[inline]
+    #[must_use]
+    pub fn checked_mul_i64vec4(&self, rhs: I64Vec4) -> Option<I64Vec4> {
+        let mut res = self.x_axis.checked_mul(I64Vec4::splat(rhs.x))?;
+        res = res.checked_add(self.y_axis.checked_mul(I64Vec4::splat(rhs.y))?)?;
+        res = res.checked_add(self.z_axis.checked_mul(I64Vec4::splat(rhs.z))?)?;
+        res = res.checked_add(self.w_axis.checked_mul(I64Vec4::splat(rhs.w))?)?;
+        Some(res)
+    }
+
+    /// Transforms a 4D vector, wrapping around on overflow.
+    #[inline]
+    #[must_use]
+    pub fn wrapping_mul_i64vec4(&self, rhs: I64Vec4) -> I64Vec4 {
+        let mut res = self.x_axis.wrapping_mul(I64Vec4::splat(rhs.x));
+        res = res.wrapping_add(self.y_axis.wrapping_mul(I64Vec4::splat(rhs.y)));
+        res = res.wrapping_add(self.z_axis.wrapping_mul(I64Vec4::splat(rhs.z)));
+        res = res.wrapping_add(self.w_axis.wrapping_mul(I64Vec4::splat(rhs.w)));
+        res
+    }
+
+    /// Multiplies two 4x4 matrices.
+    #[inline]
+    #[must_use]
+    pub fn mul_i64mat4(&self, rhs: &Self) -> Self {
+        Self::from_cols(
+            self.mul_i64vec4(rhs.x_axis),
+            self.mul_i64vec4(rhs.y_axis),
+            self.mul_i64vec4(rhs.z_axis),
+            self.mul_i64vec4(rhs.w_axis),
+        )
+    }
+
+    /// Multiplies two 4x4 matrices, returning `None` if overflow occurred.
+    #[inline]
+    #[must_use]
+    pub fn checked_mul_i64mat4(&self, rhs: &Self) -> Option<Self> {
+        Some(Self::from_cols(
+            self.checked_mul_i64vec4(rhs.x_axis)?,
+            self.checked_mul_i64vec4(rhs.y_axis)?,
+            self.checked_mul_i64vec4(rhs.z_axis)?,
+            self.checked_mul_i64vec4(rhs.w_axis)?,
+        ))
+    }
+
+    /// Multiplies two 4x4 matrices, wrapping around on overflow.
+    #[inline]
+    #[must_use]
+    pub fn wrapping_mul_i64mat4(&self, rhs: &Self) -> Self {
+        Self::from_cols(
+            self.wrapping_mul_i64vec4(rhs.x_axis),
+            self.wrapping_mul_i64vec4(rhs.y_axis),
+            self.wrapping_mul_i64vec4(rhs.z_axis),
+            self.wrapping_mul_i64vec4(rhs.w_axis),
+        )
+    }
+
+    /// Adds two 4x4 matrices.
+    #[inline]
+    #[must_use]
+    pub fn add_i64mat4(&self, rhs: &Self) -> Self {
+        Self::from_cols(
+            self.x_axis.add(rhs.x_axis),
+            self.y_axis.add(rhs.y_axis),
+            self.z_axis.add(rhs.z_axis),
+            self.w_axis.add(rhs.w_axis),
+        )
+    }
+
+    /// Adds two 4x4 matrices, returning `None` if overflow occurred.
+    #[inline]
+    #[must_use]
+    pub fn checked_add_i64mat4(&self, rhs: &Self) -> Option<Self> {
+        Some(Self::from_cols(
+            self.x_axis.checked_add(rhs.x_axis)?,
+            self.y_axis.checked_add(rhs.y_axis)?,
+            self.z_axis.checked_add(rhs.z_axis)?,
+            self.w_axis.checked_add(rhs.w_axis)?,
+        ))
+    }
+
+    /// Adds two 4x4 matrices, wrapping around on overflow.
+    #[inline]
+    #[must_use]
+    pub fn wrapping_add_i64mat4(&self, rhs: &Self) -> Self {
+        Self::from_cols(
+            self.x_axis.wrapping_add(rhs.x_axis),
+            self.y_axis.wrapping_add(rhs.y_axis),
+            self.z_axis.wrapping_add(rhs.z_axis),
+            self.w_axis.wrapping_add(rhs.w_axis),
+        )
+    }
+
+    /// Subtracts two 4x4 matrices.
+    #[inline]
+    #[must_use]
+    pub fn sub_i64mat4(&self, rhs: &Self) -> Self {
+        Self::from_cols(
+            self.x_axis.sub(rhs.x_axis),
+            self.y_axis.sub(rhs.y_axis),
+            self.z_axis.sub(rhs.z_axis),
+            self.w_axis.sub(rhs.w_axis),
+        )
+    }
+
+    /// Subtracts two 4x4 matrices, returning `None` if overflow occurred.
+    #[inline]
+    #[must_use]
+    pub fn checked_sub_i64mat4(&self, rhs: &Self) -> Option<Self> {
+        Some(Self::from_cols(
+            self.x_axis.checked_sub(rhs.x_axis)?,
+            self.y_axis.checked_sub(rhs.y_axis)?,
+            self.z_axis.checked_sub(rhs.z_axis)?,
+            self.w_axis.checked_sub(rhs.w_axis)?,
+        ))
+    }
+
+    /// Subtracts two 4x4 matrices, wrapping around on overflow.
+    #[inline]
+    #[must_use]
+    pub fn wrapping_sub_i64mat4(&self, rhs: &Self) -> Self {
+        Self::from_cols(
+            self.x_axis.wrapping_sub(rhs.x_axis),
+            self.y_axis.wrapping_sub(rhs.y_axis),
+            self.z_axis.wrapping_sub(rhs.z_axis),
+            self.w_axis.wrapping_sub(rhs.w_axis),
+        )
+    }
+
+    /// Multiplies a 4x4 matrix by a scalar.
+    #[inline]
+    #[must_use]
+    pub fn mul_scalar(&self, rhs: i64) -> Self {
+        Self::from_cols(
+            self.x_axis.mul(rhs),
+            self.y_axis.mul(rhs),
+            self.z_axis.mul(rhs),
+            self.w_axis.mul(rhs),
+        )
+    }
+
+    /// Takes the absolute value of each element in `self`
+    #[inline]
+    #[must_use]
+    pub fn abs(&self) -> Self {
+        Self::from_cols(
+            self.x_axis.abs(),
+            self.y_axis.abs(),
+            self.z_axis.abs(),
+            self.w_axis.abs(),
+        )
+    }
+
+    /// Casts all elements of `self` to `f32`.
+    #[inline]
+    #[must_use]
+    pub fn as_mat4(&self) -> Mat4 {
+        Mat4::from_cols(
+            self.x_axis.as_vec4(),
+            self.y_axis.as_vec4(),
+            self.z_axis.as_vec4(),
+            self.w_axis.as_vec4(),
+        )
+    }
+
+    /// Casts all elements of `self` to `f64`.
+    #[inline]
+    #[must_use]
+    pub fn as_dmat4(&self) -> DMat4 {
+        DMat4::from_cols(
+            self.x_axis.as_dvec4(),
+            self.y_axis.as_dvec4(),
+            self.z_axis.as_dvec4(),
+            self.w_axis.as_dvec4(),
+        )
+    }
+}
+
+impl Default for I64Mat4 {
+    #[inline]
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Add for I64Mat4 {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        self.add_i64mat4(&rhs)
+    }
+}
+
+impl Add<&Self> for I64Mat4 {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: &Self) -> Self {
+        self.add(*rhs)
+    }
+}
+
+impl Add<&I64Mat4> for &I64Mat4 {
+    type Output = I64Mat4;
+    #[inline]
+    fn add(self, rhs: &I64Mat4) -> I64Mat4 {
+        (*self).add(*rhs)
+    }
+}
+
+impl Add<I64Mat4> for &I64Mat4 {
+    type Output = I64Mat4;
+    #[inline]
+    fn add(self, rhs: I64Mat4) -> I64Mat4 {
+        (*self).add(rhs)
+    }
+}
+
+impl AddAssign for I64Mat4 {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = self.add(rhs);
+    }
+}
+
+impl AddAssign<&Self> for I64Mat4 {
+    #[inline]
+    fn add_assign(&mut self, rhs: &Self) {
+        self.add_assign(*rhs);
+    }
+}
+
+impl Sub for I64Mat4 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        self.sub_i64mat4(&rhs)
+    }
+}
+
+impl Sub<&Self> for I64Mat4 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: &Self) -> Self {
+        self.sub(*rhs)
+    }
+}
+
+impl Sub<&I64Mat4> for &I64Mat4 {
+    type Output = I64Mat4;
+    #[inline]
+    fn sub(self, rhs: &I64Mat4) -> I64Mat4 {
+        (*self).sub(*rhs)
+    }
+}
+
+impl Sub<I64Mat4> for &I64Mat4 {
+    type Output = I64Mat4;
+    #[inline]
+    fn sub(self, rhs: I64Mat4) -> I64Mat4 {
+        (*self).sub(rhs)
+    }
+}
+
+impl SubAssign for I64Mat4 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = self.sub(rhs);
+    }
+}
+
+impl SubAssign<&Self> for I64Mat4 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: &Self) {
+        self.sub_assign(*rhs);
+    }
+}
+
+impl Neg for I64Mat4 {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self::from_cols(
+            self.x_axis.neg(),
+            self.y_axis.neg(),
+            self.z_axis.neg(),
+            self.w_axis.neg(),
+        )
+    }
+}
+
+impl Neg for &I64Mat4 {
+    type Output = I64Mat4;
+    #[inline]
+    fn neg(self) -> I64Mat4 {
+        (*self).neg()
+    }
+}
+
+impl Mul for I64Mat4 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        self.mul_i64mat4(&rhs)
+    }
+}
+
+impl Mul<&Self> for I64Mat4 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: &Self) -> Self {
+        self.mul(*rhs)
+    }
+}
+
+impl Mul<&I64Mat4> for &I64Mat4 {
+    type Output = I64Mat4;
+    #[inline]
+    fn mul(self, rhs: &I64Mat4) -> I64Mat4 {
+        (*self).mul(*rhs)
+    }
+}
+
+impl Mul<I64Mat4> for &I64Mat4 {
+    type Output = I64Mat4;
+    #[inline]
+    fn mul(self, rhs: I64Mat4) -> I64Mat4 {
+        (*self).mul(rhs)
+    }
+}
+
+impl MulAssign for I64Mat4 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.mul(rhs);
+    }
+}
+
+impl MulAssign<&Self> for I64Mat4 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: &Self) {
+        self.mul_assign(*rhs);
+    }
+}
+
+impl Mul<I64Vec4> for I64Mat4 {
+    type Output = I64Vec4;
+    #[inline]
+    fn mul(self, rhs: I64Vec4) -> Self::Output {
+        self.mul_i64vec4(rhs)
+    }
+}
+
+impl Mul<&I64Vec4> for I64Mat4 {
+    type Output = I64Vec4;
+    #[inline]
+    fn mul(self, rhs: &I64Vec4) -> I64Vec4 {
+        self.mul(*rhs)
+    }
+}
+
+impl Mul<&I64Vec4> for &I64Mat4 {
+    type Output = I64Vec4;
+    #[inline]
+    fn mul(self, rhs: &I64Vec4) -> I64Vec4 {
+        (*self).mul(*rhs)
+    }
+}
+
+impl Mul<I64Vec4> for &I64Mat4 {
+    type Output = I64Vec4;
+    #[inline]
+    fn mul(self, rhs: I64Vec4) -> I64Vec4 {
+        (*self).mul(rhs)
+    }
+}
+
+impl Mul<I64Mat4> for i64 {
+    type Output = I64Mat4;
+    #[inline]
+    fn mul(self, rhs: I64Mat4) -> Self::Output {
+        rhs.mul_scalar(self)
+    }
+}
+
+impl Mul<&I64Mat4> for i64 {
+    type Output = I64Mat4;
+    #[inline]
+    fn mul(self, rhs: &I64Mat4) -> I64Mat4 {
+        self.mul(*rhs)
+    }
+}
+
+impl Mul<&I64Mat4> for &i64 {
+    type Output = I64Mat4;
+    #[inline]
+    fn mul(self, rhs: &I64Mat4) -> I64Mat4 {
+        (*self).mul(*rhs)
+    }
+}
+
+impl Mul<I64Mat4> for &i64 {
+    type Output = I64Mat4;
+    #[inline]
+    fn mul(self, rhs: I64Mat4) -> I64Mat4 {
+        (*self).mul(rhs)
+    }
+}
+
+impl Mul<i64> for I64Mat4 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: i64) -> Self {
+        self.mul_scalar(rhs)
+    }
+}
+
+impl Mul<&i64> for I64Mat4 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: &i64) -> Self {
+        self.mul(*rhs)
+    }
+}
+
+impl Mul<&i64> for &I64Mat4 {
+    type Output = I64Mat4;
+    #[inline]
+    fn mul(self, rhs: &i64) -> I64Mat4 {
+        (*self).mul(*rhs)
+    }
+}
+
+impl Mul<i64> for &I64Mat4 {
+    type Output = I64Mat4;
+    #[inline]
+    fn mul(self, rhs: i64) -> I64Mat4 {
+        (*self).mul(rhs)
+    }
+}
+
+impl MulAssign<i64> for I64Mat4 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: i64) {
+        *self = self.mul(rhs);
+    }
+}
+
+impl MulAssign<&i64> for I64Mat4 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: &i64) {
+        self.mul_assign(*rhs);
+    }
+}
+
+impl Sum<Self> for I64Mat4 {
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        iter.fold(Self::ZERO, Self::add)
+    }
+}
+
+impl<'a> Sum<&'a Self> for I64Mat4 {
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.fold(Self::ZERO, |a, &b| Self::add(a, b))
+    }
+}
+
+impl Product for I64Mat4 {
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        iter.fold(Self::IDENTITY, Self::mul)
+    }
+}
+
+impl<'a> Product<&'a Self> for I64Mat4 {
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.fold(Self::IDENTITY, |a, &b| Self::mul(a, b))
+    }
+}
+
+impl AsRef<[i64; 16]> for I64Mat4 {
+    #[inline]
+    fn as_ref(&self) -> &[i64; 16] {
+        unsafe { &*(self as *const Self as *const [i64; 16]) }
+    }
+}
+
+impl AsMut<[i64; 16]> for I64Mat4 {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [i64; 16] {
+        unsafe { &mut *(self as *mut Self as *mut [i64; 16]) }
+    }
+}
+
+impl fmt::Debug for I64Mat4 {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct(stringify!(I64Mat4))
+            .field("x_axis", &self.x_axis)
+            .field("y_axis", &self.y_axis)
+            .field("z_axis", &self.z_axis)
+            .field("w_axis", &self.w_axis)
+            .finish()
+    }
+}
+
+impl fmt::Display for I64Mat4 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "[{}, {}, {}, {}]",
+            self.x_axis, self.y_axis, self.z_axis, self.w_axis,
+        )
+    }
+}
@@ -8,13 +8,26 @@
 * [`f32`](mod@f32) types
   * vectors: [`Vec2`], [`Vec3`], [`Vec3A`] and [`Vec4`]
   * square matrices: [`Mat2`], [`Mat3`], [`Mat3A`] and [`Mat4`]
+  * non-square matrices: [`Mat2x3`], [`Mat3x2`], [`Mat3x4`] and [`Mat4x3`]
   * a quaternion type: [`Quat`]
+  * a 2D rotation type: [`Rot2`]
   * affine transformation types: [`Affine2`], [`Affine3`] and [`Affine3A`]
+  * isometry types: [`Isometry2`] and [`Isometry3A`]
+  * scale-rotation-translation transform types: [`Transform2`] and [`Transform3`]
+  * a dual quaternion type: [`DualQuat`]
+  * structure of arrays types: [`Vec3x4`], [`Vec3x8`] and [`Quatx4`]
 * [`f64`](mod@f64) types
   * vectors: [`DVec2`], [`DVec3`] and [`DVec4`]
   * square matrices: [`DMat2`], [`DMat3`] and [`DMat4`]
   * a quaternion type: [`DQuat`]
+  * a 2D rotation type: [`DRot2`]
   * affine transformation types: [`DAffine2`] and [`DAffine3`]
+  * an isometry type: [`DIsometry3`]
+  * scale-rotation-translation transform types: [`DTransform2`] and [`DTransform3`]
+  * a dual quaternion type: [`DDualQuat`]
+* [`f16`](mod@f16) types
+  * a half-precision float: [`F16`]
+  * vectors: [`HVec2`], [`HVec3`] and [`HVec4`]
 * [`i8`](mod@i8) types
   * vectors: [`I8Vec2`], [`I8Vec3`] and [`I8Vec4`]
 * [`u8`](mod@u8) types
@@ -24,11 +37,13 @@
 * [`u16`](mod@u16) types
   * vectors: [`U16Vec2`], [`U16Vec3`] and [`U16Vec4`]
 * [`i32`](mod@i32) types
-  * vectors: [`IVec2`], [`IVec3`] and [`IVec4`]
+  * vectors: [`IVec2`], [`IVec3`], [`IVec3A`] and [`IVec4`]
+  * square matrices: [`IMat2`], [`IMat3`] and [`IMat4`]
 * [`u32`](mod@u32) types
-  * vectors: [`UVec2`], [`UVec3`] and [`UVec4`]
+  * vectors: [`UVec2`], [`UVec3`], [`UVec3A`] and [`UVec4`]
 * [`i64`](mod@i64) types
   * vectors: [`I64Vec2`], [`I64Vec3`] and [`I64Vec4`]
+  * square matrices: [`I64Mat2`], [`I64Mat3`] and [`I64Mat4`]
 * [`u64`](mod@u64) types
   * vectors: [`U64Vec2`], [`U64Vec3`] and [`U64Vec4`]
 * [`isize`](mod@isize) types
@@ -49,6 +64,16 @@ Some `glam` types use SIMD for storage meaning they are 16 byte aligned, these t
 with an `A` suffix are a SIMD alternative to a scalar type, e.g. `Vec3` uses `f32` storage and
 `Vec3A` uses SIMD storage.
 
+With the `int-simd` feature the 32-bit integer vectors [`IVec4`] and [`UVec4`] also use SIMD
+storage, as do their 16 byte aligned 3D counterparts [`IVec3A`] and [`UVec3A`]. This makes
+[`IVec4`] and [`UVec4`] 16 byte aligned and comparisons on them return a [`BVec4A`] mask, the same
+as [`Vec4`]. Without the feature they keep the alignment of `i32` and `u32` and use scalar math.
+Addition, subtraction, multiplication and shifts use SIMD instructions and check all lanes for
+overflow. If any lane overflows the operation is redone per component with the scalar operators,
+so overflow panics or wraps depending on the `overflow-checks` profile setting, the same as for
+the other integer types. Multiplication is only done with SIMD when all components fit in 16 bits,
+and shifts only when the shift count is less than 32.
+
 When SIMD is not available on the target the types will maintain 16 byte alignment and internal
 padding so that object sizes and layouts will not change between architectures. There are scalar
 math fallback implementations exist when SIMD is not available. It is intended to add support for
@@ -56,6 +81,23 @@ other SIMD architectures once they appear in stable Rust.
 
 Currently only SSE2 on x86/x86_64, NEON on Aarch64, and simd128 on WASM are supported.
 
+On x86/x86_64 the SSE2 backend also has AVX code paths for [`Mat4`] multiplication and the `f64`
+[`DMat4`] and [`DVec4`] types, which are used when the `avx` target feature is enabled at compile
+time, e.g. with `-C target-feature=+avx2,+fma`. These produce the same results as the SSE2 paths.
+When the `fast-math` feature is enabled and the `fma` target feature is available, [`Mat4`],
+[`Mat3A`], [`Affine3A`] and [`DMat4`] multiplication and [`Mat4`] inversion use fused
+multiply-adds. Fused multiply-adds only round once, so their results can differ in the last bit
+from the default build, which is why they require `fast-math`.
+
+The `deterministic` feature makes the SIMD backends add the terms of dot products and quaternion
+multiplication in the same order as the scalar implementation, normalize by multiplying with the
+reciprocal length and avoid approximations such as the SSE2 `sin` used by [`Quat::slerp`]. Fused
+multiply-adds are never used, even with `fast-math`, and transcendental functions go through
+`libm`, so the `dot`, `length` and `normalize` methods of [`Vec3A`], [`Vec4`], [`DVec3`] and
+[`DVec4`], [`Quat::mul_quat`], [`Quat::slerp`] and [`Quat::from_euler`] produce bit-for-bit
+identical results on the SSE2, NEON, WASM and scalar backends. [`Mat4::inverse`] uses the
+operation order of the scalar implementation on all backends, so it is covered as well.
+
 ## Vec3A and Mat3A
 
 `Vec3A` is a SIMD optimized version of the `Vec3` type, which due to 16 byte alignment results
@@ -97,6 +139,25 @@ let v3a = Vec3A::from(v3);
 assert_eq!(Vec3A::new(1.0, 2.0, 3.0), v3a);
 ```
 
+## Vec3x4, Vec3x8 and Quatx4
+
+[`Vec3x4`], [`Vec3x8`] and [`Quatx4`] store several vectors or quaternions in structure of arrays
+layout, with each field holding one component of every element. This lets SIMD process four
+elements per instruction without the horizontal adds and shuffles that [`Vec3A`] needs for
+operations like `dot` and `cross`. The vector operations give the same results as calling the
+[`Vec3`] methods on each element.
+
+```
+use glam::{Quat, Vec3, Vec3x4};
+
+let points = [Vec3::X, Vec3::Y, Vec3::Z, Vec3::ONE];
+let rotation = Quat::from_rotation_z(core::f32::consts::FRAC_PI_2);
+
+let mut rotated = [Vec3::ZERO; 4];
+(rotation * Vec3x4::from_slice(&points)).write_to_slice(&mut rotated);
+assert!(rotated[0].abs_diff_eq(Vec3::Y, 1e-6));
+```
+
 ## Affine2, Affine3 and Affine3A
 
 `Affine2`, `Affine3` and `Affine3A` are composed of a linear transform matrix and a vector
@@ -163,6 +224,33 @@ assert_eq!(4.0, v.z);
 [`Deref`]: https://doc.rust-lang.org/std/ops/trait.Deref.html
 [`DerefMut`]: https://doc.rust-lang.org/std/ops/trait.DerefMut.html
 
+## Const evaluation
+
+Operator traits can't be called in a `const` context, so types that store their elements as
+scalars on every target provide `const_` prefixed versions of the basic arithmetic, such as
+`Vec3::const_add`, `Mat3::const_mul_mat3`, `Affine3::const_mul_affine` and
+`DQuat::const_mul_quat`. These give the same results as their non-const counterparts.
+
+Floating point arithmetic in a `const fn` requires Rust 1.82.0, so the float versions are only
+available with the `const-float-arithmetic` feature. `const_transpose` and the integer versions
+are always available.
+
+Types that use SIMD storage, such as [`Mat4`] and [`Quat`], can't do arithmetic in a `const`
+context. Their `f64` equivalents, such as `DMat4::const_mul_mat4`, can be used instead.
+
+```
+# #[cfg(feature = "const-float-arithmetic")] {
+use glam::{DMat4, DVec4};
+
+const TRANSLATE: DMat4 =
+    DMat4::from_cols(DVec4::X, DVec4::Y, DVec4::Z, DVec4::new(1.0, 2.0, 3.0, 1.0));
+const SCALE: DMat4 = DMat4::from_diagonal(DVec4::new(2.0, 2.0, 2.0, 1.0));
+const TRANSFORM: DMat4 = TRANSLATE.const_mul_mat4(&SCALE);
+
+assert_eq!(TRANSLATE * SCALE, TRANSFORM);
+# }
+```
+
 ## glam assertions
 
 `glam` does not enforce validity checks on method parameters at runtime. For example methods that
@@ -235,9 +323,21 @@ and benchmarks.
 * `approx` - traits and macros for approximate float comparisons
 * `arbitrary` - implementations of `Arbitrary` trait for all `glam` types.
 * `bytemuck` - for casting into slices of bytes
-* `encase` - `encase` trait implementations for `glam` types.
+* `encase` - `encase` trait implementations for `glam` types, except `F16`, `HVec2`, `HVec3` and
+  `HVec4`.
 * `libm` - uses `libm` math functions instead of `std`
 * `mint` - for interoperating with other 3D math libraries
+* `nanoserde` - implementations of `SerJson`, `DeJson`, `SerBin`, `DeBin`, `SerRon` and `DeRon` for
+  vector, quaternion, dual quaternion, matrix, affine, isometry and vector mask types. The binary
+  format is the sequence of components (in column major order for matrices) encoded as little-endian
+  scalars, without any padding, with mask lanes encoded as one byte each. RON is written as a tuple,
+  e.g. `(1.0, 2.0, 3.0)`, and may also be read from a list. `EulerRot` is written as its variant
+  name. Deserialization errors name the failing component and its type, e.g.
+  `Cannot parse Vec3[1], expected f32`. How float components are read is controlled at runtime
+  with a `NanoserdePolicy`, see `DeJsonWith`, `DeRonWith`, `Finite` and `Lenient`. By default
+  integers are accepted for float components. JSON is written as flat arrays, `SerJsonNamed` and
+  the `Named` wrapper write objects with named fields instead, e.g. `{"x":1.0,"y":2.0}` for vectors
+  and `{"x_axis":..,"y_axis":..}` for matrices. Both forms are always accepted when deserializing.
 * `rand` - implementations of `Distribution` trait for all `glam` types.
 * `rkyv` - implementations of `Archive`, `Serialize` and `Deserialize` for all
   `glam` types. Note that serialization is not interoperable with and without the
@@ -259,10 +359,18 @@ and benchmarks.
 * `glam-assert` - adds assertions to all builds which check the validity of parameters passed to
   `glam` to help catch runtime errors.
 * `cuda` - forces `glam` types to match expected cuda alignment
+* `int-simd` - stores [`IVec4`], [`UVec4`], [`IVec3A`] and [`UVec3A`] in SIMD registers. This
+  makes [`IVec4`] and [`UVec4`] 16 byte aligned and their comparisons return [`BVec4A`].
 * `fast-math` - By default, glam attempts to provide bit-for-bit identical results on all platforms.
   Using this feature will enable platform specific optimizations that may not be identical to other
   platforms. **Intermediate libraries should not use this feature and defer the decision to the
   final binary build**.
+* `deterministic` - makes vector dot products, lengths and normalization, quaternion
+  multiplication, slerp and [`Quat::from_euler`] and [`Mat4::inverse`] give bit-for-bit identical
+  results across the SIMD and scalar backends by evaluating them in the same order everywhere and
+  using `libm`. Takes precedence over `fast-math`.
+* `const-float-arithmetic` - adds `const_` prefixed arithmetic methods to the float vector,
+  matrix, affine and quaternion types. This requires Rust 1.82.0 or later.
 * `core-simd` - enables SIMD support via the portable simd module. This is an unstable feature which
   requires a nightly Rust toolchain and `std` support.
 
@@ -296,6 +404,9 @@ compile_error!(
     "You must specify a math backend. Consider enabling either `std`, `libm`, or `nostd-libm`."
 );
 
+#[cfg(feature = "nanoserde")]
+extern crate alloc;
+
 #[macro_use]
 mod macros;
 
@@ -346,6 +457,10 @@ pub use self::f32::*;
 pub mod f64;
 pub use self::f64::*;
 
+/** `f16` half-precision vector types. */
+pub mod f16;
+pub use self::f16::*;
+
 /** `i8` vector types. */
 pub mod i8;
 pub use self::i8::*;
@@ -362,7 +477,7 @@ pub use self::i16::*;
 pub mod u16;
 pub use self::u16::*;
 
-/** `i32` vector types. */
+/** `i32` vector and matrix types. */
 pub mod i32;
 pub use self::i32::*;
 
@@ -370,7 +485,7 @@ pub use self::i32::*;
 pub mod u32;
 pub use self::u32::*;
 
-/** `i64` vector types. */
+/** `i64` vector and matrix types. */
 pub mod i64;
 pub use self::i64::*;
 
@@ -396,3 +511,8 @@ pub use euler::EulerRot;
 /** A trait for extending [`prim@f32`] and [`prim@f64`] with extra methods. */
 mod float;
 pub use float::FloatExt;
+
+#[cfg(feature = "nanoserde")]
+pub use features::impl_nanoserde::{
+    DeJsonWith, DeRonWith, Finite, Lenient, Named, NanoserdePolicy, SerJsonNamed,
+};
@@ -23,8 +23,18 @@ pub const fn f32x4_from_array(f32x4: [f32; 4]) -> float32x4_t {
 #[inline]
 pub(crate) unsafe fn dot3(lhs: float32x4_t, rhs: float32x4_t) -> f32 {
     let x2_y2_z2_w2 = vmulq_f32(lhs, rhs);
-    let x2_y2_z2 = vsetq_lane_f32(0.0, x2_y2_z2_w2, 3);
-    vaddvq_f32(x2_y2_z2)
+    #[cfg(not(feature = "deterministic"))]
+    {
+        let x2_y2_z2 = vsetq_lane_f32(0.0, x2_y2_z2_w2, 3);
+        vaddvq_f32(x2_y2_z2)
+    }
+    // The pairwise horizontal add doesn't match the scalar order of additions.
+    #[cfg(feature = "deterministic")]
+    {
+        vgetq_lane_f32(x2_y2_z2_w2, 0)
+            + vgetq_lane_f32(x2_y2_z2_w2, 1)
+            + vgetq_lane_f32(x2_y2_z2_w2, 2)
+    }
     // let dot = dot3_in_x(lhs, rhs);
     // vdups_laneq_f32(dot, 0)
 }
@@ -41,7 +51,18 @@ pub(crate) unsafe fn dot3_into_f32x4(lhs: float32x4_t, rhs: float32x4_t) -> floa
 pub(crate) unsafe fn dot4(lhs: float32x4_t, rhs: float32x4_t) -> f32 {
     let x2_y2_z2_w2 = vmulq_f32(lhs, rhs);
     // TODO: horizontal add - might perform bad?
-    vaddvq_f32(x2_y2_z2_w2)
+    #[cfg(not(feature = "deterministic"))]
+    {
+        vaddvq_f32(x2_y2_z2_w2)
+    }
+    // The pairwise horizontal add doesn't match the scalar order of additions.
+    #[cfg(feature = "deterministic")]
+    {
+        vgetq_lane_f32(x2_y2_z2_w2, 0)
+            + vgetq_lane_f32(x2_y2_z2_w2, 1)
+            + vgetq_lane_f32(x2_y2_z2_w2, 2)
+            + vgetq_lane_f32(x2_y2_z2_w2, 3)
+    }
 }
 
 #[inline]
@@ -49,3 +70,54 @@ pub(crate) unsafe fn dot4_into_f32x4(lhs: float32x4_t, rhs: float32x4_t) -> floa
     let dot = dot4(lhs, rhs);
     vld1q_dup_f32(&dot as *const f32)
 }
+
+// The checked operations return `None` if any lane overflowed so the caller can redo the
+// operation with the scalar operators, which follow the `overflow-checks` profile setting.
+// The saturating add and subtract only differ from the wrapping ones in lanes that overflowed.
+#[cfg(feature = "int-simd")]
+#[inline(always)]
+pub(crate) unsafe fn s32x4_checked_add(a: int32x4_t, b: int32x4_t) -> Option<int32x4_t> {
+    let sum = vaddq_s32(a, b);
+    (vminvq_u32(vceqq_s32(sum, vqaddq_s32(a, b))) != 0).then_some(sum)
+}
+
+#[cfg(feature = "int-simd")]
+#[inline(always)]
+pub(crate) unsafe fn s32x4_checked_sub(a: int32x4_t, b: int32x4_t) -> Option<int32x4_t> {
+    let diff = vsubq_s32(a, b);
+    (vminvq_u32(vceqq_s32(diff, vqsubq_s32(a, b))) != 0).then_some(diff)
+}
+
+#[cfg(feature = "int-simd")]
+#[inline(always)]
+pub(crate) unsafe fn u32x4_checked_add(a: uint32x4_t, b: uint32x4_t) -> Option<uint32x4_t> {
+    let sum = vaddq_u32(a, b);
+    (vminvq_u32(vceqq_u32(sum, vqaddq_u32(a, b))) != 0).then_some(sum)
+}
+
+#[cfg(feature = "int-simd")]
+#[inline(always)]
+pub(crate) unsafe fn u32x4_checked_sub(a: uint32x4_t, b: uint32x4_t) -> Option<uint32x4_t> {
+    let diff = vsubq_u32(a, b);
+    (vminvq_u32(vceqq_u32(diff, vqsubq_u32(a, b))) != 0).then_some(diff)
+}
+
+// Like the SSE2 backend the multiplication is only done with SIMD if all lanes of both operands
+// fit in 16 bits, which is enough for the product to not overflow.
+#[cfg(feature = "int-simd")]
+#[inline(always)]
+pub(crate) unsafe fn s32x4_checked_mul(a: int32x4_t, b: int32x4_t) -> Option<int32x4_t> {
+    let bias = vdupq_n_u32(0x8000);
+    let high = vorrq_u32(
+        vshrq_n_u32::<16>(vaddq_u32(vreinterpretq_u32_s32(a), bias)),
+        vshrq_n_u32::<16>(vaddq_u32(vreinterpretq_u32_s32(b), bias)),
+    );
+    (vmaxvq_u32(high) == 0).then(|| vmulq_s32(a, b))
+}
+
+#[cfg(feature = "int-simd")]
+#[inline(always)]
+pub(crate) unsafe fn u32x4_checked_mul(a: uint32x4_t, b: uint32x4_t) -> Option<uint32x4_t> {
+    let high = vshrq_n_u32::<16>(vorrq_u32(a, b));
+    (vmaxvq_u32(high) == 0).then(|| vmulq_u32(a, b))
+}
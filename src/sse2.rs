@@ -51,10 +51,23 @@ pub(crate) unsafe fn dot3_in_x(lhs: __m128, rhs: __m128) -> __m128 {
 #[inline(always)]
 pub(crate) unsafe fn dot4_in_x(lhs: __m128, rhs: __m128) -> __m128 {
     let x2_y2_z2_w2 = _mm_mul_ps(lhs, rhs);
-    let z2_w2_0_0 = _mm_shuffle_ps(x2_y2_z2_w2, x2_y2_z2_w2, 0b00_00_11_10);
-    let x2z2_y2w2_0_0 = _mm_add_ps(x2_y2_z2_w2, z2_w2_0_0);
-    let y2w2_0_0_0 = _mm_shuffle_ps(x2z2_y2w2_0_0, x2z2_y2w2_0_0, 0b00_00_00_01);
-    _mm_add_ps(x2z2_y2w2_0_0, y2w2_0_0_0)
+    #[cfg(not(feature = "deterministic"))]
+    {
+        let z2_w2_0_0 = _mm_shuffle_ps(x2_y2_z2_w2, x2_y2_z2_w2, 0b00_00_11_10);
+        let x2z2_y2w2_0_0 = _mm_add_ps(x2_y2_z2_w2, z2_w2_0_0);
+        let y2w2_0_0_0 = _mm_shuffle_ps(x2z2_y2w2_0_0, x2z2_y2w2_0_0, 0b00_00_00_01);
+        _mm_add_ps(x2z2_y2w2_0_0, y2w2_0_0_0)
+    }
+    // Adds the products in the same order as the scalar implementation.
+    #[cfg(feature = "deterministic")]
+    {
+        let y2_0_0_0 = _mm_shuffle_ps(x2_y2_z2_w2, x2_y2_z2_w2, 0b00_00_00_01);
+        let z2_0_0_0 = _mm_shuffle_ps(x2_y2_z2_w2, x2_y2_z2_w2, 0b00_00_00_10);
+        let w2_0_0_0 = _mm_shuffle_ps(x2_y2_z2_w2, x2_y2_z2_w2, 0b00_00_00_11);
+        let x2y2_0_0_0 = _mm_add_ss(x2_y2_z2_w2, y2_0_0_0);
+        let x2y2z2_0_0_0 = _mm_add_ss(x2y2_0_0_0, z2_0_0_0);
+        _mm_add_ss(x2y2z2_0_0_0, w2_0_0_0)
+    }
 }
 
 #[inline]
@@ -126,14 +139,23 @@ pub(crate) unsafe fn m128_abs(v: __m128) -> __m128 {
 
 #[inline(always)]
 pub(crate) unsafe fn m128_mul_add(a: __m128, b: __m128, c: __m128) -> __m128 {
-    // Only enable fused multiply-adds here if "fast-math" is enabled and the
-    // platform supports it. Otherwise this may break cross-platform determinism.
-    #[cfg(all(feature = "fast-math", target_feature = "fma"))]
+    // Only enable fused multiply-adds here if "fast-math" is enabled, "deterministic" is not and
+    // the platform supports it. A fused multiply-add rounds once instead of twice, so the result
+    // can differ in the last bit from a separate multiply and add and from other platforms.
+    #[cfg(all(
+        feature = "fast-math",
+        not(feature = "deterministic"),
+        target_feature = "fma"
+    ))]
     {
         _mm_fmadd_ps(a, b, c)
     }
 
-    #[cfg(any(not(feature = "fast-math"), not(target_feature = "fma")))]
+    #[cfg(any(
+        not(feature = "fast-math"),
+        feature = "deterministic",
+        not(target_feature = "fma")
+    ))]
     {
         _mm_add_ps(_mm_mul_ps(a, b), c)
     }
@@ -144,6 +166,90 @@ pub(crate) unsafe fn m128_neg_mul_sub(a: __m128, b: __m128, c: __m128) -> __m128
     _mm_sub_ps(c, _mm_mul_ps(a, b))
 }
 
+/// Calculates `a * b - c`, fused under the same conditions as [`m128_mul_add`].
+// Only used by the `Mat4` inverse, which uses the scalar order in deterministic builds.
+#[cfg_attr(feature = "deterministic", allow(dead_code))]
+#[inline(always)]
+pub(crate) unsafe fn m128_mul_sub(a: __m128, b: __m128, c: __m128) -> __m128 {
+    #[cfg(all(
+        feature = "fast-math",
+        not(feature = "deterministic"),
+        target_feature = "fma"
+    ))]
+    {
+        _mm_fmsub_ps(a, b, c)
+    }
+
+    #[cfg(any(
+        not(feature = "fast-math"),
+        feature = "deterministic",
+        not(target_feature = "fma")
+    ))]
+    {
+        _mm_sub_ps(_mm_mul_ps(a, b), c)
+    }
+}
+
+/// Calculates `a * b + c`, fused under the same conditions as [`m128_mul_add`].
+#[cfg(target_feature = "avx")]
+#[inline(always)]
+pub(crate) unsafe fn m256_mul_add(a: __m256, b: __m256, c: __m256) -> __m256 {
+    #[cfg(all(
+        feature = "fast-math",
+        not(feature = "deterministic"),
+        target_feature = "fma"
+    ))]
+    {
+        _mm256_fmadd_ps(a, b, c)
+    }
+
+    #[cfg(any(
+        not(feature = "fast-math"),
+        feature = "deterministic",
+        not(target_feature = "fma")
+    ))]
+    {
+        _mm256_add_ps(_mm256_mul_ps(a, b), c)
+    }
+}
+
+/// Calculates `a * b + c`, fused under the same conditions as [`m128_mul_add`].
+#[cfg(target_feature = "avx")]
+#[inline(always)]
+pub(crate) unsafe fn m256d_mul_add(a: __m256d, b: __m256d, c: __m256d) -> __m256d {
+    #[cfg(all(
+        feature = "fast-math",
+        not(feature = "deterministic"),
+        target_feature = "fma"
+    ))]
+    {
+        _mm256_fmadd_pd(a, b, c)
+    }
+
+    #[cfg(any(
+        not(feature = "fast-math"),
+        feature = "deterministic",
+        not(target_feature = "fma")
+    ))]
+    {
+        _mm256_add_pd(_mm256_mul_pd(a, b), c)
+    }
+}
+
+/// Joins the two lane registers used by the `f64` types into one AVX register.
+#[cfg(target_feature = "avx")]
+#[inline(always)]
+pub(crate) unsafe fn m256d_from_m128d_x2(v: [__m128d; 2]) -> __m256d {
+    _mm256_set_m128d(v[1], v[0])
+}
+
+/// Splits an AVX register into the two lane registers used by the `f64` types.
+#[cfg(target_feature = "avx")]
+#[inline(always)]
+pub(crate) unsafe fn m128d_x2_from_m256d(v: __m256d) -> [__m128d; 2] {
+    [_mm256_castpd256_pd128(v), _mm256_extractf128_pd(v, 1)]
+}
+
 #[inline]
 pub(crate) unsafe fn m128_round(v: __m128) -> __m128 {
     // Based on https://github.com/microsoft/DirectXMath `XMVectorRound`
@@ -189,6 +295,8 @@ pub(crate) unsafe fn m128_mod_angles(angles: __m128) -> __m128 {
 /// Computes the sine of the angle in each lane of `v`. Values outside
 /// the bounds of PI may produce an increasing error as the input angle
 /// drifts from `[-PI, PI]`.
+// Not used by `Quat::slerp` when the `deterministic` feature is enabled.
+#[cfg_attr(feature = "deterministic", allow(dead_code))]
 #[inline]
 pub(crate) unsafe fn m128_sin(v: __m128) -> __m128 {
     // Based on https://github.com/microsoft/DirectXMath `XMVectorSin`
@@ -235,6 +343,144 @@ pub(crate) unsafe fn m128_sin(v: __m128) -> __m128 {
     result
 }
 
+#[cfg(feature = "int-simd")]
+#[inline(always)]
+pub(crate) unsafe fn m128i_not(v: __m128i) -> __m128i {
+    _mm_xor_si128(v, _mm_set1_epi32(-1))
+}
+
+#[cfg(feature = "int-simd")]
+#[inline(always)]
+pub(crate) unsafe fn m128i_select(mask: __m128i, if_true: __m128i, if_false: __m128i) -> __m128i {
+    _mm_or_si128(
+        _mm_andnot_si128(mask, if_false),
+        _mm_and_si128(if_true, mask),
+    )
+}
+
+// SSE2 only has signed 32-bit integer comparisons, flipping the sign bit of both operands maps
+// unsigned ordering onto signed ordering.
+#[cfg(feature = "int-simd")]
+#[inline(always)]
+pub(crate) unsafe fn m128i_cmplt_epu32(a: __m128i, b: __m128i) -> __m128i {
+    let sign = _mm_set1_epi32(i32::MIN);
+    _mm_cmplt_epi32(_mm_xor_si128(a, sign), _mm_xor_si128(b, sign))
+}
+
+#[cfg(feature = "int-simd")]
+#[inline(always)]
+pub(crate) unsafe fn m128i_cmpgt_epu32(a: __m128i, b: __m128i) -> __m128i {
+    let sign = _mm_set1_epi32(i32::MIN);
+    _mm_cmpgt_epi32(_mm_xor_si128(a, sign), _mm_xor_si128(b, sign))
+}
+
+// `_mm_min_epi32` and friends are SSE4.1.
+#[cfg(feature = "int-simd")]
+#[inline(always)]
+pub(crate) unsafe fn m128i_min_epi32(a: __m128i, b: __m128i) -> __m128i {
+    m128i_select(_mm_cmplt_epi32(a, b), a, b)
+}
+
+#[cfg(feature = "int-simd")]
+#[inline(always)]
+pub(crate) unsafe fn m128i_max_epi32(a: __m128i, b: __m128i) -> __m128i {
+    m128i_select(_mm_cmpgt_epi32(a, b), a, b)
+}
+
+#[cfg(feature = "int-simd")]
+#[inline(always)]
+pub(crate) unsafe fn m128i_min_epu32(a: __m128i, b: __m128i) -> __m128i {
+    m128i_select(m128i_cmplt_epu32(a, b), a, b)
+}
+
+#[cfg(feature = "int-simd")]
+#[inline(always)]
+pub(crate) unsafe fn m128i_max_epu32(a: __m128i, b: __m128i) -> __m128i {
+    m128i_select(m128i_cmpgt_epu32(a, b), a, b)
+}
+
+// `_mm_mullo_epi32` is SSE4.1, SSE2 can only multiply the even lanes into 64-bit results so the
+// odd lanes are shifted down and the low halves of both products are interleaved back together.
+#[cfg(feature = "int-simd")]
+#[inline(always)]
+pub(crate) unsafe fn m128i_mullo_epi32(a: __m128i, b: __m128i) -> __m128i {
+    #[cfg(target_feature = "sse4.1")]
+    {
+        _mm_mullo_epi32(a, b)
+    }
+    #[cfg(not(target_feature = "sse4.1"))]
+    {
+        let even = _mm_mul_epu32(a, b);
+        let odd = _mm_mul_epu32(_mm_srli_epi64(a, 32), _mm_srli_epi64(b, 32));
+        _mm_unpacklo_epi32(
+            _mm_shuffle_epi32(even, 0b00_00_10_00),
+            _mm_shuffle_epi32(odd, 0b00_00_10_00),
+        )
+    }
+}
+
+#[cfg(feature = "int-simd")]
+#[inline(always)]
+unsafe fn m128i_any_sign_bit(v: __m128i) -> bool {
+    _mm_movemask_ps(_mm_castsi128_ps(v)) != 0
+}
+
+// The checked operations return `None` if any lane overflowed so the caller can redo the
+// operation with the scalar operators, which follow the `overflow-checks` profile setting.
+#[cfg(feature = "int-simd")]
+#[inline(always)]
+pub(crate) unsafe fn m128i_checked_add_epi32(a: __m128i, b: __m128i) -> Option<__m128i> {
+    let sum = _mm_add_epi32(a, b);
+    // A lane overflowed if both operands have a different sign than the sum.
+    let overflow = _mm_and_si128(_mm_xor_si128(a, sum), _mm_xor_si128(b, sum));
+    (!m128i_any_sign_bit(overflow)).then_some(sum)
+}
+
+#[cfg(feature = "int-simd")]
+#[inline(always)]
+pub(crate) unsafe fn m128i_checked_sub_epi32(a: __m128i, b: __m128i) -> Option<__m128i> {
+    let diff = _mm_sub_epi32(a, b);
+    // A lane overflowed if the operands have different signs and the difference doesn't have the
+    // sign of `a`.
+    let overflow = _mm_and_si128(_mm_xor_si128(a, b), _mm_xor_si128(a, diff));
+    (!m128i_any_sign_bit(overflow)).then_some(diff)
+}
+
+#[cfg(feature = "int-simd")]
+#[inline(always)]
+pub(crate) unsafe fn m128i_checked_add_epu32(a: __m128i, b: __m128i) -> Option<__m128i> {
+    let sum = _mm_add_epi32(a, b);
+    (!m128i_any_sign_bit(m128i_cmplt_epu32(sum, a))).then_some(sum)
+}
+
+#[cfg(feature = "int-simd")]
+#[inline(always)]
+pub(crate) unsafe fn m128i_checked_sub_epu32(a: __m128i, b: __m128i) -> Option<__m128i> {
+    (!m128i_any_sign_bit(m128i_cmplt_epu32(a, b))).then_some(_mm_sub_epi32(a, b))
+}
+
+// SSE2 has no cheap way to get the high half of a 32-bit product, so the multiplication is only
+// done with SIMD if all lanes of both operands fit in 16 bits and `None` is returned otherwise.
+#[cfg(feature = "int-simd")]
+#[inline(always)]
+pub(crate) unsafe fn m128i_checked_mul_epi32(a: __m128i, b: __m128i) -> Option<__m128i> {
+    let bias = _mm_set1_epi32(0x8000);
+    let high = _mm_or_si128(
+        _mm_srli_epi32(_mm_add_epi32(a, bias), 16),
+        _mm_srli_epi32(_mm_add_epi32(b, bias), 16),
+    );
+    let fits = _mm_movemask_epi8(_mm_cmpeq_epi32(high, _mm_setzero_si128())) == 0xffff;
+    fits.then(|| m128i_mullo_epi32(a, b))
+}
+
+#[cfg(feature = "int-simd")]
+#[inline(always)]
+pub(crate) unsafe fn m128i_checked_mul_epu32(a: __m128i, b: __m128i) -> Option<__m128i> {
+    let high = _mm_srli_epi32(_mm_or_si128(a, b), 16);
+    let fits = _mm_movemask_epi8(_mm_cmpeq_epi32(high, _mm_setzero_si128())) == 0xffff;
+    fits.then(|| m128i_mullo_epi32(a, b))
+}
+
 #[test]
 fn test_sse2_m128_sin() {
     use crate::Vec4;
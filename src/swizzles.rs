@@ -4,6 +4,7 @@ mod dvec4_impl;
 
 mod ivec2_impl;
 mod ivec3_impl;
+mod ivec3a_impl;
 mod ivec4_impl;
 
 mod i8vec2_impl;
@@ -40,6 +41,7 @@ mod usizevec4_impl;
 
 mod uvec2_impl;
 mod uvec3_impl;
+mod uvec3a_impl;
 mod uvec4_impl;
 
 mod vec2_impl;
@@ -0,0 +1,646 @@
+// Generated from swizzle_impl.rs.tera template. Edit the template, not the generated file.
+
+use crate::{UVec2, UVec3A, UVec4, Vec3Swizzles};
+
+impl Vec3Swizzles for UVec3A {
+    type Vec2 = UVec2;
+
+    type Vec4 = UVec4;
+
+    #[inline]
+    fn xx(self) -> UVec2 {
+        UVec2 {
+            x: self.x,
+            y: self.x,
+        }
+    }
+
+    #[inline]
+    fn xy(self) -> UVec2 {
+        UVec2 {
+            x: self.x,
+            y: self.y,
+        }
+    }
+
+    #[inline]
+    fn with_xy(self, rhs: UVec2) -> Self {
+        Self::new(rhs.x, rhs.y, self.z)
+    }
+
+    #[inline]
+    fn xz(self) -> UVec2 {
+        UVec2 {
+            x: self.x,
+            y: self.z,
+        }
+    }
+
+    #[inline]
+    fn with_xz(self, rhs: UVec2) -> Self {
+        Self::new(rhs.x, self.y, rhs.y)
+    }
+
+    #[inline]
+    fn yx(self) -> UVec2 {
+        UVec2 {
+            x: self.y,
+            y: self.x,
+        }
+    }
+
+    #[inline]
+    fn with_yx(self, rhs: UVec2) -> Self {
+        Self::new(rhs.y, rhs.x, self.z)
+    }
+
+    #[inline]
+    fn yy(self) -> UVec2 {
+        UVec2 {
+            x: self.y,
+            y: self.y,
+        }
+    }
+
+    #[inline]
+    fn yz(self) -> UVec2 {
+        UVec2 {
+            x: self.y,
+            y: self.z,
+        }
+    }
+
+    #[inline]
+    fn with_yz(self, rhs: UVec2) -> Self {
+        Self::new(self.x, rhs.x, rhs.y)
+    }
+
+    #[inline]
+    fn zx(self) -> UVec2 {
+        UVec2 {
+            x: self.z,
+            y: self.x,
+        }
+    }
+
+    #[inline]
+    fn with_zx(self, rhs: UVec2) -> Self {
+        Self::new(rhs.y, self.y, rhs.x)
+    }
+
+    #[inline]
+    fn zy(self) -> UVec2 {
+        UVec2 {
+            x: self.z,
+            y: self.y,
+        }
+    }
+
+    #[inline]
+    fn with_zy(self, rhs: UVec2) -> Self {
+        Self::new(self.x, rhs.y, rhs.x)
+    }
+
+    #[inline]
+    fn zz(self) -> UVec2 {
+        UVec2 {
+            x: self.z,
+            y: self.z,
+        }
+    }
+
+    #[inline]
+    fn xxx(self) -> Self {
+        Self::new(self.x, self.x, self.x)
+    }
+
+    #[inline]
+    fn xxy(self) -> Self {
+        Self::new(self.x, self.x, self.y)
+    }
+
+    #[inline]
+    fn xxz(self) -> Self {
+        Self::new(self.x, self.x, self.z)
+    }
+
+    #[inline]
+    fn xyx(self) -> Self {
+        Self::new(self.x, self.y, self.x)
+    }
+
+    #[inline]
+    fn xyy(self) -> Self {
+        Self::new(self.x, self.y, self.y)
+    }
+
+    #[inline]
+    fn xzx(self) -> Self {
+        Self::new(self.x, self.z, self.x)
+    }
+
+    #[inline]
+    fn xzy(self) -> Self {
+        Self::new(self.x, self.z, self.y)
+    }
+
+    #[inline]
+    fn xzz(self) -> Self {
+        Self::new(self.x, self.z, self.z)
+    }
+
+    #[inline]
+    fn yxx(self) -> Self {
+        Self::new(self.y, self.x, self.x)
+    }
+
+    #[inline]
+    fn yxy(self) -> Self {
+        Self::new(self.y, self.x, self.y)
+    }
+
+    #[inline]
+    fn yxz(self) -> Self {
+        Self::new(self.y, self.x, self.z)
+    }
+
+    #[inline]
+    fn yyx(self) -> Self {
+        Self::new(self.y, self.y, self.x)
+    }
+
+    #[inline]
+    fn yyy(self) -> Self {
+        Self::new(self.y, self.y, self.y)
+    }
+
+    #[inline]
+    fn yyz(self) -> Self {
+        Self::new(self.y, self.y, self.z)
+    }
+
+    #[inline]
+    fn yzx(self) -> Self {
+        Self::new(self.y, self.z, self.x)
+    }
+
+    #[inline]
+    fn yzy(self) -> Self {
+        Self::new(self.y, self.z, self.y)
+    }
+
+    #[inline]
+    fn yzz(self) -> Self {
+        Self::new(self.y, self.z, self.z)
+    }
+
+    #[inline]
+    fn zxx(self) -> Self {
+        Self::new(self.z, self.x, self.x)
+    }
+
+    #[inline]
+    fn zxy(self) -> Self {
+        Self::new(self.z, self.x, self.y)
+    }
+
+    #[inline]
+    fn zxz(self) -> Self {
+        Self::new(self.z, self.x, self.z)
+    }
+
+    #[inline]
+    fn zyx(self) -> Self {
+        Self::new(self.z, self.y, self.x)
+    }
+
+    #[inline]
+    fn zyy(self) -> Self {
+        Self::new(self.z, self.y, self.y)
+    }
+
+    #[inline]
+    fn zyz(self) -> Self {
+        Self::new(self.z, self.y, self.z)
+    }
+
+    #[inline]
+    fn zzx(self) -> Self {
+        Self::new(self.z, self.z, self.x)
+    }
+
+    #[inline]
+    fn zzy(self) -> Self {
+        Self::new(self.z, self.z, self.y)
+    }
+
+    #[inline]
+    fn zzz(self) -> Self {
+        Self::new(self.z, self.z, self.z)
+    }
+
+    #[inline]
+    fn xxxx(self) -> UVec4 {
+        UVec4::new(self.x, self.x, self.x, self.x)
+    }
+
+    #[inline]
+    fn xxxy(self) -> UVec4 {
+        UVec4::new(self.x, self.x, self.x, self.y)
+    }
+
+    #[inline]
+    fn xxxz(self) -> UVec4 {
+        UVec4::new(self.x, self.x, self.x, self.z)
+    }
+
+    #[inline]
+    fn xxyx(self) -> UVec4 {
+        UVec4::new(self.x, self.x, self.y, self.x)
+    }
+
+    #[inline]
+    fn xxyy(self) -> UVec4 {
+        UVec4::new(self.x, self.x, self.y, self.y)
+    }
+
+    #[inline]
+    fn xxyz(self) -> UVec4 {
+        UVec4::new(self.x, self.x, self.y, self.z)
+    }
+
+    #[inline]
+    fn xxzx(self) -> UVec4 {
+        UVec4::new(self.x, self.x, self.z, self.x)
+    }
+
+    #[inline]
+    fn xxzy(self) -> UVec4 {
+        UVec4::new(self.x, self.x, self.z, self.y)
+    }
+
+    #[inline]
+    fn xxzz(self) -> UVec4 {
+        UVec4::new(self.x, self.x, self.z, self.z)
+    }
+
+    #[inline]
+    fn xyxx(self) -> UVec4 {
+        UVec4::new(self.x, self.y, self.x, self.x)
+    }
+
+    #[inline]
+    fn xyxy(self) -> UVec4 {
+        UVec4::new(self.x, self.y, self.x, self.y)
+    }
+
+    #[inline]
+    fn xyxz(self) -> UVec4 {
+        UVec4::new(self.x, self.y, self.x, self.z)
+    }
+
+    #[inline]
+    fn xyyx(self) -> UVec4 {
+        UVec4::new(self.x, self.y, self.y, self.x)
+    }
+
+    #[inline]
+    fn xyyy(self) -> UVec4 {
+        UVec4::new(self.x, self.y, self.y, self.y)
+    }
+
+    #[inline]
+    fn xyyz(self) -> UVec4 {
+        UVec4::new(self.x, self.y, self.y, self.z)
+    }
+
+    #[inline]
+    fn xyzx(self) -> UVec4 {
+        UVec4::new(self.x, self.y, self.z, self.x)
+    }
+
+    #[inline]
+    fn xyzy(self) -> UVec4 {
+        UVec4::new(self.x, self.y, self.z, self.y)
+    }
+
+    #[inline]
+    fn xyzz(self) -> UVec4 {
+        UVec4::new(self.x, self.y, self.z, self.z)
+    }
+
+    #[inline]
+    fn xzxx(self) -> UVec4 {
+        UVec4::new(self.x, self.z, self.x, self.x)
+    }
+
+    #[inline]
+    fn xzxy(self) -> UVec4 {
+        UVec4::new(self.x, self.z, self.x, self.y)
+    }
+
+    #[inline]
+    fn xzxz(self) -> UVec4 {
+        UVec4::new(self.x, self.z, self.x, self.z)
+    }
+
+    #[inline]
+    fn xzyx(self) -> UVec4 {
+        UVec4::new(self.x, self.z, self.y, self.x)
+    }
+
+    #[inline]
+    fn xzyy(self) -> UVec4 {
+        UVec4::new(self.x, self.z, self.y, self.y)
+    }
+
+    #[inline]
+    fn xzyz(self) -> UVec4 {
+        UVec4::new(self.x, self.z, self.y, self.z)
+    }
+
+    #[inline]
+    fn xzzx(self) -> UVec4 {
+        UVec4::new(self.x, self.z, self.z, self.x)
+    }
+
+    #[inline]
+    fn xzzy(self) -> UVec4 {
+        UVec4::new(self.x, self.z, self.z, self.y)
+    }
+
+    #[inline]
+    fn xzzz(self) -> UVec4 {
+        UVec4::new(self.x, self.z, self.z, self.z)
+    }
+
+    #[inline]
+    fn yxxx(self) -> UVec4 {
+        UVec4::new(self.y, self.x, self.x, self.x)
+    }
+
+    #[inline]
+    fn yxxy(self) -> UVec4 {
+        UVec4::new(self.y, self.x, self.x, self.y)
+    }
+
+    #[inline]
+    fn yxxz(self) -> UVec4 {
+        UVec4::new(self.y, self.x, self.x, self.z)
+    }
+
+    #[inline]
+    fn yxyx(self) -> UVec4 {
+        UVec4::new(self.y, self.x, self.y, self.x)
+    }
+
+    #[inline]
+    fn yxyy(self) -> UVec4 {
+        UVec4::new(self.y, self.x, self.y, self.y)
+    }
+
+    #[inline]
+    fn yxyz(self) -> UVec4 {
+        UVec4::new(self.y, self.x, self.y, self.z)
+    }
+
+    #[inline]
+    fn yxzx(self) -> UVec4 {
+        UVec4::new(self.y, self.x, self.z, self.x)
+    }
+
+    #[inline]
+    fn yxzy(self) -> UVec4 {
+        UVec4::new(self.y, self.x, self.z, self.y)
+    }
+
+    #[inline]
+    fn yxzz(self) -> UVec4 {
+        UVec4::new(self.y, self.x, self.z, self.z)
+    }
+
+    #[inline]
+    fn yyxx(self) -> UVec4 {
+        UVec4::new(self.y, self.y, self.x, self.x)
+    }
+
+    #[inline]
+    fn yyxy(self) -> UVec4 {
+        UVec4::new(self.y, self.y, self.x, self.y)
+    }
+
+    #[inline]
+    fn yyxz(self) -> UVec4 {
+        UVec4::new(self.y, self.y, self.x, self.z)
+    }
+
+    #[inline]
+    fn yyyx(self) -> UVec4 {
+        UVec4::new(self.y, self.y, self.y, self.x)
+    }
+
+    #[inline]
+    fn yyyy(self) -> UVec4 {
+        UVec4::new(self.y, self.y, self.y, self.y)
+    }
+
+    #[inline]
+    fn yyyz(self) -> UVec4 {
+        UVec4::new(self.y, self.y, self.y, self.z)
+    }
+
+    #[inline]
+    fn yyzx(self) -> UVec4 {
+        UVec4::new(self.y, self.y, self.z, self.x)
+    }
+
+    #[inline]
+    fn yyzy(self) -> UVec4 {
+        UVec4::new(self.y, self.y, self.z, self.y)
+    }
+
+    #[inline]
+    fn yyzz(self) -> UVec4 {
+        UVec4::new(self.y, self.y, self.z, self.z)
+    }
+
+    #[inline]
+    fn yzxx(self) -> UVec4 {
+        UVec4::new(self.y, self.z, self.x, self.x)
+    }
+
+    #[inline]
+    fn yzxy(self) -> UVec4 {
+        UVec4::new(self.y, self.z, self.x, self.y)
+    }
+
+    #[inline]
+    fn yzxz(self) -> UVec4 {
+        UVec4::new(self.y, self.z, self.x, self.z)
+    }
+
+    #[inline]
+    fn yzyx(self) -> UVec4 {
+        UVec4::new(self.y, self.z, self.y, self.x)
+    }
+
+    #[inline]
+    fn yzyy(self) -> UVec4 {
+        UVec4::new(self.y, self.z, self.y, self.y)
+    }
+
+    #[inline]
+    fn yzyz(self) -> UVec4 {
+        UVec4::new(self.y, self.z, self.y, self.z)
+    }
+
+    #[inline]
+    fn yzzx(self) -> UVec4 {
+        UVec4::new(self.y, self.z, self.z, self.x)
+    }
+
+    #[inline]
+    fn yzzy(self) -> UVec4 {
+        UVec4::new(self.y, self.z, self.z, self.y)
+    }
+
+    #[inline]
+    fn yzzz(self) -> UVec4 {
+        UVec4::new(self.y, self.z, self.z, self.z)
+    }
+
+    #[inline]
+    fn zxxx(self) -> UVec4 {
+        UVec4::new(self.z, self.x, self.x, self.x)
+    }
+
+    #[inline]
+    fn zxxy(self) -> UVec4 {
+        UVec4::new(self.z, self.x, self.x, self.y)
+    }
+
+    #[inline]
+    fn zxxz(self) -> UVec4 {
+        UVec4::new(self.z, self.x, self.x, self.z)
+    }
+
+    #[inline]
+    fn zxyx(self) -> UVec4 {
+        UVec4::new(self.z, self.x, self.y, self.x)
+    }
+
+    #[inline]
+    fn zxyy(self) -> UVec4 {
+        UVec4::new(self.z, self.x, self.y, self.y)
+    }
+
+    #[inline]
+    fn zxyz(self) -> UVec4 {
+        UVec4::new(self.z, self.x, self.y, self.z)
+    }
+
+    #[inline]
+    fn zxzx(self) -> UVec4 {
+        UVec4::new(self.z, self.x, self.z, self.x)
+    }
+
+    #[inline]
+    fn zxzy(self) -> UVec4 {
+        UVec4::new(self.z, self.x, self.z, self.y)
+    }
+
+    #[inline]
+    fn zxzz(self) -> UVec4 {
+        UVec4::new(self.z, self.x, self.z, self.z)
+    }
+
+    #[inline]
+    fn zyxx(self) -> UVec4 {
+        UVec4::new(self.z, self.y, self.x, self.x)
+    }
+
+    #[inline]
+    fn zyxy(self) -> UVec4 {
+        UVec4::new(self.z, self.y, self.x, self.y)
+    }
+
+    #[inline]
+    fn zyxz(self) -> UVec4 {
+        UVec4::new(self.z, self.y, self.x, self.z)
+    }
+
+    #[inline]
+    fn zyyx(self) -> UVec4 {
+        UVec4::new(self.z, self.y, self.y, self.x)
+    }
+
+    #[inline]
+    fn zyyy(self) -> UVec4 {
+        UVec4::new(self.z, self.y, self.y, self.y)
+    }
+
+    #[inline]
+    fn zyyz(self) -> UVec4 {
+        UVec4::new(self.z, self.y, self.y, self.z)
+    }
+
+    #[inline]
+    fn zyzx(self) -> UVec4 {
+        UVec4::new(self.z, self.y, self.z, self.x)
+    }
+
+    #[inline]
+    fn zyzy(self) -> UVec4 {
+        UVec4::new(self.z, self.y, self.z, self.y)
+    }
+
+    #[inline]
+    fn zyzz(self) -> UVec4 {
+        UVec4::new(self.z, self.y, self.z, self.z)
+    }
+
+    #[inline]
+    fn zzxx(self) -> UVec4 {
+        UVec4::new(self.z, self.z, self.x, self.x)
+    }
+
+    #[inline]
+    fn zzxy(self) -> UVec4 {
+        UVec4::new(self.z, self.z, self.x, self.y)
+    }
+
+    #[inline]
+    fn zzxz(self) -> UVec4 {
+        UVec4::new(self.z, self.z, self.x, self.z)
+    }
+
+    #[inline]
+    fn zzyx(self) -> UVec4 {
+        UVec4::new(self.z, self.z, self.y, self.x)
+    }
+
+    #[inline]
+    fn zzyy(self) -> UVec4 {
+        UVec4::new(self.z, self.z, self.y, self.y)
+    }
+
+    #[inline]
+    fn zzyz(self) -> UVec4 {
+        UVec4::new(self.z, self.z, self.y, self.z)
+    }
+
+    #[inline]
+    fn zzzx(self) -> UVec4 {
+        UVec4::new(self.z, self.z, self.z, self.x)
+    }
+
+    #[inline]
+    fn zzzy(self) -> UVec4 {
+        UVec4::new(self.z, self.z, self.z, self.y)
+    }
+
+    #[inline]
+    fn zzzz(self) -> UVec4 {
+        UVec4::new(self.z, self.z, self.z, self.z)
+    }
+}
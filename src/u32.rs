@@ -1,9 +1,75 @@
 mod uvec2;
 mod uvec3;
-mod uvec4;
+
+#[cfg(any(
+    not(any(
+        target_arch = "aarch64",
+        target_feature = "sse2",
+        target_feature = "simd128"
+    )),
+    not(feature = "int-simd"),
+    feature = "core-simd",
+    feature = "scalar-math"
+))]
+mod scalar;
+
+#[cfg(all(
+    target_arch = "aarch64",
+    feature = "int-simd",
+    not(any(feature = "core-simd", feature = "scalar-math"))
+))]
+mod neon;
+
+#[cfg(all(
+    target_feature = "sse2",
+    feature = "int-simd",
+    not(any(feature = "core-simd", feature = "scalar-math"))
+))]
+mod sse2;
+
+#[cfg(all(
+    target_feature = "simd128",
+    feature = "int-simd",
+    not(any(feature = "core-simd", feature = "scalar-math"))
+))]
+mod wasm;
+
+#[cfg(any(
+    not(any(
+        target_arch = "aarch64",
+        target_feature = "sse2",
+        target_feature = "simd128"
+    )),
+    not(feature = "int-simd"),
+    feature = "core-simd",
+    feature = "scalar-math"
+))]
+use scalar::*;
+
+#[cfg(all(
+    target_arch = "aarch64",
+    feature = "int-simd",
+    not(any(feature = "core-simd", feature = "scalar-math"))
+))]
+use neon::*;
+
+#[cfg(all(
+    target_feature = "sse2",
+    feature = "int-simd",
+    not(any(feature = "core-simd", feature = "scalar-math"))
+))]
+use sse2::*;
+
+#[cfg(all(
+    target_feature = "simd128",
+    feature = "int-simd",
+    not(any(feature = "core-simd", feature = "scalar-math"))
+))]
+use wasm::*;
 
 pub use uvec2::{uvec2, UVec2};
 pub use uvec3::{uvec3, UVec3};
+pub use uvec3a::{uvec3a, UVec3A};
 pub use uvec4::{uvec4, UVec4};
 
 #[cfg(not(target_arch = "spirv"))]
@@ -30,15 +96,26 @@ mod test {
         );
     }
 
+    mod const_test_uvec3a {
+        const_assert_eq!(16, core::mem::align_of::<super::UVec3A>());
+        const_assert_eq!(16, core::mem::size_of::<super::UVec3A>());
+    }
+
     mod const_test_uvec4 {
         const_assert_eq!(16, core::mem::size_of::<super::UVec4>());
 
-        #[cfg(not(feature = "cuda"))]
+        #[cfg(not(any(
+            all(feature = "int-simd", not(feature = "scalar-math")),
+            feature = "cuda"
+        )))]
         const_assert_eq!(
             core::mem::align_of::<u32>(),
             core::mem::align_of::<super::UVec4>()
         );
-        #[cfg(feature = "cuda")]
+        #[cfg(any(
+            all(feature = "int-simd", not(feature = "scalar-math")),
+            feature = "cuda"
+        ))]
         const_assert_eq!(16, core::mem::align_of::<super::UVec4>());
     }
 }
@@ -1,10 +1,13 @@
 // Generated from vec.rs.tera template. Edit the template, not the generated file.
 
-#[cfg(not(feature = "scalar-math"))]
+#[cfg(any(feature = "scalar-math", not(feature = "int-simd")))]
+use crate::BVec4 as BVec4A;
+
+#[cfg(all(feature = "int-simd", not(feature = "scalar-math")))]
 use crate::BVec4A;
 use crate::{
     BVec4, I16Vec4, I64Vec4, I8Vec4, ISizeVec4, IVec4, U16Vec4, U64Vec4, U8Vec4, USizeVec4, UVec2,
-    UVec3,
+    UVec3, UVec3A,
 };
 
 use core::fmt;
@@ -28,7 +31,13 @@ pub const fn uvec4(x: u32, y: u32, z: u32, w: u32) -> UVec4 {
     feature = "zerocopy",
     derive(FromBytes, Immutable, IntoBytes, KnownLayout)
 )]
-#[cfg_attr(feature = "cuda", repr(align(16)))]
+#[cfg_attr(
+    any(
+        all(feature = "int-simd", not(feature = "scalar-math")),
+        feature = "cuda"
+    ),
+    repr(align(16))
+)]
 #[repr(C)]
 #[cfg_attr(target_arch = "spirv", rust_gpu::vector::v1)]
 pub struct UVec4 {
@@ -105,7 +114,7 @@ impl UVec4 {
     /// uses the element from `if_false`.
     #[inline]
     #[must_use]
-    pub fn select(mask: BVec4, if_true: Self, if_false: Self) -> Self {
+    pub fn select(mask: BVec4A, if_true: Self, if_false: Self) -> Self {
         Self {
             x: if mask.test(0) { if_true.x } else { if_false.x },
             y: if mask.test(1) { if_true.y } else { if_false.y },
@@ -153,6 +162,8 @@ impl UVec4 {
     /// Creates a 3D vector from the `x`, `y` and `z` elements of `self`, discarding `w`.
     ///
     /// Truncation to [`UVec3`] may also be performed by using [`self.xyz()`][crate::swizzles::Vec4Swizzles::xyz()].
+    ///
+    /// To truncate to [`UVec3A`] use [`UVec3A::from_vec4()`].
     #[inline]
     #[must_use]
     pub fn truncate(self) -> UVec3 {
@@ -335,8 +346,8 @@ impl UVec4 {
     /// elements.
     #[inline]
     #[must_use]
-    pub fn cmpeq(self, rhs: Self) -> BVec4 {
-        BVec4::new(
+    pub fn cmpeq(self, rhs: Self) -> BVec4A {
+        BVec4A::new(
             self.x.eq(&rhs.x),
             self.y.eq(&rhs.y),
             self.z.eq(&rhs.z),
@@ -351,8 +362,8 @@ impl UVec4 {
     /// elements.
     #[inline]
     #[must_use]
-    pub fn cmpne(self, rhs: Self) -> BVec4 {
-        BVec4::new(
+    pub fn cmpne(self, rhs: Self) -> BVec4A {
+        BVec4A::new(
             self.x.ne(&rhs.x),
             self.y.ne(&rhs.y),
             self.z.ne(&rhs.z),
@@ -367,8 +378,8 @@ impl UVec4 {
     /// elements.
     #[inline]
     #[must_use]
-    pub fn cmpge(self, rhs: Self) -> BVec4 {
-        BVec4::new(
+    pub fn cmpge(self, rhs: Self) -> BVec4A {
+        BVec4A::new(
             self.x.ge(&rhs.x),
             self.y.ge(&rhs.y),
             self.z.ge(&rhs.z),
@@ -383,8 +394,8 @@ impl UVec4 {
     /// elements.
     #[inline]
     #[must_use]
-    pub fn cmpgt(self, rhs: Self) -> BVec4 {
-        BVec4::new(
+    pub fn cmpgt(self, rhs: Self) -> BVec4A {
+        BVec4A::new(
             self.x.gt(&rhs.x),
             self.y.gt(&rhs.y),
             self.z.gt(&rhs.z),
@@ -399,8 +410,8 @@ impl UVec4 {
     /// elements.
     #[inline]
     #[must_use]
-    pub fn cmple(self, rhs: Self) -> BVec4 {
-        BVec4::new(
+    pub fn cmple(self, rhs: Self) -> BVec4A {
+        BVec4A::new(
             self.x.le(&rhs.x),
             self.y.le(&rhs.y),
             self.z.le(&rhs.z),
@@ -415,8 +426,8 @@ impl UVec4 {
     /// elements.
     #[inline]
     #[must_use]
-    pub fn cmplt(self, rhs: Self) -> BVec4 {
-        BVec4::new(
+    pub fn cmplt(self, rhs: Self) -> BVec4A {
+        BVec4A::new(
             self.x.lt(&rhs.x),
             self.y.lt(&rhs.y),
             self.z.lt(&rhs.z),
@@ -2988,6 +2999,20 @@ impl From<UVec4> for (u32, u32, u32, u32) {
     }
 }
 
+impl From<(UVec3A, u32)> for UVec4 {
+    #[inline]
+    fn from((v, w): (UVec3A, u32)) -> Self {
+        v.extend(w)
+    }
+}
+
+impl From<(u32, UVec3A)> for UVec4 {
+    #[inline]
+    fn from((x, v): (u32, UVec3A)) -> Self {
+        Self::new(x, v.x, v.y, v.z)
+    }
+}
+
 impl From<(UVec3, u32)> for UVec4 {
     #[inline]
     fn from((v, w): (UVec3, u32)) -> Self {
@@ -3151,9 +3176,9 @@ impl From<BVec4> for UVec4 {
 }
 
 #[cfg(not(feature = "scalar-math"))]
-impl From<BVec4A> for UVec4 {
+impl From<crate::BVec4A> for UVec4 {
     #[inline]
-    fn from(v: BVec4A) -> Self {
+    fn from(v: crate::BVec4A) -> Self {
         let bool_array: [bool; 4] = v.into();
         Self::new(
             u32::from(bool_array[0]),
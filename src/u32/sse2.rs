@@ -0,0 +1,2 @@
+pub mod uvec3a;
+pub mod uvec4;
@@ -0,0 +1,2926 @@
+// Generated from vec.rs.tera template. Edit the template, not the generated file.
+
+use crate::{sse2::*, BVec3, BVec3A, IVec3A, UVec2, UVec3, UVec4};
+
+use core::fmt;
+use core::iter::{Product, Sum};
+use core::{f32, ops::*};
+
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+#[cfg(feature = "zerocopy")]
+use zerocopy_derive::*;
+
+#[repr(C)]
+union UnionCast {
+    a: [u32; 4],
+    v: UVec3A,
+}
+
+/// Creates a 3-dimensional vector.
+#[inline(always)]
+#[must_use]
+pub const fn uvec3a(x: u32, y: u32, z: u32) -> UVec3A {
+    UVec3A::new(x, y, z)
+}
+
+/// A 3-dimensional vector.
+///
+/// SIMD vector types are used for storage on supported platforms for better
+/// performance than the [`UVec3`] type.
+///
+/// It is possible to convert between [`UVec3`] and [`UVec3A`] types using [`From`]
+/// or [`Into`] trait implementations.
+///
+/// This type is 16 byte aligned.
+#[derive(Clone, Copy)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(FromBytes, Immutable, IntoBytes, KnownLayout)
+)]
+#[repr(transparent)]
+pub struct UVec3A(pub(crate) __m128i);
+
+impl UVec3A {
+    /// All zeroes.
+    pub const ZERO: Self = Self::splat(0);
+
+    /// All ones.
+    pub const ONE: Self = Self::splat(1);
+
+    /// All `u32::MIN`.
+    pub const MIN: Self = Self::splat(u32::MIN);
+
+    /// All `u32::MAX`.
+    pub const MAX: Self = Self::splat(u32::MAX);
+
+    /// A unit vector pointing along the positive X axis.
+    pub const X: Self = Self::new(1, 0, 0);
+
+    /// A unit vector pointing along the positive Y axis.
+    pub const Y: Self = Self::new(0, 1, 0);
+
+    /// A unit vector pointing along the positive Z axis.
+    pub const Z: Self = Self::new(0, 0, 1);
+
+    /// The unit axes.
+    pub const AXES: [Self; 3] = [Self::X, Self::Y, Self::Z];
+
+    /// Creates a new vector.
+    #[inline(always)]
+    #[must_use]
+    pub const fn new(x: u32, y: u32, z: u32) -> Self {
+        unsafe { UnionCast { a: [x, y, z, z] }.v }
+    }
+
+    /// Creates a vector with all elements set to `v`.
+    #[inline]
+    #[must_use]
+    pub const fn splat(v: u32) -> Self {
+        unsafe { UnionCast { a: [v; 4] }.v }
+    }
+
+    /// Returns a vector containing each element of `self` modified by a mapping function `f`.
+    #[inline]
+    #[must_use]
+    pub fn map<F>(self, f: F) -> Self
+    where
+        F: Fn(u32) -> u32,
+    {
+        Self::new(f(self.x), f(self.y), f(self.z))
+    }
+
+    /// Creates a vector from the elements in `if_true` and `if_false`, selecting which to use
+    /// for each element of `self`.
+    ///
+    /// A true element in the mask uses the corresponding element from `if_true`, and false
+    /// uses the element from `if_false`.
+    #[inline]
+    #[must_use]
+    pub fn select(mask: BVec3A, if_true: Self, if_false: Self) -> Self {
+        Self(unsafe { m128i_select(_mm_castps_si128(mask.0), if_true.0, if_false.0) })
+    }
+
+    /// Creates a new vector from an array.
+    #[inline]
+    #[must_use]
+    pub const fn from_array(a: [u32; 3]) -> Self {
+        Self::new(a[0], a[1], a[2])
+    }
+
+    /// Converts `self` to `[x, y, z]`
+    #[inline]
+    #[must_use]
+    pub const fn to_array(&self) -> [u32; 3] {
+        unsafe { *(self as *const Self as *const [u32; 3]) }
+    }
+
+    /// Creates a vector from the first 3 values in `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than 3 elements long.
+    #[inline]
+    #[must_use]
+    pub const fn from_slice(slice: &[u32]) -> Self {
+        assert!(slice.len() >= 3);
+        Self::new(slice[0], slice[1], slice[2])
+    }
+
+    /// Writes the elements of `self` to the first 3 elements in `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than 3 elements long.
+    #[inline]
+    pub fn write_to_slice(self, slice: &mut [u32]) {
+        slice[..3].copy_from_slice(&self.to_array());
+    }
+
+    /// Creates a [`UVec3A`] from the `x`, `y` and `z` elements of `self` discarding `w`.
+    ///
+    /// On architectures where SIMD is supported such as SSE2 on `x86_64` this conversion is a noop.
+    #[inline]
+    #[must_use]
+    pub fn from_vec4(v: UVec4) -> Self {
+        Self(v.0)
+    }
+
+    /// Creates a 4D vector from `self` and the given `w` value.
+    #[inline]
+    #[must_use]
+    pub fn extend(self, w: u32) -> UVec4 {
+        UVec4::new(self.x, self.y, self.z, w)
+    }
+
+    /// Creates a 2D vector from the `x` and `y` elements of `self`, discarding `z`.
+    ///
+    /// Truncation may also be performed by using [`self.xy()`][crate::swizzles::Vec3Swizzles::xy()].
+    #[inline]
+    #[must_use]
+    pub fn truncate(self) -> UVec2 {
+        use crate::swizzles::Vec3Swizzles;
+        self.xy()
+    }
+
+    // Converts `self` to a `UVec3`.
+    #[inline]
+    #[must_use]
+    pub fn to_uvec3(self) -> UVec3 {
+        UVec3::from(self)
+    }
+
+    /// Creates a 3D vector from `self` with the given value of `x`.
+    #[inline]
+    #[must_use]
+    pub fn with_x(mut self, x: u32) -> Self {
+        self.x = x;
+        self
+    }
+
+    /// Creates a 3D vector from `self` with the given value of `y`.
+    #[inline]
+    #[must_use]
+    pub fn with_y(mut self, y: u32) -> Self {
+        self.y = y;
+        self
+    }
+
+    /// Creates a 3D vector from `self` with the given value of `z`.
+    #[inline]
+    #[must_use]
+    pub fn with_z(mut self, z: u32) -> Self {
+        self.z = z;
+        self
+    }
+
+    /// Computes the dot product of `self` and `rhs`.
+    #[inline]
+    #[must_use]
+    pub fn dot(self, rhs: Self) -> u32 {
+        (self.x * rhs.x) + (self.y * rhs.y) + (self.z * rhs.z)
+    }
+
+    /// Returns a vector where every component is the dot product of `self` and `rhs`.
+    #[inline]
+    #[must_use]
+    pub fn dot_into_vec(self, rhs: Self) -> Self {
+        Self::splat(self.dot(rhs))
+    }
+
+    /// Computes the cross product of `self` and `rhs`.
+    #[inline]
+    #[must_use]
+    pub fn cross(self, rhs: Self) -> Self {
+        Self::new(
+            self.y * rhs.z - rhs.y * self.z,
+            self.z * rhs.x - rhs.z * self.x,
+            self.x * rhs.y - rhs.x * self.y,
+        )
+    }
+
+    /// Returns a vector containing the minimum values for each element of `self` and `rhs`.
+    ///
+    /// In other words this computes `[min(x, rhs.x), min(self.y, rhs.y), ..]`.
+    #[inline]
+    #[must_use]
+    pub fn min(self, rhs: Self) -> Self {
+        Self(unsafe { m128i_min_epu32(self.0, rhs.0) })
+    }
+
+    /// Returns a vector containing the maximum values for each element of `self` and `rhs`.
+    ///
+    /// In other words this computes `[max(self.x, rhs.x), max(self.y, rhs.y), ..]`.
+    #[inline]
+    #[must_use]
+    pub fn max(self, rhs: Self) -> Self {
+        Self(unsafe { m128i_max_epu32(self.0, rhs.0) })
+    }
+
+    /// Component-wise clamping of values, similar to [`u32::clamp`].
+    ///
+    /// Each element in `min` must be less-or-equal to the corresponding element in `max`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `min` is greater than `max` when `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        glam_assert!(min.cmple(max).all(), "clamp: expected min <= max");
+        self.max(min).min(max)
+    }
+
+    /// Returns the horizontal minimum of `self`.
+    ///
+    /// In other words this computes `min(x, y, ..)`.
+    #[inline]
+    #[must_use]
+    pub fn min_element(self) -> u32 {
+        let min = |a, b| if a < b { a } else { b };
+        min(self.x, min(self.y, self.z))
+    }
+
+    /// Returns the horizontal maximum of `self`.
+    ///
+    /// In other words this computes `max(x, y, ..)`.
+    #[inline]
+    #[must_use]
+    pub fn max_element(self) -> u32 {
+        let max = |a, b| if a > b { a } else { b };
+        max(self.x, max(self.y, self.z))
+    }
+
+    /// Returns the index of the first minimum element of `self`.
+    #[doc(alias = "argmin")]
+    #[inline]
+    #[must_use]
+    pub fn min_position(self) -> usize {
+        let mut min = self.x;
+        let mut index = 0;
+        if self.y < min {
+            min = self.y;
+            index = 1;
+        }
+        if self.z < min {
+            index = 2;
+        }
+        index
+    }
+
+    /// Returns the index of the first maximum element of `self`.
+    #[doc(alias = "argmax")]
+    #[inline]
+    #[must_use]
+    pub fn max_position(self) -> usize {
+        let mut max = self.x;
+        let mut index = 0;
+        if self.y > max {
+            max = self.y;
+            index = 1;
+        }
+        if self.z > max {
+            index = 2;
+        }
+        index
+    }
+
+    /// Returns the sum of all elements of `self`.
+    ///
+    /// In other words, this computes `self.x + self.y + ..`.
+    #[inline]
+    #[must_use]
+    pub fn element_sum(self) -> u32 {
+        self.x + self.y + self.z
+    }
+
+    /// Returns the product of all elements of `self`.
+    ///
+    /// In other words, this computes `self.x * self.y * ..`.
+    #[inline]
+    #[must_use]
+    pub fn element_product(self) -> u32 {
+        self.x * self.y * self.z
+    }
+
+    /// Returns a vector mask containing the result of a `==` comparison for each element of
+    /// `self` and `rhs`.
+    ///
+    /// In other words, this computes `[self.x == rhs.x, self.y == rhs.y, ..]` for all
+    /// elements.
+    #[inline]
+    #[must_use]
+    pub fn cmpeq(self, rhs: Self) -> BVec3A {
+        BVec3A(unsafe { _mm_castsi128_ps(_mm_cmpeq_epi32(self.0, rhs.0)) })
+    }
+
+    /// Returns a vector mask containing the result of a `!=` comparison for each element of
+    /// `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x != rhs.x, self.y != rhs.y, ..]` for all
+    /// elements.
+    #[inline]
+    #[must_use]
+    pub fn cmpne(self, rhs: Self) -> BVec3A {
+        BVec3A(unsafe { _mm_castsi128_ps(m128i_not(_mm_cmpeq_epi32(self.0, rhs.0))) })
+    }
+
+    /// Returns a vector mask containing the result of a `>=` comparison for each element of
+    /// `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x >= rhs.x, self.y >= rhs.y, ..]` for all
+    /// elements.
+    #[inline]
+    #[must_use]
+    pub fn cmpge(self, rhs: Self) -> BVec3A {
+        BVec3A(unsafe { _mm_castsi128_ps(m128i_not(m128i_cmplt_epu32(self.0, rhs.0))) })
+    }
+
+    /// Returns a vector mask containing the result of a `>` comparison for each element of
+    /// `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x > rhs.x, self.y > rhs.y, ..]` for all
+    /// elements.
+    #[inline]
+    #[must_use]
+    pub fn cmpgt(self, rhs: Self) -> BVec3A {
+        BVec3A(unsafe { _mm_castsi128_ps(m128i_cmpgt_epu32(self.0, rhs.0)) })
+    }
+
+    /// Returns a vector mask containing the result of a `<=` comparison for each element of
+    /// `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x <= rhs.x, self.y <= rhs.y, ..]` for all
+    /// elements.
+    #[inline]
+    #[must_use]
+    pub fn cmple(self, rhs: Self) -> BVec3A {
+        BVec3A(unsafe { _mm_castsi128_ps(m128i_not(m128i_cmpgt_epu32(self.0, rhs.0))) })
+    }
+
+    /// Returns a vector mask containing the result of a `<` comparison for each element of
+    /// `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x < rhs.x, self.y < rhs.y, ..]` for all
+    /// elements.
+    #[inline]
+    #[must_use]
+    pub fn cmplt(self, rhs: Self) -> BVec3A {
+        BVec3A(unsafe { _mm_castsi128_ps(m128i_cmplt_epu32(self.0, rhs.0)) })
+    }
+
+    /// Computes the squared length of `self`.
+    #[doc(alias = "magnitude2")]
+    #[inline]
+    #[must_use]
+    pub fn length_squared(self) -> u32 {
+        self.dot(self)
+    }
+
+    /// Computes the [manhattan distance] between two points.
+    ///
+    /// # Overflow
+    /// This method may overflow if the result is greater than [`u32::MAX`].
+    ///
+    /// See also [`checked_manhattan_distance`][UVec3A::checked_manhattan_distance].
+    ///
+    /// [manhattan distance]: https://en.wikipedia.org/wiki/Taxicab_geometry
+    #[inline]
+    #[must_use]
+    pub fn manhattan_distance(self, rhs: Self) -> u32 {
+        self.x.abs_diff(rhs.x) + self.y.abs_diff(rhs.y) + self.z.abs_diff(rhs.z)
+    }
+
+    /// Computes the [manhattan distance] between two points.
+    ///
+    /// This will returns [`None`] if the result is greater than [`u32::MAX`].
+    ///
+    /// [manhattan distance]: https://en.wikipedia.org/wiki/Taxicab_geometry
+    #[inline]
+    #[must_use]
+    pub fn checked_manhattan_distance(self, rhs: Self) -> Option<u32> {
+        let d = self.x.abs_diff(rhs.x);
+        let d = d.checked_add(self.y.abs_diff(rhs.y))?;
+        d.checked_add(self.z.abs_diff(rhs.z))
+    }
+
+    /// Computes the [chebyshev distance] between two points.
+    ///
+    /// [chebyshev distance]: https://en.wikipedia.org/wiki/Chebyshev_distance
+    #[inline]
+    #[must_use]
+    pub fn chebyshev_distance(self, rhs: Self) -> u32 {
+        // Note: the compiler will eventually optimize out the loop
+        [
+            self.x.abs_diff(rhs.x),
+            self.y.abs_diff(rhs.y),
+            self.z.abs_diff(rhs.z),
+        ]
+        .into_iter()
+        .max()
+        .unwrap()
+    }
+
+    /// Casts all elements of `self` to `f32`.
+    #[inline]
+    #[must_use]
+    pub fn as_vec3(self) -> crate::Vec3 {
+        crate::Vec3::new(self.x as f32, self.y as f32, self.z as f32)
+    }
+
+    /// Casts all elements of `self` to `f32`.
+    #[inline]
+    #[must_use]
+    pub fn as_vec3a(self) -> crate::Vec3A {
+        crate::Vec3A::new(self.x as f32, self.y as f32, self.z as f32)
+    }
+
+    /// Casts all elements of `self` to `f64`.
+    #[inline]
+    #[must_use]
+    pub fn as_dvec3(self) -> crate::DVec3 {
+        crate::DVec3::new(self.x as f64, self.y as f64, self.z as f64)
+    }
+
+    /// Casts all elements of `self` to `i8`.
+    #[inline]
+    #[must_use]
+    pub fn as_i8vec3(self) -> crate::I8Vec3 {
+        crate::I8Vec3::new(self.x as i8, self.y as i8, self.z as i8)
+    }
+
+    /// Casts all elements of `self` to `u8`.
+    #[inline]
+    #[must_use]
+    pub fn as_u8vec3(self) -> crate::U8Vec3 {
+        crate::U8Vec3::new(self.x as u8, self.y as u8, self.z as u8)
+    }
+
+    /// Casts all elements of `self` to `i16`.
+    #[inline]
+    #[must_use]
+    pub fn as_i16vec3(self) -> crate::I16Vec3 {
+        crate::I16Vec3::new(self.x as i16, self.y as i16, self.z as i16)
+    }
+
+    /// Casts all elements of `self` to `u16`.
+    #[inline]
+    #[must_use]
+    pub fn as_u16vec3(self) -> crate::U16Vec3 {
+        crate::U16Vec3::new(self.x as u16, self.y as u16, self.z as u16)
+    }
+
+    /// Casts all elements of `self` to `i32`.
+    #[inline]
+    #[must_use]
+    pub fn as_ivec3(self) -> crate::IVec3 {
+        crate::IVec3::new(self.x as i32, self.y as i32, self.z as i32)
+    }
+
+    /// Casts all elements of `self` to `i64`.
+    #[inline]
+    #[must_use]
+    pub fn as_i64vec3(self) -> crate::I64Vec3 {
+        crate::I64Vec3::new(self.x as i64, self.y as i64, self.z as i64)
+    }
+
+    /// Casts all elements of `self` to `u64`.
+    #[inline]
+    #[must_use]
+    pub fn as_u64vec3(self) -> crate::U64Vec3 {
+        crate::U64Vec3::new(self.x as u64, self.y as u64, self.z as u64)
+    }
+
+    /// Casts all elements of `self` to `isize`.
+    #[inline]
+    #[must_use]
+    pub fn as_isizevec3(self) -> crate::ISizeVec3 {
+        crate::ISizeVec3::new(self.x as isize, self.y as isize, self.z as isize)
+    }
+
+    /// Casts all elements of `self` to `usize`.
+    #[inline]
+    #[must_use]
+    pub fn as_usizevec3(self) -> crate::USizeVec3 {
+        crate::USizeVec3::new(self.x as usize, self.y as usize, self.z as usize)
+    }
+
+    /// Returns a vector containing the wrapping addition of `self` and `rhs`.
+    ///
+    /// In other words this computes `Some([self.x + rhs.x, self.y + rhs.y, ..])` but returns `None` on any overflow.
+    #[inline]
+    #[must_use]
+    pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+        let (lhs, rhs) = (self.to_array(), rhs.to_array());
+
+        let v0 = match lhs[0].checked_add(rhs[0]) {
+            Some(v) => v,
+            None => return None,
+        };
+
+        let v1 = match lhs[1].checked_add(rhs[1]) {
+            Some(v) => v,
+            None => return None,
+        };
+
+        let v2 = match lhs[2].checked_add(rhs[2]) {
+            Some(v) => v,
+            None => return None,
+        };
+
+        Some(Self::new(v0, v1, v2))
+    }
+
+    /// Returns a vector containing the wrapping subtraction of `self` and `rhs`.
+    ///
+    /// In other words this computes `Some([self.x - rhs.x, self.y - rhs.y, ..])` but returns `None` on any overflow.
+    #[inline]
+    #[must_use]
+    pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+        let (lhs, rhs) = (self.to_array(), rhs.to_array());
+
+        let v0 = match lhs[0].checked_sub(rhs[0]) {
+            Some(v) => v,
+            None => return None,
+        };
+
+        let v1 = match lhs[1].checked_sub(rhs[1]) {
+            Some(v) => v,
+            None => return None,
+        };
+
+        let v2 = match lhs[2].checked_sub(rhs[2]) {
+            Some(v) => v,
+            None => return None,
+        };
+
+        Some(Self::new(v0, v1, v2))
+    }
+
+    /// Returns a vector containing the wrapping multiplication of `self` and `rhs`.
+    ///
+    /// In other words this computes `Some([self.x * rhs.x, self.y * rhs.y, ..])` but returns `None` on any overflow.
+    #[inline]
+    #[must_use]
+    pub const fn checked_mul(self, rhs: Self) -> Option<Self> {
+        let (lhs, rhs) = (self.to_array(), rhs.to_array());
+
+        let v0 = match lhs[0].checked_mul(rhs[0]) {
+            Some(v) => v,
+            None => return None,
+        };
+
+        let v1 = match lhs[1].checked_mul(rhs[1]) {
+            Some(v) => v,
+            None => return None,
+        };
+
+        let v2 = match lhs[2].checked_mul(rhs[2]) {
+            Some(v) => v,
+            None => return None,
+        };
+
+        Some(Self::new(v0, v1, v2))
+    }
+
+    /// Returns a vector containing the wrapping division of `self` and `rhs`.
+    ///
+    /// In other words this computes `Some([self.x / rhs.x, self.y / rhs.y, ..])` but returns `None` on any division by zero.
+    #[inline]
+    #[must_use]
+    pub const fn checked_div(self, rhs: Self) -> Option<Self> {
+        let (lhs, rhs) = (self.to_array(), rhs.to_array());
+
+        let v0 = match lhs[0].checked_div(rhs[0]) {
+            Some(v) => v,
+            None => return None,
+        };
+
+        let v1 = match lhs[1].checked_div(rhs[1]) {
+            Some(v) => v,
+            None => return None,
+        };
+
+        let v2 = match lhs[2].checked_div(rhs[2]) {
+            Some(v) => v,
+            None => return None,
+        };
+
+        Some(Self::new(v0, v1, v2))
+    }
+
+    /// Returns a vector containing the wrapping addition of `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x.wrapping_add(rhs.x), self.y.wrapping_add(rhs.y), ..]`.
+    #[inline]
+    #[must_use]
+    pub const fn wrapping_add(self, rhs: Self) -> Self {
+        let (lhs, rhs) = (self.to_array(), rhs.to_array());
+        Self::new(
+            lhs[0].wrapping_add(rhs[0]),
+            lhs[1].wrapping_add(rhs[1]),
+            lhs[2].wrapping_add(rhs[2]),
+        )
+    }
+
+    /// Returns a vector containing the wrapping subtraction of `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x.wrapping_sub(rhs.x), self.y.wrapping_sub(rhs.y), ..]`.
+    #[inline]
+    #[must_use]
+    pub const fn wrapping_sub(self, rhs: Self) -> Self {
+        let (lhs, rhs) = (self.to_array(), rhs.to_array());
+        Self::new(
+            lhs[0].wrapping_sub(rhs[0]),
+            lhs[1].wrapping_sub(rhs[1]),
+            lhs[2].wrapping_sub(rhs[2]),
+        )
+    }
+
+    /// Returns a vector containing the wrapping multiplication of `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x.wrapping_mul(rhs.x), self.y.wrapping_mul(rhs.y), ..]`.
+    #[inline]
+    #[must_use]
+    pub const fn wrapping_mul(self, rhs: Self) -> Self {
+        let (lhs, rhs) = (self.to_array(), rhs.to_array());
+        Self::new(
+            lhs[0].wrapping_mul(rhs[0]),
+            lhs[1].wrapping_mul(rhs[1]),
+            lhs[2].wrapping_mul(rhs[2]),
+        )
+    }
+
+    /// Returns a vector containing the wrapping division of `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x.wrapping_div(rhs.x), self.y.wrapping_div(rhs.y), ..]`.
+    #[inline]
+    #[must_use]
+    pub const fn wrapping_div(self, rhs: Self) -> Self {
+        let (lhs, rhs) = (self.to_array(), rhs.to_array());
+        Self::new(
+            lhs[0].wrapping_div(rhs[0]),
+            lhs[1].wrapping_div(rhs[1]),
+            lhs[2].wrapping_div(rhs[2]),
+        )
+    }
+
+    /// Returns a vector containing the saturating addition of `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x.saturating_add(rhs.x), self.y.saturating_add(rhs.y), ..]`.
+    #[inline]
+    #[must_use]
+    pub const fn saturating_add(self, rhs: Self) -> Self {
+        let (lhs, rhs) = (self.to_array(), rhs.to_array());
+        Self::new(
+            lhs[0].saturating_add(rhs[0]),
+            lhs[1].saturating_add(rhs[1]),
+            lhs[2].saturating_add(rhs[2]),
+        )
+    }
+
+    /// Returns a vector containing the saturating subtraction of `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x.saturating_sub(rhs.x), self.y.saturating_sub(rhs.y), ..]`.
+    #[inline]
+    #[must_use]
+    pub const fn saturating_sub(self, rhs: Self) -> Self {
+        let (lhs, rhs) = (self.to_array(), rhs.to_array());
+        Self::new(
+            lhs[0].saturating_sub(rhs[0]),
+            lhs[1].saturating_sub(rhs[1]),
+            lhs[2].saturating_sub(rhs[2]),
+        )
+    }
+
+    /// Returns a vector containing the saturating multiplication of `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x.saturating_mul(rhs.x), self.y.saturating_mul(rhs.y), ..]`.
+    #[inline]
+    #[must_use]
+    pub const fn saturating_mul(self, rhs: Self) -> Self {
+        let (lhs, rhs) = (self.to_array(), rhs.to_array());
+        Self::new(
+            lhs[0].saturating_mul(rhs[0]),
+            lhs[1].saturating_mul(rhs[1]),
+            lhs[2].saturating_mul(rhs[2]),
+        )
+    }
+
+    /// Returns a vector containing the saturating division of `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x.saturating_div(rhs.x), self.y.saturating_div(rhs.y), ..]`.
+    #[inline]
+    #[must_use]
+    pub const fn saturating_div(self, rhs: Self) -> Self {
+        let (lhs, rhs) = (self.to_array(), rhs.to_array());
+        Self::new(
+            lhs[0].saturating_div(rhs[0]),
+            lhs[1].saturating_div(rhs[1]),
+            lhs[2].saturating_div(rhs[2]),
+        )
+    }
+
+    /// Returns a vector containing the wrapping addition of `self` and signed vector `rhs`.
+    ///
+    /// In other words this computes `Some([self.x + rhs.x, self.y + rhs.y, ..])` but returns `None` on any overflow.
+    #[inline]
+    #[must_use]
+    pub const fn checked_add_signed(self, rhs: IVec3A) -> Option<Self> {
+        let (lhs, rhs) = (self.to_array(), rhs.to_array());
+
+        let v0 = match lhs[0].checked_add_signed(rhs[0]) {
+            Some(v) => v,
+            None => return None,
+        };
+
+        let v1 = match lhs[1].checked_add_signed(rhs[1]) {
+            Some(v) => v,
+            None => return None,
+        };
+
+        let v2 = match lhs[2].checked_add_signed(rhs[2]) {
+            Some(v) => v,
+            None => return None,
+        };
+
+        Some(Self::new(v0, v1, v2))
+    }
+
+    /// Returns a vector containing the wrapping addition of `self` and signed vector `rhs`.
+    ///
+    /// In other words this computes `[self.x.wrapping_add_signed(rhs.x), self.y.wrapping_add_signed(rhs.y), ..]`.
+    #[inline]
+    #[must_use]
+    pub const fn wrapping_add_signed(self, rhs: IVec3A) -> Self {
+        let (lhs, rhs) = (self.to_array(), rhs.to_array());
+        Self::new(
+            lhs[0].wrapping_add_signed(rhs[0]),
+            lhs[1].wrapping_add_signed(rhs[1]),
+            lhs[2].wrapping_add_signed(rhs[2]),
+        )
+    }
+
+    /// Returns a vector containing the saturating addition of `self` and signed vector `rhs`.
+    ///
+    /// In other words this computes `[self.x.saturating_add_signed(rhs.x), self.y.saturating_add_signed(rhs.y), ..]`.
+    #[inline]
+    #[must_use]
+    pub const fn saturating_add_signed(self, rhs: IVec3A) -> Self {
+        let (lhs, rhs) = (self.to_array(), rhs.to_array());
+        Self::new(
+            lhs[0].saturating_add_signed(rhs[0]),
+            lhs[1].saturating_add_signed(rhs[1]),
+            lhs[2].saturating_add_signed(rhs[2]),
+        )
+    }
+}
+
+impl Default for UVec3A {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl PartialEq for UVec3A {
+    #[inline]
+    fn eq(&self, rhs: &Self) -> bool {
+        self.cmpeq(*rhs).all()
+    }
+}
+
+impl Eq for UVec3A {}
+
+impl core::hash::Hash for UVec3A {
+    #[inline]
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.x.hash(state);
+        self.y.hash(state);
+        self.z.hash(state);
+    }
+}
+
+impl Div for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: Self) -> Self {
+        Self::new(self.x.div(rhs.x), self.y.div(rhs.y), self.z.div(rhs.z))
+    }
+}
+
+impl Div<&Self> for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: &Self) -> Self {
+        self.div(*rhs)
+    }
+}
+
+impl Div<&UVec3A> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn div(self, rhs: &UVec3A) -> UVec3A {
+        (*self).div(*rhs)
+    }
+}
+
+impl Div<UVec3A> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn div(self, rhs: UVec3A) -> UVec3A {
+        (*self).div(rhs)
+    }
+}
+
+impl DivAssign for UVec3A {
+    #[inline]
+    fn div_assign(&mut self, rhs: Self) {
+        self.x.div_assign(rhs.x);
+        self.y.div_assign(rhs.y);
+        self.z.div_assign(rhs.z);
+    }
+}
+
+impl DivAssign<&Self> for UVec3A {
+    #[inline]
+    fn div_assign(&mut self, rhs: &Self) {
+        self.div_assign(*rhs);
+    }
+}
+
+impl Div<u32> for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: u32) -> Self {
+        Self::new(self.x.div(rhs), self.y.div(rhs), self.z.div(rhs))
+    }
+}
+
+impl Div<&u32> for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: &u32) -> Self {
+        self.div(*rhs)
+    }
+}
+
+impl Div<&u32> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn div(self, rhs: &u32) -> UVec3A {
+        (*self).div(*rhs)
+    }
+}
+
+impl Div<u32> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn div(self, rhs: u32) -> UVec3A {
+        (*self).div(rhs)
+    }
+}
+
+impl DivAssign<u32> for UVec3A {
+    #[inline]
+    fn div_assign(&mut self, rhs: u32) {
+        self.x.div_assign(rhs);
+        self.y.div_assign(rhs);
+        self.z.div_assign(rhs);
+    }
+}
+
+impl DivAssign<&u32> for UVec3A {
+    #[inline]
+    fn div_assign(&mut self, rhs: &u32) {
+        self.div_assign(*rhs);
+    }
+}
+
+impl Div<UVec3A> for u32 {
+    type Output = UVec3A;
+    #[inline]
+    fn div(self, rhs: UVec3A) -> UVec3A {
+        UVec3A::new(self.div(rhs.x), self.div(rhs.y), self.div(rhs.z))
+    }
+}
+
+impl Div<&UVec3A> for u32 {
+    type Output = UVec3A;
+    #[inline]
+    fn div(self, rhs: &UVec3A) -> UVec3A {
+        self.div(*rhs)
+    }
+}
+
+impl Div<&UVec3A> for &u32 {
+    type Output = UVec3A;
+    #[inline]
+    fn div(self, rhs: &UVec3A) -> UVec3A {
+        (*self).div(*rhs)
+    }
+}
+
+impl Div<UVec3A> for &u32 {
+    type Output = UVec3A;
+    #[inline]
+    fn div(self, rhs: UVec3A) -> UVec3A {
+        (*self).div(rhs)
+    }
+}
+
+impl Mul for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        // Lanes that overflow, or might overflow for multiplication, are redone with the scalar
+        // operators so overflow follows the `overflow-checks` profile setting.
+        match unsafe { m128i_checked_mul_epu32(self.0, rhs.0) } {
+            Some(v) => Self(v),
+            None => Self::new(self.x.mul(rhs.x), self.y.mul(rhs.y), self.z.mul(rhs.z)),
+        }
+    }
+}
+
+impl Mul<&Self> for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: &Self) -> Self {
+        self.mul(*rhs)
+    }
+}
+
+impl Mul<&UVec3A> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn mul(self, rhs: &UVec3A) -> UVec3A {
+        (*self).mul(*rhs)
+    }
+}
+
+impl Mul<UVec3A> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn mul(self, rhs: UVec3A) -> UVec3A {
+        (*self).mul(rhs)
+    }
+}
+
+impl MulAssign for UVec3A {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.mul(rhs);
+    }
+}
+
+impl MulAssign<&Self> for UVec3A {
+    #[inline]
+    fn mul_assign(&mut self, rhs: &Self) {
+        self.mul_assign(*rhs);
+    }
+}
+
+impl Mul<u32> for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: u32) -> Self {
+        match unsafe { m128i_checked_mul_epu32(self.0, Self::splat(rhs).0) } {
+            Some(v) => Self(v),
+            None => Self::new(self.x.mul(rhs), self.y.mul(rhs), self.z.mul(rhs)),
+        }
+    }
+}
+
+impl Mul<&u32> for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: &u32) -> Self {
+        self.mul(*rhs)
+    }
+}
+
+impl Mul<&u32> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn mul(self, rhs: &u32) -> UVec3A {
+        (*self).mul(*rhs)
+    }
+}
+
+impl Mul<u32> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn mul(self, rhs: u32) -> UVec3A {
+        (*self).mul(rhs)
+    }
+}
+
+impl MulAssign<u32> for UVec3A {
+    #[inline]
+    fn mul_assign(&mut self, rhs: u32) {
+        *self = self.mul(rhs);
+    }
+}
+
+impl MulAssign<&u32> for UVec3A {
+    #[inline]
+    fn mul_assign(&mut self, rhs: &u32) {
+        self.mul_assign(*rhs);
+    }
+}
+
+impl Mul<UVec3A> for u32 {
+    type Output = UVec3A;
+    #[inline]
+    fn mul(self, rhs: UVec3A) -> UVec3A {
+        match unsafe { m128i_checked_mul_epu32(UVec3A::splat(self).0, rhs.0) } {
+            Some(v) => UVec3A(v),
+            None => UVec3A::new(self.mul(rhs.x), self.mul(rhs.y), self.mul(rhs.z)),
+        }
+    }
+}
+
+impl Mul<&UVec3A> for u32 {
+    type Output = UVec3A;
+    #[inline]
+    fn mul(self, rhs: &UVec3A) -> UVec3A {
+        self.mul(*rhs)
+    }
+}
+
+impl Mul<&UVec3A> for &u32 {
+    type Output = UVec3A;
+    #[inline]
+    fn mul(self, rhs: &UVec3A) -> UVec3A {
+        (*self).mul(*rhs)
+    }
+}
+
+impl Mul<UVec3A> for &u32 {
+    type Output = UVec3A;
+    #[inline]
+    fn mul(self, rhs: UVec3A) -> UVec3A {
+        (*self).mul(rhs)
+    }
+}
+
+impl Add for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        match unsafe { m128i_checked_add_epu32(self.0, rhs.0) } {
+            Some(v) => Self(v),
+            None => Self::new(self.x.add(rhs.x), self.y.add(rhs.y), self.z.add(rhs.z)),
+        }
+    }
+}
+
+impl Add<&Self> for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: &Self) -> Self {
+        self.add(*rhs)
+    }
+}
+
+impl Add<&UVec3A> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn add(self, rhs: &UVec3A) -> UVec3A {
+        (*self).add(*rhs)
+    }
+}
+
+impl Add<UVec3A> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn add(self, rhs: UVec3A) -> UVec3A {
+        (*self).add(rhs)
+    }
+}
+
+impl AddAssign for UVec3A {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = self.add(rhs);
+    }
+}
+
+impl AddAssign<&Self> for UVec3A {
+    #[inline]
+    fn add_assign(&mut self, rhs: &Self) {
+        self.add_assign(*rhs);
+    }
+}
+
+impl Add<u32> for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: u32) -> Self {
+        match unsafe { m128i_checked_add_epu32(self.0, Self::splat(rhs).0) } {
+            Some(v) => Self(v),
+            None => Self::new(self.x.add(rhs), self.y.add(rhs), self.z.add(rhs)),
+        }
+    }
+}
+
+impl Add<&u32> for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: &u32) -> Self {
+        self.add(*rhs)
+    }
+}
+
+impl Add<&u32> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn add(self, rhs: &u32) -> UVec3A {
+        (*self).add(*rhs)
+    }
+}
+
+impl Add<u32> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn add(self, rhs: u32) -> UVec3A {
+        (*self).add(rhs)
+    }
+}
+
+impl AddAssign<u32> for UVec3A {
+    #[inline]
+    fn add_assign(&mut self, rhs: u32) {
+        *self = self.add(rhs);
+    }
+}
+
+impl AddAssign<&u32> for UVec3A {
+    #[inline]
+    fn add_assign(&mut self, rhs: &u32) {
+        self.add_assign(*rhs);
+    }
+}
+
+impl Add<UVec3A> for u32 {
+    type Output = UVec3A;
+    #[inline]
+    fn add(self, rhs: UVec3A) -> UVec3A {
+        match unsafe { m128i_checked_add_epu32(UVec3A::splat(self).0, rhs.0) } {
+            Some(v) => UVec3A(v),
+            None => UVec3A::new(self.add(rhs.x), self.add(rhs.y), self.add(rhs.z)),
+        }
+    }
+}
+
+impl Add<&UVec3A> for u32 {
+    type Output = UVec3A;
+    #[inline]
+    fn add(self, rhs: &UVec3A) -> UVec3A {
+        self.add(*rhs)
+    }
+}
+
+impl Add<&UVec3A> for &u32 {
+    type Output = UVec3A;
+    #[inline]
+    fn add(self, rhs: &UVec3A) -> UVec3A {
+        (*self).add(*rhs)
+    }
+}
+
+impl Add<UVec3A> for &u32 {
+    type Output = UVec3A;
+    #[inline]
+    fn add(self, rhs: UVec3A) -> UVec3A {
+        (*self).add(rhs)
+    }
+}
+
+impl Sub for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        match unsafe { m128i_checked_sub_epu32(self.0, rhs.0) } {
+            Some(v) => Self(v),
+            None => Self::new(self.x.sub(rhs.x), self.y.sub(rhs.y), self.z.sub(rhs.z)),
+        }
+    }
+}
+
+impl Sub<&Self> for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: &Self) -> Self {
+        self.sub(*rhs)
+    }
+}
+
+impl Sub<&UVec3A> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn sub(self, rhs: &UVec3A) -> UVec3A {
+        (*self).sub(*rhs)
+    }
+}
+
+impl Sub<UVec3A> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn sub(self, rhs: UVec3A) -> UVec3A {
+        (*self).sub(rhs)
+    }
+}
+
+impl SubAssign for UVec3A {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = self.sub(rhs);
+    }
+}
+
+impl SubAssign<&Self> for UVec3A {
+    #[inline]
+    fn sub_assign(&mut self, rhs: &Self) {
+        self.sub_assign(*rhs);
+    }
+}
+
+impl Sub<u32> for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: u32) -> Self {
+        match unsafe { m128i_checked_sub_epu32(self.0, Self::splat(rhs).0) } {
+            Some(v) => Self(v),
+            None => Self::new(self.x.sub(rhs), self.y.sub(rhs), self.z.sub(rhs)),
+        }
+    }
+}
+
+impl Sub<&u32> for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: &u32) -> Self {
+        self.sub(*rhs)
+    }
+}
+
+impl Sub<&u32> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn sub(self, rhs: &u32) -> UVec3A {
+        (*self).sub(*rhs)
+    }
+}
+
+impl Sub<u32> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn sub(self, rhs: u32) -> UVec3A {
+        (*self).sub(rhs)
+    }
+}
+
+impl SubAssign<u32> for UVec3A {
+    #[inline]
+    fn sub_assign(&mut self, rhs: u32) {
+        *self = self.sub(rhs);
+    }
+}
+
+impl SubAssign<&u32> for UVec3A {
+    #[inline]
+    fn sub_assign(&mut self, rhs: &u32) {
+        self.sub_assign(*rhs);
+    }
+}
+
+impl Sub<UVec3A> for u32 {
+    type Output = UVec3A;
+    #[inline]
+    fn sub(self, rhs: UVec3A) -> UVec3A {
+        match unsafe { m128i_checked_sub_epu32(UVec3A::splat(self).0, rhs.0) } {
+            Some(v) => UVec3A(v),
+            None => UVec3A::new(self.sub(rhs.x), self.sub(rhs.y), self.sub(rhs.z)),
+        }
+    }
+}
+
+impl Sub<&UVec3A> for u32 {
+    type Output = UVec3A;
+    #[inline]
+    fn sub(self, rhs: &UVec3A) -> UVec3A {
+        self.sub(*rhs)
+    }
+}
+
+impl Sub<&UVec3A> for &u32 {
+    type Output = UVec3A;
+    #[inline]
+    fn sub(self, rhs: &UVec3A) -> UVec3A {
+        (*self).sub(*rhs)
+    }
+}
+
+impl Sub<UVec3A> for &u32 {
+    type Output = UVec3A;
+    #[inline]
+    fn sub(self, rhs: UVec3A) -> UVec3A {
+        (*self).sub(rhs)
+    }
+}
+
+impl Rem for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn rem(self, rhs: Self) -> Self {
+        Self::new(self.x.rem(rhs.x), self.y.rem(rhs.y), self.z.rem(rhs.z))
+    }
+}
+
+impl Rem<&Self> for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn rem(self, rhs: &Self) -> Self {
+        self.rem(*rhs)
+    }
+}
+
+impl Rem<&UVec3A> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn rem(self, rhs: &UVec3A) -> UVec3A {
+        (*self).rem(*rhs)
+    }
+}
+
+impl Rem<UVec3A> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn rem(self, rhs: UVec3A) -> UVec3A {
+        (*self).rem(rhs)
+    }
+}
+
+impl RemAssign for UVec3A {
+    #[inline]
+    fn rem_assign(&mut self, rhs: Self) {
+        *self = self.rem(rhs);
+    }
+}
+
+impl RemAssign<&Self> for UVec3A {
+    #[inline]
+    fn rem_assign(&mut self, rhs: &Self) {
+        self.rem_assign(*rhs);
+    }
+}
+
+impl Rem<u32> for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn rem(self, rhs: u32) -> Self {
+        self.rem(Self::splat(rhs))
+    }
+}
+
+impl Rem<&u32> for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn rem(self, rhs: &u32) -> Self {
+        self.rem(*rhs)
+    }
+}
+
+impl Rem<&u32> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn rem(self, rhs: &u32) -> UVec3A {
+        (*self).rem(*rhs)
+    }
+}
+
+impl Rem<u32> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn rem(self, rhs: u32) -> UVec3A {
+        (*self).rem(rhs)
+    }
+}
+
+impl RemAssign<u32> for UVec3A {
+    #[inline]
+    fn rem_assign(&mut self, rhs: u32) {
+        *self = self.rem(Self::splat(rhs));
+    }
+}
+
+impl RemAssign<&u32> for UVec3A {
+    #[inline]
+    fn rem_assign(&mut self, rhs: &u32) {
+        self.rem_assign(*rhs);
+    }
+}
+
+impl Rem<UVec3A> for u32 {
+    type Output = UVec3A;
+    #[inline]
+    fn rem(self, rhs: UVec3A) -> UVec3A {
+        UVec3A::splat(self).rem(rhs)
+    }
+}
+
+impl Rem<&UVec3A> for u32 {
+    type Output = UVec3A;
+    #[inline]
+    fn rem(self, rhs: &UVec3A) -> UVec3A {
+        self.rem(*rhs)
+    }
+}
+
+impl Rem<&UVec3A> for &u32 {
+    type Output = UVec3A;
+    #[inline]
+    fn rem(self, rhs: &UVec3A) -> UVec3A {
+        (*self).rem(*rhs)
+    }
+}
+
+impl Rem<UVec3A> for &u32 {
+    type Output = UVec3A;
+    #[inline]
+    fn rem(self, rhs: UVec3A) -> UVec3A {
+        (*self).rem(rhs)
+    }
+}
+
+impl AsRef<[u32; 3]> for UVec3A {
+    #[inline]
+    fn as_ref(&self) -> &[u32; 3] {
+        unsafe { &*(self as *const Self as *const [u32; 3]) }
+    }
+}
+
+impl AsMut<[u32; 3]> for UVec3A {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [u32; 3] {
+        unsafe { &mut *(self as *mut Self as *mut [u32; 3]) }
+    }
+}
+
+impl Sum for UVec3A {
+    #[inline]
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        iter.fold(Self::ZERO, Self::add)
+    }
+}
+
+impl<'a> Sum<&'a Self> for UVec3A {
+    #[inline]
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.fold(Self::ZERO, |a, &b| Self::add(a, b))
+    }
+}
+
+impl Product for UVec3A {
+    #[inline]
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        iter.fold(Self::ONE, Self::mul)
+    }
+}
+
+impl<'a> Product<&'a Self> for UVec3A {
+    #[inline]
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.fold(Self::ONE, |a, &b| Self::mul(a, b))
+    }
+}
+
+impl Not for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn not(self) -> Self {
+        Self(unsafe { m128i_not(self.0) })
+    }
+}
+
+impl Not for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn not(self) -> UVec3A {
+        (*self).not()
+    }
+}
+
+impl BitAnd for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn bitand(self, rhs: Self) -> Self::Output {
+        Self(unsafe { _mm_and_si128(self.0, rhs.0) })
+    }
+}
+
+impl BitAnd<&Self> for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn bitand(self, rhs: &Self) -> Self {
+        self.bitand(*rhs)
+    }
+}
+
+impl BitAnd<&UVec3A> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn bitand(self, rhs: &UVec3A) -> UVec3A {
+        (*self).bitand(*rhs)
+    }
+}
+
+impl BitAnd<UVec3A> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn bitand(self, rhs: UVec3A) -> UVec3A {
+        (*self).bitand(rhs)
+    }
+}
+
+impl BitAndAssign for UVec3A {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: Self) {
+        *self = self.bitand(rhs);
+    }
+}
+
+impl BitAndAssign<&Self> for UVec3A {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: &Self) {
+        self.bitand_assign(*rhs);
+    }
+}
+
+impl BitOr for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self(unsafe { _mm_or_si128(self.0, rhs.0) })
+    }
+}
+
+impl BitOr<&Self> for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn bitor(self, rhs: &Self) -> Self {
+        self.bitor(*rhs)
+    }
+}
+
+impl BitOr<&UVec3A> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn bitor(self, rhs: &UVec3A) -> UVec3A {
+        (*self).bitor(*rhs)
+    }
+}
+
+impl BitOr<UVec3A> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn bitor(self, rhs: UVec3A) -> UVec3A {
+        (*self).bitor(rhs)
+    }
+}
+
+impl BitOrAssign for UVec3A {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = self.bitor(rhs);
+    }
+}
+
+impl BitOrAssign<&Self> for UVec3A {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: &Self) {
+        self.bitor_assign(*rhs);
+    }
+}
+
+impl BitXor for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        Self(unsafe { _mm_xor_si128(self.0, rhs.0) })
+    }
+}
+
+impl BitXor<&Self> for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn bitxor(self, rhs: &Self) -> Self {
+        self.bitxor(*rhs)
+    }
+}
+
+impl BitXor<&UVec3A> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn bitxor(self, rhs: &UVec3A) -> UVec3A {
+        (*self).bitxor(*rhs)
+    }
+}
+
+impl BitXor<UVec3A> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn bitxor(self, rhs: UVec3A) -> UVec3A {
+        (*self).bitxor(rhs)
+    }
+}
+
+impl BitXorAssign for UVec3A {
+    #[inline]
+    fn bitxor_assign(&mut self, rhs: Self) {
+        *self = self.bitxor(rhs);
+    }
+}
+
+impl BitXorAssign<&Self> for UVec3A {
+    #[inline]
+    fn bitxor_assign(&mut self, rhs: &Self) {
+        self.bitxor_assign(*rhs);
+    }
+}
+
+impl BitAnd<u32> for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn bitand(self, rhs: u32) -> Self::Output {
+        self.bitand(Self::splat(rhs))
+    }
+}
+
+impl BitAnd<&u32> for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn bitand(self, rhs: &u32) -> Self {
+        self.bitand(*rhs)
+    }
+}
+
+impl BitAnd<&u32> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn bitand(self, rhs: &u32) -> UVec3A {
+        (*self).bitand(*rhs)
+    }
+}
+
+impl BitAnd<u32> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn bitand(self, rhs: u32) -> UVec3A {
+        (*self).bitand(rhs)
+    }
+}
+
+impl BitAndAssign<u32> for UVec3A {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: u32) {
+        *self = self.bitand(rhs);
+    }
+}
+
+impl BitAndAssign<&u32> for UVec3A {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: &u32) {
+        self.bitand_assign(*rhs);
+    }
+}
+
+impl BitOr<u32> for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn bitor(self, rhs: u32) -> Self::Output {
+        self.bitor(Self::splat(rhs))
+    }
+}
+
+impl BitOr<&u32> for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn bitor(self, rhs: &u32) -> Self {
+        self.bitor(*rhs)
+    }
+}
+
+impl BitOr<&u32> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn bitor(self, rhs: &u32) -> UVec3A {
+        (*self).bitor(*rhs)
+    }
+}
+
+impl BitOr<u32> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn bitor(self, rhs: u32) -> UVec3A {
+        (*self).bitor(rhs)
+    }
+}
+
+impl BitOrAssign<u32> for UVec3A {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: u32) {
+        *self = self.bitor(rhs);
+    }
+}
+
+impl BitOrAssign<&u32> for UVec3A {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: &u32) {
+        self.bitor_assign(*rhs);
+    }
+}
+
+impl BitXor<u32> for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn bitxor(self, rhs: u32) -> Self::Output {
+        self.bitxor(Self::splat(rhs))
+    }
+}
+
+impl BitXor<&u32> for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn bitxor(self, rhs: &u32) -> Self {
+        self.bitxor(*rhs)
+    }
+}
+
+impl BitXor<&u32> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn bitxor(self, rhs: &u32) -> UVec3A {
+        (*self).bitxor(*rhs)
+    }
+}
+
+impl BitXor<u32> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn bitxor(self, rhs: u32) -> UVec3A {
+        (*self).bitxor(rhs)
+    }
+}
+
+impl BitXorAssign<u32> for UVec3A {
+    #[inline]
+    fn bitxor_assign(&mut self, rhs: u32) {
+        *self = self.bitxor(rhs);
+    }
+}
+
+impl BitXorAssign<&u32> for UVec3A {
+    #[inline]
+    fn bitxor_assign(&mut self, rhs: &u32) {
+        self.bitxor_assign(*rhs);
+    }
+}
+
+impl Shl<i8> for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn shl(self, rhs: i8) -> Self::Output {
+        // Counts outside of `0..32` overflow, they are shifted lane by lane with the
+        // scalar operators so overflow follows the `overflow-checks` profile setting.
+        if (0..32).contains(&rhs) {
+            let count = rhs as i32;
+            Self(unsafe { _mm_sll_epi32(self.0, _mm_cvtsi32_si128(count)) })
+        } else {
+            Self::new(self.x.shl(rhs), self.y.shl(rhs), self.z.shl(rhs))
+        }
+    }
+}
+
+impl Shl<&i8> for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn shl(self, rhs: &i8) -> Self {
+        self.shl(*rhs)
+    }
+}
+
+impl Shl<&i8> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn shl(self, rhs: &i8) -> UVec3A {
+        (*self).shl(*rhs)
+    }
+}
+
+impl Shl<i8> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn shl(self, rhs: i8) -> UVec3A {
+        (*self).shl(rhs)
+    }
+}
+
+impl ShlAssign<i8> for UVec3A {
+    #[inline]
+    fn shl_assign(&mut self, rhs: i8) {
+        *self = self.shl(rhs);
+    }
+}
+
+impl ShlAssign<&i8> for UVec3A {
+    #[inline]
+    fn shl_assign(&mut self, rhs: &i8) {
+        self.shl_assign(*rhs);
+    }
+}
+
+impl Shr<i8> for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn shr(self, rhs: i8) -> Self::Output {
+        if (0..32).contains(&rhs) {
+            let count = rhs as i32;
+            Self(unsafe { _mm_srl_epi32(self.0, _mm_cvtsi32_si128(count)) })
+        } else {
+            Self::new(self.x.shr(rhs), self.y.shr(rhs), self.z.shr(rhs))
+        }
+    }
+}
+
+impl Shr<&i8> for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn shr(self, rhs: &i8) -> Self {
+        self.shr(*rhs)
+    }
+}
+
+impl Shr<&i8> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn shr(self, rhs: &i8) -> UVec3A {
+        (*self).shr(*rhs)
+    }
+}
+
+impl Shr<i8> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn shr(self, rhs: i8) -> UVec3A {
+        (*self).shr(rhs)
+    }
+}
+
+impl ShrAssign<i8> for UVec3A {
+    #[inline]
+    fn shr_assign(&mut self, rhs: i8) {
+        *self = self.shr(rhs);
+    }
+}
+
+impl ShrAssign<&i8> for UVec3A {
+    #[inline]
+    fn shr_assign(&mut self, rhs: &i8) {
+        self.shr_assign(*rhs);
+    }
+}
+
+impl Shl<i16> for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn shl(self, rhs: i16) -> Self::Output {
+        if (0..32).contains(&rhs) {
+            let count = rhs as i32;
+            Self(unsafe { _mm_sll_epi32(self.0, _mm_cvtsi32_si128(count)) })
+        } else {
+            Self::new(self.x.shl(rhs), self.y.shl(rhs), self.z.shl(rhs))
+        }
+    }
+}
+
+impl Shl<&i16> for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn shl(self, rhs: &i16) -> Self {
+        self.shl(*rhs)
+    }
+}
+
+impl Shl<&i16> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn shl(self, rhs: &i16) -> UVec3A {
+        (*self).shl(*rhs)
+    }
+}
+
+impl Shl<i16> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn shl(self, rhs: i16) -> UVec3A {
+        (*self).shl(rhs)
+    }
+}
+
+impl ShlAssign<i16> for UVec3A {
+    #[inline]
+    fn shl_assign(&mut self, rhs: i16) {
+        *self = self.shl(rhs);
+    }
+}
+
+impl ShlAssign<&i16> for UVec3A {
+    #[inline]
+    fn shl_assign(&mut self, rhs: &i16) {
+        self.shl_assign(*rhs);
+    }
+}
+
+impl Shr<i16> for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn shr(self, rhs: i16) -> Self::Output {
+        if (0..32).contains(&rhs) {
+            let count = rhs as i32;
+            Self(unsafe { _mm_srl_epi32(self.0, _mm_cvtsi32_si128(count)) })
+        } else {
+            Self::new(self.x.shr(rhs), self.y.shr(rhs), self.z.shr(rhs))
+        }
+    }
+}
+
+impl Shr<&i16> for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn shr(self, rhs: &i16) -> Self {
+        self.shr(*rhs)
+    }
+}
+
+impl Shr<&i16> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn shr(self, rhs: &i16) -> UVec3A {
+        (*self).shr(*rhs)
+    }
+}
+
+impl Shr<i16> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn shr(self, rhs: i16) -> UVec3A {
+        (*self).shr(rhs)
+    }
+}
+
+impl ShrAssign<i16> for UVec3A {
+    #[inline]
+    fn shr_assign(&mut self, rhs: i16) {
+        *self = self.shr(rhs);
+    }
+}
+
+impl ShrAssign<&i16> for UVec3A {
+    #[inline]
+    fn shr_assign(&mut self, rhs: &i16) {
+        self.shr_assign(*rhs);
+    }
+}
+
+impl Shl<i32> for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn shl(self, rhs: i32) -> Self::Output {
+        if (0..32).contains(&rhs) {
+            let count = rhs;
+            Self(unsafe { _mm_sll_epi32(self.0, _mm_cvtsi32_si128(count)) })
+        } else {
+            Self::new(self.x.shl(rhs), self.y.shl(rhs), self.z.shl(rhs))
+        }
+    }
+}
+
+impl Shl<&i32> for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn shl(self, rhs: &i32) -> Self {
+        self.shl(*rhs)
+    }
+}
+
+impl Shl<&i32> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn shl(self, rhs: &i32) -> UVec3A {
+        (*self).shl(*rhs)
+    }
+}
+
+impl Shl<i32> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn shl(self, rhs: i32) -> UVec3A {
+        (*self).shl(rhs)
+    }
+}
+
+impl ShlAssign<i32> for UVec3A {
+    #[inline]
+    fn shl_assign(&mut self, rhs: i32) {
+        *self = self.shl(rhs);
+    }
+}
+
+impl ShlAssign<&i32> for UVec3A {
+    #[inline]
+    fn shl_assign(&mut self, rhs: &i32) {
+        self.shl_assign(*rhs);
+    }
+}
+
+impl Shr<i32> for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn shr(self, rhs: i32) -> Self::Output {
+        if (0..32).contains(&rhs) {
+            let count = rhs;
+            Self(unsafe { _mm_srl_epi32(self.0, _mm_cvtsi32_si128(count)) })
+        } else {
+            Self::new(self.x.shr(rhs), self.y.shr(rhs), self.z.shr(rhs))
+        }
+    }
+}
+
+impl Shr<&i32> for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn shr(self, rhs: &i32) -> Self {
+        self.shr(*rhs)
+    }
+}
+
+impl Shr<&i32> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn shr(self, rhs: &i32) -> UVec3A {
+        (*self).shr(*rhs)
+    }
+}
+
+impl Shr<i32> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn shr(self, rhs: i32) -> UVec3A {
+        (*self).shr(rhs)
+    }
+}
+
+impl ShrAssign<i32> for UVec3A {
+    #[inline]
+    fn shr_assign(&mut self, rhs: i32) {
+        *self = self.shr(rhs);
+    }
+}
+
+impl ShrAssign<&i32> for UVec3A {
+    #[inline]
+    fn shr_assign(&mut self, rhs: &i32) {
+        self.shr_assign(*rhs);
+    }
+}
+
+impl Shl<i64> for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn shl(self, rhs: i64) -> Self::Output {
+        if (0..32).contains(&rhs) {
+            let count = rhs as i32;
+            Self(unsafe { _mm_sll_epi32(self.0, _mm_cvtsi32_si128(count)) })
+        } else {
+            Self::new(self.x.shl(rhs), self.y.shl(rhs), self.z.shl(rhs))
+        }
+    }
+}
+
+impl Shl<&i64> for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn shl(self, rhs: &i64) -> Self {
+        self.shl(*rhs)
+    }
+}
+
+impl Shl<&i64> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn shl(self, rhs: &i64) -> UVec3A {
+        (*self).shl(*rhs)
+    }
+}
+
+impl Shl<i64> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn shl(self, rhs: i64) -> UVec3A {
+        (*self).shl(rhs)
+    }
+}
+
+impl ShlAssign<i64> for UVec3A {
+    #[inline]
+    fn shl_assign(&mut self, rhs: i64) {
+        *self = self.shl(rhs);
+    }
+}
+
+impl ShlAssign<&i64> for UVec3A {
+    #[inline]
+    fn shl_assign(&mut self, rhs: &i64) {
+        self.shl_assign(*rhs);
+    }
+}
+
+impl Shr<i64> for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn shr(self, rhs: i64) -> Self::Output {
+        if (0..32).contains(&rhs) {
+            let count = rhs as i32;
+            Self(unsafe { _mm_srl_epi32(self.0, _mm_cvtsi32_si128(count)) })
+        } else {
+            Self::new(self.x.shr(rhs), self.y.shr(rhs), self.z.shr(rhs))
+        }
+    }
+}
+
+impl Shr<&i64> for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn shr(self, rhs: &i64) -> Self {
+        self.shr(*rhs)
+    }
+}
+
+impl Shr<&i64> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn shr(self, rhs: &i64) -> UVec3A {
+        (*self).shr(*rhs)
+    }
+}
+
+impl Shr<i64> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn shr(self, rhs: i64) -> UVec3A {
+        (*self).shr(rhs)
+    }
+}
+
+impl ShrAssign<i64> for UVec3A {
+    #[inline]
+    fn shr_assign(&mut self, rhs: i64) {
+        *self = self.shr(rhs);
+    }
+}
+
+impl ShrAssign<&i64> for UVec3A {
+    #[inline]
+    fn shr_assign(&mut self, rhs: &i64) {
+        self.shr_assign(*rhs);
+    }
+}
+
+impl Shl<u8> for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn shl(self, rhs: u8) -> Self::Output {
+        if (0..32).contains(&rhs) {
+            let count = rhs as i32;
+            Self(unsafe { _mm_sll_epi32(self.0, _mm_cvtsi32_si128(count)) })
+        } else {
+            Self::new(self.x.shl(rhs), self.y.shl(rhs), self.z.shl(rhs))
+        }
+    }
+}
+
+impl Shl<&u8> for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn shl(self, rhs: &u8) -> Self {
+        self.shl(*rhs)
+    }
+}
+
+impl Shl<&u8> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn shl(self, rhs: &u8) -> UVec3A {
+        (*self).shl(*rhs)
+    }
+}
+
+impl Shl<u8> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn shl(self, rhs: u8) -> UVec3A {
+        (*self).shl(rhs)
+    }
+}
+
+impl ShlAssign<u8> for UVec3A {
+    #[inline]
+    fn shl_assign(&mut self, rhs: u8) {
+        *self = self.shl(rhs);
+    }
+}
+
+impl ShlAssign<&u8> for UVec3A {
+    #[inline]
+    fn shl_assign(&mut self, rhs: &u8) {
+        self.shl_assign(*rhs);
+    }
+}
+
+impl Shr<u8> for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn shr(self, rhs: u8) -> Self::Output {
+        if (0..32).contains(&rhs) {
+            let count = rhs as i32;
+            Self(unsafe { _mm_srl_epi32(self.0, _mm_cvtsi32_si128(count)) })
+        } else {
+            Self::new(self.x.shr(rhs), self.y.shr(rhs), self.z.shr(rhs))
+        }
+    }
+}
+
+impl Shr<&u8> for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn shr(self, rhs: &u8) -> Self {
+        self.shr(*rhs)
+    }
+}
+
+impl Shr<&u8> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn shr(self, rhs: &u8) -> UVec3A {
+        (*self).shr(*rhs)
+    }
+}
+
+impl Shr<u8> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn shr(self, rhs: u8) -> UVec3A {
+        (*self).shr(rhs)
+    }
+}
+
+impl ShrAssign<u8> for UVec3A {
+    #[inline]
+    fn shr_assign(&mut self, rhs: u8) {
+        *self = self.shr(rhs);
+    }
+}
+
+impl ShrAssign<&u8> for UVec3A {
+    #[inline]
+    fn shr_assign(&mut self, rhs: &u8) {
+        self.shr_assign(*rhs);
+    }
+}
+
+impl Shl<u16> for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn shl(self, rhs: u16) -> Self::Output {
+        if (0..32).contains(&rhs) {
+            let count = rhs as i32;
+            Self(unsafe { _mm_sll_epi32(self.0, _mm_cvtsi32_si128(count)) })
+        } else {
+            Self::new(self.x.shl(rhs), self.y.shl(rhs), self.z.shl(rhs))
+        }
+    }
+}
+
+impl Shl<&u16> for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn shl(self, rhs: &u16) -> Self {
+        self.shl(*rhs)
+    }
+}
+
+impl Shl<&u16> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn shl(self, rhs: &u16) -> UVec3A {
+        (*self).shl(*rhs)
+    }
+}
+
+impl Shl<u16> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn shl(self, rhs: u16) -> UVec3A {
+        (*self).shl(rhs)
+    }
+}
+
+impl ShlAssign<u16> for UVec3A {
+    #[inline]
+    fn shl_assign(&mut self, rhs: u16) {
+        *self = self.shl(rhs);
+    }
+}
+
+impl ShlAssign<&u16> for UVec3A {
+    #[inline]
+    fn shl_assign(&mut self, rhs: &u16) {
+        self.shl_assign(*rhs);
+    }
+}
+
+impl Shr<u16> for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn shr(self, rhs: u16) -> Self::Output {
+        if (0..32).contains(&rhs) {
+            let count = rhs as i32;
+            Self(unsafe { _mm_srl_epi32(self.0, _mm_cvtsi32_si128(count)) })
+        } else {
+            Self::new(self.x.shr(rhs), self.y.shr(rhs), self.z.shr(rhs))
+        }
+    }
+}
+
+impl Shr<&u16> for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn shr(self, rhs: &u16) -> Self {
+        self.shr(*rhs)
+    }
+}
+
+impl Shr<&u16> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn shr(self, rhs: &u16) -> UVec3A {
+        (*self).shr(*rhs)
+    }
+}
+
+impl Shr<u16> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn shr(self, rhs: u16) -> UVec3A {
+        (*self).shr(rhs)
+    }
+}
+
+impl ShrAssign<u16> for UVec3A {
+    #[inline]
+    fn shr_assign(&mut self, rhs: u16) {
+        *self = self.shr(rhs);
+    }
+}
+
+impl ShrAssign<&u16> for UVec3A {
+    #[inline]
+    fn shr_assign(&mut self, rhs: &u16) {
+        self.shr_assign(*rhs);
+    }
+}
+
+impl Shl<u32> for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn shl(self, rhs: u32) -> Self::Output {
+        if (0..32).contains(&rhs) {
+            let count = rhs as i32;
+            Self(unsafe { _mm_sll_epi32(self.0, _mm_cvtsi32_si128(count)) })
+        } else {
+            Self::new(self.x.shl(rhs), self.y.shl(rhs), self.z.shl(rhs))
+        }
+    }
+}
+
+impl Shl<&u32> for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn shl(self, rhs: &u32) -> Self {
+        self.shl(*rhs)
+    }
+}
+
+impl Shl<&u32> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn shl(self, rhs: &u32) -> UVec3A {
+        (*self).shl(*rhs)
+    }
+}
+
+impl Shl<u32> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn shl(self, rhs: u32) -> UVec3A {
+        (*self).shl(rhs)
+    }
+}
+
+impl ShlAssign<u32> for UVec3A {
+    #[inline]
+    fn shl_assign(&mut self, rhs: u32) {
+        *self = self.shl(rhs);
+    }
+}
+
+impl ShlAssign<&u32> for UVec3A {
+    #[inline]
+    fn shl_assign(&mut self, rhs: &u32) {
+        self.shl_assign(*rhs);
+    }
+}
+
+impl Shr<u32> for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn shr(self, rhs: u32) -> Self::Output {
+        if (0..32).contains(&rhs) {
+            let count = rhs as i32;
+            Self(unsafe { _mm_srl_epi32(self.0, _mm_cvtsi32_si128(count)) })
+        } else {
+            Self::new(self.x.shr(rhs), self.y.shr(rhs), self.z.shr(rhs))
+        }
+    }
+}
+
+impl Shr<&u32> for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn shr(self, rhs: &u32) -> Self {
+        self.shr(*rhs)
+    }
+}
+
+impl Shr<&u32> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn shr(self, rhs: &u32) -> UVec3A {
+        (*self).shr(*rhs)
+    }
+}
+
+impl Shr<u32> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn shr(self, rhs: u32) -> UVec3A {
+        (*self).shr(rhs)
+    }
+}
+
+impl ShrAssign<u32> for UVec3A {
+    #[inline]
+    fn shr_assign(&mut self, rhs: u32) {
+        *self = self.shr(rhs);
+    }
+}
+
+impl ShrAssign<&u32> for UVec3A {
+    #[inline]
+    fn shr_assign(&mut self, rhs: &u32) {
+        self.shr_assign(*rhs);
+    }
+}
+
+impl Shl<u64> for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn shl(self, rhs: u64) -> Self::Output {
+        if (0..32).contains(&rhs) {
+            let count = rhs as i32;
+            Self(unsafe { _mm_sll_epi32(self.0, _mm_cvtsi32_si128(count)) })
+        } else {
+            Self::new(self.x.shl(rhs), self.y.shl(rhs), self.z.shl(rhs))
+        }
+    }
+}
+
+impl Shl<&u64> for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn shl(self, rhs: &u64) -> Self {
+        self.shl(*rhs)
+    }
+}
+
+impl Shl<&u64> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn shl(self, rhs: &u64) -> UVec3A {
+        (*self).shl(*rhs)
+    }
+}
+
+impl Shl<u64> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn shl(self, rhs: u64) -> UVec3A {
+        (*self).shl(rhs)
+    }
+}
+
+impl ShlAssign<u64> for UVec3A {
+    #[inline]
+    fn shl_assign(&mut self, rhs: u64) {
+        *self = self.shl(rhs);
+    }
+}
+
+impl ShlAssign<&u64> for UVec3A {
+    #[inline]
+    fn shl_assign(&mut self, rhs: &u64) {
+        self.shl_assign(*rhs);
+    }
+}
+
+impl Shr<u64> for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn shr(self, rhs: u64) -> Self::Output {
+        if (0..32).contains(&rhs) {
+            let count = rhs as i32;
+            Self(unsafe { _mm_srl_epi32(self.0, _mm_cvtsi32_si128(count)) })
+        } else {
+            Self::new(self.x.shr(rhs), self.y.shr(rhs), self.z.shr(rhs))
+        }
+    }
+}
+
+impl Shr<&u64> for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn shr(self, rhs: &u64) -> Self {
+        self.shr(*rhs)
+    }
+}
+
+impl Shr<&u64> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn shr(self, rhs: &u64) -> UVec3A {
+        (*self).shr(*rhs)
+    }
+}
+
+impl Shr<u64> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn shr(self, rhs: u64) -> UVec3A {
+        (*self).shr(rhs)
+    }
+}
+
+impl ShrAssign<u64> for UVec3A {
+    #[inline]
+    fn shr_assign(&mut self, rhs: u64) {
+        *self = self.shr(rhs);
+    }
+}
+
+impl ShrAssign<&u64> for UVec3A {
+    #[inline]
+    fn shr_assign(&mut self, rhs: &u64) {
+        self.shr_assign(*rhs);
+    }
+}
+
+impl Shl<IVec3A> for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn shl(self, rhs: IVec3A) -> Self {
+        Self::new(self.x.shl(rhs.x), self.y.shl(rhs.y), self.z.shl(rhs.z))
+    }
+}
+
+impl Shl<&IVec3A> for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn shl(self, rhs: &IVec3A) -> Self {
+        self.shl(*rhs)
+    }
+}
+
+impl Shl<&IVec3A> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn shl(self, rhs: &IVec3A) -> UVec3A {
+        (*self).shl(*rhs)
+    }
+}
+
+impl Shl<IVec3A> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn shl(self, rhs: IVec3A) -> UVec3A {
+        (*self).shl(rhs)
+    }
+}
+
+impl Shr<IVec3A> for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn shr(self, rhs: IVec3A) -> Self {
+        Self::new(self.x.shr(rhs.x), self.y.shr(rhs.y), self.z.shr(rhs.z))
+    }
+}
+
+impl Shr<&IVec3A> for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn shr(self, rhs: &IVec3A) -> Self {
+        self.shr(*rhs)
+    }
+}
+
+impl Shr<&IVec3A> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn shr(self, rhs: &IVec3A) -> UVec3A {
+        (*self).shr(*rhs)
+    }
+}
+
+impl Shr<IVec3A> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn shr(self, rhs: IVec3A) -> UVec3A {
+        (*self).shr(rhs)
+    }
+}
+
+impl Shl for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn shl(self, rhs: Self) -> Self {
+        Self::new(self.x.shl(rhs.x), self.y.shl(rhs.y), self.z.shl(rhs.z))
+    }
+}
+
+impl Shl<&Self> for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn shl(self, rhs: &Self) -> Self {
+        self.shl(*rhs)
+    }
+}
+
+impl Shl<&UVec3A> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn shl(self, rhs: &UVec3A) -> UVec3A {
+        (*self).shl(*rhs)
+    }
+}
+
+impl Shl<UVec3A> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn shl(self, rhs: UVec3A) -> UVec3A {
+        (*self).shl(rhs)
+    }
+}
+
+impl Shr for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn shr(self, rhs: Self) -> Self {
+        Self::new(self.x.shr(rhs.x), self.y.shr(rhs.y), self.z.shr(rhs.z))
+    }
+}
+
+impl Shr<&Self> for UVec3A {
+    type Output = Self;
+    #[inline]
+    fn shr(self, rhs: &Self) -> Self {
+        self.shr(*rhs)
+    }
+}
+
+impl Shr<&UVec3A> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn shr(self, rhs: &UVec3A) -> UVec3A {
+        (*self).shr(*rhs)
+    }
+}
+
+impl Shr<UVec3A> for &UVec3A {
+    type Output = UVec3A;
+    #[inline]
+    fn shr(self, rhs: UVec3A) -> UVec3A {
+        (*self).shr(rhs)
+    }
+}
+
+impl Index<usize> for UVec3A {
+    type Output = u32;
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            _ => panic!("index out of bounds"),
+        }
+    }
+}
+
+impl IndexMut<usize> for UVec3A {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            _ => panic!("index out of bounds"),
+        }
+    }
+}
+
+impl fmt::Display for UVec3A {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}, {}, {}]", self.x, self.y, self.z)
+    }
+}
+
+impl fmt::Debug for UVec3A {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_tuple(stringify!(UVec3A))
+            .field(&self.x)
+            .field(&self.y)
+            .field(&self.z)
+            .finish()
+    }
+}
+
+impl From<UVec3A> for __m128i {
+    #[inline(always)]
+    fn from(t: UVec3A) -> Self {
+        t.0
+    }
+}
+
+impl From<__m128i> for UVec3A {
+    #[inline(always)]
+    fn from(t: __m128i) -> Self {
+        Self(t)
+    }
+}
+
+impl From<[u32; 3]> for UVec3A {
+    #[inline]
+    fn from(a: [u32; 3]) -> Self {
+        Self::new(a[0], a[1], a[2])
+    }
+}
+
+impl From<UVec3A> for [u32; 3] {
+    #[inline]
+    fn from(v: UVec3A) -> Self {
+        v.to_array()
+    }
+}
+
+impl From<(u32, u32, u32)> for UVec3A {
+    #[inline]
+    fn from(t: (u32, u32, u32)) -> Self {
+        Self::new(t.0, t.1, t.2)
+    }
+}
+
+impl From<UVec3A> for (u32, u32, u32) {
+    #[inline]
+    fn from(v: UVec3A) -> Self {
+        (v.x, v.y, v.z)
+    }
+}
+
+impl From<UVec3> for UVec3A {
+    #[inline]
+    fn from(v: UVec3) -> Self {
+        Self::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<UVec3A> for UVec3 {
+    #[inline]
+    fn from(v: UVec3A) -> Self {
+        Self {
+            x: v.x,
+            y: v.y,
+            z: v.z,
+        }
+    }
+}
+
+impl From<(UVec2, u32)> for UVec3A {
+    #[inline]
+    fn from((v, z): (UVec2, u32)) -> Self {
+        Self::new(v.x, v.y, z)
+    }
+}
+
+impl Deref for UVec3A {
+    type Target = crate::deref::Vec3<u32>;
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        unsafe { &*(self as *const Self).cast() }
+    }
+}
+
+impl DerefMut for UVec3A {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unsafe { &mut *(self as *mut Self).cast() }
+    }
+}
+
+impl TryFrom<IVec3A> for UVec3A {
+    type Error = core::num::TryFromIntError;
+
+    #[inline]
+    fn try_from(v: IVec3A) -> Result<Self, Self::Error> {
+        Ok(Self::new(
+            u32::try_from(v.x)?,
+            u32::try_from(v.y)?,
+            u32::try_from(v.z)?,
+        ))
+    }
+}
+
+impl From<BVec3> for UVec3A {
+    #[inline]
+    fn from(v: BVec3) -> Self {
+        Self::new(u32::from(v.x), u32::from(v.y), u32::from(v.z))
+    }
+}
+
+impl From<BVec3A> for UVec3A {
+    #[inline]
+    fn from(v: BVec3A) -> Self {
+        let bool_array: [bool; 3] = v.into();
+        Self::new(
+            u32::from(bool_array[0]),
+            u32::from(bool_array[1]),
+            u32::from(bool_array[2]),
+        )
+    }
+}
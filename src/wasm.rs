@@ -21,10 +21,23 @@ pub(crate) fn dot3_in_x(lhs: v128, rhs: v128) -> v128 {
 #[inline(always)]
 pub(crate) fn dot4_in_x(lhs: v128, rhs: v128) -> v128 {
     let x2_y2_z2_w2 = f32x4_mul(lhs, rhs);
-    let z2_w2_0_0 = i32x4_shuffle::<2, 3, 0, 0>(x2_y2_z2_w2, x2_y2_z2_w2);
-    let x2z2_y2w2_0_0 = f32x4_add(x2_y2_z2_w2, z2_w2_0_0);
-    let y2w2_0_0_0 = i32x4_shuffle::<1, 0, 0, 0>(x2z2_y2w2_0_0, x2z2_y2w2_0_0);
-    f32x4_add(x2z2_y2w2_0_0, y2w2_0_0_0)
+    #[cfg(not(feature = "deterministic"))]
+    {
+        let z2_w2_0_0 = i32x4_shuffle::<2, 3, 0, 0>(x2_y2_z2_w2, x2_y2_z2_w2);
+        let x2z2_y2w2_0_0 = f32x4_add(x2_y2_z2_w2, z2_w2_0_0);
+        let y2w2_0_0_0 = i32x4_shuffle::<1, 0, 0, 0>(x2z2_y2w2_0_0, x2z2_y2w2_0_0);
+        f32x4_add(x2z2_y2w2_0_0, y2w2_0_0_0)
+    }
+    // Adds the products in the same order as the scalar implementation.
+    #[cfg(feature = "deterministic")]
+    {
+        let y2_0_0_0 = i32x4_shuffle::<1, 0, 0, 0>(x2_y2_z2_w2, x2_y2_z2_w2);
+        let z2_0_0_0 = i32x4_shuffle::<2, 0, 0, 0>(x2_y2_z2_w2, x2_y2_z2_w2);
+        let w2_0_0_0 = i32x4_shuffle::<3, 0, 0, 0>(x2_y2_z2_w2, x2_y2_z2_w2);
+        let x2y2_0_0_0 = f32x4_add(x2_y2_z2_w2, y2_0_0_0);
+        let x2y2z2_0_0_0 = f32x4_add(x2y2_0_0_0, z2_0_0_0);
+        f32x4_add(x2y2z2_0_0_0, w2_0_0_0)
+    }
 }
 
 #[inline]
@@ -48,3 +61,57 @@ pub(crate) fn dot4_into_v128(lhs: v128, rhs: v128) -> v128 {
     let dot_in_x = dot4_in_x(lhs, rhs);
     i32x4_shuffle::<0, 0, 0, 0>(dot_in_x, dot_in_x)
 }
+
+// The checked operations return `None` if any lane overflowed so the caller can redo the
+// operation with the scalar operators, which follow the `overflow-checks` profile setting.
+#[cfg(feature = "int-simd")]
+#[inline(always)]
+pub(crate) fn i32x4_checked_add(a: v128, b: v128) -> Option<v128> {
+    let sum = i32x4_add(a, b);
+    // A lane overflowed if both operands have a different sign than the sum.
+    let overflow = v128_and(v128_xor(a, sum), v128_xor(b, sum));
+    (i32x4_bitmask(overflow) == 0).then_some(sum)
+}
+
+#[cfg(feature = "int-simd")]
+#[inline(always)]
+pub(crate) fn i32x4_checked_sub(a: v128, b: v128) -> Option<v128> {
+    let diff = i32x4_sub(a, b);
+    // A lane overflowed if the operands have different signs and the difference doesn't have the
+    // sign of `a`.
+    let overflow = v128_and(v128_xor(a, b), v128_xor(a, diff));
+    (i32x4_bitmask(overflow) == 0).then_some(diff)
+}
+
+#[cfg(feature = "int-simd")]
+#[inline(always)]
+pub(crate) fn u32x4_checked_add(a: v128, b: v128) -> Option<v128> {
+    let sum = u32x4_add(a, b);
+    (!v128_any_true(u32x4_lt(sum, a))).then_some(sum)
+}
+
+#[cfg(feature = "int-simd")]
+#[inline(always)]
+pub(crate) fn u32x4_checked_sub(a: v128, b: v128) -> Option<v128> {
+    (!v128_any_true(u32x4_lt(a, b))).then_some(u32x4_sub(a, b))
+}
+
+// There is no widening 32-bit multiply, so the multiplication is only done with SIMD if all lanes
+// of both operands fit in 16 bits, which is enough for the product to not overflow.
+#[cfg(feature = "int-simd")]
+#[inline(always)]
+pub(crate) fn i32x4_checked_mul(a: v128, b: v128) -> Option<v128> {
+    let bias = i32x4_splat(0x8000);
+    let high = v128_or(
+        u32x4_shr(i32x4_add(a, bias), 16),
+        u32x4_shr(i32x4_add(b, bias), 16),
+    );
+    (!v128_any_true(high)).then(|| i32x4_mul(a, b))
+}
+
+#[cfg(feature = "int-simd")]
+#[inline(always)]
+pub(crate) fn u32x4_checked_mul(a: v128, b: v128) -> Option<v128> {
+    let high = u32x4_shr(v128_or(a, b), 16);
+    (!v128_any_true(high)).then(|| u32x4_mul(a, b))
+}
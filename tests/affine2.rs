@@ -378,5 +378,26 @@ mod daffine2 {
         );
     });
 
+    #[cfg(feature = "const-float-arithmetic")]
+    glam_test!(test_const_arithmetic, {
+        const A: DAffine2 = DAffine2::from_cols(
+            DVec2::new(1.0, 2.0),
+            DVec2::new(-3.0, 4.5),
+            DVec2::new(5.0, -6.0),
+        );
+        const B: DAffine2 = DAffine2::from_cols(
+            DVec2::new(0.5, -1.0),
+            DVec2::new(2.0, 0.75),
+            DVec2::new(-8.0, 0.125),
+        );
+        const V: DVec2 = DVec2::new(0.1, -0.2);
+        const PRODUCT: DAffine2 = A.const_mul_affine(&B);
+        const POINT: DVec2 = A.const_transform_point2(V);
+        const VECTOR: DVec2 = A.const_transform_vector2(V);
+        assert_eq!(A * B, PRODUCT);
+        assert_eq!(A.transform_point2(V), POINT);
+        assert_eq!(A.transform_vector2(V), VECTOR);
+    });
+
     impl_affine2_tests!(f64, DAffine2, DVec2, DMat2, DMat3);
 }
@@ -264,6 +264,53 @@ macro_rules! impl_affine3_tests {
             );
         });
 
+        glam_test!(test_affine3_decompose_shear, {
+            let in_scale = $vec3::new(2.0, 3.0, 4.0);
+            let in_shear = $vec3::new(0.5, -0.25, 1.5);
+            let in_rotation = $quat::from_rotation_y($t::to_radians(30.0));
+            let in_translation = $vec3::new(-2.0, 4.0, -0.125);
+            let in_mat = $affine3::from_scale_shear_rotation_translation(
+                in_scale,
+                in_shear,
+                in_rotation,
+                in_translation,
+            );
+            assert_approx_eq!(
+                $mat4::from(in_mat),
+                $mat4::from_translation(in_translation)
+                    * $mat4::from_mat3($mat3::from_scale_shear_rotation(
+                        in_scale,
+                        in_shear,
+                        in_rotation
+                    )),
+                1e-6
+            );
+
+            let (scale, shear, rotation, translation) =
+                in_mat.to_scale_shear_rotation_translation().unwrap();
+            assert_approx_eq!(in_scale, scale, 1e-5);
+            assert_approx_eq!(in_shear, shear, 1e-5);
+            assert_approx_eq!(in_rotation, rotation, 1e-5);
+            assert_approx_eq!(in_translation, translation);
+
+            // negative determinant
+            let in_mat = in_mat * $affine3::from_scale($vec3::new(1.0, 1.0, -1.0));
+            let (scale, shear, rotation, translation) =
+                in_mat.to_scale_shear_rotation_translation().unwrap();
+            assert_approx_eq!(
+                in_mat,
+                $affine3::from_scale_shear_rotation_translation(
+                    scale,
+                    shear,
+                    rotation,
+                    translation
+                ),
+                1e-5
+            );
+
+            assert_eq!(None, $affine3::ZERO.to_scale_shear_rotation_translation());
+        });
+
         glam_test!(test_affine3_look_at, {
             let eye = $vec3::new(0.0, 0.0, -5.0);
             let center = $vec3::new(0.0, 0.0, 0.0);
@@ -395,6 +442,29 @@ mod affine3 {
         assert_eq!(mem::align_of::<f32>(), mem::align_of::<Affine3>());
     });
 
+    #[cfg(feature = "const-float-arithmetic")]
+    glam_test!(test_const_arithmetic, {
+        const A: Affine3 = Affine3::from_cols(
+            Vec3::new(1.0, 2.0, 3.0),
+            Vec3::new(-4.0, 5.5, 6.0),
+            Vec3::new(7.0, 0.25, -9.0),
+            Vec3::new(10.0, -11.0, 12.0),
+        );
+        const B: Affine3 = Affine3::from_cols(
+            Vec3::new(0.5, -1.0, 2.0),
+            Vec3::new(3.0, 1.5, -0.75),
+            Vec3::new(4.0, 2.0, 1.0),
+            Vec3::new(-2.0, 8.0, 0.125),
+        );
+        const V: Vec3 = Vec3::new(0.1, -0.2, 0.3);
+        const PRODUCT: Affine3 = A.const_mul_affine(&B);
+        const POINT: Vec3 = A.const_transform_point3(V);
+        const VECTOR: Vec3 = A.const_transform_vector3(V);
+        assert_eq!(A * B, PRODUCT);
+        assert_eq!(A.transform_point3(V), POINT);
+        assert_eq!(A.transform_vector3(V), VECTOR);
+    });
+
     impl_affine3_tests!(f32, Affine3, Quat, Vec3, Mat3, Mat4);
 }
 
@@ -446,6 +516,50 @@ mod affine3a {
         assert_approx_eq!(Vec3A::new(1.0, 2.0, 4.5), result3, 1.0e-6);
     });
 
+    glam_test!(test_transform_slices, {
+        let points: Vec<Vec3> = (0..7)
+            .map(|i| Vec3::new(i as f32 - 3.0, (i * i) as f32 * 0.5, 1.0 / (i + 1) as f32))
+            .collect();
+        let points3a: Vec<Vec3A> = points.iter().map(|p| Vec3A::from(*p)).collect();
+        let m = Affine3A::from_scale_rotation_translation(
+            Vec3::new(0.5, 1.5, 2.0),
+            Quat::from_rotation_y(deg(45.0)),
+            Vec3::new(1.0, 2.0, 3.0),
+        );
+
+        let mut out = vec![Vec3::ZERO; points.len()];
+        m.transform_points3(&points, &mut out);
+        let expected: Vec<Vec3> = points.iter().map(|p| m.transform_point3(*p)).collect();
+        assert_eq!(expected, out);
+        let mut in_place = points.clone();
+        m.transform_points3_in_place(&mut in_place);
+        assert_eq!(expected, in_place);
+
+        m.transform_vectors3(&points, &mut out);
+        let expected: Vec<Vec3> = points.iter().map(|p| m.transform_vector3(*p)).collect();
+        assert_eq!(expected, out);
+        let mut in_place = points.clone();
+        m.transform_vectors3_in_place(&mut in_place);
+        assert_eq!(expected, in_place);
+
+        let mut out = vec![Vec3A::ZERO; points.len()];
+        m.transform_points3a(&points3a, &mut out);
+        let expected: Vec<Vec3A> = points3a.iter().map(|p| m.transform_point3a(*p)).collect();
+        assert_eq!(expected, out);
+        let mut in_place = points3a.clone();
+        m.transform_points3a_in_place(&mut in_place);
+        assert_eq!(expected, in_place);
+
+        m.transform_vectors3a(&points3a, &mut out);
+        let expected: Vec<Vec3A> = points3a.iter().map(|p| m.transform_vector3a(*p)).collect();
+        assert_eq!(expected, out);
+        let mut in_place = points3a.clone();
+        m.transform_vectors3a_in_place(&mut in_place);
+        assert_eq!(expected, in_place);
+
+        should_panic!({ m.transform_vectors3a(&points3a, &mut [Vec3A::ZERO; 3]) });
+    });
+
     glam_test!(test_as, {
         use glam::DAffine3;
         assert_eq!(
@@ -0,0 +1,326 @@
+// Tests for the `deterministic` feature. The golden values were produced by the scalar backend,
+// e.g. with `cargo test --features "deterministic scalar-math"`, and every other backend has to
+// reproduce them bit for bit. The `_matches_scalar` tests compare against a plain per component
+// evaluation of the scalar implementation in the same build. A failure in either means that a
+// backend no longer evaluates the same operations in the same order as the others.
+#![cfg(feature = "deterministic")]
+
+#[macro_use]
+mod support;
+
+use glam::{DVec3, DVec4, EulerRot, Mat4, Quat, Vec3, Vec3A, Vec4};
+use support::Rng;
+
+const ITERATIONS: usize = 1000;
+
+fn assert_bits<const N: usize>(expected: [u32; N], actual: [f32; N]) {
+    assert_eq!(
+        expected,
+        actual.map(f32::to_bits),
+        "expected {:?}, got {actual:?}",
+        expected.map(f32::from_bits)
+    );
+}
+
+fn assert_same<const N: usize>(expected: [f32; N], actual: [f32; N]) {
+    assert_eq!(
+        expected.map(f32::to_bits),
+        actual.map(f32::to_bits),
+        "expected {expected:?}, got {actual:?}"
+    );
+}
+
+fn assert_same_f64<const N: usize>(expected: [f64; N], actual: [f64; N]) {
+    assert_eq!(
+        expected.map(f64::to_bits),
+        actual.map(f64::to_bits),
+        "expected {expected:?}, got {actual:?}"
+    );
+}
+
+fn next_f32(rng: &mut Rng) -> f32 {
+    // Avoid the special cases, `normalize` asserts that the result is finite.
+    let v = rng.next_f64() as f32;
+    if v.is_finite() && v != 0.0 {
+        v
+    } else {
+        1.0
+    }
+}
+
+fn next_f64(rng: &mut Rng) -> f64 {
+    next_f32(rng) as f64
+}
+
+fn dot<const N: usize>(a: [f32; N], b: [f32; N]) -> f32 {
+    (1..N).fold(a[0] * b[0], |sum, i| sum + a[i] * b[i])
+}
+
+fn dot_f64<const N: usize>(a: [f64; N], b: [f64; N]) -> f64 {
+    (1..N).fold(a[0] * b[0], |sum, i| sum + a[i] * b[i])
+}
+
+fn normalize<const N: usize>(a: [f32; N]) -> [f32; N] {
+    let length_recip = 1.0 / libm::sqrtf(dot(a, a));
+    a.map(|x| x * length_recip)
+}
+
+fn normalize_f64<const N: usize>(a: [f64; N]) -> [f64; N] {
+    let length_recip = 1.0 / libm::sqrt(dot_f64(a, a));
+    a.map(|x| x * length_recip)
+}
+
+// Copy of the scalar `math::acos_approx` used by `Quat::slerp`.
+fn acos_approx(v: f32) -> f32 {
+    let nonnegative = v >= 0.0;
+    let x = libm::fabsf(v);
+    let mut omx = 1.0 - x;
+    if omx < 0.0 {
+        omx = 0.0;
+    }
+    let root = libm::sqrtf(omx);
+    #[allow(clippy::approx_constant)]
+    let mut result =
+        ((((((-0.001_262_491_1 * x + 0.006_670_09) * x - 0.017_088_126) * x + 0.030_891_88) * x
+            - 0.050_174_303)
+            * x
+            + 0.088_978_99)
+            * x
+            - 0.214_598_8)
+            * x
+            + 1.570_796_3;
+    result *= root;
+    if nonnegative {
+        result
+    } else {
+        core::f32::consts::PI - result
+    }
+}
+
+const EULER: [(EulerRot, f32, f32, f32); 5] = [
+    (EulerRot::XYZ, 0.1, 0.2, 0.3),
+    (EulerRot::YXZ, -1.2, 2.5, 0.7),
+    (EulerRot::ZYX, 3.0, -0.5, 1.5),
+    (EulerRot::XYX, 0.8, 1.9, -2.4),
+    (EulerRot::ZXZEx, -3.1, 0.4, 2.2),
+];
+
+fn from_euler(i: usize) -> Quat {
+    let (order, a, b, c) = EULER[i % EULER.len()];
+    Quat::from_euler(order, a, b, c)
+}
+
+glam_test!(test_vec3_normalize, {
+    const INPUT: [[f32; 3]; 5] = [
+        [1.0, 2.0, 3.0],
+        [-0.3, 1e-3, 7.5],
+        [1e15, -3e14, 2.5e14],
+        [3e-18, 4e-18, -1.2e-17],
+        [0.1, 0.7, -0.2],
+    ];
+    const EXPECTED: [[u32; 3]; 5] = [
+        [0x3e88d677, 0x3f08d677, 0x3f4d41b2],
+        [0xbd23b587, 0x390bb2cc, 0x3f7fcba3],
+        [0x3f6e7650, 0xbe8f13ca, 0x3e6e7650],
+        [0x3e6c4ec6, 0x3e9d89d9, 0xbf6c4ec6],
+        [0x3e0b5948, 0x3f73dc3e, 0xbe8b5948],
+    ];
+    for (v, expected) in INPUT.iter().zip(EXPECTED) {
+        assert_bits(expected, Vec3::from_array(*v).normalize().to_array());
+    }
+});
+
+glam_test!(test_quat_from_euler, {
+    const EXPECTED: [[u32; 4]; 5] = [
+        [0x3d8337d7, 0x3dbab0d0, 0x3e1d1f32, 0x3f7b5aed],
+        [0x3f2cb8ca, 0xbedf2390, 0x3f17b3e5, 0x3d78c17c],
+        [0x3e68be10, 0x3f255f86, 0x3f3816a7, 0xbdf1ce35],
+        [0xbed5a4ed, 0xbcc29236, 0x3f502545, 0x3ecf7e90],
+        [0xbe3358c7, 0x3dc00ead, 0xbeda435b, 0x3f61eb67],
+    ];
+    for (i, expected) in EXPECTED.into_iter().enumerate() {
+        assert_bits(expected, from_euler(i).to_array());
+    }
+});
+
+glam_test!(test_quat_slerp, {
+    const EXPECTED: [[[u32; 4]; 3]; 5] = [
+        [
+            [0x3e9633ea, 0xbd942663, 0x3eafe5f3, 0x3f63a29d],
+            [0x3ef8f885, 0xbe684eb5, 0x3efb6b0a, 0x3f2fae41],
+            [0x3f2988ee, 0xbed153ee, 0x3f1874ed, 0x3e4bd12c],
+        ],
+        [
+            [0x3f269ee5, 0xbe2475a6, 0x3f3decf0, 0x3c581122],
+            [0x3f0fb6af, 0x3e05f25b, 0x3f50fde7, 0xbd122bae],
+            [0x3e9cdc93, 0x3f0ecc7f, 0x3f43a5b7, 0xbdd5f395],
+        ],
+        [
+            [0x3d74d843, 0x3f073274, 0x3f58bd16, 0x3cdd93df],
+            [0xbde65880, 0x3ebc9af2, 0x3f6834ac, 0x3e2e19f0],
+            [0xbeba6886, 0x3d6e67b7, 0x3f5ab2b2, 0x3ebb98c7],
+        ],
+        [
+            [0xbee15a57, 0x3c51c6b3, 0x3f13da2e, 0x3f2ffae4],
+            [0xbece3375, 0x3d42fde5, 0x3e869f89, 0x3f601d17],
+            [0xbe6ec561, 0x3db45a32, 0xbe973b25, 0x3f6c1b91],
+        ],
+        [
+            [0xbdf279a8, 0x3dc67737, 0xbe93e355, 0x3f71f037],
+            [0xbd6fe2fa, 0x3dc7b4d2, 0xbe13530c, 0x3f7ba852],
+            [0x3d227cc0, 0x3dbee9a3, 0x3dc10ebd, 0x3f7d8977],
+        ],
+    ];
+    for (i, expected) in EXPECTED.into_iter().enumerate() {
+        let (start, end) = (from_euler(i), from_euler(i + 1));
+        for (s, expected) in [0.25, 0.5, 0.9].into_iter().zip(expected) {
+            assert_bits(expected, start.slerp(end, s).to_array());
+        }
+    }
+});
+
+glam_test!(test_quat_mul_quat, {
+    const EXPECTED: [[u32; 4]; 5] = [
+        [0x3e3a6600, 0x3f1cc021, 0x3f3566f8, 0xbe997416],
+        [0xbdbccd5a, 0xbf79520a, 0x3dbbb432, 0xbe3e7c8c],
+        [0xbdf8f315, 0x3f07ab5c, 0x3f51c36c, 0x3e39f000],
+        [0xbeec4999, 0x3e04db56, 0x3f52f09a, 0x3e9aa6b7],
+        [0x3ee8bcb7, 0xbf101007, 0x3f0297c1, 0x3eee3571],
+    ];
+    for (i, expected) in EXPECTED.into_iter().enumerate() {
+        assert_bits(expected, (from_euler(i) * from_euler(i + 2)).to_array());
+    }
+});
+
+glam_test!(test_mat4_inverse, {
+    const INPUT: [[f32; 16]; 4] = [
+        [
+            0.8, 0.1, -0.6, 0.0, -0.3, 0.9, 0.2, 0.0, 0.5, 0.4, 0.75, 0.0, 12.5, -3.25, 7.0, 1.0,
+        ],
+        [
+            1.3, 0.0, 0.0, 0.0, 0.0, 1.7, 0.0, 0.0, 0.0, 0.0, -1.002, -1.0, 0.0, 0.0, -0.2002, 0.0,
+        ],
+        [
+            2.0, -1.5, 0.25, 3.0, 0.7, 4.1, -2.2, 0.3, -1.1, 0.6, 5.3, -0.9, 0.4, -2.8, 1.9, 6.2,
+        ],
+        [
+            1e-3, 2e-4, 0.0, 0.0, -3e-4, 1.5e-3, 1e-5, 0.0, 0.0, 0.0, 2e-3, 0.0, 100.0, 250.0,
+            -75.0, 1.0,
+        ],
+    ];
+    const EXPECTED: [[u32; 16]; 4] = [
+        [
+            0x3f33183b, 0xbebda130, 0x3f288f47, 0x80000000, 0x3ec3a64e, 0x3f877322, 0x3cc0a3bc,
+            0x00000000, 0xbf2b91d6, 0xbea28a29, 0x3f61bfe4, 0x80000000, 0xc033f0f3, 0x4124a7f6,
+            0xc1653ad8, 0x3f800000,
+        ],
+        [
+            0x3f44ec50, 0x00000000, 0x80000000, 0x00000000, 0x00000000, 0x3f169696, 0x00000000,
+            0x80000000, 0x80000000, 0x00000000, 0x80000000, 0xc09fd715, 0x00000000, 0x80000000,
+            0xbf800000, 0x40a028eb,
+        ],
+        [
+            0x3f179d1b, 0x3c8916ab, 0x3d9fb15e, 0xbe8d5799, 0xbd0ddea3, 0x3e6c4ea4, 0x3db9c0b2,
+            0x3c99ad07, 0x3de51d86, 0xbba0049a, 0x3e4358e8, 0xbcd6a891, 0xbdb48004, 0x3dd64ae2,
+            0xbcb892dd, 0x3e484c0b,
+        ],
+        [
+            0x44706276, 0xc3003483, 0x3f241a41, 0x80000000, 0x43404ec6, 0x442041a5, 0xc04d20d1,
+            0x00000000, 0x00000000, 0x80000000, 0x43fa0000, 0x80000000, 0xc80cd9b1, 0xc80ffaf9,
+            0x47155d2e, 0x3f800000,
+        ],
+    ];
+    for (m, expected) in INPUT.iter().zip(EXPECTED) {
+        assert_bits(expected, Mat4::from_cols_array(m).inverse().to_cols_array());
+    }
+});
+
+glam_test!(test_vec3a_matches_scalar, {
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+    for _ in 0..ITERATIONS {
+        let a: [f32; 3] = rng.array(next_f32);
+        let b: [f32; 3] = rng.array(next_f32);
+        let (va, vb) = (Vec3A::from_array(a), Vec3A::from_array(b));
+        // `Vec3` always uses the scalar implementation.
+        let (sa, sb) = (Vec3::from_array(a), Vec3::from_array(b));
+        assert_same([sa.dot(sb)], [va.dot(vb)]);
+        assert_same([sa.length()], [va.length()]);
+        assert_same(sa.normalize().to_array(), va.normalize().to_array());
+    }
+});
+
+glam_test!(test_vec4_matches_scalar, {
+    let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+    for _ in 0..ITERATIONS {
+        let a: [f32; 4] = rng.array(next_f32);
+        let b: [f32; 4] = rng.array(next_f32);
+        let (va, vb) = (Vec4::from_array(a), Vec4::from_array(b));
+        assert_same([dot(a, b)], [va.dot(vb)]);
+        assert_same([libm::sqrtf(dot(a, a))], [va.length()]);
+        assert_same(normalize(a), va.normalize().to_array());
+    }
+});
+
+glam_test!(test_dvec3_matches_scalar, {
+    let mut rng = Rng(0xd1b5_4a32_d192_ed03);
+    for _ in 0..ITERATIONS {
+        let a: [f64; 3] = rng.array(next_f64);
+        let b: [f64; 3] = rng.array(next_f64);
+        let (va, vb) = (DVec3::from_array(a), DVec3::from_array(b));
+        assert_same_f64([dot_f64(a, b)], [va.dot(vb)]);
+        assert_same_f64([libm::sqrt(dot_f64(a, a))], [va.length()]);
+        assert_same_f64(normalize_f64(a), va.normalize().to_array());
+    }
+});
+
+glam_test!(test_dvec4_matches_scalar, {
+    let mut rng = Rng(0x8cb9_2ba7_2f3d_8dd7);
+    for _ in 0..ITERATIONS {
+        let a: [f64; 4] = rng.array(next_f64);
+        let b: [f64; 4] = rng.array(next_f64);
+        let (va, vb) = (DVec4::from_array(a), DVec4::from_array(b));
+        assert_same_f64([dot_f64(a, b)], [va.dot(vb)]);
+        assert_same_f64([libm::sqrt(dot_f64(a, a))], [va.length()]);
+        assert_same_f64(normalize_f64(a), va.normalize().to_array());
+    }
+});
+
+glam_test!(test_quat_mul_quat_matches_scalar, {
+    let mut rng = Rng(0x94d0_49bb_1331_11eb);
+    for _ in 0..ITERATIONS {
+        let [x0, y0, z0, w0] = rng.array(next_f32);
+        let [x1, y1, z1, w1] = rng.array(next_f32);
+        let expected = [
+            w0 * x1 + x0 * w1 + y0 * z1 - z0 * y1,
+            w0 * y1 - x0 * z1 + y0 * w1 + z0 * x1,
+            w0 * z1 + x0 * y1 - y0 * x1 + z0 * w1,
+            w0 * w1 - x0 * x1 - y0 * y1 - z0 * z1,
+        ];
+        let actual = Quat::from_xyzw(x0, y0, z0, w0) * Quat::from_xyzw(x1, y1, z1, w1);
+        assert_same(expected, actual.to_array());
+    }
+});
+
+glam_test!(test_quat_slerp_matches_scalar, {
+    for i in 0..EULER.len() {
+        let (start, end) = (from_euler(i), from_euler(i + 1));
+        for s in [0.25, 0.5, 0.9] {
+            let a = start.to_array();
+            let mut b = end.to_array();
+            let mut d = dot(a, b);
+            if d < 0.0 {
+                b = b.map(|x| -x);
+                d = -d;
+            }
+            // None of the inputs are close enough to take the `lerp` path.
+            assert!(d <= 1.0 - f32::EPSILON);
+            let theta = acos_approx(d);
+            let scale1 = libm::sinf(theta * (1.0 - s));
+            let scale2 = libm::sinf(theta * s);
+            let theta_sin_recip = 1.0 / libm::sinf(theta);
+            let expected =
+                core::array::from_fn(|j| (a[j] * scale1 + b[j] * scale2) * theta_sin_recip);
+            assert_same(expected, start.slerp(end, s).to_array());
+        }
+    }
+});
@@ -0,0 +1,409 @@
+#[macro_use]
+mod support;
+
+macro_rules! impl_dualquat_tests {
+    ($t:ident, $new:ident, $dualquat:ident, $quat:ident, $vec3:ident, $mat4:ident, $affine3:ident, $from_affine:ident, $to_affine:ident) => {
+        glam_test!(test_const, {
+            const DQ0: $dualquat = $dualquat::from_array([1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+            const DQ1: $dualquat = $dualquat::from_real_dual(
+                $quat::from_xyzw(1.0, 2.0, 3.0, 4.0),
+                $quat::from_xyzw(5.0, 6.0, 7.0, 8.0),
+            );
+            assert_eq!(DQ0, DQ1);
+            assert_eq!([1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0], DQ0.to_array());
+        });
+
+        glam_test!(test_identity, {
+            let identity = $dualquat::IDENTITY;
+            assert_eq!($quat::IDENTITY, identity.real);
+            assert_eq!($quat::from_xyzw(0.0, 0.0, 0.0, 0.0), identity.dual);
+            assert!(identity.is_normalized());
+            assert_eq!($dualquat::IDENTITY, $dualquat::default());
+
+            let p = $vec3::new(1.0, 2.0, 3.0);
+            assert_eq!(p, identity.transform_point3(p));
+            assert_eq!(p, identity.transform_vector3(p));
+            assert_eq!($mat4::IDENTITY, identity.to_mat4());
+        });
+
+        glam_test!(test_nan, {
+            assert!($dualquat::NAN.is_nan());
+            assert!(!$dualquat::NAN.is_finite());
+            assert!(!$dualquat::IDENTITY.is_nan());
+            assert!($dualquat::IDENTITY.is_finite());
+        });
+
+        glam_test!(test_from_rotation_translation, {
+            let rotation = $quat::from_rotation_y(deg(90.0));
+            let translation = $vec3::new(1.0, 2.0, 3.0);
+            let dq = $dualquat::from_rotation_translation(rotation, translation);
+            assert!(dq.is_normalized());
+            assert_approx_eq!(rotation, dq.rotation());
+            assert_approx_eq!(translation, dq.translation(), 1e-6);
+
+            let (r, t) = dq.to_rotation_translation();
+            assert_approx_eq!(rotation, r);
+            assert_approx_eq!(translation, t, 1e-6);
+
+            assert_eq!(dq, $new(rotation, translation));
+
+            let dq = $dualquat::from_quat(rotation);
+            assert_eq!(rotation, dq.rotation());
+            assert_approx_eq!($vec3::ZERO, dq.translation());
+            assert_eq!(dq, $dualquat::from(rotation));
+
+            let dq = $dualquat::from_translation(translation);
+            assert_eq!($quat::IDENTITY, dq.rotation());
+            assert_approx_eq!(translation, dq.translation());
+
+            should_glam_assert!({
+                $dualquat::from_rotation_translation($quat::IDENTITY * 2.0, $vec3::ZERO)
+            });
+            should_glam_assert!({ $dualquat::from_quat($quat::IDENTITY * 2.0) });
+        });
+
+        glam_test!(test_transform, {
+            let rotation = $quat::from_euler(glam::EulerRot::YXZ, deg(30.0), deg(45.0), deg(60.0));
+            let translation = $vec3::new(-1.0, 2.0, 0.5);
+            let dq = $dualquat::from_rotation_translation(rotation, translation);
+            let m = $mat4::from_rotation_translation(rotation, translation);
+            let a = $affine3::from_rotation_translation(rotation, translation);
+
+            let p = $vec3::new(3.0, -4.0, 5.0);
+            assert_approx_eq!(m.transform_point3(p), dq.transform_point3(p), 1e-5);
+            assert_approx_eq!(m.transform_vector3(p), dq.transform_vector3(p), 1e-5);
+            assert_approx_eq!(a.transform_point3(p), dq.transform_point3(p), 1e-5);
+
+            assert_approx_eq!(m, dq.to_mat4(), 1e-6);
+            assert_approx_eq!(m, $mat4::from(dq), 1e-6);
+            assert!(a.abs_diff_eq(dq.$to_affine(), 1e-6));
+            assert!(a.abs_diff_eq(dq.into(), 1e-6));
+
+            should_glam_assert!({ ($dualquat::IDENTITY * 2.0).transform_point3($vec3::ZERO) });
+            should_glam_assert!({ ($dualquat::IDENTITY * 2.0).transform_vector3($vec3::ZERO) });
+        });
+
+        glam_test!(test_from_mat4_affine, {
+            let rotation = $quat::from_rotation_z(deg(-120.0));
+            let translation = $vec3::new(4.0, 5.0, 6.0);
+            let dq = $dualquat::from_rotation_translation(rotation, translation);
+
+            // `q` and `-q` represent the same rotation so compare the resulting transforms
+            let m = $mat4::from_rotation_translation(rotation, translation);
+            let from_mat4 = $dualquat::from_mat4(&m);
+            assert!(from_mat4.is_normalized());
+            assert_approx_eq!(m, from_mat4.to_mat4(), 1e-5);
+            assert_approx_eq!(dq.dot(from_mat4).abs(), 1.0, 1e-6);
+
+            let a = $affine3::from_rotation_translation(rotation, translation);
+            let from_affine = $dualquat::$from_affine(&a);
+            assert!(from_affine.is_normalized());
+            assert!(a.abs_diff_eq(from_affine.$to_affine(), 1e-5));
+            assert_approx_eq!(dq.dot(from_affine).abs(), 1.0, 1e-6);
+
+            should_glam_assert!({ $dualquat::from_mat4(&$mat4::from_scale($vec3::splat(2.0))) });
+        });
+
+        glam_test!(test_mul, {
+            let dq0 = $dualquat::from_rotation_translation(
+                $quat::from_rotation_x(deg(45.0)),
+                $vec3::new(1.0, 0.0, -2.0),
+            );
+            let dq1 = $dualquat::from_rotation_translation(
+                $quat::from_rotation_y(deg(-30.0)),
+                $vec3::new(0.0, 3.0, 1.0),
+            );
+            let dq = dq0 * dq1;
+            assert!(dq.is_normalized());
+            assert_eq!(dq, dq0.mul_dual_quat(dq1));
+            assert_approx_eq!(dq0.to_mat4() * dq1.to_mat4(), dq.to_mat4(), 1e-5);
+
+            let p = $vec3::new(2.0, -1.0, 0.5);
+            assert_approx_eq!(
+                dq0.transform_point3(dq1.transform_point3(p)),
+                dq.transform_point3(p),
+                1e-5
+            );
+
+            let mut dq2 = dq0;
+            dq2 *= dq1;
+            assert_eq!(dq, dq2);
+
+            assert_eq!(dq0, dq0 * $dualquat::IDENTITY);
+            assert_eq!(dq0, $dualquat::IDENTITY * dq0);
+        });
+
+        glam_test!(test_conjugate_inverse, {
+            let dq = $dualquat::from_array([1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+            assert_eq!(
+                $dualquat::from_array([-1.0, -2.0, -3.0, 4.0, -5.0, -6.0, -7.0, 8.0]),
+                dq.conjugate()
+            );
+            assert_eq!(
+                $dualquat::from_array([1.0, 2.0, 3.0, 4.0, -5.0, -6.0, -7.0, -8.0]),
+                dq.dual_conjugate()
+            );
+            assert_eq!(
+                $dualquat::from_array([-1.0, -2.0, -3.0, 4.0, 5.0, 6.0, 7.0, -8.0]),
+                dq.combined_conjugate()
+            );
+
+            let dq = $dualquat::from_rotation_translation(
+                $quat::from_rotation_z(deg(60.0)),
+                $vec3::new(1.0, 2.0, 3.0),
+            );
+            let inv = dq.inverse();
+            assert_approx_eq!($dualquat::IDENTITY, dq * inv, 1e-6);
+            assert_approx_eq!($dualquat::IDENTITY, inv * dq, 1e-6);
+            assert_approx_eq!(dq.to_mat4().inverse(), inv.to_mat4(), 1e-5);
+
+            let p = $vec3::new(-3.0, 0.5, 2.0);
+            assert_approx_eq!(p, inv.transform_point3(dq.transform_point3(p)), 1e-5);
+
+            should_glam_assert!({ ($dualquat::IDENTITY * 2.0).inverse() });
+        });
+
+        glam_test!(test_normalize, {
+            let dq = $dualquat::from_rotation_translation(
+                $quat::from_rotation_x(deg(135.0)),
+                $vec3::new(-2.0, 1.0, 4.0),
+            );
+            assert_approx_eq!(dq.length(), 1.0);
+            assert_approx_eq!(dq.dot(dq), 1.0);
+
+            let scaled = dq * 3.0;
+            assert!(!scaled.is_normalized());
+            assert_approx_eq!(scaled.length(), 3.0, 1e-6);
+            assert_approx_eq!(dq, scaled.normalize(), 1e-6);
+
+            // a dual part that is not orthogonal to the real part is corrected
+            let skewed = $dualquat::from_real_dual(dq.real, dq.dual + dq.real * 0.25);
+            assert!(!skewed.is_normalized());
+            let normalized = skewed.normalize();
+            assert!(normalized.is_normalized());
+            assert_approx_eq!(dq, normalized, 1e-6);
+
+            should_glam_assert!({ $dualquat::ZERO.normalize() });
+        });
+
+        glam_test!(test_large_translation, {
+            // the dual part grows with the translation, which must not make a unit dual
+            // quaternion fail the normalization check
+            let rotation = $quat::from_euler(glam::EulerRot::YXZ, deg(30.0), deg(45.0), deg(60.0));
+            let translation = $vec3::new(-350.0, 720.0, 510.0);
+            let dq = $dualquat::from_rotation_translation(rotation, translation);
+            assert!(dq.is_normalized());
+
+            let p = $vec3::new(1.0, -2.0, 3.0);
+            assert_approx_eq!(rotation * p + translation, dq.transform_point3(p), 1e-3);
+            assert_approx_eq!(
+                p,
+                dq.inverse().transform_point3(dq.transform_point3(p)),
+                1e-2
+            );
+
+            let end = $dualquat::from_translation($vec3::new(400.0, -250.0, 900.0));
+            assert_approx_eq!(end, dq.sclerp(end, 1.0), 1e-2);
+
+            let skewed = $dualquat::from_real_dual(dq.real, dq.dual + dq.real * 0.25);
+            assert!(!skewed.is_normalized());
+        });
+
+        glam_test!(test_dlb, {
+            let dq0 = $dualquat::from_rotation_translation(
+                $quat::from_rotation_y(deg(20.0)),
+                $vec3::new(1.0, 0.0, 0.0),
+            );
+            let dq1 = $dualquat::from_rotation_translation(
+                $quat::from_rotation_y(deg(80.0)),
+                $vec3::new(0.0, 0.0, 1.0),
+            );
+            assert_approx_eq!(dq0, $dualquat::dlb(&[(dq0, 1.0)]), 1e-6);
+            assert_approx_eq!(dq0, $dualquat::dlb(&[(dq0, 1.0), (dq1, 0.0)]), 1e-6);
+            assert_approx_eq!(dq1, $dualquat::dlb(&[(dq0, 0.0), (dq1, 1.0)]), 1e-6);
+
+            // antipodal inputs represent the same transform and are blended consistently
+            assert_approx_eq!(dq0, $dualquat::dlb(&[(dq0, 0.5), (-dq0, 0.5)]), 1e-6);
+
+            let mid = $dualquat::dlb(&[(dq0, 0.5), (dq1, 0.5)]);
+            assert!(mid.is_normalized());
+            assert_approx_eq!($quat::from_rotation_y(deg(50.0)), mid.rotation(), 1e-6);
+
+            assert_approx_eq!(dq0, dq0.lerp(dq1, 0.0), 1e-6);
+            assert_approx_eq!(dq1, dq0.lerp(dq1, 1.0), 1e-6);
+            assert_approx_eq!(mid, dq0.lerp(dq1, 0.5), 1e-6);
+
+            should_glam_assert!({ $dualquat::dlb(&[]) });
+            should_glam_assert!({ ($dualquat::IDENTITY * 2.0).lerp($dualquat::IDENTITY, 0.5) });
+        });
+
+        glam_test!(test_sclerp, {
+            let dq0 = $dualquat::from_rotation_translation(
+                $quat::from_rotation_x(deg(10.0)),
+                $vec3::new(1.0, 2.0, 3.0),
+            );
+            let dq1 = $dualquat::from_rotation_translation(
+                $quat::from_rotation_z(deg(70.0)),
+                $vec3::new(-2.0, 0.0, 1.0),
+            );
+            assert_approx_eq!(dq0, dq0.sclerp(dq1, 0.0), 1e-6);
+            assert_approx_eq!(dq1, dq0.sclerp(dq1, 1.0), 1e-5);
+            assert!(dq0.sclerp(dq1, 0.3).is_normalized());
+
+            // takes the shortest path
+            assert_approx_eq!(dq1, dq0.sclerp(-dq1, 1.0), 1e-5);
+
+            // a screw motion along the rotation axis is split evenly
+            let screw = $dualquat::from_rotation_translation(
+                $quat::from_rotation_z(deg(90.0)),
+                $vec3::new(0.0, 0.0, 4.0),
+            );
+            let half = $dualquat::IDENTITY.sclerp(screw, 0.5);
+            assert_approx_eq!($quat::from_rotation_z(deg(45.0)), half.rotation(), 1e-6);
+            assert_approx_eq!($vec3::new(0.0, 0.0, 2.0), half.translation(), 1e-6);
+
+            // rotation about an axis that does not pass through the origin
+            let pivot = $vec3::new(1.0, 0.0, 0.0);
+            let about_pivot = $dualquat::from_translation(pivot)
+                * $dualquat::from_quat($quat::from_rotation_z(deg(120.0)))
+                * $dualquat::from_translation(-pivot);
+            let half = $dualquat::IDENTITY.sclerp(about_pivot, 0.5);
+            assert_approx_eq!(
+                $vec3::new(0.5, -(0.75 as $t).sqrt(), 0.0),
+                half.transform_point3($vec3::ZERO),
+                1e-5
+            );
+
+            // pure translation
+            let t = $dualquat::from_translation($vec3::new(2.0, -4.0, 6.0));
+            let half = $dualquat::IDENTITY.sclerp(t, 0.5);
+            assert_approx_eq!($quat::IDENTITY, half.rotation());
+            assert_approx_eq!($vec3::new(1.0, -2.0, 3.0), half.translation(), 1e-6);
+
+            should_glam_assert!({ ($dualquat::IDENTITY * 2.0).sclerp($dualquat::IDENTITY, 0.5) });
+        });
+
+        glam_test!(test_ops, {
+            let a = $dualquat::from_array([1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+            let b = $dualquat::from_array([8.0, 7.0, 6.0, 5.0, 4.0, 3.0, 2.0, 1.0]);
+            assert_eq!($dualquat::from_array([9.0; 8]), a + b);
+            assert_eq!(
+                $dualquat::from_array([-7.0, -5.0, -3.0, -1.0, 1.0, 3.0, 5.0, 7.0]),
+                a - b
+            );
+            assert_eq!(
+                $dualquat::from_array([2.0, 4.0, 6.0, 8.0, 10.0, 12.0, 14.0, 16.0]),
+                a * 2.0
+            );
+            assert_eq!(
+                $dualquat::from_array([-1.0, -2.0, -3.0, -4.0, -5.0, -6.0, -7.0, -8.0]),
+                -a
+            );
+            let arr: [$t; 8] = a.into();
+            assert_eq!(a, $dualquat::from(arr));
+        });
+
+        glam_test!(test_fmt, {
+            let a = $dualquat::IDENTITY;
+            assert_eq!(
+                format!("{:?}", a),
+                format!(
+                    "{} {{ real: {}(0.0, 0.0, 0.0, 1.0), dual: {}(0.0, 0.0, 0.0, 0.0) }}",
+                    stringify!($dualquat),
+                    stringify!($quat),
+                    stringify!($quat)
+                )
+            );
+            assert_eq!(format!("{}", a), "[[0, 0, 0, 1], [0, 0, 0, 0]]");
+            assert_eq!(
+                format!("{:.1}", a),
+                "[[0.0, 0.0, 0.0, 1.0], [0.0, 0.0, 0.0, 0.0]]"
+            );
+        });
+    };
+}
+
+mod dualquat {
+    use crate::support::deg;
+    use glam::{dualquat, Affine3A, DualQuat, Mat4, Quat, Vec3};
+
+    glam_test!(test_align, {
+        use std::mem;
+        assert_eq!(32, mem::size_of::<DualQuat>());
+        assert_eq!(mem::align_of::<Quat>(), mem::align_of::<DualQuat>());
+    });
+
+    glam_test!(test_as, {
+        use glam::{DDualQuat, DQuat, DVec3};
+        assert_approx_eq!(
+            DDualQuat::from_rotation_translation(
+                DQuat::from_rotation_y(1.0),
+                DVec3::new(1.0, 2.0, 3.0)
+            ),
+            DualQuat::from_rotation_translation(
+                Quat::from_rotation_y(1.0),
+                Vec3::new(1.0, 2.0, 3.0)
+            )
+            .as_ddualquat(),
+            1e-6
+        );
+        assert_approx_eq!(
+            DualQuat::from_rotation_translation(
+                Quat::from_rotation_y(1.0),
+                Vec3::new(1.0, 2.0, 3.0)
+            ),
+            DDualQuat::from_rotation_translation(
+                DQuat::from_rotation_y(1.0),
+                DVec3::new(1.0, 2.0, 3.0)
+            )
+            .as_dualquat(),
+            1e-6
+        );
+    });
+
+    glam_test!(test_from_affine3, {
+        use glam::Affine3;
+        let rotation = Quat::from_rotation_x(deg(30.0));
+        let translation = Vec3::new(1.0, -2.0, 3.0);
+        assert_approx_eq!(
+            DualQuat::from_rotation_translation(rotation, translation),
+            DualQuat::from_affine3(&Affine3::from_rotation_translation(rotation, translation)),
+            1e-6
+        );
+    });
+
+    impl_dualquat_tests!(
+        f32,
+        dualquat,
+        DualQuat,
+        Quat,
+        Vec3,
+        Mat4,
+        Affine3A,
+        from_affine3a,
+        to_affine3a
+    );
+}
+
+mod ddualquat {
+    use crate::support::deg;
+    use glam::{ddualquat, DAffine3, DDualQuat, DMat4, DQuat, DVec3};
+
+    glam_test!(test_align, {
+        use std::mem;
+        assert_eq!(64, mem::size_of::<DDualQuat>());
+        assert_eq!(mem::align_of::<DQuat>(), mem::align_of::<DDualQuat>());
+    });
+
+    impl_dualquat_tests!(
+        f64,
+        ddualquat,
+        DDualQuat,
+        DQuat,
+        DVec3,
+        DMat4,
+        DAffine3,
+        from_affine3,
+        to_affine3
+    );
+}
@@ -0,0 +1,255 @@
+// The f64 types use SIMD registers inside some methods on SSE2 and NEON targets. These tests
+// compare those methods against a plain per component evaluation of the same expressions, which
+// is what the scalar backend does, so they must agree exactly.
+#[macro_use]
+mod support;
+
+use glam::{DMat4, DQuat, DVec2, DVec3, DVec4};
+use support::Rng;
+
+const ITERATIONS: usize = 1000;
+
+fn assert_same(expected: f64, actual: f64) {
+    assert!(
+        expected.to_bits() == actual.to_bits() || (expected.is_nan() && actual.is_nan()),
+        "expected {expected:?}, got {actual:?}"
+    );
+}
+
+fn assert_same_array<const N: usize>(expected: [f64; N], actual: [f64; N]) {
+    for (e, a) in expected.iter().zip(actual.iter()) {
+        assert_same(*e, *a);
+    }
+}
+
+fn zip<const N: usize>(a: [f64; N], b: [f64; N], f: impl Fn(f64, f64) -> f64) -> [f64; N] {
+    core::array::from_fn(|i| f(a[i], b[i]))
+}
+
+macro_rules! impl_vec_simd_tests {
+    ($vec:ident, $n:expr) => {
+        glam_test!(test_binary_ops, {
+            let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+            for _ in 0..ITERATIONS {
+                let a: [f64; $n] = rng.array(Rng::next_f64);
+                let b: [f64; $n] = rng.array(Rng::next_f64);
+                let s = rng.next_f64();
+                let (va, vb) = ($vec::from_array(a), $vec::from_array(b));
+
+                assert_same_array(zip(a, b, |x, y| x + y), (va + vb).to_array());
+                assert_same_array(zip(a, b, |x, y| x - y), (va - vb).to_array());
+                assert_same_array(zip(a, b, |x, y| x * y), (va * vb).to_array());
+                assert_same_array(zip(a, b, |x, y| x / y), (va / vb).to_array());
+
+                assert_same_array(a.map(|x| x + s), (va + s).to_array());
+                assert_same_array(a.map(|x| x - s), (va - s).to_array());
+                assert_same_array(a.map(|x| x * s), (va * s).to_array());
+                assert_same_array(a.map(|x| x / s), (va / s).to_array());
+
+                assert_same_array(a.map(|x| s + x), (s + va).to_array());
+                assert_same_array(a.map(|x| s - x), (s - va).to_array());
+                assert_same_array(a.map(|x| s * x), (s * va).to_array());
+                assert_same_array(a.map(|x| s / x), (s / va).to_array());
+
+                let mut v = va;
+                v += vb;
+                v -= s;
+                v *= vb;
+                v /= s;
+                let expected = zip(a, b, |x, y| ((x + y) - s) * y / s);
+                assert_same_array(expected, v.to_array());
+            }
+        });
+
+        glam_test!(test_unary_ops, {
+            let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+            for _ in 0..ITERATIONS {
+                let a: [f64; $n] = rng.array(Rng::next_f64);
+                let va = $vec::from_array(a);
+                assert_same_array(a.map(|x| -x), (-va).to_array());
+                assert_same_array(a.map(f64::abs), va.abs().to_array());
+            }
+        });
+
+        glam_test!(test_dot, {
+            let mut rng = Rng(0xd1b5_4a32_d192_ed03);
+            for _ in 0..ITERATIONS {
+                let a: [f64; $n] = rng.array(Rng::next_f64);
+                let b: [f64; $n] = rng.array(Rng::next_f64);
+                // the products are added in the same order as the scalar implementation
+                let dot = |a: [f64; $n], b: [f64; $n]| {
+                    (1..$n).fold(a[0] * b[0], |sum, i| sum + a[i] * b[i])
+                };
+                let (va, vb) = ($vec::from_array(a), $vec::from_array(b));
+                assert_same(dot(a, b), va.dot(vb));
+                assert_same(dot(a, a).sqrt(), va.length());
+            }
+        });
+    };
+}
+
+mod dvec2 {
+    use super::*;
+    impl_vec_simd_tests!(DVec2, 2);
+}
+
+mod dvec3 {
+    use super::*;
+    impl_vec_simd_tests!(DVec3, 3);
+}
+
+mod dvec4 {
+    use super::*;
+    impl_vec_simd_tests!(DVec4, 4);
+}
+
+mod dquat {
+    use super::*;
+
+    glam_test!(test_mul_quat, {
+        let mut rng = Rng(0x8cb9_2ba7_2f3d_8dd7);
+        for _ in 0..ITERATIONS {
+            let [x0, y0, z0, w0] = rng.array(Rng::next_f64);
+            let [x1, y1, z1, w1] = rng.array(Rng::next_f64);
+            let expected = [
+                w0 * x1 + x0 * w1 + y0 * z1 - z0 * y1,
+                w0 * y1 - x0 * z1 + y0 * w1 + z0 * x1,
+                w0 * z1 + x0 * y1 - y0 * x1 + z0 * w1,
+                w0 * w1 - x0 * x1 - y0 * y1 - z0 * z1,
+            ];
+            let a = DQuat::from_xyzw(x0, y0, z0, w0);
+            let b = DQuat::from_xyzw(x1, y1, z1, w1);
+            assert_same_array(expected, a.mul_quat(b).to_array());
+        }
+    });
+
+    glam_test!(test_mul_quat_normalized, {
+        let mut rng = Rng(0x6a09_e667_f3bc_c908);
+        let mut count = 0;
+        while count < ITERATIONS {
+            let q: [f64; 4] = rng.array(Rng::next_f64);
+            let r: [f64; 4] = rng.array(Rng::next_f64);
+            if !q
+                .iter()
+                .chain(r.iter())
+                .all(|x| x.is_finite() && x.abs() > 1e-3)
+            {
+                continue;
+            }
+            count += 1;
+            let a = DQuat::from_array(q).normalize();
+            let b = DQuat::from_array(r).normalize();
+            let (x0, y0, z0, w0) = a.into();
+            let (x1, y1, z1, w1) = b.into();
+            let expected = [
+                w0 * x1 + x0 * w1 + y0 * z1 - z0 * y1,
+                w0 * y1 - x0 * z1 + y0 * w1 + z0 * x1,
+                w0 * z1 + x0 * y1 - y0 * x1 + z0 * w1,
+                w0 * w1 - x0 * x1 - y0 * y1 - z0 * z1,
+            ];
+            assert_same_array(expected, (a * b).to_array());
+        }
+    });
+}
+
+mod dmat4 {
+    use super::*;
+
+    // the AVX path fuses the multiply-adds when `fast-math` and FMA are enabled
+    fn mul_add(a: f64, b: f64, c: f64) -> f64 {
+        if cfg!(all(
+            feature = "fast-math",
+            not(feature = "deterministic"),
+            target_feature = "fma",
+            not(any(feature = "core-simd", feature = "scalar-math"))
+        )) {
+            a.mul_add(b, c)
+        } else {
+            a * b + c
+        }
+    }
+
+    fn mul_vec4_with(
+        m: &[f64; 16],
+        v: [f64; 4],
+        mul_add: impl Fn(f64, f64, f64) -> f64,
+    ) -> [f64; 4] {
+        core::array::from_fn(|i| {
+            let mut res = m[i] * v[0];
+            res = mul_add(m[4 + i], v[1], res);
+            res = mul_add(m[8 + i], v[2], res);
+            res = mul_add(m[12 + i], v[3], res);
+            res
+        })
+    }
+
+    fn mul_vec4(m: &[f64; 16], v: [f64; 4]) -> [f64; 4] {
+        mul_vec4_with(m, v, mul_add)
+    }
+
+    glam_test!(test_mul_vec4, {
+        let mut rng = Rng(0xbb67_ae85_84ca_a73b);
+        for _ in 0..ITERATIONS {
+            let m: [f64; 16] = rng.array(Rng::next_f64);
+            let v: [f64; 4] = rng.array(Rng::next_f64);
+            let actual = DMat4::from_cols_array(&m) * DVec4::from_array(v);
+            assert_same_array(mul_vec4(&m, v), actual.to_array());
+        }
+    });
+
+    glam_test!(test_mul_mat4, {
+        let mut rng = Rng(0x3c6e_f372_fe94_f82b);
+        for _ in 0..ITERATIONS {
+            let a: [f64; 16] = rng.array(Rng::next_f64);
+            let b: [f64; 16] = rng.array(Rng::next_f64);
+            let mut expected = [0.0; 16];
+            for col in 0..4 {
+                let v = mul_vec4(
+                    &a,
+                    [b[col * 4], b[col * 4 + 1], b[col * 4 + 2], b[col * 4 + 3]],
+                );
+                expected[col * 4..col * 4 + 4].copy_from_slice(&v);
+            }
+            let actual = DMat4::from_cols_array(&a) * DMat4::from_cols_array(&b);
+            assert_same_array(expected, actual.to_cols_array());
+        }
+    });
+
+    glam_test!(test_transpose, {
+        let mut rng = Rng(0xa54f_f53a_5f1d_36f1);
+        for _ in 0..ITERATIONS {
+            let m: [f64; 16] = rng.array(Rng::next_f64);
+            let expected: [f64; 16] = core::array::from_fn(|i| m[(i % 4) * 4 + i / 4]);
+            let actual = DMat4::from_cols_array(&m).transpose();
+            assert_same_array(expected, actual.to_cols_array());
+        }
+    });
+
+    glam_test!(test_transform_point3, {
+        let mut rng = Rng(0x510e_527f_ade6_82d1);
+        for _ in 0..ITERATIONS {
+            let [a, b, c, sx, sy, sz, tx, ty, tz, px, py, pz] = rng.array(Rng::next_f64);
+            if ![a, b, c, sx, sy, sz, tx, ty, tz, px, py, pz]
+                .iter()
+                .all(|x| x.is_finite())
+            {
+                continue;
+            }
+            let rotation = DQuat::from_euler(
+                glam::EulerRot::YXZ,
+                a.clamp(-4.0, 4.0),
+                b.clamp(-4.0, 4.0),
+                c.clamp(-4.0, 4.0),
+            );
+            let m = DMat4::from_scale_rotation_translation(
+                DVec3::new(sx, sy, sz),
+                rotation,
+                DVec3::new(tx, ty, tz),
+            );
+            // transform_point3 is never fused
+            let p = mul_vec4_with(&m.to_cols_array(), [px, py, pz, 1.0], |a, b, c| a * b + c);
+            let actual = m.transform_point3(DVec3::new(px, py, pz));
+            assert_same_array([p[0], p[1], p[2]], actual.to_array());
+        }
+    });
+}
@@ -0,0 +1,197 @@
+#[macro_use]
+mod support;
+
+use glam::{hvec2, hvec3, hvec4, HVec2, HVec3, HVec4, U16Vec3, Vec2, Vec3, Vec3A, Vec4, F16};
+
+fn h(v: f32) -> F16 {
+    F16::from_f32(v)
+}
+
+glam_test!(test_f16_consts, {
+    assert_eq!(0.0, F16::ZERO.to_f32());
+    assert_eq!(1.0, F16::ONE.to_f32());
+    assert_eq!(-1.0, F16::NEG_ONE.to_f32());
+    assert_eq!(65504.0, F16::MAX.to_f32());
+    assert_eq!(-65504.0, F16::MIN.to_f32());
+    assert_eq!(2.0f32.powi(-14), F16::MIN_POSITIVE.to_f32());
+    assert_eq!(2.0f32.powi(-10), F16::EPSILON.to_f32());
+    assert_eq!(f32::INFINITY, F16::INFINITY.to_f32());
+    assert_eq!(f32::NEG_INFINITY, F16::NEG_INFINITY.to_f32());
+    assert!(F16::NAN.to_f32().is_nan());
+    assert!(F16::NEG_ZERO.is_sign_negative());
+    assert_eq!(F16::ZERO, F16::NEG_ZERO);
+    assert_ne!(F16::NAN, F16::NAN);
+});
+
+glam_test!(test_f16_round_trip, {
+    for bits in 0..=u16::MAX {
+        let v = F16::from_bits(bits);
+        let f = v.to_f32();
+        assert_eq!(v.is_nan(), f.is_nan());
+        assert_eq!(v.is_finite(), f.is_finite());
+        assert_eq!(v.is_sign_negative(), f.is_sign_negative());
+        if v.is_nan() {
+            // NaNs keep their sign and payload and become quiet.
+            assert_eq!(bits | 0x0200, F16::from_f32(f).to_bits());
+        } else {
+            assert_eq!(bits, F16::from_f32(f).to_bits());
+            assert_eq!(f as f64, v.to_f64());
+        }
+    }
+});
+
+glam_test!(test_f16_round_to_nearest_even, {
+    // Walks every pair of adjacent positive finite values and checks that the midpoint between
+    // them rounds to the even one and that values either side of it round to the nearest.
+    for bits in 0..F16::MAX.to_bits() {
+        let lo = F16::from_bits(bits).to_f32();
+        let hi = F16::from_bits(bits + 1).to_f32();
+        let mid = (lo + hi) * 0.5;
+        let below = f32::from_bits(mid.to_bits() - 1);
+        let above = f32::from_bits(mid.to_bits() + 1);
+        let even = if bits % 2 == 0 { bits } else { bits + 1 };
+        assert_eq!(even, F16::from_f32(mid).to_bits());
+        assert_eq!(bits, F16::from_f32(below).to_bits());
+        assert_eq!(bits + 1, F16::from_f32(above).to_bits());
+        assert_eq!(even | 0x8000, F16::from_f32(-mid).to_bits());
+    }
+
+    // Values past the largest finite value round to infinity from the midpoint onwards.
+    assert_eq!(F16::MAX, h(65519.0));
+    assert_eq!(F16::INFINITY.to_bits(), h(65520.0).to_bits());
+    assert_eq!(F16::NEG_INFINITY.to_bits(), h(-1.0e10).to_bits());
+    assert_eq!(F16::INFINITY.to_bits(), h(f32::MAX).to_bits());
+
+    // Values below half the smallest subnormal flush to a signed zero.
+    assert_eq!(0x0000, h(2.0f32.powi(-25)).to_bits());
+    assert_eq!(0x0001, h(2.0f32.powi(-25) * 1.5).to_bits());
+    assert_eq!(0x8000, h(-2.0f32.powi(-30)).to_bits());
+    assert_eq!(0x0000, h(f32::MIN_POSITIVE).to_bits());
+});
+
+glam_test!(test_f16_ops, {
+    assert_eq!(h(3.0), h(1.0) + h(2.0));
+    assert_eq!(h(-1.0), h(1.0) - h(2.0));
+    assert_eq!(h(6.0), h(2.0) * h(3.0));
+    assert_eq!(h(1.0 / 3.0), h(1.0) / h(3.0));
+    assert_eq!(h(1.0), h(7.0) % h(3.0));
+    assert_eq!(h(-2.5), -h(2.5));
+    assert_eq!(F16::MAX, -F16::MIN);
+    assert_eq!(
+        F16::INFINITY.to_bits(),
+        (F16::MAX + F16::ONE * h(32.0)).to_bits()
+    );
+    assert_eq!(h(2.5), h(-2.5).abs());
+    assert!(h(1.0) < h(2.0));
+    assert!(F16::NEG_INFINITY < F16::MIN);
+
+    let mut a = h(1.0);
+    a += h(2.0);
+    a *= h(4.0);
+    a -= h(2.0);
+    a /= h(5.0);
+    assert_eq!(h(2.0), a);
+
+    assert_eq!(1.5f32, f32::from(h(1.5)));
+    assert_eq!(1.5f64, f64::from(h(1.5)));
+    assert_eq!("1.5", format!("{}", h(1.5)));
+    assert_eq!("1.50", format!("{:.2}", h(1.5)));
+    assert_eq!("1.5", format!("{:?}", h(1.5)));
+});
+
+glam_test!(test_hvec_new, {
+    let v = hvec3(h(1.0), h(2.0), h(3.0));
+    assert_eq!(v, HVec3::new(h(1.0), h(2.0), h(3.0)));
+    assert_eq!(v, HVec3::from_array([h(1.0), h(2.0), h(3.0)]));
+    assert_eq!(v, HVec3::from([h(1.0), h(2.0), h(3.0)]));
+    assert_eq!(v, HVec3::from_slice(&[h(1.0), h(2.0), h(3.0), h(4.0)]));
+    assert_eq!([h(1.0), h(2.0), h(3.0)], v.to_array());
+    assert_eq!([h(1.0), h(2.0), h(3.0)], <[F16; 3]>::from(v));
+    assert_eq!(HVec3::ONE, HVec3::splat(F16::ONE));
+    assert_eq!(HVec3::ZERO, HVec3::default());
+    assert_eq!(
+        [HVec3::X, HVec3::Y, HVec3::Z],
+        [Vec3::X, Vec3::Y, Vec3::Z].map(Vec3::as_hvec3)
+    );
+    assert_eq!(-HVec3::Y, HVec3::NEG_Y);
+    assert_eq!(HVec3::NEG_ONE, -HVec3::ONE);
+    assert!(HVec3::NAN.is_nan());
+    assert!(!HVec3::NAN.is_finite());
+    assert!(v.is_finite());
+
+    let mut a = [F16::ZERO; 4];
+    v.write_to_slice(&mut a);
+    assert_eq!([h(1.0), h(2.0), h(3.0), F16::ZERO], a);
+    should_panic!({ HVec3::from_slice(&[h(1.0), h(2.0)]) });
+
+    let mut v = v;
+    assert_eq!(h(2.0), v[1]);
+    v[2] = h(5.0);
+    assert_eq!(h(5.0), v.z);
+    should_panic!({ HVec3::ZERO[3] });
+
+    assert_eq!(U16Vec3::new(0x3c00, 0x4000, 0x4500), v.to_bits());
+    assert_eq!(v, HVec3::from_bits(v.to_bits()));
+
+    assert_eq!("[1, 2, 5]", format!("{}", v));
+    assert_eq!("[1.0, 2.0, 5.0]", format!("{:.1}", v));
+    assert_eq!("HVec3(1.0, 2.0, 5.0)", format!("{:?}", v));
+
+    assert_eq!(HVec2::AXES, [HVec2::X, HVec2::Y]);
+    assert_eq!(HVec4::AXES, [HVec4::X, HVec4::Y, HVec4::Z, HVec4::W]);
+    assert_eq!(hvec2(h(1.0), h(2.0)), HVec2::new(h(1.0), h(2.0)));
+    assert_eq!(
+        hvec4(h(1.0), h(2.0), h(3.0), h(4.0)),
+        Vec4::new(1.0, 2.0, 3.0, 4.0).as_hvec4()
+    );
+});
+
+glam_test!(test_hvec_conversions, {
+    let v = Vec3::new(1.0, -0.1, 70000.0).as_hvec3();
+    assert_eq!(h(1.0), v.x);
+    assert_eq!(h(-0.1), v.y);
+    assert_eq!(F16::INFINITY.to_bits(), v.z.to_bits());
+    assert_eq!(0xae66, v.y.to_bits());
+    assert_eq!(Vec3::new(1.0, v.y.to_f32(), f32::INFINITY), v.as_vec3());
+    assert_eq!(v.as_vec3(), Vec3::from(v));
+    assert_eq!(Vec3A::from(v.as_vec3()), v.as_vec3a());
+    assert_eq!(v, Vec3A::new(1.0, -0.1, 70000.0).as_hvec3());
+
+    let v = Vec2::new(0.333, 1024.5).as_hvec2();
+    assert_eq!(0x3554, v.x.to_bits());
+    assert_eq!(Vec2::new(v.x.to_f32(), 1024.0), v.as_vec2());
+    assert_eq!(v.as_vec2(), Vec2::from(v));
+
+    let v = Vec4::new(0.5, 2049.0, 2051.0, -1.0e-8).as_hvec4();
+    assert_eq!(Vec4::new(0.5, 2048.0, 2052.0, -0.0), v.as_vec4());
+    assert_eq!(v.as_vec4(), Vec4::from(v));
+});
+
+glam_test!(test_hvec_ops, {
+    let a = Vec3::new(1.5, -2.25, 100.0);
+    let b = Vec3::new(0.1, 3.0, -7.0);
+    let (ha, hb) = (a.as_hvec3(), b.as_hvec3());
+    let (fa, fb) = (ha.as_vec3(), hb.as_vec3());
+    assert_eq!((fa + fb).as_hvec3(), ha + hb);
+    assert_eq!((fa - fb).as_hvec3(), ha - hb);
+    assert_eq!((fa * fb).as_hvec3(), ha * hb);
+    assert_eq!((fa / fb).as_hvec3(), ha / hb);
+    assert_eq!((fa % fb).as_hvec3(), ha % hb);
+    assert_eq!((fa * 3.0).as_hvec3(), ha * h(3.0));
+    assert_eq!((3.0 / fa).as_hvec3(), h(3.0) / ha);
+    assert_eq!((-fa).as_hvec3(), -ha);
+
+    let mut c = ha;
+    c += hb;
+    c *= h(2.0);
+    c -= ha;
+    c /= hb;
+    assert_eq!((((fa + fb) * 2.0 - fa) / fb).as_hvec3(), c);
+
+    // Results are rounded back to half precision after each operation.
+    assert_eq!(
+        HVec2::splat(h(2048.0)),
+        HVec2::splat(h(2048.0)) + HVec2::ONE
+    );
+    assert_eq!(HVec4::splat(F16::MAX) * h(2.0), HVec4::splat(F16::INFINITY));
+});
@@ -0,0 +1,488 @@
+#[macro_use]
+mod support;
+
+macro_rules! impl_imat_tests {
+    (
+        $t:ident,
+        $mat:ident,
+        $vec:ident,
+        $fmat:ident,
+        $dmat:ident,
+        $n:literal,
+        $mul_vec:ident,
+        $mul_mat:ident,
+        $checked_mul_vec:ident,
+        $wrapping_mul_vec:ident,
+        $checked_mul_mat:ident,
+        $wrapping_mul_mat:ident,
+        $checked_add:ident,
+        $wrapping_add:ident,
+        $checked_sub:ident,
+        $wrapping_sub:ident,
+        $as_mat:ident,
+        $as_dmat:ident,
+        $as_dvec:ident
+    ) => {
+        fn matrix1d() -> [$t; $n * $n] {
+            core::array::from_fn(|i| (i + 1) as $t)
+        }
+
+        fn matrix2d() -> [[$t; $n]; $n] {
+            core::array::from_fn(|c| core::array::from_fn(|r| (c * $n + r + 1) as $t))
+        }
+
+        glam_test!(test_consts, {
+            assert_eq!([0; $n * $n], $mat::ZERO.to_cols_array());
+            assert_eq!($mat::IDENTITY, $mat::default());
+            assert_eq!($mat::IDENTITY, $mat::from_diagonal($vec::ONE));
+            assert_eq!(1, $mat::IDENTITY.determinant());
+            assert_eq!(0, $mat::ZERO.determinant());
+        });
+
+        glam_test!(test_from_cols, {
+            let m = $mat::from_cols_array(&matrix1d());
+            assert_eq!(matrix1d(), m.to_cols_array());
+            assert_eq!(matrix2d(), m.to_cols_array_2d());
+            assert_eq!(m, $mat::from_cols_array_2d(&matrix2d()));
+            assert_eq!(m, $mat::from_cols_slice(&matrix1d()));
+            assert_eq!(&matrix1d(), m.as_ref());
+            for (i, col) in matrix2d().iter().enumerate() {
+                assert_eq!($vec::from_array(*col), m.col(i));
+            }
+            for r in 0..$n {
+                for c in 0..$n {
+                    assert_eq!(matrix2d()[c][r], m.row(r).to_array()[c]);
+                }
+            }
+
+            let mut a = [0; $n * $n + 1];
+            m.write_cols_to_slice(&mut a);
+            assert_eq!(matrix1d(), a[..$n * $n]);
+
+            let mut m = m;
+            m.col_mut(0).x = 100;
+            m.as_mut()[1] = 200;
+            assert_eq!(100, m.col(0).x);
+            assert_eq!(200, m.col(0).y);
+
+            should_panic!({ $mat::IDENTITY.col($n) });
+            should_panic!({ $mat::IDENTITY.row($n) });
+            should_panic!({
+                let mut m = $mat::IDENTITY;
+                m.col_mut($n);
+            });
+            should_panic!({ $mat::from_cols_slice(&[0; $n * $n - 1]) });
+        });
+
+        glam_test!(test_transpose, {
+            let m = $mat::from_cols_array(&matrix1d());
+            let t = m.transpose();
+            for i in 0..$n {
+                assert_eq!(m.row(i), t.col(i));
+                assert_eq!(m.col(i), t.row(i));
+            }
+            assert_eq!(m, t.transpose());
+            assert_eq!(m.diagonal(), t.diagonal());
+        });
+
+        glam_test!(test_determinant, {
+            let m = $mat::from_cols_array(&matrix1d());
+            assert_eq!(m.$as_dmat().determinant() as $t, m.determinant());
+            assert_eq!(m.determinant(), m.transpose().determinant());
+
+            let d = $vec::from_array(core::array::from_fn(|i| (i + 2) as $t));
+            let expected: $t = d.to_array().iter().product();
+            assert_eq!(expected, $mat::from_diagonal(d).determinant());
+            assert_eq!(
+                -1,
+                $mat::from_diagonal(-$vec::X + $vec::ONE - $vec::X).determinant()
+            );
+        });
+
+        glam_test!(test_mul, {
+            let m = $mat::from_cols_array(&matrix1d());
+            let n = m.transpose() - $mat::IDENTITY;
+            let v = $vec::from_array(core::array::from_fn(|i| i as $t - 1));
+
+            let expected = m.$as_dmat() * n.$as_dmat();
+            assert_eq!(expected, (m * n).$as_dmat());
+            assert_eq!(m * n, m.$mul_mat(&n));
+            assert_eq!(Some(m * n), m.$checked_mul_mat(&n));
+            assert_eq!(m * n, m.$wrapping_mul_mat(&n));
+
+            let expected = m.$as_dmat() * v.$as_dvec();
+            assert_eq!(expected, (m * v).$as_dvec());
+            assert_eq!(m * v, m.$mul_vec(v));
+            assert_eq!(Some(m * v), m.$checked_mul_vec(v));
+            assert_eq!(m * v, m.$wrapping_mul_vec(v));
+
+            assert_eq!(m, m * $mat::IDENTITY);
+            assert_eq!(m, $mat::IDENTITY * m);
+            assert_eq!(v, $mat::IDENTITY * v);
+
+            let mut o = m;
+            o *= n;
+            assert_eq!(m * n, o);
+
+            let (rm, rn, rv) = (&m, &n, &v);
+            assert_eq!(m * n, rm * rn);
+            assert_eq!(m * v, rm * rv);
+        });
+
+        glam_test!(test_overflow, {
+            let big = $mat::from_diagonal($vec::splat($t::MAX));
+            let two = $mat::from_diagonal($vec::splat(2));
+            assert_eq!(None, big.$checked_mul_mat(&two));
+            assert_eq!(None, big.$checked_mul_vec($vec::splat(2)));
+            assert_eq!(None, big.$checked_add(&$mat::IDENTITY));
+            assert_eq!(None, (-big - $mat::IDENTITY).$checked_sub(&$mat::IDENTITY));
+
+            let wrapped = $mat::from_diagonal($vec::splat($t::MAX.wrapping_mul(2)));
+            assert_eq!(wrapped, big.$wrapping_mul_mat(&two));
+            assert_eq!(
+                $vec::splat($t::MAX.wrapping_mul(2)),
+                big.$wrapping_mul_vec($vec::splat(2))
+            );
+            assert_eq!(
+                $mat::from_diagonal($vec::splat($t::MIN)),
+                big.$wrapping_add(&$mat::IDENTITY)
+            );
+            assert_eq!(
+                $mat::from_diagonal($vec::splat($t::MAX)),
+                $mat::from_diagonal($vec::splat($t::MIN)).$wrapping_sub(&$mat::IDENTITY)
+            );
+
+            let m = $mat::from_cols_array(&matrix1d());
+            assert_eq!(Some(m + m), m.$checked_add(&m));
+            assert_eq!(Some($mat::ZERO), m.$checked_sub(&m));
+        });
+
+        glam_test!(test_ops, {
+            let m = $mat::from_cols_array(&matrix1d());
+            let m2 = $mat::from_cols_array(&matrix1d().map(|x| x * 2));
+            assert_eq!(m2, m + m);
+            assert_eq!(m2, m * 2);
+            assert_eq!(m2, 2 * m);
+            assert_eq!(m, m2 - m);
+            assert_eq!($mat::ZERO, m + -m);
+            assert_eq!(m, m.abs());
+            assert_eq!(m, (-m).abs());
+
+            let mut n = m;
+            n += m;
+            assert_eq!(m2, n);
+            n -= m;
+            assert_eq!(m, n);
+            n *= 2;
+            assert_eq!(m2, n);
+
+            assert_eq!(m2, [m, m].iter().sum());
+            assert_eq!(m2, [m, m].into_iter().sum());
+            assert_eq!(m * m2, [m, m2].iter().product());
+            assert_eq!(m * m2, [m, m2].into_iter().product());
+        });
+
+        glam_test!(test_as, {
+            let m = $mat::from_cols_array(&matrix1d());
+            assert_eq!(
+                $fmat::from_cols_array(&matrix1d().map(|x| x as f32)),
+                m.$as_mat()
+            );
+            assert_eq!(
+                $dmat::from_cols_array(&matrix1d().map(|x| x as f64)),
+                m.$as_dmat()
+            );
+        });
+
+        glam_test!(test_hash, {
+            use std::collections::hash_map::DefaultHasher;
+            use std::hash::{Hash, Hasher};
+            fn hash(m: &$mat) -> u64 {
+                let mut hasher = DefaultHasher::new();
+                m.hash(&mut hasher);
+                hasher.finish()
+            }
+            let m = $mat::from_cols_array(&matrix1d());
+            assert_eq!(hash(&m), hash(&$mat::from_cols_array_2d(&matrix2d())));
+            assert_ne!(hash(&m), hash(&m.transpose()));
+        });
+    };
+}
+
+mod imat2 {
+    use glam::{imat2, ivec2, DMat2, IMat2, IVec2, Mat2};
+
+    impl_imat_tests!(
+        i32,
+        IMat2,
+        IVec2,
+        Mat2,
+        DMat2,
+        2,
+        mul_ivec2,
+        mul_imat2,
+        checked_mul_ivec2,
+        wrapping_mul_ivec2,
+        checked_mul_imat2,
+        wrapping_mul_imat2,
+        checked_add_imat2,
+        wrapping_add_imat2,
+        checked_sub_imat2,
+        wrapping_sub_imat2,
+        as_mat2,
+        as_dmat2,
+        as_dvec2
+    );
+
+    glam_test!(test_quarter_turns, {
+        for turns in -8..8 {
+            let m = IMat2::from_quarter_turns(turns);
+            let expected = Mat2::from_angle(turns as f32 * core::f32::consts::FRAC_PI_2);
+            assert!(m.as_mat2().abs_diff_eq(expected, 1e-6));
+            assert_eq!(1, m.determinant());
+            assert_eq!(m, IMat2::from_quarter_turns(turns + 4));
+        }
+        assert_eq!(ivec2(0, 1), IMat2::from_quarter_turns(1) * IVec2::X);
+        assert_eq!(ivec2(0, -1), IMat2::from_quarter_turns(-1) * IVec2::X);
+    });
+
+    glam_test!(test_fmt, {
+        let m = imat2(ivec2(1, 2), ivec2(3, 4));
+        assert_eq!(format!("{m}"), "[[1, 2], [3, 4]]");
+        assert_eq!(
+            format!("{m:?}"),
+            "IMat2 { x_axis: IVec2(1, 2), y_axis: IVec2(3, 4) }"
+        );
+    });
+}
+
+mod imat3 {
+    use glam::{imat3, ivec2, ivec3, DMat3, IMat2, IMat3, IVec2, IVec3, Mat3};
+
+    impl_imat_tests!(
+        i32,
+        IMat3,
+        IVec3,
+        Mat3,
+        DMat3,
+        3,
+        mul_ivec3,
+        mul_imat3,
+        checked_mul_ivec3,
+        wrapping_mul_ivec3,
+        checked_mul_imat3,
+        wrapping_mul_imat3,
+        checked_add_imat3,
+        wrapping_add_imat3,
+        checked_sub_imat3,
+        wrapping_sub_imat3,
+        as_mat3,
+        as_dmat3,
+        as_dvec3
+    );
+
+    glam_test!(test_quarter_turns, {
+        use core::f32::consts::FRAC_PI_2;
+        for turns in -8..8 {
+            let angle = turns as f32 * FRAC_PI_2;
+            let x = IMat3::from_quarter_turns_x(turns);
+            let y = IMat3::from_quarter_turns_y(turns);
+            let z = IMat3::from_quarter_turns_z(turns);
+            assert!(x.as_mat3().abs_diff_eq(Mat3::from_rotation_x(angle), 1e-6));
+            assert!(y.as_mat3().abs_diff_eq(Mat3::from_rotation_y(angle), 1e-6));
+            assert!(z.as_mat3().abs_diff_eq(Mat3::from_rotation_z(angle), 1e-6));
+            for m in [x, y, z] {
+                assert_eq!(1, m.determinant());
+                assert_eq!(IMat3::IDENTITY, m * m.transpose());
+                assert!(IMat3::CUBE_ROTATIONS.contains(&m));
+            }
+        }
+        assert_eq!(IVec3::Z, IMat3::from_quarter_turns_x(1) * IVec3::Y);
+        assert_eq!(IVec3::X, IMat3::from_quarter_turns_y(1) * IVec3::Z);
+        assert_eq!(IVec3::Y, IMat3::from_quarter_turns_z(1) * IVec3::X);
+    });
+
+    glam_test!(test_cube_rotations, {
+        let rotations = IMat3::CUBE_ROTATIONS;
+        assert_eq!(IMat3::IDENTITY, rotations[0]);
+        for (i, a) in rotations.iter().enumerate() {
+            assert_eq!(1, a.determinant());
+            assert_eq!(IMat3::IDENTITY, *a * a.transpose());
+            assert!(rotations[..i].iter().all(|b| a != b));
+            for b in &rotations {
+                assert!(rotations.contains(&(*a * *b)));
+            }
+        }
+
+        // every rotation is a product of quarter turns around the x and y axes
+        for m in &rotations {
+            let found = (0..4).any(|x| {
+                (0..4).any(|y| {
+                    (0..4).any(|z| {
+                        IMat3::from_quarter_turns_x(x)
+                            * IMat3::from_quarter_turns_y(y)
+                            * IMat3::from_quarter_turns_z(z)
+                            == *m
+                    })
+                })
+            });
+            assert!(found);
+        }
+    });
+
+    glam_test!(test_transform2d, {
+        let m =
+            IMat3::from_translation(ivec2(3, -4)) * IMat3::from_imat2(IMat2::from_quarter_turns(1));
+        assert_eq!(ivec2(3, -3), m.transform_point2(IVec2::X));
+        assert_eq!(ivec2(0, 1), m.transform_vector2(IVec2::X));
+        assert_eq!(m * ivec3(1, 0, 1), m.transform_point2(IVec2::X).extend(1));
+    });
+
+    glam_test!(test_fmt, {
+        let m = imat3(ivec3(1, 2, 3), ivec3(4, 5, 6), ivec3(7, 8, 9));
+        assert_eq!(format!("{m}"), "[[1, 2, 3], [4, 5, 6], [7, 8, 9]]");
+    });
+}
+
+mod imat4 {
+    use glam::{imat4, ivec3, ivec4, DMat4, IMat3, IMat4, IVec3, IVec4, Mat4};
+
+    impl_imat_tests!(
+        i32,
+        IMat4,
+        IVec4,
+        Mat4,
+        DMat4,
+        4,
+        mul_ivec4,
+        mul_imat4,
+        checked_mul_ivec4,
+        wrapping_mul_ivec4,
+        checked_mul_imat4,
+        wrapping_mul_imat4,
+        checked_add_imat4,
+        wrapping_add_imat4,
+        checked_sub_imat4,
+        wrapping_sub_imat4,
+        as_mat4,
+        as_dmat4,
+        as_dvec4
+    );
+
+    glam_test!(test_transform3d, {
+        let r = IMat3::CUBE_ROTATIONS[4];
+        let m = IMat4::from_translation(ivec3(1, 2, 3)) * IMat4::from_imat3(r);
+        assert_eq!(1, m.determinant());
+        assert_eq!(r * IVec3::X + ivec3(1, 2, 3), m.transform_point3(IVec3::X));
+        assert_eq!(r * IVec3::X, m.transform_vector3(IVec3::X));
+        assert_eq!(
+            m * ivec4(1, 0, 0, 1),
+            m.transform_point3(IVec3::X).extend(1)
+        );
+    });
+
+    glam_test!(test_fmt, {
+        let m = imat4(IVec4::X, IVec4::Y, IVec4::Z, IVec4::W);
+        assert_eq!(
+            format!("{m}"),
+            "[[1, 0, 0, 0], [0, 1, 0, 0], [0, 0, 1, 0], [0, 0, 0, 1]]"
+        );
+    });
+}
+
+mod i64mat2 {
+    use glam::{DMat2, I64Mat2, I64Vec2, Mat2};
+
+    impl_imat_tests!(
+        i64,
+        I64Mat2,
+        I64Vec2,
+        Mat2,
+        DMat2,
+        2,
+        mul_i64vec2,
+        mul_i64mat2,
+        checked_mul_i64vec2,
+        wrapping_mul_i64vec2,
+        checked_mul_i64mat2,
+        wrapping_mul_i64mat2,
+        checked_add_i64mat2,
+        wrapping_add_i64mat2,
+        checked_sub_i64mat2,
+        wrapping_sub_i64mat2,
+        as_mat2,
+        as_dmat2,
+        as_dvec2
+    );
+
+    glam_test!(test_quarter_turns, {
+        for turns in -8..8 {
+            let m = I64Mat2::from_quarter_turns(turns);
+            assert_eq!(
+                glam::IMat2::from_quarter_turns(turns).as_dmat2(),
+                m.as_dmat2()
+            );
+        }
+    });
+}
+
+mod i64mat3 {
+    use glam::{DMat3, I64Mat3, I64Vec3, IMat3, Mat3};
+
+    impl_imat_tests!(
+        i64,
+        I64Mat3,
+        I64Vec3,
+        Mat3,
+        DMat3,
+        3,
+        mul_i64vec3,
+        mul_i64mat3,
+        checked_mul_i64vec3,
+        wrapping_mul_i64vec3,
+        checked_mul_i64mat3,
+        wrapping_mul_i64mat3,
+        checked_add_i64mat3,
+        wrapping_add_i64mat3,
+        checked_sub_i64mat3,
+        wrapping_sub_i64mat3,
+        as_mat3,
+        as_dmat3,
+        as_dvec3
+    );
+
+    glam_test!(test_cube_rotations, {
+        for (a, b) in I64Mat3::CUBE_ROTATIONS
+            .iter()
+            .zip(IMat3::CUBE_ROTATIONS.iter())
+        {
+            assert_eq!(b.as_dmat3(), a.as_dmat3());
+        }
+    });
+}
+
+mod i64mat4 {
+    use glam::{DMat4, I64Mat4, I64Vec4, Mat4};
+
+    impl_imat_tests!(
+        i64,
+        I64Mat4,
+        I64Vec4,
+        Mat4,
+        DMat4,
+        4,
+        mul_i64vec4,
+        mul_i64mat4,
+        checked_mul_i64vec4,
+        wrapping_mul_i64vec4,
+        checked_mul_i64mat4,
+        wrapping_mul_i64mat4,
+        checked_add_i64mat4,
+        wrapping_add_i64mat4,
+        checked_sub_i64mat4,
+        wrapping_sub_i64mat4,
+        as_mat4,
+        as_dmat4,
+        as_dvec4
+    );
+}
@@ -0,0 +1,441 @@
+#[macro_use]
+mod support;
+
+mod isometry2 {
+    use super::support::{deg, FloatCompare};
+    use glam::{Affine2, Isometry2, Mat2, Mat3, Vec2};
+
+    impl FloatCompare for Isometry2 {
+        #[inline]
+        fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+            self.abs_diff_eq(*other, max_abs_diff)
+        }
+        #[inline]
+        fn abs_diff(&self, other: &Self) -> Self {
+            Self {
+                rotation: self.rotation.abs_diff(&other.rotation),
+                translation: self.translation.abs_diff(&other.translation),
+            }
+        }
+    }
+
+    glam_test!(test_align, {
+        use std::mem;
+        if cfg!(not(feature = "scalar-math")) {
+            assert_eq!(32, mem::size_of::<Isometry2>());
+            assert_eq!(16, mem::align_of::<Isometry2>());
+        } else if cfg!(not(feature = "cuda")) {
+            assert_eq!(24, mem::size_of::<Isometry2>());
+            assert_eq!(mem::align_of::<Vec2>(), mem::align_of::<Isometry2>());
+        }
+    });
+
+    glam_test!(test_identity, {
+        assert_eq!(
+            Isometry2::IDENTITY,
+            Isometry2::IDENTITY * Isometry2::IDENTITY
+        );
+        assert_eq!(Isometry2::IDENTITY, Isometry2::default());
+        assert_eq!(Mat2::IDENTITY, Isometry2::IDENTITY.rotation);
+        assert_eq!(Vec2::ZERO, Isometry2::IDENTITY.translation);
+    });
+
+    glam_test!(test_nan, {
+        assert!(Isometry2::NAN.is_nan());
+        assert!(!Isometry2::NAN.is_finite());
+        assert!(!Isometry2::IDENTITY.is_nan());
+        assert!(Isometry2::IDENTITY.is_finite());
+    });
+
+    glam_test!(test_array, {
+        const A: [f32; 6] = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        const I: Isometry2 = Isometry2::from_array(A);
+        assert_eq!(A, I.to_array());
+        assert_eq!(A, <[f32; 6]>::from(I));
+        assert_eq!(I, Isometry2::from(A));
+        assert_eq!(Affine2::from_cols_array(&A), Affine2::from(I));
+    });
+
+    glam_test!(test_from_angle_translation, {
+        let t = Vec2::new(1.0, 2.0);
+        let iso = Isometry2::from_angle_translation(deg(30.0), t);
+        assert_eq!(Mat2::from_angle(deg(30.0)), iso.rotation);
+        assert_eq!(t, iso.translation);
+        assert_approx_eq!(deg(30.0), iso.angle());
+        let (angle, translation) = iso.to_angle_translation();
+        assert_approx_eq!(deg(30.0), angle);
+        assert_eq!(t, translation);
+
+        assert_eq!(
+            iso,
+            Isometry2::from_translation(t) * Isometry2::from_angle(deg(30.0))
+        );
+        assert_approx_eq!(deg(-170.0), Isometry2::from_angle(deg(190.0)).angle(), 1e-5);
+    });
+
+    glam_test!(test_transform, {
+        let iso = Isometry2::from_angle_translation(deg(90.0), Vec2::new(1.0, 2.0));
+        let a = Affine2::from_angle_translation(deg(90.0), Vec2::new(1.0, 2.0));
+        let p = Vec2::new(3.0, -4.0);
+        assert_approx_eq!(Vec2::new(5.0, 5.0), iso.transform_point2(p), 1e-6);
+        assert_approx_eq!(Vec2::new(4.0, 3.0), iso.transform_vector2(p), 1e-6);
+        assert_approx_eq!(a.transform_point2(p), iso.transform_point2(p));
+        assert_approx_eq!(a.transform_vector2(p), iso.transform_vector2(p));
+    });
+
+    glam_test!(test_conversions, {
+        let iso = Isometry2::from_angle_translation(deg(-45.0), Vec2::new(3.0, 1.0));
+        let a = Affine2::from_angle_translation(deg(-45.0), Vec2::new(3.0, 1.0));
+        assert_eq!(a, iso.to_affine2());
+        assert_eq!(a, Affine2::from(iso));
+        assert_eq!(iso, Isometry2::from_affine2(&a));
+
+        let m = Mat3::from(a);
+        assert_eq!(m, iso.to_mat3());
+        assert_eq!(m, Mat3::from(iso));
+        assert_eq!(iso, Isometry2::from_mat3(&m));
+
+        should_glam_assert!({ Isometry2::from_affine2(&Affine2::from_scale(Vec2::splat(2.0))) });
+        should_glam_assert!({ Isometry2::from_mat3(&Mat3::from_scale(Vec2::splat(2.0))) });
+    });
+
+    glam_test!(test_mul_inverse, {
+        let iso0 = Isometry2::from_angle_translation(deg(30.0), Vec2::new(1.0, 2.0));
+        let iso1 = Isometry2::from_angle_translation(deg(-75.0), Vec2::new(-3.0, 0.5));
+        let iso = iso0 * iso1;
+        assert_approx_eq!(iso0.to_mat3() * iso1.to_mat3(), iso.to_mat3(), 1e-6);
+        assert_approx_eq!(deg(-45.0), iso.angle(), 1e-6);
+
+        let mut iso2 = iso0;
+        iso2 *= iso1;
+        assert_eq!(iso, iso2);
+        assert_eq!(iso, [iso0, iso1].iter().product());
+
+        let inv = iso0.inverse();
+        assert_approx_eq!(Isometry2::IDENTITY, iso0 * inv, 1e-6);
+        assert_approx_eq!(Isometry2::IDENTITY, inv * iso0, 1e-6);
+        assert!(iso0
+            .to_affine2()
+            .inverse()
+            .abs_diff_eq(inv.to_affine2(), 1e-6));
+    });
+
+    glam_test!(test_lerp_slerp, {
+        let iso0 = Isometry2::from_angle_translation(deg(10.0), Vec2::new(0.0, 2.0));
+        let iso1 = Isometry2::from_angle_translation(deg(70.0), Vec2::new(4.0, -2.0));
+        assert_approx_eq!(iso0, iso0.lerp(iso1, 0.0), 1e-6);
+        assert_approx_eq!(iso1, iso0.lerp(iso1, 1.0), 1e-6);
+        assert_approx_eq!(iso0, iso0.slerp(iso1, 0.0), 1e-6);
+        assert_approx_eq!(iso1, iso0.slerp(iso1, 1.0), 1e-6);
+
+        let mid = Isometry2::from_angle_translation(deg(40.0), Vec2::new(2.0, 0.0));
+        assert_approx_eq!(mid, iso0.lerp(iso1, 0.5), 1e-6);
+        assert_approx_eq!(mid, iso0.slerp(iso1, 0.5), 1e-6);
+
+        let quarter = iso0.slerp(iso1, 0.25);
+        assert_approx_eq!(deg(25.0), quarter.angle(), 1e-6);
+        let quarter = iso0.lerp(iso1, 0.25);
+        assert!(quarter.rotation.x_axis.is_normalized());
+        assert_approx_eq!(quarter.rotation.x_axis.perp(), quarter.rotation.y_axis);
+
+        // takes the shortest path across the discontinuity at ±180 degrees
+        let iso0 = Isometry2::from_angle(deg(170.0));
+        let iso1 = Isometry2::from_angle(deg(-170.0));
+        assert_approx_eq!(deg(180.0), iso0.slerp(iso1, 0.5).angle().abs(), 1e-5);
+    });
+
+    glam_test!(test_fmt, {
+        let a = Isometry2::IDENTITY;
+        assert_eq!(
+            format!("{:?}", a),
+            "Isometry2 { rotation: Mat2 { x_axis: Vec2(1.0, 0.0), y_axis: Vec2(0.0, 1.0) }, translation: Vec2(0.0, 0.0) }"
+        );
+        assert_eq!(format!("{}", a), "[[[1, 0], [0, 1]], [0, 0]]");
+        assert_eq!(
+            format!("{:.1}", a),
+            "[[[1.0, 0.0], [0.0, 1.0]], [0.0, 0.0]]"
+        );
+    });
+}
+
+macro_rules! impl_isometry3_tests {
+    ($t:ident, $isometry:ident, $quat:ident, $vec3:ident, $mat4:ident, $affine3:ident, $from_affine:ident, $to_affine:ident) => {
+        glam_test!(test_identity, {
+            assert_eq!(
+                $isometry::IDENTITY,
+                $isometry::IDENTITY * $isometry::IDENTITY
+            );
+            assert_eq!($isometry::IDENTITY, $isometry::default());
+            assert_eq!($quat::IDENTITY, $isometry::IDENTITY.rotation);
+
+            let p = $vec3::new(1.0, 2.0, 3.0);
+            assert_eq!(p, $isometry::IDENTITY.transform_point3(p));
+            assert_eq!(p, $isometry::IDENTITY.transform_vector3(p));
+            assert_eq!($mat4::IDENTITY, $isometry::IDENTITY.to_mat4());
+        });
+
+        glam_test!(test_nan, {
+            assert!($isometry::NAN.is_nan());
+            assert!(!$isometry::NAN.is_finite());
+            assert!(!$isometry::IDENTITY.is_nan());
+            assert!($isometry::IDENTITY.is_finite());
+        });
+
+        glam_test!(test_array, {
+            const A: [$t; 7] = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0];
+            const I: $isometry = $isometry::from_array(A);
+            assert_eq!($quat::from_xyzw(1.0, 2.0, 3.0, 4.0), I.rotation);
+            assert_eq!(A, I.to_array());
+            assert_eq!(A, <[$t; 7]>::from(I));
+            assert_eq!(I, $isometry::from(A));
+        });
+
+        glam_test!(test_from_rotation_translation, {
+            let rotation = $quat::from_rotation_y(deg(90.0));
+            let translation = $vec3::new(1.0, 2.0, 3.0);
+            let iso = $isometry::from_rotation_translation(rotation, translation);
+            assert_eq!((rotation, translation), iso.to_rotation_translation());
+            assert_eq!(
+                iso,
+                $isometry::from_translation(translation) * $isometry::from_quat(rotation)
+            );
+
+            should_glam_assert!({
+                $isometry::from_rotation_translation($quat::IDENTITY * 2.0, $vec3::ZERO)
+            });
+            should_glam_assert!({ $isometry::from_quat($quat::IDENTITY * 2.0) });
+        });
+
+        glam_test!(test_transform, {
+            let rotation = $quat::from_euler(glam::EulerRot::YXZ, deg(30.0), deg(45.0), deg(60.0));
+            let translation = $vec3::new(-1.0, 2.0, 0.5);
+            let iso = $isometry::from_rotation_translation(rotation, translation);
+            let m = $mat4::from_rotation_translation(rotation, translation);
+            let a = $affine3::from_rotation_translation(rotation, translation);
+
+            let p = $vec3::new(3.0, -4.0, 5.0);
+            assert_approx_eq!(m.transform_point3(p), iso.transform_point3(p), 1e-5);
+            assert_approx_eq!(m.transform_vector3(p), iso.transform_vector3(p), 1e-5);
+            assert_approx_eq!(a.transform_point3(p), iso.transform_point3(p), 1e-5);
+            assert_approx_eq!(a.transform_vector3(p), iso.transform_vector3(p), 1e-5);
+        });
+
+        glam_test!(test_conversions, {
+            let rotation = $quat::from_rotation_z(deg(-120.0));
+            let translation = $vec3::new(4.0, 5.0, 6.0);
+            let iso = $isometry::from_rotation_translation(rotation, translation);
+
+            let m = $mat4::from_rotation_translation(rotation, translation);
+            assert_eq!(m, iso.to_mat4());
+            assert_eq!(m, $mat4::from(iso));
+            let from_mat4 = $isometry::from_mat4(&m);
+            assert_approx_eq!(m, from_mat4.to_mat4(), 1e-6);
+            assert_approx_eq!(iso.rotation.dot(from_mat4.rotation).abs(), 1.0, 1e-6);
+
+            let a = $affine3::from_rotation_translation(rotation, translation);
+            assert_eq!(a, iso.$to_affine());
+            assert_eq!(a, $affine3::from(iso));
+            let from_affine = $isometry::$from_affine(&a);
+            assert!(a.abs_diff_eq(from_affine.$to_affine(), 1e-6));
+            assert_approx_eq!(iso.rotation.dot(from_affine.rotation).abs(), 1.0, 1e-6);
+
+            should_glam_assert!({ $isometry::from_mat4(&$mat4::from_scale($vec3::splat(2.0))) });
+            should_glam_assert!({
+                $isometry::$from_affine(&$affine3::from_scale($vec3::splat(2.0)))
+            });
+        });
+
+        glam_test!(test_mul_inverse, {
+            let iso0 = $isometry::from_rotation_translation(
+                $quat::from_rotation_x(deg(45.0)),
+                $vec3::new(1.0, 0.0, -2.0),
+            );
+            let iso1 = $isometry::from_rotation_translation(
+                $quat::from_rotation_y(deg(-30.0)),
+                $vec3::new(0.0, 3.0, 1.0),
+            );
+            let iso = iso0 * iso1;
+            assert_approx_eq!(iso0.to_mat4() * iso1.to_mat4(), iso.to_mat4(), 1e-5);
+
+            let p = $vec3::new(2.0, -1.0, 0.5);
+            assert_approx_eq!(
+                iso0.transform_point3(iso1.transform_point3(p)),
+                iso.transform_point3(p),
+                1e-5
+            );
+
+            let mut iso2 = iso0;
+            iso2 *= iso1;
+            assert_eq!(iso, iso2);
+            assert_eq!(iso, [iso0, iso1].iter().product());
+
+            let inv = iso0.inverse();
+            assert_approx_eq!($isometry::IDENTITY, iso0 * inv, 1e-6);
+            assert_approx_eq!($isometry::IDENTITY, inv * iso0, 1e-6);
+            assert_approx_eq!(iso0.to_mat4().inverse(), inv.to_mat4(), 1e-5);
+
+            should_glam_assert!({
+                $isometry::from_array([0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0]).inverse()
+            });
+        });
+
+        glam_test!(test_lerp_slerp, {
+            let iso0 = $isometry::from_rotation_translation(
+                $quat::from_rotation_y(deg(20.0)),
+                $vec3::new(0.0, 2.0, 0.0),
+            );
+            let iso1 = $isometry::from_rotation_translation(
+                $quat::from_rotation_y(deg(80.0)),
+                $vec3::new(4.0, -2.0, 6.0),
+            );
+            assert_approx_eq!(iso0, iso0.lerp(iso1, 0.0), 1e-6);
+            assert_approx_eq!(iso1, iso0.lerp(iso1, 1.0), 1e-6);
+            assert_approx_eq!(iso0, iso0.slerp(iso1, 0.0), 1e-6);
+            assert_approx_eq!(iso1, iso0.slerp(iso1, 1.0), 1e-6);
+
+            let mid = $isometry::from_rotation_translation(
+                $quat::from_rotation_y(deg(50.0)),
+                $vec3::new(2.0, 0.0, 3.0),
+            );
+            assert_approx_eq!(mid, iso0.lerp(iso1, 0.5), 1e-6);
+            assert_approx_eq!(mid, iso0.slerp(iso1, 0.5), 1e-6);
+
+            let quarter = iso0.slerp(iso1, 0.25);
+            assert_approx_eq!($quat::from_rotation_y(deg(35.0)), quarter.rotation, 1e-6);
+            assert_approx_eq!(
+                $vec3::new(1.0, 1.0, 1.5),
+                quarter.to_rotation_translation().1
+            );
+        });
+
+        glam_test!(test_fmt, {
+            let a = $isometry::IDENTITY;
+            assert_eq!(format!("{}", a), "[[0, 0, 0, 1], [0, 0, 0]]");
+            assert_eq!(
+                format!("{:.1}", a),
+                "[[0.0, 0.0, 0.0, 1.0], [0.0, 0.0, 0.0]]"
+            );
+        });
+    };
+}
+
+mod isometry3a {
+    use super::support::{deg, FloatCompare};
+    use glam::{Affine3A, Isometry3A, Mat4, Quat, Vec3, Vec3A};
+
+    impl FloatCompare for Isometry3A {
+        #[inline]
+        fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+            self.abs_diff_eq(*other, max_abs_diff)
+        }
+        #[inline]
+        fn abs_diff(&self, other: &Self) -> Self {
+            Self {
+                rotation: self.rotation.abs_diff(&other.rotation),
+                translation: self.translation.abs_diff(&other.translation),
+            }
+        }
+    }
+
+    glam_test!(test_align, {
+        use std::mem;
+        assert_eq!(32, mem::size_of::<Isometry3A>());
+        assert_eq!(16, mem::align_of::<Isometry3A>());
+    });
+
+    glam_test!(test_transform_vec3a, {
+        let iso = Isometry3A::from_rotation_translation(
+            Quat::from_rotation_x(deg(90.0)),
+            Vec3::new(1.0, 2.0, 3.0),
+        );
+        assert_approx_eq!(Vec3A::new(0.0, 0.0, 1.0), iso.transform_vector3a(Vec3A::Y));
+        assert_approx_eq!(Vec3A::new(1.0, 2.0, 4.0), iso.transform_point3a(Vec3A::Y));
+    });
+
+    glam_test!(test_debug, {
+        assert_eq!(
+            format!("{:?}", Isometry3A::IDENTITY),
+            "Isometry3A { rotation: Quat(0.0, 0.0, 0.0, 1.0), translation: Vec3A(0.0, 0.0, 0.0) }"
+        );
+    });
+
+    glam_test!(test_as, {
+        use glam::{DIsometry3, DQuat, DVec3};
+        let iso = DIsometry3::from_rotation_translation(
+            DQuat::from_rotation_y(1.0),
+            DVec3::new(1.0, 2.0, 3.0),
+        );
+        assert_approx_eq!(
+            iso.rotation,
+            Isometry3A::from_rotation_translation(
+                Quat::from_rotation_y(1.0),
+                Vec3::new(1.0, 2.0, 3.0)
+            )
+            .as_disometry3()
+            .rotation,
+            1e-6
+        );
+        assert_eq!(
+            Isometry3A::from_array([1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0]),
+            DIsometry3::from_array([1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0]).as_isometry3a()
+        );
+        assert_eq!(
+            DIsometry3::from_array([1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0]),
+            Isometry3A::from_array([1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0]).as_disometry3()
+        );
+    });
+
+    impl_isometry3_tests!(
+        f32,
+        Isometry3A,
+        Quat,
+        Vec3,
+        Mat4,
+        Affine3A,
+        from_affine3a,
+        to_affine3a
+    );
+}
+
+mod disometry3 {
+    use super::support::{deg, FloatCompare};
+    use glam::{DAffine3, DIsometry3, DMat4, DQuat, DVec3};
+
+    impl FloatCompare for DIsometry3 {
+        #[inline]
+        fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+            self.abs_diff_eq(*other, max_abs_diff as f64)
+        }
+        #[inline]
+        fn abs_diff(&self, other: &Self) -> Self {
+            Self {
+                rotation: self.rotation.abs_diff(&other.rotation),
+                translation: self.translation.abs_diff(&other.translation),
+            }
+        }
+    }
+
+    glam_test!(test_align, {
+        use std::mem;
+        assert_eq!(56, mem::size_of::<DIsometry3>());
+        assert_eq!(mem::align_of::<f64>(), mem::align_of::<DIsometry3>());
+    });
+
+    glam_test!(test_debug, {
+        assert_eq!(
+            format!("{:?}", DIsometry3::IDENTITY),
+            "DIsometry3 { rotation: DQuat(0.0, 0.0, 0.0, 1.0), translation: DVec3(0.0, 0.0, 0.0) }"
+        );
+    });
+
+    impl_isometry3_tests!(
+        f64,
+        DIsometry3,
+        DQuat,
+        DVec3,
+        DMat4,
+        DAffine3,
+        from_affine3,
+        to_affine3
+    );
+}
@@ -223,6 +223,74 @@ macro_rules! impl_mat2_tests {
             should_glam_assert!({ $mat2::ZERO.inverse() });
         });
 
+        glam_test!(test_mat2_solve, {
+            // The first column needs a row swap for partial pivoting.
+            let m = $mat2::from_cols($newvec2(0.0, 2.0), $newvec2(3.0, 1.0));
+            let x = $newvec2(1.5, -2.0);
+            assert_approx_eq!(x, m.solve(m * x).unwrap());
+            assert_eq!(Some($vec2::ZERO), m.solve($vec2::ZERO));
+            assert_eq!(Some(x), $mat2::IDENTITY.solve(x));
+
+            assert_eq!(None, $mat2::ZERO.solve($vec2::ONE));
+            assert_eq!(
+                None,
+                $mat2::from_cols($newvec2(1.0, 2.0), $vec2::ZERO).solve($vec2::ONE)
+            );
+        });
+
+        glam_test!(test_mat2_lu, {
+            let m = $mat2::from_cols($newvec2(0.0, 2.0), $newvec2(3.0, 1.0));
+            let (p, l, u) = m.lu();
+            assert_eq!($mat2::from_cols($vec2::Y, $vec2::X), p);
+            assert_eq!($vec2::ONE, l.diagonal());
+            assert_eq!(0.0, l.y_axis.x);
+            assert_eq!(0.0, u.x_axis.y);
+            assert_approx_eq!(p * m, l * u);
+
+            let m = $mat2::from_cols_array_2d(&ARRAY2X2);
+            let (p, l, u) = m.lu();
+            assert_approx_eq!(p * m, l * u);
+
+            let (p, l, u) = $mat2::ZERO.lu();
+            assert_eq!($mat2::IDENTITY, p);
+            assert_eq!($mat2::IDENTITY, l);
+            assert_eq!($mat2::ZERO, u);
+        });
+
+        glam_test!(test_mat2_qr, {
+            let m = $mat2::from_cols_array_2d(&ARRAY2X2);
+            let (q, r) = m.qr();
+            assert_approx_eq!(m, q * r, 1.0e-6);
+            assert_approx_eq!($mat2::IDENTITY, q.transpose() * q, 1.0e-6);
+            assert_eq!(0.0, r.x_axis.y);
+            assert!(r.diagonal().cmpge($vec2::ZERO).all());
+
+            let (q, r) = $mat2::IDENTITY.qr();
+            assert_approx_eq!($mat2::IDENTITY, q);
+            assert_approx_eq!($mat2::IDENTITY, r);
+
+            let (q, r) = $mat2::ZERO.qr();
+            assert_eq!($mat2::IDENTITY, q);
+            assert_eq!($mat2::ZERO, r);
+        });
+
+        glam_test!(test_mat2_cholesky, {
+            let m = $mat2::from_cols($newvec2(4.0, 2.0), $newvec2(2.0, 5.0));
+            let l = m.cholesky().unwrap();
+            assert_eq!($mat2::from_cols($newvec2(2.0, 1.0), $newvec2(0.0, 2.0)), l);
+            assert_approx_eq!(m, l * l.transpose());
+
+            assert_eq!(Some($mat2::IDENTITY), $mat2::IDENTITY.cholesky());
+            assert_eq!(None, $mat2::ZERO.cholesky());
+            assert_eq!(None, (-$mat2::IDENTITY).cholesky());
+            // Symmetric but indefinite.
+            assert_eq!(
+                None,
+                $mat2::from_cols($newvec2(1.0, 2.0), $newvec2(2.0, 1.0)).cholesky()
+            );
+            assert_eq!(None, $mat2::NAN.cholesky());
+        });
+
         glam_test!(test_mat2_ops, {
             let m0 = $mat2::from_cols_array_2d(&ARRAY2X2);
             let m0x2 = $mat2::from_cols_array_2d(&[[2.0, 4.0], [6.0, 8.0]]);
@@ -338,6 +338,139 @@ macro_rules! impl_mat3_tests {
             should_glam_assert!({ $mat3::ZERO.inverse() });
         });
 
+        glam_test!(test_mat3_solve, {
+            // The first column needs a row swap for partial pivoting.
+            let m = $mat3::from_cols(
+                $newvec3(0.0, 2.0, 1.0),
+                $newvec3(1.0, 1.0, -3.0),
+                $newvec3(4.0, 0.5, 2.0),
+            );
+            let x = $newvec3(1.5, -2.0, 0.25);
+            assert_approx_eq!(x, m.solve(m * x).unwrap(), 1.0e-6);
+            assert_eq!(Some(x), $mat3::IDENTITY.solve(x));
+
+            assert_eq!(None, $mat3::ZERO.solve(m.x_axis));
+            let singular = $mat3::from_cols(m.x_axis, m.x_axis * 0.0, m.z_axis);
+            assert_eq!(None, singular.solve(m.x_axis));
+        });
+
+        glam_test!(test_mat3_lu, {
+            let m = $mat3::from_cols(
+                $newvec3(0.0, 2.0, 1.0),
+                $newvec3(1.0, 1.0, -3.0),
+                $newvec3(4.0, 0.5, 2.0),
+            );
+            let (p, l, u) = m.lu();
+            assert_approx_eq!(p * m, l * u, 1.0e-6);
+            assert_approx_eq!($mat3::IDENTITY, p * p.transpose());
+            for (c, (l_col, u_col)) in l
+                .to_cols_array_2d()
+                .iter()
+                .zip(u.to_cols_array_2d())
+                .enumerate()
+            {
+                assert_eq!(1.0, l_col[c]);
+                assert!(l_col[..c].iter().all(|&x| x == 0.0));
+                assert!(l_col[c + 1..].iter().all(|x| x.abs() <= 1.0));
+                assert!(u_col[c + 1..].iter().all(|&x| x == 0.0));
+            }
+
+            let m = $mat3::from_cols_array_2d(&ARRAY3X3);
+            let (p, l, u) = m.lu();
+            assert_approx_eq!(p * m, l * u, 1.0e-6);
+        });
+
+        glam_test!(test_mat3_qr, {
+            let m = $mat3::from_cols(
+                $newvec3(0.0, 2.0, 1.0),
+                $newvec3(1.0, 1.0, -3.0),
+                $newvec3(4.0, 0.5, 2.0),
+            );
+            let (q, r) = m.qr();
+            assert_approx_eq!(m, q * r, 1.0e-6);
+            assert_approx_eq!($mat3::IDENTITY, q.transpose() * q, 1.0e-6);
+            for (c, col) in r.to_cols_array_2d().iter().enumerate() {
+                assert!(col[c] >= 0.0);
+                assert!(col[c + 1..].iter().all(|&x| x == 0.0));
+            }
+
+            let m = $mat3::from_cols_array_2d(&ARRAY3X3);
+            let (q, r) = m.qr();
+            assert_approx_eq!(m, q * r, 1.0e-5);
+            assert_approx_eq!($mat3::IDENTITY, q.transpose() * q, 1.0e-6);
+
+            let (q, r) = $mat3::IDENTITY.qr();
+            assert_approx_eq!($mat3::IDENTITY, q);
+            assert_approx_eq!($mat3::IDENTITY, r);
+        });
+
+        glam_test!(test_mat3_cholesky, {
+            let l = $mat3::from_cols(
+                $newvec3(2.0, 1.0, -1.0),
+                $newvec3(0.0, 3.0, 0.5),
+                $newvec3(0.0, 0.0, 1.5),
+            );
+            let m = l * l.transpose();
+            assert_approx_eq!(l, m.cholesky().unwrap(), 1.0e-6);
+
+            assert_eq!(Some($mat3::IDENTITY), $mat3::IDENTITY.cholesky());
+            assert_eq!(None, $mat3::ZERO.cholesky());
+            assert_eq!(None, (-$mat3::IDENTITY).cholesky());
+            assert_eq!(
+                None,
+                $mat3::from_diagonal($vec3::new(1.0, -1.0, 1.0)).cholesky()
+            );
+            assert_eq!(None, $mat3::NAN.cholesky());
+        });
+
+        glam_test!(test_mat3_exp_ln, {
+            assert_eq!($mat3::IDENTITY, $mat3::ZERO.exp());
+            assert_eq!($mat3::IDENTITY, $mat3::from_scaled_axis($vec3::ZERO));
+            assert_eq!($vec3::ZERO, $mat3::IDENTITY.to_scaled_axis());
+            assert_eq!($mat3::ZERO, $mat3::IDENTITY.ln());
+
+            let w = $vec3::new(0.5, -1.0, 2.0);
+            for v in [
+                $vec3::X,
+                -$vec3::Z,
+                $vec3::new(1.0, 1.0e-4, 0.0),
+                $vec3::new(0.1, 0.2, 0.3),
+                $vec3::new(-2.0, 0.5, -1.0),
+            ] {
+                let angle = v.length();
+                let m = $mat3::from_axis_angle(v / angle, angle);
+                assert_approx_eq!(m, $mat3::from_scaled_axis(v), 1.0e-6);
+                assert_approx_eq!(
+                    $mat3::from_quat($quat::from_scaled_axis(v)),
+                    $mat3::from_scaled_axis(v),
+                    1.0e-6
+                );
+                assert_approx_eq!(v, m.to_scaled_axis(), 1.0e-5);
+
+                let ln = m.ln();
+                assert_eq!(ln, -ln.transpose());
+                assert_approx_eq!(v.cross(w), ln.mul_vec3(w), 1.0e-5);
+                assert_approx_eq!(m, ln.exp(), 1.0e-6);
+            }
+
+            // Small angles keep their relative precision.
+            let v = $vec3::new(1.0e-9, -2.0e-9, 3.0e-9);
+            let m = $mat3::from_scaled_axis(v);
+            assert!((m.to_scaled_axis() - v).length() <= 1.0e-6 * v.length());
+
+            // Close to a half turn, and past it where the shorter rotation is returned.
+            let axis = $vec3::new(1.0, 2.0, -3.0).normalize();
+            let angle = core::$t::consts::PI - 0.01;
+            let m = $mat3::from_axis_angle(axis, angle);
+            assert_approx_eq!(axis * angle, m.to_scaled_axis(), 1.0e-4);
+            let m = $mat3::from_axis_angle(axis, 4.0);
+            assert_approx_eq!(
+                axis * (4.0 - 2.0 * core::$t::consts::PI),
+                m.to_scaled_axis(),
+                1.0e-5
+            );
+        });
+
         glam_test!(test_mat3_ops, {
             let m0 = $mat3::from_cols_array_2d(&ARRAY3X3);
             let m0x2 = $mat3::from_cols_array_2d(&[
@@ -478,6 +611,204 @@ macro_rules! impl_mat3_tests {
             assert!(!($mat3::IDENTITY * $t::NEG_INFINITY).is_finite());
             assert!(!($mat3::IDENTITY * $t::NAN).is_finite());
         });
+
+        glam_test!(test_mat3_symmetric_eigen, {
+            let check = |m: $mat3, expected: [$t; 3]| {
+                let (values, vectors) = m.symmetric_eigen();
+                assert_approx_eq!(
+                    $vec3::new(expected[0], expected[1], expected[2]),
+                    values,
+                    1e-5
+                );
+                assert_approx_eq!($mat3::IDENTITY, vectors * vectors.transpose(), 1e-5);
+                assert_approx_eq!(1.0, vectors.determinant(), 1e-5);
+                assert_approx_eq!(
+                    m,
+                    vectors * $mat3::from_diagonal(values) * vectors.transpose(),
+                    1e-5
+                );
+                for i in 0..3 {
+                    assert_approx_eq!(m * vectors.col(i), vectors.col(i) * values[i], 1e-5);
+                }
+
+                let (quat_values, quat) = m.symmetric_eigen_quat();
+                assert_eq!(values, quat_values);
+                assert!(quat.is_normalized());
+                assert_approx_eq!(vectors, $mat3::from_quat(quat), 1e-5);
+            };
+
+            let rot = $mat3::from_quat($quat::from_axis_angle(
+                $vec3::new(1.0, 2.0, 3.0).normalize(),
+                deg(50.0),
+            ));
+            let sym = |d: [$t; 3]| rot * $mat3::from_diagonal($vec3::from(d)) * rot.transpose();
+
+            check(sym([5.0, -2.0, 1.0]), [5.0, 1.0, -2.0]);
+            check(
+                $mat3::from_diagonal($vec3::new(1.0, 3.0, 2.0)),
+                [3.0, 2.0, 1.0],
+            );
+
+            // repeated and zero eigenvalues
+            check(sym([2.0, 2.0, -1.0]), [2.0, 2.0, -1.0]);
+            check(sym([4.0, 0.0, 0.0]), [4.0, 0.0, 0.0]);
+            check(sym([3.0, 3.0, 3.0]), [3.0, 3.0, 3.0]);
+
+            assert_eq!(
+                ($vec3::ZERO, $mat3::IDENTITY),
+                $mat3::ZERO.symmetric_eigen()
+            );
+            assert_eq!(
+                ($vec3::ONE, $mat3::IDENTITY),
+                $mat3::IDENTITY.symmetric_eigen()
+            );
+
+            // asymmetric input is symmetrized
+            let m = $mat3::from_cols_array(&[2.0, 1.0, 0.0, 3.0, 2.0, 0.0, 0.0, 0.0, 1.0]);
+            let (values, _) = m.symmetric_eigen();
+            assert_approx_eq!($vec3::new(4.0, 1.0, 0.0), values, 1e-5);
+        });
+
+        glam_test!(test_mat3_svd, {
+            let check = |m: $mat3| {
+                let (u, sigma, v) = m.svd();
+                assert_approx_eq!($mat3::IDENTITY, u * u.transpose(), 1e-5);
+                assert_approx_eq!($mat3::IDENTITY, v * v.transpose(), 1e-5);
+                assert_approx_eq!(1.0, v.determinant(), 1e-5);
+                assert_approx_eq!(m, u * $mat3::from_diagonal(sigma) * v.transpose(), 1e-4);
+                assert!(sigma.x >= sigma.y && sigma.y >= sigma.z && sigma.z >= 0.0);
+                (u, sigma, v)
+            };
+
+            let rot = $mat3::from_quat($quat::from_axis_angle(
+                $vec3::new(1.0, 2.0, 3.0).normalize(),
+                deg(50.0),
+            ));
+            let rot2 = $mat3::from_rotation_y(deg(-30.0));
+
+            let (u, sigma, _) = check(rot * $mat3::from_diagonal($vec3::new(2.0, 5.0, 3.0)) * rot2);
+            assert_approx_eq!($vec3::new(5.0, 3.0, 2.0), sigma, 1e-5);
+            assert_approx_eq!(1.0, u.determinant(), 1e-5);
+
+            let (_, sigma, _) = check(rot);
+            assert_approx_eq!($vec3::ONE, sigma, 1e-5);
+
+            // reflections end up in u
+            let (u, sigma, _) = check(rot * $mat3::from_diagonal($vec3::new(1.0, -2.0, 3.0)));
+            assert_approx_eq!($vec3::new(3.0, 2.0, 1.0), sigma, 1e-5);
+            assert_approx_eq!(-1.0, u.determinant(), 1e-5);
+
+            // rank deficient matrices
+            let (_, sigma, _) = check($mat3::from_cols_array_2d(&ARRAY3X3));
+            assert_approx_eq!(0.0, sigma.z, 1e-5);
+
+            let (_, sigma, _) = check($mat3::from_cols(
+                $newvec3(1.0, 2.0, 3.0),
+                $newvec3(2.0, 4.0, 6.0),
+                $newvec3(-1.0, -2.0, -3.0),
+            ));
+            assert_approx_eq!(0.0, sigma.y, 1e-5);
+            assert_approx_eq!(0.0, sigma.z, 1e-5);
+
+            let (u, sigma, v) = check($mat3::ZERO);
+            assert_eq!($vec3::ZERO, sigma);
+            assert_approx_eq!(1.0, u.determinant(), 1e-5);
+            assert_eq!($mat3::IDENTITY, v);
+        });
+
+        glam_test!(test_mat3_polar_decompose, {
+            let check = |m: $mat3| {
+                let (rotation, stretch) = m.polar_decompose();
+                assert_approx_eq!($mat3::IDENTITY, rotation * rotation.transpose(), 1e-5);
+                assert_approx_eq!(1.0, rotation.determinant(), 1e-5);
+                assert_approx_eq!(stretch, stretch.transpose(), 1e-5);
+                assert_approx_eq!(m, rotation * stretch, 1e-5);
+                assert_eq!(
+                    rotation * stretch,
+                    $mat3::from_polar_decomposition(rotation, stretch)
+                );
+                (rotation, stretch)
+            };
+
+            let rot = $mat3::from_quat($quat::from_axis_angle(
+                $vec3::new(1.0, 2.0, 3.0).normalize(),
+                deg(50.0),
+            ));
+
+            let (rotation, stretch) = check(rot);
+            assert_approx_eq!(rot, rotation, 1e-5);
+            assert_approx_eq!($mat3::IDENTITY, stretch, 1e-5);
+
+            let sym = $mat3::from_cols(
+                $newvec3(2.0, 0.5, 0.0),
+                $newvec3(0.5, 3.0, -0.25),
+                $newvec3(0.0, -0.25, 1.0),
+            );
+            let (rotation, stretch) = check(rot * sym);
+            assert_approx_eq!(rot, rotation, 1e-5);
+            assert_approx_eq!(sym, stretch, 1e-5);
+
+            // reflections end up in the stretch
+            let (_, stretch) = check(rot * $mat3::from_diagonal($vec3::new(1.0, -2.0, 3.0)));
+            assert_approx_eq!(-6.0, stretch.determinant(), 1e-4);
+
+            // rank deficient matrices
+            check($mat3::from_cols_array_2d(&ARRAY3X3));
+            let (_, stretch) = check($mat3::ZERO);
+            assert_eq!($mat3::ZERO, stretch);
+        });
+
+        glam_test!(test_mat3_scale_shear_rotation, {
+            let in_rotation =
+                $quat::from_axis_angle($vec3::new(1.0, 2.0, 3.0).normalize(), deg(50.0));
+            let in_scale = $vec3::new(2.0, 3.0, 4.0);
+            let in_shear = $vec3::new(0.5, -0.25, 1.5);
+            let m = $mat3::from_scale_shear_rotation(in_scale, in_shear, in_rotation);
+            assert_approx_eq!(
+                $mat3::from_quat(in_rotation)
+                    * $mat3::from_cols(
+                        $newvec3(1.0, 0.0, 0.0),
+                        $newvec3(0.5, 1.0, 0.0),
+                        $newvec3(-0.25, 1.5, 1.0),
+                    )
+                    * $mat3::from_diagonal(in_scale),
+                m,
+                1e-5
+            );
+
+            let (scale, shear, rotation) = m.to_scale_shear_rotation().unwrap();
+            assert_approx_eq!(in_scale, scale, 1e-5);
+            assert_approx_eq!(in_shear, shear, 1e-5);
+            assert_approx_eq!(
+                $mat3::from_quat(in_rotation),
+                $mat3::from_quat(rotation),
+                1e-5
+            );
+
+            // without shear the result matches a scale and rotation
+            let m = $mat3::from_quat(in_rotation) * $mat3::from_diagonal(in_scale);
+            let (scale, shear, _) = m.to_scale_shear_rotation().unwrap();
+            assert_approx_eq!(in_scale, scale, 1e-5);
+            assert_approx_eq!($vec3::ZERO, shear, 1e-5);
+
+            // negative determinant
+            let m =
+                $mat3::from_scale_shear_rotation($vec3::new(2.0, -3.0, 4.0), in_shear, in_rotation);
+            let (scale, shear, rotation) = m.to_scale_shear_rotation().unwrap();
+            assert!(scale.x < 0.0);
+            assert_approx_eq!(
+                m,
+                $mat3::from_scale_shear_rotation(scale, shear, rotation),
+                1e-5
+            );
+
+            assert_eq!(None, $mat3::ZERO.to_scale_shear_rotation());
+            assert_eq!(
+                None,
+                $mat3::from_cols_array_2d(&ARRAY3X3).to_scale_shear_rotation()
+            );
+            assert_eq!(None, ($mat3::IDENTITY * $t::NAN).to_scale_shear_rotation());
+        });
     };
 }
 
@@ -523,6 +854,19 @@ mod mat3 {
         );
     });
 
+    #[cfg(feature = "const-float-arithmetic")]
+    glam_test!(test_const_arithmetic, {
+        const A: Mat3 = Mat3::from_cols_array(&[1.0, 2.0, 3.0, -4.0, 5.5, 6.0, 7.0, 0.25, -9.0]);
+        const B: Mat3 = Mat3::from_cols_array(&[0.5, -1.0, 2.0, 3.0, 1.5, -0.75, 4.0, 2.0, 1.0]);
+        const V: Vec3 = Vec3::new(0.1, -0.2, 0.3);
+        const TRANSPOSE: Mat3 = A.const_transpose();
+        const PRODUCT: Mat3 = A.const_mul_mat3(&B);
+        const TRANSFORMED: Vec3 = A.const_mul_vec3(V);
+        assert_eq!(A.transpose(), TRANSPOSE);
+        assert_eq!(A * B, PRODUCT);
+        assert_eq!(A * V, TRANSFORMED);
+    });
+
     impl_mat3_tests!(f32, mat3, Mat3, Mat2, Mat4, Quat, vec3, Vec3, Vec2);
     impl_as_ref_tests!(Mat3);
 }
@@ -212,6 +212,24 @@ macro_rules! impl_mat4_tests {
             assert_eq!(m.transpose().mul_vec4(v), m.mul_transpose_vec4(v));
         });
 
+        glam_test!(test_mat4_mul_mat4_columns, {
+            // the AVX path transforms two columns at once and must match `mul_vec4` exactly
+            let a = $mat4::from_scale_rotation_translation(
+                $vec3::new(0.5, 1.5, -2.0),
+                $quat::from_rotation_y(deg(33.0)),
+                $vec3::new(1.0, -2.0, 3.0),
+            );
+            let b = $mat4::from_cols_array(&ARRAY1X16) * 0.1;
+            let expected = $mat4::from_cols(
+                a.mul_vec4(b.x_axis),
+                a.mul_vec4(b.y_axis),
+                a.mul_vec4(b.z_axis),
+                a.mul_vec4(b.w_axis),
+            );
+            assert_eq!(expected, a * b);
+            assert_eq!(expected, a.mul_mat4(&b));
+        });
+
         glam_test!(test_mat4_mul_diagonal, {
             let v = $vec4::new(1.0, 2.0, 3.0, 4.0);
             assert_eq!($mat4::IDENTITY * $mat4::from_diagonal(v), $mat4::IDENTITY.mul_diagonal_scale(v));
@@ -381,6 +399,90 @@ macro_rules! impl_mat4_tests {
             should_glam_assert!({ $mat4::ZERO.inverse() });
         });
 
+        glam_test!(test_mat4_solve, {
+            let m = $mat4::from_cols(
+                $newvec4(1.0, -0.3, 1.0, 1.0),
+                $newvec4(0.5, 0.6, 0.7, 0.8),
+                $newvec4(-0.9, -0.3, 0.0, 12.0),
+                $newvec4(0.13, 0.14, 0.15, 0.16),
+            );
+            let x = $newvec4(1.5, -2.0, 0.25, 3.0);
+            assert_approx_eq!(x, m.solve(m * x).unwrap(), 1.0e-5);
+            assert_approx_eq!(m.inverse() * x, m.solve(x).unwrap(), 1.0e-4);
+            assert_eq!(Some(x), $mat4::IDENTITY.solve(x));
+
+            assert_eq!(None, $mat4::ZERO.solve(x));
+            let singular = $mat4::from_cols(m.x_axis, m.y_axis, $vec4::ZERO, m.w_axis);
+            assert_eq!(None, singular.solve(x));
+        });
+
+        glam_test!(test_mat4_lu, {
+            let m = $mat4::from_cols(
+                $newvec4(1.0, -0.3, 1.0, 1.0),
+                $newvec4(0.5, 0.6, 0.7, 0.8),
+                $newvec4(-0.9, -0.3, 0.0, 12.0),
+                $newvec4(0.13, 0.14, 0.15, 0.16),
+            );
+            let (p, l, u) = m.lu();
+            assert_approx_eq!(p * m, l * u, 1.0e-6);
+            assert_approx_eq!($mat4::IDENTITY, p * p.transpose());
+            for (c, (l_col, u_col)) in l
+                .to_cols_array_2d()
+                .iter()
+                .zip(u.to_cols_array_2d())
+                .enumerate()
+            {
+                assert_eq!(1.0, l_col[c]);
+                assert!(l_col[..c].iter().all(|&x| x == 0.0));
+                assert!(l_col[c + 1..].iter().all(|x| x.abs() <= 1.0));
+                assert!(u_col[c + 1..].iter().all(|&x| x == 0.0));
+            }
+
+            let m = $mat4::from_cols_array_2d(&ARRAY4X4);
+            let (p, l, u) = m.lu();
+            assert_approx_eq!(p * m, l * u, 1.0e-5);
+        });
+
+        glam_test!(test_mat4_qr, {
+            let m = $mat4::from_cols(
+                $newvec4(1.0, -0.3, 1.0, 1.0),
+                $newvec4(0.5, 0.6, 0.7, 0.8),
+                $newvec4(-0.9, -0.3, 0.0, 12.0),
+                $newvec4(0.13, 0.14, 0.15, 0.16),
+            );
+            let (q, r) = m.qr();
+            assert_approx_eq!(m, q * r, 1.0e-5);
+            assert_approx_eq!($mat4::IDENTITY, q.transpose() * q, 1.0e-6);
+            for (c, col) in r.to_cols_array_2d().iter().enumerate() {
+                assert!(col[c] >= 0.0);
+                assert!(col[c + 1..].iter().all(|&x| x == 0.0));
+            }
+
+            let (q, r) = $mat4::IDENTITY.qr();
+            assert_approx_eq!($mat4::IDENTITY, q);
+            assert_approx_eq!($mat4::IDENTITY, r);
+        });
+
+        glam_test!(test_mat4_cholesky, {
+            let l = $mat4::from_cols(
+                $newvec4(2.0, 1.0, -1.0, 0.5),
+                $newvec4(0.0, 3.0, 0.5, -2.0),
+                $newvec4(0.0, 0.0, 1.5, 1.0),
+                $newvec4(0.0, 0.0, 0.0, 0.75),
+            );
+            let m = l * l.transpose();
+            assert_approx_eq!(l, m.cholesky().unwrap(), 1.0e-5);
+
+            assert_eq!(Some($mat4::IDENTITY), $mat4::IDENTITY.cholesky());
+            assert_eq!(None, $mat4::ZERO.cholesky());
+            assert_eq!(None, (-$mat4::IDENTITY).cholesky());
+            assert_eq!(
+                None,
+                $mat4::from_diagonal($newvec4(1.0, 1.0, -1.0, 1.0)).cholesky()
+            );
+            assert_eq!(None, $mat4::NAN.cholesky());
+        });
+
         glam_test!(test_mat4_decompose, {
             // identity
             let (out_scale, out_rotation, out_translation) =
@@ -471,6 +573,84 @@ macro_rules! impl_mat4_tests {
             should_glam_assert!({ $mat4::ZERO.to_scale_rotation_translation() });
         });
 
+        glam_test!(test_mat4_decompose_shear_perspective, {
+            assert_eq!(
+                Some((
+                    $vec3::ONE,
+                    $vec3::ZERO,
+                    $quat::IDENTITY,
+                    $vec3::ZERO,
+                    $vec4::W
+                )),
+                $mat4::IDENTITY.decompose()
+            );
+
+            let in_scale = $vec3::new(2.0, 3.0, 4.0);
+            let in_shear = $vec3::new(0.5, -0.25, 1.5);
+            let in_rotation = $quat::from_euler(
+                glam::EulerRot::YXZ,
+                $t::to_radians(-45.0),
+                $t::to_radians(30.0),
+                $t::to_radians(60.0),
+            );
+            let in_translation = $vec3::new(-2.0, 4.0, -0.125);
+
+            // affine
+            let in_mat = $mat4::recompose(in_scale, in_shear, in_rotation, in_translation, $vec4::W);
+            assert_approx_eq!(
+                $mat4::from_translation(in_translation)
+                    * $mat4::from_mat3($mat3::from_scale_shear_rotation(
+                        in_scale,
+                        in_shear,
+                        in_rotation
+                    )),
+                in_mat,
+                1e-6
+            );
+            let (scale, shear, rotation, translation, perspective) = in_mat.decompose().unwrap();
+            assert_approx_eq!(in_scale, scale, 1e-5);
+            assert_approx_eq!(in_shear, shear, 1e-5);
+            assert_approx_eq!(in_rotation, rotation, 1e-5);
+            assert_approx_eq!(in_translation, translation, 1e-6);
+            assert_approx_eq!($vec4::W, perspective, 1e-6);
+
+            // without shear the result matches to_scale_rotation_translation
+            let in_mat = $mat4::from_scale_rotation_translation(in_scale, in_rotation, in_translation);
+            let (scale, shear, rotation, translation, _) = in_mat.decompose().unwrap();
+            let (out_scale, out_rotation, out_translation) = in_mat.to_scale_rotation_translation();
+            assert_approx_eq!(out_scale, scale, 1e-5);
+            assert_approx_eq!($vec3::ZERO, shear, 1e-5);
+            assert_approx_eq!(out_rotation, rotation, 1e-5);
+            assert_approx_eq!(out_translation, translation);
+
+            // perspective
+            let in_perspective = $vec4::new(0.25, -0.5, 0.125, 2.0);
+            let in_mat = $mat4::recompose(in_scale, in_shear, in_rotation, in_translation, in_perspective);
+            let (scale, shear, rotation, translation, perspective) = in_mat.decompose().unwrap();
+            assert_approx_eq!(in_scale, scale, 1e-5);
+            assert_approx_eq!(in_shear, shear, 1e-5);
+            assert_approx_eq!(in_rotation, rotation, 1e-5);
+            assert_approx_eq!(in_translation, translation, 1e-5);
+            assert_approx_eq!(in_perspective, perspective, 1e-5);
+
+            let projection = $mat4::perspective_rh($t::to_radians(60.0), 1.5, 0.1, 100.0)
+                * $mat4::look_at_rh(
+                    $vec3::new(1.0, 2.0, 3.0),
+                    $vec3::ZERO,
+                    $vec3::Y,
+                );
+            let (scale, shear, rotation, translation, perspective) =
+                projection.decompose().unwrap();
+            assert_approx_eq!(
+                projection,
+                $mat4::recompose(scale, shear, rotation, translation, perspective),
+                1e-4
+            );
+
+            assert_eq!(None, $mat4::ZERO.decompose());
+            assert_eq!(None, $mat4::from_scale($vec3::new(1.0, 0.0, 1.0)).decompose());
+        });
+
         glam_test!(test_mat4_look_at, {
             let eye = $vec3::new(0.0, 0.0, -5.0);
             let center = $vec3::new(0.0, 0.0, 0.0);
@@ -893,6 +1073,43 @@ mod mat4 {
         );
     });
 
+    glam_test!(test_transform_slices, {
+        let points: Vec<Vec3> = (0..11)
+            .map(|i| vec3(i as f32 - 5.0, (i * i) as f32 * 0.25, 1.0 / (i + 1) as f32))
+            .collect();
+        let mut out = vec![Vec3::ZERO; points.len()];
+
+        let m = Mat4::from_scale_rotation_translation(
+            Vec3::new(0.5, 1.5, 2.0),
+            Quat::from_rotation_x(deg(30.0)),
+            Vec3::new(1.0, 2.0, 3.0),
+        );
+        m.transform_points3(&points, &mut out);
+        let expected: Vec<Vec3> = points.iter().map(|p| m.transform_point3(*p)).collect();
+        assert_eq!(expected, out);
+        let mut in_place = points.clone();
+        m.transform_points3_in_place(&mut in_place);
+        assert_eq!(expected, in_place);
+
+        m.transform_vectors3(&points, &mut out);
+        let expected: Vec<Vec3> = points.iter().map(|p| m.transform_vector3(*p)).collect();
+        assert_eq!(expected, out);
+        let mut in_place = points.clone();
+        m.transform_vectors3_in_place(&mut in_place);
+        assert_eq!(expected, in_place);
+
+        let m = Mat4::perspective_rh(deg(60.0), 1.5, 0.1, 100.0);
+        m.project_points3(&points, &mut out);
+        let expected: Vec<Vec3> = points.iter().map(|p| m.project_point3(*p)).collect();
+        assert_eq!(expected, out);
+        let mut in_place = points.clone();
+        m.project_points3_in_place(&mut in_place);
+        assert_eq!(expected, in_place);
+
+        m.project_points3(&[], &mut []);
+        should_panic!({ m.transform_points3(&points, &mut [Vec3::ZERO; 3]) });
+    });
+
     glam_test!(test_as, {
         use glam::DMat4;
         assert_eq!(
@@ -941,6 +1158,23 @@ mod dmat4 {
         assert_eq!(128, mem::size_of::<DMat4>());
     });
 
+    #[cfg(feature = "const-float-arithmetic")]
+    glam_test!(test_const_arithmetic, {
+        const A: DMat4 = DMat4::from_cols_array(&[
+            1.0, 2.0, 3.0, 4.0, -5.0, 6.5, 7.0, 8.0, 9.0, 0.25, -11.0, 12.0, 13.0, 14.0, 15.0, 1.0,
+        ]);
+        const B: DMat4 = DMat4::from_cols_array(&[
+            0.5, -1.0, 2.0, 0.0, 3.0, 1.5, -0.75, 0.0, 4.0, 2.0, 1.0, 0.0, -2.0, 8.0, 0.125, 1.0,
+        ]);
+        const V: DVec4 = DVec4::new(0.1, -0.2, 0.3, 1.0);
+        const TRANSPOSE: DMat4 = A.const_transpose();
+        const PRODUCT: DMat4 = A.const_mul_mat4(&B);
+        const TRANSFORMED: DVec4 = A.const_mul_vec4(V);
+        assert_eq!(A.transpose(), TRANSPOSE);
+        assert_eq!(A * B, PRODUCT);
+        assert_eq!(A * V, TRANSFORMED);
+    });
+
     impl_mat4_tests!(f64, dmat4, dvec4, dvec3, DMat4, DMat3, DQuat, DVec4, DVec3);
     impl_as_ref_tests!(DMat4);
 }
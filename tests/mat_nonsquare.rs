@@ -0,0 +1,343 @@
+#[macro_use]
+mod support;
+
+macro_rules! impl_mat_nonsquare_tests {
+    (
+        $mat:ident,
+        $transpose:ident,
+        $col:ident,
+        $row:ident,
+        $matc:ident,
+        $matr:ident,
+        $c:literal,
+        $r:literal,
+        $mul_vec:ident,
+        $mul_transpose_vec:ident,
+        $mul_mat_transpose:ident
+    ) => {
+        fn matrix1d() -> [f32; $c * $r] {
+            core::array::from_fn(|i| (i + 1) as f32)
+        }
+
+        fn matrix2d() -> [[f32; $r]; $c] {
+            core::array::from_fn(|c| core::array::from_fn(|r| (c * $r + r + 1) as f32))
+        }
+
+        glam_test!(test_consts, {
+            assert_eq!([0.0; $c * $r], $mat::ZERO.to_cols_array());
+            assert_eq!($mat::ZERO, $mat::default());
+            assert!($mat::NAN.is_nan());
+            assert!(!$mat::NAN.is_finite());
+            assert!($mat::ZERO.is_finite());
+        });
+
+        glam_test!(test_from_cols, {
+            let m = $mat::from_cols_array(&matrix1d());
+            assert_eq!(matrix1d(), m.to_cols_array());
+            assert_eq!(matrix2d(), m.to_cols_array_2d());
+            assert_eq!(m, $mat::from_cols_array_2d(&matrix2d()));
+            assert_eq!(&matrix1d(), m.as_ref());
+            for (i, col) in matrix2d().iter().enumerate() {
+                assert_eq!($col::from_array(*col), m.col(i));
+            }
+            should_panic!({ m.col($c) });
+            should_panic!({ m.row($r) });
+
+            let mut n = m;
+            *n.col_mut(0) = $col::ZERO;
+            assert_eq!($col::ZERO, n.x_axis);
+            n.as_mut()[0] = 1.0;
+            assert_eq!(1.0, n.x_axis.x);
+            should_panic!({
+                let mut n = n;
+                n.col_mut($c);
+            });
+        });
+
+        glam_test!(test_slice, {
+            let mut a = [0.0; $c * $r + 1];
+            let m = $mat::from_cols_slice(&matrix1d());
+            assert_eq!(matrix1d(), m.to_cols_array());
+            m.write_cols_to_slice(&mut a);
+            assert_eq!(matrix1d()[..], a[..$c * $r]);
+            assert_eq!(0.0, a[$c * $r]);
+            should_panic!({ $mat::from_cols_slice(&[0.0; $c * $r - 1]) });
+            should_panic!({ $mat::ZERO.write_cols_to_slice(&mut [0.0; $c * $r - 1]) });
+        });
+
+        glam_test!(test_transpose, {
+            let m = $mat::from_cols_array(&matrix1d());
+            let t: $transpose = m.transpose();
+            assert_eq!(m, t.transpose());
+            for i in 0..$r {
+                assert_eq!(m.row(i), t.col(i));
+                for j in 0..$c {
+                    assert_eq!(m.col(j)[i], m.row(i)[j]);
+                }
+            }
+        });
+
+        glam_test!(test_mul_vec, {
+            let m = $mat::from_cols_array(&matrix1d());
+            let v = $row::from_array(core::array::from_fn(|i| (i as f32) - 1.5));
+            let expected = $col::from_array(core::array::from_fn(|i| {
+                (0..$c).map(|j| matrix2d()[j][i] * v[j]).sum()
+            }));
+            assert_eq!(expected, m * v);
+            assert_eq!(expected, m.$mul_vec(v));
+
+            let u = $col::from_array(core::array::from_fn(|i| (i as f32) + 0.5));
+            assert_eq!(m.transpose() * u, m.$mul_transpose_vec(u));
+        });
+
+        glam_test!(test_mul_mat, {
+            let m = $mat::from_cols_array(&matrix1d());
+            assert_eq!(m, m * $matc::IDENTITY);
+            assert_eq!(m, $matr::IDENTITY * m);
+            assert_eq!(m * 2.0, m * $matc::from_diagonal($row::splat(2.0)));
+            assert_eq!(m * 2.0, $matr::from_diagonal($col::splat(2.0)) * m);
+
+            // Check against multiplying each column of the right hand side.
+            let a = $matc::from_cols_array(&core::array::from_fn(|i| (i as f32) * 0.5 - 1.0));
+            let b = m * a;
+            for j in 0..$c {
+                assert_eq!(m * a.col(j), b.col(j));
+            }
+            let a = $matr::from_cols_array(&core::array::from_fn(|i| (i as f32) * 0.5 - 1.0));
+            let b = a * m;
+            for j in 0..$c {
+                assert_eq!(a * m.col(j), b.col(j));
+            }
+
+            let t = $transpose::from_cols_array(&core::array::from_fn(|i| 2.0 - (i as f32)));
+            let p: $matr = m * t;
+            for j in 0..$r {
+                assert_eq!(m * t.col(j), p.col(j));
+            }
+            let v = $col::from_array(core::array::from_fn(|i| (i as f32) + 1.0));
+            assert_eq!(m * (t * v), p * v);
+            assert_eq!(p, m.$mul_mat_transpose(&t));
+
+            let mut n = m;
+            n *= $matc::from_diagonal($row::splat(3.0));
+            assert_eq!(m * 3.0, n);
+        });
+
+        glam_test!(test_ops, {
+            let m = $mat::from_cols_array(&matrix1d());
+            let m2 = $mat::from_cols_array(&matrix1d().map(|v| v * 2.0));
+            assert_eq!(m2, m + m);
+            assert_eq!(m, m2 - m);
+            assert_eq!(m2, m * 2.0);
+            assert_eq!(m2, 2.0 * m);
+            assert_eq!(m, m2 / 2.0);
+            assert_eq!($mat::ZERO, m + -m);
+            assert_eq!(m2, [m, m].iter().sum());
+            assert_eq!(m2, [m, m].into_iter().sum());
+
+            let mut n = m;
+            n += m;
+            assert_eq!(m2, n);
+            n -= m;
+            assert_eq!(m, n);
+            n *= 2.0;
+            assert_eq!(m2, n);
+            n /= 2.0;
+            assert_eq!(m, n);
+
+            assert_eq!(m, (-m).abs());
+            assert!(m.abs_diff_eq(m + $mat::from_cols_array(&[1.0e-6; $c * $r]), 1.0e-5));
+            assert!(!m.abs_diff_eq(m + $mat::from_cols_array(&[1.0e-4; $c * $r]), 1.0e-5));
+        });
+
+        glam_test!(test_affine, {
+            let a = affine();
+            let m = $mat::from(a);
+            assert_eq!(a, m.into());
+        });
+    };
+}
+
+mod mat2x3 {
+    use glam::{mat2x3, vec3, Affine2, Mat2, Mat2x3, Mat3, Mat3x2, Vec2, Vec3};
+
+    fn affine() -> Affine2 {
+        Affine2::from_scale_angle_translation(Vec2::new(2.0, 3.0), 0.5, Vec2::new(4.0, 5.0))
+    }
+
+    impl_mat_nonsquare_tests!(
+        Mat2x3,
+        Mat3x2,
+        Vec3,
+        Vec2,
+        Mat2,
+        Mat3,
+        2,
+        3,
+        mul_vec2,
+        mul_transpose_vec3,
+        mul_mat3x2
+    );
+
+    glam_test!(test_mat2x3_affine_rows, {
+        let a = affine();
+        let m = Mat2x3::from(a);
+        let p = Vec2::new(-1.0, 2.0);
+        let rows = Mat3::from(a).transpose();
+        assert_eq!(Mat2x3::from_cols(rows.x_axis, rows.y_axis), m);
+        assert!(a
+            .transform_point2(p)
+            .abs_diff_eq(m.mul_transpose_vec3(p.extend(1.0)), 1e-6));
+    });
+
+    glam_test!(test_mat2x3_fmt, {
+        let m = mat2x3(vec3(1.0, 2.0, 3.0), vec3(4.0, 5.0, 6.0));
+        assert_eq!(
+            format!("{:?}", m),
+            "Mat2x3 { x_axis: Vec3(1.0, 2.0, 3.0), y_axis: Vec3(4.0, 5.0, 6.0) }"
+        );
+        assert_eq!(format!("{}", m), "[[1, 2, 3], [4, 5, 6]]");
+        assert_eq!(format!("{:.1}", m), "[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]");
+    });
+}
+
+mod mat3x2 {
+    use glam::{mat3x2, vec2, Affine2, Mat2, Mat2x3, Mat3, Mat3x2, Vec2, Vec3};
+
+    fn affine() -> Affine2 {
+        Affine2::from_scale_angle_translation(Vec2::new(2.0, 3.0), 0.5, Vec2::new(4.0, 5.0))
+    }
+
+    impl_mat_nonsquare_tests!(
+        Mat3x2,
+        Mat2x3,
+        Vec2,
+        Vec3,
+        Mat3,
+        Mat2,
+        3,
+        2,
+        mul_vec3,
+        mul_transpose_vec2,
+        mul_mat2x3
+    );
+
+    glam_test!(test_mat3x2_affine_cols, {
+        let a = affine();
+        let m = Mat3x2::from(a);
+        let p = Vec2::new(-1.0, 2.0);
+        assert_eq!(a.to_cols_array(), m.to_cols_array());
+        assert!(a.transform_point2(p).abs_diff_eq(m * p.extend(1.0), 1e-6));
+        assert!(a.transform_vector2(p).abs_diff_eq(m * p.extend(0.0), 1e-6));
+    });
+
+    glam_test!(test_mat3x2_fmt, {
+        let m = mat3x2(vec2(1.0, 2.0), vec2(3.0, 4.0), vec2(5.0, 6.0));
+        assert_eq!(
+            format!("{:?}", m),
+            "Mat3x2 { x_axis: Vec2(1.0, 2.0), y_axis: Vec2(3.0, 4.0), z_axis: Vec2(5.0, 6.0) }"
+        );
+        assert_eq!(format!("{}", m), "[[1, 2], [3, 4], [5, 6]]");
+    });
+}
+
+mod mat3x4 {
+    use glam::{mat3x4, vec4, Affine3A, Mat3, Mat3x4, Mat4, Mat4x3, Quat, Vec3, Vec4};
+
+    fn affine() -> Affine3A {
+        Affine3A::from_scale_rotation_translation(
+            Vec3::new(2.0, 3.0, 4.0),
+            Quat::from_rotation_y(0.5),
+            Vec3::new(5.0, 6.0, 7.0),
+        )
+    }
+
+    impl_mat_nonsquare_tests!(
+        Mat3x4,
+        Mat4x3,
+        Vec4,
+        Vec3,
+        Mat3,
+        Mat4,
+        3,
+        4,
+        mul_vec3,
+        mul_transpose_vec4,
+        mul_mat4x3
+    );
+
+    glam_test!(test_mat3x4_affine_rows, {
+        let a = affine();
+        let m = Mat3x4::from(a);
+        let rows = Mat4::from(a).transpose();
+        assert_eq!(Mat3x4::from_cols(rows.x_axis, rows.y_axis, rows.z_axis), m);
+        for i in 0..3 {
+            assert_eq!(Mat4::from(a).row(i), m.col(i));
+        }
+
+        let p = Vec3::new(-1.0, 2.0, 0.5);
+        assert!(a
+            .transform_point3(p)
+            .abs_diff_eq(m.mul_transpose_vec4(p.extend(1.0)), 1e-6));
+    });
+
+    glam_test!(test_mat3x4_fmt, {
+        let m = mat3x4(
+            vec4(1.0, 2.0, 3.0, 4.0),
+            vec4(5.0, 6.0, 7.0, 8.0),
+            vec4(9.0, 10.0, 11.0, 12.0),
+        );
+        assert_eq!(
+            format!("{}", m),
+            "[[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]]"
+        );
+    });
+}
+
+mod mat4x3 {
+    use glam::{mat4x3, vec3, Affine3A, Mat3, Mat3x4, Mat4, Mat4x3, Quat, Vec3, Vec4};
+
+    fn affine() -> Affine3A {
+        Affine3A::from_scale_rotation_translation(
+            Vec3::new(2.0, 3.0, 4.0),
+            Quat::from_rotation_y(0.5),
+            Vec3::new(5.0, 6.0, 7.0),
+        )
+    }
+
+    impl_mat_nonsquare_tests!(
+        Mat4x3,
+        Mat3x4,
+        Vec3,
+        Vec4,
+        Mat4,
+        Mat3,
+        4,
+        3,
+        mul_vec4,
+        mul_transpose_vec3,
+        mul_mat3x4
+    );
+
+    glam_test!(test_mat4x3_affine_cols, {
+        let a = affine();
+        let m = Mat4x3::from(a);
+        let p = Vec3::new(-1.0, 2.0, 0.5);
+        assert_eq!(a.to_cols_array(), m.to_cols_array());
+        assert!(a.transform_point3(p).abs_diff_eq(m * p.extend(1.0), 1e-6));
+        assert!(a.transform_vector3(p).abs_diff_eq(m * p.extend(0.0), 1e-6));
+    });
+
+    glam_test!(test_mat4x3_fmt, {
+        let m = mat4x3(
+            vec3(1.0, 2.0, 3.0),
+            vec3(4.0, 5.0, 6.0),
+            vec3(7.0, 8.0, 9.0),
+            vec3(10.0, 11.0, 12.0),
+        );
+        assert_eq!(
+            format!("{}", m),
+            "[[1, 2, 3], [4, 5, 6], [7, 8, 9], [10, 11, 12]]"
+        );
+    });
+}
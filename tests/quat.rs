@@ -178,6 +178,46 @@ macro_rules! impl_quat_tests {
             }
         });
 
+        glam_test!(test_quat_exp_ln, {
+            assert_eq!($quat::IDENTITY, $quat::from_xyzw(0.0, 0.0, 0.0, 0.0).exp());
+            assert_eq!($quat::from_xyzw(0.0, 0.0, 0.0, 0.0), $quat::IDENTITY.ln());
+
+            for &v in &vec3_float_test_vectors!($vec3) {
+                let angle = v.length();
+                if angle < 2.0 * core::$t::consts::PI {
+                    let q = $quat::from_axis_angle(v / angle, angle);
+                    let half = v * 0.5;
+                    let pure = $quat::from_xyzw(half.x, half.y, half.z, 0.0);
+                    assert_approx_eq!(q, pure.exp());
+                    assert_approx_eq!(pure, q.ln(), 1.0e-6);
+                    assert_approx_eq!(q * 2.0, (q * 2.0).ln().exp(), 1.0e-6);
+                }
+            }
+
+            // Small angles keep their relative precision.
+            let v = $vec3::new(1.0e-9, -2.0e-9, 3.0e-9);
+            let q = $quat::from_xyzw(v.x, v.y, v.z, 0.0).exp();
+            assert!((q.xyz() - v).length() <= 1.0e-6 * v.length());
+            assert!((q.ln().xyz() - v).length() <= 1.0e-6 * v.length());
+
+            // A half turn has no preferred axis.
+            let ln = $quat::from_xyzw(0.0, 0.0, 0.0, -1.0).ln();
+            assert_approx_eq!($quat::from_xyzw(core::$t::consts::PI, 0.0, 0.0, 0.0), ln);
+            assert_approx_eq!($quat::from_xyzw(0.0, 0.0, 0.0, -1.0), ln.exp());
+        });
+
+        glam_test!(test_quat_powf, {
+            let axis = $vec3::new(1.0, 2.0, -3.0).normalize();
+            let q = $quat::from_axis_angle(axis, 2.5);
+            assert_approx_eq!($quat::from_axis_angle(axis, 1.25), q.powf(0.5), 1.0e-6);
+            assert_approx_eq!($quat::from_axis_angle(axis, 5.0), q.powf(2.0), 1.0e-6);
+            assert_approx_eq!(q * q * q, q.powf(3.0), 1.0e-6);
+            assert_approx_eq!(q.inverse(), q.powf(-1.0), 1.0e-6);
+            assert_approx_eq!(q, q.powf(1.0), 1.0e-6);
+            assert_approx_eq!($quat::IDENTITY, q.powf(0.0));
+            assert_eq!($quat::IDENTITY, $quat::IDENTITY.powf(0.3));
+        });
+
         glam_test!(test_quat_look_at, {
             let eye = $vec3::new(0.0, 0.0, -5.0);
             let center = $vec3::new(0.0, 0.0, 0.0);
@@ -784,6 +824,19 @@ mod quat {
         assert_approx_eq!(-Vec3A::X, mrzx.mul_vec3a(Vec3A::Y));
     });
 
+    glam_test!(test_rotate_slice, {
+        let vectors: Vec<Vec3> = (0..9)
+            .map(|i| Vec3::new(i as f32 - 4.0, (i * i) as f32 * 0.5, 2.0))
+            .collect();
+        let q = Quat::from_euler(EulerRot::ZYX, deg(30.0), deg(-60.0), deg(120.0));
+        let mut rotated = vectors.clone();
+        q.rotate_slice(&mut rotated);
+        for (v, r) in vectors.iter().zip(&rotated) {
+            assert_approx_eq!(q * *v, *r, 1e-5);
+        }
+        q.rotate_slice(&mut []);
+    });
+
     glam_test!(test_from_mat3a, {
         use glam::Mat3A;
         let yaw = deg(30.0);
@@ -820,5 +873,13 @@ mod dquat {
         assert_eq!(mem::align_of::<f64>(), mem::align_of::<DQuat>());
     });
 
+    #[cfg(feature = "const-float-arithmetic")]
+    glam_test!(test_const_mul_quat, {
+        const A: DQuat = DQuat::from_xyzw(0.5, 0.5, 0.5, 0.5);
+        const B: DQuat = DQuat::from_xyzw(0.0, 0.6, 0.0, 0.8);
+        const PRODUCT: DQuat = A.const_mul_quat(B);
+        assert_eq!(A * B, PRODUCT);
+    });
+
     impl_quat_tests!(f64, dquat, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4);
 }
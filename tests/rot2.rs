@@ -0,0 +1,252 @@
+#[macro_use]
+mod support;
+
+macro_rules! impl_rot2_tests {
+    ($t:ident, $rot2:ident, $vec2:ident, $mat2:ident, $affine2:ident) => {
+        use core::$t::consts::{FRAC_PI_2, PI};
+
+        impl FloatCompare for $rot2 {
+            #[inline]
+            fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+                self.abs_diff_eq(*other, max_abs_diff as $t)
+            }
+            #[inline]
+            fn abs_diff(&self, other: &Self) -> Self {
+                Self {
+                    cos: (self.cos - other.cos).abs(),
+                    sin: (self.sin - other.sin).abs(),
+                }
+            }
+        }
+
+        glam_test!(test_identity, {
+            assert_eq!($rot2::IDENTITY, $rot2::IDENTITY * $rot2::IDENTITY);
+            assert_eq!($rot2::IDENTITY, $rot2::default());
+            assert_eq!($rot2::IDENTITY, $rot2::from_angle(0.0));
+            assert_eq!($mat2::IDENTITY, $rot2::IDENTITY.to_mat2());
+
+            let v = $vec2::new(1.0, 2.0);
+            assert_eq!(v, $rot2::IDENTITY * v);
+        });
+
+        glam_test!(test_nan, {
+            assert!($rot2::NAN.is_nan());
+            assert!(!$rot2::NAN.is_finite());
+            assert!(!$rot2::IDENTITY.is_nan());
+            assert!($rot2::IDENTITY.is_finite());
+        });
+
+        glam_test!(test_array, {
+            const A: [$t; 2] = [0.6, 0.8];
+            const R: $rot2 = $rot2::from_array(A);
+            assert_eq!(0.6, R.cos);
+            assert_eq!(0.8, R.sin);
+            assert_eq!(A, R.to_array());
+            assert_eq!(A, <[$t; 2]>::from(R));
+            assert_eq!(R, $rot2::from(A));
+            assert_eq!(R, $rot2::from_sin_cos(0.8, 0.6));
+        });
+
+        glam_test!(test_from_angle, {
+            let r = $rot2::from_angle(deg(30.0));
+            assert_approx_eq!(deg(30.0), r.to_angle(), 1e-6);
+            assert_approx_eq!(1.0, r.length(), 1e-6);
+            assert!(r.is_normalized());
+
+            assert_approx_eq!(PI, $rot2::from_angle(PI).to_angle().abs(), 1e-6);
+            assert_approx_eq!(-FRAC_PI_2, $rot2::from_angle(-FRAC_PI_2).to_angle(), 1e-6);
+            assert_approx_eq!(deg(-90.0), $rot2::from_angle(deg(270.0)).to_angle(), 1e-5);
+        });
+
+        glam_test!(test_from_rotation_arc, {
+            let from = $vec2::X;
+            let to = $vec2::new(1.0, 1.0).normalize();
+            let r = $rot2::from_rotation_arc(from, to);
+            assert_approx_eq!(deg(45.0), r.to_angle(), 1e-6);
+            assert_approx_eq!(to, r * from, 1e-6);
+
+            let r = $rot2::from_rotation_arc(to, from);
+            assert_approx_eq!(deg(-45.0), r.to_angle(), 1e-6);
+
+            // opposite vectors are not a special case
+            let r = $rot2::from_rotation_arc($vec2::Y, $vec2::NEG_Y);
+            assert_approx_eq!(PI, r.to_angle().abs(), 1e-6);
+            assert_approx_eq!($vec2::NEG_Y, r * $vec2::Y, 1e-6);
+
+            let r = $rot2::from_rotation_arc(to, to);
+            assert_approx_eq!($rot2::IDENTITY, r, 1e-6);
+
+            should_glam_assert!({ $rot2::from_rotation_arc($vec2::ZERO, $vec2::X) });
+            should_glam_assert!({ $rot2::from_rotation_arc($vec2::X, $vec2::ONE) });
+        });
+
+        glam_test!(test_mul_vec2, {
+            let r = $rot2::from_angle(deg(90.0));
+            assert_approx_eq!($vec2::Y, r * $vec2::X, 1e-6);
+            assert_approx_eq!($vec2::NEG_X, r.mul_vec2($vec2::Y), 1e-6);
+
+            let r = $rot2::from_angle(deg(-25.0));
+            let m = $mat2::from_angle(deg(-25.0));
+            let v = $vec2::new(3.0, -4.0);
+            assert_approx_eq!(m * v, r * v, 1e-6);
+            assert_approx_eq!(m * v, &r * v, 1e-6);
+
+            should_glam_assert!({ $rot2::from_array([2.0, 0.0]) * $vec2::X });
+        });
+
+        glam_test!(test_mul_inverse, {
+            let r0 = $rot2::from_angle(deg(20.0));
+            let r1 = $rot2::from_angle(deg(45.0));
+            let r2 = r0 * r1;
+            assert_approx_eq!($rot2::from_angle(deg(65.0)), r2, 1e-6);
+            assert_approx_eq!(r2, r1 * r0, 1e-6);
+            assert_approx_eq!(r2, &r0 * &r1, 1e-6);
+            assert_approx_eq!(r2, r0 * &r1, 1e-6);
+            assert_approx_eq!(r2, &r0 * r1, 1e-6);
+
+            let mut r3 = r0;
+            r3 *= r1;
+            assert_approx_eq!(r2, r3, 1e-6);
+            let mut r3 = r0;
+            r3 *= &r1;
+            assert_approx_eq!(r2, r3, 1e-6);
+
+            let r4 = [r0, r1].iter().product::<$rot2>();
+            assert_approx_eq!(r2, r4, 1e-6);
+
+            assert_approx_eq!($rot2::IDENTITY, r2 * r2.inverse(), 1e-6);
+            assert_approx_eq!($rot2::from_angle(deg(-65.0)), r2.inverse(), 1e-6);
+            let v = $vec2::new(1.0, 2.0);
+            assert_approx_eq!(v, r2.inverse() * (r2 * v), 1e-6);
+
+            should_glam_assert!({ $rot2::from_array([2.0, 0.0]) * $rot2::IDENTITY });
+            should_glam_assert!({ $rot2::IDENTITY * $rot2::from_array([0.0, 0.0]) });
+        });
+
+        glam_test!(test_normalize, {
+            let r = $rot2::from_array([3.0, 4.0]);
+            assert_eq!(25.0, r.length_squared());
+            assert_eq!(5.0, r.length());
+            assert!(!r.is_normalized());
+            let n = r.normalize();
+            assert_approx_eq!($rot2::from_array([0.6, 0.8]), n, 1e-6);
+            assert!(n.is_normalized());
+
+            should_glam_assert!({ $rot2::from_array([0.0, 0.0]).normalize() });
+        });
+
+        glam_test!(test_angle_between, {
+            let r0 = $rot2::from_angle(deg(10.0));
+            let r1 = $rot2::from_angle(deg(50.0));
+            assert_approx_eq!(deg(40.0), r0.angle_between(r1), 1e-6);
+            assert_approx_eq!(deg(-40.0), r1.angle_between(r0), 1e-6);
+
+            // shortest signed angle across the -π/π boundary
+            let r0 = $rot2::from_angle(deg(170.0));
+            let r1 = $rot2::from_angle(deg(-170.0));
+            assert_approx_eq!(deg(20.0), r0.angle_between(r1), 1e-5);
+
+            // non unit inputs are fine
+            let r0 = $rot2::from_array([2.0, 0.0]);
+            let r1 = $rot2::from_array([0.0, 3.0]);
+            assert_approx_eq!(deg(90.0), r0.angle_between(r1), 1e-6);
+        });
+
+        glam_test!(test_slerp, {
+            let r0 = $rot2::from_angle(deg(10.0));
+            let r1 = $rot2::from_angle(deg(50.0));
+            assert_approx_eq!(r0, r0.slerp(r1, 0.0), 1e-6);
+            assert_approx_eq!(r1, r0.slerp(r1, 1.0), 1e-6);
+            assert_approx_eq!($rot2::from_angle(deg(30.0)), r0.slerp(r1, 0.5), 1e-6);
+            assert_approx_eq!($rot2::from_angle(deg(20.0)), r0.slerp(r1, 0.25), 1e-6);
+
+            // takes the shortest path
+            let r0 = $rot2::from_angle(deg(170.0));
+            let r1 = $rot2::from_angle(deg(-170.0));
+            assert_approx_eq!($rot2::from_angle(deg(180.0)), r0.slerp(r1, 0.5), 1e-6);
+
+            should_glam_assert!({ $rot2::from_array([2.0, 0.0]).slerp($rot2::IDENTITY, 0.5) });
+        });
+
+        glam_test!(test_conversions, {
+            let r = $rot2::from_angle(deg(-60.0));
+            let m = $mat2::from_angle(deg(-60.0));
+            assert_approx_eq!(m, r.to_mat2(), 1e-6);
+            assert_approx_eq!(m, $mat2::from(r), 1e-6);
+            assert_approx_eq!(r, $rot2::from_mat2(&m), 1e-6);
+
+            let a = r.to_affine2();
+            assert!($affine2::from_angle(deg(-60.0)).abs_diff_eq(a, 1e-6));
+            assert_eq!(a, $affine2::from(r));
+            assert_approx_eq!(r, $rot2::from_affine2(&a), 1e-6);
+
+            should_glam_assert!({ $rot2::from_mat2(&$mat2::ZERO) });
+        });
+
+        glam_test!(test_fmt, {
+            let a = $rot2::IDENTITY;
+            assert_eq!(format!("{}", a), "[1, 0]");
+            assert_eq!(format!("{:.1}", a), "[1.0, 0.0]");
+        });
+    };
+}
+
+mod rot2 {
+    use super::support::{deg, FloatCompare};
+    use glam::{Affine2, Mat2, Rot2, Vec2};
+
+    glam_test!(test_align, {
+        use std::mem;
+        assert_eq!(8, mem::size_of::<Rot2>());
+        if cfg!(feature = "scalar-math") {
+            assert_eq!(mem::align_of::<f32>(), mem::align_of::<Rot2>());
+        } else {
+            assert_eq!(8, mem::align_of::<Rot2>());
+        }
+    });
+
+    glam_test!(test_debug, {
+        assert_eq!(
+            format!("{:?}", Rot2::IDENTITY),
+            "Rot2 { cos: 1.0, sin: 0.0 }"
+        );
+    });
+
+    glam_test!(test_as, {
+        use glam::DRot2;
+        assert_eq!(
+            Rot2::from_array([0.6, 0.8]),
+            DRot2::from_array([0.6, 0.8]).as_rot2()
+        );
+        assert_eq!(
+            DRot2::from_array([0.5, 0.25]),
+            Rot2::from_array([0.5, 0.25]).as_drot2()
+        );
+    });
+
+    impl_rot2_tests!(f32, Rot2, Vec2, Mat2, Affine2);
+}
+
+mod drot2 {
+    use super::support::{deg, FloatCompare};
+    use glam::{DAffine2, DMat2, DRot2, DVec2};
+
+    glam_test!(test_align, {
+        use std::mem;
+        assert_eq!(16, mem::size_of::<DRot2>());
+        if cfg!(feature = "scalar-math") {
+            assert_eq!(mem::align_of::<f64>(), mem::align_of::<DRot2>());
+        } else {
+            assert_eq!(16, mem::align_of::<DRot2>());
+        }
+    });
+
+    glam_test!(test_debug, {
+        assert_eq!(
+            format!("{:?}", DRot2::IDENTITY),
+            "DRot2 { cos: 1.0, sin: 0.0 }"
+        );
+    });
+
+    impl_rot2_tests!(f64, DRot2, DVec2, DMat2, DAffine2);
+}
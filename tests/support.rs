@@ -4,8 +4,8 @@
 mod macros;
 
 use glam::{
-    DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4, Mat2, Mat3, Mat3A, Mat4, Quat, Vec2, Vec3,
-    Vec3A, Vec4,
+    DDualQuat, DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4, DualQuat, Mat2, Mat3, Mat3A, Mat4,
+    Quat, Vec2, Vec3, Vec3A, Vec4,
 };
 
 pub trait Deg {
@@ -38,6 +38,46 @@ pub fn rad<T>(angle: T) -> T {
     angle
 }
 
+/// Small xorshift generator so the tests don't depend on a rand crate.
+pub struct Rng(pub u64);
+
+impl Rng {
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// Mostly well behaved values in `[-100, 100]` with the occasional special case.
+    pub fn next_f64(&mut self) -> f64 {
+        let r = self.next_u64();
+        match r % 64 {
+            0 => f64::NAN,
+            1 => f64::INFINITY,
+            2 => f64::NEG_INFINITY,
+            3 => -0.0,
+            4 => 0.0,
+            5 => f64::MIN_POSITIVE / 4.0,
+            _ => ((r >> 11) as f64 / (1u64 << 53) as f64 - 0.5) * 200.0,
+        }
+    }
+
+    /// Uniformly distributed in `[-max, max]`.
+    pub fn uniform(&mut self, max: f32) -> f32 {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64 * 2.0 - 1.0) as f32 * max
+    }
+
+    /// Any positive finite value, including subnormals, with a uniformly distributed exponent.
+    pub fn positive(&mut self) -> f32 {
+        f32::from_bits((self.next_u64() >> 33) as u32 % 0x7f80_0000).max(f32::from_bits(1))
+    }
+
+    pub fn array<T, const N: usize>(&mut self, mut f: impl FnMut(&mut Self) -> T) -> [T; N] {
+        core::array::from_fn(|_| f(self))
+    }
+}
+
 /// Trait used by the `assert_approx_eq` macro for floating point comparisons.
 pub trait FloatCompare<Rhs: ?Sized = Self> {
     /// Return true if the absolute difference between `self` and `other` is
@@ -245,6 +285,34 @@ impl FloatCompare for DQuat {
     }
 }
 
+impl FloatCompare for DualQuat {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.abs_diff_eq(*other, max_abs_diff)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        Self::from_real_dual(
+            self.real.abs_diff(&other.real),
+            self.dual.abs_diff(&other.dual),
+        )
+    }
+}
+
+impl FloatCompare for DDualQuat {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.abs_diff_eq(*other, max_abs_diff as f64)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        Self::from_real_dual(
+            self.real.abs_diff(&other.real),
+            self.dual.abs_diff(&other.dual),
+        )
+    }
+}
+
 impl FloatCompare for DVec2 {
     #[inline]
     fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
@@ -0,0 +1,602 @@
+#[macro_use]
+mod support;
+
+macro_rules! impl_transform2_tests {
+    ($t:ident, $transform:ident, $vec2:ident, $mat3:ident, $affine2:ident) => {
+        impl FloatCompare for $transform {
+            #[inline]
+            fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+                self.abs_diff_eq(*other, max_abs_diff as $t)
+            }
+            #[inline]
+            fn abs_diff(&self, other: &Self) -> Self {
+                Self {
+                    scale: self.scale.abs_diff(&other.scale),
+                    angle: (self.angle - other.angle).abs(),
+                    translation: self.translation.abs_diff(&other.translation),
+                }
+            }
+        }
+
+        glam_test!(test_identity, {
+            assert_eq!(
+                $transform::IDENTITY,
+                $transform::IDENTITY * $transform::IDENTITY
+            );
+            assert_eq!($transform::IDENTITY, $transform::default());
+
+            let p = $vec2::new(1.0, 2.0);
+            assert_eq!(p, $transform::IDENTITY.transform_point2(p));
+            assert_eq!(p, $transform::IDENTITY.transform_vector2(p));
+            assert_eq!($mat3::IDENTITY, $transform::IDENTITY.to_mat3());
+        });
+
+        glam_test!(test_nan, {
+            assert!($transform::NAN.is_nan());
+            assert!(!$transform::NAN.is_finite());
+            assert!(!$transform::IDENTITY.is_nan());
+            assert!($transform::IDENTITY.is_finite());
+        });
+
+        glam_test!(test_array, {
+            const A: [$t; 5] = [1.0, 2.0, 3.0, 4.0, 5.0];
+            const T: $transform = $transform::from_array(A);
+            assert_eq!($vec2::new(1.0, 2.0), T.scale);
+            assert_eq!(3.0, T.angle);
+            assert_eq!($vec2::new(4.0, 5.0), T.translation);
+            assert_eq!(A, T.to_array());
+            assert_eq!(A, <[$t; 5]>::from(T));
+            assert_eq!(T, $transform::from(A));
+        });
+
+        glam_test!(test_from_scale_angle_translation, {
+            let scale = $vec2::new(2.0, 3.0);
+            let translation = $vec2::new(1.0, -2.0);
+            let t = $transform::from_scale_angle_translation(scale, deg(30.0), translation);
+            assert_eq!(
+                (scale, deg(30.0), translation),
+                t.to_scale_angle_translation()
+            );
+            assert_eq!(
+                t,
+                $transform::from_translation(translation)
+                    * $transform::from_angle(deg(30.0))
+                    * $transform::from_scale(scale)
+            );
+        });
+
+        glam_test!(test_transform, {
+            let t = $transform::from_scale_angle_translation(
+                $vec2::new(2.0, 0.5),
+                deg(90.0),
+                $vec2::new(1.0, 2.0),
+            );
+            let p = $vec2::new(3.0, -4.0);
+            assert_approx_eq!($vec2::new(3.0, 8.0), t.transform_point2(p), 1e-6);
+            assert_approx_eq!($vec2::new(2.0, 6.0), t.transform_vector2(p), 1e-6);
+
+            let a = t.to_affine2();
+            assert_approx_eq!(a.transform_point2(p), t.transform_point2(p), 1e-6);
+            assert_approx_eq!(a.transform_vector2(p), t.transform_vector2(p), 1e-6);
+        });
+
+        glam_test!(test_conversions, {
+            let scale = $vec2::new(2.0, 3.0);
+            let translation = $vec2::new(3.0, 1.0);
+            let t = $transform::from_scale_angle_translation(scale, deg(-45.0), translation);
+            let a = $affine2::from_scale_angle_translation(scale, deg(-45.0), translation);
+            assert_eq!(a, t.to_affine2());
+            assert_eq!(a, $affine2::from(t));
+            assert_approx_eq!(t, $transform::from_affine2(&a), 1e-6);
+
+            let m = $mat3::from_scale_angle_translation(scale, deg(-45.0), translation);
+            assert_eq!(m, t.to_mat3());
+            assert_eq!(m, $mat3::from(t));
+            assert_approx_eq!(t, $transform::from_mat3(&m), 1e-6);
+
+            should_glam_assert!({ $transform::from_affine2(&$affine2::ZERO) });
+            should_glam_assert!({ $transform::from_mat3(&$mat3::ZERO) });
+        });
+
+        glam_test!(test_mul_inverse, {
+            let t0 = $transform::from_scale_angle_translation(
+                $vec2::splat(2.0),
+                deg(30.0),
+                $vec2::new(1.0, 2.0),
+            );
+            let t1 = $transform::from_scale_angle_translation(
+                $vec2::new(0.5, 3.0),
+                deg(-75.0),
+                $vec2::new(-3.0, 0.5),
+            );
+            let t = t0 * t1;
+            assert_eq!(t, t0.mul_transform(&t1));
+            assert_approx_eq!(t0.to_mat3() * t1.to_mat3(), t.to_mat3(), 1e-5);
+            assert_approx_eq!(deg(-45.0), t.angle, 1e-6);
+
+            let mut t2 = t0;
+            t2 *= t1;
+            assert_eq!(t, t2);
+            assert_eq!(t, [t0, t1].iter().product());
+
+            let inv = t0.inverse();
+            assert_approx_eq!($transform::IDENTITY, t0 * inv, 1e-6);
+            assert_approx_eq!($transform::IDENTITY, inv * t0, 1e-6);
+            assert_approx_eq!(t0.to_mat3().inverse(), inv.to_mat3(), 1e-6);
+
+            should_glam_assert!({ $transform::from_scale($vec2::new(1.0, 0.0)).inverse() });
+        });
+
+        glam_test!(test_non_uniform_scale, {
+            let parent = $transform::from_scale_angle_translation(
+                $vec2::new(2.0, 1.0),
+                deg(30.0),
+                $vec2::new(1.0, 2.0),
+            );
+
+            // exact when the child does not rotate
+            let child = $transform::from_scale_angle_translation(
+                $vec2::new(3.0, 0.5),
+                0.0,
+                $vec2::new(-1.0, 4.0),
+            );
+            assert_approx_eq!(
+                parent.to_mat3() * child.to_mat3(),
+                (parent * child).to_mat3(),
+                1e-5
+            );
+
+            // otherwise the shear of the true product is discarded
+            let child = $transform::from_angle(deg(45.0));
+            assert!(
+                !(parent.to_mat3() * child.to_mat3()).abs_diff_eq((parent * child).to_mat3(), 1e-2)
+            );
+            assert!(!parent
+                .to_mat3()
+                .inverse()
+                .abs_diff_eq(parent.inverse().to_mat3(), 1e-2));
+
+            // a non-uniform scale without rotation is inverted exactly
+            let p = $vec2::new(3.0, -4.0);
+            let inv = $transform::from_scale($vec2::new(2.0, 1.0)).inverse();
+            assert_approx_eq!(
+                p,
+                inv.transform_point2(
+                    $transform::from_scale($vec2::new(2.0, 1.0)).transform_point2(p)
+                ),
+                1e-6
+            );
+        });
+
+        glam_test!(test_lerp, {
+            let t0 = $transform::from_scale_angle_translation(
+                $vec2::new(1.0, 2.0),
+                deg(10.0),
+                $vec2::new(0.0, 2.0),
+            );
+            let t1 = $transform::from_scale_angle_translation(
+                $vec2::new(3.0, 4.0),
+                deg(70.0),
+                $vec2::new(4.0, -2.0),
+            );
+            assert_approx_eq!(t0, t0.lerp(t1, 0.0), 1e-6);
+            assert_approx_eq!(t1, t0.lerp(t1, 1.0), 1e-6);
+            assert_approx_eq!(
+                $transform::from_scale_angle_translation(
+                    $vec2::new(2.0, 3.0),
+                    deg(40.0),
+                    $vec2::new(2.0, 0.0),
+                ),
+                t0.lerp(t1, 0.5),
+                1e-6
+            );
+
+            // takes the shortest path across the discontinuity at ±180 degrees
+            let t0 = $transform::from_angle(deg(170.0));
+            let t1 = $transform::from_angle(deg(-170.0));
+            assert_approx_eq!(deg(180.0), t0.lerp(t1, 0.5).angle, 1e-5);
+        });
+
+        glam_test!(test_fmt, {
+            let a = $transform::IDENTITY;
+            assert_eq!(format!("{}", a), "[[1, 1], 0, [0, 0]]");
+            assert_eq!(format!("{:.1}", a), "[[1.0, 1.0], 0.0, [0.0, 0.0]]");
+        });
+    };
+}
+
+macro_rules! impl_transform3_tests {
+    ($t:ident, $transform:ident, $quat:ident, $vec3:ident, $mat4:ident, $affine3:ident, $from_affine:ident, $to_affine:ident) => {
+        impl FloatCompare for $transform {
+            #[inline]
+            fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+                self.abs_diff_eq(*other, max_abs_diff as $t)
+            }
+            #[inline]
+            fn abs_diff(&self, other: &Self) -> Self {
+                Self {
+                    scale: self.scale.abs_diff(&other.scale),
+                    rotation: self.rotation.abs_diff(&other.rotation),
+                    translation: self.translation.abs_diff(&other.translation),
+                }
+            }
+        }
+
+        glam_test!(test_identity, {
+            assert_eq!(
+                $transform::IDENTITY,
+                $transform::IDENTITY * $transform::IDENTITY
+            );
+            assert_eq!($transform::IDENTITY, $transform::default());
+
+            let p = $vec3::new(1.0, 2.0, 3.0);
+            assert_eq!(p, $transform::IDENTITY.transform_point3(p));
+            assert_eq!(p, $transform::IDENTITY.transform_vector3(p));
+            assert_eq!($mat4::IDENTITY, $transform::IDENTITY.to_mat4());
+        });
+
+        glam_test!(test_nan, {
+            assert!($transform::NAN.is_nan());
+            assert!(!$transform::NAN.is_finite());
+            assert!(!$transform::IDENTITY.is_nan());
+            assert!($transform::IDENTITY.is_finite());
+        });
+
+        glam_test!(test_array, {
+            const A: [$t; 10] = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0];
+            const T: $transform = $transform::from_array(A);
+            assert_eq!($vec3::new(1.0, 2.0, 3.0), T.scale);
+            assert_eq!($quat::from_xyzw(4.0, 5.0, 6.0, 7.0), T.rotation);
+            assert_eq!($vec3::new(8.0, 9.0, 10.0), T.translation);
+            assert_eq!(A, T.to_array());
+            assert_eq!(A, <[$t; 10]>::from(T));
+            assert_eq!(T, $transform::from(A));
+        });
+
+        glam_test!(test_from_scale_rotation_translation, {
+            let scale = $vec3::new(2.0, 3.0, 4.0);
+            let rotation = $quat::from_rotation_y(deg(90.0));
+            let translation = $vec3::new(1.0, 2.0, 3.0);
+            let t = $transform::from_scale_rotation_translation(scale, rotation, translation);
+            assert_eq!(
+                (scale, rotation, translation),
+                t.to_scale_rotation_translation()
+            );
+            assert_eq!(
+                t,
+                $transform::from_translation(translation)
+                    * $transform::from_quat(rotation)
+                    * $transform::from_scale(scale)
+            );
+            assert_eq!(
+                $transform::from_scale_rotation_translation($vec3::ONE, rotation, translation),
+                $transform::from_rotation_translation(rotation, translation)
+            );
+
+            should_glam_assert!({
+                $transform::from_scale_rotation_translation(
+                    $vec3::ONE,
+                    $quat::IDENTITY * 2.0,
+                    $vec3::ZERO,
+                )
+            });
+            should_glam_assert!({ $transform::from_quat($quat::IDENTITY * 2.0) });
+        });
+
+        glam_test!(test_transform, {
+            let scale = $vec3::new(2.0, 0.5, -1.0);
+            let rotation = $quat::from_euler(glam::EulerRot::YXZ, deg(30.0), deg(45.0), deg(60.0));
+            let translation = $vec3::new(-1.0, 2.0, 0.5);
+            let t = $transform::from_scale_rotation_translation(scale, rotation, translation);
+            let m = $mat4::from_scale_rotation_translation(scale, rotation, translation);
+            let a = $affine3::from_scale_rotation_translation(scale, rotation, translation);
+
+            let p = $vec3::new(3.0, -4.0, 5.0);
+            assert_approx_eq!(m.transform_point3(p), t.transform_point3(p), 1e-5);
+            assert_approx_eq!(m.transform_vector3(p), t.transform_vector3(p), 1e-5);
+            assert_approx_eq!(a.transform_point3(p), t.transform_point3(p), 1e-5);
+            assert_approx_eq!(a.transform_vector3(p), t.transform_vector3(p), 1e-5);
+        });
+
+        glam_test!(test_conversions, {
+            let scale = $vec3::new(2.0, 3.0, 4.0);
+            let rotation = $quat::from_rotation_z(deg(-120.0));
+            let translation = $vec3::new(4.0, 5.0, 6.0);
+            let t = $transform::from_scale_rotation_translation(scale, rotation, translation);
+
+            let m = $mat4::from_scale_rotation_translation(scale, rotation, translation);
+            assert_eq!(m, t.to_mat4());
+            assert_eq!(m, $mat4::from(t));
+            let from_mat4 = $transform::from_mat4(&m);
+            assert_approx_eq!(m, from_mat4.to_mat4(), 1e-5);
+            assert_approx_eq!(scale, from_mat4.scale, 1e-5);
+            assert_approx_eq!(t.rotation.dot(from_mat4.rotation).abs(), 1.0, 1e-6);
+
+            let a = $affine3::from_scale_rotation_translation(scale, rotation, translation);
+            assert_eq!(a, t.$to_affine());
+            assert_eq!(a, $affine3::from(t));
+            let from_affine = $transform::$from_affine(&a);
+            assert!(a.abs_diff_eq(from_affine.$to_affine(), 1e-5));
+            assert_approx_eq!(t.rotation.dot(from_affine.rotation).abs(), 1.0, 1e-6);
+
+            should_glam_assert!({ $transform::from_mat4(&$mat4::ZERO) });
+            should_glam_assert!({ $transform::$from_affine(&$affine3::ZERO) });
+        });
+
+        glam_test!(test_mul_inverse, {
+            let t0 = $transform::from_scale_rotation_translation(
+                $vec3::splat(2.0),
+                $quat::from_rotation_x(deg(45.0)),
+                $vec3::new(1.0, 0.0, -2.0),
+            );
+            let t1 = $transform::from_scale_rotation_translation(
+                $vec3::new(0.5, 3.0, 1.5),
+                $quat::from_rotation_y(deg(-30.0)),
+                $vec3::new(0.0, 3.0, 1.0),
+            );
+            let t = t0 * t1;
+            assert_eq!(t, t0.mul_transform(&t1));
+            assert_approx_eq!(t0.to_mat4() * t1.to_mat4(), t.to_mat4(), 1e-5);
+
+            let p = $vec3::new(2.0, -1.0, 0.5);
+            assert_approx_eq!(
+                t0.transform_point3(t1.transform_point3(p)),
+                t.transform_point3(p),
+                1e-5
+            );
+
+            let mut t2 = t0;
+            t2 *= t1;
+            assert_eq!(t, t2);
+            assert_eq!(t, [t0, t1].iter().product());
+
+            let inv = t0.inverse();
+            assert_approx_eq!($transform::IDENTITY, t0 * inv, 1e-6);
+            assert_approx_eq!($transform::IDENTITY, inv * t0, 1e-6);
+            assert_approx_eq!(t0.to_mat4().inverse(), inv.to_mat4(), 1e-5);
+
+            should_glam_assert!({ $transform::from_scale($vec3::new(1.0, 0.0, 1.0)).inverse() });
+        });
+
+        glam_test!(test_non_uniform_scale, {
+            let parent = $transform::from_scale_rotation_translation(
+                $vec3::new(2.0, 1.0, 0.5),
+                $quat::from_rotation_z(deg(30.0)),
+                $vec3::new(1.0, 2.0, 3.0),
+            );
+
+            // exact when the child does not rotate
+            let child = $transform::from_scale_rotation_translation(
+                $vec3::new(3.0, 0.5, 2.0),
+                $quat::IDENTITY,
+                $vec3::new(-1.0, 4.0, 1.0),
+            );
+            assert_approx_eq!(
+                parent.to_mat4() * child.to_mat4(),
+                (parent * child).to_mat4(),
+                1e-5
+            );
+
+            // otherwise the shear of the true product is discarded
+            let child = $transform::from_quat($quat::from_rotation_x(deg(45.0)));
+            assert!(
+                !(parent.to_mat4() * child.to_mat4()).abs_diff_eq((parent * child).to_mat4(), 1e-2)
+            );
+            assert!(!parent
+                .to_mat4()
+                .inverse()
+                .abs_diff_eq(parent.inverse().to_mat4(), 1e-2));
+
+            // a non-uniform scale without rotation is inverted exactly
+            let p = $vec3::new(3.0, -4.0, 5.0);
+            let scale = $transform::from_scale($vec3::new(2.0, 1.0, 0.5));
+            assert_approx_eq!(
+                p,
+                scale.inverse().transform_point3(scale.transform_point3(p)),
+                1e-6
+            );
+        });
+
+        glam_test!(test_lerp_slerp, {
+            let t0 = $transform::from_scale_rotation_translation(
+                $vec3::new(1.0, 2.0, 3.0),
+                $quat::from_rotation_y(deg(20.0)),
+                $vec3::new(0.0, 2.0, 0.0),
+            );
+            let t1 = $transform::from_scale_rotation_translation(
+                $vec3::new(3.0, 4.0, 5.0),
+                $quat::from_rotation_y(deg(80.0)),
+                $vec3::new(4.0, -2.0, 6.0),
+            );
+            assert_approx_eq!(t0, t0.lerp(t1, 0.0), 1e-6);
+            assert_approx_eq!(t1, t0.lerp(t1, 1.0), 1e-6);
+            assert_approx_eq!(t0, t0.slerp(t1, 0.0), 1e-6);
+            assert_approx_eq!(t1, t0.slerp(t1, 1.0), 1e-6);
+
+            let mid = $transform::from_scale_rotation_translation(
+                $vec3::new(2.0, 3.0, 4.0),
+                $quat::from_rotation_y(deg(50.0)),
+                $vec3::new(2.0, 0.0, 3.0),
+            );
+            assert_approx_eq!(mid, t0.lerp(t1, 0.5), 1e-6);
+            assert_approx_eq!(mid, t0.slerp(t1, 0.5), 1e-6);
+
+            let quarter = t0.slerp(t1, 0.25);
+            assert_approx_eq!($quat::from_rotation_y(deg(35.0)), quarter.rotation, 1e-6);
+            assert_approx_eq!($vec3::new(1.5, 2.5, 3.5), quarter.scale);
+            assert_approx_eq!($vec3::new(1.0, 1.0, 1.5), quarter.translation);
+        });
+
+        glam_test!(test_look_at, {
+            let eye = $vec3::new(1.0, 2.0, 3.0);
+            let mut t = $transform::from_scale_rotation_translation(
+                $vec3::splat(2.0),
+                $quat::from_rotation_x(deg(10.0)),
+                eye,
+            );
+            t.look_at(eye - $vec3::Z, $vec3::Y);
+            assert_approx_eq!($quat::IDENTITY, t.rotation, 1e-6);
+            assert_eq!($vec3::splat(2.0), t.scale);
+            assert_eq!(eye, t.translation);
+
+            t.look_at(eye + $vec3::new(5.0, 0.0, 0.0), $vec3::Y);
+            assert_approx_eq!($vec3::X, t.rotation * -$vec3::Z, 1e-6);
+            assert_approx_eq!($vec3::Y, t.rotation * $vec3::Y, 1e-6);
+
+            t.look_to($vec3::new(0.0, -3.0, 0.0), $vec3::Z);
+            assert_approx_eq!(-$vec3::Y, t.rotation * -$vec3::Z, 1e-6);
+            assert_approx_eq!($vec3::Z, t.rotation * $vec3::Y, 1e-6);
+
+            // the inverse of the equivalent view transform
+            let target = $vec3::new(-2.0, 0.5, 1.0);
+            let mut t = $transform::from_translation(eye);
+            t.look_at(target, $vec3::Y);
+            assert!($affine3::look_at_rh(eye, target, $vec3::Y)
+                .inverse()
+                .abs_diff_eq(t.$to_affine(), 1e-5));
+
+            should_glam_assert!({
+                let mut t = $transform::IDENTITY;
+                t.look_at($vec3::X, $vec3::Y * 2.0)
+            });
+        });
+
+        glam_test!(test_fmt, {
+            let a = $transform::IDENTITY;
+            assert_eq!(format!("{}", a), "[[1, 1, 1], [0, 0, 0, 1], [0, 0, 0]]");
+            assert_eq!(
+                format!("{:.1}", a),
+                "[[1.0, 1.0, 1.0], [0.0, 0.0, 0.0, 1.0], [0.0, 0.0, 0.0]]"
+            );
+        });
+    };
+}
+
+mod transform2 {
+    use super::support::{deg, FloatCompare};
+    use glam::{Affine2, Mat3, Transform2, Vec2};
+
+    glam_test!(test_align, {
+        use std::mem;
+        assert_eq!(20, mem::size_of::<Transform2>());
+        assert_eq!(mem::align_of::<f32>(), mem::align_of::<Transform2>());
+    });
+
+    glam_test!(test_debug, {
+        assert_eq!(
+            format!("{:?}", Transform2::IDENTITY),
+            "Transform2 { scale: Vec2(1.0, 1.0), angle: 0.0, translation: Vec2(0.0, 0.0) }"
+        );
+    });
+
+    glam_test!(test_as, {
+        use glam::DTransform2;
+        assert_eq!(
+            Transform2::from_array([1.0, 2.0, 3.0, 4.0, 5.0]),
+            DTransform2::from_array([1.0, 2.0, 3.0, 4.0, 5.0]).as_transform2()
+        );
+        assert_eq!(
+            DTransform2::from_array([1.0, 2.0, 3.0, 4.0, 5.0]),
+            Transform2::from_array([1.0, 2.0, 3.0, 4.0, 5.0]).as_dtransform2()
+        );
+    });
+
+    impl_transform2_tests!(f32, Transform2, Vec2, Mat3, Affine2);
+}
+
+mod dtransform2 {
+    use super::support::{deg, FloatCompare};
+    use glam::{DAffine2, DMat3, DTransform2, DVec2};
+
+    glam_test!(test_align, {
+        use std::mem;
+        assert_eq!(40, mem::size_of::<DTransform2>());
+        assert_eq!(mem::align_of::<f64>(), mem::align_of::<DTransform2>());
+    });
+
+    glam_test!(test_debug, {
+        assert_eq!(
+            format!("{:?}", DTransform2::IDENTITY),
+            "DTransform2 { scale: DVec2(1.0, 1.0), angle: 0.0, translation: DVec2(0.0, 0.0) }"
+        );
+    });
+
+    impl_transform2_tests!(f64, DTransform2, DVec2, DMat3, DAffine2);
+}
+
+mod transform3 {
+    use super::support::{deg, FloatCompare};
+    use glam::{Affine3A, Mat4, Quat, Transform3, Vec3};
+
+    glam_test!(test_align, {
+        use std::mem;
+        if cfg!(not(feature = "scalar-math")) {
+            assert_eq!(48, mem::size_of::<Transform3>());
+            assert_eq!(16, mem::align_of::<Transform3>());
+        } else {
+            assert_eq!(40, mem::size_of::<Transform3>());
+            assert_eq!(mem::align_of::<f32>(), mem::align_of::<Transform3>());
+        }
+    });
+
+    glam_test!(test_debug, {
+        assert_eq!(
+            format!("{:?}", Transform3::IDENTITY),
+            "Transform3 { scale: Vec3(1.0, 1.0, 1.0), rotation: Quat(0.0, 0.0, 0.0, 1.0), translation: Vec3(0.0, 0.0, 0.0) }"
+        );
+    });
+
+    glam_test!(test_as, {
+        use glam::DTransform3;
+        const A: [f32; 10] = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0];
+        const D: [f64; 10] = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0];
+        assert_eq!(
+            Transform3::from_array(A),
+            DTransform3::from_array(D).as_transform3()
+        );
+        assert_eq!(
+            DTransform3::from_array(D),
+            Transform3::from_array(A).as_dtransform3()
+        );
+    });
+
+    impl_transform3_tests!(
+        f32,
+        Transform3,
+        Quat,
+        Vec3,
+        Mat4,
+        Affine3A,
+        from_affine3a,
+        to_affine3a
+    );
+}
+
+mod dtransform3 {
+    use super::support::{deg, FloatCompare};
+    use glam::{DAffine3, DMat4, DQuat, DTransform3, DVec3};
+
+    glam_test!(test_align, {
+        use std::mem;
+        assert_eq!(80, mem::size_of::<DTransform3>());
+        assert_eq!(mem::align_of::<f64>(), mem::align_of::<DTransform3>());
+    });
+
+    glam_test!(test_debug, {
+        assert_eq!(
+            format!("{:?}", DTransform3::IDENTITY),
+            "DTransform3 { scale: DVec3(1.0, 1.0, 1.0), rotation: DQuat(0.0, 0.0, 0.0, 1.0), translation: DVec3(0.0, 0.0, 0.0) }"
+        );
+    });
+
+    impl_transform3_tests!(
+        f64,
+        DTransform3,
+        DQuat,
+        DVec3,
+        DMat4,
+        DAffine3,
+        from_affine3,
+        to_affine3
+    );
+}
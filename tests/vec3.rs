@@ -2483,13 +2483,16 @@ macro_rules! impl_vec3_shift_op_test {
 
 macro_rules! impl_vec3_shift_op_tests {
     ($vec3:ident) => {
+        impl_vec3_shift_op_tests!($vec3, IVec3, UVec3);
+    };
+    ($vec3:ident, $ivec3:ident, $uvec3:ident) => {
         mod shift_ivec3_by_ivec3 {
             use super::*;
-            impl_vec3_shift_op_test!($vec3, IVec3, 0, 2);
+            impl_vec3_shift_op_test!($vec3, $ivec3, 0, 2);
         }
         mod shift_ivec3_by_uvec3 {
             use super::*;
-            impl_vec3_shift_op_test!($vec3, UVec3, 0, 2);
+            impl_vec3_shift_op_test!($vec3, $uvec3, 0, 2);
         }
     };
 }
@@ -2651,6 +2654,22 @@ mod vec3 {
         assert_eq!(Vec3A::new(1.0, 2.0, 3.0), v.to_vec3a());
     });
 
+    #[cfg(feature = "const-float-arithmetic")]
+    glam_test!(test_const_arithmetic, {
+        const A: Vec3 = Vec3::new(1.5, -2.0, 3.25);
+        const B: Vec3 = Vec3::new(-4.0, 0.5, 6.0);
+        const SUM: Vec3 = A.const_add(B);
+        const DIFF: Vec3 = A.const_sub(B);
+        const PRODUCT: Vec3 = A.const_mul(B);
+        const DOT: f32 = A.const_dot(B);
+        const CROSS: Vec3 = A.const_cross(B);
+        assert_eq!(A + B, SUM);
+        assert_eq!(A - B, DIFF);
+        assert_eq!(A * B, PRODUCT);
+        assert_eq!(A.dot(B), DOT);
+        assert_eq!(A.cross(B), CROSS);
+    });
+
     impl_vec3_float_tests!(f32, vec3, Vec3, BVec3, Quat);
 }
 
@@ -3660,9 +3679,111 @@ mod ivec3 {
     impl_vec3_shift_op_tests!(IVec3);
 
     impl_vec3_scalar_bit_op_tests!(IVec3, -2, 2);
+
+    glam_test!(test_const_arithmetic, {
+        const A: IVec3 = IVec3::new(1, -2, 3);
+        const B: IVec3 = IVec3::new(-4, 5, 6);
+        const SUM: IVec3 = A.const_add(B);
+        const DIFF: IVec3 = A.const_sub(B);
+        const PRODUCT: IVec3 = A.const_mul(B);
+        const DOT: i32 = A.const_dot(B);
+        const CROSS: IVec3 = A.const_cross(B);
+        assert_eq!(A + B, SUM);
+        assert_eq!(A - B, DIFF);
+        assert_eq!(A * B, PRODUCT);
+        assert_eq!(A.dot(B), DOT);
+        assert_eq!(A.cross(B), CROSS);
+    });
+
     impl_vec3_bit_op_tests!(IVec3, -2, 2);
 }
 
+mod ivec3a {
+    use glam::{ivec3a, BVec3A, IVec3, IVec3A, IVec4, UVec3A};
+
+    glam_test!(test_align, {
+        use std::mem;
+        assert_eq!(16, mem::size_of::<IVec3A>());
+        assert_eq!(16, mem::align_of::<IVec3A>());
+    });
+
+    glam_test!(test_mask_align16, {
+        // make sure the unused 'w' value doesn't break IVec3A behaviour
+        let a = IVec4::ZERO;
+        let mut b = IVec3A::from_vec4(a);
+        b.x = 1;
+        b.y = 1;
+        b.z = 1;
+        assert!(!b.cmpeq(IVec3A::ZERO).any());
+        assert!(b.cmpeq(IVec3A::splat(1)).all());
+        assert_eq!(IVec3A::ONE, b);
+    });
+
+    #[cfg(all(
+        target_feature = "sse2",
+        feature = "int-simd",
+        not(any(feature = "core-simd", feature = "scalar-math"))
+    ))]
+    #[test]
+    fn test_m128i() {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::*;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::*;
+
+        #[repr(C, align(16))]
+        struct A16([i32; 4]);
+
+        let v0 = IVec3A::new(-1, 2, -3);
+        let m0: __m128i = v0.into();
+        let mut a0 = A16([0; 4]);
+        unsafe {
+            _mm_store_si128(a0.0.as_mut_ptr().cast(), m0);
+        }
+        assert_eq!([-1, 2, -3], [a0.0[0], a0.0[1], a0.0[2]]);
+        let v1 = IVec3A::from(m0);
+        assert_eq!(v0, v1);
+    }
+
+    glam_test!(test_min_max_from_vec4, {
+        // checks that the 4th element is unused.
+        let v1 = IVec3A::from_vec4(IVec4::new(1, 2, 3, 4));
+        assert_eq!(v1.max_element(), 3);
+        assert_eq!(v1.element_sum(), 6);
+        assert_eq!(v1.element_product(), 6);
+        let v2 = IVec3A::from_vec4(IVec4::new(4, 3, 2, -1));
+        assert_eq!(v2.min_element(), 2);
+        assert_eq!(v2.is_negative_bitmask(), 0);
+    });
+
+    glam_test!(test_to_ivec3, {
+        let v = IVec3A::new(1, 2, 3);
+        assert_eq!(IVec3::new(1, 2, 3), v.to_ivec3());
+        assert_eq!(IVec3::new(1, 2, 3), IVec3::from(v));
+        assert_eq!(v, IVec3::new(1, 2, 3).to_ivec3a());
+        assert_eq!(v, IVec3A::from(IVec3::new(1, 2, 3)));
+    });
+
+    glam_test!(test_try_from, {
+        assert_eq!(
+            IVec3A::new(1, 2, 3),
+            IVec3A::try_from(UVec3A::new(1, 2, 3)).unwrap()
+        );
+        assert!(IVec3A::try_from(UVec3A::new(u32::MAX, 2, 3)).is_err());
+        assert!(IVec3A::try_from(UVec3A::new(1, u32::MAX, 3)).is_err());
+        assert!(IVec3A::try_from(UVec3A::new(1, 2, u32::MAX)).is_err());
+    });
+
+    impl_vec3_signed_integer_tests!(i32, ivec3a, IVec3A, BVec3A);
+    impl_vec3_eq_hash_tests!(i32, ivec3a);
+
+    impl_vec3_scalar_shift_op_tests!(IVec3A, -2, 2);
+    impl_vec3_shift_op_tests!(IVec3A, IVec3A, UVec3A);
+
+    impl_vec3_scalar_bit_op_tests!(IVec3A, -2, 2);
+    impl_vec3_bit_op_tests!(IVec3A, -2, 2);
+}
+
 mod uvec3 {
     use glam::{
         uvec3, BVec3, I16Vec3, I64Vec3, I8Vec3, ISizeVec3, IVec3, U16Vec3, U64Vec3, U8Vec3,
@@ -3833,6 +3954,91 @@ mod uvec3 {
     impl_vec3_bit_op_tests!(UVec3, 0, 2);
 }
 
+mod uvec3a {
+    use glam::{uvec3a, BVec3A, IVec3A, UVec3, UVec3A, UVec4};
+
+    glam_test!(test_align, {
+        use std::mem;
+        assert_eq!(16, mem::size_of::<UVec3A>());
+        assert_eq!(16, mem::align_of::<UVec3A>());
+    });
+
+    glam_test!(test_mask_align16, {
+        // make sure the unused 'w' value doesn't break UVec3A behaviour
+        let a = UVec4::ZERO;
+        let mut b = UVec3A::from_vec4(a);
+        b.x = 1;
+        b.y = 1;
+        b.z = 1;
+        assert!(!b.cmpeq(UVec3A::ZERO).any());
+        assert!(b.cmpeq(UVec3A::splat(1)).all());
+        assert_eq!(UVec3A::ONE, b);
+    });
+
+    #[cfg(all(
+        target_feature = "sse2",
+        feature = "int-simd",
+        not(any(feature = "core-simd", feature = "scalar-math"))
+    ))]
+    #[test]
+    fn test_m128i() {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::*;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::*;
+
+        #[repr(C, align(16))]
+        struct A16([u32; 4]);
+
+        let v0 = UVec3A::new(1, 2, 3);
+        let m0: __m128i = v0.into();
+        let mut a0 = A16([0; 4]);
+        unsafe {
+            _mm_store_si128(a0.0.as_mut_ptr().cast(), m0);
+        }
+        assert_eq!([1, 2, 3], [a0.0[0], a0.0[1], a0.0[2]]);
+        let v1 = UVec3A::from(m0);
+        assert_eq!(v0, v1);
+    }
+
+    glam_test!(test_min_max_from_vec4, {
+        // checks that the 4th element is unused.
+        let v1 = UVec3A::from_vec4(UVec4::new(1, 2, 3, 4));
+        assert_eq!(v1.max_element(), 3);
+        assert_eq!(v1.element_sum(), 6);
+        assert_eq!(v1.element_product(), 6);
+        let v2 = UVec3A::from_vec4(UVec4::new(4, 3, 2, 1));
+        assert_eq!(v2.min_element(), 2);
+    });
+
+    glam_test!(test_to_uvec3, {
+        let v = UVec3A::new(1, 2, 3);
+        assert_eq!(UVec3::new(1, 2, 3), v.to_uvec3());
+        assert_eq!(UVec3::new(1, 2, 3), UVec3::from(v));
+        assert_eq!(v, UVec3::new(1, 2, 3).to_uvec3a());
+        assert_eq!(v, UVec3A::from(UVec3::new(1, 2, 3)));
+    });
+
+    glam_test!(test_try_from, {
+        assert_eq!(
+            UVec3A::new(1, 2, 3),
+            UVec3A::try_from(IVec3A::new(1, 2, 3)).unwrap()
+        );
+        assert!(UVec3A::try_from(IVec3A::new(-1, 2, 3)).is_err());
+        assert!(UVec3A::try_from(IVec3A::new(1, -2, 3)).is_err());
+        assert!(UVec3A::try_from(IVec3A::new(1, 2, -3)).is_err());
+    });
+
+    impl_vec3_unsigned_integer_tests!(u32, uvec3a, UVec3A, BVec3A);
+    impl_vec3_eq_hash_tests!(u32, uvec3a);
+
+    impl_vec3_scalar_shift_op_tests!(UVec3A, 0, 2);
+    impl_vec3_shift_op_tests!(UVec3A, IVec3A, UVec3A);
+
+    impl_vec3_scalar_bit_op_tests!(UVec3A, 0, 2);
+    impl_vec3_bit_op_tests!(UVec3A, 0, 2);
+}
+
 mod i64vec3 {
     use glam::{
         i64vec3, BVec3, I16Vec3, I64Vec3, I8Vec3, ISizeVec3, IVec3, U16Vec3, U64Vec3, U8Vec3,
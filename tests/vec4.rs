@@ -3834,18 +3834,68 @@ mod u16vec4 {
 }
 
 mod ivec4 {
+    #[cfg(any(feature = "scalar-math", not(feature = "int-simd")))]
+    use glam::BVec4;
+    #[cfg(all(feature = "int-simd", not(feature = "scalar-math")))]
+    use glam::BVec4A;
     use glam::{
-        ivec4, BVec4, I16Vec4, I64Vec4, I8Vec4, ISizeVec4, IVec2, IVec3, IVec4, U16Vec4, U64Vec4,
-        U8Vec4, USizeVec4, UVec4,
+        ivec4, I16Vec4, I64Vec4, I8Vec4, ISizeVec4, IVec2, IVec3, IVec4, U16Vec4, U64Vec4, U8Vec4,
+        USizeVec4, UVec4,
     };
 
     glam_test!(test_align, {
         use std::mem;
         assert_eq!(16, mem::size_of::<IVec4>());
-        #[cfg(not(feature = "cuda"))]
-        assert_eq!(4, mem::align_of::<IVec4>());
-        #[cfg(feature = "cuda")]
-        assert_eq!(16, mem::align_of::<IVec4>());
+        if cfg!(any(
+            all(feature = "int-simd", not(feature = "scalar-math")),
+            feature = "cuda"
+        )) {
+            assert_eq!(16, mem::align_of::<IVec4>());
+        } else {
+            assert_eq!(4, mem::align_of::<IVec4>());
+        }
+    });
+
+    #[cfg(all(
+        target_feature = "sse2",
+        feature = "int-simd",
+        not(any(feature = "core-simd", feature = "scalar-math"))
+    ))]
+    #[test]
+    fn test_m128i() {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::*;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::*;
+
+        #[repr(C, align(16))]
+        struct A16([i32; 4]);
+
+        let v0 = IVec4::new(-1, 2, -3, 4);
+        let m0: __m128i = v0.into();
+        let mut a0 = A16([0; 4]);
+        unsafe {
+            _mm_store_si128(a0.0.as_mut_ptr().cast(), m0);
+        }
+        assert_eq!([-1, 2, -3, 4], a0.0);
+        let v1 = IVec4::from(m0);
+        assert_eq!(v0, v1);
+    }
+
+    glam_test!(test_vec3a, {
+        use glam::IVec3A;
+        assert_eq!(
+            IVec4::new(1, 2, 3, 4),
+            IVec4::from((IVec3A::new(1, 2, 3), 4))
+        );
+        assert_eq!(
+            IVec4::new(1, 2, 3, 4),
+            IVec4::from((1, IVec3A::new(2, 3, 4)))
+        );
+        assert_eq!(
+            IVec3A::new(1, 2, 3),
+            IVec3A::from_vec4(IVec4::new(1, 2, 3, 4))
+        );
     });
 
     glam_test!(test_try_from, {
@@ -3928,6 +3978,49 @@ mod ivec4 {
             IVec4::new(i32::MAX, 5, i32::MIN, 0).wrapping_mul(IVec4::new(3, 3, 5, 1)),
             IVec4::new(2147483645, 15, -2147483648, 0)
         );
+        assert_eq!(
+            IVec4::new(-7, i32::MAX, 65536, -3).wrapping_mul(IVec4::new(6, -2, 65536, -11)),
+            IVec4::new(-42, 2, 0, 33)
+        );
+    });
+
+    glam_test!(test_shift_sign, {
+        assert_eq!(
+            IVec4::new(-8, 8, i32::MIN, -1) >> 2,
+            IVec4::new(-2, 2, i32::MIN / 4, -1)
+        );
+        assert_eq!(
+            IVec4::new(-8, 8, 1 << 30, -1) << 1u8,
+            IVec4::new(-16, 16, i32::MIN, -2)
+        );
+    });
+
+    glam_test!(test_overflow_matches_scalar, {
+        // Overflowing lanes are redone with the scalar operators, so the SIMD operators must panic
+        // or wrap exactly like `i32` does with the `overflow-checks` setting of this build.
+        #[cfg(all(feature = "std", panic = "unwind"))]
+        {
+            use core::hint::black_box;
+            use std::panic::catch_unwind;
+            let one = black_box(1);
+            let scalar = catch_unwind(|| black_box(i32::MAX) + one).ok();
+            let simd = catch_unwind(|| IVec4::new(0, i32::MAX, 0, 0) + IVec4::new(0, one, 0, 0));
+            assert_eq!(scalar.map(|y| IVec4::new(0, y, 0, 0)), simd.ok());
+            let scalar = catch_unwind(|| black_box(i32::MIN) - one).ok();
+            let simd = catch_unwind(|| IVec4::new(i32::MIN, 0, 0, 0) - one);
+            assert_eq!(scalar.map(|x| IVec4::new(x, -1, -1, -1)), simd.ok());
+            let scalar = catch_unwind(|| black_box(i32::MAX) * 2).ok();
+            let simd = catch_unwind(|| 2 * IVec4::new(1, 2, 3, i32::MAX));
+            assert_eq!(scalar.map(|w| IVec4::new(2, 4, 6, w)), simd.ok());
+            let scalar = catch_unwind(|| one << black_box(32)).ok();
+            let simd = catch_unwind(|| IVec4::ONE << black_box(32));
+            assert_eq!(scalar.map(IVec4::splat), simd.ok());
+        }
+        // Products that don't fit in 16 bits go lane by lane but must not overflow.
+        assert_eq!(
+            IVec4::new(100_000, -3, 7, 0) * IVec4::new(-3, 100_000, 1, 0),
+            IVec4::new(-300_000, -300_000, 7, 0)
+        );
     });
 
     glam_test!(test_wrapping_div, {
@@ -4013,6 +4106,10 @@ mod ivec4 {
         );
     });
 
+    #[cfg(all(feature = "int-simd", not(feature = "scalar-math")))]
+    impl_vec4_signed_integer_tests!(i32, ivec4, IVec4, IVec3, IVec2, BVec4A);
+
+    #[cfg(any(feature = "scalar-math", not(feature = "int-simd")))]
     impl_vec4_signed_integer_tests!(i32, ivec4, IVec4, IVec3, IVec2, BVec4);
     impl_vec4_eq_hash_tests!(i32, ivec4);
 
@@ -4024,18 +4121,68 @@ mod ivec4 {
 }
 
 mod uvec4 {
+    #[cfg(any(feature = "scalar-math", not(feature = "int-simd")))]
+    use glam::BVec4;
+    #[cfg(all(feature = "int-simd", not(feature = "scalar-math")))]
+    use glam::BVec4A;
     use glam::{
-        uvec4, BVec4, I16Vec4, I64Vec4, I8Vec4, ISizeVec4, IVec4, U16Vec4, U64Vec4, U8Vec4,
-        USizeVec4, UVec2, UVec3, UVec4,
+        uvec4, I16Vec4, I64Vec4, I8Vec4, ISizeVec4, IVec4, U16Vec4, U64Vec4, U8Vec4, USizeVec4,
+        UVec2, UVec3, UVec4,
     };
 
     glam_test!(test_align, {
         use std::mem;
         assert_eq!(16, mem::size_of::<UVec4>());
-        #[cfg(not(feature = "cuda"))]
-        assert_eq!(4, mem::align_of::<UVec4>());
-        #[cfg(feature = "cuda")]
-        assert_eq!(16, mem::align_of::<UVec4>());
+        if cfg!(any(
+            all(feature = "int-simd", not(feature = "scalar-math")),
+            feature = "cuda"
+        )) {
+            assert_eq!(16, mem::align_of::<UVec4>());
+        } else {
+            assert_eq!(4, mem::align_of::<UVec4>());
+        }
+    });
+
+    #[cfg(all(
+        target_feature = "sse2",
+        feature = "int-simd",
+        not(any(feature = "core-simd", feature = "scalar-math"))
+    ))]
+    #[test]
+    fn test_m128i() {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::*;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::*;
+
+        #[repr(C, align(16))]
+        struct A16([u32; 4]);
+
+        let v0 = UVec4::new(1, 2, 3, 4);
+        let m0: __m128i = v0.into();
+        let mut a0 = A16([0; 4]);
+        unsafe {
+            _mm_store_si128(a0.0.as_mut_ptr().cast(), m0);
+        }
+        assert_eq!([1, 2, 3, 4], a0.0);
+        let v1 = UVec4::from(m0);
+        assert_eq!(v0, v1);
+    }
+
+    glam_test!(test_vec3a, {
+        use glam::UVec3A;
+        assert_eq!(
+            UVec4::new(1, 2, 3, 4),
+            UVec4::from((UVec3A::new(1, 2, 3), 4))
+        );
+        assert_eq!(
+            UVec4::new(1, 2, 3, 4),
+            UVec4::from((1, UVec3A::new(2, 3, 4)))
+        );
+        assert_eq!(
+            UVec3A::new(1, 2, 3),
+            UVec3A::from_vec4(UVec4::new(1, 2, 3, 4))
+        );
     });
 
     glam_test!(test_try_from, {
@@ -4138,6 +4285,49 @@ mod uvec4 {
             UVec4::new(u32::MAX, 5, u32::MAX, 0).wrapping_mul(UVec4::new(3, 3, 5, 1)),
             UVec4::new(4294967293, 15, 4294967291, 0)
         );
+        assert_eq!(
+            UVec4::new(7, u32::MAX, 65536, 3).wrapping_mul(UVec4::new(6, 2, 65536, u32::MAX)),
+            UVec4::new(42, 4294967294, 0, 4294967293)
+        );
+    });
+
+    glam_test!(test_shift_sign, {
+        assert_eq!(
+            UVec4::new(u32::MAX, 8, 1 << 31, 0) >> 2,
+            UVec4::new(u32::MAX / 4, 2, 1 << 29, 0)
+        );
+        assert_eq!(
+            UVec4::new(u32::MAX, 8, 1 << 30, 0) << 1i64,
+            UVec4::new(u32::MAX - 1, 16, 1 << 31, 0)
+        );
+    });
+
+    glam_test!(test_overflow_matches_scalar, {
+        // Overflowing lanes are redone with the scalar operators, so the SIMD operators must panic
+        // or wrap exactly like `u32` does with the `overflow-checks` setting of this build.
+        #[cfg(all(feature = "std", panic = "unwind"))]
+        {
+            use core::hint::black_box;
+            use std::panic::catch_unwind;
+            let one = black_box(1);
+            let scalar = catch_unwind(|| black_box(u32::MAX) + one).ok();
+            let simd = catch_unwind(|| UVec4::new(0, u32::MAX, 0, 0) + UVec4::new(0, one, 0, 0));
+            assert_eq!(scalar.map(|y| UVec4::new(0, y, 0, 0)), simd.ok());
+            let scalar = catch_unwind(|| black_box(0) - one).ok();
+            let simd = catch_unwind(|| UVec4::new(0, 1, 2, 3) - one);
+            assert_eq!(scalar.map(|x| UVec4::new(x, 0, 1, 2)), simd.ok());
+            let scalar = catch_unwind(|| black_box(u32::MAX) * 2).ok();
+            let simd = catch_unwind(|| 2 * UVec4::new(1, 2, 3, u32::MAX));
+            assert_eq!(scalar.map(|w| UVec4::new(2, 4, 6, w)), simd.ok());
+            let scalar = catch_unwind(|| one >> black_box(32u8)).ok();
+            let simd = catch_unwind(|| UVec4::ONE >> black_box(32u8));
+            assert_eq!(scalar.map(UVec4::splat), simd.ok());
+        }
+        // Products that don't fit in 16 bits go lane by lane but must not overflow.
+        assert_eq!(
+            UVec4::new(100_000, 3, 7, 0) * UVec4::new(3, 100_000, 1, 0),
+            UVec4::new(300_000, 300_000, 7, 0)
+        );
     });
 
     glam_test!(test_wrapping_div, {
@@ -4199,6 +4389,10 @@ mod uvec4 {
         );
     });
 
+    #[cfg(all(feature = "int-simd", not(feature = "scalar-math")))]
+    impl_vec4_unsigned_integer_tests!(u32, uvec4, UVec4, UVec3, UVec2, BVec4A);
+
+    #[cfg(any(feature = "scalar-math", not(feature = "int-simd")))]
     impl_vec4_unsigned_integer_tests!(u32, uvec4, UVec4, UVec3, UVec2, BVec4);
     impl_vec4_eq_hash_tests!(u32, uvec4);
 
@@ -0,0 +1,220 @@
+// `Vec4` and `Vec3A` evaluate transcendental functions with SIMD polynomial approximations on the
+// SIMD backends, and the `fast_*` variants use polynomials on every backend. These tests measure
+// their error in ULP against the scalar implementation, which is itself within about 1 ULP of the
+// exact result, so the documented bounds are checked with one ULP of slack.
+#[macro_use]
+mod support;
+
+use glam::{Vec3A, Vec4};
+use support::Rng;
+
+const ITERATIONS: usize = 20000;
+
+/// Distance in ULP between `a` and `b`, treating all NaNs as equal.
+fn ulp_diff(a: f32, b: f32) -> u32 {
+    fn ordered(x: f32) -> i64 {
+        let i = x.to_bits() as i32;
+        (if i < 0 { i32::MIN - i } else { i }) as i64
+    }
+    if a.is_nan() || b.is_nan() {
+        return if a.is_nan() && b.is_nan() {
+            0
+        } else {
+            u32::MAX
+        };
+    }
+    (ordered(a) - ordered(b)).unsigned_abs() as u32
+}
+
+/// Asserts that each element of `actual` is within `max_ulp` of `f` applied to `input`.
+fn assert_ulp<const N: usize>(
+    name: &str,
+    input: [f32; N],
+    actual: [f32; N],
+    max_ulp: u32,
+    f: impl Fn(f32) -> f32,
+) {
+    for (x, a) in input.iter().zip(actual.iter()) {
+        let expected = f(*x);
+        let ulp = ulp_diff(expected, *a);
+        assert!(
+            ulp <= max_ulp,
+            "{name}({x:?}): expected {expected:?}, got {a:?} ({ulp} ulp)"
+        );
+    }
+}
+
+macro_rules! impl_vec_math_tests {
+    ($vec:ident, $n:expr) => {
+        glam_test!(test_sin_cos_ulp, {
+            let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+            for i in 0..ITERATIONS {
+                let max = [1.0, 10.0, 100.0, 8192.0][i % 4];
+                let x: [f32; $n] = rng.array(|r| r.uniform(max));
+                let v = $vec::from_array(x);
+                let (sin, cos) = v.sin_cos();
+                assert_ulp("sin", x, sin.to_array(), 3, f32::sin);
+                assert_ulp("cos", x, cos.to_array(), 3, f32::cos);
+                assert_ulp("sin", x, v.sin().to_array(), 3, f32::sin);
+                assert_ulp("cos", x, v.cos().to_array(), 3, f32::cos);
+                let (sin, cos) = v.fast_sin_cos();
+                assert_ulp("fast_sin", x, sin.to_array(), 28, f32::sin);
+                assert_ulp("fast_cos", x, cos.to_array(), 28, f32::cos);
+            }
+        });
+
+        glam_test!(test_sin_cos_fallback, {
+            // elements outside of the polynomial's range match the scalar implementation exactly,
+            // without affecting the other elements
+            let x: [f32; $n] = core::array::from_fn(|i| [0.5, 1e5, f32::INFINITY, f32::NAN][i]);
+            let (sin, cos) = $vec::from_array(x).sin_cos();
+            assert_ulp("sin", x, sin.to_array(), 0, |x| {
+                if x.abs() <= 8192.0 {
+                    $vec::from_array([x; $n]).sin().x
+                } else {
+                    x.sin()
+                }
+            });
+            assert_ulp("cos", x, cos.to_array(), 0, |x| {
+                if x.abs() <= 8192.0 {
+                    $vec::from_array([x; $n]).cos().x
+                } else {
+                    x.cos()
+                }
+            });
+        });
+
+        glam_test!(test_exp_ulp, {
+            let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+            for i in 0..ITERATIONS {
+                let max = [1.0, 10.0, 110.0][i % 3];
+                let x: [f32; $n] = rng.array(|r| r.uniform(max));
+                assert_ulp("exp", x, $vec::from_array(x).exp().to_array(), 2, f32::exp);
+                let x = x.map(|x| x.clamp(-87.3, 88.3));
+                assert_ulp(
+                    "fast_exp",
+                    x,
+                    $vec::from_array(x).fast_exp().to_array(),
+                    71,
+                    f32::exp,
+                );
+            }
+        });
+
+        glam_test!(test_exp_special, {
+            let x: [f32; $n] =
+                core::array::from_fn(|i| [f32::INFINITY, f32::NEG_INFINITY, f32::NAN, 0.0][i]);
+            assert_ulp("exp", x, $vec::from_array(x).exp().to_array(), 0, f32::exp);
+            let x = [88.72283; $n];
+            assert_ulp("exp", x, $vec::from_array(x).exp().to_array(), 1, f32::exp);
+            let x = [88.72284; $n];
+            assert_eq!($vec::from_array(x).exp(), $vec::INFINITY);
+            let x = [-103.0; $n];
+            assert_ulp("exp", x, $vec::from_array(x).exp().to_array(), 1, f32::exp);
+            let x = [-104.0; $n];
+            assert_eq!($vec::from_array(x).exp(), $vec::ZERO);
+            assert_eq!($vec::ZERO.exp(), $vec::ONE);
+        });
+
+        glam_test!(test_ln_ulp, {
+            let mut rng = Rng(0xd1b5_4a32_d192_ed03);
+            for _ in 0..ITERATIONS {
+                let x: [f32; $n] = rng.array(Rng::positive);
+                assert_ulp("ln", x, $vec::from_array(x).ln().to_array(), 2, f32::ln);
+                assert_ulp(
+                    "fast_ln",
+                    x,
+                    $vec::from_array(x).fast_ln().to_array(),
+                    27,
+                    f32::ln,
+                );
+                let x: [f32; $n] = rng.array(|r| 1.0 + r.uniform(0.5));
+                assert_ulp("ln", x, $vec::from_array(x).ln().to_array(), 2, f32::ln);
+                assert_ulp(
+                    "fast_ln",
+                    x,
+                    $vec::from_array(x).fast_ln().to_array(),
+                    27,
+                    f32::ln,
+                );
+            }
+        });
+
+        glam_test!(test_ln_special, {
+            let x: [f32; $n] = core::array::from_fn(|i| [f32::INFINITY, 0.0, -1.0, f32::NAN][i]);
+            assert_ulp("ln", x, $vec::from_array(x).ln().to_array(), 0, f32::ln);
+            let x: [f32; $n] =
+                core::array::from_fn(|i| [-0.0, f32::NEG_INFINITY, f32::from_bits(1), 1.0][i]);
+            assert_ulp("ln", x, $vec::from_array(x).ln().to_array(), 0, f32::ln);
+        });
+
+        glam_test!(test_powf_ulp, {
+            let mut rng = Rng(0xbb67_ae85_84ca_a73b);
+            for i in 0..ITERATIONS {
+                let n = rng.uniform([4.0, 40.0][i % 2]);
+                let x: [f32; $n] = rng.array(Rng::positive);
+                assert_ulp("powf", x, $vec::from_array(x).powf(n).to_array(), 2, |x| {
+                    x.powf(n)
+                });
+                let x: [f32; $n] = rng.array(|r| 1.0 + r.uniform(0.99));
+                assert_ulp("powf", x, $vec::from_array(x).powf(n).to_array(), 2, |x| {
+                    x.powf(n)
+                });
+                let actual = $vec::from_array(x).fast_powf(n).to_array();
+                for (x, a) in x.iter().zip(actual.iter()) {
+                    let expected = (*x as f64).powf(n as f64);
+                    if !(1e-37..1e37).contains(&expected) {
+                        continue;
+                    }
+                    let max = 5e-6 * (1.0 + (n as f64 * (*x as f64).ln()).abs());
+                    assert!(
+                        ((*a as f64 - expected) / expected).abs() <= max,
+                        "fast_powf({x:?}, {n:?}): expected {expected:?}, got {a:?}"
+                    );
+                }
+            }
+        });
+
+        glam_test!(test_powf_special, {
+            for n in [
+                0.0,
+                1.0,
+                -1.0,
+                0.5,
+                2.0,
+                f32::INFINITY,
+                f32::NAN,
+                -f32::INFINITY,
+            ] {
+                let x: [f32; $n] = core::array::from_fn(|i| [2.0, 0.0, -2.0, 1.0][i]);
+                assert_ulp("powf", x, $vec::from_array(x).powf(n).to_array(), 0, |x| {
+                    x.powf(n)
+                });
+                let x: [f32; $n] =
+                    core::array::from_fn(|i| [f32::NAN, f32::INFINITY, -0.0, f32::NEG_INFINITY][i]);
+                assert_ulp("powf", x, $vec::from_array(x).powf(n).to_array(), 0, |x| {
+                    x.powf(n)
+                });
+            }
+            assert_eq!(
+                $vec::from_array([3.0; $n]).powf(3.0),
+                $vec::from_array([27.0; $n])
+            );
+            assert_eq!(
+                $vec::from_array([1e-20; $n]).powf(3.0),
+                $vec::from_array([0.0; $n])
+            );
+            assert_eq!($vec::from_array([1e20; $n]).powf(3.0), $vec::INFINITY);
+        });
+    };
+}
+
+mod vec3a {
+    use super::*;
+    impl_vec_math_tests!(Vec3A, 3);
+}
+
+mod vec4 {
+    use super::*;
+    impl_vec_math_tests!(Vec4, 4);
+}
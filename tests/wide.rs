@@ -0,0 +1,252 @@
+#[macro_use]
+mod support;
+
+use glam::{EulerRot, Quat, Quatx4, Vec3, Vec3x4, Vec3x8, Vec4};
+
+const A: [Vec3; 8] = [
+    Vec3::new(1.0, 2.0, 3.0),
+    Vec3::new(-4.0, 5.5, 0.25),
+    Vec3::new(0.0, -1.0, 7.0),
+    Vec3::new(3.0, 0.0, -2.0),
+    Vec3::new(-0.5, -0.75, 9.0),
+    Vec3::new(6.0, 1.0, 1.0),
+    Vec3::new(2.0, -8.0, 0.5),
+    Vec3::new(0.125, 3.0, -3.0),
+];
+
+const B: [Vec3; 8] = [
+    Vec3::new(-2.0, 0.5, 1.0),
+    Vec3::new(3.0, 3.0, -1.5),
+    Vec3::new(1.0, 1.0, 1.0),
+    Vec3::new(-7.0, 2.0, 4.0),
+    Vec3::new(0.0, 0.0, -1.0),
+    Vec3::new(5.0, -6.0, 0.75),
+    Vec3::new(1.5, 2.5, 3.5),
+    Vec3::new(-1.0, -1.0, 8.0),
+];
+
+fn a4() -> [Vec3; 4] {
+    [A[0], A[1], A[2], A[3]]
+}
+
+fn b4() -> [Vec3; 4] {
+    [B[0], B[1], B[2], B[3]]
+}
+
+fn quats() -> [Quat; 4] {
+    [
+        Quat::from_rotation_x(0.5),
+        Quat::from_euler(EulerRot::YXZ, 1.0, -0.5, 2.0),
+        Quat::IDENTITY,
+        Quat::from_axis_angle(Vec3::new(1.0, 1.0, -1.0).normalize(), -2.5),
+    ]
+}
+
+glam_test!(test_vec3x4_pack, {
+    let v = Vec3x4::from_array(a4());
+    assert_eq!(Vec4::new(1.0, -4.0, 0.0, 3.0), v.x);
+    assert_eq!(Vec4::new(2.0, 5.5, -1.0, 0.0), v.y);
+    assert_eq!(Vec4::new(3.0, 0.25, 7.0, -2.0), v.z);
+    assert_eq!(a4(), v.to_array());
+    assert_eq!(v, Vec3x4::from(a4()));
+    assert_eq!(a4(), <[Vec3; 4]>::from(v));
+
+    assert_eq!(v, Vec3x4::from_slice(&A[..4]));
+    assert_eq!(Vec3x4::from_array(b4()), Vec3x4::from_slice(&B));
+
+    let mut out = [Vec3::NAN; 5];
+    v.write_to_slice(&mut out);
+    assert_eq!(a4(), [out[0], out[1], out[2], out[3]]);
+    assert!(out[4].is_nan());
+
+    should_panic!({ Vec3x4::from_slice(&A[..3]) });
+    should_panic!({ Vec3x4::ZERO.write_to_slice(&mut [Vec3::ZERO; 3]) });
+
+    assert_eq!([Vec3::X; 4], Vec3x4::splat(Vec3::X).to_array());
+    assert_eq!([Vec3::ONE; 4], Vec3x4::ONE.to_array());
+    assert_eq!(Vec3x4::ZERO, Vec3x4::default());
+});
+
+glam_test!(test_vec3x8_pack, {
+    let v = Vec3x8::from_array(A);
+    assert_eq!(A, v.to_array());
+    assert_eq!(v, Vec3x8::from_slice(&A));
+
+    let [lo, hi] = v.to_halves();
+    assert_eq!(a4(), lo.to_array());
+    assert_eq!([A[4], A[5], A[6], A[7]], hi.to_array());
+    assert_eq!(v, Vec3x8::from_halves([lo, hi]));
+    assert_eq!(v, Vec3x8::from([lo, hi]));
+    assert_eq!([lo, hi], <[Vec3x4; 2]>::from(v));
+
+    let mut out = [Vec3::ZERO; 8];
+    v.write_to_slice(&mut out);
+    assert_eq!(A, out);
+
+    should_panic!({ Vec3x8::from_slice(&A[..7]) });
+});
+
+glam_test!(test_vec3x4_select, {
+    let a = Vec3x4::from_array(a4());
+    let b = Vec3x4::from_array(b4());
+    let mask = [true, false, false, true].into();
+    assert_eq!(
+        [A[0], B[1], B[2], A[3]],
+        Vec3x4::select(mask, a, b).to_array()
+    );
+
+    let a = Vec3x8::from_array(A);
+    let b = Vec3x8::from_array(B);
+    let mask = [
+        [false, true, false, true].into(),
+        [true, true, false, false].into(),
+    ];
+    assert_eq!([A[1], A[3], A[4], A[5], B[0], B[2], B[6], B[7]], {
+        let r = Vec3x8::select(mask, a, b).to_array();
+        [r[1], r[3], r[4], r[5], r[0], r[2], r[6], r[7]]
+    });
+});
+
+glam_test!(test_vec3x4_math, {
+    let a = Vec3x4::from_array(a4());
+    let b = Vec3x4::from_array(b4());
+    let lanes = |f: &dyn Fn(Vec3) -> f32| Vec4::from_array(a4().map(f));
+    let pairs =
+        |f: &dyn Fn(Vec3, Vec3) -> Vec3| -> [Vec3; 4] { core::array::from_fn(|i| f(A[i], B[i])) };
+
+    assert_eq!(
+        Vec4::from_array(core::array::from_fn(|i| A[i].dot(B[i]))),
+        a.dot(b)
+    );
+    assert_eq!(pairs(&|a, b| a.cross(b)), a.cross(b).to_array());
+    assert_eq!(pairs(&|a, b| a.min(b)), a.min(b).to_array());
+    assert_eq!(pairs(&|a, b| a.max(b)), a.max(b).to_array());
+    assert_eq!(pairs(&|a, b| a + b), (a + b).to_array());
+    assert_eq!(pairs(&|a, b| a - b), (a - b).to_array());
+    assert_eq!(pairs(&|a, b| a * b), (a * b).to_array());
+    assert_eq!(pairs(&|a, b| a / b), (a / b).to_array());
+    assert_eq!(pairs(&|a, b| a.lerp(b, 0.25)), a.lerp(b, 0.25).to_array());
+    assert_eq!(
+        Vec4::from_array(core::array::from_fn(|i| A[i].distance(B[i]))),
+        a.distance(b)
+    );
+    assert_eq!(
+        Vec4::from_array(core::array::from_fn(|i| A[i].distance_squared(B[i]))),
+        a.distance_squared(b)
+    );
+
+    assert_eq!(lanes(&Vec3::length), a.length());
+    assert_eq!(lanes(&Vec3::length_squared), a.length_squared());
+    assert_eq!(lanes(&Vec3::length_recip), a.length_recip());
+    assert_eq!(a4().map(Vec3::normalize), a.normalize().to_array());
+    assert_eq!(a4().map(Vec3::abs), a.abs().to_array());
+    assert_eq!(a4().map(|v| -v), (-a).to_array());
+    assert_eq!(a4().map(|v| v * 2.0), (a * 2.0).to_array());
+    assert_eq!(a4().map(|v| v / 2.0), (a / 2.0).to_array());
+
+    let s = Vec4::new(1.0, 2.0, -3.0, 0.5);
+    assert_eq!(
+        core::array::from_fn::<_, 4, _>(|i| A[i] * s[i]),
+        (a * s).to_array()
+    );
+
+    let mut c = a;
+    c += b;
+    c *= 3.0;
+    c -= a;
+    c /= s;
+    assert_eq!(
+        core::array::from_fn::<_, 4, _>(|i| ((A[i] + B[i]) * 3.0 - A[i]) / s[i]),
+        c.to_array()
+    );
+
+    let z = Vec3x4::from_array([A[0], Vec3::ZERO, Vec3::NAN, A[3]]);
+    assert_eq!(
+        [A[0].normalize(), Vec3::ZERO, Vec3::ZERO, A[3].normalize()],
+        z.normalize_or_zero().to_array()
+    );
+});
+
+glam_test!(test_vec3x8_math, {
+    let a = Vec3x8::from_array(A);
+    let b = Vec3x8::from_array(B);
+    let pairs =
+        |f: &dyn Fn(Vec3, Vec3) -> Vec3| -> [Vec3; 8] { core::array::from_fn(|i| f(A[i], B[i])) };
+    let [lo, hi] = a.dot(b);
+    assert_eq!(
+        core::array::from_fn::<_, 8, _>(|i| A[i].dot(B[i])),
+        [lo.to_array(), hi.to_array()].concat()[..]
+    );
+    assert_eq!(pairs(&|a, b| a.cross(b)), a.cross(b).to_array());
+    assert_eq!(pairs(&|a, b| a + b * 2.0), (a + b * 2.0).to_array());
+    assert_eq!(A.map(Vec3::normalize), a.normalize().to_array());
+    assert_eq!(
+        A.map(Vec3::normalize_or_zero),
+        a.normalize_or_zero().to_array()
+    );
+});
+
+glam_test!(test_quatx4_pack, {
+    let q = Quatx4::from_array(quats());
+    assert_eq!(quats(), q.to_array());
+    assert_eq!(q, Quatx4::from_slice(&quats()));
+    assert_eq!(
+        Vec4::new(0.0, 0.0, 0.0, 1.0),
+        Quatx4::IDENTITY.to_array()[1].into()
+    );
+    assert_eq!([Quat::IDENTITY; 4], Quatx4::default().to_array());
+    assert_eq!([quats()[1]; 4], Quatx4::splat(quats()[1]).to_array());
+
+    let mut out = [Quat::IDENTITY; 4];
+    q.write_to_slice(&mut out);
+    assert_eq!(quats(), out);
+
+    should_panic!({ Quatx4::from_slice(&quats()[..3]) });
+
+    let mask = [false, true, true, false].into();
+    let r = Quatx4::select(mask, q, Quatx4::IDENTITY).to_array();
+    assert_eq!([Quat::IDENTITY, quats()[1], quats()[2], Quat::IDENTITY], r);
+});
+
+glam_test!(test_quatx4_math, {
+    let q = Quatx4::from_array(quats());
+    let p = Quatx4::from_array(quats().map(|q| q * Quat::from_rotation_z(0.75)));
+    let v = Vec3x4::from_array(a4());
+
+    let rotated = (q * v).to_array();
+    for i in 0..4 {
+        assert_approx_eq!(quats()[i] * A[i], rotated[i], 1e-5);
+    }
+
+    let rotated = (quats()[1] * v).to_array();
+    for i in 0..4 {
+        assert_approx_eq!(quats()[1] * A[i], rotated[i], 1e-5);
+    }
+
+    let rotated = (quats()[3] * Vec3x8::from_array(A)).to_array();
+    for i in 0..8 {
+        assert_approx_eq!(quats()[3] * A[i], rotated[i], 1e-5);
+    }
+
+    let qp = (q * p).to_array();
+    let lerped = q.lerp(-p, 0.3).to_array();
+    let inverse = q.inverse().to_array();
+    let normalized = (q * 2.0).normalize().to_array();
+    for i in 0..4 {
+        let (q0, p0) = (quats()[i], p.to_array()[i]);
+        assert_approx_eq!(q0 * p0, qp[i], 1e-6);
+        assert_approx_eq!(q0.lerp(-p0, 0.3), lerped[i], 1e-6);
+        assert_eq!(q0.inverse(), inverse[i]);
+        assert_approx_eq!(q0, normalized[i], 1e-6);
+    }
+    assert_approx_eq!(Vec4::ONE, q.length(), 1e-6);
+    assert_approx_eq!(Vec4::ONE, q.length_squared(), 1e-6);
+    assert_approx_eq!(Vec4::ONE, q.length_recip(), 1e-6);
+    assert_approx_eq!(Vec4::ONE, q.dot(q), 1e-6);
+
+    let mut r = q;
+    r *= Quatx4::IDENTITY;
+    assert_eq!(q, r);
+    assert_eq!(q, -(-q));
+    assert_eq!(q, (q + q) - q);
+});